@@ -0,0 +1,4 @@
+-- Migration: Add per-segment audio quality score to transcripts
+-- 0.0 (unusable) to 1.0 (clean); NULL for segments saved before scoring existed
+
+ALTER TABLE transcripts ADD COLUMN quality_score REAL;
@@ -0,0 +1,5 @@
+-- Migration: Add diarized speaker label to transcripts
+-- Holds the speaker assigned by diarization (e.g. 'Speaker 2'); NULL when diarization was off.
+-- The existing 'speaker' column keeps the audio source ('mic' / 'system').
+
+ALTER TABLE transcripts ADD COLUMN speaker_label TEXT;
@@ -0,0 +1,14 @@
+-- Migration: Add voice profiles for speaker enrollment
+-- Each profile holds an averaged speaker embedding (little-endian f32 array) so diarized
+-- speakers can be labeled with a person's name across meetings.
+
+CREATE TABLE IF NOT EXISTS voice_profiles (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    embedding BLOB NOT NULL,
+    sample_count INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_voice_profiles_name ON voice_profiles(name COLLATE NOCASE);
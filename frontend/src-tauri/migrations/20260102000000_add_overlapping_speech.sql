@@ -0,0 +1,5 @@
+-- Migration: Flag transcript segments spoken over another speaker
+-- Set when a segment's time range overlaps a segment from a different speaker (split mic/system
+-- transcription only); these regions are the least reliable and worth double-checking.
+
+ALTER TABLE transcripts ADD COLUMN overlapping_speech BOOLEAN NOT NULL DEFAULT 0;
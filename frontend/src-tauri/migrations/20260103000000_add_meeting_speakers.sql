@@ -0,0 +1,17 @@
+-- Migration: Store per-meeting speaker embeddings for cross-meeting voice matching
+-- One row per diarized speaker of a meeting (centroid embedding, little-endian f32 array).
+-- Rows sharing a voice_group_id are believed to be the same person across meetings.
+
+CREATE TABLE IF NOT EXISTS meeting_speakers (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    embedding BLOB NOT NULL,
+    segment_count INTEGER NOT NULL DEFAULT 0,
+    voice_group_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_speakers_meeting ON meeting_speakers(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_meeting_speakers_group ON meeting_speakers(voice_group_id);
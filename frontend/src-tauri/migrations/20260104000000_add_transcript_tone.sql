@@ -0,0 +1,6 @@
+-- Migration: Add per-segment sentiment and tension from the optional tone analysis pass
+-- sentiment: 'positive' | 'neutral' | 'negative'; tension: 0.0 (calm) to 1.0 (heated).
+-- Both NULL until the meeting has been analyzed.
+
+ALTER TABLE transcripts ADD COLUMN sentiment TEXT;
+ALTER TABLE transcripts ADD COLUMN tension REAL;
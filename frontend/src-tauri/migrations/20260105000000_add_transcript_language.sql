@@ -0,0 +1,4 @@
+-- Migration: Add per-segment language (ISO 639-1, e.g. 'en', 'es') for multilingual meetings
+-- NULL when the segment was too short to identify or predates language identification.
+
+ALTER TABLE transcripts ADD COLUMN language TEXT;
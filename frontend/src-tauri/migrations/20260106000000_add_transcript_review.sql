@@ -0,0 +1,6 @@
+-- Migration: Store segment confidence and a "needs review" flag for risky segments
+-- needs_review is set at save time from confidence x audio quality (and overlapping speech)
+-- and cleared once the user has proofread the segment.
+
+ALTER TABLE transcripts ADD COLUMN confidence REAL;
+ALTER TABLE transcripts ADD COLUMN needs_review BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,17 @@
+-- Migration: Store action items extracted from meetings as structured rows
+-- Kept apart from the free-text summary so they can be listed, ticked off and exported.
+-- segment_id points at the transcript segment the item was taken from (if it still exists).
+
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    description TEXT NOT NULL,
+    assignee TEXT,
+    due_date TEXT,
+    segment_id TEXT,
+    done BOOLEAN NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting ON action_items(meeting_id);
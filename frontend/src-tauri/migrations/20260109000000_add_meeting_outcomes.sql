@@ -0,0 +1,15 @@
+-- Migration: Store decisions and open questions extracted from meetings
+-- kind is 'decision' or 'open_question'; segment_ids is a JSON array of the transcript
+-- segment ids where the decision was made or the question was raised.
+
+CREATE TABLE IF NOT EXISTS meeting_outcomes (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    text TEXT NOT NULL,
+    segment_ids TEXT NOT NULL DEFAULT '[]',
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_outcomes_meeting ON meeting_outcomes(meeting_id);
@@ -0,0 +1,5 @@
+-- Migration: Store user-defined prompt templates for minutes generation
+-- JSON array of {id, name, meeting_types, system_prompt, prompt}; prompts use
+-- {{transcript}}, {{participants}}, {{date}} and {{agenda}} variables.
+
+ALTER TABLE settings ADD COLUMN promptTemplates TEXT;
@@ -0,0 +1,17 @@
+-- Migration: Store topical chapters of a meeting
+-- One row per chapter in meeting order; start_time/end_time are recording-relative seconds
+-- (end_time NULL for the last chapter) so the player can jump between chapters.
+
+CREATE TABLE IF NOT EXISTS meeting_chapters (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    start_segment_id TEXT,
+    start_time REAL,
+    end_time REAL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_chapters_meeting ON meeting_chapters(meeting_id, position);
@@ -0,0 +1,5 @@
+-- Migration: One-line meeting description
+-- Generated from the transcript when a meeting ends, alongside an automatic title for
+-- meetings still carrying the default timestamp name.
+
+ALTER TABLE meetings ADD COLUMN description TEXT;
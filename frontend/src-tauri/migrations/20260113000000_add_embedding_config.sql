@@ -0,0 +1,5 @@
+-- Migration: Embedding model used for the per-meeting vector index
+-- JSON {provider, model}; provider is "ollama" (local), "openai" or "custom-openai"
+-- (the custom endpoint from customOpenAIConfig). NULL leaves semantic indexing off.
+
+ALTER TABLE settings ADD COLUMN embeddingConfig TEXT;
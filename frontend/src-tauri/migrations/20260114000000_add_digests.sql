@@ -0,0 +1,28 @@
+-- Migration: Multi-meeting digests
+-- meeting_tags labels meetings so a digest can cover one project or team; tags are
+-- stored lower-case. meeting_digests keeps every generated report; meeting_ids is a JSON
+-- array of the meetings it covered and scheduled marks runs started by the weekly schedule.
+-- settings.digestSchedule holds the schedule as JSON (NULL when off).
+
+CREATE TABLE IF NOT EXISTS meeting_tags (
+    meeting_id TEXT NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, tag),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_tags_tag ON meeting_tags(tag);
+
+CREATE TABLE IF NOT EXISTS meeting_digests (
+    id TEXT PRIMARY KEY NOT NULL,
+    title TEXT NOT NULL,
+    range_start TEXT NOT NULL,
+    range_end TEXT NOT NULL,
+    tag TEXT,
+    meeting_ids TEXT NOT NULL,
+    markdown TEXT NOT NULL,
+    scheduled BOOLEAN NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL
+);
+
+ALTER TABLE settings ADD COLUMN digestSchedule TEXT;
@@ -0,0 +1,5 @@
+-- Migration: Default output style for generated minutes
+-- One of "executive_brief", "detailed_minutes", "bullet_points" or "narrative".
+-- NULL means detailed minutes; the style can still be chosen per meeting.
+
+ALTER TABLE settings ADD COLUMN summaryStyle TEXT;
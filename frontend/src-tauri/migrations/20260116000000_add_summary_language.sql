@@ -0,0 +1,5 @@
+-- Migration: Output language of a meeting's minutes
+-- ISO 639-1 code (optionally with a region, e.g. "pt-br") chosen when the summary is
+-- generated. NULL writes the minutes in the language the meeting was held in.
+
+ALTER TABLE meetings ADD COLUMN summary_language TEXT;
@@ -0,0 +1,19 @@
+-- Migration: LLM usage and cost tracking
+-- One row per completion request made by the summary model: minutes, extraction passes,
+-- titles, Q&A and so on. Token counts are estimated from the prompt and reply text;
+-- cost_usd is NULL when the model's price isn't known. meeting_id is cleared (not
+-- deleted) with its meeting so overall spend stays accurate.
+
+CREATE TABLE IF NOT EXISTS llm_usage (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT,
+    provider TEXT NOT NULL,
+    model TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    prompt_tokens INTEGER NOT NULL,
+    completion_tokens INTEGER NOT NULL,
+    cost_usd REAL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_llm_usage_meeting_id ON llm_usage(meeting_id);
@@ -0,0 +1,21 @@
+-- Migration: Summary versions
+-- Every generated summary is kept as a numbered version of its meeting, along with the
+-- template, model and style it was written with. Before a summary is replaced (by a
+-- regeneration or a restore) the current one is saved too if it isn't already a version:
+-- source is "generated", "edited" (the user changed it) or "earlier" (saved before
+-- versioning existed).
+
+CREATE TABLE IF NOT EXISTS summary_versions (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    source TEXT NOT NULL,
+    markdown TEXT NOT NULL,
+    template_id TEXT,
+    provider TEXT,
+    model TEXT,
+    style TEXT,
+    created_at TEXT NOT NULL,
+    UNIQUE (meeting_id, version),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
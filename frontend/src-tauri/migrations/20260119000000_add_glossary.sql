@@ -0,0 +1,14 @@
+-- Migration: Glossary
+-- Names, projects, acronyms and terms the summary model should know. Every entry is
+-- given to the model when minutes are written so it spells names correctly and expands
+-- acronyms. kind is "person", "project", "acronym" or "term"; definition is optional
+-- (a role, what a project is, what an acronym stands for). Terms are unique regardless
+-- of case.
+
+CREATE TABLE IF NOT EXISTS glossary_entries (
+    id TEXT PRIMARY KEY NOT NULL,
+    kind TEXT NOT NULL,
+    term TEXT NOT NULL COLLATE NOCASE UNIQUE,
+    definition TEXT,
+    updated_at TEXT NOT NULL
+);
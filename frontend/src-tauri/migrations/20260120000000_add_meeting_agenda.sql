@@ -0,0 +1,5 @@
+-- Migration: Meeting agendas
+-- The agenda attached to a meeting, as plain text with one item per line. When set, the
+-- minutes get an agenda coverage section and prompt templates receive it as {{agenda}}.
+
+ALTER TABLE meetings ADD COLUMN agenda TEXT;
@@ -0,0 +1,18 @@
+-- Migration: Standup reports
+-- Per-speaker updates extracted from standups: kind is 'yesterday', 'today', 'blocker'
+-- or 'risk'. segment_ids is a JSON array of the transcript segments the update came
+-- from and start_time the recording time of the first one.
+
+CREATE TABLE IF NOT EXISTS standup_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    speaker TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    text TEXT NOT NULL,
+    segment_ids TEXT NOT NULL DEFAULT '[]',
+    start_time REAL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_standup_items_meeting ON standup_items(meeting_id);
@@ -0,0 +1,20 @@
+-- Migration: Highlight quotes
+-- The most important verbatim quotes of a meeting, in meeting order. segment_id is the
+-- transcript segment the quote comes from; start_time/end_time are recording-relative
+-- seconds of that segment for playback and clip export (end_time is None for the last).
+
+CREATE TABLE IF NOT EXISTS meeting_highlights (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    quote TEXT NOT NULL,
+    speaker TEXT,
+    segment_id TEXT,
+    start_time REAL,
+    end_time REAL,
+    reason TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_highlights_meeting ON meeting_highlights(meeting_id);
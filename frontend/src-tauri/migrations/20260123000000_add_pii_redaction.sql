@@ -0,0 +1,14 @@
+-- Migration: PII redaction
+-- settings.piiRedaction holds the redaction settings as JSON (NULL when off). Personal data
+-- replaced by placeholders before a transcript went to a cloud model is remembered per
+-- meeting in redaction_mappings, so redacted minutes can get the originals back later.
+
+ALTER TABLE settings ADD COLUMN piiRedaction TEXT;
+
+CREATE TABLE IF NOT EXISTS redaction_mappings (
+    meeting_id TEXT NOT NULL,
+    placeholder TEXT NOT NULL,
+    original TEXT NOT NULL,
+    PRIMARY KEY (meeting_id, placeholder),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
@@ -0,0 +1,21 @@
+-- Migration: Summary feedback
+-- Thumbs up/down and an optional comment on a generated summary or one of its sections,
+-- tagged with the template, provider and model that wrote it so feedback can be compared
+-- across prompt templates. section is the heading the feedback is about, NULL for the
+-- summary as a whole; rating is 1 (up) or -1 (down).
+
+CREATE TABLE IF NOT EXISTS summary_feedback (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    version INTEGER,
+    section TEXT,
+    rating INTEGER NOT NULL,
+    comment TEXT,
+    template_id TEXT,
+    provider TEXT,
+    model TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_summary_feedback_template ON summary_feedback(template_id);
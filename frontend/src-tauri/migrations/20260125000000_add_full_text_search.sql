@@ -0,0 +1,65 @@
+-- Migration: Full-text search
+-- FTS5 indexes over transcript segments and the markdown of stored summaries, kept in sync
+-- by triggers. Each index row takes the rowid of the row it indexes so updates and deletes
+-- stay cheap; searches join back on the segment and meeting ids.
+
+CREATE VIRTUAL TABLE IF NOT EXISTS transcripts_fts USING fts5(
+    transcript,
+    segment_id UNINDEXED,
+    meeting_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO transcripts_fts (rowid, transcript, segment_id, meeting_id)
+SELECT rowid, transcript, id, meeting_id FROM transcripts;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_insert AFTER INSERT ON transcripts BEGIN
+    INSERT INTO transcripts_fts (rowid, transcript, segment_id, meeting_id)
+    VALUES (new.rowid, new.transcript, new.id, new.meeting_id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_delete AFTER DELETE ON transcripts BEGIN
+    DELETE FROM transcripts_fts WHERE rowid = old.rowid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_fts_update AFTER UPDATE OF transcript, meeting_id ON transcripts BEGIN
+    DELETE FROM transcripts_fts WHERE rowid = old.rowid;
+    INSERT INTO transcripts_fts (rowid, transcript, segment_id, meeting_id)
+    VALUES (new.rowid, new.transcript, new.id, new.meeting_id);
+END;
+
+-- Summaries live in summary_processes.result as JSON; only the markdown is indexed
+CREATE VIRTUAL TABLE IF NOT EXISTS summaries_fts USING fts5(
+    markdown,
+    meeting_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO summaries_fts (rowid, markdown, meeting_id)
+SELECT rowid, markdown, meeting_id FROM (
+    SELECT rowid, meeting_id,
+           CASE WHEN json_valid(result) THEN json_extract(result, '$.markdown') END AS markdown
+    FROM summary_processes
+)
+WHERE markdown IS NOT NULL;
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_insert AFTER INSERT ON summary_processes BEGIN
+    INSERT INTO summaries_fts (rowid, markdown, meeting_id)
+    SELECT new.rowid, markdown, new.meeting_id FROM (
+        SELECT CASE WHEN json_valid(new.result) THEN json_extract(new.result, '$.markdown') END AS markdown
+    )
+    WHERE markdown IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_delete AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM summaries_fts WHERE rowid = old.rowid;
+END;
+
+CREATE TRIGGER IF NOT EXISTS summaries_fts_update AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM summaries_fts WHERE rowid = old.rowid;
+    INSERT INTO summaries_fts (rowid, markdown, meeting_id)
+    SELECT new.rowid, markdown, new.meeting_id FROM (
+        SELECT CASE WHEN json_valid(new.result) THEN json_extract(new.result, '$.markdown') END AS markdown
+    )
+    WHERE markdown IS NOT NULL;
+END;
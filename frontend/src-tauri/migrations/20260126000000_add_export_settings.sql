@@ -0,0 +1,5 @@
+-- Migration: Export settings
+-- settings.exportSettings holds the meeting export options as JSON (NULL uses the
+-- built-in defaults), such as the Markdown template meetings are rendered with.
+
+ALTER TABLE settings ADD COLUMN exportSettings TEXT;
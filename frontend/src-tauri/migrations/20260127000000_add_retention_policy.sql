@@ -0,0 +1,8 @@
+-- Migration: Retention policy
+-- meetings.starred marks meetings the user wants to keep; retention cleanup skips them
+-- when the policy says so. settings.retentionPolicy holds the policy as JSON (NULL when
+-- never configured, which keeps everything).
+
+ALTER TABLE meetings ADD COLUMN starred BOOLEAN NOT NULL DEFAULT 0;
+
+ALTER TABLE settings ADD COLUMN retentionPolicy TEXT;
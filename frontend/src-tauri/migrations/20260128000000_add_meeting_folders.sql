@@ -0,0 +1,20 @@
+-- Migration: Meeting folders
+-- Folders organize meetings in the library (unrelated to meetings.folder_path, where the
+-- recording is stored). parent_id nests folders; a top-level folder doubles as a
+-- workspace. Names are unique among siblings, ignoring case. meetings.library_folder_id
+-- is the folder a meeting is filed in, NULL for unfiled.
+
+CREATE TABLE IF NOT EXISTS meeting_folders (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    parent_id TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (parent_id) REFERENCES meeting_folders(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_meeting_folders_name
+    ON meeting_folders(COALESCE(parent_id, ''), name COLLATE NOCASE);
+
+ALTER TABLE meetings ADD COLUMN library_folder_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_meetings_library_folder ON meetings(library_folder_id);
@@ -0,0 +1,19 @@
+-- Migration: Transcript revision history
+-- Every manual edit of a transcript (text, merge, split, speaker) is one revision holding
+-- the affected segment rows before and after it as JSON arrays, so the original ASR output
+-- survives any number of edits. Reverting writes a new revision (reverts = the revision
+-- undone) and marks the undone one with reverted_by.
+
+CREATE TABLE IF NOT EXISTS transcript_revisions (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    before_segments TEXT NOT NULL,
+    after_segments TEXT NOT NULL,
+    reverts TEXT,
+    reverted_by TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcript_revisions_meeting ON transcript_revisions(meeting_id, created_at);
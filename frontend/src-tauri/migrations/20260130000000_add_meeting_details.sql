@@ -0,0 +1,26 @@
+-- Migration: Meeting details
+-- Structured facts about a meeting beyond its title: when it was scheduled, how long it
+-- ran, the platform it was held on and the calendar event it belongs to. All are
+-- optional and filled in automatically where possible, then editable.
+-- meeting_participants lists who attended; source records where an entry came from
+-- ('manual', 'speaker' for a named transcript speaker, 'calendar' for an invitee).
+
+ALTER TABLE meetings ADD COLUMN scheduled_start TEXT;
+ALTER TABLE meetings ADD COLUMN duration_seconds REAL;
+ALTER TABLE meetings ADD COLUMN platform TEXT;
+ALTER TABLE meetings ADD COLUMN calendar_event_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_meetings_calendar_event ON meetings(calendar_event_id);
+
+CREATE TABLE IF NOT EXISTS meeting_participants (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    email TEXT,
+    source TEXT NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'speaker', 'calendar')),
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_meeting_participants_name
+    ON meeting_participants(meeting_id, name COLLATE NOCASE);
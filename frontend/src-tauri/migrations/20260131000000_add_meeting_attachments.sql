@@ -0,0 +1,17 @@
+-- Migration: Meeting attachments
+-- Files attached to a meeting (agenda, slide deck, shared documents). The files are
+-- copied into the app data directory under attachments/<meeting_id>/; stored_name is the
+-- copy's file name there and file_name the name the user attached it under.
+
+CREATE TABLE IF NOT EXISTS meeting_attachments (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    stored_name TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_meeting_attachments_meeting ON meeting_attachments(meeting_id, created_at);
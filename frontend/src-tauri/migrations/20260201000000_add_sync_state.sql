@@ -0,0 +1,14 @@
+-- Migration: Sync state
+-- settings.syncSettings holds the remote storage configuration as JSON (NULL when sync was
+-- never set up). sync_state remembers, per meeting, the content hashes of the local and
+-- remote copies at the last successful sync, to tell which side changed since. Rows
+-- outlive their meeting so a local deletion can be pushed to the remote.
+
+ALTER TABLE settings ADD COLUMN syncSettings TEXT;
+
+CREATE TABLE IF NOT EXISTS sync_state (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    local_hash TEXT NOT NULL,
+    remote_hash TEXT NOT NULL,
+    synced_at TEXT NOT NULL
+);
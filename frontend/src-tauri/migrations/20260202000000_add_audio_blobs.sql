@@ -0,0 +1,21 @@
+-- Migration: Deduplicated audio storage
+-- audio_blobs lists the recordings held once in an .audio-store folder beside the meeting
+-- folders, by SHA-256 of their content. audio_blob_links lists the meeting recordings that
+-- are hard links to a blob; a blob no longer linked from any existing file is garbage.
+
+CREATE TABLE IF NOT EXISTS audio_blobs (
+    path TEXT PRIMARY KEY NOT NULL,
+    hash TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audio_blobs_hash ON audio_blobs(hash);
+
+CREATE TABLE IF NOT EXISTS audio_blob_links (
+    path TEXT PRIMARY KEY NOT NULL,
+    blob_path TEXT NOT NULL REFERENCES audio_blobs(path) ON DELETE CASCADE,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audio_blob_links_blob ON audio_blob_links(blob_path);
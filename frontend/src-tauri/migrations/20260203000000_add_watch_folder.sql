@@ -0,0 +1,16 @@
+-- Migration: Watch folder
+-- settings.watchFolderSettings holds the watched directory configuration as JSON (NULL
+-- when never set up). watch_folder_files records each audio file picked up from it, so a
+-- file is imported once; a file replaced with different contents is picked up again.
+
+ALTER TABLE settings ADD COLUMN watchFolderSettings TEXT;
+
+CREATE TABLE IF NOT EXISTS watch_folder_files (
+    path TEXT PRIMARY KEY NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    modified_at TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('processing', 'done', 'failed')),
+    meeting_id TEXT,
+    error TEXT,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,15 @@
+-- Migration: Bookmarked transcript segments
+-- Segments the user starred, during the meeting or while reviewing it, with an optional
+-- note. Unstarring deletes the row; a segment can be bookmarked once.
+
+CREATE TABLE IF NOT EXISTS segment_bookmarks (
+    transcript_id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    note TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_bookmarks_meeting ON segment_bookmarks(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_segment_bookmarks_created ON segment_bookmarks(created_at);
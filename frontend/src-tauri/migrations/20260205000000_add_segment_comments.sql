@@ -0,0 +1,18 @@
+-- Migration: Comments on transcript segments
+-- Reviewers' notes on what was said, kept apart from the transcript text so annotating
+-- never edits it. author is the name the reviewer entered; comments go with their segment
+-- and meeting.
+
+CREATE TABLE IF NOT EXISTS segment_comments (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    transcript_id TEXT NOT NULL,
+    author TEXT NOT NULL,
+    text TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    FOREIGN KEY (transcript_id) REFERENCES transcripts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_segment_comments_meeting ON segment_comments(meeting_id);
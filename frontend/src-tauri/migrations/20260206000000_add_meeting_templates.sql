@@ -0,0 +1,8 @@
+-- Migration: Meeting templates
+-- settings.meetingTemplates holds the user's templates for recurring meetings as JSON (NULL
+-- when none were defined). meetings.meeting_template_id records the template applied to a
+-- meeting, so it is applied once and summaries can use its template, language and model.
+
+ALTER TABLE settings ADD COLUMN meetingTemplates TEXT;
+
+ALTER TABLE meetings ADD COLUMN meeting_template_id TEXT;
@@ -0,0 +1,10 @@
+-- Migration: Trash
+-- Deleting a meeting sets meetings.deleted_at instead of removing it; trashed meetings are
+-- hidden everywhere and removed for good once settings.trashSettings' purge window (JSON,
+-- NULL for the default) has passed, or when the trash is emptied.
+
+ALTER TABLE meetings ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_meetings_deleted_at ON meetings(deleted_at);
+
+ALTER TABLE settings ADD COLUMN trashSettings TEXT;
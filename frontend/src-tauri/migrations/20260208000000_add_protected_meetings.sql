@@ -0,0 +1,15 @@
+-- Migration: Password-protected meetings
+-- A protected meeting's transcript text and summaries are kept only as ciphertext
+-- (XChaCha20-Poly1305 under a key derived with Argon2 from the meeting's passphrase and
+-- salt); the plaintext columns are blanked while it is locked. unlocked is 1 while the
+-- plaintext has been written back for reading.
+
+CREATE TABLE IF NOT EXISTS protected_meetings (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    salt BLOB NOT NULL,
+    nonce BLOB NOT NULL,
+    ciphertext BLOB NOT NULL,
+    unlocked INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
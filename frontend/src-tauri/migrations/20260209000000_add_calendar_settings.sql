@@ -0,0 +1,5 @@
+-- Migration: Calendar integration
+-- settings.calendarSettings holds the connected calendars and prompt preferences as JSON
+-- (NULL until a calendar is set up). OAuth tokens live in the system keychain, not here.
+
+ALTER TABLE settings ADD COLUMN calendarSettings TEXT;
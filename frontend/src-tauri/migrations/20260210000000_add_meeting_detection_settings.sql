@@ -0,0 +1,5 @@
+-- Migration: Meeting app detection
+-- settings.meetingDetectionSettings holds whether calls in meeting apps are watched for,
+-- which apps, and whether to notify, as JSON (NULL until first saved; detection is off).
+
+ALTER TABLE settings ADD COLUMN meetingDetectionSettings TEXT;
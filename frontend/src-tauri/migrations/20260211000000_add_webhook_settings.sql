@@ -0,0 +1,5 @@
+-- Migration: Outbound webhooks
+-- settings.webhookSettings holds the webhooks (URL, signing secret, events, payload options)
+-- as JSON; NULL until one is added. Delivery history is kept in memory only.
+
+ALTER TABLE settings ADD COLUMN webhookSettings TEXT;
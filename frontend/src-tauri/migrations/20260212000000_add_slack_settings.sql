@@ -0,0 +1,5 @@
+-- Migration: Slack integration
+-- settings.slackSettings holds the Slack connection (bot token or incoming webhook), the
+-- default channel and the per-tag auto-post rules as JSON; NULL until Slack is connected.
+
+ALTER TABLE settings ADD COLUMN slackSettings TEXT;
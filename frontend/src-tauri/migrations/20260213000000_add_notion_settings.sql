@@ -0,0 +1,6 @@
+-- Migration: Notion export
+-- settings.notionSettings holds the integration secret, the target database, the property
+-- names to fill and whether meetings are exported automatically, as JSON; NULL until
+-- Notion is connected.
+
+ALTER TABLE settings ADD COLUMN notionSettings TEXT;
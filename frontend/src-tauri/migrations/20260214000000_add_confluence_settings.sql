@@ -0,0 +1,6 @@
+-- Migration: Confluence publishing
+-- settings.confluenceSettings holds the Confluence site, account email and API token, the
+-- target space and parent page, and whether minutes are published automatically, as JSON;
+-- NULL until Confluence is connected.
+
+ALTER TABLE settings ADD COLUMN confluenceSettings TEXT;
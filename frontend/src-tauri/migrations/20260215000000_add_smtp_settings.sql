@@ -0,0 +1,5 @@
+-- Migration: Email via SMTP
+-- settings.smtpSettings holds the SMTP server (host, port, TLS mode, login name) and the
+-- sender as JSON; NULL until email is set up. The password is kept in the OS keychain.
+
+ALTER TABLE settings ADD COLUMN smtpSettings TEXT;
@@ -0,0 +1,5 @@
+-- Migration: Local REST API
+-- settings.localApiSettings holds whether the localhost API server runs, its port and the
+-- bearer token clients must send, as JSON; NULL until the local API is set up.
+
+ALTER TABLE settings ADD COLUMN localApiSettings TEXT;
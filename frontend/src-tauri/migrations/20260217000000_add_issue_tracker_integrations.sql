@@ -0,0 +1,10 @@
+-- Migration: Jira and Linear issues from action items
+-- settings.jiraSettings and settings.linearSettings hold the connection, the default
+-- project or team and the per-tag mapping, as JSON; NULL until the tracker is connected.
+-- action_items.issue_key and issue_url link an item to the issue created from it.
+
+ALTER TABLE settings ADD COLUMN jiraSettings TEXT;
+ALTER TABLE settings ADD COLUMN linearSettings TEXT;
+
+ALTER TABLE action_items ADD COLUMN issue_key TEXT;
+ALTER TABLE action_items ADD COLUMN issue_url TEXT;
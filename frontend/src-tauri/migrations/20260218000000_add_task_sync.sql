@@ -0,0 +1,19 @@
+-- Migration: Todoist and Asana task sync
+-- settings.taskSyncSettings holds the task manager connection, the names the user goes by
+-- and whether meetings sync on their own, as JSON; NULL until a task manager is connected.
+-- synced_tasks remembers which of a meeting's action items became tasks. Items are keyed by
+-- their normalized text rather than their id, because extracting action items again gives
+-- them new ids.
+
+ALTER TABLE settings ADD COLUMN taskSyncSettings TEXT;
+
+CREATE TABLE IF NOT EXISTS synced_tasks (
+    provider TEXT NOT NULL,
+    meeting_id TEXT NOT NULL,
+    item_key TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    task_url TEXT,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (provider, meeting_id, item_key),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
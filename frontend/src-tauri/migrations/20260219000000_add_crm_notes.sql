@@ -0,0 +1,17 @@
+-- Migration: HubSpot and Salesforce notes
+-- settings.crmSettings holds the CRM connection, the email domains of the user's own
+-- organization and whether minutes are attached automatically, as JSON; NULL until a CRM is
+-- connected. crm_notes remembers the note each meeting became, so attaching the meeting
+-- again updates that note instead of adding another one.
+
+ALTER TABLE settings ADD COLUMN crmSettings TEXT;
+
+CREATE TABLE IF NOT EXISTS crm_notes (
+    provider TEXT NOT NULL,
+    meeting_id TEXT NOT NULL,
+    note_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (provider, meeting_id),
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
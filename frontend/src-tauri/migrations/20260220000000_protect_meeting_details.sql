@@ -0,0 +1,8 @@
+-- Migration: Encrypt all text of protected meetings
+-- Besides transcripts and summaries, a locked meeting now also blanks its action items,
+-- decisions, highlights, stand-up items, segment comments and transcript revisions, which are
+-- encrypted with the rest. covers_details is 1 once the ciphertext holds that text; meetings
+-- locked before keep those rows readable until they are next unlocked and locked, since
+-- blanking them without a copy in the ciphertext would lose them.
+
+ALTER TABLE protected_meetings ADD COLUMN covers_details INTEGER NOT NULL DEFAULT 0;
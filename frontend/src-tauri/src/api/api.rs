@@ -1,7 +1,7 @@
 use log::{debug as log_debug, error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
 use tauri_plugin_store::StoreExt;
 
 use crate::{
@@ -31,6 +31,10 @@ pub struct ApiResponse<T> {
 pub struct Meeting {
     pub id: String,
     pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub starred: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -121,6 +125,12 @@ pub struct DeleteMeetingRequest {
 pub struct MeetingDetails {
     pub id: String,
     pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary_language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agenda: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub transcripts: Vec<MeetingTranscript>,
@@ -138,6 +148,31 @@ pub struct MeetingTranscript {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Audio quality score of the source chunk; low values mark possibly garbled text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_score: Option<f64>,
+    // Audio source ('mic' = local user, 'system' = "Remote") when channels were transcribed separately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Diarized speaker ("Speaker 1", ...) when speaker diarization was enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_label: Option<String>,
+    // Spoken over a segment from another speaker; reviewers should double-check it
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overlapping_speech: bool,
+    // Tone analysis result, used to mark heated moments on the timeline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentiment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tension: Option<f64>,
+    // Spoken language (ISO 639-1) so multilingual meetings can be labeled per segment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    // Low confidence / poor audio / crosstalk - worth proofreading
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub needs_review: bool,
 }
 
 /// Meeting metadata without transcripts (for pagination)
@@ -189,6 +224,21 @@ pub struct TranscriptSegment {
     pub audio_end_time: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<f64>,
+    // Audio quality score of the source chunk; low values mark possibly garbled text
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_score: Option<f64>,
+    // Audio source ('mic' = local user, 'system' = "Remote") when channels were transcribed separately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Diarized speaker ("Speaker 1", ...) when speaker diarization was enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_label: Option<String>,
+    // Spoken language (ISO 639-1); identified from the text at save time when missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    // Transcription confidence reported by the engine
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -342,6 +392,8 @@ pub async fn api_get_meetings<R: Runtime>(
                 .map(|m| Meeting {
                     id: m.id,
                     title: m.title,
+                    description: m.description,
+                    starred: m.starred,
                 })
                 .collect();
             Ok(result)
@@ -748,7 +800,7 @@ pub async fn api_delete_api_key<R: Runtime>(
 
 #[tauri::command]
 pub async fn api_delete_meeting<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     meeting_id: String,
     auth_token: Option<String>,
@@ -761,12 +813,14 @@ pub async fn api_delete_meeting<R: Runtime>(
 
     let pool = state.db_manager.pool();
 
-    match MeetingsRepository::delete_meeting(pool, &meeting_id).await {
+    // Deleted meetings go to the trash; see crate::trash for restoring and purging
+    match MeetingsRepository::move_to_trash(pool, &meeting_id).await {
         Ok(true) => {
-            log_info!("Successfully deleted meeting {}", meeting_id);
+            log_info!("Moved meeting {} to the trash", meeting_id);
+            let _ = app.emit("meeting-trashed", serde_json::json!({ "meeting_id": meeting_id }));
             Ok(serde_json::json!({
                 "status": "success",
-                "message": "Meeting deleted successfully"
+                "message": "Meeting moved to trash"
             }))
         }
         Ok(false) => {
@@ -797,6 +851,7 @@ pub async fn api_get_meeting<R: Runtime>(
     );
 
     let pool = state.db_manager.pool();
+    crate::protection::ensure_unlocked(pool, &meeting_id).await?;
 
     match MeetingsRepository::get_meeting(pool, &meeting_id).await {
         Ok(Some(meeting)) => {
@@ -864,6 +919,7 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
     );
 
     let pool = state.db_manager.pool();
+    crate::protection::ensure_unlocked(pool, &meeting_id).await?;
 
     match MeetingsRepository::get_meeting_transcripts_paginated(pool, &meeting_id, limit, offset).await {
         Ok((transcripts, total_count)) => {
@@ -884,6 +940,15 @@ pub async fn api_get_meeting_transcripts<R: Runtime>(
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    quality_score: t.quality_score,
+                    speaker: t.speaker,
+                    speaker_label: t.speaker_label,
+                    overlapping_speech: t.overlapping_speech,
+                    sentiment: t.sentiment,
+                    tension: t.tension,
+                    language: t.language,
+                    confidence: t.confidence,
+                    needs_review: t.needs_review,
                 })
                 .collect::<Vec<_>>();
 
@@ -934,7 +999,7 @@ pub async fn api_save_meeting_title<R: Runtime>(
 
 #[tauri::command]
 pub async fn api_save_transcript<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     meeting_title: String,
     transcripts: Vec<serde_json::Value>,
@@ -983,7 +1048,7 @@ pub async fn api_save_transcript<R: Runtime>(
         pool,
         &meeting_title,
         &transcripts_to_save,
-        folder_path,
+        folder_path.clone(),
     )
     .await
     {
@@ -992,6 +1057,34 @@ pub async fn api_save_transcript<R: Runtime>(
                 "Successfully saved transcript and created meeting with id: {}",
                 meeting_id
             );
+            // Link diarized voices to speakers from earlier meetings
+            if let Some(folder) = &folder_path {
+                match crate::diarization::fingerprints::import_meeting_speakers(
+                    pool,
+                    &meeting_id,
+                    std::path::Path::new(folder),
+                )
+                .await
+                {
+                    Ok(renames) if !renames.is_empty() => {
+                        log_info!("Applied {} known speaker name(s) to meeting {}", renames.len(), meeting_id);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log_warn!("Failed to import speaker fingerprints: {}", e),
+                }
+            }
+            crate::bookmarks::attach_pending_moments(pool, &meeting_id, folder_path.as_deref()).await;
+            // Before templates, so one for the event's series can match
+            crate::calendar::attach_recording_event(pool, &meeting_id).await;
+            if let Err(e) = crate::meeting_templates::apply_matching(pool, &meeting_id).await {
+                log_warn!("{}", e);
+            }
+            crate::meeting_info::spawn_autofill(&app, meeting_id.clone(), true);
+            crate::integrations::spawn_meeting_event(&app, crate::integrations::MeetingEvent::MeetingFinished, meeting_id.clone());
+            crate::integrations::spawn_meeting_event(&app, crate::integrations::MeetingEvent::TranscriptReady, meeting_id.clone());
+            // Replace the default timestamp name with a generated title and description
+            crate::summary::auto_title::spawn_for_meeting(&app, meeting_id.clone());
+            crate::summary::vector_index::spawn_index_update(&app, meeting_id.clone());
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Transcript saved successfully",
@@ -1184,6 +1277,7 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
     max_tokens: Option<i32>,
     temperature: Option<f32>,
     top_p: Option<f32>,
+    context_tokens: Option<u32>,
 ) -> Result<serde_json::Value, String> {
     log_info!(
         "api_save_custom_openai_config called: endpoint='{}', model='{}'",
@@ -1220,6 +1314,11 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
             return Err("Max tokens must be at least 1".to_string());
         }
     }
+    if let Some(tokens) = context_tokens {
+        if tokens < 1024 {
+            return Err("Context window must be at least 1024 tokens".to_string());
+        }
+    }
 
     let config = CustomOpenAIConfig {
         endpoint: endpoint.trim().to_string(),
@@ -1228,6 +1327,7 @@ pub async fn api_save_custom_openai_config<R: Runtime>(
         max_tokens,
         temperature,
         top_p,
+        context_tokens,
     };
 
     let pool = state.db_manager.pool();
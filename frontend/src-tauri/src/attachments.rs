@@ -0,0 +1,284 @@
+// Meeting attachments
+// Context documents (agenda PDF, slide deck, shared files) kept with a meeting. Attaching
+// copies the file into the app data directory, under attachments/<meeting_id>/, so it
+// stays with the minutes when the original is moved or deleted.
+
+use chrono::Utc;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::database::models::MeetingAttachment;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_attachment::MeetingAttachmentsRepository;
+use crate::state::AppState;
+
+const MAX_ATTACHMENT_BYTES: u64 = 500 * 1024 * 1024;
+const MAX_FILE_NAME_CHARS: usize = 200;
+
+pub fn attachments_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("attachments")
+}
+
+/// Delete the attached files of a meeting, after the meeting itself is deleted
+pub fn remove_meeting_attachments(app_data_dir: &Path, meeting_id: &str) -> std::io::Result<()> {
+    let dir = attachments_dir(app_data_dir).join(meeting_id);
+    if dir.is_dir() {
+        std::fs::remove_dir_all(dir)?;
+    }
+    Ok(())
+}
+
+fn extension(file_name: &str) -> Option<String> {
+    Path::new(file_name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .filter(|extension| !extension.is_empty() && extension.len() <= 10)
+        .filter(|extension| extension.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Media type shown with an attachment, from its extension
+fn mime_type(file_name: &str) -> &'static str {
+    match extension(file_name).as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("doc") => "application/msword",
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        Some("ppt") => "application/vnd.ms-powerpoint",
+        Some("pptx") => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        Some("xls") => "application/vnd.ms-excel",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("key") => "application/vnd.apple.keynote",
+        Some("odt") => "application/vnd.oasis.opendocument.text",
+        Some("odp") => "application/vnd.oasis.opendocument.presentation",
+        Some("txt") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("zip") => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Name of the stored copy: the attachment id, keeping the extension so the system opens
+/// it with the right app
+fn stored_name(id: &str, file_name: &str) -> String {
+    match extension(file_name) {
+        Some(extension) => format!("{}.{}", id, extension),
+        None => id.to_string(),
+    }
+}
+
+fn validate_file_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("File name cannot be empty".to_string());
+    }
+    if name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid file name: {}", name));
+    }
+    if name.chars().count() > MAX_FILE_NAME_CHARS {
+        return Err(format!("File name must be at most {} characters", MAX_FILE_NAME_CHARS));
+    }
+    Ok(name.to_string())
+}
+
+fn app_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to locate the app data directory: {}", e))
+}
+
+async fn load_attachment<R: Runtime>(app: &AppHandle<R>, attachment_id: &str) -> Result<(MeetingAttachment, PathBuf), String> {
+    let state = app.state::<AppState>();
+    let attachment = MeetingAttachmentsRepository::get(state.db_manager.pool(), attachment_id)
+        .await
+        .map_err(|e| format!("Failed to load attachment: {}", e))?
+        .ok_or_else(|| format!("Attachment {} not found", attachment_id))?;
+    let path = attachments_dir(&app_data_dir(app)?)
+        .join(&attachment.meeting_id)
+        .join(&attachment.stored_name);
+    Ok((attachment, path))
+}
+
+/// Attach files to a meeting, copying them into the app data directory
+///
+/// # Arguments
+/// * `paths` - Files to attach, each at most 500 MB
+#[command]
+pub async fn add_meeting_attachments<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    paths: Vec<String>,
+) -> Result<Vec<MeetingAttachment>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+    let mut sources = Vec::new();
+    for path in &paths {
+        let source = PathBuf::from(path);
+        let metadata = std::fs::metadata(&source).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        if !metadata.is_file() {
+            return Err(format!("Not a file: {}", path));
+        }
+        if metadata.len() > MAX_ATTACHMENT_BYTES {
+            return Err(format!("{} is larger than {} MB", path, MAX_ATTACHMENT_BYTES / (1024 * 1024)));
+        }
+        let file_name = source
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .ok_or_else(|| format!("Invalid file name: {}", path))?;
+        sources.push((source, validate_file_name(&file_name)?));
+    }
+
+    let meeting_dir = attachments_dir(&app_data_dir(&app)?).join(&meeting_id);
+    std::fs::create_dir_all(&meeting_dir)
+        .map_err(|e| format!("Failed to create {}: {}", meeting_dir.display(), e))?;
+
+    let mut attached = Vec::new();
+    for (source, file_name) in sources {
+        let id = format!("attachment-{}", Uuid::new_v4());
+        let stored_name = stored_name(&id, &file_name);
+        let destination = meeting_dir.join(&stored_name);
+        let copy_to = destination.clone();
+        let size_bytes = tokio::task::spawn_blocking(move || std::fs::copy(&source, &copy_to))
+            .await
+            .map_err(|e| format!("Attachment task failed: {}", e))?
+            .map_err(|e| format!("Failed to copy {}: {}", file_name, e))?;
+
+        let attachment = MeetingAttachment {
+            id,
+            meeting_id: meeting_id.clone(),
+            mime_type: mime_type(&file_name).to_string(),
+            file_name,
+            stored_name,
+            size_bytes: size_bytes as i64,
+            created_at: Utc::now(),
+        };
+        if let Err(e) = MeetingAttachmentsRepository::insert(pool, &attachment).await {
+            let _ = std::fs::remove_file(&destination);
+            return Err(format!("Failed to save attachment: {}", e));
+        }
+        attached.push(attachment);
+    }
+
+    info!("Attached {} file(s) to meeting {}", attached.len(), meeting_id);
+    Ok(attached)
+}
+
+#[command]
+pub async fn list_meeting_attachments<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingAttachment>, String> {
+    let state = app.state::<AppState>();
+    MeetingAttachmentsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load attachments: {}", e))
+}
+
+/// Open an attachment with the system's default app for its type
+#[command]
+pub async fn open_meeting_attachment<R: Runtime>(app: AppHandle<R>, attachment_id: String) -> Result<(), String> {
+    let (attachment, path) = load_attachment(&app, &attachment_id).await?;
+    if !path.is_file() {
+        return Err(format!("The file of {} is missing", attachment.file_name));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::process::Command::new("explorer")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("xdg-open")
+            .arg(&path)
+            .spawn()
+            .map_err(|e| format!("Failed to open attachment: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Copy an attachment out of the app, e.g. to share it
+#[command]
+pub async fn save_meeting_attachment_as<R: Runtime>(
+    app: AppHandle<R>,
+    attachment_id: String,
+    path: String,
+) -> Result<(), String> {
+    let (_, source) = load_attachment(&app, &attachment_id).await?;
+    let destination = PathBuf::from(&path);
+    tokio::task::spawn_blocking(move || std::fs::copy(&source, &destination))
+        .await
+        .map_err(|e| format!("Attachment task failed: {}", e))?
+        .map_err(|e| format!("Failed to save attachment to {}: {}", path, e))?;
+    Ok(())
+}
+
+/// Change the name an attachment is shown under; the stored copy keeps its name
+#[command]
+pub async fn rename_meeting_attachment<R: Runtime>(
+    app: AppHandle<R>,
+    attachment_id: String,
+    file_name: String,
+) -> Result<(), String> {
+    let file_name = validate_file_name(&file_name)?;
+    let state = app.state::<AppState>();
+    let renamed = MeetingAttachmentsRepository::rename(state.db_manager.pool(), &attachment_id, &file_name)
+        .await
+        .map_err(|e| format!("Failed to rename attachment: {}", e))?;
+    if !renamed {
+        return Err(format!("Attachment {} not found", attachment_id));
+    }
+    Ok(())
+}
+
+#[command]
+pub async fn remove_meeting_attachment<R: Runtime>(app: AppHandle<R>, attachment_id: String) -> Result<(), String> {
+    let (_, path) = load_attachment(&app, &attachment_id).await?;
+    let state = app.state::<AppState>();
+    MeetingAttachmentsRepository::delete(state.db_manager.pool(), &attachment_id)
+        .await
+        .map_err(|e| format!("Failed to remove attachment: {}", e))?;
+    if let Err(e) = std::fs::remove_file(&path) {
+        warn!("Failed to delete attachment file {}: {}", path.display(), e);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_names() {
+        assert_eq!(stored_name("attachment-1", "Q3 Agenda.PDF"), "attachment-1.pdf");
+        assert_eq!(stored_name("attachment-1", "notes"), "attachment-1");
+        assert_eq!(stored_name("attachment-1", "deck.p p"), "attachment-1");
+        assert_eq!(mime_type("slides.pptx"), "application/vnd.openxmlformats-officedocument.presentationml.presentation");
+        assert_eq!(mime_type("archive.tar.gz"), "application/octet-stream");
+
+        assert_eq!(validate_file_name("  agenda.pdf "), Ok("agenda.pdf".to_string()));
+        assert!(validate_file_name("../agenda.pdf").is_err());
+        assert!(validate_file_name(" ").is_err());
+    }
+}
@@ -0,0 +1,244 @@
+// audio/acoustic_events.rs
+//
+// Non-speech acoustic events (laughter, applause, long silence) annotated into the
+// transcript timeline as "[laughter]", "[applause]" or "[silence 2m]" segments.
+// Applause and silence are detected from the mixed audio windows in the pipeline;
+// laughter is recognized from what the transcription engine makes of it ("ha ha ha",
+// "(laughs)"), which is far more reliable than guessing it from signal features.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::transcription::TranscriptUpdate;
+
+pub const LAUGHTER_ANNOTATION: &str = "[laughter]";
+/// Silence shorter than this is just a pause in the conversation
+pub const LONG_SILENCE_SECONDS: f64 = 60.0;
+/// Applause has to last this long to be told apart from a door slam or a cough
+pub const MIN_APPLAUSE_SECONDS: f64 = 1.5;
+
+// Mixed audio quieter than this (dBFS) counts as silence
+const SILENCE_DBFS: f32 = -50.0;
+// Applause is loud, noise-like (many zero crossings) and steady from frame to frame,
+// where speech has few zero crossings and strongly varying syllable energy
+const APPLAUSE_MIN_DBFS: f32 = -40.0;
+const APPLAUSE_MIN_ZCR: f32 = 0.15;
+const APPLAUSE_MAX_ENERGY_CV: f32 = 0.5;
+const FRAME_MS: u32 = 20;
+
+// Whole segment is laughter: "haha", "Ha ha ha!", "hehe", "(laughs)", "[LAUGHTER]", "*laughing*"
+static LAUGHTER_TEXT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\W*(?:(?:ha|he|hah|heh){2,}|(?:ha|he)(?:\W+(?:ha|he))+|[\[(*]\s*(?:\w+\s+)?(?:laugh\w*|chuckl\w*|giggl\w*)\s*[\])*])\W*$",
+    )
+    .unwrap()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AcousticEventKind {
+    Laughter,
+    Applause,
+    Silence,
+}
+
+/// A detected non-speech event (times in seconds from recording start)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AcousticEvent {
+    pub kind: AcousticEventKind,
+    pub start: f64,
+    pub end: f64,
+}
+
+impl AcousticEvent {
+    /// Transcript annotation, e.g. "[applause]" or "[silence 2m]"
+    pub fn annotation(&self) -> String {
+        match self.kind {
+            AcousticEventKind::Laughter => LAUGHTER_ANNOTATION.to_string(),
+            AcousticEventKind::Applause => "[applause]".to_string(),
+            AcousticEventKind::Silence => {
+                let minutes = ((self.end - self.start) / 60.0).round().max(1.0);
+                format!("[silence {}m]", minutes as u64)
+            }
+        }
+    }
+
+    /// Transcript segment carrying the annotation, sent through the regular transcript-update path
+    pub fn to_transcript_update(&self) -> TranscriptUpdate {
+        TranscriptUpdate {
+            text: self.annotation(),
+            timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+            source: "Acoustic".to_string(),
+            sequence_id: super::transcription::worker::next_sequence_id(),
+            chunk_start_time: self.start,
+            is_partial: false,
+            confidence: 1.0,
+            audio_start_time: self.start,
+            audio_end_time: self.end,
+            duration: self.end - self.start,
+            hallucination_flag: None,
+            audio_quality: None,
+            speaker: None,
+            segment_id: None,
+            speaker_label: None,
+            language: None,
+        }
+    }
+}
+
+/// True if a transcribed segment is nothing but laughter
+pub fn is_laughter_text(text: &str) -> bool {
+    LAUGHTER_TEXT.is_match(text.trim())
+}
+
+/// (RMS in dBFS, zero-crossing rate, coefficient of variation of per-frame RMS)
+fn window_features(samples: &[f32], sample_rate: u32) -> (f32, f32, f32) {
+    let rms = (samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32).sqrt();
+    let rms_dbfs = 20.0 * rms.max(1e-10).log10();
+
+    let crossings = samples
+        .windows(2)
+        .filter(|pair| (pair[0] >= 0.0) != (pair[1] >= 0.0))
+        .count();
+    let zcr = crossings as f32 / samples.len() as f32;
+
+    let frame_size = ((sample_rate * FRAME_MS / 1000) as usize).max(1);
+    let frame_rms: Vec<f32> = samples
+        .chunks(frame_size)
+        .map(|frame| (frame.iter().map(|&x| x * x).sum::<f32>() / frame.len() as f32).sqrt())
+        .collect();
+    let mean = frame_rms.iter().sum::<f32>() / frame_rms.len() as f32;
+    let variance = frame_rms.iter().map(|r| (r - mean).powi(2)).sum::<f32>() / frame_rms.len() as f32;
+    let energy_cv = if mean > 0.0 { variance.sqrt() / mean } else { 0.0 };
+
+    (rms_dbfs, zcr, energy_cv)
+}
+
+/// Tracks runs of silent and applause-like mixed audio windows and reports an event
+/// when a run long enough to be worth annotating ends
+#[derive(Debug, Default)]
+pub struct AcousticEventDetector {
+    // (run start, run end) of the current silent / applause-like stretch
+    silence_run: Option<(f64, f64)>,
+    applause_run: Option<(f64, f64)>,
+}
+
+impl AcousticEventDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one window of mixed audio starting at `start_time` (seconds from recording start).
+    /// `speech_active` is the VAD's view, so quiet speech is never mistaken for silence.
+    pub fn process_window(
+        &mut self,
+        samples: &[f32],
+        sample_rate: u32,
+        start_time: f64,
+        speech_active: bool,
+    ) -> Vec<AcousticEvent> {
+        if samples.is_empty() {
+            return Vec::new();
+        }
+        let end_time = start_time + samples.len() as f64 / sample_rate as f64;
+        let (rms_dbfs, zcr, energy_cv) = window_features(samples, sample_rate);
+
+        let silent = !speech_active && rms_dbfs < SILENCE_DBFS;
+        let applause_like =
+            rms_dbfs >= APPLAUSE_MIN_DBFS && zcr >= APPLAUSE_MIN_ZCR && energy_cv <= APPLAUSE_MAX_ENERGY_CV;
+
+        let mut events = Vec::new();
+        events.extend(Self::advance(&mut self.silence_run, silent, start_time, end_time, AcousticEventKind::Silence));
+        events.extend(Self::advance(&mut self.applause_run, applause_like, start_time, end_time, AcousticEventKind::Applause));
+        events
+    }
+
+    /// Close the open applause run at the end of the recording. Trailing silence isn't
+    /// reported - it's usually just the time before someone pressed stop.
+    pub fn finish(&mut self) -> Option<AcousticEvent> {
+        self.silence_run = None;
+        self.applause_run
+            .take()
+            .and_then(|(start, end)| Self::event_if_long_enough(AcousticEventKind::Applause, start, end))
+    }
+
+    fn advance(
+        run: &mut Option<(f64, f64)>,
+        active: bool,
+        start_time: f64,
+        end_time: f64,
+        kind: AcousticEventKind,
+    ) -> Option<AcousticEvent> {
+        if active {
+            *run = Some(match *run {
+                Some((run_start, _)) => (run_start, end_time),
+                None => (start_time, end_time),
+            });
+            return None;
+        }
+        run.take()
+            .and_then(|(run_start, run_end)| Self::event_if_long_enough(kind, run_start, run_end))
+    }
+
+    fn event_if_long_enough(kind: AcousticEventKind, start: f64, end: f64) -> Option<AcousticEvent> {
+        let min_seconds = match kind {
+            AcousticEventKind::Silence => LONG_SILENCE_SECONDS,
+            AcousticEventKind::Applause => MIN_APPLAUSE_SECONDS,
+            AcousticEventKind::Laughter => 0.0,
+        };
+        if end - start >= min_seconds {
+            Some(AcousticEvent { kind, start, end })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 16000;
+
+    fn window(generator: impl Fn(usize) -> f32) -> Vec<f32> {
+        (0..RATE as usize / 2).map(generator).collect()
+    }
+
+    #[test]
+    fn test_laughter_text() {
+        assert!(is_laughter_text("Hahaha!"));
+        assert!(is_laughter_text("ha ha ha"));
+        assert!(is_laughter_text("(audience laughs)"));
+        assert!(is_laughter_text("[LAUGHTER]"));
+        assert!(!is_laughter_text("ha"));
+        assert!(!is_laughter_text("Ha, that's a good point"));
+    }
+
+    #[test]
+    fn test_detects_long_silence_and_applause() {
+        let mut detector = AcousticEventDetector::new();
+        let quiet = window(|_| 0.0001);
+        // Dense alternating-sign noise: loud, many zero crossings, steady energy
+        let clapping = window(|i| if (i * 7919) % 3 == 0 { 0.3 } else { -0.25 });
+        let speech = window(|i| (i as f32 * 0.02).sin() * 0.3 * ((i / 1600) % 2) as f32);
+
+        let mut time = 0.0;
+        let mut events = Vec::new();
+        for _ in 0..130 {
+            events.extend(detector.process_window(&quiet, RATE, time, false));
+            time += 0.5;
+        }
+        for _ in 0..4 {
+            events.extend(detector.process_window(&clapping, RATE, time, false));
+            time += 0.5;
+        }
+        events.extend(detector.process_window(&speech, RATE, time, true));
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, AcousticEventKind::Silence);
+        assert_eq!(events[0].annotation(), "[silence 1m]");
+        assert_eq!(events[1], AcousticEvent { kind: AcousticEventKind::Applause, start: 65.0, end: 67.0 });
+        assert!(detector.finish().is_none());
+    }
+}
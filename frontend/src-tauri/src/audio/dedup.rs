@@ -0,0 +1,339 @@
+// Deduplicated audio storage
+// The same recording imported twice, or a meeting recreated from its audio, would otherwise
+// keep two full copies. Recordings are hashed (SHA-256) and kept once, as a blob in an
+// .audio-store folder beside the meeting folders; each meeting's audio.mp4 becomes a hard
+// link to that blob, so everything reading the meeting folder works as before. On storage
+// without hard links (FAT/exFAT drives) recordings stay separate copies.
+//
+// Deleting a meeting folder only removes its link; blobs no longer linked from any
+// recording are removed by garbage collection, which runs after the trash is purged and
+// after retention deletes recordings.
+
+use chrono::Utc;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::AudioBlob;
+use crate::database::repositories::audio_blob::AudioBlobsRepository;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::state::AppState;
+
+const STORE_DIR: &str = ".audio-store";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupOutcome {
+    /// Now a link to an existing blob with the same content
+    Shared,
+    /// Kept as the first blob with this content
+    Stored,
+    /// Already a link to the blob with its content
+    AlreadyShared,
+    /// The storage doesn't support hard links; left as a separate copy
+    Unsupported,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DedupReport {
+    pub recordings: usize,
+    /// Recordings turned into links to an existing copy
+    pub shared: usize,
+    pub saved_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GarbageReport {
+    pub removed_blobs: usize,
+    pub freed_bytes: u64,
+}
+
+/// Store folder for recordings in `file`'s meeting folder: beside the meeting folders,
+/// so on the same volume
+fn store_dir(file: &Path) -> Option<PathBuf> {
+    Some(file.parent()?.parent()?.join(STORE_DIR))
+}
+
+fn blob_path(store: &Path, hash: &str, extension: &str) -> PathBuf {
+    store.join(&hash[..2]).join(format!("{}.{}", hash, extension))
+}
+
+/// SHA-256 (hex) and size of a file
+fn hash_file(path: &Path) -> std::io::Result<(String, u64)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0u8; 1024 * 1024];
+    let mut size = 0u64;
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+    let hash = hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok((hash, size))
+}
+
+/// Swap `file` for a hard link to `blob`; `file` is untouched when that fails
+fn replace_with_link(blob: &Path, file: &Path) -> std::io::Result<()> {
+    let mut staged = file.as_os_str().to_owned();
+    staged.push(".dedup");
+    let staged = PathBuf::from(staged);
+    let _ = std::fs::remove_file(&staged);
+    std::fs::hard_link(blob, &staged)?;
+    std::fs::rename(&staged, file).inspect_err(|_| {
+        let _ = std::fs::remove_file(&staged);
+    })
+}
+
+/// Whether `a` and `b` are links to the same data
+#[cfg(unix)]
+fn same_file(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    let (Ok(a), Ok(b)) = (std::fs::metadata(a), std::fs::metadata(b)) else {
+        return false;
+    };
+    a.dev() == b.dev() && a.ino() == b.ino()
+}
+
+/// Whether `a` and `b` are links to the same data: the same volume serial number and
+/// file index
+#[cfg(windows)]
+fn same_file(a: &Path, b: &Path) -> bool {
+    fn file_id(path: &Path) -> Option<(u32, u32, u32)> {
+        use std::os::windows::io::AsRawHandle;
+        use windows_sys::Win32::Storage::FileSystem::{GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION};
+
+        let file = std::fs::File::open(path).ok()?;
+        // SAFETY: the handle stays open for the call, and the struct is plain data the
+        // call fills in
+        let mut info: BY_HANDLE_FILE_INFORMATION = unsafe { std::mem::zeroed() };
+        if unsafe { GetFileInformationByHandle(file.as_raw_handle() as _, &mut info) } == 0 {
+            return None;
+        }
+        Some((info.dwVolumeSerialNumber, info.nFileIndexHigh, info.nFileIndexLow))
+    }
+    matches!((file_id(a), file_id(b)), (Some(a), Some(b)) if a == b)
+}
+
+async fn blocking<T: Send + 'static>(task: impl FnOnce() -> std::io::Result<T> + Send + 'static) -> Result<T, String> {
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|e| format!("Audio storage task failed: {}", e))?
+        .map_err(|e| e.to_string())
+}
+
+/// Keep one copy of a recording's content: link it to a blob with the same hash, or make it
+/// the blob others will link to
+pub async fn deduplicate_file(pool: &SqlitePool, file: &Path) -> Result<DedupOutcome, String> {
+    let store = store_dir(file).ok_or_else(|| format!("{} is not in a meeting folder", file.display()))?;
+    let key = file.to_string_lossy().to_string();
+    let source = file.to_path_buf();
+    let (hash, size) = blocking(move || hash_file(&source)).await?;
+
+    let linked = AudioBlobsRepository::linked_blob(pool, &key)
+        .await
+        .map_err(|e| format!("Failed to load audio storage: {}", e))?;
+    if linked.is_some_and(|blob| blob.hash == hash && same_file(Path::new(&blob.path), file)) {
+        return Ok(DedupOutcome::AlreadyShared);
+    }
+
+    let candidates = AudioBlobsRepository::find_by_hash(pool, &hash)
+        .await
+        .map_err(|e| format!("Failed to load audio storage: {}", e))?;
+    for blob in candidates {
+        let blob_file = PathBuf::from(&blob.path);
+        if !blob_file.is_file() {
+            continue;
+        }
+        let target = file.to_path_buf();
+        // Blobs on another volume can't be linked to
+        if blocking(move || replace_with_link(&blob_file, &target)).await.is_ok() {
+            AudioBlobsRepository::add_link(pool, &key, &blob.path)
+                .await
+                .map_err(|e| format!("Failed to save audio storage: {}", e))?;
+            return Ok(DedupOutcome::Shared);
+        }
+    }
+
+    let extension = file
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_else(|| "bin".to_string());
+    let blob_file = blob_path(&store, &hash, &extension);
+    let (source, destination) = (file.to_path_buf(), blob_file.clone());
+    let linked = blocking(move || {
+        std::fs::create_dir_all(destination.parent().unwrap_or(&destination))?;
+        if destination.exists() {
+            std::fs::remove_file(&destination)?;
+        }
+        std::fs::hard_link(&source, &destination)
+    })
+    .await;
+    if let Err(e) = linked {
+        warn!("Keeping {} as a separate copy: {}", file.display(), e);
+        return Ok(DedupOutcome::Unsupported);
+    }
+
+    let blob = AudioBlob {
+        path: blob_file.to_string_lossy().to_string(),
+        hash,
+        size_bytes: size as i64,
+        created_at: Utc::now(),
+    };
+    AudioBlobsRepository::insert(pool, &blob)
+        .await
+        .map_err(|e| format!("Failed to save audio storage: {}", e))?;
+    AudioBlobsRepository::add_link(pool, &key, &blob.path)
+        .await
+        .map_err(|e| format!("Failed to save audio storage: {}", e))?;
+    Ok(DedupOutcome::Stored)
+}
+
+/// Delete a recording that links to a blob, so garbage collection can free the blob once
+/// nothing else links to it; recordings kept as separate copies are left alone
+pub async fn remove_linked_recording(pool: &SqlitePool, file: &Path) -> Result<(), String> {
+    let linked = AudioBlobsRepository::linked_blob(pool, &file.to_string_lossy())
+        .await
+        .map_err(|e| format!("Failed to load audio storage: {}", e))?;
+    if linked.is_some_and(|blob| same_file(Path::new(&blob.path), file)) {
+        std::fs::remove_file(file).map_err(|e| format!("Failed to delete {}: {}", file.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Remove blobs no recording links to any more
+pub async fn collect_garbage(pool: &SqlitePool) -> Result<GarbageReport, String> {
+    let links = AudioBlobsRepository::list_links(pool)
+        .await
+        .map_err(|e| format!("Failed to load audio storage: {}", e))?;
+    let mut linked = std::collections::HashSet::new();
+    for (path, blob_path) in links {
+        // Deleted with its meeting, or replaced by another recording
+        if same_file(Path::new(&path), Path::new(&blob_path)) {
+            linked.insert(blob_path);
+        } else {
+            AudioBlobsRepository::remove_link(pool, &path)
+                .await
+                .map_err(|e| format!("Failed to update audio storage: {}", e))?;
+        }
+    }
+
+    let mut report = GarbageReport::default();
+    let blobs = AudioBlobsRepository::list(pool)
+        .await
+        .map_err(|e| format!("Failed to load audio storage: {}", e))?;
+    for blob in blobs.into_iter().filter(|blob| !linked.contains(&blob.path)) {
+        let path = PathBuf::from(&blob.path);
+        match std::fs::remove_file(&path) {
+            Ok(()) => {
+                report.removed_blobs += 1;
+                report.freed_bytes += blob.size_bytes.max(0) as u64;
+                if let Some(parent) = path.parent() {
+                    // Only succeeds once the prefix folder is empty
+                    let _ = std::fs::remove_dir(parent);
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => {
+                warn!("Failed to remove unused audio blob {}: {}", path.display(), e);
+                continue;
+            }
+        }
+        AudioBlobsRepository::delete(pool, &blob.path)
+            .await
+            .map_err(|e| format!("Failed to update audio storage: {}", e))?;
+    }
+    Ok(report)
+}
+
+/// Deduplicate the recordings of all meetings, e.g. after importing the same audio twice
+#[command]
+pub async fn deduplicate_recordings<R: Runtime>(app: AppHandle<R>) -> Result<DedupReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+
+    let mut report = DedupReport::default();
+    for meeting in meetings {
+        let Some(file) = meeting
+            .folder_path
+            .map(|folder| Path::new(&folder).join("audio.mp4"))
+            .filter(|file| file.is_file())
+        else {
+            continue;
+        };
+        report.recordings += 1;
+        let size = std::fs::metadata(&file).map(|metadata| metadata.len()).unwrap_or(0);
+        match deduplicate_file(pool, &file).await {
+            Ok(DedupOutcome::Shared) => {
+                report.shared += 1;
+                report.saved_bytes += size;
+            }
+            Ok(_) => {}
+            Err(e) => report.errors.push(format!("{}: {}", meeting.title, e)),
+        }
+    }
+    info!(
+        "Deduplicated {} recording(s): {} shared, {} bytes saved",
+        report.recordings, report.shared, report.saved_bytes
+    );
+    Ok(report)
+}
+
+/// Delete stored audio no meeting uses any more
+#[command]
+pub async fn collect_audio_garbage<R: Runtime>(app: AppHandle<R>) -> Result<GarbageReport, String> {
+    let state = app.state::<AppState>();
+    let report = collect_garbage(state.db_manager.pool()).await?;
+    info!(
+        "Removed {} unused audio blob(s), freeing {} bytes",
+        report.removed_blobs, report.freed_bytes
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_recordings_to_one_copy() {
+        let root = std::env::temp_dir().join(format!("meeting-audio-dedup-{}", std::process::id()));
+        let (first, second) = (root.join("Standup").join("audio.mp4"), root.join("Standup copy").join("audio.mp4"));
+        for file in [&first, &second] {
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(file, b"same audio").unwrap();
+        }
+
+        let (hash, size) = hash_file(&first).unwrap();
+        assert_eq!(size, 10);
+        assert_eq!(hash_file(&second).unwrap().0, hash);
+        let store = store_dir(&first).unwrap();
+        assert_eq!(store, root.join(STORE_DIR));
+        let blob = blob_path(&store, &hash, "mp4");
+        assert_eq!(blob, store.join(&hash[..2]).join(format!("{}.mp4", hash)));
+
+        std::fs::create_dir_all(blob.parent().unwrap()).unwrap();
+        std::fs::hard_link(&first, &blob).unwrap();
+        assert!(!same_file(&second, &blob));
+        replace_with_link(&blob, &second).unwrap();
+        assert!(same_file(&second, &blob));
+        assert_eq!(std::fs::read(&second).unwrap(), b"same audio");
+
+        // Removing a meeting's recording leaves the others readable
+        std::fs::remove_file(&first).unwrap();
+        std::fs::remove_file(&blob).unwrap();
+        assert_eq!(std::fs::read(&second).unwrap(), b"same audio");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,6 +1,7 @@
 use super::ffmpeg::find_ffmpeg_path; // Correct path to encode module
 use super::AudioDevice;
 use std::io::Write;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use std::{
     path::PathBuf,
@@ -8,6 +9,23 @@ use std::{
 };
 use tracing::{debug, error};
 
+/// AAC bitrate of saved recordings, in kbit/s
+pub const DEFAULT_BITRATE_KBPS: u32 = 192;
+/// Bitrate used when the recordings folder is low on space: about a third of the size,
+/// still clear for speech
+pub const LOW_SPACE_BITRATE_KBPS: u32 = 64;
+
+static BITRATE_KBPS: AtomicU32 = AtomicU32::new(DEFAULT_BITRATE_KBPS);
+
+/// Set the bitrate recordings are encoded at from now on
+pub fn set_recording_bitrate(kbps: u32) {
+    BITRATE_KBPS.store(kbps, Ordering::SeqCst);
+}
+
+pub fn recording_bitrate() -> u32 {
+    BITRATE_KBPS.load(Ordering::SeqCst)
+}
+
 pub struct AudioInput {
     pub data: Arc<Vec<f32>>,
     pub sample_rate: u32,
@@ -33,6 +51,7 @@ pub fn encode_single_audio(
 
     debug!("Using FFmpeg at: {:?}", ffmpeg_path);
 
+    let bitrate = format!("{}k", recording_bitrate());
     let mut command = Command::new(ffmpeg_path);
     command
         .args([
@@ -47,7 +66,7 @@ pub fn encode_single_audio(
             "-c:a",
             "aac",
             "-b:a",
-            "192k", // Increased from 64k for better audio quality (especially for speech)
+            &bitrate,
             "-profile:a",
             "aac_low", // Use AAC-LC profile for better compatibility
             "-movflags",
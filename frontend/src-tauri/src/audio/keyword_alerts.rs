@@ -0,0 +1,188 @@
+// audio/keyword_alerts.rs
+//
+// Real-time keyword alerts. Users register watch keywords ("budget", their own name,
+// a client name) and every finalized live transcript segment is checked against them,
+// emitting a `keyword-alert` event so a half-listening user can tune back in.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_store::StoreExt;
+
+use super::transcription::TranscriptUpdate;
+
+const KEYWORDS_STORE: &str = "keyword_alerts.json";
+/// Don't re-alert on the same keyword while it keeps coming up in the discussion
+pub const ALERT_COOLDOWN_SECONDS: f64 = 60.0;
+
+static MATCHER: Lazy<Mutex<KeywordMatcher>> = Lazy::new(|| Mutex::new(KeywordMatcher::default()));
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeywordAlert {
+    pub keywords: Vec<String>,
+    pub text: String,
+    pub sequence_id: u64,
+    pub audio_start_time: f64,
+    pub speaker: Option<String>,
+}
+
+/// Case-insensitive whole-word matcher over the registered keywords
+#[derive(Default)]
+pub struct KeywordMatcher {
+    patterns: Vec<(String, Regex)>,
+    // keyword -> recording time of its last alert
+    last_alert: HashMap<String, f64>,
+}
+
+impl KeywordMatcher {
+    pub fn new(keywords: &[String]) -> Self {
+        let patterns = keywords
+            .iter()
+            .filter_map(|keyword| {
+                let pattern = format!(r"(?i)\b{}\b", regex::escape(keyword));
+                match Regex::new(&pattern) {
+                    Ok(regex) => Some((keyword.clone(), regex)),
+                    Err(e) => {
+                        warn!("Skipping watch keyword '{}': {}", keyword, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self {
+            patterns,
+            last_alert: HashMap::new(),
+        }
+    }
+
+    /// Keywords found in `text` that aren't cooling down at `audio_time`
+    pub fn check(&mut self, text: &str, audio_time: f64) -> Vec<String> {
+        let mut matched = Vec::new();
+        for (keyword, regex) in &self.patterns {
+            if !regex.is_match(text) {
+                continue;
+            }
+            let cooling_down = self
+                .last_alert
+                .get(keyword)
+                .is_some_and(|last| audio_time - last < ALERT_COOLDOWN_SECONDS);
+            if !cooling_down {
+                self.last_alert.insert(keyword.clone(), audio_time);
+                matched.push(keyword.clone());
+            }
+        }
+        matched
+    }
+}
+
+/// Trim, drop empties and case-insensitive duplicates, keeping the first spelling
+fn normalize_keywords(keywords: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for keyword in keywords {
+        let keyword = keyword.trim().to_string();
+        if keyword.is_empty() || normalized.iter().any(|k| k.eq_ignore_ascii_case(&keyword)) {
+            continue;
+        }
+        normalized.push(keyword);
+    }
+    normalized
+}
+
+pub fn load_watch_keywords<R: Runtime>(app: &AppHandle<R>) -> Vec<String> {
+    let store = match app.store(KEYWORDS_STORE) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!("Failed to access keyword store: {}", e);
+            return Vec::new();
+        }
+    };
+    store
+        .get("keywords")
+        .and_then(|value| serde_json::from_value::<Vec<String>>(value).ok())
+        .unwrap_or_default()
+}
+
+/// Reset the matcher with the stored keywords (called when a recording starts)
+pub fn reload_matcher<R: Runtime>(app: &AppHandle<R>) {
+    let keywords = load_watch_keywords(app);
+    if !keywords.is_empty() {
+        info!("🔔 Watching live transcript for {} keyword(s)", keywords.len());
+    }
+    *MATCHER.lock().unwrap() = KeywordMatcher::new(&keywords);
+}
+
+/// Check a finalized live segment and emit `keyword-alert` if it mentions a watch keyword
+pub fn check_transcript_update<R: Runtime>(app: &AppHandle<R>, update: &TranscriptUpdate) {
+    if update.is_partial {
+        return;
+    }
+    let keywords = match MATCHER.lock() {
+        Ok(mut matcher) => matcher.check(&update.text, update.audio_start_time),
+        Err(_) => return,
+    };
+    if keywords.is_empty() {
+        return;
+    }
+
+    info!("🔔 Keyword alert: {:?} at {:.1}s", keywords, update.audio_start_time);
+    let alert = KeywordAlert {
+        keywords,
+        text: update.text.clone(),
+        sequence_id: update.sequence_id,
+        audio_start_time: update.audio_start_time,
+        speaker: update.speaker_label.clone().or_else(|| update.speaker.clone()),
+    };
+    let _ = app.emit("keyword-alert", alert);
+}
+
+#[tauri::command]
+pub async fn get_watch_keywords<R: Runtime>(app: AppHandle<R>) -> Result<Vec<String>, String> {
+    Ok(load_watch_keywords(&app))
+}
+
+/// Replace the watch keywords; takes effect immediately, including mid-recording
+#[tauri::command]
+pub async fn set_watch_keywords<R: Runtime>(
+    app: AppHandle<R>,
+    keywords: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let keywords = normalize_keywords(keywords);
+    let store = app
+        .store(KEYWORDS_STORE)
+        .map_err(|e| format!("Failed to access keyword store: {}", e))?;
+    store.set("keywords", serde_json::json!(keywords));
+    store
+        .save()
+        .map_err(|e| format!("Failed to save watch keywords: {}", e))?;
+
+    *MATCHER.lock().unwrap() = KeywordMatcher::new(&keywords);
+    info!("🔔 Updated watch keywords ({} total)", keywords.len());
+    Ok(keywords)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_whole_words_with_cooldown() {
+        let mut matcher = KeywordMatcher::new(&normalize_keywords(vec![
+            " Budget ".to_string(),
+            "budget".to_string(),
+            "Acme Corp".to_string(),
+            "".to_string(),
+        ]));
+
+        assert_eq!(matcher.check("We need to revisit the BUDGET.", 10.0), vec!["Budget"]);
+        assert!(matcher.check("Budget again", 30.0).is_empty());
+        assert!(matcher.check("The budgeting tool is fine", 75.0).is_empty());
+        assert_eq!(
+            matcher.check("acme corp wants a budget update", 80.0),
+            vec!["Budget", "Acme Corp"]
+        );
+    }
+}
@@ -34,6 +34,11 @@ pub mod system_detector;
 pub mod system_audio_commands;
 pub mod device_monitor;  // NEW: Device disconnect/reconnect monitoring
 pub mod playback_monitor; // NEW: Playback device detection for BT warnings
+pub mod quality;
+pub mod keyword_alerts;
+pub mod acoustic_events;
+pub mod playback;
+pub mod dedup;
 
 // Transcription module (provider abstraction, engine management, worker pool)
 pub mod transcription;
@@ -76,7 +81,7 @@ pub use recording_commands::{
     is_recording, get_transcription_status, RecordingArgs, TranscriptionStatus, TranscriptUpdate
 };
 pub use recording_preferences::{
-    RecordingPreferences, get_default_recordings_folder
+    RecordingPreferences, TranscriptionPipelineOptions, get_default_recordings_folder
 };
 pub use recording_saver::RecordingSaver;
 pub use level_monitor::{AudioLevelMonitor, AudioLevelData, AudioLevelUpdate};
@@ -99,4 +104,5 @@ pub use diagnostics::{
 pub use ffmpeg_mixer::{FFmpegAudioMixer, BufferStats, RNNOISE_APPLY_ENABLED};
 
 pub use vad::{extract_speech_16k};
+pub use quality::{ChunkQuality, QualityLevel};
 
@@ -9,9 +9,15 @@ use super::batch_processor::AudioMetricsBatcher;
 use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction};
 
 use super::devices::AudioDevice;
-use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
+use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType, LiveSegment};
 use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
-use super::vad::{ContinuousVadProcessor};
+use super::vad::{ContinuousVadProcessor, SpeechSegment};
+use super::acoustic_events::AcousticEventDetector;
+
+// Live partial transcripts: snapshot in-progress speech at most this often...
+const PARTIAL_INTERVAL_MS: u128 = 1500;
+// ...and only once at least 1s of speech (16kHz) has accumulated
+const MIN_PARTIAL_SAMPLES: usize = 16000;
 
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
@@ -613,6 +619,7 @@ impl AudioCapture {
             timestamp,
             chunk_id,
             device_type: self.device_type.clone(),
+            live_segment: None,
         };
 
         // NOTE: Raw audio is NOT sent to recording saver to prevent echo
@@ -682,8 +689,17 @@ pub struct AudioPipeline {
     transcription_sender: mpsc::UnboundedSender<AudioChunk>,
     state: Arc<RecordingState>,
     vad_processor: ContinuousVadProcessor,
+    // Second VAD for system audio when mic/system are transcribed separately (None = mixed)
+    system_vad_processor: Option<ContinuousVadProcessor>,
     sample_rate: u32,
     chunk_id_counter: u64,
+    // Live caption segments: next ID and the segment currently open per source (mic/mixed, system)
+    next_live_segment_id: u64,
+    open_live_segments: [Option<u64>; 2],
+    last_partial_at: std::time::Instant,
+    // Forced flush: longest in-progress speech may wait before being sent (None = never forced)
+    max_segment_duration: Option<std::time::Duration>,
+    last_segment_sent_at: std::time::Instant,
     // Performance optimization: reduce logging frequency
     last_summary_time: std::time::Instant,
     processed_chunks: u64,
@@ -694,6 +710,9 @@ pub struct AudioPipeline {
     mixer: ProfessionalAudioMixer,
     // Recording sender for pre-mixed audio
     recording_sender_for_mixed: Option<mpsc::UnboundedSender<AudioChunk>>,
+    // Applause / long silence detection on the mixed audio, timed by mixed samples seen so far
+    acoustic_detector: AcousticEventDetector,
+    mixed_samples_processed: u64,
 }
 
 impl AudioPipeline {
@@ -749,8 +768,14 @@ impl AudioPipeline {
             transcription_sender,
             state,
             vad_processor,
+            system_vad_processor: None,
             sample_rate,
             chunk_id_counter: 0,
+            next_live_segment_id: 0,
+            open_live_segments: [None, None],
+            last_partial_at: std::time::Instant::now(),
+            max_segment_duration: None,
+            last_segment_sent_at: std::time::Instant::now(),
             // Performance optimization: reduce logging frequency
             last_summary_time: std::time::Instant::now(),
             processed_chunks: 0,
@@ -760,6 +785,8 @@ impl AudioPipeline {
             ring_buffer,
             mixer,
             recording_sender_for_mixed: None,  // Will be set by manager
+            acoustic_detector: AcousticEventDetector::new(),
+            mixed_samples_processed: 0,
         }
     }
 
@@ -831,40 +858,37 @@ impl AudioPipeline {
                             // Previous 2x gain was causing excessive limiting/distortion
                             let mixed_with_gain = mixed_clean;
 
-                            // STEP 3: Send mixed audio for transcription (VAD + Whisper)
-                            match self.vad_processor.process_audio(&mixed_with_gain) {
-                                Ok(speech_segments) => {
-                                    for segment in speech_segments {
-                                        let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
-
-                                        if segment.samples.len() >= 800 {  // Minimum 50ms at 16kHz - matches Parakeet capability
-                                            info!("📤 Sending VAD segment: {:.1}ms, {} samples",
-                                                  duration_ms, segment.samples.len());
-
-                                            let transcription_chunk = AudioChunk {
-                                                data: segment.samples,
-                                                sample_rate: 16000,
-                                                timestamp: segment.start_timestamp_ms / 1000.0,
-                                                chunk_id: self.chunk_id_counter,
-                                                device_type: DeviceType::Microphone,  // Mixed audio
-                                            };
-
-                                            if let Err(e) = self.transcription_sender.send(transcription_chunk) {
-                                                warn!("Failed to send VAD segment: {}", e);
-                                            } else {
-                                                self.chunk_id_counter += 1;
-                                            }
-                                        } else {
-                                            debug!("⏭️ Dropping short VAD segment: {:.1}ms ({} samples < 800)",
-                                                   duration_ms, segment.samples.len());
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    warn!("⚠️ VAD error: {}", e);
+                            // STEP 3: Send audio for transcription (VAD + Whisper)
+                            // Split-channel mode runs a VAD per source so every segment keeps its speaker
+                            let vad_results = match self.system_vad_processor.as_mut() {
+                                Some(system_vad) => vec![
+                                    (self.vad_processor.process_audio(&mic_window), DeviceType::Microphone),
+                                    (system_vad.process_audio(&sys_window), DeviceType::System),
+                                ],
+                                None => vec![
+                                    (self.vad_processor.process_audio(&mixed_with_gain), DeviceType::Microphone), // Mixed audio
+                                ],
+                            };
+
+                            for (result, device_type) in vad_results {
+                                match result {
+                                    Ok(speech_segments) => self.send_speech_segments(speech_segments, device_type),
+                                    Err(e) => warn!("⚠️ VAD error: {}", e),
                                 }
                             }
 
+                            // Interim snapshots of still-open speech for live captions
+                            self.send_partial_snapshots();
+
+                            // Annotate applause and long silences in the transcript timeline
+                            let window_start = self.mixed_samples_processed as f64 / self.sample_rate as f64;
+                            self.mixed_samples_processed += mixed_with_gain.len() as u64;
+                            let speech_active = self.vad_processor.is_in_speech()
+                                || self.system_vad_processor.as_ref().is_some_and(|vad| vad.is_in_speech());
+                            for event in self.acoustic_detector.process_window(&mixed_with_gain, self.sample_rate, window_start, speech_active) {
+                                self.state.report_acoustic_event(&event);
+                            }
+
                             // STEP 4: Send mixed audio for recording (WAV file)
                             if let Some(ref sender) = self.recording_sender_for_mixed {
                                 let recording_chunk = AudioChunk {
@@ -873,18 +897,22 @@ impl AudioPipeline {
                                     timestamp: chunk.timestamp,
                                     chunk_id: self.chunk_id_counter,
                                     device_type: DeviceType::Microphone,  // Mixed audio
+                                    live_segment: None,
                                 };
                                 let _ = sender.send(recording_chunk);
                             }
                         }
                     }
+
+                    self.check_forced_flush();
                 }
                 Ok(None) => {
                     info!("Audio pipeline: sender closed after processing {} chunks", self.processed_chunks);
                     break;
                 }
                 Err(_) => {
-                    // Timeout - just continue, VAD handles all segmentation
+                    // Timeout - VAD handles segmentation, but stalled speech must not wait forever
+                    self.check_forced_flush();
                     continue;
                 }
             }
@@ -900,50 +928,176 @@ impl AudioPipeline {
     fn flush_remaining_audio(&mut self) -> Result<()> {
         info!("Flushing remaining audio from pipeline (processed {} chunks)", self.processed_chunks);
 
-        // Flush any remaining audio from VAD processor and send segments to transcription
+        // Flush any remaining audio from the VAD processor(s) and send segments to transcription
         match self.vad_processor.flush() {
-            Ok(final_segments) => {
-                for segment in final_segments {
-                    let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
-
-                    // Send segments >= 50ms (800 samples at 16kHz) - matches main pipeline filter
-                    if segment.samples.len() >= 800 {
-                        info!("📤 Sending final VAD segment to Whisper: {:.1}ms duration, {} samples",
-                              duration_ms, segment.samples.len());
-
-                        let transcription_chunk = AudioChunk {
-                            data: segment.samples,
-                            sample_rate: 16000,
-                            timestamp: segment.start_timestamp_ms / 1000.0,
-                            chunk_id: self.chunk_id_counter,
-                            device_type: DeviceType::Microphone,
-                        };
-
-                        if let Err(e) = self.transcription_sender.send(transcription_chunk) {
-                            warn!("Failed to send final VAD segment: {}", e);
-                        } else {
-                            self.chunk_id_counter += 1;
-                        }
-                    } else {
-                        info!("⏭️ Skipping short final segment: {:.1}ms ({} samples < 800)",
-                              duration_ms, segment.samples.len());
-                    }
+            Ok(final_segments) => self.send_speech_segments(final_segments, DeviceType::Microphone),
+            Err(e) => warn!("Failed to flush VAD processor: {}", e),
+        }
+
+        let system_flush = self.system_vad_processor.as_mut().map(|vad| vad.flush());
+        match system_flush {
+            Some(Ok(final_segments)) => self.send_speech_segments(final_segments, DeviceType::System),
+            Some(Err(e)) => warn!("Failed to flush system VAD processor: {}", e),
+            None => {}
+        }
+
+        if let Some(event) = self.acoustic_detector.finish() {
+            self.state.report_acoustic_event(&event);
+        }
+
+        Ok(())
+    }
+
+    /// Send VAD speech segments to transcription, tagged with the source they came from
+    fn send_speech_segments(&mut self, segments: Vec<SpeechSegment>, device_type: DeviceType) {
+        for segment in segments {
+            let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
+
+            if segment.samples.len() >= 800 {  // Minimum 50ms at 16kHz - matches Parakeet capability
+                info!("📤 Sending VAD segment ({:?}): {:.1}ms, {} samples",
+                      device_type, duration_ms, segment.samples.len());
+
+                // Close the live segment so the final text replaces its interim captions
+                let live_segment_id = self
+                    .open_live_segments[Self::live_slot(&device_type)]
+                    .take()
+                    .unwrap_or_else(|| self.allocate_live_segment_id());
+
+                let transcription_chunk = AudioChunk {
+                    data: segment.samples,
+                    sample_rate: 16000,
+                    timestamp: segment.start_timestamp_ms / 1000.0,
+                    chunk_id: self.chunk_id_counter,
+                    device_type: device_type.clone(),
+                    live_segment: Some(LiveSegment { id: live_segment_id, is_final: true }),
+                };
+
+                if let Err(e) = self.transcription_sender.send(transcription_chunk) {
+                    warn!("Failed to send VAD segment: {}", e);
+                } else {
+                    self.chunk_id_counter += 1;
+                    self.last_segment_sent_at = std::time::Instant::now();
                 }
+            } else {
+                debug!("⏭️ Dropping short VAD segment: {:.1}ms ({} samples < 800)",
+                       duration_ms, segment.samples.len());
             }
-            Err(e) => {
-                warn!("Failed to flush VAD processor: {}", e);
+        }
+    }
+
+    /// Send interim snapshots of in-progress speech so the UI can show live captions
+    fn send_partial_snapshots(&mut self) {
+        if self.last_partial_at.elapsed().as_millis() < PARTIAL_INTERVAL_MS {
+            return;
+        }
+        self.last_partial_at = std::time::Instant::now();
+
+        let mut snapshots = vec![(self.vad_processor.in_progress_speech(), DeviceType::Microphone)];
+        if let Some(ref system_vad) = self.system_vad_processor {
+            snapshots.push((system_vad.in_progress_speech(), DeviceType::System));
+        }
+
+        for (snapshot, device_type) in snapshots {
+            let segment = match snapshot {
+                Some(segment) if segment.samples.len() >= MIN_PARTIAL_SAMPLES => segment,
+                _ => continue,
+            };
+
+            let slot = Self::live_slot(&device_type);
+            let live_segment_id = match self.open_live_segments[slot] {
+                Some(id) => id,
+                None => {
+                    let id = self.allocate_live_segment_id();
+                    self.open_live_segments[slot] = Some(id);
+                    id
+                }
+            };
+
+            let partial_chunk = AudioChunk {
+                data: segment.samples,
+                sample_rate: 16000,
+                timestamp: segment.start_timestamp_ms / 1000.0,
+                chunk_id: self.chunk_id_counter,
+                device_type,
+                live_segment: Some(LiveSegment { id: live_segment_id, is_final: false }),
+            };
+
+            if let Err(e) = self.transcription_sender.send(partial_chunk) {
+                warn!("Failed to send partial segment: {}", e);
+            } else {
+                self.chunk_id_counter += 1;
             }
         }
+    }
 
-        Ok(())
+    /// Forced flush timer: if speech has been open longer than the cap without a segment
+    /// going out (e.g. audio stopped arriving mid-utterance), cut it so the transcript catches up
+    fn check_forced_flush(&mut self) {
+        let max_duration = match self.max_segment_duration {
+            Some(duration) => duration,
+            None => return,
+        };
+        if self.last_segment_sent_at.elapsed() < max_duration {
+            return;
+        }
+        // Reset even if nothing was open so idle periods don't trigger a cut on every tick
+        self.last_segment_sent_at = std::time::Instant::now();
+
+        if let Some(segment) = self.vad_processor.force_cut() {
+            info!("⏱️ Forced flush: sending {:.1}s of in-progress speech", segment.samples.len() as f64 / 16000.0);
+            self.send_speech_segments(vec![segment], DeviceType::Microphone);
+        }
+        let system_segment = self.system_vad_processor.as_mut().and_then(|vad| vad.force_cut());
+        if let Some(segment) = system_segment {
+            info!("⏱️ Forced flush: sending {:.1}s of in-progress system speech", segment.samples.len() as f64 / 16000.0);
+            self.send_speech_segments(vec![segment], DeviceType::System);
+        }
     }
 
+    /// Cap segment length on every VAD processor (also drives the forced flush timer)
+    fn apply_max_segment_duration(&mut self, max_segment_seconds: Option<u32>) {
+        let max_ms = max_segment_seconds.map(|s| s * 1000);
+        self.vad_processor.set_max_segment_duration(max_ms);
+        if let Some(ref mut system_vad) = self.system_vad_processor {
+            system_vad.set_max_segment_duration(max_ms);
+        }
+        self.max_segment_duration = max_segment_seconds.map(|s| std::time::Duration::from_secs(s as u64));
+    }
+
+    fn allocate_live_segment_id(&mut self) -> u64 {
+        let id = self.next_live_segment_id;
+        self.next_live_segment_id += 1;
+        id
+    }
+
+    fn live_slot(device_type: &DeviceType) -> usize {
+        match device_type {
+            DeviceType::Microphone => 0,
+            DeviceType::System => 1,
+        }
+    }
+
+    /// Transcribe mic and system audio independently instead of the mixed signal
+    fn enable_split_channels(&mut self) {
+        // Same redemption time as the primary VAD
+        match ContinuousVadProcessor::new(self.sample_rate, 400) {
+            Ok(processor) => {
+                info!("🎙️ Split-channel transcription enabled: mic and system audio get separate VAD segments");
+                self.system_vad_processor = Some(processor);
+            }
+            Err(e) => {
+                warn!("Failed to create system VAD processor, falling back to mixed transcription: {}", e);
+            }
+        }
+    }
 }
 
 /// Simple audio pipeline manager
 pub struct AudioPipelineManager {
     pipeline_handle: Option<JoinHandle<Result<()>>>,
     audio_sender: Option<mpsc::UnboundedSender<AudioChunk>>,
+    split_channels: bool,
+    max_segment_seconds: Option<u32>,
 }
 
 impl AudioPipelineManager {
@@ -951,9 +1105,21 @@ impl AudioPipelineManager {
         Self {
             pipeline_handle: None,
             audio_sender: None,
+            split_channels: false,
+            max_segment_seconds: None,
         }
     }
 
+    /// Transcribe mic and system audio separately (takes effect on next start)
+    pub fn set_split_channels(&mut self, enabled: bool) {
+        self.split_channels = enabled;
+    }
+
+    /// Cap segment duration / transcript lag in seconds (takes effect on next start)
+    pub fn set_max_segment_duration(&mut self, max_segment_seconds: Option<u32>) {
+        self.max_segment_seconds = max_segment_seconds;
+    }
+
     /// Start the audio pipeline with device information for adaptive buffering
     pub fn start(
         &mut self,
@@ -995,6 +1161,12 @@ impl AudioPipelineManager {
         // This ensures both mic AND system audio are captured in recordings
         pipeline.recording_sender_for_mixed = recording_sender;
 
+        if self.split_channels {
+            pipeline.enable_split_channels();
+        }
+        // After split channels so the system VAD gets the cap too
+        pipeline.apply_max_segment_duration(self.max_segment_seconds);
+
         let handle = tokio::spawn(async move {
             pipeline.run().await
         });
@@ -1039,6 +1211,7 @@ impl AudioPipelineManager {
                 timestamp: 0.0,
                 chunk_id: u64::MAX, // Special ID to indicate flush
                 device_type: super::recording_state::DeviceType::Microphone,
+                live_segment: None,
             };
 
             if let Err(e) = sender.send(flush_chunk) {
@@ -1059,6 +1232,7 @@ impl AudioPipelineManager {
                         timestamp: 0.0,
                         chunk_id: u64::MAX - (i as u64),
                         device_type: super::recording_state::DeviceType::Microphone,
+                        live_segment: None,
                     };
                     let _ = sender.send(additional_flush);
                 }
@@ -0,0 +1,355 @@
+// Playback of stored meeting recordings
+// Recordings are served over the meeting-audio:// scheme with HTTP range support, so an
+// <audio> element streams and seeks a long recording instead of loading it whole. A
+// transcript segment maps to its stretch of the recording, either as a URL with a media
+// fragment (#t=start,end) or decoded to a short WAV for click-to-listen checks. Any range
+// can also be cut out to a standalone WAV or MP3 clip for sharing.
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::http::{header, Request, Response, StatusCode};
+use tauri::{command, AppHandle, Manager, Runtime, UriSchemeContext, UriSchemeResponder};
+use tracing::{info, warn};
+
+use super::ffmpeg::find_ffmpeg_path;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::{self, AppState};
+
+pub const SCHEME: &str = "meeting-audio";
+/// Most bytes sent for one range request; players ask again for the rest
+const MAX_RANGE_BYTES: u64 = 1024 * 1024;
+/// Audio before and after a segment when cutting it out, in seconds
+const DEFAULT_PADDING: f64 = 0.5;
+/// Length played for a segment without an end time
+const UNTIMED_SEGMENT_SECONDS: f64 = 15.0;
+
+/// Longest clip that can be exported, in seconds
+const MAX_CLIP_SECONDS: f64 = 3.0 * 60.0 * 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipFormat {
+    Wav,
+    Mp3,
+}
+
+/// Where to listen to one transcript segment
+#[derive(Debug, Serialize)]
+pub struct SegmentAudio {
+    pub meeting_id: String,
+    pub segment_id: String,
+    /// Recording URL with a #t=start,end media fragment
+    pub url: String,
+    pub start_time: f64,
+    pub end_time: Option<f64>,
+}
+
+/// URL of a meeting's recording on the meeting-audio scheme
+pub fn recording_url(meeting_id: &str) -> String {
+    // Windows and Android webviews only load custom schemes through http://<scheme>.localhost
+    if cfg!(any(target_os = "windows", target_os = "android")) {
+        format!("http://{}.localhost/{}", SCHEME, meeting_id)
+    } else {
+        format!("{}://localhost/{}", SCHEME, meeting_id)
+    }
+}
+
+async fn recording_path(pool: &sqlx::SqlitePool, meeting_id: &str) -> Result<PathBuf, String> {
+    MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?
+        .folder_path
+        .map(|folder| Path::new(&folder).join("audio.mp4"))
+        .filter(|path| path.is_file())
+        .ok_or_else(|| "The meeting recording is not available".to_string())
+}
+
+/// Inclusive byte range of a `Range` header value, limited to `MAX_RANGE_BYTES`; None when
+/// it can't be satisfied
+fn parse_range(value: &str, len: u64) -> Option<(u64, u64)> {
+    let spec = value.trim().strip_prefix("bytes=")?;
+    // Only the first range of a multi-range request is served
+    let (start, end) = spec.split(',').next()?.trim().split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().ok()?;
+            (len.checked_sub(suffix.min(len))?, len.checked_sub(1)?)
+        }
+        (start, "") => (start.parse().ok()?, len.checked_sub(1)?),
+        (start, end) => (start.parse().ok()?, end.parse::<u64>().ok()?.min(len.checked_sub(1)?)),
+    };
+    if start > end || start >= len {
+        return None;
+    }
+    Some((start, end.min(start + MAX_RANGE_BYTES - 1)))
+}
+
+fn serve_file(path: &Path, range: Option<&str>) -> std::io::Result<Response<Vec<u8>>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let builder = Response::builder()
+        .header(header::CONTENT_TYPE, "audio/mp4")
+        .header(header::ACCEPT_RANGES, "bytes");
+
+    // Without a Range header the first chunk is sent as a partial response too, so a long
+    // recording is never read into memory whole
+    let response = match parse_range(range.unwrap_or("bytes=0-"), len) {
+        Some((start, end)) => {
+            let mut body = vec![0u8; (end - start + 1) as usize];
+            file.seek(SeekFrom::Start(start))?;
+            file.read_exact(&mut body)?;
+            builder
+                .status(StatusCode::PARTIAL_CONTENT)
+                .header(header::CONTENT_RANGE, format!("bytes {}-{}/{}", start, end, len))
+                .body(body)
+        }
+        // An empty recording has no range to send
+        None if range.is_none() => builder.status(StatusCode::OK).body(Vec::new()),
+        None => builder
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(header::CONTENT_RANGE, format!("bytes */{}", len))
+            .body(Vec::new()),
+    };
+    response.map_err(std::io::Error::other)
+}
+
+fn error_response(status: StatusCode, message: String) -> Response<Vec<u8>> {
+    let mut response = Response::new(message.into_bytes());
+    *response.status_mut() = status;
+    response
+}
+
+/// Handler of the meeting-audio scheme; the path is the meeting id
+pub fn handle_audio_request<R: Runtime>(
+    ctx: UriSchemeContext<'_, R>,
+    request: Request<Vec<u8>>,
+    responder: UriSchemeResponder,
+) {
+    let app = ctx.app_handle().clone();
+    let meeting_id = request.uri().path().trim_matches('/').to_string();
+    let range = request
+        .headers()
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    tauri::async_runtime::spawn(async move {
+        let Some(pool) = state::try_pool(&app) else {
+            return responder.respond(error_response(StatusCode::SERVICE_UNAVAILABLE, "Database not ready".to_string()));
+        };
+        let path = match recording_path(&pool, &meeting_id).await {
+            Ok(path) => path,
+            Err(e) => return responder.respond(error_response(StatusCode::NOT_FOUND, e)),
+        };
+        let served = tokio::task::spawn_blocking(move || serve_file(&path, range.as_deref())).await;
+        match served {
+            Ok(Ok(response)) => responder.respond(response),
+            Ok(Err(e)) => {
+                warn!("Failed to serve recording of {}: {}", meeting_id, e);
+                responder.respond(error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+            }
+            Err(e) => responder.respond(error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        }
+    });
+}
+
+async fn segment_audio(pool: &sqlx::SqlitePool, segment_id: &str) -> Result<SegmentAudio, String> {
+    let segment = TranscriptsRepository::get_segment(pool, segment_id)
+        .await
+        .map_err(|e| format!("Failed to load segment: {}", e))?
+        .ok_or_else(|| format!("Transcript segment not found: {}", segment_id))?;
+    let start_time = segment
+        .audio_start_time
+        .ok_or_else(|| "This segment has no recording time".to_string())?;
+    let end_time = segment
+        .audio_end_time
+        .or(segment.duration.map(|duration| start_time + duration))
+        .filter(|end| *end > start_time);
+    let fragment = match end_time {
+        Some(end) => format!("#t={:.2},{:.2}", start_time, end),
+        None => format!("#t={:.2}", start_time),
+    };
+    Ok(SegmentAudio {
+        url: format!("{}{}", recording_url(&segment.meeting_id), fragment),
+        meeting_id: segment.meeting_id,
+        segment_id: segment.id,
+        start_time,
+        end_time,
+    })
+}
+
+/// Recording URL and time range of a transcript segment, to play it in the UI
+#[command]
+pub async fn get_segment_audio<R: Runtime>(app: AppHandle<R>, segment_id: String) -> Result<SegmentAudio, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let audio = segment_audio(pool, &segment_id).await?;
+    recording_path(pool, &audio.meeting_id).await?;
+    Ok(audio)
+}
+
+/// The stretch of recording under a transcript segment as WAV bytes
+///
+/// # Arguments
+/// * `padding` - Seconds of audio kept before and after the segment (default 0.5)
+#[command]
+pub async fn read_segment_audio<R: Runtime>(
+    app: AppHandle<R>,
+    segment_id: String,
+    padding: Option<f64>,
+) -> Result<tauri::ipc::Response, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let audio = segment_audio(pool, &segment_id).await?;
+    let path = recording_path(pool, &audio.meeting_id).await?;
+    let padding = padding.unwrap_or(DEFAULT_PADDING).clamp(0.0, 10.0);
+    let start = (audio.start_time - padding).max(0.0);
+    let end = audio.end_time.unwrap_or(audio.start_time + UNTIMED_SEGMENT_SECONDS) + padding;
+    let output = cut_recording(path, start, end, ClipFormat::Wav, None).await?;
+    if output.is_empty() {
+        return Err("FFmpeg failed to cut the segment audio".to_string());
+    }
+    Ok(tauri::ipc::Response::new(output))
+}
+
+/// FFmpeg output arguments for a clip of `duration` seconds
+fn clip_args(duration: f64, format: ClipFormat) -> Vec<String> {
+    let codec: &[&str] = match format {
+        ClipFormat::Wav => &["-c:a", "pcm_s16le", "-f", "wav"],
+        ClipFormat::Mp3 => &["-c:a", "libmp3lame", "-q:a", "2", "-f", "mp3"],
+    };
+    ["-t".to_string(), format!("{:.2}", duration), "-vn".to_string()]
+        .into_iter()
+        .chain(codec.iter().map(|arg| arg.to_string()))
+        .collect()
+}
+
+/// Cut `start..end` seconds out of a recording; written to `destination` when given,
+/// returned otherwise
+async fn cut_recording(
+    input: PathBuf,
+    start: f64,
+    end: f64,
+    format: ClipFormat,
+    destination: Option<PathBuf>,
+) -> Result<Vec<u8>, String> {
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| "FFmpeg not found".to_string())?;
+    let output = tokio::task::spawn_blocking(move || {
+        let mut command = std::process::Command::new(ffmpeg_path);
+        command.args(["-ss", &format!("{:.2}", start), "-i"]);
+        command.arg(&input);
+        command.args(clip_args(end - start, format));
+        match &destination {
+            Some(path) => command.arg("-y").arg(path),
+            None => command.arg("pipe:1"),
+        };
+
+        // Hide console window on Windows
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        command.output()
+    })
+    .await
+    .map_err(|e| format!("Audio task failed: {}", e))?
+    .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "FFmpeg failed to cut the recording: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(output.stdout)
+}
+
+fn validate_clip_range(start_time: f64, end_time: f64) -> Result<(), String> {
+    if !start_time.is_finite() || !end_time.is_finite() || start_time < 0.0 {
+        return Err("Invalid clip range".to_string());
+    }
+    if end_time <= start_time {
+        return Err("The clip must end after it starts".to_string());
+    }
+    if end_time - start_time > MAX_CLIP_SECONDS {
+        return Err(format!("Clips can be at most {} hours long", MAX_CLIP_SECONDS / 3600.0));
+    }
+    Ok(())
+}
+
+/// Save a stretch of a meeting's recording as a standalone audio file, e.g. to share a
+/// decision in the speakers' own words
+///
+/// # Arguments
+/// * `start_time` / `end_time` - Range in seconds from the start of the recording, as
+///   stored on transcript segments
+/// * `format` - "wav" or "mp3" (default: from the extension of `path`, else WAV)
+/// * `path` - File to write
+#[command]
+pub async fn export_audio_clip<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    start_time: f64,
+    end_time: f64,
+    format: Option<ClipFormat>,
+    path: String,
+) -> Result<(), String> {
+    validate_clip_range(start_time, end_time)?;
+    let state = app.state::<AppState>();
+    let recording = recording_path(state.db_manager.pool(), &meeting_id).await?;
+    let destination = PathBuf::from(&path);
+    let format = format.unwrap_or_else(|| {
+        match destination.extension().map(|extension| extension.to_string_lossy().to_lowercase()) {
+            Some(extension) if extension == "mp3" => ClipFormat::Mp3,
+            _ => ClipFormat::Wav,
+        }
+    });
+
+    cut_recording(recording, start_time, end_time, format, Some(destination.clone())).await?;
+    if !std::fs::metadata(&destination).is_ok_and(|metadata| metadata.len() > 0) {
+        let _ = std::fs::remove_file(&destination);
+        return Err("The clip is empty; is the range past the end of the recording?".to_string());
+    }
+    info!("Exported {:.1}s clip of meeting {} to {}", end_time - start_time, meeting_id, path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("bytes=0-99", 1000), Some((0, 99)));
+        assert_eq!(parse_range("bytes=900-", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=-100", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=990-5000", 1000), Some((990, 999)));
+        assert_eq!(parse_range("bytes=0-", 10 * MAX_RANGE_BYTES), Some((0, MAX_RANGE_BYTES - 1)));
+        assert_eq!(parse_range("bytes=1000-", 1000), None);
+        assert_eq!(parse_range("bytes=50-10", 1000), None);
+        assert_eq!(parse_range("items=0-1", 1000), None);
+        assert_eq!(parse_range("bytes=0-", 0), None);
+    }
+
+    #[test]
+    fn test_clip_args() {
+        assert_eq!(
+            clip_args(13.5, ClipFormat::Mp3),
+            vec!["-t", "13.50", "-vn", "-c:a", "libmp3lame", "-q:a", "2", "-f", "mp3"]
+        );
+        assert_eq!(clip_args(1.0, ClipFormat::Wav), vec!["-t", "1.00", "-vn", "-c:a", "pcm_s16le", "-f", "wav"]);
+
+        assert!(validate_clip_range(1.0, 2.5).is_ok());
+        assert!(validate_clip_range(2.0, 2.0).is_err());
+        assert!(validate_clip_range(-1.0, 2.0).is_err());
+        assert!(validate_clip_range(0.0, f64::NAN).is_err());
+        assert!(validate_clip_range(0.0, MAX_CLIP_SECONDS + 1.0).is_err());
+    }
+}
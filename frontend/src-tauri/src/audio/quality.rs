@@ -0,0 +1,182 @@
+// audio/quality.rs
+//
+// Per-chunk audio quality scoring (energy, clipping, estimated SNR).
+// Used by the transcription worker to skip hopeless chunks and to mark
+// low-quality regions in the transcript so readers know why text is garbled.
+
+use serde::{Deserialize, Serialize};
+
+// Frame length for the noise-floor estimate
+const FRAME_MS: u32 = 20;
+// Samples at or above this magnitude count as clipped
+const CLIP_LEVEL: f32 = 0.99;
+
+/// Coarse quality bucket derived from the score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QualityLevel {
+    Good,
+    Fair,
+    Poor,
+    /// Not worth sending to the transcription engine
+    Unusable,
+}
+
+/// Quality metrics for a single audio chunk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkQuality {
+    /// Overall score, 0.0 (unusable) to 1.0 (clean)
+    pub score: f32,
+    pub level: QualityLevel,
+    /// Estimated signal-to-noise ratio in dB (loud frames vs. quiet frames)
+    pub snr_db: f32,
+    /// Fraction of samples at full scale
+    pub clipping_ratio: f32,
+    /// RMS level in dBFS
+    pub rms_dbfs: f32,
+}
+
+impl ChunkQuality {
+    /// Score a chunk of mono f32 samples
+    pub fn analyze(samples: &[f32], sample_rate: u32) -> Self {
+        if samples.is_empty() {
+            return Self {
+                score: 0.0,
+                level: QualityLevel::Unusable,
+                snr_db: 0.0,
+                clipping_ratio: 0.0,
+                rms_dbfs: f32::NEG_INFINITY,
+            };
+        }
+
+        let rms = (samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32).sqrt();
+        let rms_dbfs = to_db(rms);
+        let clipped = samples.iter().filter(|&&x| x.abs() >= CLIP_LEVEL).count();
+        let clipping_ratio = clipped as f32 / samples.len() as f32;
+        let frame_size = ((sample_rate * FRAME_MS / 1000) as usize).max(1);
+        let snr_db = estimate_snr_db(samples, frame_size);
+
+        // Energy: -60 dBFS or quieter is silence, -30 dBFS and above is comfortably loud
+        let energy_score = ((rms_dbfs + 60.0) / 30.0).clamp(0.0, 1.0);
+        // SNR: 0 dB is noise, 20 dB and above is clean
+        let snr_score = (snr_db / 20.0).clamp(0.0, 1.0);
+        // Clipping: 5% clipped samples wipes out the score
+        let clipping_penalty = (clipping_ratio / 0.05).clamp(0.0, 1.0);
+
+        let score = ((0.4 * energy_score + 0.6 * snr_score) * (1.0 - clipping_penalty)).clamp(0.0, 1.0);
+
+        Self {
+            score,
+            level: QualityLevel::from_score(score),
+            snr_db,
+            clipping_ratio,
+            rms_dbfs,
+        }
+    }
+
+    /// Whether the chunk should be skipped entirely
+    pub fn is_unusable(&self) -> bool {
+        self.level == QualityLevel::Unusable
+    }
+
+    /// Whether the resulting text should be marked as possibly garbled
+    pub fn is_low_quality(&self) -> bool {
+        matches!(self.level, QualityLevel::Poor | QualityLevel::Unusable)
+    }
+}
+
+impl QualityLevel {
+    pub fn from_score(score: f32) -> Self {
+        if score >= 0.6 {
+            Self::Good
+        } else if score >= 0.35 {
+            Self::Fair
+        } else if score >= 0.1 {
+            Self::Poor
+        } else {
+            Self::Unusable
+        }
+    }
+}
+
+fn to_db(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        f32::NEG_INFINITY
+    } else {
+        20.0 * amplitude.log10()
+    }
+}
+
+/// Estimate SNR by comparing loud frames (speech) to quiet frames (noise floor)
+fn estimate_snr_db(samples: &[f32], frame_size: usize) -> f32 {
+    let mut frame_energies: Vec<f32> = samples
+        .chunks(frame_size)
+        .filter(|frame| frame.len() == frame_size)
+        .map(|frame| frame.iter().map(|&x| x * x).sum::<f32>() / frame.len() as f32)
+        .collect();
+
+    if frame_energies.len() < 2 {
+        return 0.0;
+    }
+
+    frame_energies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let noise_idx = frame_energies.len() / 10;
+    let signal_idx = (frame_energies.len() * 9 / 10).min(frame_energies.len() - 1);
+
+    let noise = frame_energies[noise_idx].max(1e-10);
+    let signal = frame_energies[signal_idx];
+
+    if signal <= noise {
+        return 0.0;
+    }
+    (10.0 * (signal / noise).log10()).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone_with_pauses(amplitude: f32) -> Vec<f32> {
+        // 1s at 16kHz: alternate 100ms of tone and 100ms of near-silence
+        (0..16000)
+            .map(|i| {
+                let t = i as f32 / 16000.0;
+                if (i / 1600) % 2 == 0 {
+                    amplitude * (2.0 * std::f32::consts::PI * 220.0 * t).sin()
+                } else {
+                    0.0005 * (2.0 * std::f32::consts::PI * 50.0 * t).sin()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_silence_is_unusable() {
+        let quality = ChunkQuality::analyze(&vec![0.0; 16000], 16000);
+        assert!(quality.is_unusable());
+    }
+
+    #[test]
+    fn test_clean_speech_like_signal_is_good() {
+        let quality = ChunkQuality::analyze(&tone_with_pauses(0.3), 16000);
+        assert_eq!(quality.level, QualityLevel::Good);
+        assert!(quality.snr_db > 20.0);
+        assert_eq!(quality.clipping_ratio, 0.0);
+    }
+
+    #[test]
+    fn test_clipping_penalized() {
+        let clipped: Vec<f32> = tone_with_pauses(3.0).into_iter().map(|x| x.clamp(-1.0, 1.0)).collect();
+        let quality = ChunkQuality::analyze(&clipped, 16000);
+        assert!(quality.clipping_ratio > 0.05);
+        assert!(quality.is_low_quality());
+    }
+
+    #[test]
+    fn test_constant_noise_has_low_snr() {
+        let noise: Vec<f32> = (0..16000).map(|i| if i % 2 == 0 { 0.05 } else { -0.05 }).collect();
+        let quality = ChunkQuality::analyze(&noise, 16000);
+        assert!(quality.snr_db < 1.0);
+        assert!(quality.level != QualityLevel::Good);
+    }
+}
@@ -21,6 +21,7 @@ use super::{
     DeviceEvent,
     DeviceMonitorType
 };
+use super::recording_preferences::TranscriptionPipelineOptions;
 
 // Import transcription modules
 use super::transcription::{
@@ -65,6 +66,26 @@ pub struct TranscriptionStatus {
 // RECORDING COMMANDS
 // ============================================================================
 
+/// Convert a live transcript update into the structured segment saved with the recording
+fn segment_from_update(update: &TranscriptUpdate) -> crate::audio::recording_saver::TranscriptSegment {
+    crate::audio::recording_saver::TranscriptSegment {
+        id: format!("seg_{}", update.sequence_id),
+        text: update.text.clone(),
+        audio_start_time: update.audio_start_time,
+        audio_end_time: update.audio_end_time,
+        duration: update.duration,
+        display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
+        confidence: update.confidence,
+        sequence_id: update.sequence_id,
+        hallucination_flag: update.hallucination_flag.clone(),
+        audio_quality: update.audio_quality.clone(),
+        speaker: update.speaker.clone(),
+        speaker_label: update.speaker_label.clone(),
+        overlapping_speech: false,
+        language: update.language.clone(),
+    }
+}
+
 /// Start recording with default devices
 pub async fn start_recording<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
     start_recording_with_meeting_name(app, None).await
@@ -104,6 +125,11 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     }
     info!("✅ Transcription model validation passed");
 
+    // Warn about low disk space, and lower the recording bitrate if the user allows it
+    crate::storage::check_before_recording(&app).await;
+    // Remember the calendar event being recorded, to fill in the meeting when it is saved
+    crate::calendar::note_recording_started();
+
     // Async-first approach - no more blocking operations!
     info!("🚀 Starting async recording initialization");
 
@@ -111,16 +137,17 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     let mut manager = RecordingManager::new();
 
     // Load recording preferences to get auto_save AND device preferences
-    let (auto_save, preferred_mic_name, preferred_system_name) =
+    let (auto_save, preferred_mic_name, preferred_system_name, pipeline_options) =
         match super::recording_preferences::load_recording_preferences(&app).await {
             Ok(prefs) => {
-                info!("📋 Loaded recording preferences: auto_save={}, preferred_mic={:?}, preferred_system={:?}",
-                      prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device);
-                (prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device)
+                let pipeline_options = TranscriptionPipelineOptions::from(&prefs);
+                info!("📋 Loaded recording preferences: auto_save={}, preferred_mic={:?}, preferred_system={:?}, pipeline={:?}",
+                      prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device, pipeline_options);
+                (prefs.auto_save, prefs.preferred_mic_device, prefs.preferred_system_device, pipeline_options)
             }
             Err(e) => {
                 warn!("Failed to load recording preferences, using defaults: {}", e);
-                (true, None, None)
+                (true, None, None, TranscriptionPipelineOptions::default())
             }
         };
 
@@ -223,6 +250,7 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         )
     });
     manager.set_meeting_name(Some(effective_meeting_name));
+    manager.set_transcription_options(pipeline_options);
 
     // Set up error callback
     let app_for_error = app.clone();
@@ -230,6 +258,12 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
         let _ = app_for_error.emit("recording-error", error.user_message());
     });
 
+    // Acoustic annotations join the transcript through the regular transcript-update path
+    let app_for_events = app.clone();
+    manager.set_acoustic_event_callback(move |event| {
+        let _ = app_for_events.emit("transcript-update", event.to_transcript_update());
+    });
+
     // Start recording with resolved devices (replaces start_recording_with_defaults_and_auto_save call)
     let transcription_receiver = manager
         .start_recording(microphone_device, system_device, auto_save)
@@ -248,37 +282,43 @@ pub async fn start_recording_with_meeting_name<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver, pipeline_options.split_channels);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
     }
 
+    super::keyword_alerts::reload_matcher(&app);
+    crate::summary::live_summary::start(&app).await;
+
     // CRITICAL: Listen for transcript-update events and save to recording manager
     // This enables transcript history persistence for page reload sync
     // Store listener ID for cleanup during stop_recording to ensure microphone is released
     {
         use tauri::Listener;
+        let listener_app = app.clone();
         let listener_id = app.listen("transcript-update", move |event: tauri::Event| {
             // Parse the transcript update from the event payload
             if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
+                super::keyword_alerts::check_transcript_update(&listener_app, &update);
+
                 // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
+                let segment = segment_from_update(&update);
 
                 // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
+                let overlapping = match RECORDING_MANAGER.lock() {
+                    Ok(manager_guard) => match manager_guard.as_ref() {
+                        Some(manager) => manager.add_transcript_segment(segment),
+                        None => Vec::new(),
+                    },
+                    Err(_) => Vec::new(),
+                };
+
+                // Let the UI mark crosstalk, including earlier segments the new one talks over
+                if !overlapping.is_empty() {
+                    let _ = listener_app.emit("transcript-overlap", serde_json::json!({
+                        "sequence_ids": overlapping,
+                    }));
                 }
             }
         });
@@ -347,6 +387,11 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     }
     info!("✅ Transcription model validation passed");
 
+    // Warn about low disk space, and lower the recording bitrate if the user allows it
+    crate::storage::check_before_recording(&app).await;
+    // Remember the calendar event being recorded, to fill in the meeting when it is saved
+    crate::calendar::note_recording_started();
+
     // Parse devices
     let mic_device = if let Some(ref name) = mic_device_name {
         Some(Arc::new(parse_audio_device(name).map_err(|e| {
@@ -370,15 +415,17 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     // Create new recording manager
     let mut manager = RecordingManager::new();
 
-    // Load recording preferences to check auto_save setting
-    let auto_save = match super::recording_preferences::load_recording_preferences(&app).await {
+    // Load recording preferences to check auto_save and transcription pipeline settings
+    let (auto_save, pipeline_options) = match super::recording_preferences::load_recording_preferences(&app).await {
         Ok(prefs) => {
-            info!("📋 Loaded recording preferences: auto_save={}", prefs.auto_save);
-            prefs.auto_save
+            let pipeline_options = TranscriptionPipelineOptions::from(&prefs);
+            info!("📋 Loaded recording preferences: auto_save={}, pipeline={:?}",
+                  prefs.auto_save, pipeline_options);
+            (prefs.auto_save, pipeline_options)
         }
         Err(e) => {
             warn!("Failed to load recording preferences, defaulting to auto_save=true: {}", e);
-            true // Default to saving if preferences can't be loaded
+            (true, TranscriptionPipelineOptions::default()) // Default to saving if preferences can't be loaded
         }
     };
 
@@ -391,6 +438,7 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         )
     });
     manager.set_meeting_name(Some(effective_meeting_name));
+    manager.set_transcription_options(pipeline_options);
 
     // Set up error callback
     let app_for_error = app.clone();
@@ -398,6 +446,12 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
         let _ = app_for_error.emit("recording-error", error.user_message());
     });
 
+    // Acoustic annotations join the transcript through the regular transcript-update path
+    let app_for_events = app.clone();
+    manager.set_acoustic_event_callback(move |event| {
+        let _ = app_for_events.emit("transcript-update", event.to_transcript_update());
+    });
+
     // Start recording with specified devices and auto_save setting
     let transcription_receiver = manager
         .start_recording(mic_device, system_device, auto_save)
@@ -416,37 +470,43 @@ pub async fn start_recording_with_devices_and_meeting<R: Runtime>(
     reset_speech_detected_flag(); // Reset for new recording session
 
     // Start optimized parallel transcription task and store handle
-    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver);
+    let task_handle = transcription::start_transcription_task(app.clone(), transcription_receiver, pipeline_options.split_channels);
     {
         let mut global_task = TRANSCRIPTION_TASK.lock().unwrap();
         *global_task = Some(task_handle);
     }
 
+    super::keyword_alerts::reload_matcher(&app);
+    crate::summary::live_summary::start(&app).await;
+
     // CRITICAL: Listen for transcript-update events and save to recording manager
     // This enables transcript history persistence for page reload sync
     // Store listener ID for cleanup during stop_recording to ensure microphone is released
     {
         use tauri::Listener;
+        let listener_app = app.clone();
         let listener_id = app.listen("transcript-update", move |event: tauri::Event| {
             // Parse the transcript update from the event payload
             if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
+                super::keyword_alerts::check_transcript_update(&listener_app, &update);
+
                 // Create structured transcript segment
-                let segment = crate::audio::recording_saver::TranscriptSegment {
-                    id: format!("seg_{}", update.sequence_id),
-                    text: update.text.clone(),
-                    audio_start_time: update.audio_start_time,
-                    audio_end_time: update.audio_end_time,
-                    duration: update.duration,
-                    display_time: update.timestamp.clone(), // Use wall-clock timestamp for display
-                    confidence: update.confidence,
-                    sequence_id: update.sequence_id,
-                };
+                let segment = segment_from_update(&update);
 
                 // Save to recording manager
-                if let Ok(manager_guard) = RECORDING_MANAGER.lock() {
-                    if let Some(manager) = manager_guard.as_ref() {
-                        manager.add_transcript_segment(segment);
-                    }
+                let overlapping = match RECORDING_MANAGER.lock() {
+                    Ok(manager_guard) => match manager_guard.as_ref() {
+                        Some(manager) => manager.add_transcript_segment(segment),
+                        None => Vec::new(),
+                    },
+                    Err(_) => Vec::new(),
+                };
+
+                // Let the UI mark crosstalk, including earlier segments the new one talks over
+                if !overlapping.is_empty() {
+                    let _ = listener_app.emit("transcript-overlap", serde_json::json!({
+                        "sequence_ids": overlapping,
+                    }));
                 }
             }
         });
@@ -537,6 +597,7 @@ pub async fn stop_recording<R: Runtime>(
             info!("✅ Transcript-update listener removed");
         }
     }
+    crate::summary::live_summary::stop();
 
     // Step 2: Signal transcription workers to finish processing ALL queued chunks
     let _ = app.emit(
@@ -813,6 +874,9 @@ pub async fn stop_recording<R: Runtime>(
         // Extract meeting info BEFORE async operations
         let meeting_folder = manager.get_meeting_folder();
         let meeting_name = manager.get_meeting_name();
+        if let Some(folder) = &meeting_folder {
+            crate::diarization::fingerprints::write_pending_session_speakers(folder);
+        }
 
         match tokio::time::timeout(
             tokio::time::Duration::from_secs(300), // 5 minutes max for file I/O
@@ -895,6 +959,16 @@ pub async fn is_recording() -> bool {
     IS_RECORDING.load(Ordering::SeqCst)
 }
 
+/// Seconds of audio recorded so far (pauses excluded) and the meeting folder, while recording
+pub fn recording_position() -> Option<(f64, Option<std::path::PathBuf>)> {
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        return None;
+    }
+    let manager_guard = RECORDING_MANAGER.lock().unwrap();
+    let manager = manager_guard.as_ref()?;
+    Some((manager.get_active_recording_duration()?, manager.get_meeting_folder()))
+}
+
 /// Get recording statistics
 pub async fn get_transcription_status() -> TranscriptionStatus {
     TranscriptionStatus {
@@ -14,6 +14,7 @@ use super::recording_state::{RecordingState, AudioChunk, DeviceType as Recording
 use super::pipeline::AudioPipelineManager;
 use super::stream::AudioStreamManager;
 use super::recording_saver::RecordingSaver;
+use super::recording_preferences::TranscriptionPipelineOptions;
 use super::device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
 
 /// Stream manager type enumeration
@@ -424,6 +425,14 @@ impl RecordingManager {
         self.state.set_error_callback(callback);
     }
 
+    /// Set callback for acoustic events (applause, long silence) detected during recording
+    pub fn set_acoustic_event_callback<F>(&self, callback: F)
+    where
+        F: Fn(&super::acoustic_events::AcousticEvent) + Send + Sync + 'static,
+    {
+        self.state.set_acoustic_event_callback(callback);
+    }
+
     /// Check if there's a fatal error
     pub fn has_fatal_error(&self) -> bool {
         self.state.has_fatal_error()
@@ -434,9 +443,16 @@ impl RecordingManager {
         self.recording_saver.set_meeting_name(name);
     }
 
-    /// Add a structured transcript segment to be saved later
-    pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) {
-        self.recording_saver.add_transcript_segment(segment);
+    /// Configure how the pipeline segments audio for transcription (takes effect on next start)
+    pub fn set_transcription_options(&mut self, options: TranscriptionPipelineOptions) {
+        self.pipeline_manager.set_split_channels(options.split_channels);
+        self.pipeline_manager.set_max_segment_duration(options.max_segment_seconds);
+    }
+
+    /// Add a structured transcript segment to be saved later.
+    /// Returns the sequence ids of segments newly flagged as overlapping speech.
+    pub fn add_transcript_segment(&self, segment: super::recording_saver::TranscriptSegment) -> Vec<u64> {
+        self.recording_saver.add_transcript_segment(segment)
     }
 
     /// Add a transcript chunk to be saved later (legacy method)
@@ -20,6 +20,27 @@ pub struct RecordingPreferences {
     pub preferred_mic_device: Option<String>,
     #[serde(default)]
     pub preferred_system_device: Option<String>,
+    /// Transcribe mic and system audio separately and label segments "Me"/"Remote"
+    #[serde(default)]
+    pub split_channel_transcription: bool,
+    /// Name used for microphone segments in split-channel mode (defaults to "Me")
+    #[serde(default)]
+    pub local_speaker_name: Option<String>,
+    /// Longest a transcript may lag behind live audio during continuous speech (0 = no cap)
+    #[serde(default = "default_max_transcript_lag_seconds")]
+    pub max_transcript_lag_seconds: u32,
+    /// Label transcript segments by speaker ("Speaker 1", "Speaker 2", ...) using voice embeddings
+    #[serde(default)]
+    pub speaker_diarization: bool,
+    /// Keep a rolling summary of the meeting up to date while recording (uses the summary model)
+    #[serde(default)]
+    pub live_summary: bool,
+    /// Minutes between rolling summary updates
+    #[serde(default = "default_live_summary_interval_minutes")]
+    pub live_summary_interval_minutes: u32,
+    /// Record at a lower audio bitrate when the recordings folder is low on disk space
+    #[serde(default)]
+    pub lower_quality_on_low_space: bool,
     #[cfg(target_os = "macos")]
     #[serde(default)]
     pub system_audio_backend: Option<String>,
@@ -33,12 +54,53 @@ impl Default for RecordingPreferences {
             file_format: "mp4".to_string(),
             preferred_mic_device: None,
             preferred_system_device: None,
+            split_channel_transcription: false,
+            local_speaker_name: None,
+            max_transcript_lag_seconds: default_max_transcript_lag_seconds(),
+            speaker_diarization: false,
+            live_summary: false,
+            live_summary_interval_minutes: default_live_summary_interval_minutes(),
+            lower_quality_on_low_space: false,
             #[cfg(target_os = "macos")]
             system_audio_backend: Some("coreaudio".to_string()),
         }
     }
 }
 
+fn default_max_transcript_lag_seconds() -> u32 {
+    20
+}
+
+fn default_live_summary_interval_minutes() -> u32 {
+    3
+}
+
+/// Recording preferences that shape the transcription pipeline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TranscriptionPipelineOptions {
+    pub split_channels: bool,
+    /// Segment duration cap / forced flush interval in seconds (None = VAD decides alone)
+    pub max_segment_seconds: Option<u32>,
+}
+
+impl Default for TranscriptionPipelineOptions {
+    fn default() -> Self {
+        Self {
+            split_channels: false,
+            max_segment_seconds: Some(default_max_transcript_lag_seconds()),
+        }
+    }
+}
+
+impl From<&RecordingPreferences> for TranscriptionPipelineOptions {
+    fn from(prefs: &RecordingPreferences) -> Self {
+        Self {
+            split_channels: prefs.split_channel_transcription,
+            max_segment_seconds: (prefs.max_transcript_lag_seconds > 0).then_some(prefs.max_transcript_lag_seconds),
+        }
+    }
+}
+
 /// Get the default recordings folder based on platform
 pub fn get_default_recordings_folder() -> PathBuf {
     #[cfg(target_os = "windows")]
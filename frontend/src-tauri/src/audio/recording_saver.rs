@@ -22,6 +22,67 @@ pub struct TranscriptSegment {
     pub display_time: String,   // Formatted time for display like "[02:15]"
     pub confidence: f32,
     pub sequence_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hallucination_flag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_quality: Option<super::quality::ChunkQuality>,
+    /// Audio source ('mic' / 'system') when channels are transcribed separately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    /// Diarized speaker ("Speaker 1", ...) when speaker diarization is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_label: Option<String>,
+    /// Spoken over a segment from another speaker (least reliable text)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overlapping_speech: bool,
+    /// Spoken language (ISO 639-1) when it could be identified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+/// Display label for a speaker id ("Me" for the microphone, "Remote" for system audio)
+pub fn speaker_label(speaker: &str) -> &'static str {
+    match speaker {
+        "mic" => "Me",
+        "system" => "Remote",
+        _ => "Unknown",
+    }
+}
+
+/// Render segments as a plain-text speaker-labeled transcript ("Speaker 1:" when diarized,
+/// otherwise "Me:"/"Remote:"), merging consecutive segments from the same speaker.
+/// Returns None if no segment has a speaker.
+pub fn format_labeled_transcript(segments: &[TranscriptSegment]) -> Option<String> {
+    if !segments.iter().any(|s| s.speaker.is_some() || s.speaker_label.is_some()) {
+        return None;
+    }
+
+    let mut lines: Vec<(Option<&str>, String)> = Vec::new();
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let speaker = segment
+            .speaker_label
+            .as_deref()
+            .or_else(|| segment.speaker.as_deref().map(speaker_label));
+        match lines.last_mut() {
+            Some((last_speaker, last_text)) if *last_speaker == speaker => {
+                last_text.push(' ');
+                last_text.push_str(text);
+            }
+            _ => lines.push((speaker, text.to_string())),
+        }
+    }
+
+    Some(
+        lines
+            .into_iter()
+            .map(|(speaker, text)| format!("{}: {}", speaker.unwrap_or("Unknown"), text))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
 }
 
 /// Meeting metadata structure
@@ -92,8 +153,10 @@ impl RecordingSaver {
     }
 
     /// Add or update a structured transcript segment (upserts based on sequence_id)
-    /// Also saves incrementally to disk
-    pub fn add_transcript_segment(&self, segment: TranscriptSegment) {
+    /// Also saves incrementally to disk. Returns the sequence ids of segments newly
+    /// flagged as overlapping speech (the new segment and/or the ones it talks over).
+    pub fn add_transcript_segment(&self, segment: TranscriptSegment) -> Vec<u64> {
+        let mut newly_overlapping = Vec::new();
         if let Ok(mut segments) = self.transcript_segments.lock() {
             // Check if segment with same sequence_id exists (update it)
             if let Some(existing) = segments.iter_mut().find(|s| s.sequence_id == segment.sequence_id) {
@@ -101,11 +164,28 @@ impl RecordingSaver {
                 info!("Updated transcript segment {} (seq: {}) - total segments: {}",
                       segment.id, segment.sequence_id, segments.len());
             } else {
-                // New segment, add it
-                segments.push(segment.clone());
+                // New segment - keep segments ordered by audio time so separately
+                // transcribed mic/system segments interleave correctly
+                let position = segments
+                    .iter()
+                    .rposition(|s| s.audio_start_time <= segment.audio_start_time)
+                    .map_or(0, |i| i + 1);
+                segments.insert(position, segment.clone());
                 info!("Added new transcript segment {} (seq: {}) - total segments: {}",
                       segment.id, segment.sequence_id, segments.len());
             }
+
+            let spans: Vec<(f64, f64, Option<&str>)> = segments
+                .iter()
+                .map(|s| (s.audio_start_time, s.audio_end_time, s.speaker.as_deref()))
+                .collect();
+            let flags = crate::diarization::overlap::overlapping_flags(&spans);
+            for (existing, overlapping) in segments.iter_mut().zip(flags) {
+                if overlapping && !existing.overlapping_speech {
+                    existing.overlapping_speech = true;
+                    newly_overlapping.push(existing.sequence_id);
+                }
+            }
         } else {
             error!("Failed to lock transcript segments for adding segment {}", segment.id);
         }
@@ -116,6 +196,7 @@ impl RecordingSaver {
                 warn!("Failed to write incremental transcript update: {}", e);
             }
         }
+        newly_overlapping
     }
 
     /// Legacy method for backward compatibility - converts text to basic segment
@@ -129,6 +210,12 @@ impl RecordingSaver {
             display_time: "[00:00]".to_string(),
             confidence: 1.0,
             sequence_id: 0,
+            hallucination_flag: None,
+            audio_quality: None,
+            speaker: None,
+            speaker_label: None,
+            overlapping_speech: false,
+            language: None,
         };
         self.add_transcript_segment(segment);
     }
@@ -298,12 +385,15 @@ impl RecordingSaver {
         let temp_path = folder.join(".transcripts.json.tmp");
 
         // Create JSON structure
-        let json = serde_json::json!({
+        let mut json = serde_json::json!({
             "version": "1.0",
             "segments": segments_clone,
             "last_updated": chrono::Utc::now().to_rfc3339(),
             "total_segments": segments_clone.len()
         });
+        if let Some(labeled) = format_labeled_transcript(&segments_clone) {
+            json["labeled_transcript"] = serde_json::Value::String(labeled);
+        }
 
         // Serialize to pretty JSON string
         let json_string = serde_json::to_string_pretty(&json)
@@ -483,3 +573,79 @@ impl Default for RecordingSaver {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(seq: u64, start: f64, text: &str, speaker: Option<&str>) -> TranscriptSegment {
+        TranscriptSegment {
+            id: format!("seg_{}", seq),
+            text: text.to_string(),
+            audio_start_time: start,
+            audio_end_time: start + 1.0,
+            duration: 1.0,
+            display_time: "[00:00]".to_string(),
+            confidence: 0.9,
+            sequence_id: seq,
+            hallucination_flag: None,
+            audio_quality: None,
+            speaker: speaker.map(|s| s.to_string()),
+            speaker_label: None,
+            overlapping_speech: false,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_segments_interleaved_by_audio_time() {
+        let saver = RecordingSaver::new();
+        saver.add_transcript_segment(segment(0, 0.0, "Hi all", Some("mic")));
+        saver.add_transcript_segment(segment(1, 5.0, "Next item", Some("mic")));
+        // System segment finalized later but spoken in between
+        saver.add_transcript_segment(segment(2, 2.0, "Hello", Some("system")));
+
+        let order: Vec<u64> = saver.get_transcript_segments().iter().map(|s| s.sequence_id).collect();
+        assert_eq!(order, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_crosstalk_flags_both_segments() {
+        let saver = RecordingSaver::new();
+        assert!(saver.add_transcript_segment(segment(0, 0.0, "So the plan is", Some("mic"))).is_empty());
+        // Remote speaker starts talking 0.3s into the local segment
+        assert_eq!(saver.add_transcript_segment(segment(1, 0.3, "Wait", Some("system"))), vec![0, 1]);
+        assert!(saver.add_transcript_segment(segment(2, 3.0, "Go on", Some("system"))).is_empty());
+
+        let flags: Vec<bool> = saver.get_transcript_segments().iter().map(|s| s.overlapping_speech).collect();
+        assert_eq!(flags, vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_labeled_transcript_merges_consecutive_speakers() {
+        let segments = vec![
+            segment(0, 0.0, "Hi all.", Some("mic")),
+            segment(1, 1.0, "Can you hear me?", Some("mic")),
+            segment(2, 2.0, "Yes.", Some("system")),
+        ];
+        assert_eq!(
+            format_labeled_transcript(&segments).unwrap(),
+            "Me: Hi all. Can you hear me?\nRemote: Yes."
+        );
+        assert!(format_labeled_transcript(&[segment(0, 0.0, "Mixed", None)]).is_none());
+    }
+
+    #[test]
+    fn test_labeled_transcript_prefers_diarized_speaker() {
+        let mut first = segment(0, 0.0, "Shall we start?", Some("system"));
+        first.speaker_label = Some("Speaker 1".to_string());
+        let mut second = segment(1, 1.0, "Sure.", Some("system"));
+        second.speaker_label = Some("Speaker 2".to_string());
+        let mine = segment(2, 2.0, "Go ahead.", Some("mic"));
+
+        assert_eq!(
+            format_labeled_transcript(&[first, second, mine]).unwrap(),
+            "Speaker 1: Shall we start?\nSpeaker 2: Sure.\nMe: Go ahead."
+        );
+    }
+}
@@ -6,6 +6,7 @@ use anyhow::Result;
 
 use super::devices::AudioDevice;
 use super::buffer_pool::AudioBufferPool;
+use super::acoustic_events::AcousticEvent;
 
 /// Device type for audio chunks
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +15,16 @@ pub enum DeviceType {
     System,
 }
 
+impl DeviceType {
+    /// Speaker identifier stored with transcript segments ('mic' / 'system')
+    pub fn speaker_id(&self) -> &'static str {
+        match self {
+            DeviceType::Microphone => "mic",
+            DeviceType::System => "system",
+        }
+    }
+}
+
 /// Audio chunk with metadata for processing
 #[derive(Debug, Clone)]
 pub struct AudioChunk {
@@ -22,6 +33,16 @@ pub struct AudioChunk {
     pub timestamp: f64,
     pub chunk_id: u64,
     pub device_type: DeviceType,
+    /// Live caption segment this chunk belongs to (None for raw/recording chunks)
+    pub live_segment: Option<LiveSegment>,
+}
+
+/// Identity of a live caption segment. Interim snapshots of in-progress speech
+/// share the ID of the final chunk that eventually replaces them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiveSegment {
+    pub id: u64,
+    pub is_final: bool,
 }
 
 /// Processed audio chunk (post-VAD) for recording
@@ -116,6 +137,9 @@ pub struct RecordingState {
     last_error: Mutex<Option<AudioError>>,
     error_callback: Mutex<Option<Box<dyn Fn(&AudioError) + Send + Sync>>>,
 
+    // Non-speech events (applause, long silence) detected by the pipeline
+    acoustic_event_callback: Mutex<Option<Box<dyn Fn(&AcousticEvent) + Send + Sync>>>,
+
     // Statistics
     stats: Mutex<RecordingStats>,
 
@@ -141,6 +165,7 @@ impl RecordingState {
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
             error_callback: Mutex::new(None),
+            acoustic_event_callback: Mutex::new(None),
             stats: Mutex::new(RecordingStats::default()),
             recording_start: Mutex::new(None),
             pause_start: Mutex::new(None),
@@ -323,6 +348,21 @@ impl RecordingState {
         }
     }
 
+    // Acoustic events
+    pub fn set_acoustic_event_callback<F>(&self, callback: F)
+    where
+        F: Fn(&AcousticEvent) + Send + Sync + 'static,
+    {
+        *self.acoustic_event_callback.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    pub fn report_acoustic_event(&self, event: &AcousticEvent) {
+        log::info!("🔉 Acoustic event {} at {:.1}s-{:.1}s", event.annotation(), event.start, event.end);
+        if let Some(callback) = self.acoustic_event_callback.lock().unwrap().as_ref() {
+            callback(event);
+        }
+    }
+
     pub fn get_error_count(&self) -> u32 {
         self.error_count.load(Ordering::SeqCst)
     }
@@ -429,6 +469,7 @@ impl Default for RecordingState {
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
             error_callback: Mutex::new(None),
+            acoustic_event_callback: Mutex::new(None),
             stats: Mutex::new(RecordingStats::default()),
             recording_start: Mutex::new(None),
             pause_start: Mutex::new(None),
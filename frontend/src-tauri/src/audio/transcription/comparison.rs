@@ -0,0 +1,556 @@
+// audio/transcription/comparison.rs
+//
+// Provider A/B comparison mode. Every finalized segment from the primary engine
+// is also sent to a second configured provider; both transcripts are stored side
+// by side with a word-level diff and WER, so users can pick a provider/model
+// based on their own meetings rather than benchmarks.
+
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tauri_plugin_store::StoreExt;
+
+use super::groq_provider::GroqProvider;
+use super::parakeet_provider::ParakeetProvider;
+use super::provider::TranscriptionProvider;
+use super::whisper_provider::WhisperProvider;
+use super::worker::TranscriptUpdate;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+
+const STORE_FILE: &str = "transcription_comparison.json";
+const STORE_KEY: &str = "config";
+const COMPARISONS_DIR: &str = "transcript_comparisons";
+
+/// Secondary provider settings for comparison mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComparisonConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Provider id as used in the transcript config ("localWhisper", "parakeet", "groq")
+    #[serde(default)]
+    pub provider: String,
+    #[serde(default)]
+    pub model: String,
+}
+
+/// One step of the word alignment between the two transcripts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum DiffOp {
+    Equal { word: String },
+    Substitute { reference: String, hypothesis: String },
+    /// Word only in the reference (primary) transcript
+    Delete { reference: String },
+    /// Word only in the hypothesis (secondary) transcript
+    Insert { hypothesis: String },
+}
+
+/// Word-level comparison of two transcripts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WerDiff {
+    /// (substitutions + deletions + insertions) / reference words
+    pub wer: f64,
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub reference_words: usize,
+    pub ops: Vec<DiffOp>,
+}
+
+impl WerDiff {
+    pub fn errors(&self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+}
+
+/// Lowercase and drop punctuation so "Okay," and "okay" count as the same word
+fn normalize_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| {
+            w.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Word error rate of `hypothesis` against `reference`, with the aligned diff
+///
+/// Neither transcript is ground truth here; the primary engine's output is used
+/// as the reference so the number reads as "how much would change if we switched".
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> WerDiff {
+    let r = normalize_words(reference);
+    let h = normalize_words(hypothesis);
+
+    // Levenshtein distance table over words
+    let mut dist = vec![vec![0usize; h.len() + 1]; r.len() + 1];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=h.len() {
+        dist[0][j] = j;
+    }
+    for i in 1..=r.len() {
+        for j in 1..=h.len() {
+            let cost = if r[i - 1] == h[j - 1] { 0 } else { 1 };
+            dist[i][j] = (dist[i - 1][j - 1] + cost)
+                .min(dist[i - 1][j] + 1)
+                .min(dist[i][j - 1] + 1);
+        }
+    }
+
+    // Walk back from the bottom-right corner to recover the alignment
+    let mut ops = Vec::with_capacity(r.len().max(h.len()));
+    let (mut substitutions, mut deletions, mut insertions) = (0, 0, 0);
+    let (mut i, mut j) = (r.len(), h.len());
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && r[i - 1] == h[j - 1] && dist[i][j] == dist[i - 1][j - 1] {
+            ops.push(DiffOp::Equal { word: r[i - 1].clone() });
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && dist[i][j] == dist[i - 1][j - 1] + 1 {
+            ops.push(DiffOp::Substitute {
+                reference: r[i - 1].clone(),
+                hypothesis: h[j - 1].clone(),
+            });
+            substitutions += 1;
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && dist[i][j] == dist[i - 1][j] + 1 {
+            ops.push(DiffOp::Delete { reference: r[i - 1].clone() });
+            deletions += 1;
+            i -= 1;
+        } else {
+            ops.push(DiffOp::Insert { hypothesis: h[j - 1].clone() });
+            insertions += 1;
+            j -= 1;
+        }
+    }
+    ops.reverse();
+
+    let errors = substitutions + deletions + insertions;
+    let wer = if r.is_empty() {
+        if h.is_empty() { 0.0 } else { 1.0 }
+    } else {
+        errors as f64 / r.len() as f64
+    };
+
+    WerDiff {
+        wer,
+        substitutions,
+        deletions,
+        insertions,
+        reference_words: r.len(),
+        ops,
+    }
+}
+
+/// One segment transcribed by both providers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonSegment {
+    pub sequence_id: u64,
+    pub audio_start_time: f64,
+    pub audio_end_time: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    pub primary_text: String,
+    pub secondary_text: String,
+    pub diff: WerDiff,
+}
+
+/// Aggregate WER over a whole session (weighted by reference words)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComparisonSummary {
+    pub segments: usize,
+    pub reference_words: usize,
+    pub errors: usize,
+    pub wer: f64,
+}
+
+/// Side-by-side transcripts for one recording session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonSession {
+    pub id: String,
+    pub started_at: String,
+    pub primary_provider: String,
+    pub primary_model: String,
+    pub secondary_provider: String,
+    pub secondary_model: String,
+    pub summary: ComparisonSummary,
+    pub segments: Vec<ComparisonSegment>,
+}
+
+/// Session listing entry (segments omitted)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonSessionInfo {
+    pub id: String,
+    pub started_at: String,
+    pub primary_provider: String,
+    pub primary_model: String,
+    pub secondary_provider: String,
+    pub secondary_model: String,
+    pub summary: ComparisonSummary,
+}
+
+/// Collects comparison segments for the current recording and keeps the session file up to date
+pub struct ComparisonRecorder {
+    path: PathBuf,
+    session: Mutex<ComparisonSession>,
+}
+
+impl ComparisonRecorder {
+    pub fn new<R: Runtime>(
+        app: &AppHandle<R>,
+        primary_provider: String,
+        primary_model: String,
+        config: &ComparisonConfig,
+    ) -> Result<Self, String> {
+        let dir = comparisons_dir(app)?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create comparisons directory: {}", e))?;
+
+        let now = Utc::now();
+        let id = now.format("%Y%m%d_%H%M%S").to_string();
+        Ok(Self {
+            path: dir.join(format!("{}.json", id)),
+            session: Mutex::new(ComparisonSession {
+                id,
+                started_at: now.to_rfc3339(),
+                primary_provider,
+                primary_model,
+                secondary_provider: config.provider.clone(),
+                secondary_model: config.model.clone(),
+                summary: ComparisonSummary::default(),
+                segments: Vec::new(),
+            }),
+        })
+    }
+
+    /// Add a segment, update the running summary and rewrite the session file
+    pub fn record(&self, segment: ComparisonSegment) -> ComparisonSummary {
+        let mut session = match self.session.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        session.summary.segments += 1;
+        session.summary.reference_words += segment.diff.reference_words;
+        session.summary.errors += segment.diff.errors();
+        session.summary.wer = if session.summary.reference_words > 0 {
+            session.summary.errors as f64 / session.summary.reference_words as f64
+        } else {
+            0.0
+        };
+
+        // Secondary results can finish out of order
+        let pos = session
+            .segments
+            .partition_point(|s| s.audio_start_time <= segment.audio_start_time);
+        session.segments.insert(pos, segment);
+
+        match serde_json::to_string_pretty(&*session) {
+            Ok(json) => {
+                if let Err(e) = std::fs::write(&self.path, json) {
+                    warn!("Failed to write comparison session {}: {}", self.path.display(), e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize comparison session: {}", e),
+        }
+
+        session.summary.clone()
+    }
+}
+
+/// Secondary provider plus recorder for one recording session
+pub struct ComparisonRunner {
+    provider: Arc<dyn TranscriptionProvider>,
+    recorder: ComparisonRecorder,
+}
+
+impl ComparisonRunner {
+    /// Set up comparison mode for a new session if it is enabled; failures only disable comparison
+    pub async fn start<R: Runtime>(
+        app: &AppHandle<R>,
+        primary_provider: &str,
+        primary_model: &str,
+    ) -> Option<Arc<Self>> {
+        let config = load_comparison_config(app);
+        if !config.enabled {
+            return None;
+        }
+
+        let provider = match init_comparison_provider(app, &config).await {
+            Ok(provider) => provider,
+            Err(e) => {
+                warn!("⚠️ Transcription comparison disabled for this session: {}", e);
+                let _ = app.emit("transcription-warning", format!("Provider comparison disabled: {}", e));
+                return None;
+            }
+        };
+
+        let recorder = match ComparisonRecorder::new(
+            app,
+            primary_provider.to_string(),
+            primary_model.to_string(),
+            &config,
+        ) {
+            Ok(recorder) => recorder,
+            Err(e) => {
+                warn!("⚠️ Transcription comparison disabled for this session: {}", e);
+                return None;
+            }
+        };
+
+        info!(
+            "⚖️ Comparing {} ({}) against {} ({})",
+            primary_provider, primary_model, config.provider, config.model
+        );
+        Some(Arc::new(Self { provider, recorder }))
+    }
+
+    /// Transcribe the same audio with the secondary provider in the background and record the diff
+    pub fn compare<R: Runtime>(self: &Arc<Self>, app: &AppHandle<R>, samples: Vec<f32>, update: &TranscriptUpdate) {
+        let runner = self.clone();
+        let app = app.clone();
+        let sequence_id = update.sequence_id;
+        let audio_start_time = update.audio_start_time;
+        let audio_end_time = update.audio_end_time;
+        let speaker = update.speaker.clone();
+        let primary_text = update.text.clone();
+
+        tokio::spawn(async move {
+            let language = crate::get_language_preference_internal();
+            let secondary_text = match runner.provider.transcribe(samples, language).await {
+                Ok(result) => super::replacements::apply_replacements(result.text.trim()),
+                Err(e) => {
+                    warn!("Comparison provider failed on segment {}: {}", sequence_id, e);
+                    return;
+                }
+            };
+
+            let diff = word_error_rate(&primary_text, &secondary_text);
+            let segment = ComparisonSegment {
+                sequence_id,
+                audio_start_time,
+                audio_end_time,
+                speaker,
+                primary_text,
+                secondary_text,
+                diff,
+            };
+            let _ = app.emit("transcript-comparison", &segment);
+
+            let summary = runner.recorder.record(segment);
+            let _ = app.emit("transcript-comparison-summary", &summary);
+        });
+    }
+}
+
+fn comparisons_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(COMPARISONS_DIR))
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+/// Load the comparison settings from the store
+pub fn load_comparison_config<R: Runtime>(app: &AppHandle<R>) -> ComparisonConfig {
+    let store = match app.store(STORE_FILE) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!("Failed to access comparison store: {}, comparison mode disabled", e);
+            return ComparisonConfig::default();
+        }
+    };
+
+    match store.get(STORE_KEY) {
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            warn!("Failed to deserialize comparison config: {}, comparison mode disabled", e);
+            ComparisonConfig::default()
+        }),
+        None => ComparisonConfig::default(),
+    }
+}
+
+/// Build the secondary provider described by the comparison config
+///
+/// Local engines are process-wide singletons, so a local secondary only works when it is a
+/// different engine from the primary (e.g. Parakeet primary, Whisper secondary) and its model
+/// is already loaded.
+pub async fn init_comparison_provider<R: Runtime>(
+    app: &AppHandle<R>,
+    config: &ComparisonConfig,
+) -> Result<Arc<dyn TranscriptionProvider>, String> {
+    match config.provider.as_str() {
+        "groq" => {
+            let state = app.state::<AppState>();
+            let api_key = SettingsRepository::get_transcript_api_key(state.db_manager.pool(), "groq")
+                .await
+                .map_err(|e| format!("Failed to get Groq API key: {}", e))?
+                .filter(|key| !key.trim().is_empty())
+                .ok_or_else(|| "Comparison provider Groq requires an API key".to_string())?;
+            Ok(Arc::new(GroqProvider::new(api_key, config.model.clone())))
+        }
+        "parakeet" => {
+            let engine = crate::parakeet_engine::commands::PARAKEET_ENGINE
+                .lock()
+                .map_err(|e| format!("Failed to access Parakeet engine: {}", e))?
+                .as_ref()
+                .cloned()
+                .ok_or_else(|| "Parakeet engine not initialized".to_string())?;
+            if !engine.is_model_loaded().await {
+                return Err("Load a Parakeet model before using it for comparison".to_string());
+            }
+            Ok(Arc::new(ParakeetProvider::new(engine)))
+        }
+        "localWhisper" => {
+            let engine = crate::whisper_engine::commands::WHISPER_ENGINE
+                .lock()
+                .map_err(|e| format!("Failed to access Whisper engine: {}", e))?
+                .as_ref()
+                .cloned()
+                .ok_or_else(|| "Whisper engine not initialized".to_string())?;
+            if !engine.is_model_loaded().await {
+                return Err("Load a Whisper model before using it for comparison".to_string());
+            }
+            Ok(Arc::new(WhisperProvider::new(engine)))
+        }
+        other => Err(format!("Unsupported comparison provider: {}", other)),
+    }
+}
+
+#[tauri::command]
+pub async fn get_transcription_comparison_config<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<ComparisonConfig, String> {
+    Ok(load_comparison_config(&app))
+}
+
+#[tauri::command]
+pub async fn set_transcription_comparison_config<R: Runtime>(
+    app: AppHandle<R>,
+    config: ComparisonConfig,
+) -> Result<(), String> {
+    if config.enabled && config.provider.trim().is_empty() {
+        return Err("Select a provider to compare against".to_string());
+    }
+
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("Failed to access comparison store: {}", e))?;
+    let value = serde_json::to_value(&config)
+        .map_err(|e| format!("Failed to serialize comparison config: {}", e))?;
+    store.set(STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save comparison config: {}", e))?;
+
+    info!(
+        "Saved transcription comparison config (enabled: {}, provider: {}, model: {})",
+        config.enabled, config.provider, config.model
+    );
+    Ok(())
+}
+
+/// List recorded comparison sessions, newest first
+#[tauri::command]
+pub async fn list_transcript_comparisons<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<ComparisonSessionInfo>, String> {
+    let dir = comparisons_dir(&app)?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read comparisons directory: {}", e))?;
+
+    let mut sessions = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let session = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str::<ComparisonSession>(&json).map_err(|e| e.to_string()));
+        match session {
+            Ok(session) => sessions.push(ComparisonSessionInfo {
+                id: session.id,
+                started_at: session.started_at,
+                primary_provider: session.primary_provider,
+                primary_model: session.primary_model,
+                secondary_provider: session.secondary_provider,
+                secondary_model: session.secondary_model,
+                summary: session.summary,
+            }),
+            Err(e) => warn!("Skipping unreadable comparison file {}: {}", path.display(), e),
+        }
+    }
+
+    sessions.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(sessions)
+}
+
+#[tauri::command]
+pub async fn get_transcript_comparison<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+) -> Result<ComparisonSession, String> {
+    // Ids are timestamps; reject anything that could escape the directory
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("Invalid comparison id: {}", id));
+    }
+
+    let path = comparisons_dir(&app)?.join(format!("{}.json", id));
+    let json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read comparison {}: {}", id, e))?;
+    serde_json::from_str(&json).map_err(|e| format!("Failed to parse comparison {}: {}", id, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_transcripts() {
+        let diff = word_error_rate("Let's ship it on Friday.", "let's ship it on friday");
+        assert_eq!(diff.wer, 0.0);
+        assert_eq!(diff.errors(), 0);
+        assert_eq!(diff.reference_words, 5);
+    }
+
+    #[test]
+    fn test_counts_each_edit_type() {
+        // "the" deleted, "quick" -> "quack", "today" inserted
+        let diff = word_error_rate("the quick brown fox", "quack brown fox today");
+        assert_eq!(diff.substitutions, 1);
+        assert_eq!(diff.deletions, 1);
+        assert_eq!(diff.insertions, 1);
+        assert!((diff.wer - 0.75).abs() < 1e-9);
+        assert_eq!(
+            diff.ops,
+            vec![
+                DiffOp::Delete { reference: "the".to_string() },
+                DiffOp::Substitute { reference: "quick".to_string(), hypothesis: "quack".to_string() },
+                DiffOp::Equal { word: "brown".to_string() },
+                DiffOp::Equal { word: "fox".to_string() },
+                DiffOp::Insert { hypothesis: "today".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_reference() {
+        assert_eq!(word_error_rate("", "").wer, 0.0);
+        let diff = word_error_rate("", "hello there");
+        assert_eq!(diff.wer, 1.0);
+        assert_eq!(diff.insertions, 2);
+    }
+}
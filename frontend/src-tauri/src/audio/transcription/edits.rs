@@ -0,0 +1,320 @@
+// audio/transcription/edits.rs
+//
+// Manual transcript corrections: edit a segment's text, merge two adjacent segments,
+// split one in two, or fix which speaker said something. Every change is stored as a
+// revision with the affected segments before and after it, so the recognized text is
+// never lost and any revision can be reverted as long as later edits left its segments
+// alone. transcripts.json in the meeting folder keeps the text as recorded.
+
+use serde_json::json;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::database::models::{Transcript, TranscriptRevision};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::database::repositories::transcript_revision::TranscriptRevisionsRepository;
+use crate::state::AppState;
+
+fn clean_text(text: &str) -> Result<String, String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return Err("Segment text cannot be empty".to_string());
+    }
+    Ok(text)
+}
+
+/// End of a segment in recording seconds, if it is timed
+fn segment_end(segment: &Transcript) -> Option<f64> {
+    segment
+        .audio_end_time
+        .or_else(|| Some(segment.audio_start_time? + segment.duration?))
+}
+
+/// Split before character `at`; the time is divided in proportion to the text
+fn split_segment(segment: &Transcript, at: usize, new_id: String) -> Result<(Transcript, Transcript), String> {
+    let chars: Vec<char> = segment.transcript.chars().collect();
+    if at == 0 || at >= chars.len() {
+        return Err("Split inside the segment text".to_string());
+    }
+    let first_text: String = chars[..at].iter().collect();
+    let second_text: String = chars[at..].iter().collect();
+    let (first_text, second_text) = match (clean_text(&first_text), clean_text(&second_text)) {
+        (Ok(first), Ok(second)) => (first, second),
+        _ => return Err("Both parts of a split segment need text".to_string()),
+    };
+
+    let mut first = segment.clone();
+    let mut second = segment.clone();
+    second.id = new_id;
+    // Analysis results described the whole segment
+    for part in [&mut first, &mut second] {
+        part.sentiment = None;
+        part.tension = None;
+    }
+    if let (Some(start), Some(end)) = (segment.audio_start_time, segment_end(segment)) {
+        let split_time = start + (end - start) * at as f64 / chars.len() as f64;
+        first.audio_end_time = Some(split_time);
+        first.duration = Some(split_time - start);
+        second.audio_start_time = Some(split_time);
+        second.audio_end_time = Some(end);
+        second.duration = Some(end - split_time);
+    }
+    first.transcript = first_text;
+    second.transcript = second_text;
+    Ok((first, second))
+}
+
+/// `second` appended to `first`, keeping `first`'s id and speaker
+fn merge_segments(first: &Transcript, second: &Transcript) -> Transcript {
+    let mut merged = first.clone();
+    merged.transcript = format!("{} {}", first.transcript.trim(), second.transcript.trim());
+    if let (Some(start), Some(end)) = (first.audio_start_time, segment_end(second).or(segment_end(first))) {
+        merged.audio_end_time = Some(end);
+        merged.duration = Some(end - start);
+    }
+    let lowest = |a: Option<f64>, b: Option<f64>| match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    merged.confidence = lowest(first.confidence, second.confidence);
+    merged.quality_score = lowest(first.quality_score, second.quality_score);
+    merged.overlapping_speech = first.overlapping_speech || second.overlapping_speech;
+    merged.needs_review = first.needs_review || second.needs_review;
+    merged.sentiment = None;
+    merged.tension = None;
+    merged
+}
+
+/// Whether a segment still reads as a revision left it
+fn unchanged_since(current: &Transcript, expected: &Transcript) -> bool {
+    current.transcript == expected.transcript
+        && current.speaker_label == expected.speaker_label
+        && current.audio_start_time == expected.audio_start_time
+        && current.audio_end_time == expected.audio_end_time
+}
+
+fn parse_segments(json: &str) -> Result<Vec<Transcript>, String> {
+    serde_json::from_str(json).map_err(|e| format!("Stored revision is invalid: {}", e))
+}
+
+async fn load_segment(pool: &sqlx::SqlitePool, segment_id: &str) -> Result<Transcript, String> {
+    TranscriptsRepository::get_segment(pool, segment_id)
+        .await
+        .map_err(|e| format!("Failed to load segment: {}", e))?
+        .ok_or_else(|| format!("Transcript segment not found: {}", segment_id))
+}
+
+async fn record<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: &str,
+    before: &[Transcript],
+    after: &[Transcript],
+    reverts: Option<&str>,
+) -> Result<TranscriptRevision, String> {
+    let meeting_id = before
+        .first()
+        .map(|segment| segment.meeting_id.clone())
+        .ok_or_else(|| "No segments to change".to_string())?;
+    let state = app.state::<AppState>();
+    let revision =
+        TranscriptRevisionsRepository::apply(state.db_manager.pool(), &meeting_id, kind, before, after, reverts)
+            .await
+            .map_err(|e| format!("Failed to save transcript edit: {}", e))?;
+    info!("Transcript of {} edited ({}, revision {})", meeting_id, kind, revision.id);
+    let _ = app.emit(
+        "transcript-edited",
+        json!({ "meeting_id": meeting_id, "revision_id": revision.id }),
+    );
+    crate::summary::vector_index::spawn_index_update(app, meeting_id);
+    Ok(revision)
+}
+
+/// Replace the text of a segment
+#[command]
+pub async fn edit_transcript_segment<R: Runtime>(
+    app: AppHandle<R>,
+    segment_id: String,
+    text: String,
+) -> Result<TranscriptRevision, String> {
+    let state = app.state::<AppState>();
+    let segment = load_segment(state.db_manager.pool(), &segment_id).await?;
+    let text = clean_text(&text)?;
+    if text == segment.transcript {
+        return Err("The text is unchanged".to_string());
+    }
+    let mut edited = segment.clone();
+    edited.transcript = text;
+    edited.sentiment = None;
+    edited.tension = None;
+    record(&app, "text", &[segment], &[edited], None).await
+}
+
+/// Split a segment before character `offset` of its text
+#[command]
+pub async fn split_transcript_segment<R: Runtime>(
+    app: AppHandle<R>,
+    segment_id: String,
+    offset: usize,
+) -> Result<TranscriptRevision, String> {
+    let state = app.state::<AppState>();
+    let segment = load_segment(state.db_manager.pool(), &segment_id).await?;
+    let (first, second) = split_segment(&segment, offset, format!("transcript-{}", Uuid::new_v4()))?;
+    record(&app, "split", &[segment], &[first, second], None).await
+}
+
+/// Merge a segment into the one right before it
+#[command]
+pub async fn merge_transcript_segments<R: Runtime>(
+    app: AppHandle<R>,
+    first_id: String,
+    second_id: String,
+) -> Result<TranscriptRevision, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let first = load_segment(pool, &first_id).await?;
+    let segments = TranscriptsRepository::list_for_meeting(pool, &first.meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript: {}", e))?;
+    let position = segments
+        .iter()
+        .position(|segment| segment.id == first_id)
+        .ok_or_else(|| format!("Transcript segment not found: {}", first_id))?;
+    let second = segments
+        .get(position + 1)
+        .filter(|segment| segment.id == second_id)
+        .ok_or_else(|| "Only a segment and the one right after it can be merged".to_string())?
+        .clone();
+    let merged = merge_segments(&first, &second);
+    record(&app, "merge", &[first, second], &[merged], None).await
+}
+
+/// Attribute segments to another speaker
+#[command]
+pub async fn set_segments_speaker<R: Runtime>(
+    app: AppHandle<R>,
+    segment_ids: Vec<String>,
+    speaker: String,
+) -> Result<TranscriptRevision, String> {
+    let speaker = speaker.trim();
+    if speaker.is_empty() {
+        return Err("Speaker name cannot be empty".to_string());
+    }
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut before = Vec::new();
+    for segment_id in &segment_ids {
+        before.push(load_segment(pool, segment_id).await?);
+    }
+    if before.windows(2).any(|pair| pair[0].meeting_id != pair[1].meeting_id) {
+        return Err("All segments must belong to the same meeting".to_string());
+    }
+    let after: Vec<Transcript> = before
+        .iter()
+        .cloned()
+        .map(|mut segment| {
+            segment.speaker_label = Some(speaker.to_string());
+            segment
+        })
+        .collect();
+    record(&app, "speaker", &before, &after, None).await
+}
+
+/// A meeting's transcript edits, newest first
+#[command]
+pub async fn list_transcript_revisions<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<TranscriptRevision>, String> {
+    let state = app.state::<AppState>();
+    TranscriptRevisionsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcript history: {}", e))
+}
+
+/// Undo a revision, restoring its segments as they were before it
+///
+/// The undo is itself a revision, so it can be reverted in turn.
+#[command]
+pub async fn revert_transcript_revision<R: Runtime>(
+    app: AppHandle<R>,
+    revision_id: String,
+) -> Result<TranscriptRevision, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let revision = TranscriptRevisionsRepository::get(pool, &revision_id)
+        .await
+        .map_err(|e| format!("Failed to load revision: {}", e))?
+        .ok_or_else(|| format!("Revision {} not found", revision_id))?;
+    if revision.reverted_by.is_some() {
+        return Err("This edit has already been reverted".to_string());
+    }
+
+    let edited = parse_segments(&revision.after_segments)?;
+    let original = parse_segments(&revision.before_segments)?;
+    let mut current = Vec::new();
+    for expected in &edited {
+        let segment = TranscriptsRepository::get_segment(pool, &expected.id)
+            .await
+            .map_err(|e| format!("Failed to load segment: {}", e))?
+            .filter(|segment| unchanged_since(segment, expected))
+            .ok_or_else(|| "Later edits changed these segments; revert those first".to_string())?;
+        current.push(segment);
+    }
+    record(&app, "revert", &current, &original, Some(&revision.id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(id: &str, text: &str, start: f64, end: f64) -> Transcript {
+        Transcript {
+            id: id.to_string(),
+            meeting_id: "meeting-1".to_string(),
+            transcript: text.to_string(),
+            timestamp: "10:00:00".to_string(),
+            summary: None,
+            action_items: None,
+            key_points: None,
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            quality_score: Some(0.9),
+            speaker: Some("system".to_string()),
+            speaker_label: Some("Speaker 1".to_string()),
+            overlapping_speech: false,
+            sentiment: Some("neutral".to_string()),
+            tension: Some(0.1),
+            language: Some("en".to_string()),
+            confidence: Some(0.8),
+            needs_review: false,
+        }
+    }
+
+    #[test]
+    fn test_split_and_merge_round_trip_timing() {
+        let original = segment("t1", "Ship it Friday. Who owns QA?", 10.0, 16.0);
+        let (first, second) = split_segment(&original, 15, "t2".to_string()).unwrap();
+        assert_eq!(first.id, "t1");
+        assert_eq!(first.transcript, "Ship it Friday.");
+        assert_eq!(second.transcript, "Who owns QA?");
+        assert!((first.audio_end_time.unwrap() - 13.214).abs() < 0.01);
+        assert_eq!(second.audio_start_time, first.audio_end_time);
+        assert_eq!(second.audio_end_time, Some(16.0));
+        assert!(split_segment(&original, 0, "t3".to_string()).is_err());
+        assert!(split_segment(&segment("t4", "Hi ", 0.0, 1.0), 2, "t5".to_string()).is_err());
+
+        let mut low = second.clone();
+        low.confidence = Some(0.4);
+        low.needs_review = true;
+        let merged = merge_segments(&first, &low);
+        assert_eq!(merged.id, "t1");
+        assert_eq!(merged.transcript, "Ship it Friday. Who owns QA?");
+        assert_eq!((merged.audio_start_time, merged.audio_end_time), (Some(10.0), Some(16.0)));
+        assert_eq!(merged.confidence, Some(0.4));
+        assert!(merged.needs_review);
+        assert!(unchanged_since(&merged, &merged.clone()));
+        assert!(!unchanged_since(&merged, &first));
+    }
+}
@@ -2,7 +2,7 @@
 //
 // TranscriptionEngine enum and model initialization/validation logic.
 
-use super::provider::TranscriptionProvider;
+use super::provider::{TranscriptResult, TranscriptionError, TranscriptionProvider};
 use super::groq_provider::GroqProvider;
 use log::{info, warn};
 use std::sync::Arc;
@@ -46,6 +46,39 @@ impl TranscriptionEngine {
             Self::Provider(provider) => provider.provider_name(),
         }
     }
+
+    /// Transcribe 16kHz mono samples outside the live pipeline (no events emitted)
+    pub async fn transcribe(
+        &self,
+        samples: Vec<f32>,
+        language: Option<String>,
+    ) -> Result<TranscriptResult, TranscriptionError> {
+        match self {
+            Self::Whisper(engine) => engine
+                .transcribe_audio_detailed(samples, language)
+                .await
+                .map(|result| TranscriptResult {
+                    text: result.text.trim().to_string(),
+                    confidence: Some(result.confidence),
+                    is_partial: result.is_partial,
+                    no_speech_prob: Some(result.no_speech_prob),
+                    language: result.language,
+                })
+                .map_err(|e| TranscriptionError::EngineFailed(e.to_string())),
+            Self::Parakeet(engine) => engine
+                .transcribe_audio(samples)
+                .await
+                .map(|text| TranscriptResult {
+                    text: text.trim().to_string(),
+                    confidence: None,
+                    is_partial: false,
+                    no_speech_prob: None,
+                    language: None,
+                })
+                .map_err(|e| TranscriptionError::EngineFailed(e.to_string())),
+            Self::Provider(provider) => provider.transcribe(samples, language).await,
+        }
+    }
 }
 
 // ============================================================================
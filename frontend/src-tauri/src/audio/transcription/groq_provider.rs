@@ -66,7 +66,14 @@ impl TranscriptionProvider for GroqProvider {
             .multipart(form)
             .send()
             .await
-            .map_err(|e| TranscriptionError::EngineFailed(format!("Groq API request failed: {}", e)))?;
+            .map_err(|e| {
+                // Connectivity problems are retryable - the worker queues the chunk for replay
+                if e.is_connect() || e.is_timeout() || e.is_request() {
+                    TranscriptionError::NetworkUnavailable(format!("Groq API unreachable: {}", e))
+                } else {
+                    TranscriptionError::EngineFailed(format!("Groq API request failed: {}", e))
+                }
+            })?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -86,6 +93,8 @@ impl TranscriptionProvider for GroqProvider {
             text: groq_response.text,
             confidence: None, // Groq doesn't provide confidence scores
             is_partial: false,
+            no_speech_prob: None,
+            language: None,
         })
     }
 
@@ -0,0 +1,242 @@
+// audio/transcription/hallucination.rs
+//
+// Detection of classic Whisper-style hallucinations: runaway repeated n-grams,
+// stock phrases ("thanks for watching") emitted on near-silent audio, and text
+// produced for chunks the model itself considers non-speech.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Outcome of running a transcript segment through the hallucination filter
+#[derive(Debug, Clone, PartialEq)]
+pub enum HallucinationVerdict {
+    /// Nothing suspicious
+    Clean,
+    /// Probably real speech but suspicious - keep the text and flag it with a reason
+    Flagged(String),
+    /// Almost certainly hallucinated - drop the segment
+    Suppressed(String),
+}
+
+/// Tunable thresholds for the filter
+#[derive(Debug, Clone)]
+pub struct HallucinationFilterConfig {
+    /// RMS below which a chunk is treated as near-silent
+    pub silence_rms: f32,
+    /// Estimated no-speech probability above which text is suspicious
+    pub no_speech_threshold: f32,
+    /// Minimum consecutive repeats of an n-gram to count as a loop
+    pub min_repeats: usize,
+    /// Share of words covered by repeated n-grams before the segment is suppressed
+    pub repetition_suppress_ratio: f32,
+}
+
+impl Default for HallucinationFilterConfig {
+    fn default() -> Self {
+        Self {
+            silence_rms: 0.005,
+            no_speech_threshold: 0.6,
+            min_repeats: 3,
+            repetition_suppress_ratio: 0.6,
+        }
+    }
+}
+
+// Stock outputs Whisper produces on silence/noise (mostly learned from video subtitles)
+static STOCK_PHRASES: Lazy<Vec<Regex>> = Lazy::new(|| {
+    [
+        r"^thanks? (you )?for watching",
+        r"^thank you\.?$",
+        r"^please subscribe",
+        r"(like and )?subscribe to (my|our|the) channel",
+        r"^subtitles? (by|created by)",
+        r"^transcribed by",
+        r"^(translated|captioned) by",
+        r"^see you (in the )?next (time|video)",
+        r"^you$",
+        r"^bye\.?$",
+        r"^\[?(music|applause|silence|blank_audio)\]?$",
+        r"amara\.org",
+    ]
+    .iter()
+    .map(|p| Regex::new(&format!("(?i){}", p)).unwrap())
+    .collect()
+});
+
+/// Hallucination filter for transcript segments
+#[derive(Debug, Clone, Default)]
+pub struct HallucinationFilter {
+    config: HallucinationFilterConfig,
+}
+
+impl HallucinationFilter {
+    pub fn new(config: HallucinationFilterConfig) -> Self {
+        Self { config }
+    }
+
+    /// Evaluate a segment
+    ///
+    /// # Arguments
+    /// * `text` - Transcribed text
+    /// * `chunk_rms` - RMS energy of the source audio chunk
+    /// * `no_speech_prob` - Provider's estimate that the chunk contains no speech (if available)
+    pub fn check(&self, text: &str, chunk_rms: f32, no_speech_prob: Option<f32>) -> HallucinationVerdict {
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return HallucinationVerdict::Clean;
+        }
+
+        let near_silent = chunk_rms < self.config.silence_rms;
+        let normalized = normalize(trimmed);
+
+        // 1. Stock phrases are only trusted when there was real audio behind them
+        if STOCK_PHRASES.iter().any(|re| re.is_match(&normalized)) {
+            if near_silent || no_speech_prob.map_or(false, |p| p >= self.config.no_speech_threshold) {
+                return HallucinationVerdict::Suppressed(format!(
+                    "stock phrase on near-silent audio (rms: {:.4})",
+                    chunk_rms
+                ));
+            }
+            return HallucinationVerdict::Flagged("stock phrase commonly hallucinated".to_string());
+        }
+
+        // 2. Repetition loops ("okay okay okay okay ...")
+        let words: Vec<&str> = normalized.split_whitespace().collect();
+        let repeated_ratio = repeated_ngram_ratio(&words, self.config.min_repeats);
+        if repeated_ratio >= self.config.repetition_suppress_ratio {
+            return HallucinationVerdict::Suppressed(format!(
+                "repeated n-gram loop covers {:.0}% of text",
+                repeated_ratio * 100.0
+            ));
+        }
+
+        // 3. Text on a chunk the model considers non-speech
+        if let Some(prob) = no_speech_prob {
+            if prob >= self.config.no_speech_threshold {
+                if near_silent {
+                    return HallucinationVerdict::Suppressed(format!(
+                        "text on non-speech audio (no_speech_prob: {:.2}, rms: {:.4})",
+                        prob, chunk_rms
+                    ));
+                }
+                return HallucinationVerdict::Flagged(format!(
+                    "high no-speech probability ({:.2})",
+                    prob
+                ));
+            }
+        }
+
+        if repeated_ratio > 0.0 {
+            return HallucinationVerdict::Flagged(format!(
+                "repeated phrases ({:.0}% of text)",
+                repeated_ratio * 100.0
+            ));
+        }
+
+        HallucinationVerdict::Clean
+    }
+}
+
+/// Lowercase and strip punctuation so "Thanks for watching!" and "thanks for watching" compare equal
+fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() || c == '[' || c == ']' || c == '_' || c == '.' {
+            c.to_ascii_lowercase()
+        } else {
+            ' '
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fraction of words that belong to an n-gram (n = 1..=4) repeated at least
+/// `min_repeats` times back to back
+fn repeated_ngram_ratio(words: &[&str], min_repeats: usize) -> f32 {
+    if words.len() < min_repeats {
+        return 0.0;
+    }
+
+    let clean: Vec<String> = words
+        .iter()
+        .map(|w| w.trim_matches('.').to_string())
+        .collect();
+    let mut covered = vec![false; clean.len()];
+
+    for n in 1..=4usize {
+        let mut i = 0;
+        while i + n <= clean.len() {
+            let mut repeats = 1;
+            while i + (repeats + 1) * n <= clean.len()
+                && clean[i..i + n] == clean[i + repeats * n..i + (repeats + 1) * n]
+            {
+                repeats += 1;
+            }
+
+            if repeats >= min_repeats {
+                for flag in covered.iter_mut().skip(i).take(repeats * n) {
+                    *flag = true;
+                }
+                i += repeats * n;
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    covered.iter().filter(|c| **c).count() as f32 / clean.len() as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_speech_passes() {
+        let filter = HallucinationFilter::default();
+        let verdict = filter.check("Let's review the quarterly numbers first.", 0.05, Some(0.1));
+        assert_eq!(verdict, HallucinationVerdict::Clean);
+    }
+
+    #[test]
+    fn test_stock_phrase_on_silence_suppressed() {
+        let filter = HallucinationFilter::default();
+        let verdict = filter.check("Thanks for watching!", 0.001, None);
+        assert!(matches!(verdict, HallucinationVerdict::Suppressed(_)));
+    }
+
+    #[test]
+    fn test_stock_phrase_with_audio_flagged() {
+        let filter = HallucinationFilter::default();
+        let verdict = filter.check("Thank you.", 0.08, Some(0.1));
+        assert!(matches!(verdict, HallucinationVerdict::Flagged(_)));
+    }
+
+    #[test]
+    fn test_repetition_loop_suppressed() {
+        let filter = HallucinationFilter::default();
+        let verdict = filter.check("okay okay okay okay okay okay", 0.05, None);
+        assert!(matches!(verdict, HallucinationVerdict::Suppressed(_)));
+    }
+
+    #[test]
+    fn test_high_no_speech_prob() {
+        let filter = HallucinationFilter::default();
+        assert!(matches!(
+            filter.check("I think so", 0.001, Some(0.9)),
+            HallucinationVerdict::Suppressed(_)
+        ));
+        assert!(matches!(
+            filter.check("I think so", 0.05, Some(0.9)),
+            HallucinationVerdict::Flagged(_)
+        ));
+    }
+
+    #[test]
+    fn test_repeated_ngram_ratio() {
+        let words: Vec<&str> = "we need to we need to we need to ship".split_whitespace().collect();
+        let ratio = repeated_ngram_ratio(&words, 3);
+        assert!((ratio - 0.9).abs() < 0.01);
+    }
+}
@@ -0,0 +1,190 @@
+// audio/transcription/language_id.rs
+//
+// Per-segment language identification for multilingual meetings. Whisper reports the
+// language it detected when running in auto mode; for other providers (and when a
+// language is forced) segments are identified from their text by function-word
+// frequency, which is reliable for the European languages most mixed meetings use.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+// Segments with fewer words than this are too short to tell languages apart
+const MIN_WORDS: usize = 3;
+// Languages making up less of a meeting than this are treated as stray misdetections
+const MIN_LANGUAGE_SHARE: f64 = 0.1;
+
+// Frequent function words that are (mostly) unique to one language
+static FUNCTION_WORDS: Lazy<Vec<(&'static str, Vec<&'static str>)>> = Lazy::new(|| {
+    vec![
+        ("en", vec!["the", "and", "is", "are", "you", "that", "this", "with", "have", "was", "what", "we", "it's", "for", "not", "they", "of", "to"]),
+        ("es", vec!["el", "los", "las", "y", "es", "que", "una", "con", "para", "pero", "está", "muy", "también", "porque", "del", "se", "lo", "por"]),
+        ("fr", vec!["le", "les", "et", "est", "une", "des", "avec", "pour", "mais", "c'est", "nous", "vous", "pas", "du", "je", "ce", "qui", "sur"]),
+        ("de", vec!["der", "die", "das", "und", "ist", "nicht", "ich", "wir", "sie", "mit", "auf", "für", "ein", "eine", "auch", "zu", "den", "es"]),
+        ("it", vec!["il", "gli", "che", "è", "non", "sono", "una", "con", "per", "ma", "anche", "della", "questo", "noi", "ci", "di", "io", "molto"]),
+        ("pt", vec!["o", "os", "que", "é", "não", "uma", "com", "para", "mas", "também", "você", "isso", "muito", "dos", "ao", "em", "está", "nós"]),
+        ("ca", vec!["els", "i", "és", "que", "amb", "per", "però", "també", "això", "molt", "nosaltres", "perquè", "aquest", "una", "del", "hi", "ho", "som"]),
+        ("nl", vec!["de", "het", "een", "en", "is", "niet", "ik", "wij", "we", "met", "voor", "maar", "ook", "dat", "van", "zijn", "op", "dit"]),
+    ]
+});
+
+/// ISO 639-1 code of the language a piece of text is most likely in, if it can be told
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let words: Vec<String> = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+    if words.len() < MIN_WORDS {
+        return None;
+    }
+
+    let mut scores: Vec<(&'static str, usize)> = FUNCTION_WORDS
+        .iter()
+        .map(|(code, list)| (*code, words.iter().filter(|w| list.contains(&w.as_str())).count()))
+        .collect();
+    scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+
+    // Require a clear winner; ties between related languages stay unlabeled
+    match (scores.first(), scores.get(1)) {
+        (Some(&(code, best)), Some(&(_, second))) if best >= 2 && best > second => Some(code),
+        _ => None,
+    }
+}
+
+/// English name of a language code (falls back to the code itself)
+pub fn language_name(code: &str) -> &str {
+    match code {
+        "en" => "English",
+        "es" => "Spanish",
+        "fr" => "French",
+        "de" => "German",
+        "it" => "Italian",
+        "pt" => "Portuguese",
+        "ca" => "Catalan",
+        "nl" => "Dutch",
+        "ja" => "Japanese",
+        "zh" => "Chinese",
+        "ko" => "Korean",
+        "ru" => "Russian",
+        other => other,
+    }
+}
+
+/// Languages making up a significant share of a meeting, given (language code, segment
+/// count) pairs, as (code, share) with the main language first
+fn significant_languages(breakdown: &[(String, i64)]) -> Vec<(&str, f64)> {
+    let total: i64 = breakdown.iter().map(|(_, count)| count).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut languages: HashMap<&str, i64> = HashMap::new();
+    for (code, count) in breakdown {
+        *languages.entry(code.as_str()).or_insert(0) += count;
+    }
+    let mut significant: Vec<(&str, f64)> = languages
+        .into_iter()
+        .map(|(code, count)| (code, count as f64 / total as f64))
+        .filter(|(_, share)| *share >= MIN_LANGUAGE_SHARE)
+        .collect();
+    significant.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    significant
+}
+
+/// Summarization instruction for a meeting spoken in several languages, given
+/// (language code, segment count) pairs. None for single-language meetings.
+pub fn multilingual_instruction(breakdown: &[(String, i64)]) -> Option<String> {
+    let significant = significant_languages(breakdown);
+    if significant.len() < 2 {
+        return None;
+    }
+
+    let mix = significant
+        .iter()
+        .map(|(code, share)| format!("{} ({:.0}%)", language_name(code), share * 100.0))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!(
+        "This meeting was multilingual: the transcript mixes {}. Read every part regardless of language, \
+write the report in {} (the main language of the meeting), and keep names, quotes and \
+technical terms in the language they were spoken in.",
+        mix,
+        language_name(significant[0].0)
+    ))
+}
+
+/// Normalize a user-chosen output language to a lower-case ISO 639-1 code, with an
+/// optional region ("pt-br"). None when it doesn't look like a language code.
+pub fn normalize_language_code(code: &str) -> Option<String> {
+    let code = code.trim().to_lowercase().replace('_', "-");
+    let mut parts = code.split('-');
+    let language = parts.next()?;
+    let region = parts.next();
+    let valid = (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && region.iter().all(|region| region.len() == 2 && region.chars().all(|c| c.is_ascii_alphabetic()))
+        && parts.next().is_none();
+    valid.then_some(code)
+}
+
+/// Summarization instruction to write the minutes in `output_code` whatever language(s)
+/// the meeting was held in. Replaces the multilingual instruction when an output
+/// language has been chosen.
+pub fn output_language_instruction(output_code: &str, breakdown: &[(String, i64)]) -> String {
+    let output = language_name(output_code.split('-').next().unwrap_or(output_code));
+    let spoken: Vec<&str> = significant_languages(breakdown)
+        .into_iter()
+        .map(|(code, _)| code)
+        .filter(|code| !output_code.starts_with(code))
+        .map(language_name)
+        .collect();
+    let source = match spoken.len() {
+        0 => String::new(),
+        1 => format!("The meeting was held in {}. ", spoken[0]),
+        _ => format!("The meeting mixes {}. ", spoken.join(" and ")),
+    };
+    format!(
+        "{}Write the entire report in {} (language code \"{}\"), including section headings, \
+translating from the transcript wherever it is in another language. Keep names, product names and \
+technical terms as they were spoken; when quoting someone, give the original words followed by a \
+translation in parentheses.",
+        source, output, output_code
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(detect_language("We need to finish the report and send it to the client"), Some("en"));
+        assert_eq!(detect_language("Creo que el presupuesto es muy alto para este trimestre"), Some("es"));
+        assert_eq!(detect_language("Nous allons valider le budget avec le client"), Some("fr"));
+        assert_eq!(detect_language("OK thanks"), None);
+    }
+
+    #[test]
+    fn test_multilingual_instruction() {
+        let mixed = vec![("es".to_string(), 12), ("en".to_string(), 6), ("fr".to_string(), 1)];
+        let instruction = multilingual_instruction(&mixed).unwrap();
+        assert!(instruction.contains("Spanish (63%), English (32%)"));
+        assert!(!instruction.contains("French"));
+        assert!(instruction.contains("write the report in Spanish"));
+
+        assert!(multilingual_instruction(&[("en".to_string(), 40), ("es".to_string(), 2)]).is_none());
+    }
+
+    #[test]
+    fn test_output_language_instruction() {
+        assert_eq!(normalize_language_code(" EN "), Some("en".to_string()));
+        assert_eq!(normalize_language_code("pt_BR"), Some("pt-br".to_string()));
+        assert_eq!(normalize_language_code("English"), None);
+        assert_eq!(normalize_language_code(""), None);
+
+        let instruction = output_language_instruction("en", &[("es".to_string(), 30), ("en".to_string(), 2)]);
+        assert!(instruction.starts_with("The meeting was held in Spanish. Write the entire report in English"));
+        let instruction = output_language_instruction("pt-br", &[]);
+        assert!(instruction.starts_with("Write the entire report in Portuguese (language code \"pt-br\")"));
+    }
+}
@@ -8,6 +8,13 @@ pub mod parakeet_provider;
 pub mod groq_provider;
 pub mod engine;
 pub mod worker;
+pub mod replacements;
+pub mod hallucination;
+pub mod offline_queue;
+pub mod comparison;
+pub mod language_id;
+pub mod review;
+pub mod edits;
 
 // Re-export commonly used types
 pub use provider::{TranscriptionError, TranscriptionProvider, TranscriptResult};
@@ -20,6 +27,9 @@ pub use engine::{
     get_or_init_transcription_engine,
     get_or_init_whisper
 };
+pub use comparison::{word_error_rate, ComparisonConfig, ComparisonRunner, WerDiff};
+pub use hallucination::{HallucinationFilter, HallucinationVerdict};
+pub use replacements::{apply_replacements, ReplacementDictionary, ReplacementRule};
 pub use worker::{
     start_transcription_task,
     reset_speech_detected_flag,
@@ -0,0 +1,386 @@
+// audio/transcription/offline_queue.rs
+//
+// Backlog for cloud transcription when the network drops mid-meeting.
+// Chunks are held in memory up to a limit, then spilled to disk, and replayed
+// in order once the provider is reachable again.
+
+use log::{info, warn};
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::audio::recording_state::{AudioChunk, DeviceType, LiveSegment};
+
+// ~2 minutes of typical VAD segments kept in memory before spilling
+const MAX_IN_MEMORY: usize = 32;
+// Hard cap on spilled chunks; oldest are dropped beyond this
+const MAX_SPILLED: usize = 2000;
+const INITIAL_RETRY: Duration = Duration::from_secs(5);
+const MAX_RETRY: Duration = Duration::from_secs(60);
+
+/// FIFO backlog of chunks waiting for connectivity
+pub struct OfflineQueue {
+    memory: VecDeque<AudioChunk>,
+    spill_dir: PathBuf,
+    spilled: VecDeque<PathBuf>,
+    max_in_memory: usize,
+    max_spilled: usize,
+    next_spill_id: u64,
+    offline_since: Option<Instant>,
+    next_retry_at: Option<Instant>,
+    retry_interval: Duration,
+    dropped: u64,
+    /// Drops the caller hasn't accounted for yet
+    unreported_drops: u64,
+}
+
+impl OfflineQueue {
+    /// Create a queue spilling into `spill_dir` (leftovers from a previous session are removed)
+    pub fn new(spill_dir: PathBuf) -> Self {
+        Self::with_limits(spill_dir, MAX_IN_MEMORY, MAX_SPILLED)
+    }
+
+    pub fn with_limits(spill_dir: PathBuf, max_in_memory: usize, max_spilled: usize) -> Self {
+        if spill_dir.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&spill_dir) {
+                warn!("Failed to clear old transcription backlog at {}: {}", spill_dir.display(), e);
+            }
+        }
+
+        Self {
+            memory: VecDeque::new(),
+            spill_dir,
+            spilled: VecDeque::new(),
+            max_in_memory: max_in_memory.max(1),
+            max_spilled,
+            next_spill_id: 0,
+            offline_since: None,
+            next_retry_at: None,
+            retry_interval: INITIAL_RETRY,
+            dropped: 0,
+            unreported_drops: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.memory.len() + self.spilled.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Chunks dropped because the disk backlog was full or a spilled chunk was unreadable
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Chunks dropped since the last call; they will never be transcribed, so the worker
+    /// counts them as completed
+    pub fn take_unreported_drops(&mut self) -> u64 {
+        std::mem::take(&mut self.unreported_drops)
+    }
+
+    /// Queue a chunk behind everything already waiting
+    pub fn push_back(&mut self, chunk: AudioChunk) {
+        // Once anything is on disk, newer chunks must follow it there to keep order
+        if self.spilled.is_empty() && self.memory.len() < self.max_in_memory {
+            self.memory.push_back(chunk);
+            return;
+        }
+
+        if self.spilled.len() >= self.max_spilled {
+            if let Some(oldest) = self.spilled.pop_front() {
+                let _ = std::fs::remove_file(&oldest);
+                self.dropped += 1;
+                self.unreported_drops += 1;
+                warn!("⚠️ Transcription backlog full ({} chunks on disk), dropped oldest spilled chunk", self.max_spilled);
+            }
+        }
+
+        match self.spill(&chunk) {
+            Ok(path) => self.spilled.push_back(path),
+            Err(e) => {
+                // Keep it in memory rather than lose it
+                warn!("Failed to spill chunk {} to disk: {}, keeping in memory", chunk.chunk_id, e);
+                self.memory.push_back(chunk);
+            }
+        }
+    }
+
+    /// Put a chunk back at the head of the queue (a replay attempt that failed)
+    pub fn push_front(&mut self, chunk: AudioChunk) {
+        self.memory.push_front(chunk);
+    }
+
+    /// Take the oldest queued chunk
+    pub fn pop_front(&mut self) -> Option<AudioChunk> {
+        if let Some(chunk) = self.memory.pop_front() {
+            return Some(chunk);
+        }
+
+        while let Some(path) = self.spilled.pop_front() {
+            let result = read_chunk(&path);
+            let _ = std::fs::remove_file(&path);
+            match result {
+                Ok(chunk) => return Some(chunk),
+                Err(e) => {
+                    warn!("Failed to read spilled chunk {}: {}, skipping", path.display(), e);
+                    self.dropped += 1;
+                    self.unreported_drops += 1;
+                }
+            }
+        }
+        None
+    }
+
+    /// Discard everything still queued, returning how many chunks were dropped
+    pub fn clear(&mut self) -> usize {
+        let count = self.len();
+        self.memory.clear();
+        for path in self.spilled.drain(..) {
+            let _ = std::fs::remove_file(&path);
+        }
+        count
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline_since.is_some()
+    }
+
+    pub fn offline_duration(&self) -> Duration {
+        self.offline_since.map_or(Duration::ZERO, |since| since.elapsed())
+    }
+
+    /// Record a network failure; returns true if we just went offline
+    pub fn mark_offline(&mut self) -> bool {
+        let went_offline = self.offline_since.is_none();
+        if went_offline {
+            self.offline_since = Some(Instant::now());
+            self.retry_interval = INITIAL_RETRY;
+        } else {
+            self.retry_interval = (self.retry_interval * 2).min(MAX_RETRY);
+        }
+        self.next_retry_at = Some(Instant::now() + self.retry_interval);
+        went_offline
+    }
+
+    /// Record a successful request; returns true if we just came back online
+    pub fn mark_online(&mut self) -> bool {
+        let came_online = self.offline_since.is_some();
+        if came_online {
+            info!("🌐 Connectivity restored after {:.0}s, replaying {} queued chunk(s)",
+                  self.offline_duration().as_secs_f64(), self.len());
+        }
+        self.offline_since = None;
+        self.next_retry_at = None;
+        self.retry_interval = INITIAL_RETRY;
+        came_online
+    }
+
+    /// Whether the head of the queue should be (re)tried now
+    pub fn ready_to_replay(&self) -> bool {
+        !self.is_empty() && self.next_retry_at.map_or(true, |at| Instant::now() >= at)
+    }
+
+    /// How long to wait for new audio before the next replay attempt
+    pub fn time_until_retry(&self) -> Duration {
+        self.next_retry_at
+            .map_or(Duration::ZERO, |at| at.saturating_duration_since(Instant::now()))
+    }
+
+    fn spill(&mut self, chunk: &AudioChunk) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(&self.spill_dir)?;
+        let path = self.spill_dir.join(format!("{:08}.chunk", self.next_spill_id));
+        self.next_spill_id += 1;
+        write_chunk(&path, chunk)?;
+        Ok(path)
+    }
+}
+
+impl Drop for OfflineQueue {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+// Spill format: chunk_id u64 | timestamp f64 | sample_rate u32 | device u8 |
+// live flag u8 [| live id u64 | live final u8] | sample count u64 | f32 samples (all little-endian)
+fn write_chunk(path: &Path, chunk: &AudioChunk) -> std::io::Result<()> {
+    let mut bytes = Vec::with_capacity(40 + chunk.data.len() * 4);
+    bytes.extend_from_slice(&chunk.chunk_id.to_le_bytes());
+    bytes.extend_from_slice(&chunk.timestamp.to_le_bytes());
+    bytes.extend_from_slice(&chunk.sample_rate.to_le_bytes());
+    bytes.push(match chunk.device_type {
+        DeviceType::Microphone => 0,
+        DeviceType::System => 1,
+    });
+    match chunk.live_segment {
+        Some(live) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&live.id.to_le_bytes());
+            bytes.push(live.is_final as u8);
+        }
+        None => bytes.push(0),
+    }
+    bytes.extend_from_slice(&(chunk.data.len() as u64).to_le_bytes());
+    for sample in &chunk.data {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    let mut file = std::fs::File::create(path)?;
+    file.write_all(&bytes)
+}
+
+fn read_chunk(path: &Path) -> std::io::Result<AudioChunk> {
+    let mut file = std::fs::File::open(path)?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let mut reader = ByteReader { bytes: &bytes, pos: 0 };
+    let chunk_id = u64::from_le_bytes(reader.take()?);
+    let timestamp = f64::from_le_bytes(reader.take()?);
+    let sample_rate = u32::from_le_bytes(reader.take()?);
+    let device_type = match reader.take::<1>()?[0] {
+        1 => DeviceType::System,
+        _ => DeviceType::Microphone,
+    };
+    let live_segment = match reader.take::<1>()?[0] {
+        1 => {
+            let id = u64::from_le_bytes(reader.take()?);
+            let is_final = reader.take::<1>()?[0] != 0;
+            Some(LiveSegment { id, is_final })
+        }
+        _ => None,
+    };
+    let count = u64::from_le_bytes(reader.take()?) as usize;
+    let mut data = Vec::with_capacity(count);
+    for _ in 0..count {
+        data.push(f32::from_le_bytes(reader.take()?));
+    }
+
+    Ok(AudioChunk {
+        data,
+        sample_rate,
+        timestamp,
+        chunk_id,
+        device_type,
+        live_segment,
+    })
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl ByteReader<'_> {
+    fn take<const N: usize>(&mut self) -> std::io::Result<[u8; N]> {
+        let end = self.pos + N;
+        let slice = self.bytes.get(self.pos..end).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "truncated spilled chunk")
+        })?;
+        self.pos = end;
+        let mut out = [0u8; N];
+        out.copy_from_slice(slice);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(id: u64) -> AudioChunk {
+        AudioChunk {
+            data: vec![id as f32 * 0.01; 160],
+            sample_rate: 16000,
+            timestamp: id as f64,
+            chunk_id: id,
+            device_type: if id % 2 == 0 { DeviceType::Microphone } else { DeviceType::System },
+            live_segment: Some(LiveSegment { id, is_final: true }),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("meetily-offline-queue-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_spills_to_disk_and_keeps_order() {
+        let mut queue = OfflineQueue::with_limits(temp_dir("order"), 2, 100);
+        for id in 0..5 {
+            queue.push_back(chunk(id));
+        }
+        assert_eq!(queue.len(), 5);
+        assert_eq!(queue.spilled.len(), 3);
+
+        let ids: Vec<u64> = std::iter::from_fn(|| queue.pop_front()).map(|c| c.chunk_id).collect();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_spilled_chunk_round_trip() {
+        let mut queue = OfflineQueue::with_limits(temp_dir("roundtrip"), 1, 100);
+        queue.push_back(chunk(10));
+        queue.push_back(chunk(11));
+
+        queue.pop_front();
+        let restored = queue.pop_front().unwrap();
+        let original = chunk(11);
+        assert_eq!(restored.data, original.data);
+        assert_eq!(restored.timestamp, original.timestamp);
+        assert_eq!(restored.device_type, original.device_type);
+        assert_eq!(restored.live_segment, original.live_segment);
+    }
+
+    #[test]
+    fn test_disk_cap_drops_oldest_spilled() {
+        let mut queue = OfflineQueue::with_limits(temp_dir("cap"), 1, 2);
+        for id in 0..5 {
+            queue.push_back(chunk(id));
+        }
+        assert_eq!(queue.dropped(), 2);
+
+        let ids: Vec<u64> = std::iter::from_fn(|| queue.pop_front()).map(|c| c.chunk_id).collect();
+        assert_eq!(ids, vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn test_dropped_chunks_count_as_completed() {
+        let mut queue = OfflineQueue::with_limits(temp_dir("drops"), 1, 2);
+        let queued = 6;
+        for id in 0..queued {
+            queue.push_back(chunk(id));
+        }
+        // Cap overflow dropped chunks 1, 2 and 3; now corrupt chunk 4 on disk
+        std::fs::write(&queue.spilled[0], b"truncated").unwrap();
+
+        // The worker's shutdown accounting: every chunk ends up transcribed or dropped
+        let mut completed = 0;
+        while let Some(_chunk) = queue.pop_front() {
+            completed += 1;
+        }
+        completed += queue.take_unreported_drops();
+        assert_eq!(queue.dropped(), 4);
+        assert_eq!(completed, queued);
+        assert_eq!(queue.take_unreported_drops(), 0);
+    }
+
+    #[test]
+    fn test_retry_backoff() {
+        let mut queue = OfflineQueue::with_limits(temp_dir("retry"), 4, 4);
+        queue.push_back(chunk(0));
+        assert!(queue.ready_to_replay());
+
+        assert!(queue.mark_offline());
+        assert!(!queue.ready_to_replay());
+        assert!(!queue.mark_offline());
+        assert_eq!(queue.retry_interval, INITIAL_RETRY * 2);
+
+        assert!(queue.mark_online());
+        assert!(queue.ready_to_replay());
+    }
+}
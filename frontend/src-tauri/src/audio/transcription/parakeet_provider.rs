@@ -38,6 +38,8 @@ impl TranscriptionProvider for ParakeetProvider {
                 text: text.trim().to_string(),
                 confidence: None, // Parakeet doesn't provide confidence scores
                 is_partial: false, // Parakeet doesn't provide partial results
+                no_speech_prob: None,
+                language: None,
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
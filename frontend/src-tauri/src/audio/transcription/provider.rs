@@ -16,6 +16,8 @@ pub enum TranscriptionError {
     AudioTooShort { samples: usize, minimum: usize },
     EngineFailed(String),
     UnsupportedLanguage(String),
+    /// Cloud provider unreachable (connection/timeout) - the chunk can be retried later
+    NetworkUnavailable(String),
 }
 
 impl std::fmt::Display for TranscriptionError {
@@ -31,6 +33,7 @@ impl std::fmt::Display for TranscriptionError {
             Self::UnsupportedLanguage(lang) => {
                 write!(f, "Language '{}' is not supported by this provider", lang)
             }
+            Self::NetworkUnavailable(msg) => write!(f, "Transcription service unreachable: {}", msg),
         }
     }
 }
@@ -43,6 +46,8 @@ pub struct TranscriptResult {
     pub text: String,
     pub confidence: Option<f32>, // None if provider doesn't support confidence scores
     pub is_partial: bool,
+    pub no_speech_prob: Option<f32>, // None if provider doesn't estimate speech presence
+    pub language: Option<String>, // Detected language (ISO 639-1), None if provider doesn't report it
 }
 
 /// Trait for transcription providers (Whisper, Parakeet, future providers)
@@ -0,0 +1,278 @@
+// audio/transcription/replacements.rs
+//
+// User-defined replacement dictionary applied to transcript text after the
+// engine returns. Fixes names and jargon that speech models consistently
+// misspell (product names, people, acronyms).
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_store::StoreExt;
+
+const STORE_FILE: &str = "transcript_replacements.json";
+const STORE_KEY: &str = "dictionary";
+
+/// How a rule's pattern is matched against transcript text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchType {
+    /// Literal phrase, matched on word boundaries
+    Exact,
+    /// Raw regular expression (capture groups usable as `$1` in the replacement)
+    Regex,
+}
+
+/// A single replacement rule as edited by the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplacementRule {
+    pub pattern: String,
+    pub replacement: String,
+    #[serde(rename = "matchType", default = "default_match_type")]
+    pub match_type: MatchType,
+    #[serde(rename = "caseSensitive", default)]
+    pub case_sensitive: bool,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_match_type() -> MatchType {
+    MatchType::Exact
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Persisted replacement dictionary (ordered - rules are applied top to bottom)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReplacementDictionary {
+    pub rules: Vec<ReplacementRule>,
+}
+
+/// Dictionary with all enabled rules compiled to regexes
+#[derive(Debug, Default)]
+pub struct CompiledDictionary {
+    rules: Vec<(Regex, String)>,
+}
+
+impl CompiledDictionary {
+    /// Compile every enabled rule, failing on the first invalid pattern
+    pub fn compile(dictionary: &ReplacementDictionary) -> Result<Self, String> {
+        let mut rules = Vec::with_capacity(dictionary.rules.len());
+
+        for (i, rule) in dictionary.rules.iter().enumerate() {
+            if !rule.enabled {
+                continue;
+            }
+            if rule.pattern.trim().is_empty() {
+                return Err(format!("Rule {} has an empty pattern", i + 1));
+            }
+
+            let source = match rule.match_type {
+                // Only anchor on word boundaries where the phrase itself starts/ends with a word char,
+                // otherwise patterns like "C++" would never match
+                MatchType::Exact => {
+                    let escaped = regex::escape(rule.pattern.trim());
+                    let starts_word = rule.pattern.trim().chars().next().map_or(false, is_word_char);
+                    let ends_word = rule.pattern.trim().chars().last().map_or(false, is_word_char);
+                    format!(
+                        "{}{}{}",
+                        if starts_word { r"\b" } else { "" },
+                        escaped,
+                        if ends_word { r"\b" } else { "" }
+                    )
+                }
+                MatchType::Regex => rule.pattern.clone(),
+            };
+
+            let regex = RegexBuilder::new(&source)
+                .case_insensitive(!rule.case_sensitive)
+                .build()
+                .map_err(|e| format!("Rule {} ('{}') is not a valid pattern: {}", i + 1, rule.pattern, e))?;
+
+            // Exact rules insert the replacement literally; escape '$' so it is not read as a group reference
+            let replacement = match rule.match_type {
+                MatchType::Exact => rule.replacement.replace('$', "$$"),
+                MatchType::Regex => rule.replacement.clone(),
+            };
+
+            rules.push((regex, replacement));
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Apply all rules in order
+    pub fn apply(&self, text: &str) -> String {
+        let mut result = text.to_string();
+        for (regex, replacement) in &self.rules {
+            if regex.is_match(&result) {
+                result = regex.replace_all(&result, replacement.as_str()).into_owned();
+            }
+        }
+        result
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Active dictionary used by the transcription workers
+static ACTIVE_DICTIONARY: Lazy<RwLock<Arc<CompiledDictionary>>> =
+    Lazy::new(|| RwLock::new(Arc::new(CompiledDictionary::default())));
+
+/// Apply the active replacement dictionary to a transcript segment
+pub fn apply_replacements(text: &str) -> String {
+    let dictionary = match ACTIVE_DICTIONARY.read() {
+        Ok(guard) => guard.clone(),
+        Err(_) => return text.to_string(),
+    };
+
+    if dictionary.is_empty() {
+        return text.to_string();
+    }
+    dictionary.apply(text)
+}
+
+fn set_active_dictionary(compiled: CompiledDictionary) {
+    if let Ok(mut guard) = ACTIVE_DICTIONARY.write() {
+        *guard = Arc::new(compiled);
+    }
+}
+
+/// Load the replacement dictionary from the store
+pub fn load_replacement_dictionary<R: Runtime>(app: &AppHandle<R>) -> ReplacementDictionary {
+    let store = match app.store(STORE_FILE) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!("Failed to access replacement store: {}, using empty dictionary", e);
+            return ReplacementDictionary::default();
+        }
+    };
+
+    match store.get(STORE_KEY) {
+        Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            warn!("Failed to deserialize replacement dictionary: {}, using empty dictionary", e);
+            ReplacementDictionary::default()
+        }),
+        None => ReplacementDictionary::default(),
+    }
+}
+
+/// Load the persisted dictionary and make it active for the transcription workers
+pub fn refresh_active_dictionary<R: Runtime>(app: &AppHandle<R>) {
+    let dictionary = load_replacement_dictionary(app);
+    match CompiledDictionary::compile(&dictionary) {
+        Ok(compiled) => {
+            info!("📖 Loaded {} transcript replacement rule(s)", compiled.rules.len());
+            set_active_dictionary(compiled);
+        }
+        Err(e) => {
+            warn!("Stored replacement dictionary is invalid, replacements disabled: {}", e);
+            set_active_dictionary(CompiledDictionary::default());
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn get_replacement_dictionary<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<ReplacementDictionary, String> {
+    Ok(load_replacement_dictionary(&app))
+}
+
+#[tauri::command]
+pub async fn set_replacement_dictionary<R: Runtime>(
+    app: AppHandle<R>,
+    dictionary: ReplacementDictionary,
+) -> Result<(), String> {
+    // Validate before persisting so a bad regex never reaches the store
+    let compiled = CompiledDictionary::compile(&dictionary)?;
+
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("Failed to access replacement store: {}", e))?;
+    let value = serde_json::to_value(&dictionary)
+        .map_err(|e| format!("Failed to serialize replacement dictionary: {}", e))?;
+    store.set(STORE_KEY, value);
+    store
+        .save()
+        .map_err(|e| format!("Failed to save replacement dictionary: {}", e))?;
+
+    info!("Saved {} transcript replacement rule(s)", dictionary.rules.len());
+    set_active_dictionary(compiled);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, replacement: &str, match_type: MatchType, case_sensitive: bool) -> ReplacementRule {
+        ReplacementRule {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            match_type,
+            case_sensitive,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_exact_rule_matches_whole_words_only() {
+        let dictionary = ReplacementDictionary {
+            rules: vec![rule("meet lee", "Meetily", MatchType::Exact, false)],
+        };
+        let compiled = CompiledDictionary::compile(&dictionary).unwrap();
+
+        assert_eq!(compiled.apply("Open Meet Lee now"), "Open Meetily now");
+        assert_eq!(compiled.apply("meet leet"), "meet leet");
+    }
+
+    #[test]
+    fn test_case_sensitive_rule() {
+        let dictionary = ReplacementDictionary {
+            rules: vec![rule("Jon", "John", MatchType::Exact, true)],
+        };
+        let compiled = CompiledDictionary::compile(&dictionary).unwrap();
+
+        assert_eq!(compiled.apply("Jon and jon"), "John and jon");
+    }
+
+    #[test]
+    fn test_regex_rule_with_groups() {
+        let dictionary = ReplacementDictionary {
+            rules: vec![rule(r"k8s\s+(\w+)", "Kubernetes $1", MatchType::Regex, false)],
+        };
+        let compiled = CompiledDictionary::compile(&dictionary).unwrap();
+
+        assert_eq!(compiled.apply("the K8s cluster"), "the Kubernetes cluster");
+    }
+
+    #[test]
+    fn test_invalid_regex_rejected() {
+        let dictionary = ReplacementDictionary {
+            rules: vec![rule("(unclosed", "x", MatchType::Regex, false)],
+        };
+        assert!(CompiledDictionary::compile(&dictionary).is_err());
+    }
+
+    #[test]
+    fn test_disabled_rules_skipped() {
+        let mut disabled = rule("foo", "bar", MatchType::Exact, false);
+        disabled.enabled = false;
+        let dictionary = ReplacementDictionary { rules: vec![disabled] };
+        let compiled = CompiledDictionary::compile(&dictionary).unwrap();
+
+        assert!(compiled.is_empty());
+        assert_eq!(compiled.apply("foo"), "foo");
+    }
+}
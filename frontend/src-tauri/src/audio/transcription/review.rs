@@ -0,0 +1,122 @@
+// audio/transcription/review.rs
+//
+// "Needs review" flagging for risky transcript segments. Provider confidence and the
+// audio quality score of the source chunk are combined into one reliability score;
+// segments below the threshold (or spoken over another speaker) are flagged when the
+// meeting is saved, so users can proofread only those parts of a long transcript.
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+/// Combined reliability (confidence x quality) below which a segment needs review
+pub const REVIEW_THRESHOLD: f64 = 0.35;
+// Individual signals low enough to be worth naming as a reason
+const LOW_CONFIDENCE: f64 = 0.5;
+const LOW_QUALITY: f64 = 0.35;
+
+/// A flagged segment with the reasons it was flagged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSegment {
+    pub id: String,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub speaker: Option<String>,
+    pub confidence: Option<f64>,
+    pub quality_score: Option<f64>,
+    pub reasons: Vec<String>,
+}
+
+/// Combined reliability score; missing signals count as fully reliable
+pub fn reliability(confidence: Option<f64>, quality_score: Option<f64>) -> f64 {
+    confidence.unwrap_or(1.0).clamp(0.0, 1.0) * quality_score.unwrap_or(1.0).clamp(0.0, 1.0)
+}
+
+pub fn needs_review(confidence: Option<f64>, quality_score: Option<f64>, overlapping_speech: bool) -> bool {
+    overlapping_speech || reliability(confidence, quality_score) < REVIEW_THRESHOLD
+}
+
+/// Human-readable reasons a segment was flagged
+pub fn review_reasons(confidence: Option<f64>, quality_score: Option<f64>, overlapping_speech: bool) -> Vec<String> {
+    let mut reasons = Vec::new();
+    if let Some(confidence) = confidence.filter(|c| *c < LOW_CONFIDENCE) {
+        reasons.push(format!("low transcription confidence ({:.2})", confidence));
+    }
+    if let Some(quality) = quality_score.filter(|q| *q < LOW_QUALITY) {
+        reasons.push(format!("poor audio quality ({:.2})", quality));
+    }
+    if overlapping_speech {
+        reasons.push("overlapping speech".to_string());
+    }
+    // Neither signal alone was low, but together they fall under the threshold
+    if reasons.is_empty() && reliability(confidence, quality_score) < REVIEW_THRESHOLD {
+        reasons.push(format!(
+            "low combined reliability ({:.2})",
+            reliability(confidence, quality_score)
+        ));
+    }
+    reasons
+}
+
+/// Segments of a meeting still flagged for review, in audio order
+#[command]
+pub async fn get_segments_needing_review<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<ReviewSegment>, String> {
+    let state = app.state::<AppState>();
+    let rows = TranscriptsRepository::list_needing_review(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load segments needing review: {}", e))?;
+    Ok(rows
+        .into_iter()
+        .map(|(id, text, audio_start_time, speaker, confidence, quality_score, overlapping)| ReviewSegment {
+            reasons: review_reasons(confidence, quality_score, overlapping),
+            id,
+            text,
+            audio_start_time,
+            speaker,
+            confidence,
+            quality_score,
+        })
+        .collect())
+}
+
+/// Clear (or restore) the review flag once a segment has been proofread
+#[command]
+pub async fn set_segment_reviewed<R: Runtime>(
+    app: AppHandle<R>,
+    transcript_id: String,
+    reviewed: bool,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let updated = TranscriptsRepository::set_needs_review(state.db_manager.pool(), &transcript_id, !reviewed)
+        .await
+        .map_err(|e| format!("Failed to update review flag: {}", e))?;
+    if updated == 0 {
+        return Err(format!("Transcript segment not found: {}", transcript_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_risky_segments() {
+        assert!(!needs_review(Some(0.9), Some(0.8), false));
+        assert!(!needs_review(None, None, false));
+        assert!(needs_review(Some(0.9), Some(0.2), false));
+        assert!(needs_review(Some(0.6), Some(0.5), false));
+        assert!(needs_review(Some(0.95), Some(0.9), true));
+
+        assert_eq!(review_reasons(Some(0.3), Some(0.2), false).len(), 2);
+        assert_eq!(
+            review_reasons(Some(0.6), Some(0.5), false),
+            vec!["low combined reliability (0.30)".to_string()]
+        );
+    }
+}
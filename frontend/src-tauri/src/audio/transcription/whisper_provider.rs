@@ -26,13 +26,15 @@ impl TranscriptionProvider for WhisperProvider {
     ) -> std::result::Result<TranscriptResult, TranscriptionError> {
         match self
             .engine
-            .transcribe_audio_with_confidence(audio, language)
+            .transcribe_audio_detailed(audio, language)
             .await
         {
-            Ok((text, confidence, is_partial)) => Ok(TranscriptResult {
-                text: text.trim().to_string(),
-                confidence: Some(confidence),
-                is_partial,
+            Ok(result) => Ok(TranscriptResult {
+                text: result.text.trim().to_string(),
+                confidence: Some(result.confidence),
+                is_partial: result.is_partial,
+                no_speech_prob: Some(result.no_speech_prob),
+                language: result.language,
             }),
             Err(e) => Err(TranscriptionError::EngineFailed(e.to_string())),
         }
@@ -2,14 +2,21 @@
 //
 // Parallel transcription worker pool and chunk processing logic.
 
+use super::comparison::ComparisonRunner;
 use super::engine::TranscriptionEngine;
-use super::provider::TranscriptionError;
+use super::hallucination::{HallucinationFilter, HallucinationVerdict};
+use super::offline_queue::OfflineQueue;
+use crate::audio::quality::ChunkQuality;
+use super::provider::{TranscriptionError, TranscriptResult};
 use crate::audio::AudioChunk;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tauri::{AppHandle, Emitter, Runtime};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+// How long to keep retrying an offline backlog after recording stops before giving up
+const BACKLOG_SHUTDOWN_GRACE: std::time::Duration = std::time::Duration::from_secs(60);
 
 // Sequence counter for transcript updates
 static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -17,6 +24,11 @@ static SEQUENCE_COUNTER: AtomicU64 = AtomicU64::new(0);
 // Speech detection flag - reset per recording session
 static SPEECH_DETECTED_EMITTED: AtomicBool = AtomicBool::new(false);
 
+/// Next transcript sequence id (shared with acoustic event annotations so ids stay unique)
+pub fn next_sequence_id() -> u64 {
+    SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst)
+}
+
 /// Reset the speech detected flag for a new recording session
 pub fn reset_speech_detected_flag() {
     SPEECH_DETECTED_EMITTED.store(false, Ordering::SeqCst);
@@ -36,6 +48,24 @@ pub struct TranscriptUpdate {
     pub audio_start_time: f64, // Seconds from recording start (e.g., 125.3)
     pub audio_end_time: f64,   // Seconds from recording start (e.g., 128.6)
     pub duration: f64,          // Segment duration in seconds (e.g., 3.3)
+    // Reason the segment looks like a hallucination (kept but flagged for the reader)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hallucination_flag: Option<String>,
+    // Per-chunk audio quality, so low-quality regions can be marked in the transcript
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_quality: Option<ChunkQuality>,
+    // Audio source ('mic' / 'system') when mic and system are transcribed separately
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+    // Live caption segment this text settles (matches transcript-partial segment_id)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub segment_id: Option<String>,
+    // Diarized speaker ("Speaker 1", ...) when speaker diarization is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker_label: Option<String>,
+    // Spoken language (ISO 639-1) of the segment, for multilingual meetings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 // NOTE: get_transcript_history and get_recording_meeting_name functions
@@ -45,6 +75,7 @@ pub struct TranscriptUpdate {
 pub fn start_transcription_task<R: Runtime>(
     app: AppHandle<R>,
     transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    split_channels: bool,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         info!("🚀 Starting optimized parallel transcription task - guaranteeing zero chunk loss");
@@ -63,6 +94,30 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
+        // Load the user's replacement dictionary for this session
+        super::replacements::refresh_active_dictionary(&app);
+
+        // Optional A/B mode: run a second provider on the same audio and diff the results
+        let primary_model = transcription_engine
+            .get_current_model()
+            .await
+            .unwrap_or_else(|| "unknown".to_string());
+        let comparison =
+            ComparisonRunner::start(&app, transcription_engine.provider_name(), &primary_model).await;
+        let diarizer = crate::diarization::SessionDiarizer::start(&app).await;
+
+        // In split-channel mode the mic is the local user; only system audio needs diarizing
+        let local_speaker_name = if split_channels {
+            crate::audio::recording_preferences::load_recording_preferences(&app)
+                .await
+                .ok()
+                .and_then(|prefs| prefs.local_speaker_name)
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+        } else {
+            None
+        };
+
         // Create parallel workers for faster processing while preserving ALL chunks
         const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
         let (work_sender, work_receiver) = tokio::sync::mpsc::unbounded_channel::<AudioChunk>();
@@ -88,9 +143,16 @@ pub fn start_transcription_task<R: Runtime>(
             let chunks_completed_clone = chunks_completed.clone();
             let input_finished_clone = input_finished.clone();
             let chunks_queued_clone = chunks_queued.clone();
+            let comparison_clone = comparison.clone();
+            let diarizer_clone = diarizer.clone();
+            let local_speaker_name = local_speaker_name.clone();
 
             let worker_handle = tokio::spawn(async move {
                 info!("👷 Worker {} started", worker_id);
+                let hallucination_filter = HallucinationFilter::default();
+                // Chunks waiting for a cloud provider to become reachable again
+                let mut backlog = OfflineQueue::new(backlog_dir(&app_clone, worker_id));
+                let mut shutdown_wait_started: Option<std::time::Instant> = None;
 
                 // PRE-VALIDATE model state to avoid repeated async calls per chunk
                 let initial_model_loaded = engine_clone.is_model_loaded().await;
@@ -111,11 +173,23 @@ pub fn start_transcription_task<R: Runtime>(
                 }
 
                 loop {
-                    // Try to get a chunk to process
-                    let chunk = {
+                    // Replay the offline backlog first (in order) once a retry is due; otherwise
+                    // wait for new audio, waking up for the next retry while chunks are queued
+                    let (chunk, is_replay) = if backlog.ready_to_replay() {
+                        (backlog.pop_front(), true)
+                    } else {
                         let mut receiver = work_receiver_clone.lock().await;
-                        receiver.recv().await
+                        if backlog.is_empty() {
+                            (receiver.recv().await, false)
+                        } else {
+                            match tokio::time::timeout(backlog.time_until_retry(), receiver.recv()).await {
+                                Ok(chunk) => (chunk, false),
+                                Err(_) => continue, // Retry due
+                            }
+                        }
                     };
+                    // Chunks the backlog dropped never come back, so shutdown mustn't wait for them
+                    chunks_completed_clone.fetch_add(backlog.take_unreported_drops(), Ordering::SeqCst);
 
                     match chunk {
                         Some(chunk) => {
@@ -132,9 +206,28 @@ pub fn start_transcription_task<R: Runtime>(
                                 );
                             }
 
+                            // Live caption segment this chunk settles (interim snapshots are handled below)
+                            let final_segment_id = chunk
+                                .live_segment
+                                .filter(|s| s.is_final)
+                                .map(|s| format!("live_{}", s.id));
+
+                            // While offline, new audio joins the backlog behind older chunks
+                            if !is_replay && backlog.is_offline() {
+                                if chunk.live_segment.map_or(false, |s| !s.is_final) {
+                                    // Interim captions are pointless once audio is delayed
+                                    chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                } else {
+                                    backlog.push_back(chunk);
+                                    emit_backlog_progress(&app_clone, &backlog);
+                                }
+                                continue;
+                            }
+
                             // Check if model is still loaded before processing
                             if !engine_clone.is_model_loaded().await {
                                 warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
+                                emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
                                 // Still count as completed even if we can't process
                                 chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
                                 continue;
@@ -142,6 +235,67 @@ pub fn start_transcription_task<R: Runtime>(
 
                             let chunk_timestamp = chunk.timestamp;
                             let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
+                            let chunk_rms = calculate_rms(&chunk.data);
+                            let chunk_quality = ChunkQuality::analyze(&chunk.data, chunk.sample_rate);
+                            // Only split-channel chunks carry a meaningful source; mixed audio is tagged Microphone
+                            let speaker = split_channels.then(|| chunk.device_type.speaker_id().to_string());
+
+                            // Interim snapshot of in-progress speech: transcribe it for a live caption only
+                            if let Some(live) = chunk.live_segment.filter(|s| !s.is_final) {
+                                // Skip if anything else is waiting - the final chunk supersedes it anyway
+                                let backlog = chunks_queued_clone
+                                    .load(Ordering::SeqCst)
+                                    .saturating_sub(chunks_completed_clone.load(Ordering::SeqCst) + 1);
+
+                                if backlog == 0 && !chunk_quality.is_unusable() {
+                                    if let Ok(result) = transcribe_chunk_with_provider(&engine_clone, chunk, &app_clone).await {
+                                        let text = super::replacements::apply_replacements(&result.text);
+                                        let suppressed = matches!(
+                                            hallucination_filter.check(&text, chunk_rms, result.no_speech_prob),
+                                            HallucinationVerdict::Suppressed(_)
+                                        );
+                                        if !text.trim().is_empty() && !suppressed {
+                                            let _ = app_clone.emit("transcript-partial", serde_json::json!({
+                                                "segment_id": format!("live_{}", live.id),
+                                                "text": text,
+                                                "audio_start_time": chunk_timestamp,
+                                                "audio_end_time": chunk_timestamp + chunk_duration,
+                                                "speaker": speaker,
+                                            }));
+                                        }
+                                    }
+                                }
+
+                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                continue;
+                            }
+
+                            // Hopeless audio (silence, pure noise, hard clipping) only produces garbage text
+                            if chunk_quality.is_unusable() {
+                                info!(
+                                    "🔇 Worker {} skipping unusable chunk {} (score: {:.2}, snr: {:.1} dB, clipping: {:.1}%)",
+                                    worker_id,
+                                    chunk.chunk_id,
+                                    chunk_quality.score,
+                                    chunk_quality.snr_db,
+                                    chunk_quality.clipping_ratio * 100.0
+                                );
+                                let _ = app_clone.emit("transcript-chunk-skipped", serde_json::json!({
+                                    "chunk_id": chunk.chunk_id,
+                                    "audio_start_time": chunk_timestamp,
+                                    "audio_end_time": chunk_timestamp + chunk_duration,
+                                    "quality": &chunk_quality,
+                                }));
+                                emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
+                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                continue;
+                            }
+
+                            // Cloud providers can drop offline - keep a copy so the chunk can be replayed
+                            let retry_copy = matches!(engine_clone, TranscriptionEngine::Provider(_))
+                                .then(|| chunk.clone());
+                            let segment_samples = (comparison_clone.is_some() || diarizer_clone.is_some())
+                                .then(|| chunk.data.clone());
 
                             // Transcribe with provider-agnostic approach
                             match transcribe_chunk_with_provider(
@@ -151,7 +305,60 @@ pub fn start_transcription_task<R: Runtime>(
                             )
                             .await
                             {
-                                Ok((transcript, confidence_opt, is_partial)) => {
+                                Ok(result) => {
+                                    if backlog.mark_online() {
+                                        let _ = app_clone.emit("transcription-online", serde_json::json!({
+                                            "queued": backlog.len(),
+                                        }));
+                                    }
+                                    if is_replay {
+                                        emit_backlog_progress(&app_clone, &backlog);
+                                        if backlog.is_empty() {
+                                            info!("✅ Worker {} drained the offline transcription backlog", worker_id);
+                                            let _ = app_clone.emit("transcription-backlog-drained", serde_json::json!({
+                                                "dropped": backlog.dropped(),
+                                            }));
+                                        }
+                                    }
+
+                                    let confidence_opt = result.confidence;
+                                    let is_partial = result.is_partial;
+
+                                    // Fix user-defined names and jargon before anything downstream sees the text
+                                    let mut transcript = super::replacements::apply_replacements(&result.text);
+
+                                    // Laughter comes back as "ha ha ha" - annotate it rather than let the
+                                    // repetition filter below drop it as a loop
+                                    if crate::audio::acoustic_events::is_laughter_text(&transcript) {
+                                        transcript = crate::audio::acoustic_events::LAUGHTER_ANNOTATION.to_string();
+                                    }
+
+                                    // Engine-detected language, else identify it from the text
+                                    let language = result.language.clone().or_else(|| {
+                                        super::language_id::detect_language(&transcript).map(str::to_string)
+                                    });
+
+                                    // Drop or flag classic hallucinations before they reach the transcript
+                                    let hallucination_flag = match hallucination_filter.check(&transcript, chunk_rms, result.no_speech_prob) {
+                                        HallucinationVerdict::Clean => None,
+                                        HallucinationVerdict::Flagged(reason) => {
+                                            info!("⚠️ Worker {} flagged possible hallucination '{}': {}", worker_id, transcript, reason);
+                                            Some(reason)
+                                        }
+                                        HallucinationVerdict::Suppressed(reason) => {
+                                            info!("🚫 Worker {} suppressed hallucination '{}': {}", worker_id, transcript, reason);
+                                            let _ = app_clone.emit("transcript-hallucination-suppressed", serde_json::json!({
+                                                "text": transcript,
+                                                "reason": reason,
+                                                "audio_start_time": chunk_timestamp,
+                                                "audio_end_time": chunk_timestamp + chunk_duration,
+                                            }));
+                                            emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
+                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                            continue;
+                                        }
+                                    };
+
                                     // Provider-aware confidence threshold
                                     let confidence_threshold = match &engine_clone {
                                         TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
@@ -192,10 +399,30 @@ pub fn start_transcription_task<R: Runtime>(
                                         }
 
                                         // Generate sequence ID and calculate timestamps FIRST
-                                        let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                                        let sequence_id = next_sequence_id();
                                         let audio_start_time = chunk_timestamp; // Already in seconds from recording start
                                         let audio_end_time = chunk_timestamp + chunk_duration;
 
+                                        let speaker_label = match (&diarizer_clone, &segment_samples) {
+                                            _ if speaker.as_deref() == Some("mic") => {
+                                                if let Some(diarizer) = &diarizer_clone {
+                                                    diarizer.note_local_speech();
+                                                }
+                                                local_speaker_name.clone()
+                                            }
+                                            (Some(diarizer), Some(samples)) => diarizer.label_segment(samples).await,
+                                            _ => None,
+                                        };
+
+                                        // Live "N participants detected" estimate for the UI
+                                        if let Some(count) = diarizer_clone.as_ref().and_then(|d| d.participant_count_change()) {
+                                            info!("🗣️ Estimated participants so far: {}", count);
+                                            let _ = app_clone.emit("speaker-count-updated", serde_json::json!({
+                                                "count": count,
+                                                "audio_time": chunk_timestamp + chunk_duration,
+                                            }));
+                                        }
+
                                         // Save structured transcript segment to recording manager (only final results)
                                         // Save ALL segments (partial and final) to ensure complete JSON
                                         // Create structured segment with full timestamp data
@@ -217,6 +444,12 @@ pub fn start_transcription_task<R: Runtime>(
                                             audio_start_time,
                                             audio_end_time,
                                             duration: chunk_duration,
+                                            hallucination_flag,
+                                            audio_quality: Some(chunk_quality),
+                                            speaker,
+                                            segment_id: final_segment_id.clone(),
+                                            speaker_label,
+                                            language,
                                         };
 
                                         if let Err(e) = app_clone.emit("transcript-update", &update)
@@ -226,6 +459,10 @@ pub fn start_transcription_task<R: Runtime>(
                                                 worker_id, e
                                             );
                                         }
+                                        emit_segment_final(&app_clone, final_segment_id.as_deref(), Some(&update));
+                                        if let (Some(runner), Some(samples)) = (&comparison_clone, segment_samples) {
+                                            runner.compare(&app_clone, samples, &update);
+                                        }
                                         // PERFORMANCE: Removed verbose logging of every emission
                                     } else if !transcript.trim().is_empty() && should_log_this_chunk
                                     {
@@ -233,9 +470,31 @@ pub fn start_transcription_task<R: Runtime>(
                                         if let Some(c) = confidence_opt {
                                             info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
                                         }
+                                        emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
+                                    } else {
+                                        emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
                                     }
                                 }
                                 Err(e) => {
+                                    // Network drop on a cloud provider: queue the chunk and retry later
+                                    if let (TranscriptionError::NetworkUnavailable(_), Some(copy)) = (&e, retry_copy) {
+                                        if backlog.mark_offline() {
+                                            warn!("📴 Worker {}: {} - queueing audio until connectivity returns", worker_id, e);
+                                            let _ = app_clone.emit("transcription-offline", serde_json::json!({
+                                                "error": e.to_string(),
+                                                "message": "Transcription service unreachable. Audio is being queued and will be transcribed when the connection returns.",
+                                            }));
+                                        }
+                                        if is_replay {
+                                            backlog.push_front(copy);
+                                        } else {
+                                            backlog.push_back(copy);
+                                        }
+                                        emit_backlog_progress(&app_clone, &backlog);
+                                        continue;
+                                    }
+
+                                    emit_segment_final(&app_clone, final_segment_id.as_deref(), None);
                                     // Improved error handling with specific cases
                                     match e {
                                         TranscriptionError::AudioTooShort { .. } => {
@@ -289,6 +548,26 @@ pub fn start_transcription_task<R: Runtime>(
                             }));
                         }
                         None => {
+                            // Recording stopped with chunks still waiting on connectivity: keep retrying
+                            // for a grace period, then give up so shutdown can complete
+                            if input_finished_clone.load(Ordering::SeqCst) && !backlog.is_empty() {
+                                let started = *shutdown_wait_started.get_or_insert_with(std::time::Instant::now);
+                                if started.elapsed() >= BACKLOG_SHUTDOWN_GRACE {
+                                    let abandoned = backlog.clear();
+                                    error!("❌ Worker {} giving up on {} offline chunk(s) after shutdown", worker_id, abandoned);
+                                    chunks_completed_clone.fetch_add(abandoned as u64, Ordering::SeqCst);
+                                    let _ = app_clone.emit("transcription-backlog-abandoned", serde_json::json!({
+                                        "chunks": abandoned,
+                                        "message": "Some audio could not be transcribed because the transcription service stayed unreachable",
+                                    }));
+                                } else {
+                                    // The closed channel returns at once, so sleep until the next retry
+                                    let remaining = BACKLOG_SHUTDOWN_GRACE.saturating_sub(started.elapsed());
+                                    tokio::time::sleep(backlog.time_until_retry().min(remaining)).await;
+                                    continue;
+                                }
+                            }
+
                             // No more chunks available
                             if input_finished_clone.load(Ordering::SeqCst) {
                                 // Double-check that all queued chunks are actually completed
@@ -358,6 +637,11 @@ pub fn start_transcription_task<R: Runtime>(
             }
         }
 
+        // Keep this session's voices for cross-meeting matching (cleared when diarization was off)
+        crate::diarization::fingerprints::stash_session_speakers(
+            diarizer.as_ref().map(|d| d.session_speakers()).unwrap_or_default(),
+        );
+
         // Final verification with retry logic to catch any stragglers
         let mut verification_attempts = 0;
         const MAX_VERIFICATION_ATTEMPTS: u32 = 10;
@@ -404,12 +688,11 @@ pub fn start_transcription_task<R: Runtime>(
 }
 
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
-/// Returns: (text, confidence Option, is_partial)
 async fn transcribe_chunk_with_provider<R: Runtime>(
     engine: &TranscriptionEngine,
     chunk: AudioChunk,
     app: &AppHandle<R>,
-) -> std::result::Result<(String, Option<f32>, bool), TranscriptionError> {
+) -> std::result::Result<TranscriptResult, TranscriptionError> {
     // Convert to 16kHz mono for transcription
     let transcription_data = if chunk.sample_rate != 16000 {
         crate::audio::audio_processing::resample_audio(&chunk.data, chunk.sample_rate, 16000)
@@ -449,21 +732,25 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
             let language = crate::get_language_preference_internal();
 
             match whisper_engine
-                .transcribe_audio_with_confidence(speech_samples, language)
+                .transcribe_audio_detailed(speech_samples, language)
                 .await
             {
-                Ok((text, confidence, is_partial)) => {
-                    let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), Some(confidence), is_partial));
+                Ok(result) => {
+                    let cleaned_text = result.text.trim().to_string();
+                    if !cleaned_text.is_empty() {
+                        info!(
+                            "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {}, no_speech: {:.2})",
+                            chunk.chunk_id, cleaned_text, result.confidence, result.is_partial, result.no_speech_prob
+                        );
                     }
 
-                    info!(
-                        "Whisper transcription complete for chunk {}: '{}' (confidence: {:.2}, partial: {})",
-                        chunk.chunk_id, cleaned_text, confidence, is_partial
-                    );
-
-                    Ok((cleaned_text, Some(confidence), is_partial))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: Some(result.confidence),
+                        is_partial: result.is_partial,
+                        no_speech_prob: Some(result.no_speech_prob),
+                        language: result.language,
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -489,17 +776,21 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
             match parakeet_engine.transcribe_audio(speech_samples).await {
                 Ok(text) => {
                     let cleaned_text = text.trim().to_string();
-                    if cleaned_text.is_empty() {
-                        return Ok((String::new(), None, false));
+                    if !cleaned_text.is_empty() {
+                        info!(
+                            "Parakeet transcription complete for chunk {}: '{}'",
+                            chunk.chunk_id, cleaned_text
+                        );
                     }
 
-                    info!(
-                        "Parakeet transcription complete for chunk {}: '{}'",
-                        chunk.chunk_id, cleaned_text
-                    );
-
                     // Parakeet doesn't provide confidence or partial results
-                    Ok((cleaned_text, None, false))
+                    Ok(TranscriptResult {
+                        text: cleaned_text,
+                        confidence: None,
+                        is_partial: false,
+                        no_speech_prob: None,
+                        language: None,
+                    })
                 }
                 Err(e) => {
                     error!(
@@ -529,7 +820,7 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                 Ok(result) => {
                     let cleaned_text = result.text.trim().to_string();
                     if cleaned_text.is_empty() {
-                        return Ok((String::new(), result.confidence, result.is_partial));
+                        return Ok(TranscriptResult { text: String::new(), ..result });
                     }
 
                     let confidence_str = match result.confidence {
@@ -546,7 +837,17 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
                         result.is_partial
                     );
 
-                    Ok((cleaned_text, result.confidence, result.is_partial))
+                    Ok(TranscriptResult { text: cleaned_text, ..result })
+                }
+                Err(TranscriptionError::NetworkUnavailable(msg)) => {
+                    // The worker queues the chunk for replay; no user-facing error per chunk
+                    warn!(
+                        "{} unreachable for chunk {}: {}",
+                        provider.provider_name(),
+                        chunk.chunk_id,
+                        msg
+                    );
+                    Err(TranscriptionError::NetworkUnavailable(msg))
                 }
                 Err(e) => {
                     error!(
@@ -572,6 +873,48 @@ async fn transcribe_chunk_with_provider<R: Runtime>(
     }
 }
 
+/// Settle a live caption segment: carries the final text (and the transcript-update
+/// sequence_id) or marks the segment discarded so interim captions can be removed
+fn emit_segment_final<R: Runtime>(app: &AppHandle<R>, segment_id: Option<&str>, update: Option<&TranscriptUpdate>) {
+    let segment_id = match segment_id {
+        Some(id) => id,
+        None => return,
+    };
+
+    let _ = app.emit("transcript-segment-final", serde_json::json!({
+        "segment_id": segment_id,
+        "text": update.map_or("", |u| u.text.as_str()),
+        "sequence_id": update.map(|u| u.sequence_id),
+        "discarded": update.is_none(),
+    }));
+}
+
+/// Report how many chunks are waiting for the cloud provider
+fn emit_backlog_progress<R: Runtime>(app: &AppHandle<R>, backlog: &OfflineQueue) {
+    let _ = app.emit("transcription-backlog-progress", serde_json::json!({
+        "remaining": backlog.len(),
+        "offline": backlog.is_offline(),
+        "dropped": backlog.dropped(),
+    }));
+}
+
+/// Spill directory for a worker's offline backlog
+fn backlog_dir<R: Runtime>(app: &AppHandle<R>, worker_id: usize) -> std::path::PathBuf {
+    app.path()
+        .app_data_dir()
+        .unwrap_or_else(|_| std::env::temp_dir())
+        .join("transcription_backlog")
+        .join(format!("worker_{}", worker_id))
+}
+
+/// Root-mean-square energy of a chunk
+fn calculate_rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|&x| x * x).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
 /// Format current timestamp (wall-clock time)
 fn format_current_timestamp() -> String {
     let now = std::time::SystemTime::now()
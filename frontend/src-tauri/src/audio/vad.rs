@@ -4,6 +4,10 @@ use log::{debug, info};
 use std::collections::VecDeque;
 use std::time::Duration;
 
+// When a segment hits the duration cap, look for the quietest 30ms frame in its last 1.5s (16kHz)
+// so the cut lands between words rather than in the middle of one
+const CUT_SEARCH_SAMPLES: usize = 24000;
+
 /// Represents a complete speech segment detected by VAD
 #[derive(Debug, Clone)]
 pub struct SpeechSegment {
@@ -26,6 +30,12 @@ pub struct ContinuousVadProcessor {
     speech_start_sample: usize,
     // State tracking for smart logging
     last_logged_state: bool,
+    // Duration cap for a single segment (16kHz samples); None = VAD decides alone
+    max_segment_samples: Option<usize>,
+    // Start (ms) of the speech still held in current_speech
+    speech_start_ms: f64,
+    // Current speech was split by the cap, so the session's SpeechEnd samples overlap what was sent
+    forced_cut: bool,
 }
 
 impl ContinuousVadProcessor {
@@ -79,9 +89,49 @@ impl ContinuousVadProcessor {
             speech_start_sample: 0,
             // Initialize state tracking
             last_logged_state: false,
+            max_segment_samples: None,
+            speech_start_ms: 0.0,
+            forced_cut: false,
         })
     }
 
+    /// Cap segment length so long uninterrupted speech is still transcribed in bounded pieces
+    pub fn set_max_segment_duration(&mut self, max_duration_ms: Option<u32>) {
+        self.max_segment_samples = max_duration_ms.map(|ms| (ms as usize * 16).max(self.chunk_size));
+    }
+
+    /// Close the speech in progress now, keeping speech detection open for what follows
+    /// Used by the pipeline's flush timer when audio stops arriving mid-utterance
+    pub fn force_cut(&mut self) -> Option<SpeechSegment> {
+        if !self.in_speech || self.current_speech.is_empty() {
+            return None;
+        }
+        let now_ms = self.processed_samples as f64 / 16.0;
+        let segment = self.take_current_speech(self.current_speech.len(), now_ms);
+        Some(segment)
+    }
+
+    /// Split current_speech at `cut`, returning the head as a segment and keeping the tail
+    fn take_current_speech(&mut self, cut: usize, now_ms: f64) -> SpeechSegment {
+        let remainder = self.current_speech.split_off(cut);
+        let samples = std::mem::replace(&mut self.current_speech, remainder);
+        let end_ms = now_ms - self.current_speech.len() as f64 / 16.0;
+
+        let segment = SpeechSegment {
+            samples,
+            start_timestamp_ms: self.speech_start_ms,
+            end_timestamp_ms: end_ms,
+            confidence: 0.85, // Forced cut, not a natural pause
+        };
+
+        info!("VAD: Forced segment cut after {:.1}ms of continuous speech",
+              segment.end_timestamp_ms - segment.start_timestamp_ms);
+
+        self.speech_start_ms = end_ms;
+        self.forced_cut = true;
+        segment
+    }
+
     /// Process incoming audio samples and return any complete speech segments
     /// Handles resampling from input sample rate to 16kHz for VAD processing
     pub fn process_audio(&mut self, samples: &[f32]) -> Result<Vec<SpeechSegment>> {
@@ -202,6 +252,29 @@ impl ContinuousVadProcessor {
         Ok(completed_segments)
     }
 
+    /// Whether the VAD is currently inside a speech segment
+    pub fn is_in_speech(&self) -> bool {
+        self.in_speech
+    }
+
+    /// Snapshot of the speech segment still in progress, used for live partial transcripts
+    pub fn in_progress_speech(&self) -> Option<SpeechSegment> {
+        if !self.in_speech || self.current_speech.is_empty() {
+            return None;
+        }
+
+        // current_speech and processed_samples are both at the 16kHz VAD rate
+        let end_ms = self.processed_samples as f64 / 16.0;
+        let start_ms = end_ms - self.current_speech.len() as f64 / 16.0;
+
+        Some(SpeechSegment {
+            samples: self.current_speech.clone(),
+            start_timestamp_ms: start_ms.max(0.0),
+            end_timestamp_ms: end_ms,
+            confidence: 0.5, // Speech may still change
+        })
+    }
+
     fn process_chunk(&mut self, chunk: &[f32]) -> Result<()> {
         let transitions = self.session.process(chunk)
             .map_err(|e| anyhow!("VAD processing failed: {}", e))?;
@@ -217,6 +290,8 @@ impl ContinuousVadProcessor {
                     }
                     self.in_speech = true;
                     self.speech_start_sample = self.processed_samples + (timestamp_ms * self.sample_rate as usize / 1000);
+                    self.speech_start_ms = timestamp_ms as f64;
+                    self.forced_cut = false;
                     self.current_speech.clear();
                 }
                 VadTransition::SpeechEnd { start_timestamp_ms, end_timestamp_ms, samples } => {
@@ -227,17 +302,21 @@ impl ContinuousVadProcessor {
                     }
                     self.in_speech = false;
 
-                    // Use samples from VAD transition if available, otherwise use accumulated samples
-                    let speech_samples = if !samples.is_empty() {
-                        samples
+                    // Use samples from VAD transition if available, otherwise use accumulated samples.
+                    // After a forced cut only the remainder is new - the session's samples start at the original onset
+                    let (speech_samples, segment_start_ms) = if self.forced_cut {
+                        (self.current_speech.clone(), self.speech_start_ms)
+                    } else if !samples.is_empty() {
+                        (samples, start_timestamp_ms as f64)
                     } else {
-                        self.current_speech.clone()
+                        (self.current_speech.clone(), start_timestamp_ms as f64)
                     };
+                    self.forced_cut = false;
 
                     if !speech_samples.is_empty() {
                         let segment = SpeechSegment {
                             samples: speech_samples,
-                            start_timestamp_ms: start_timestamp_ms as f64,
+                            start_timestamp_ms: segment_start_ms,
                             end_timestamp_ms: end_timestamp_ms as f64,
                             confidence: 0.9, // VAD confidence
                         };
@@ -259,10 +338,41 @@ impl ContinuousVadProcessor {
         }
 
         self.processed_samples += chunk.len();
+
+        // Enforce the duration cap on long uninterrupted speech
+        if let Some(max_samples) = self.max_segment_samples {
+            if self.in_speech && self.current_speech.len() >= max_samples {
+                let cut = quietest_cut_point(&self.current_speech, self.chunk_size, CUT_SEARCH_SAMPLES);
+                let now_ms = self.processed_samples as f64 / 16.0;
+                let segment = self.take_current_speech(cut, now_ms);
+                self.speech_segments.push_back(segment);
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Index of the start of the lowest-energy frame within the last `search_len` samples.
+/// Never returns 0 so the cut always produces a non-empty segment.
+fn quietest_cut_point(samples: &[f32], frame_size: usize, search_len: usize) -> usize {
+    let search_start = samples.len().saturating_sub(search_len).max(frame_size);
+    let mut best = samples.len();
+    let mut best_energy = f32::MAX;
+
+    let mut start = search_start;
+    while start + frame_size <= samples.len() {
+        let energy: f32 = samples[start..start + frame_size].iter().map(|&x| x * x).sum();
+        if energy < best_energy {
+            best_energy = energy;
+            best = start;
+        }
+        start += frame_size;
+    }
+
+    best.min(samples.len())
+}
+
 /// Legacy function for backward compatibility - now uses the optimized approach
 pub fn extract_speech_16k(samples_mono_16k: &[f32]) -> Result<Vec<f32>> {
     let mut processor = ContinuousVadProcessor::new(16000, 400)?;
@@ -317,3 +427,25 @@ pub fn get_speech_chunks(samples_mono_16k: &[f32], redemption_time_ms: u32) -> R
 }
 
  
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quietest_cut_point_finds_pause() {
+        // 3s of "speech" with a quiet frame at 2.4s
+        let mut samples = vec![0.3f32; 48000];
+        for s in samples.iter_mut().skip(38400).take(480) {
+            *s = 0.001;
+        }
+        assert_eq!(quietest_cut_point(&samples, 480, CUT_SEARCH_SAMPLES), 38400);
+    }
+
+    #[test]
+    fn test_quietest_cut_point_never_zero() {
+        let samples = vec![0.0f32; 960];
+        let cut = quietest_cut_point(&samples, 480, CUT_SEARCH_SAMPLES);
+        assert!(cut > 0 && cut <= samples.len());
+    }
+}
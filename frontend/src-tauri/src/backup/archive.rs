@@ -0,0 +1,292 @@
+// Backup archive format
+// A plaintext header (magic, format version, Argon2 salt, stream nonce) followed by the
+// payload encrypted with XChaCha20-Poly1305 in 64 KiB STREAM chunks, so archives of any
+// size are written and read without holding them in memory, and truncation or tampering
+// fails to decrypt. The payload is a sequence of entries, each a name length (u16 LE), the
+// UTF-8 name, a data length (u64 LE) and the data.
+
+use argon2::Argon2;
+use chacha20poly1305::aead::generic_array::GenericArray;
+use chacha20poly1305::aead::stream::{DecryptorBE32, EncryptorBE32};
+use chacha20poly1305::{Key, KeyInit, XChaCha20Poly1305};
+use rand::{rngs::OsRng, RngCore};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+const MAGIC: &[u8; 8] = b"MMBACKUP";
+const FORMAT_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+/// XChaCha20's 24-byte nonce minus STREAM's 4-byte counter and last-chunk flag
+const NONCE_LEN: usize = 19;
+const CHUNK_LEN: usize = 64 * 1024;
+const TAG_LEN: usize = 16;
+
+fn derive_key(password: &str, salt: &[u8]) -> io::Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("Failed to derive the backup key: {}", e)))?;
+    Ok(key)
+}
+
+fn cipher(password: &str, salt: &[u8]) -> io::Result<XChaCha20Poly1305> {
+    Ok(XChaCha20Poly1305::new(Key::from_slice(&derive_key(password, salt)?)))
+}
+
+/// Encrypts everything written to it; `finish` must be called to seal the archive
+pub struct EncryptingWriter<W: Write> {
+    inner: W,
+    encryptor: Option<EncryptorBE32<XChaCha20Poly1305>>,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> EncryptingWriter<W> {
+    pub fn new(mut inner: W, password: &str) -> io::Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut salt);
+        OsRng.fill_bytes(&mut nonce);
+        let encryptor = EncryptorBE32::from_aead(cipher(password, &salt)?, GenericArray::from_slice(&nonce));
+
+        inner.write_all(MAGIC)?;
+        inner.write_all(&[FORMAT_VERSION])?;
+        inner.write_all(&salt)?;
+        inner.write_all(&nonce)?;
+        Ok(Self {
+            inner,
+            encryptor: Some(encryptor),
+            buffer: Vec::with_capacity(CHUNK_LEN),
+        })
+    }
+
+    /// Encrypt the final chunk and hand back the underlying writer
+    pub fn finish(mut self) -> io::Result<W> {
+        let encryptor = self
+            .encryptor
+            .take()
+            .ok_or_else(|| io::Error::other("Backup archive already finished"))?;
+        let chunk = encryptor
+            .encrypt_last(self.buffer.as_slice())
+            .map_err(|_| io::Error::other("Failed to encrypt the backup"))?;
+        self.inner.write_all(&chunk)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for EncryptingWriter<W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        if data.is_empty() {
+            return Ok(0);
+        }
+        // A full chunk is only sealed once more data arrives, since the last one is
+        // encrypted differently
+        if self.buffer.len() == CHUNK_LEN {
+            let encryptor = self
+                .encryptor
+                .as_mut()
+                .ok_or_else(|| io::Error::other("Backup archive already finished"))?;
+            let chunk = encryptor
+                .encrypt_next(self.buffer.as_slice())
+                .map_err(|_| io::Error::other("Failed to encrypt the backup"))?;
+            self.inner.write_all(&chunk)?;
+            self.buffer.clear();
+        }
+        let taken = data.len().min(CHUNK_LEN - self.buffer.len());
+        self.buffer.extend_from_slice(&data[..taken]);
+        Ok(taken)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Up to `len` bytes, fewer only at the end of the input
+fn read_up_to(reader: &mut impl Read, len: usize) -> io::Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(len);
+    reader.take(len as u64).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+fn damaged() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "Wrong password, or the backup is damaged or incomplete",
+    )
+}
+
+/// Decrypts an archive written by `EncryptingWriter`
+pub struct DecryptingReader<R: Read> {
+    inner: R,
+    decryptor: Option<DecryptorBE32<XChaCha20Poly1305>>,
+    /// Ciphertext read ahead, to tell whether the current chunk is the last
+    next_chunk: Vec<u8>,
+    plain: Vec<u8>,
+    position: usize,
+}
+
+impl<R: Read> DecryptingReader<R> {
+    pub fn new(mut inner: R, password: &str) -> io::Result<Self> {
+        let mut header = [0u8; 8 + 1 + SALT_LEN + NONCE_LEN];
+        inner.read_exact(&mut header).map_err(|_| not_a_backup())?;
+        if &header[..8] != MAGIC {
+            return Err(not_a_backup());
+        }
+        if header[8] > FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "This backup was made by a newer version of the app; update the app to restore it",
+            ));
+        }
+        let (salt, nonce) = header[9..].split_at(SALT_LEN);
+        let decryptor = DecryptorBE32::from_aead(cipher(password, salt)?, GenericArray::from_slice(nonce));
+        let next_chunk = read_up_to(&mut inner, CHUNK_LEN + TAG_LEN)?;
+        Ok(Self {
+            inner,
+            decryptor: Some(decryptor),
+            next_chunk,
+            plain: Vec::new(),
+            position: 0,
+        })
+    }
+
+    /// Decrypt the next chunk into `plain`; false once the last chunk has been read
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.decryptor.is_none() {
+            return Ok(false);
+        }
+        let chunk = std::mem::take(&mut self.next_chunk);
+        self.next_chunk = read_up_to(&mut self.inner, CHUNK_LEN + TAG_LEN)?;
+        self.plain = if self.next_chunk.is_empty() {
+            let decryptor = self.decryptor.take().ok_or_else(damaged)?;
+            decryptor.decrypt_last(chunk.as_slice()).map_err(|_| damaged())?
+        } else {
+            let decryptor = self.decryptor.as_mut().ok_or_else(damaged)?;
+            decryptor.decrypt_next(chunk.as_slice()).map_err(|_| damaged())?
+        };
+        self.position = 0;
+        Ok(true)
+    }
+}
+
+fn not_a_backup() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "The file is not a backup made by this app")
+}
+
+impl<R: Read> Read for DecryptingReader<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.plain.len() {
+            if !self.fill()? {
+                return Ok(0);
+            }
+        }
+        let count = out.len().min(self.plain.len() - self.position);
+        out[..count].copy_from_slice(&self.plain[self.position..self.position + count]);
+        self.position += count;
+        Ok(count)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryHeader {
+    pub name: String,
+    pub len: u64,
+}
+
+/// Write one entry with exactly `len` bytes from `data`
+pub fn write_entry(writer: &mut impl Write, name: &str, len: u64, data: &mut impl Read) -> io::Result<()> {
+    let name_len = u16::try_from(name.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("Path too long for a backup: {}", name)))?;
+    writer.write_all(&name_len.to_le_bytes())?;
+    writer.write_all(name.as_bytes())?;
+    writer.write_all(&len.to_le_bytes())?;
+    let copied = io::copy(&mut data.take(len), writer)?;
+    if copied != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("{} changed while it was being backed up", name),
+        ));
+    }
+    Ok(())
+}
+
+/// Header of the next entry, None at the end of the archive; the caller then reads or
+/// skips exactly `len` bytes
+pub fn read_entry_header(reader: &mut impl Read) -> io::Result<Option<EntryHeader>> {
+    let name_len = read_up_to(reader, 2)?;
+    match name_len.len() {
+        0 => return Ok(None),
+        2 => {}
+        _ => return Err(damaged()),
+    }
+    let name = read_up_to(reader, u16::from_le_bytes([name_len[0], name_len[1]]) as usize)?;
+    let name = String::from_utf8(name).map_err(|_| damaged())?;
+    let mut len = [0u8; 8];
+    reader.read_exact(&mut len).map_err(|_| damaged())?;
+    Ok(Some(EntryHeader {
+        name,
+        len: u64::from_le_bytes(len),
+    }))
+}
+
+/// An entry name ("a/b/c") as a relative path, None if it could escape the folder it is
+/// extracted into
+pub fn safe_relative_path(name: &str) -> Option<PathBuf> {
+    if name.is_empty() || name.contains('\\') || name.contains(':') {
+        return None;
+    }
+    let path = Path::new(name);
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        .then(|| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_round_trip_and_unsafe_names_are_rejected() {
+        let mut payload = Vec::new();
+        write_entry(&mut payload, "manifest.json", 2, &mut &b"{}"[..]).unwrap();
+        write_entry(&mut payload, "recordings/Standup/audio.mp4", 3, &mut &b"abcdef"[..]).unwrap();
+        assert!(write_entry(&mut payload, "short", 10, &mut &b"abc"[..]).is_err());
+
+        let mut reader = payload.as_slice();
+        let first = read_entry_header(&mut reader).unwrap().unwrap();
+        assert_eq!(first, EntryHeader { name: "manifest.json".to_string(), len: 2 });
+        assert_eq!(read_up_to(&mut reader, first.len as usize).unwrap(), b"{}");
+        let second = read_entry_header(&mut reader).unwrap().unwrap();
+        assert_eq!(read_up_to(&mut reader, second.len as usize).unwrap(), b"abc");
+
+        assert_eq!(safe_relative_path("Standup/audio.mp4"), Some(PathBuf::from("Standup").join("audio.mp4")));
+        for name in ["", "../etc/passwd", "/etc/passwd", "a/../../b", "C:\\x", "a\\..\\b", "./a"] {
+            assert_eq!(safe_relative_path(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn encrypted_archive_round_trips_and_detects_tampering() {
+        let data: Vec<u8> = (0..CHUNK_LEN * 2 + 123).map(|i| (i % 251) as u8).collect();
+        let mut writer = EncryptingWriter::new(Vec::new(), "correct horse").unwrap();
+        writer.write_all(&data).unwrap();
+        let archive = writer.finish().unwrap();
+
+        let mut plain = Vec::new();
+        DecryptingReader::new(archive.as_slice(), "correct horse")
+            .unwrap()
+            .read_to_end(&mut plain)
+            .unwrap();
+        assert_eq!(plain, data);
+
+        let mut ignored = Vec::new();
+        let wrong_password = DecryptingReader::new(archive.as_slice(), "wrong").unwrap().read_to_end(&mut ignored);
+        assert_eq!(wrong_password.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        // Cut at a chunk boundary: the remaining last chunk wasn't sealed as last
+        let header_len = 8 + 1 + SALT_LEN + NONCE_LEN;
+        let truncated = &archive[..header_len + 2 * (CHUNK_LEN + TAG_LEN)];
+        assert!(DecryptingReader::new(truncated, "correct horse").unwrap().read_to_end(&mut ignored).is_err());
+        assert!(DecryptingReader::new(&b"PK\x03\x04 not a backup at all......"[..], "x").is_err());
+    }
+}
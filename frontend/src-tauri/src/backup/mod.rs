@@ -0,0 +1,468 @@
+// Backup and restore
+// One password-protected archive holds the whole database, every meeting recording and
+// the files attached to meetings, for moving to another machine or recovering from a lost
+// disk. Restoring stages the database
+// to replace the current one when the app restarts, like switching encryption does.
+// The database travels encrypted with a key of its own, kept in the archive's manifest, so
+// the snapshot and the extracted copy written next to the database are never plaintext.
+
+pub mod archive;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnection, SqlitePool};
+use sqlx::Connection;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::attachments::attachments_dir;
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::database::encryption::{
+    current_key, database_path, export_copy, generate_key, keyed_options, raw_key, sibling, stage_copy,
+};
+use crate::database::migrations::latest_known;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::export::export_path;
+use crate::state::AppState;
+use archive::{read_entry_header, safe_relative_path, write_entry, DecryptingReader, EncryptingWriter};
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const DATABASE_ENTRY: &str = "database.sqlite";
+const RECORDINGS_PREFIX: &str = "recordings/";
+const ATTACHMENTS_PREFIX: &str = "attachments/";
+const MIN_PASSWORD_CHARS: usize = 8;
+const MAX_MANIFEST_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackedUpRecording {
+    /// Recording folder on the machine the backup was made on
+    pub original_path: String,
+    /// Folder name inside the archive's recordings/
+    pub archive_dir: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub app_version: String,
+    pub created_at: DateTime<Utc>,
+    /// Latest database migration applied when the backup was made
+    pub schema_version: i64,
+    pub meetings: i64,
+    pub recordings: Vec<BackedUpRecording>,
+    /// Key (hex) the database entry is encrypted with, so the copy written next to the
+    /// database is never plaintext; None in backups that hold a plain database
+    #[serde(default)]
+    pub database_key: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BackupSummary {
+    pub path: String,
+    pub meetings: i64,
+    pub recordings: usize,
+    pub size_bytes: u64,
+}
+
+fn app_data_dir<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to locate the app data directory: {}", e))
+}
+
+fn check_password(password: &str) -> Result<(), String> {
+    if password.chars().count() < MIN_PASSWORD_CHARS {
+        return Err(format!("Use a backup password of at least {} characters", MIN_PASSWORD_CHARS));
+    }
+    Ok(())
+}
+
+async fn latest_applied_migration(pool: &SqlitePool) -> Result<i64, String> {
+    sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations WHERE success = 1")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("Failed to read the database version: {}", e))
+}
+
+/// Files of a recording folder relative to it, without incremental-save checkpoints
+fn recording_files(folder: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        for entry in std::fs::read_dir(folder.join(&relative))? {
+            let entry = entry?;
+            let path = relative.join(entry.file_name());
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() && entry.file_name() != ".checkpoints" {
+                pending.push(path);
+            } else if file_type.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// A relative path as it appears in entry names ("/"-separated on every OS)
+fn entry_path(relative: &Path) -> String {
+    let parts: Vec<String> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect();
+    parts.join("/")
+}
+
+/// Archive entry name of a path relative to a recording folder
+fn recording_entry_name(archive_dir: &str, relative: &Path) -> String {
+    format!("{}{}/{}", RECORDINGS_PREFIX, archive_dir, entry_path(relative))
+}
+
+fn write_file_entry(writer: &mut impl io::Write, name: &str, path: &Path) -> io::Result<()> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    write_entry(writer, name, len, &mut file)
+}
+
+fn write_archive(
+    output: &Path,
+    password: &str,
+    manifest: &BackupManifest,
+    snapshot: &Path,
+    attachments: &Path,
+) -> io::Result<u64> {
+    let mut partial = output.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+
+    let result = (|| {
+        let mut writer = EncryptingWriter::new(BufWriter::new(File::create(&partial)?), password)?;
+        let manifest_json = serde_json::to_vec_pretty(manifest)?;
+        write_entry(&mut writer, MANIFEST_ENTRY, manifest_json.len() as u64, &mut manifest_json.as_slice())?;
+        write_file_entry(&mut writer, DATABASE_ENTRY, snapshot)?;
+        for recording in &manifest.recordings {
+            let folder = Path::new(&recording.original_path);
+            for relative in recording_files(folder)? {
+                let name = recording_entry_name(&recording.archive_dir, &relative);
+                write_file_entry(&mut writer, &name, &folder.join(&relative))?;
+            }
+        }
+        if attachments.is_dir() {
+            for relative in recording_files(attachments)? {
+                let name = format!("{}{}", ATTACHMENTS_PREFIX, entry_path(&relative));
+                write_file_entry(&mut writer, &name, &attachments.join(&relative))?;
+            }
+        }
+        let file = writer.finish()?.into_inner().map_err(|e| e.into_error())?;
+        file.sync_all()?;
+        std::fs::rename(&partial, output)?;
+        std::fs::metadata(output).map(|metadata| metadata.len())
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(&partial);
+    }
+    result
+}
+
+/// Back up every meeting, setting, recording and attachment into one encrypted archive
+///
+/// # Arguments
+/// * `path` - Archive to write; ".mmbackup" is added when it has no extension
+/// * `password` - Needed to restore; it is not stored anywhere, so a lost password means
+///   a lost backup
+#[command]
+pub async fn create_backup<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+    password: String,
+) -> Result<BackupSummary, String> {
+    check_password(&password)?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let db_path = database_path(&app)?;
+
+    let schema_version = latest_applied_migration(pool).await?;
+    let meetings: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM meetings")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("Failed to count meetings: {}", e))?;
+    let folders = MeetingsRepository::list_recording_folders(pool)
+        .await
+        .map_err(|e| format!("Failed to list recording folders: {}", e))?;
+
+    let mut recordings = Vec::new();
+    let mut used_names = HashSet::new();
+    for folder in folders {
+        let folder_path = Path::new(&folder);
+        if !folder_path.is_dir() {
+            warn!("Recording folder {} is missing; backing up without it", folder);
+            continue;
+        }
+        let base = folder_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "recording".to_string());
+        let mut archive_dir = base.clone();
+        let mut suffix = 2;
+        while !used_names.insert(archive_dir.clone()) {
+            archive_dir = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        recordings.push(BackedUpRecording {
+            original_path: folder,
+            archive_dir,
+        });
+    }
+
+    // A consistent snapshot under a key of its own, which travels in the manifest: the
+    // archive is encrypted with the password, and another machine can open the database
+    let database_key = generate_key();
+    let snapshot = sibling(&db_path, &format!("backup-{}", Uuid::new_v4()));
+    let mut conn = pool.acquire().await.map_err(|e| format!("Failed to open the database: {}", e))?;
+    let exported = export_copy(&mut conn, &snapshot, &raw_key(&database_key)?).await;
+    drop(conn);
+    if let Err(e) = exported {
+        let _ = std::fs::remove_file(&snapshot);
+        return Err(e);
+    }
+
+    let manifest = BackupManifest {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Utc::now(),
+        schema_version,
+        meetings,
+        recordings,
+        database_key: Some(database_key),
+    };
+    let output = export_path(&path, "mmbackup");
+    let attachments = attachments_dir(&app_data_dir(&app)?);
+    let (written_manifest, archive_path, snapshot_path) = (manifest.clone(), output.clone(), snapshot.clone());
+    let written = tokio::task::spawn_blocking(move || {
+        write_archive(&archive_path, &password, &written_manifest, &snapshot_path, &attachments)
+    })
+    .await
+    .map_err(|e| format!("Backup task failed: {}", e));
+    if let Err(e) = std::fs::remove_file(&snapshot) {
+        warn!("Failed to remove the backup snapshot {}: {}", snapshot.display(), e);
+    }
+    let size_bytes = written?.map_err(|e| format!("Failed to write the backup: {}", e))?;
+
+    info!(
+        "Backed up {} meeting(s) and {} recording folder(s) to {} ({} bytes)",
+        manifest.meetings,
+        manifest.recordings.len(),
+        output.display(),
+        size_bytes
+    );
+    Ok(BackupSummary {
+        path: output.to_string_lossy().to_string(),
+        meetings: manifest.meetings,
+        recordings: manifest.recordings.len(),
+        size_bytes,
+    })
+}
+
+/// Copy exactly `len` bytes of the current entry to `destination` (None skips them)
+fn copy_entry(reader: &mut impl Read, len: u64, destination: Option<&Path>) -> io::Result<()> {
+    let mut data = reader.take(len);
+    let copied = match destination {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            io::copy(&mut data, &mut BufWriter::new(File::create(path)?))?
+        }
+        None => io::copy(&mut data, &mut io::sink())?,
+    };
+    if copied != len {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "The backup is incomplete"));
+    }
+    Ok(())
+}
+
+/// Unpack an archive: the database to `database`, recordings into `recordings_dir` and
+/// attached files into `attachments`
+///
+/// A recording folder that still exists at its original path is left alone and reused.
+/// Returns the manifest and the (original, new) path of every recording folder moved.
+fn extract_archive(
+    archive: &Path,
+    password: &str,
+    database: &Path,
+    recordings_dir: &Path,
+    attachments: &Path,
+) -> Result<(BackupManifest, Vec<(String, String)>), String> {
+    let read_error = |e: io::Error| format!("Failed to read the backup: {}", e);
+    let file = File::open(archive).map_err(|e| format!("Failed to open {}: {}", archive.display(), e))?;
+    let mut reader = DecryptingReader::new(BufReader::new(file), password).map_err(read_error)?;
+
+    let manifest_header = read_entry_header(&mut reader)
+        .map_err(read_error)?
+        .filter(|header| header.name == MANIFEST_ENTRY && header.len <= MAX_MANIFEST_BYTES)
+        .ok_or_else(|| "The backup has no manifest".to_string())?;
+    let mut manifest_json = Vec::new();
+    (&mut reader)
+        .take(manifest_header.len)
+        .read_to_end(&mut manifest_json)
+        .map_err(read_error)?;
+    let manifest: BackupManifest =
+        serde_json::from_slice(&manifest_json).map_err(|e| format!("The backup manifest is invalid: {}", e))?;
+    // Backups of a schema newer than this build knows can't be restored
+    if manifest.schema_version > latest_known() {
+        return Err(format!(
+            "This backup was made by a newer version of the app ({}); update the app to restore it",
+            manifest.app_version
+        ));
+    }
+
+    // Folder each recording is extracted into; None keeps the existing original folder
+    let mut destinations: HashMap<String, Option<PathBuf>> = HashMap::new();
+    let mut moves = Vec::new();
+    for recording in &manifest.recordings {
+        let single_folder = safe_relative_path(&recording.archive_dir).filter(|path| path.components().count() == 1);
+        if single_folder.is_none() {
+            return Err(format!("Unsafe recording folder in backup: {}", recording.archive_dir));
+        }
+        if Path::new(&recording.original_path).is_dir() {
+            destinations.insert(recording.archive_dir.clone(), None);
+            continue;
+        }
+        let mut destination = recordings_dir.join(&recording.archive_dir);
+        let mut suffix = 2;
+        while destination.exists() {
+            destination = recordings_dir.join(format!("{} ({})", recording.archive_dir, suffix));
+            suffix += 1;
+        }
+        moves.push((recording.original_path.clone(), destination.to_string_lossy().to_string()));
+        destinations.insert(recording.archive_dir.clone(), Some(destination));
+    }
+
+    let result = (|| {
+        let mut database_restored = false;
+        while let Some(entry) = read_entry_header(&mut reader).map_err(read_error)? {
+            let destination = if entry.name == DATABASE_ENTRY {
+                database_restored = true;
+                Some(database.to_path_buf())
+            } else if let Some(name) = entry.name.strip_prefix(RECORDINGS_PREFIX) {
+                let relative =
+                    safe_relative_path(name).ok_or_else(|| format!("Unsafe path in backup: {}", entry.name))?;
+                let mut components = relative.components();
+                let archive_dir = components.next().map(|dir| dir.as_os_str().to_string_lossy().to_string());
+                archive_dir
+                    .and_then(|dir| destinations.get(&dir).cloned().flatten())
+                    .map(|folder| folder.join(components.as_path()))
+            } else if let Some(name) = entry.name.strip_prefix(ATTACHMENTS_PREFIX) {
+                let relative =
+                    safe_relative_path(name).ok_or_else(|| format!("Unsafe path in backup: {}", entry.name))?;
+                Some(attachments.join(relative))
+            } else {
+                // Written by a newer version; nothing this one can use
+                None
+            };
+            copy_entry(&mut reader, entry.len, destination.as_deref()).map_err(read_error)?;
+        }
+        if database_restored {
+            Ok(())
+        } else {
+            Err("The backup has no database".to_string())
+        }
+    })();
+
+    if let Err(e) = result {
+        for folder in destinations.values().flatten() {
+            let _ = std::fs::remove_dir_all(folder);
+        }
+        let _ = std::fs::remove_file(database);
+        return Err(e);
+    }
+    Ok((manifest, moves))
+}
+
+/// Restore a backup made by `create_backup`, replacing every meeting in this app
+///
+/// Recordings go to the recordings folder (existing ones at their original path are
+/// reused) and meetings are pointed at them. The restored database is staged, encrypted
+/// if the current one is, and the app restarts to switch to it; migrations then bring an
+/// older backup up to date.
+#[command]
+pub async fn restore_backup<R: Runtime>(app: AppHandle<R>, path: String, password: String) -> Result<(), String> {
+    let db_path = database_path(&app)?;
+    let key = current_key(&db_path)?;
+    let recordings_dir = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+        .save_folder;
+
+    let attachments = attachments_dir(&app_data_dir(&app)?);
+
+    let restoring = sibling(&db_path, "restoring");
+    let (archive_path, restoring_path) = (PathBuf::from(&path), restoring.clone());
+    let (manifest, moves) = tokio::task::spawn_blocking(move || {
+        extract_archive(&archive_path, &password, &restoring_path, &recordings_dir, &attachments)
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))??;
+
+    let staged = async {
+        let backup_key = match &manifest.database_key {
+            Some(database_key) => raw_key(database_key)?,
+            None => String::new(),
+        };
+        let options = keyed_options(&restoring, &backup_key)?;
+        let mut conn = SqliteConnection::connect_with(&options)
+            .await
+            .map_err(|e| format!("Failed to open the restored database: {}", e))?;
+        for (original, restored) in &moves {
+            MeetingsRepository::move_recording_folder(&mut conn, original, restored)
+                .await
+                .map_err(|e| format!("Failed to update recording folders: {}", e))?;
+        }
+        stage_copy(&mut conn, &db_path, &key).await?;
+        conn.close()
+            .await
+            .map_err(|e| format!("Failed to close the restored database: {}", e))
+    }
+    .await;
+    for suffix in ["", "-wal", "-shm", "-journal"] {
+        let mut path = restoring.as_os_str().to_owned();
+        path.push(suffix);
+        let _ = std::fs::remove_file(PathBuf::from(path));
+    }
+    staged?;
+
+    info!(
+        "Restored backup of {} meeting(s) from {} (made {} by version {}); restarting",
+        manifest.meetings,
+        path,
+        manifest.created_at,
+        manifest.app_version
+    );
+    app.restart()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_entries_use_forward_slashes_and_skip_checkpoints() {
+        let folder = std::env::temp_dir().join(format!("meeting-backup-{}", std::process::id()));
+        std::fs::create_dir_all(folder.join(".checkpoints")).unwrap();
+        std::fs::create_dir_all(folder.join("clips")).unwrap();
+        std::fs::write(folder.join("audio.mp4"), b"audio").unwrap();
+        std::fs::write(folder.join("clips").join("intro.m4a"), b"clip").unwrap();
+        std::fs::write(folder.join(".checkpoints").join("audio_chunk_000.mp4"), b"partial").unwrap();
+
+        let files = recording_files(&folder).unwrap();
+        assert_eq!(files, vec![PathBuf::from("audio.mp4"), Path::new("clips").join("intro.m4a")]);
+        assert_eq!(
+            recording_entry_name("Standup_2026-01-09", &files[1]),
+            "recordings/Standup_2026-01-09/clips/intro.m4a"
+        );
+        std::fs::remove_dir_all(&folder).unwrap();
+    }
+}
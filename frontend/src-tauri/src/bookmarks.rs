@@ -0,0 +1,201 @@
+// Bookmarks
+// Transcript segments the user starred, with an optional note, listed per meeting and across
+// all meetings. While recording, a bookmark marks a moment instead (from the app's hotkey or
+// the tray menu): the segment there may not be transcribed yet, and segments only get their
+// ids once the meeting is saved, so the recording time is held until then and matched to
+// the segment playing at that moment.
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::SegmentBookmark;
+use crate::database::repositories::segment_bookmark::SegmentBookmarksRepository;
+use crate::state::AppState;
+
+const MAX_NOTE_CHARS: usize = 500;
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+// Moments bookmarked in the current recording, waiting for its meeting to be saved
+static PENDING_MOMENTS: Mutex<Vec<PendingMoment>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone)]
+struct PendingMoment {
+    /// Meeting folder of the recording, None when recordings aren't saved
+    folder: Option<PathBuf>,
+    /// Seconds from the recording start
+    audio_time: f64,
+    note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BookmarkedMoment {
+    pub audio_time: f64,
+    pub note: Option<String>,
+}
+
+fn clean_note(note: Option<String>) -> Result<Option<String>, String> {
+    let Some(note) = note.map(|note| note.trim().to_string()).filter(|note| !note.is_empty()) else {
+        return Ok(None);
+    };
+    if note.chars().count() > MAX_NOTE_CHARS {
+        return Err(format!("Bookmark notes are at most {} characters", MAX_NOTE_CHARS));
+    }
+    Ok(Some(note))
+}
+
+/// Segment playing at `time`: the one spanning it, else the last one started before it
+/// (the user reacted to what was just said), else the first
+fn segment_at(segments: &[(String, f64, Option<f64>)], time: f64) -> Option<&str> {
+    segments
+        .iter()
+        .find(|(_, start, end)| *start <= time && end.is_some_and(|end| time < end))
+        .or_else(|| segments.iter().rev().find(|(_, start, _)| *start <= time))
+        .or_else(|| segments.first())
+        .map(|(id, _, _)| id.as_str())
+}
+
+/// Star the segments at the moments bookmarked while recording the meeting just saved from
+/// `folder_path`
+pub async fn attach_pending_moments(pool: &SqlitePool, meeting_id: &str, folder_path: Option<&str>) {
+    let folder = folder_path.map(Path::new);
+    let moments: Vec<PendingMoment> = {
+        let mut pending = PENDING_MOMENTS.lock().unwrap();
+        let (matching, rest) = pending
+            .drain(..)
+            .partition(|moment: &PendingMoment| moment.folder.as_deref() == folder);
+        *pending = rest;
+        matching
+    };
+    if moments.is_empty() {
+        return;
+    }
+
+    let segments = match SegmentBookmarksRepository::timed_segments(pool, meeting_id).await {
+        Ok(segments) => segments,
+        Err(e) => {
+            warn!("Failed to load segments of meeting {} for bookmarks: {}", meeting_id, e);
+            return;
+        }
+    };
+    let mut attached = 0;
+    for moment in &moments {
+        let Some(transcript_id) = segment_at(&segments, moment.audio_time) else {
+            continue;
+        };
+        match SegmentBookmarksRepository::star(pool, meeting_id, transcript_id, moment.note.as_deref()).await {
+            Ok(true) => attached += 1,
+            Ok(false) => {}
+            Err(e) => warn!("Failed to save a bookmark of meeting {}: {}", meeting_id, e),
+        }
+    }
+    info!("Bookmarked {} of {} moment(s) in meeting {}", attached, moments.len(), meeting_id);
+}
+
+/// Bookmark the current moment of the recording; emits "bookmark-added"
+pub fn bookmark_moment<R: Runtime>(app: &AppHandle<R>, note: Option<String>) -> Result<BookmarkedMoment, String> {
+    let note = clean_note(note)?;
+    let (audio_time, folder) =
+        crate::audio::recording_commands::recording_position().ok_or_else(|| "Not recording".to_string())?;
+    {
+        let mut pending = PENDING_MOMENTS.lock().unwrap();
+        // Moments of an earlier recording that was never saved
+        pending.retain(|moment| moment.folder == folder);
+        pending.push(PendingMoment {
+            folder,
+            audio_time,
+            note: note.clone(),
+        });
+    }
+
+    let moment = BookmarkedMoment { audio_time, note };
+    let _ = app.emit("bookmark-added", &moment);
+    Ok(moment)
+}
+
+/// Bookmark what is being said now, during a recording
+#[command]
+pub async fn bookmark_current_moment<R: Runtime>(app: AppHandle<R>, note: Option<String>) -> Result<BookmarkedMoment, String> {
+    bookmark_moment(&app, note)
+}
+
+/// Star or unstar a segment of a saved meeting; starring a starred segment updates its note
+#[command]
+pub async fn set_segment_bookmark<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    transcript_id: String,
+    starred: bool,
+    note: Option<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    if !starred {
+        SegmentBookmarksRepository::unstar(pool, &transcript_id)
+            .await
+            .map_err(|e| format!("Failed to remove bookmark: {}", e))?;
+        return Ok(());
+    }
+
+    let note = clean_note(note)?;
+    let saved = SegmentBookmarksRepository::star(pool, &meeting_id, &transcript_id, note.as_deref())
+        .await
+        .map_err(|e| format!("Failed to save bookmark: {}", e))?;
+    if !saved {
+        return Err(format!("Segment {} not found in meeting {}", transcript_id, meeting_id));
+    }
+    Ok(())
+}
+
+/// A meeting's bookmarked segments, in recording order
+#[command]
+pub async fn get_meeting_bookmarks<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<Vec<SegmentBookmark>, String> {
+    let state = app.state::<AppState>();
+    SegmentBookmarksRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load bookmarks: {}", e))
+}
+
+/// Bookmarks across all meetings, most recently starred first
+#[command]
+pub async fn list_bookmarks<R: Runtime>(
+    app: AppHandle<R>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<Vec<SegmentBookmark>, String> {
+    let state = app.state::<AppState>();
+    SegmentBookmarksRepository::list_recent(
+        state.db_manager.pool(),
+        limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, 500),
+        offset.unwrap_or(0).max(0),
+    )
+    .await
+    .map_err(|e| format!("Failed to load bookmarks: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_moments_to_segments() {
+        let segments = vec![
+            ("s1".to_string(), 2.0, Some(6.0)),
+            ("s2".to_string(), 8.0, Some(14.0)),
+            ("s3".to_string(), 15.0, None),
+        ];
+        assert_eq!(segment_at(&segments, 9.5), Some("s2"));
+        // In the pause after a segment
+        assert_eq!(segment_at(&segments, 7.0), Some("s1"));
+        assert_eq!(segment_at(&segments, 40.0), Some("s3"));
+        assert_eq!(segment_at(&segments, 0.5), Some("s1"));
+        assert_eq!(segment_at(&[], 3.0), None);
+
+        assert_eq!(clean_note(Some("  ".to_string())), Ok(None));
+        assert_eq!(clean_note(Some(" Pricing decision ".to_string())), Ok(Some("Pricing decision".to_string())));
+        assert!(clean_note(Some("x".repeat(MAX_NOTE_CHARS + 1))).is_err());
+    }
+}
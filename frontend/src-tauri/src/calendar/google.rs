@@ -0,0 +1,205 @@
+// Google Calendar
+// Read-only access to the user's primary calendar through the Calendar API. Recurring
+// events are expanded into single occurrences, whose ids ("<series>_<start>") let meeting
+// templates match the whole series.
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde_json::Value;
+
+use super::oauth::OAuthClient;
+use super::{conference_link, CalendarEvent, EventAttendee, GoogleCalendarSettings};
+
+const AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const SCOPES: &[&str] = &["https://www.googleapis.com/auth/calendar.readonly"];
+const API_URL: &str = "https://www.googleapis.com/calendar/v3/calendars/primary";
+/// Keychain account holding the tokens
+pub const TOKEN_ACCOUNT: &str = "calendar-google";
+const MAX_EVENTS: &str = "100";
+
+pub fn client(settings: &GoogleCalendarSettings) -> OAuthClient {
+    OAuthClient {
+        auth_url: AUTH_URL.to_string(),
+        token_url: TOKEN_URL.to_string(),
+        client_id: settings.client_id.trim().to_string(),
+        client_secret: Some(settings.client_secret.trim().to_string()).filter(|secret| !secret.is_empty()),
+        scopes: SCOPES,
+        // A refresh token is only issued with offline access, and only on consent
+        extra_params: &[("access_type", "offline"), ("prompt", "consent")],
+    }
+}
+
+async fn get_json(access_token: &str, url: url::Url) -> Result<Value, String> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .bearer_auth(access_token)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Google Calendar: {}", e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Google Calendar returned {}: {}", status, body.trim()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Invalid response from Google Calendar: {}", e))
+}
+
+/// Address of the connected account: the primary calendar's id
+pub async fn account_email(access_token: &str) -> Result<String, String> {
+    let url = url::Url::parse(API_URL).map_err(|e| e.to_string())?;
+    let calendar = get_json(access_token, url).await?;
+    calendar
+        .get("id")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| "Google Calendar did not return the account".to_string())
+}
+
+/// Events overlapping `from`..`to`, in start order
+pub async fn fetch_events(access_token: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<CalendarEvent>, String> {
+    let mut url = url::Url::parse(&format!("{}/events", API_URL)).map_err(|e| e.to_string())?;
+    url.query_pairs_mut()
+        .append_pair("timeMin", &from.to_rfc3339())
+        .append_pair("timeMax", &to.to_rfc3339())
+        .append_pair("singleEvents", "true")
+        .append_pair("orderBy", "startTime")
+        .append_pair("maxResults", MAX_EVENTS);
+    let body = get_json(access_token, url).await?;
+    Ok(parse_events(&body))
+}
+
+/// Start or end of an event: a time, or a date for all-day events
+fn parse_time(value: &Value) -> Option<(DateTime<Utc>, bool)> {
+    if let Some(time) = value.get("dateTime").and_then(Value::as_str) {
+        return DateTime::parse_from_rfc3339(time).ok().map(|time| (time.with_timezone(&Utc), false));
+    }
+    let date = NaiveDate::parse_from_str(value.get("date")?.as_str()?, "%Y-%m-%d").ok()?;
+    Some((Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?), true))
+}
+
+/// The video call of an event: its conference entry point, else a link in the location or
+/// description
+fn event_conference_url(item: &Value) -> Option<String> {
+    let entry_point = item
+        .pointer("/conferenceData/entryPoints")
+        .and_then(Value::as_array)
+        .and_then(|points| {
+            points
+                .iter()
+                .find(|point| point.get("entryPointType").and_then(Value::as_str) == Some("video"))
+        })
+        .and_then(|point| point.get("uri").and_then(Value::as_str));
+    entry_point
+        .or_else(|| item.get("hangoutLink").and_then(Value::as_str))
+        .map(str::to_string)
+        .or_else(|| {
+            ["location", "description"]
+                .iter()
+                .find_map(|field| item.get(*field).and_then(Value::as_str).and_then(conference_link))
+        })
+}
+
+/// Events from an events list response, without cancelled ones
+fn parse_events(body: &Value) -> Vec<CalendarEvent> {
+    let Some(items) = body.get("items").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter(|item| item.get("status").and_then(Value::as_str) != Some("cancelled"))
+        .filter_map(|item| {
+            let (start, all_day) = parse_time(item.get("start")?)?;
+            let (end, _) = parse_time(item.get("end")?)?;
+            let attendees = item
+                .get("attendees")
+                .and_then(Value::as_array)
+                .map(|attendees| {
+                    attendees
+                        .iter()
+                        // Meeting rooms are listed as attendees too
+                        .filter(|attendee| attendee.get("resource").and_then(Value::as_bool) != Some(true))
+                        .filter(|attendee| attendee.get("responseStatus").and_then(Value::as_str) != Some("declined"))
+                        .filter_map(|attendee| {
+                            let email = attendee.get("email").and_then(Value::as_str).map(str::to_string);
+                            let name = attendee
+                                .get("displayName")
+                                .and_then(Value::as_str)
+                                .map(str::to_string)
+                                .or_else(|| email.clone())?;
+                            Some(EventAttendee { name, email })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            Some(CalendarEvent {
+                id: item.get("id")?.as_str()?.to_string(),
+                provider: "google".to_string(),
+                title: item
+                    .get("summary")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|title| !title.is_empty())
+                    .unwrap_or("Untitled event")
+                    .to_string(),
+                start,
+                end,
+                all_day,
+                description: item.get("description").and_then(Value::as_str).map(str::to_string),
+                attendees,
+                conference_url: event_conference_url(item),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_event_list() {
+        let body = serde_json::json!({
+            "items": [
+                {
+                    "id": "abc123_20260210T090000Z",
+                    "status": "confirmed",
+                    "summary": "Sprint planning",
+                    "description": "Agenda:<br>1. Velocity<br>2. Scope",
+                    "start": { "dateTime": "2026-02-10T10:00:00+01:00" },
+                    "end": { "dateTime": "2026-02-10T11:00:00+01:00" },
+                    "hangoutLink": "https://meet.google.com/abc-defg-hij",
+                    "attendees": [
+                        { "email": "ana@example.com", "displayName": "Ana" },
+                        { "email": "bo@example.com", "responseStatus": "declined" },
+                        { "email": "room@resource.calendar.google.com", "resource": true },
+                        { "email": "cy@example.com" }
+                    ]
+                },
+                {
+                    "id": "holiday",
+                    "summary": "Offsite",
+                    "location": "https://example.zoom.us/j/123456789?pwd=x",
+                    "start": { "date": "2026-02-11" },
+                    "end": { "date": "2026-02-12" }
+                },
+                { "id": "gone", "status": "cancelled" }
+            ]
+        });
+        let events = parse_events(&body);
+        assert_eq!(events.len(), 2);
+
+        let planning = &events[0];
+        assert_eq!(planning.start, Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap());
+        assert!(!planning.all_day);
+        assert_eq!(planning.conference_url.as_deref(), Some("https://meet.google.com/abc-defg-hij"));
+        let names: Vec<&str> = planning.attendees.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Ana", "cy@example.com"]);
+
+        let offsite = &events[1];
+        assert!(offsite.all_day);
+        assert_eq!(offsite.conference_url.as_deref(), Some("https://example.zoom.us/j/123456789?pwd=x"));
+    }
+}
@@ -0,0 +1,622 @@
+// iCalendar (RFC 5545) events
+// Reads the VEVENTs of an .ics feed or CalDAV response into calendar events within a time
+// window. Recurring events are expanded here (daily, weekly, monthly and yearly rules with
+// the common BYDAY / BYMONTHDAY forms, COUNT, UNTIL and EXDATE), with moved or cancelled
+// occurrences taken from their RECURRENCE-ID overrides. Times with a TZID are converted
+// through the IANA database; unknown zones and floating times are read as local time.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc, Weekday};
+use std::collections::HashMap;
+
+use super::{conference_link, CalendarEvent, EventAttendee};
+
+/// Recurrence periods walked at most per event, so a malformed rule can't loop for long
+const MAX_PERIODS: u32 = 5000;
+
+#[derive(Debug, Clone)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Content lines with folded continuation lines joined
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)) {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// "NAME;KEY=value;KEY=\"quoted\":VALUE"
+fn parse_line(line: &str) -> Option<Property> {
+    // The value starts at the first colon outside a quoted parameter
+    let mut quoted = false;
+    let split = line.char_indices().find(|(_, c)| {
+        if *c == '"' {
+            quoted = !quoted;
+        }
+        *c == ':' && !quoted
+    })?;
+    let (head, value) = (&line[..split.0], &line[split.0 + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_uppercase();
+    let params = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| (key.trim().to_uppercase(), value.trim().trim_matches('"').to_string()))
+        .collect();
+    Some(Property {
+        name,
+        params,
+        value: value.to_string(),
+    })
+}
+
+/// TEXT values with their escapes undone
+fn unescape(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => text.push('\n'),
+            Some(other) => text.push(other),
+            None => {}
+        }
+    }
+    text
+}
+
+/// Properties of each VEVENT, leaving out nested components such as alarms
+fn vevents(text: &str) -> Vec<Vec<Property>> {
+    let mut events = Vec::new();
+    let mut current: Option<Vec<Property>> = None;
+    let mut nested = 0;
+    for property in unfold(text).iter().filter_map(|line| parse_line(line)) {
+        let component = property.value.trim().to_uppercase();
+        match (property.name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if component == "VEVENT" => current = Some(Vec::new()),
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) if component == "VEVENT" => events.extend(current.take()),
+            (_, Some(properties)) if nested == 0 => properties.push(property),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// Where an event's local times are anchored
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Zone {
+    Utc,
+    Named(chrono_tz::Tz),
+    Local,
+}
+
+impl Zone {
+    fn of(property: &Property) -> Self {
+        if property.value.trim().ends_with('Z') {
+            return Zone::Utc;
+        }
+        let Some(tzid) = property.param("TZID") else {
+            return Zone::Local;
+        };
+        // Some producers prefix the id with a vendor path, e.g. "/mozilla.org/20050126_1/Europe/Berlin"
+        let mut tail: Vec<&str> = tzid.rsplitn(3, '/').take(2).collect();
+        tail.reverse();
+        tzid.trim_start_matches('/')
+            .parse::<chrono_tz::Tz>()
+            .or_else(|_| tail.join("/").parse())
+            .map(Zone::Named)
+            .unwrap_or(Zone::Local)
+    }
+
+    /// A local time in this zone; times skipped by a DST change move past the gap
+    fn to_utc(self, time: NaiveDateTime) -> Option<DateTime<Utc>> {
+        fn resolve<Z: TimeZone>(zone: &Z, time: NaiveDateTime) -> Option<DateTime<Utc>> {
+            zone.from_local_datetime(&time)
+                .earliest()
+                .or_else(|| zone.from_local_datetime(&(time + Duration::hours(1))).earliest())
+                .map(|time| time.with_timezone(&Utc))
+        }
+        match self {
+            Zone::Utc => Some(Utc.from_utc_datetime(&time)),
+            Zone::Named(tz) => resolve(&tz, time),
+            Zone::Local => resolve(&Local, time),
+        }
+    }
+}
+
+/// A DATE or DATE-TIME value: the local time and whether it is a whole day
+fn parse_value_time(value: &str) -> Option<(NaiveDateTime, bool)> {
+    let value = value.trim().trim_end_matches('Z');
+    if value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((date.and_time(NaiveTime::MIN), true));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok().map(|time| (time, false))
+}
+
+/// A DURATION value such as "PT1H30M", "P1D" or "P2W"
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in value.strip_prefix('P')?.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            unit => {
+                let amount: i64 = number.parse().ok()?;
+                number.clear();
+                total += match unit {
+                    'W' => Duration::weeks(amount),
+                    'D' => Duration::days(amount),
+                    'H' => Duration::hours(amount),
+                    'M' => Duration::minutes(amount),
+                    'S' => Duration::seconds(amount),
+                    _ => return None,
+                };
+            }
+        }
+    }
+    Some(if negative { -total } else { total })
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The supported parts of an RRULE
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    /// Last local time an occurrence may start at
+    until: Option<NaiveDateTime>,
+    /// Weekdays, with an ordinal within the month for monthly rules ("2TU", "-1FR")
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+}
+
+fn parse_rule(value: &str, zone: Zone) -> Option<Rule> {
+    let parts: HashMap<String, String> = value
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .map(|(key, value)| (key.trim().to_uppercase(), value.trim().to_uppercase()))
+        .collect();
+    let frequency = match parts.get("FREQ")?.as_str() {
+        "DAILY" => Frequency::Daily,
+        "WEEKLY" => Frequency::Weekly,
+        "MONTHLY" => Frequency::Monthly,
+        "YEARLY" => Frequency::Yearly,
+        _ => return None,
+    };
+    let until = parts.get("UNTIL").and_then(|until| {
+        let (time, all_day) = parse_value_time(until)?;
+        let time = if all_day { time + Duration::days(1) - Duration::seconds(1) } else { time };
+        // A UTC UNTIL is compared with occurrences in the event's own zone
+        match (until.ends_with('Z'), zone) {
+            (true, Zone::Named(tz)) => Some(Utc.from_utc_datetime(&time).with_timezone(&tz).naive_local()),
+            (true, Zone::Local) => Some(Utc.from_utc_datetime(&time).with_timezone(&Local).naive_local()),
+            _ => Some(time),
+        }
+    });
+    let by_day = parts
+        .get("BYDAY")
+        .map(|days| {
+            days.split(',')
+                .filter_map(|day| {
+                    let day = day.trim();
+                    let (ordinal, code) = day.split_at(day.len().saturating_sub(2));
+                    let ordinal = if ordinal.is_empty() { None } else { Some(ordinal.parse().ok()?) };
+                    Some((ordinal, parse_weekday(code)?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let by_month_day = parts
+        .get("BYMONTHDAY")
+        .map(|days| days.split(',').filter_map(|day| day.trim().parse().ok()).collect())
+        .unwrap_or_default();
+    Some(Rule {
+        frequency,
+        interval: parts.get("INTERVAL").and_then(|n| n.parse().ok()).filter(|n| *n > 0).unwrap_or(1),
+        count: parts.get("COUNT").and_then(|n| n.parse().ok()),
+        until,
+        by_day,
+        by_month_day,
+    })
+}
+
+/// The `ordinal`th `weekday` of a month, counting from the end when negative
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, ordinal: i32) -> Option<NaiveDate> {
+    if ordinal > 0 {
+        NaiveDate::from_weekday_of_month_opt(year, month, weekday, ordinal as u8)
+    } else {
+        let next_month = if month == 12 {
+            NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+        } else {
+            NaiveDate::from_ymd_opt(year, month + 1, 1)?
+        };
+        let last = next_month.pred_opt()?;
+        let back = (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        let date = last - Duration::days(back as i64 + 7 * (-ordinal as i64 - 1));
+        (date.month() == month).then_some(date)
+    }
+}
+
+/// Days of a month named by BYMONTHDAY, negative values counting from the end
+fn month_day(year: i32, month: u32, day: i32) -> Option<NaiveDate> {
+    if day > 0 {
+        return NaiveDate::from_ymd_opt(year, month, day as u32);
+    }
+    let next_month = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    let date = next_month - Duration::days(-day as i64);
+    (date.month() == month).then_some(date)
+}
+
+/// Dates a rule produces in its `period`th period after `start`, in order
+fn period_dates(rule: &Rule, start: NaiveDate, period: u32) -> Vec<NaiveDate> {
+    let step = (period * rule.interval) as i64;
+    let mut dates: Vec<NaiveDate> = match rule.frequency {
+        Frequency::Daily => vec![start + Duration::days(step)],
+        Frequency::Weekly => {
+            let week = start - Duration::days(start.weekday().num_days_from_monday() as i64) + Duration::weeks(step);
+            if rule.by_day.is_empty() {
+                vec![week + Duration::days(start.weekday().num_days_from_monday() as i64)]
+            } else {
+                rule.by_day
+                    .iter()
+                    .map(|(_, weekday)| week + Duration::days(weekday.num_days_from_monday() as i64))
+                    .collect()
+            }
+        }
+        Frequency::Monthly => {
+            let months = start.month0() as i64 + step;
+            let (year, month) = (start.year() + (months / 12) as i32, (months % 12) as u32 + 1);
+            if !rule.by_month_day.is_empty() {
+                rule.by_month_day.iter().filter_map(|day| month_day(year, month, *day)).collect()
+            } else if !rule.by_day.is_empty() {
+                rule.by_day
+                    .iter()
+                    .flat_map(|(ordinal, weekday)| match ordinal {
+                        Some(ordinal) => nth_weekday(year, month, *weekday, *ordinal).into_iter().collect::<Vec<_>>(),
+                        None => (1..=5).filter_map(|n| nth_weekday(year, month, *weekday, n)).collect(),
+                    })
+                    .collect()
+            } else {
+                NaiveDate::from_ymd_opt(year, month, start.day()).into_iter().collect()
+            }
+        }
+        Frequency::Yearly => NaiveDate::from_ymd_opt(start.year() + step as i32, start.month(), start.day())
+            .into_iter()
+            .collect(),
+    };
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
+/// Local start times of a recurring event up to `until`, beginning with the first
+fn expand(rule: &Rule, first: NaiveDateTime, until: NaiveDateTime) -> Vec<NaiveDateTime> {
+    let mut starts = Vec::new();
+    let mut produced = 0;
+    for period in 0..MAX_PERIODS {
+        for date in period_dates(rule, first.date(), period) {
+            let start = date.and_time(first.time());
+            if start < first {
+                continue;
+            }
+            if start > until || rule.until.is_some_and(|last| start > last) || rule.count.is_some_and(|count| produced >= count) {
+                return starts;
+            }
+            produced += 1;
+            starts.push(start);
+        }
+    }
+    starts
+}
+
+/// The video call of an event, from the vendor properties or any link it mentions
+fn conference_url(properties: &[Property]) -> Option<String> {
+    let vendor = properties.iter().find(|property| {
+        matches!(property.name.as_str(), "X-GOOGLE-CONFERENCE" | "X-MICROSOFT-SKYPETEAMSMEETINGURL")
+    });
+    vendor
+        .map(|property| property.value.trim().to_string())
+        .or_else(|| {
+            ["URL", "LOCATION", "DESCRIPTION"].iter().find_map(|name| {
+                properties
+                    .iter()
+                    .filter(|property| property.name == *name)
+                    .find_map(|property| conference_link(&unescape(&property.value)))
+            })
+        })
+}
+
+fn attendees(properties: &[Property]) -> Vec<EventAttendee> {
+    properties
+        .iter()
+        .filter(|property| property.name == "ATTENDEE")
+        .filter(|property| !matches!(property.param("CUTYPE"), Some("ROOM") | Some("RESOURCE")))
+        .filter(|property| property.param("PARTSTAT") != Some("DECLINED"))
+        .filter_map(|property| {
+            let value = property.value.trim();
+            let email = value
+                .strip_prefix("mailto:")
+                .or_else(|| value.strip_prefix("MAILTO:"))
+                .map(str::to_string)
+                .filter(|email| email.contains('@'));
+            let name = property
+                .param("CN")
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .or_else(|| email.clone())?;
+            Some(EventAttendee { name, email })
+        })
+        .collect()
+}
+
+/// One VEVENT as read, before recurrence is expanded
+struct Master {
+    uid: String,
+    properties: Vec<Property>,
+    zone: Zone,
+    start: NaiveDateTime,
+    all_day: bool,
+    length: Duration,
+    cancelled: bool,
+}
+
+fn read_master(properties: Vec<Property>) -> Option<Master> {
+    let get = |name: &str| properties.iter().find(|property| property.name == name);
+    let uid = get("UID")?.value.trim().to_string();
+    let dtstart = get("DTSTART")?;
+    let (start, all_day) = parse_value_time(&dtstart.value)?;
+    let zone = Zone::of(dtstart);
+    let length = match (get("DTEND").and_then(|end| parse_value_time(&end.value)), get("DURATION")) {
+        (Some((end, _)), _) => end - start,
+        (None, Some(duration)) => parse_duration(&duration.value)?,
+        (None, None) if all_day => Duration::days(1),
+        (None, None) => Duration::hours(1),
+    };
+    let cancelled = get("STATUS").is_some_and(|status| status.value.trim().eq_ignore_ascii_case("CANCELLED"));
+    Some(Master {
+        uid,
+        zone,
+        start,
+        all_day,
+        length: length.max(Duration::zero()),
+        cancelled,
+        properties,
+    })
+}
+
+fn to_event(master: &Master, id: String, provider: &str, start: DateTime<Utc>) -> CalendarEvent {
+    let text = |name: &str| {
+        master
+            .properties
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| unescape(&property.value).trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+    CalendarEvent {
+        id,
+        provider: provider.to_string(),
+        title: text("SUMMARY").unwrap_or_else(|| "Untitled event".to_string()),
+        start,
+        end: start + master.length,
+        all_day: master.all_day,
+        description: text("DESCRIPTION"),
+        attendees: attendees(&master.properties),
+        conference_url: conference_url(&master.properties),
+    }
+}
+
+/// Id of one occurrence of a recurring event, like Google's "<series>_<start>"
+fn occurrence_id(uid: &str, start: DateTime<Utc>) -> String {
+    format!("{}_{}", uid, start.format("%Y%m%dT%H%M%SZ"))
+}
+
+/// Events of an iCalendar document overlapping `from`..`to`, in start order
+pub fn parse_events(text: &str, provider: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<CalendarEvent> {
+    let (mut masters, mut overrides): (Vec<Master>, Vec<Master>) = (Vec::new(), Vec::new());
+    for properties in vevents(text) {
+        let is_override = properties.iter().any(|property| property.name == "RECURRENCE-ID");
+        if let Some(master) = read_master(properties) {
+            if is_override {
+                overrides.push(master);
+            } else {
+                masters.push(master);
+            }
+        }
+    }
+    // Overrides by the occurrence they replace
+    let mut replaced: HashMap<String, &Master> = HashMap::new();
+    for event in &overrides {
+        let recurrence = event.properties.iter().find(|property| property.name == "RECURRENCE-ID");
+        if let Some((time, _)) = recurrence.and_then(|property| parse_value_time(&property.value)) {
+            if let Some(original) = recurrence.map(Zone::of).and_then(|zone| zone.to_utc(time)) {
+                replaced.insert(occurrence_id(&event.uid, original), event);
+            }
+        }
+    }
+
+    let mut events = Vec::new();
+    for master in masters.iter().filter(|master| !master.cancelled) {
+        let rule = master
+            .properties
+            .iter()
+            .find(|property| property.name == "RRULE")
+            .and_then(|property| parse_rule(&property.value, master.zone));
+        let Some(rule) = rule else {
+            if let Some(start) = master.zone.to_utc(master.start) {
+                events.push(to_event(master, master.uid.clone(), provider, start));
+            }
+            continue;
+        };
+
+        let excluded: Vec<DateTime<Utc>> = master
+            .properties
+            .iter()
+            .filter(|property| property.name == "EXDATE")
+            .flat_map(|property| {
+                let zone = Zone::of(property);
+                property
+                    .value
+                    .split(',')
+                    .filter_map(|value| parse_value_time(value))
+                    .filter_map(move |(time, _)| zone.to_utc(time))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        // Walk in local time, with a day of slack for the zone offset
+        let last_local = to.naive_utc() + Duration::days(1);
+        for local_start in expand(&rule, master.start, last_local) {
+            let Some(start) = master.zone.to_utc(local_start) else {
+                continue;
+            };
+            if excluded.contains(&start) {
+                continue;
+            }
+            let id = occurrence_id(&master.uid, start);
+            match replaced.get(&id) {
+                Some(moved) if moved.cancelled => {}
+                Some(moved) => {
+                    if let Some(moved_start) = moved.zone.to_utc(moved.start) {
+                        events.push(to_event(moved, id, provider, moved_start));
+                    }
+                }
+                None => events.push(to_event(master, id, provider, start)),
+            }
+        }
+    }
+    events.retain(|event| event.end > from && event.start < to);
+    events.sort_by_key(|event| event.start);
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_single_events() {
+        let ics = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:vendor-call@example.com\r\nSUMMARY:Vendor call\\, Q3\r\n\
+            DTSTART;TZID=Europe/Madrid:20260210T100000\r\nDURATION:PT45M\r\n\
+            DESCRIPTION:Agenda:\\n1. Pricing\\nJoin https://acme.zoom.us/j/42\r\n\
+            ATTENDEE;CN=\"Ruiz, Dana\";PARTSTAT=ACCEPTED:mailto:dana@example.com\r\n\
+            ATTENDEE;CUTYPE=ROOM;CN=Room 4:mailto:room4@example.com\r\n\
+            ATTENDEE;PARTSTAT=DECLINED;CN=Eli:mailto:eli@example.com\r\n\
+            ATTENDEE;CN=Fay:mailto:fay@exam\r\n ple.com\r\n\
+            BEGIN:VALARM\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\n\
+            END:VEVENT\r\nBEGIN:VEVENT\r\nUID:all-day\r\nSUMMARY:Offsite\r\nDTSTART;VALUE=DATE:20260211\r\nEND:VEVENT\r\n\
+            BEGIN:VEVENT\r\nUID:gone\r\nSTATUS:CANCELLED\r\nDTSTART:20260210T120000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let events = parse_events(ics, "ics", utc(2026, 2, 9, 0, 0), utc(2026, 2, 20, 0, 0));
+        assert_eq!(events.len(), 2);
+
+        let call = &events[0];
+        assert_eq!(call.id, "vendor-call@example.com");
+        assert_eq!(call.title, "Vendor call, Q3");
+        assert_eq!((call.start, call.end), (utc(2026, 2, 10, 9, 0), utc(2026, 2, 10, 9, 45)));
+        assert_eq!(call.description.as_deref(), Some("Agenda:\n1. Pricing\nJoin https://acme.zoom.us/j/42"));
+        assert_eq!(call.conference_url.as_deref(), Some("https://acme.zoom.us/j/42"));
+        let names: Vec<&str> = call.attendees.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Ruiz, Dana", "Fay"]);
+        assert_eq!(call.attendees[1].email.as_deref(), Some("fay@example.com"));
+
+        assert!(events[1].all_day);
+        assert_eq!(events[1].end - events[1].start, Duration::days(1));
+    }
+
+    #[test]
+    fn expands_recurring_events() {
+        // Weekly on Tuesday and Thursday across the end of DST in New York; one
+        // occurrence moved, one cancelled, one excluded
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:sync\nSUMMARY:Sync\n\
+            DTSTART;TZID=America/New_York:20261027T090000\nDTEND;TZID=America/New_York:20261027T093000\n\
+            RRULE:FREQ=WEEKLY;BYDAY=TU,TH;COUNT=6\nEXDATE;TZID=America/New_York:20261105T090000\nEND:VEVENT\n\
+            BEGIN:VEVENT\nUID:sync\nRECURRENCE-ID;TZID=America/New_York:20261029T090000\nSUMMARY:Sync (moved)\n\
+            DTSTART;TZID=America/New_York:20261029T110000\nDTEND;TZID=America/New_York:20261029T113000\nEND:VEVENT\n\
+            BEGIN:VEVENT\nUID:sync\nRECURRENCE-ID;TZID=America/New_York:20261110T090000\nSTATUS:CANCELLED\n\
+            DTSTART;TZID=America/New_York:20261110T090000\nEND:VEVENT\nEND:VCALENDAR\n";
+        let events = parse_events(ics, "caldav", utc(2026, 10, 1, 0, 0), utc(2026, 12, 1, 0, 0));
+        let starts: Vec<(String, DateTime<Utc>)> = events.iter().map(|e| (e.title.clone(), e.start)).collect();
+        assert_eq!(
+            starts,
+            [
+                ("Sync".to_string(), utc(2026, 10, 27, 13, 0)),
+                ("Sync (moved)".to_string(), utc(2026, 10, 29, 15, 0)),
+                ("Sync".to_string(), utc(2026, 11, 3, 14, 0)),
+                ("Sync".to_string(), utc(2026, 11, 12, 14, 0)),
+            ]
+        );
+        assert_eq!(events[1].id, "sync_20261029T130000Z");
+
+        let rule = |value: &str| parse_rule(value, Zone::Utc).unwrap();
+        let first = NaiveDate::from_ymd_opt(2026, 1, 30).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let until = first + Duration::days(200);
+        let dates = |rule: &Rule| expand(rule, first, until).iter().map(|start| start.date().to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            dates(&rule("FREQ=MONTHLY;BYDAY=-1FR;COUNT=3")),
+            ["2026-01-30", "2026-02-27", "2026-03-27"]
+        );
+        assert_eq!(dates(&rule("FREQ=MONTHLY;COUNT=3")), ["2026-01-30", "2026-03-30", "2026-04-30"]);
+        assert_eq!(
+            dates(&rule("FREQ=DAILY;INTERVAL=2;UNTIL=20260205T090000Z")),
+            ["2026-01-30", "2026-02-01", "2026-02-03", "2026-02-05"]
+        );
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+    }
+}
@@ -0,0 +1,805 @@
+// Calendar integration
+// Upcoming events from the user's Google or Microsoft 365 calendar, or from subscribed
+// .ics feeds and CalDAV calendars, give meetings their context: the event a recording
+// belongs to (the one in progress when it started, or the one picked from the
+// start prompt) fills in the saved meeting's title, participants, agenda, scheduled start
+// and platform, and links it to the event so templates for the series apply. Anything
+// already set on the meeting is kept.
+//
+// Events are fetched every few minutes and held in memory only. When an event with a video
+// call begins, "calendar-event-starting" is emitted and, if the user wants the prompt, a
+// notification asks them to record it.
+
+pub mod google;
+pub mod ics;
+pub mod oauth;
+pub mod outlook;
+pub mod subscription;
+
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::notifications::commands::NotificationManagerState;
+use crate::state::{self, AppState};
+use crate::summary::auto_title::is_default_title;
+
+/// How often the background task checks for starting events
+const TICK: std::time::Duration = std::time::Duration::from_secs(30);
+/// How long fetched events are used before fetching again
+const REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+/// An event counts as the one being recorded from this long before it starts
+const EARLY_START_MINUTES: i64 = 5;
+/// How long after an event starts the record prompt is still shown
+const PROMPT_WINDOW_MINUTES: i64 = 5;
+/// Events that started this long ago are still fetched, for meetings running late
+const LOOKBEHIND_HOURS: i64 = 12;
+const MAX_AGENDA_CHARS: usize = 4000;
+
+/// Video call hosts, by the platform name used for meeting details
+const CONFERENCE_HOSTS: &[(&str, &str)] = &[
+    ("zoom.us", "Zoom"),
+    ("meet.google.com", "Google Meet"),
+    ("teams.microsoft.com", "Microsoft Teams"),
+    ("teams.live.com", "Microsoft Teams"),
+    ("webex.com", "Webex"),
+    ("gotomeeting.com", "GoTo Meeting"),
+    ("meet.goto.com", "GoTo Meeting"),
+    ("whereby.com", "Whereby"),
+    ("meet.jit.si", "Jitsi"),
+];
+
+static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r#"https://[^\s<>"'()\[\]]+"#).unwrap());
+static HTML_TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+static LINE_BREAK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?>|</p>|</div>|</li>").unwrap());
+
+// Events last fetched, and when
+static EVENTS: Lazy<Mutex<(Vec<CalendarEvent>, Option<Instant>)>> = Lazy::new(|| Mutex::new((Vec::new(), None)));
+// Occurrences already prompted for, as "<id>@<start>"
+static PROMPTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+// Event picked for the next recording, from the start prompt
+static NEXT_RECORDING_EVENT: Mutex<Option<CalendarEvent>> = Mutex::new(None);
+// Event of the current recording, applied when its meeting is saved
+static RECORDING_EVENT: Mutex<Option<CalendarEvent>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventAttendee {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    /// Provider's id of this occurrence
+    pub id: String,
+    /// Calendar the event comes from: "google", "outlook", "ics" or "caldav"
+    pub provider: String,
+    pub title: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub all_day: bool,
+    /// Event notes as written, possibly HTML
+    pub description: Option<String>,
+    pub attendees: Vec<EventAttendee>,
+    /// Link to join the video call
+    pub conference_url: Option<String>,
+}
+
+impl CalendarEvent {
+    /// Identifies one occurrence, even for providers that reuse ids across a series
+    fn occurrence_key(&self) -> String {
+        format!("{}@{}", self.id, self.start.timestamp())
+    }
+}
+
+/// A Google account connected through an OAuth client the user registered
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoogleCalendarSettings {
+    pub client_id: String,
+    #[serde(default)]
+    pub client_secret: String,
+    /// Address of the connected account, None until signed in
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+impl GoogleCalendarSettings {
+    fn client_key(&self) -> String {
+        self.client_id.trim().to_string()
+    }
+}
+
+fn default_tenant() -> String {
+    "common".to_string()
+}
+
+/// A Microsoft 365 or Outlook.com account connected through an app registered in Entra ID
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlookCalendarSettings {
+    pub client_id: String,
+    /// Directory the app is registered in: "common" for any account, "organizations",
+    /// "consumers", or a tenant id or domain
+    #[serde(default = "default_tenant")]
+    pub tenant: String,
+    /// Address of the connected account, None until signed in
+    #[serde(default)]
+    pub account: Option<String>,
+}
+
+impl OutlookCalendarSettings {
+    fn client_key(&self) -> String {
+        format!("{}/{}", self.tenant.trim(), self.client_id.trim())
+    }
+}
+
+/// Where a subscribed calendar is read from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SubscriptionSource {
+    /// A published feed, e.g. "webcal://p42-caldav.icloud.com/published/2/…"
+    Ics { url: String },
+    Caldav {
+        /// Calendar collection URL, e.g. "https://cloud.example.com/remote.php/dav/calendars/me/work/"
+        url: String,
+        username: String,
+        /// Usually an app password
+        password: String,
+    },
+}
+
+/// A calendar read by URL instead of through a signed-in account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarSubscription {
+    /// Assigned when first saved
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub source: SubscriptionSource,
+}
+
+impl CalendarSubscription {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Name the subscribed calendar".to_string());
+        }
+        match &self.source {
+            SubscriptionSource::Ics { url } => subscription::feed_url(url).map(|_| ()),
+            SubscriptionSource::Caldav { url, username, .. } => {
+                subscription::feed_url(url)?;
+                if username.trim().is_empty() {
+                    return Err(format!("Enter the CalDAV user name for {}", self.name));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn default_prompt_to_record() -> bool {
+    true
+}
+
+fn default_lookahead_hours() -> u32 {
+    24
+}
+
+/// Calendar settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarSettings {
+    #[serde(default)]
+    pub google: Option<GoogleCalendarSettings>,
+    #[serde(default)]
+    pub outlook: Option<OutlookCalendarSettings>,
+    #[serde(default)]
+    pub subscriptions: Vec<CalendarSubscription>,
+    /// Notify when an event with a video call starts, offering to record it
+    #[serde(default = "default_prompt_to_record")]
+    pub prompt_to_record: bool,
+    /// How far ahead upcoming events are listed
+    #[serde(default = "default_lookahead_hours")]
+    pub lookahead_hours: u32,
+}
+
+impl Default for CalendarSettings {
+    fn default() -> Self {
+        Self {
+            google: None,
+            outlook: None,
+            subscriptions: Vec::new(),
+            prompt_to_record: default_prompt_to_record(),
+            lookahead_hours: default_lookahead_hours(),
+        }
+    }
+}
+
+impl CalendarSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=24 * 14).contains(&self.lookahead_hours) {
+            return Err("List events from 1 hour to 14 days ahead".to_string());
+        }
+        if let Some(google) = &self.google {
+            if google.client_id.trim().is_empty() {
+                return Err("Enter the OAuth client ID of your Google Cloud app".to_string());
+            }
+        }
+        if let Some(outlook) = &self.outlook {
+            if outlook.client_id.trim().is_empty() {
+                return Err("Enter the application (client) ID of your Microsoft app registration".to_string());
+            }
+            if outlook.tenant.trim().is_empty() || outlook.tenant.contains(['/', '?', '#']) {
+                return Err(format!("Invalid Microsoft tenant: {}", outlook.tenant));
+            }
+        }
+        for subscription in &self.subscriptions {
+            subscription.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Whether any calendar account is signed in or subscribed to
+    fn connected(&self) -> bool {
+        self.google.as_ref().is_some_and(|google| google.account.is_some())
+            || self.outlook.as_ref().is_some_and(|outlook| outlook.account.is_some())
+            || !self.subscriptions.is_empty()
+    }
+}
+
+/// Platform name for a video call link
+fn conference_platform(url: &str) -> Option<&'static str> {
+    let host = url::Url::parse(url).ok()?.host_str()?.to_lowercase();
+    CONFERENCE_HOSTS
+        .iter()
+        .find(|(domain, _)| host == *domain || host.ends_with(&format!(".{}", domain)))
+        .map(|(_, platform)| *platform)
+}
+
+/// The first video call link in free text, e.g. an event's location or notes
+pub(crate) fn conference_link(text: &str) -> Option<String> {
+    URL_REGEX
+        .find_iter(text)
+        .map(|found| found.as_str().trim_end_matches(['.', ',', ';']))
+        .find(|url| conference_platform(url).is_some())
+        .map(str::to_string)
+}
+
+/// An agenda from event notes: plain text without HTML, joining instructions or dial-in
+/// details
+fn agenda_from_description(description: &str) -> Option<String> {
+    let text = LINE_BREAK_REGEX.replace_all(description, "\n");
+    let text = HTML_TAG_REGEX.replace_all(&text, "");
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        // Google Meet and Teams append their joining details below a divider
+        if line.starts_with("-::~") || line.starts_with("________________") {
+            break;
+        }
+        if conference_link(line).is_some() {
+            continue;
+        }
+        if line.is_empty() && matches!(lines.last(), None | Some(&"")) {
+            continue;
+        }
+        lines.push(line);
+    }
+    let agenda = lines.join("\n").trim().chars().take(MAX_AGENDA_CHARS).collect::<String>();
+    Some(agenda).filter(|agenda| !agenda.is_empty())
+}
+
+/// The timed event happening at `now`, counting events about to start; the one that
+/// started last wins when they overlap
+fn event_in_progress(events: &[CalendarEvent], now: DateTime<Utc>) -> Option<&CalendarEvent> {
+    events
+        .iter()
+        .filter(|event| !event.all_day)
+        .filter(|event| event.start - Duration::minutes(EARLY_START_MINUTES) <= now && now < event.end)
+        .max_by_key(|event| event.start)
+}
+
+/// Events with a video call that start around `now` and weren't prompted for yet
+fn starting_events<'a>(events: &'a [CalendarEvent], now: DateTime<Utc>, prompted: &[String]) -> Vec<&'a CalendarEvent> {
+    events
+        .iter()
+        .filter(|event| !event.all_day && event.conference_url.is_some())
+        .filter(|event| {
+            event.start - Duration::seconds(TICK.as_secs() as i64) <= now
+                && now < event.start + Duration::minutes(PROMPT_WINDOW_MINUTES)
+        })
+        .filter(|event| !prompted.contains(&event.occurrence_key()))
+        .collect()
+}
+
+pub(crate) async fn load_settings(pool: &SqlitePool) -> Result<CalendarSettings, String> {
+    SettingsRepository::get_calendar_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load calendar settings: {}", e))
+}
+
+async fn save_settings(pool: &SqlitePool, settings: &CalendarSettings) -> Result<(), String> {
+    SettingsRepository::save_calendar_settings(pool, settings)
+        .await
+        .map_err(|e| format!("Failed to save calendar settings: {}", e))
+}
+
+/// The account to keep when a calendar's settings are saved: the signed-in one, unless the
+/// OAuth client changed and can't use that sign-in. Also returns whether the old sign-in
+/// has to be removed
+fn kept_account(previous: Option<(String, Option<String>)>, client_key: Option<String>) -> (Option<String>, bool) {
+    match previous {
+        Some((previous_key, account)) if Some(&previous_key) == client_key.as_ref() => (account, false),
+        Some((_, account)) => (None, account.is_some()),
+        None => (None, false),
+    }
+}
+
+/// Fetch events from every connected calendar, from a while ago to the lookahead, in start
+/// order; the cache is replaced only when all calendars answered
+async fn refresh_events(settings: &CalendarSettings) -> Result<Vec<CalendarEvent>, String> {
+    let now = Utc::now();
+    let (from, to) = (
+        now - Duration::hours(LOOKBEHIND_HOURS),
+        now + Duration::hours(settings.lookahead_hours as i64),
+    );
+    let mut events = Vec::new();
+    if let Some(connection) = settings.google.as_ref().filter(|google| google.account.is_some()) {
+        let access_token = google::client(connection).access_token(google::TOKEN_ACCOUNT).await?;
+        events.extend(google::fetch_events(&access_token, from, to).await?);
+    }
+    if let Some(connection) = settings.outlook.as_ref().filter(|outlook| outlook.account.is_some()) {
+        let access_token = outlook::client(connection).access_token(outlook::TOKEN_ACCOUNT).await?;
+        events.extend(outlook::fetch_events(&access_token, from, to).await?);
+    }
+    for calendar in &settings.subscriptions {
+        events.extend(subscription::fetch_events(calendar, from, to).await?);
+    }
+    events.sort_by_key(|event| event.start);
+    *EVENTS.lock().unwrap() = (events.clone(), Some(Instant::now()));
+    Ok(events)
+}
+
+/// Cached events, fetched again when they are stale or `force` is set
+async fn current_events(settings: &CalendarSettings, force: bool) -> Result<Vec<CalendarEvent>, String> {
+    if !settings.connected() {
+        return Ok(Vec::new());
+    }
+    {
+        let cache = EVENTS.lock().unwrap();
+        let fresh = cache.1.is_some_and(|fetched| fetched.elapsed() < REFRESH_INTERVAL);
+        if fresh && !force {
+            return Ok(cache.0.clone());
+        }
+    }
+    refresh_events(settings).await
+}
+
+fn cached_event(event_id: &str) -> Option<CalendarEvent> {
+    EVENTS.lock().unwrap().0.iter().find(|event| event.id == event_id).cloned()
+}
+
+/// Remember the calendar event of a recording that is starting: the one picked from the
+/// start prompt, else the one in progress
+pub fn note_recording_started() {
+    let picked = NEXT_RECORDING_EVENT.lock().unwrap().take();
+    let event = picked.or_else(|| event_in_progress(&EVENTS.lock().unwrap().0, Utc::now()).cloned());
+    if let Some(event) = &event {
+        info!("Recording calendar event {:?}", event.title);
+    }
+    *RECORDING_EVENT.lock().unwrap() = event;
+}
+
+/// Fill in a meeting's details from a calendar event, keeping anything already set
+async fn prefill_meeting(pool: &SqlitePool, meeting_id: &str, event: &CalendarEvent) -> Result<(), String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+    if is_default_title(&meeting.title) {
+        MeetingsRepository::update_meeting_title(pool, meeting_id, &event.title)
+            .await
+            .map_err(|e| format!("Failed to rename meeting: {}", e))?;
+    }
+    let attendees: Vec<(String, Option<String>)> = event
+        .attendees
+        .iter()
+        .map(|attendee| (attendee.name.clone(), attendee.email.clone()))
+        .collect();
+    MeetingParticipantsRepository::add_missing_contacts(pool, meeting_id, &attendees, "calendar")
+        .await
+        .map_err(|e| format!("Failed to add participants: {}", e))?;
+    if meeting.agenda.is_none() {
+        if let Some(agenda) = event.description.as_deref().and_then(agenda_from_description) {
+            MeetingsRepository::set_agenda(pool, meeting_id, Some(&agenda))
+                .await
+                .map_err(|e| format!("Failed to save agenda: {}", e))?;
+        }
+    }
+    let platform = meeting
+        .platform
+        .or_else(|| event.conference_url.as_deref().and_then(conference_platform).map(str::to_string));
+    MeetingsRepository::set_details(
+        pool,
+        meeting_id,
+        meeting.scheduled_start.or(Some(event.start)),
+        meeting.duration_seconds,
+        platform.as_deref(),
+        Some(&event.id),
+    )
+    .await
+    .map_err(|e| format!("Failed to save meeting details: {}", e))?;
+    Ok(())
+}
+
+/// Fill in a just-saved recording from the calendar event it was started for
+pub async fn attach_recording_event(pool: &SqlitePool, meeting_id: &str) {
+    let Some(event) = RECORDING_EVENT.lock().unwrap().take() else {
+        return;
+    };
+    match prefill_meeting(pool, meeting_id, &event).await {
+        Ok(()) => info!("Filled in meeting {} from calendar event {:?}", meeting_id, event.title),
+        Err(e) => warn!("Failed to fill in meeting {} from its calendar event: {}", meeting_id, e),
+    }
+}
+
+/// Notify that a meeting is starting, when notifications are set up
+async fn notify_starting<R: Runtime>(app: &AppHandle<R>, event: &CalendarEvent) {
+    let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+        return;
+    };
+    let manager = manager_state.read().await;
+    if let Some(manager) = manager.as_ref() {
+        if let Err(e) = manager.show_meeting_starting(&event.title).await {
+            warn!("Failed to show the record prompt for {:?}: {}", event.title, e);
+        }
+    }
+}
+
+/// Fetch events when due and prompt for the ones starting now
+async fn check_calendar<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool) -> Result<(), String> {
+    let settings = load_settings(pool).await?;
+    let events = current_events(&settings, false).await?;
+    let starting: Vec<CalendarEvent> = {
+        let mut prompted = PROMPTED.lock().unwrap();
+        let starting: Vec<CalendarEvent> = starting_events(&events, Utc::now(), &prompted).into_iter().cloned().collect();
+        prompted.extend(starting.iter().map(CalendarEvent::occurrence_key));
+        // Keep the list short; only occurrences inside the prompt window matter
+        let excess = prompted.len().saturating_sub(100);
+        prompted.drain(..excess);
+        starting
+    };
+    if starting.is_empty() || crate::audio::recording_commands::is_recording().await {
+        return Ok(());
+    }
+    for event in &starting {
+        info!("Calendar event {:?} is starting", event.title);
+        let _ = app.emit("calendar-event-starting", event);
+        if settings.prompt_to_record {
+            notify_starting(app, event).await;
+        }
+    }
+    Ok(())
+}
+
+/// Start the background task that keeps upcoming events fresh and prompts to record
+pub fn start_calendar_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                if let Err(e) = check_calendar(&app, &pool).await {
+                    warn!("Calendar check failed: {}", e);
+                    // Retry the fetch at the next refresh instead of every tick
+                    EVENTS.lock().unwrap().1 = Some(Instant::now());
+                }
+            }
+            tokio::time::sleep(TICK).await;
+        }
+    });
+}
+
+#[command]
+pub async fn get_calendar_settings<R: Runtime>(app: AppHandle<R>) -> Result<CalendarSettings, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+/// Save calendar settings; connected accounts are kept unless their OAuth client changes,
+/// which signs them out
+#[command]
+pub async fn save_calendar_settings<R: Runtime>(app: AppHandle<R>, mut settings: CalendarSettings) -> Result<CalendarSettings, String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let previous = load_settings(pool).await?;
+    let (account, sign_out) = kept_account(
+        previous.google.map(|google| (google.client_key(), google.account)),
+        settings.google.as_ref().map(GoogleCalendarSettings::client_key),
+    );
+    if let Some(google) = &mut settings.google {
+        google.account = account;
+    }
+    if sign_out {
+        oauth::delete_tokens(google::TOKEN_ACCOUNT)?;
+    }
+    let (account, sign_out) = kept_account(
+        previous.outlook.map(|outlook| (outlook.client_key(), outlook.account)),
+        settings.outlook.as_ref().map(OutlookCalendarSettings::client_key),
+    );
+    if let Some(outlook) = &mut settings.outlook {
+        outlook.account = account;
+    }
+    if sign_out {
+        oauth::delete_tokens(outlook::TOKEN_ACCOUNT)?;
+    }
+    for calendar in settings.subscriptions.iter_mut().filter(|calendar| calendar.id.is_empty()) {
+        calendar.id = uuid::Uuid::new_v4().to_string();
+    }
+    save_settings(pool, &settings).await?;
+    if settings.subscriptions != previous.subscriptions {
+        // Fetch again on the next check, with the new feeds
+        EVENTS.lock().unwrap().1 = None;
+    }
+    Ok(settings)
+}
+
+/// Check a subscribed calendar can be read before saving it
+///
+/// # Returns
+/// How many events it has in the next day
+#[command]
+pub async fn test_calendar_subscription(subscription: CalendarSubscription) -> Result<usize, String> {
+    subscription.validate()?;
+    let now = Utc::now();
+    let events = subscription::fetch_events(&subscription, now, now + Duration::hours(default_lookahead_hours() as i64)).await?;
+    Ok(events.len())
+}
+
+/// Sign in to Google in the browser and connect the account's primary calendar; returns the
+/// account address
+#[command]
+pub async fn connect_google_calendar<R: Runtime>(app: AppHandle<R>) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut settings = load_settings(pool).await?;
+    let connection = settings
+        .google
+        .as_mut()
+        .ok_or_else(|| "Enter the OAuth client of your Google Cloud app first".to_string())?;
+    let client = google::client(connection);
+    let tokens = client.authorize().await?;
+    let account = google::account_email(&tokens.access_token).await?;
+    oauth::store_tokens(google::TOKEN_ACCOUNT, &tokens)?;
+    connection.account = Some(account.clone());
+    save_settings(pool, &settings).await?;
+    info!("Connected Google Calendar of {}", account);
+
+    if let Err(e) = refresh_events(&settings).await {
+        warn!("Failed to fetch calendar events after connecting: {}", e);
+    }
+    let _ = app.emit("calendar-connected", serde_json::json!({ "provider": "google", "account": account }));
+    Ok(account)
+}
+
+/// Sign out of Google Calendar, keeping the OAuth client
+#[command]
+pub async fn disconnect_google_calendar<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut settings = load_settings(pool).await?;
+    oauth::delete_tokens(google::TOKEN_ACCOUNT)?;
+    if let Some(google) = &mut settings.google {
+        google.account = None;
+    }
+    save_settings(pool, &settings).await?;
+    EVENTS.lock().unwrap().0.retain(|event| event.provider != "google");
+    info!("Disconnected Google Calendar");
+    Ok(())
+}
+
+/// Sign in to Microsoft in the browser and connect the account's default calendar; returns
+/// the account address
+#[command]
+pub async fn connect_outlook_calendar<R: Runtime>(app: AppHandle<R>) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut settings = load_settings(pool).await?;
+    let connection = settings
+        .outlook
+        .as_mut()
+        .ok_or_else(|| "Enter the client ID of your Microsoft app registration first".to_string())?;
+    let client = outlook::client(connection);
+    let tokens = client.authorize().await?;
+    let account = outlook::account_email(&tokens.access_token).await?;
+    oauth::store_tokens(outlook::TOKEN_ACCOUNT, &tokens)?;
+    connection.account = Some(account.clone());
+    save_settings(pool, &settings).await?;
+    info!("Connected Outlook calendar of {}", account);
+
+    if let Err(e) = refresh_events(&settings).await {
+        warn!("Failed to fetch calendar events after connecting: {}", e);
+    }
+    let _ = app.emit("calendar-connected", serde_json::json!({ "provider": "outlook", "account": account }));
+    Ok(account)
+}
+
+/// Sign out of the Outlook calendar, keeping the app registration
+#[command]
+pub async fn disconnect_outlook_calendar<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut settings = load_settings(pool).await?;
+    oauth::delete_tokens(outlook::TOKEN_ACCOUNT)?;
+    if let Some(outlook) = &mut settings.outlook {
+        outlook.account = None;
+    }
+    save_settings(pool, &settings).await?;
+    EVENTS.lock().unwrap().0.retain(|event| event.provider != "outlook");
+    info!("Disconnected Outlook calendar");
+    Ok(())
+}
+
+/// Events that haven't ended yet, in start order
+#[command]
+pub async fn list_upcoming_events<R: Runtime>(app: AppHandle<R>, refresh: Option<bool>) -> Result<Vec<CalendarEvent>, String> {
+    let state = app.state::<AppState>();
+    let settings = load_settings(state.db_manager.pool()).await?;
+    let now = Utc::now();
+    Ok(current_events(&settings, refresh.unwrap_or(false))
+        .await?
+        .into_iter()
+        .filter(|event| event.end > now)
+        .collect())
+}
+
+/// The event happening now, to name a recording after
+#[command]
+pub async fn get_current_calendar_event<R: Runtime>(app: AppHandle<R>) -> Result<Option<CalendarEvent>, String> {
+    let state = app.state::<AppState>();
+    let settings = load_settings(state.db_manager.pool()).await?;
+    let events = current_events(&settings, false).await?;
+    Ok(event_in_progress(&events, Utc::now()).cloned())
+}
+
+/// Record the next recording as this event, e.g. from the start prompt; the recording
+/// should be started with the returned event's title
+#[command]
+pub async fn record_calendar_event(event_id: String) -> Result<CalendarEvent, String> {
+    let event = cached_event(&event_id).ok_or_else(|| format!("Calendar event {} not found", event_id))?;
+    *NEXT_RECORDING_EVENT.lock().unwrap() = Some(event.clone());
+    Ok(event)
+}
+
+/// Link a saved meeting to an upcoming or recent calendar event and fill in its details
+#[command]
+pub async fn link_meeting_to_calendar_event<R: Runtime>(app: AppHandle<R>, meeting_id: String, event_id: String) -> Result<(), String> {
+    let event = cached_event(&event_id).ok_or_else(|| format!("Calendar event {} not found", event_id))?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    prefill_meeting(pool, &meeting_id, &event).await?;
+    // The event's series may have a meeting template
+    crate::meeting_templates::apply_matching(pool, &meeting_id).await?;
+    let _ = app.emit("meeting-info-updated", serde_json::json!({ "meeting_id": meeting_id }));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn event(id: &str, start_hour: u32, end_hour: u32, conference_url: Option<&str>) -> CalendarEvent {
+        CalendarEvent {
+            id: id.to_string(),
+            provider: "google".to_string(),
+            title: id.to_string(),
+            start: Utc.with_ymd_and_hms(2026, 2, 10, start_hour, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 2, 10, end_hour, 0, 0).unwrap(),
+            all_day: false,
+            description: None,
+            attendees: Vec::new(),
+            conference_url: conference_url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn finds_conference_links_and_agendas() {
+        assert_eq!(
+            conference_link("Room 4 or https://acme.zoom.us/j/987654321, thanks"),
+            Some("https://acme.zoom.us/j/987654321".to_string())
+        );
+        assert_eq!(conference_link("Docs: https://example.com/notes"), None);
+        assert_eq!(conference_platform("https://teams.microsoft.com/l/meetup-join/x"), Some("Microsoft Teams"));
+        assert_eq!(conference_platform("https://notzoom.us/j/1"), None);
+
+        let description = "<p>Agenda:</p><ul><li>Budget &amp; hiring</li><li>Roadmap</li></ul><br><br>\
+            Join: https://meet.google.com/abc-defg-hij<br>-::~:~::~:~::~:~::-<br>Dial-in: +1 555 0100";
+        assert_eq!(
+            agenda_from_description(description).as_deref(),
+            Some("Agenda:\nBudget & hiring\nRoadmap")
+        );
+        assert_eq!(agenda_from_description("<br>https://zoom.us/j/1<br>"), None);
+    }
+
+    #[test]
+    fn reads_subscriptions() {
+        let mut settings: CalendarSettings = serde_json::from_str(
+            r#"{"subscriptions": [
+                {"name": "Team", "kind": "ics", "url": "webcal://example.com/team.ics"},
+                {"id": "nc", "name": "Nextcloud", "kind": "caldav", "url": "https://cloud.example.com/dav/calendars/me/work/",
+                 "username": "me", "password": "app-password"}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(settings.prompt_to_record);
+        assert!(settings.connected());
+        assert!(settings.validate().is_ok());
+        assert_eq!(
+            settings.subscriptions[1].source,
+            SubscriptionSource::Caldav {
+                url: "https://cloud.example.com/dav/calendars/me/work/".to_string(),
+                username: "me".to_string(),
+                password: "app-password".to_string(),
+            }
+        );
+
+        settings.subscriptions[0].source = SubscriptionSource::Ics { url: "team.ics".to_string() };
+        assert!(settings.validate().is_err());
+        settings.subscriptions.remove(0);
+        if let SubscriptionSource::Caldav { username, .. } = &mut settings.subscriptions[0].source {
+            username.clear();
+        }
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn keeps_sign_in_for_the_same_client() {
+        let previous = || Some(("common/app".to_string(), Some("me@contoso.com".to_string())));
+        assert_eq!(
+            kept_account(previous(), Some("common/app".to_string())),
+            (Some("me@contoso.com".to_string()), false)
+        );
+        assert_eq!(kept_account(previous(), Some("contoso.com/app".to_string())), (None, true));
+        assert_eq!(kept_account(previous(), None), (None, true));
+        assert_eq!(kept_account(Some(("app".to_string(), None)), None), (None, false));
+        assert_eq!(kept_account(None, Some("app".to_string())), (None, false));
+    }
+
+    #[test]
+    fn picks_events_by_time() {
+        let events = vec![
+            event("standup", 9, 10, Some("https://meet.google.com/a")),
+            event("review", 10, 11, None),
+            event("planning", 10, 12, Some("https://zoom.us/j/1")),
+        ];
+        let at = |hour: u32, minute: u32| Utc.with_ymd_and_hms(2026, 2, 10, hour, minute, 0).unwrap();
+
+        assert_eq!(event_in_progress(&events, at(9, 30)).map(|e| e.id.as_str()), Some("standup"));
+        assert_eq!(event_in_progress(&events, at(8, 56)).map(|e| e.id.as_str()), Some("standup"));
+        assert_eq!(event_in_progress(&events, at(11, 30)).map(|e| e.id.as_str()), Some("planning"));
+        assert_eq!(event_in_progress(&events, at(12, 0)), None);
+
+        let starting: Vec<&str> = starting_events(&events, at(10, 2), &[]).iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(starting, ["planning"]);
+        let prompted = vec![events[2].occurrence_key()];
+        assert!(starting_events(&events, at(10, 2), &prompted).is_empty());
+        assert!(starting_events(&events, at(10, 6), &[]).is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+// OAuth for calendar accounts
+// Desktop flow with PKCE: the consent page opens in the browser and redirects back to a
+// one-shot listener on 127.0.0.1, which hands the code over for tokens. Tokens are kept in
+// the system keychain, never in the database, and refreshed shortly before they expire.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::info;
+
+const KEYCHAIN_SERVICE: &str = "com.meetily.ai";
+/// How long the user has to finish signing in
+const AUTHORIZE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+/// Access tokens this close to expiring are refreshed before use
+const REFRESH_MARGIN_SECONDS: i64 = 60;
+
+const CALLBACK_PAGE: &str = "<!DOCTYPE html><html><body style=\"font-family: sans-serif\">\
+<h3>Calendar connected</h3><p>You can close this tab and return to Meetily.</p></body></html>";
+const CALLBACK_ERROR_PAGE: &str = "<!DOCTYPE html><html><body style=\"font-family: sans-serif\">\
+<h3>Calendar not connected</h3><p>Return to Meetily to try again.</p></body></html>";
+
+/// An OAuth app registered with a calendar provider
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    pub auth_url: String,
+    pub token_url: String,
+    pub client_id: String,
+    /// Desktop apps of some providers (Google) get a secret that isn't confidential
+    pub client_secret: Option<String>,
+    pub scopes: &'static [&'static str],
+    /// Extra parameters for the consent page, e.g. to get a refresh token
+    pub extra_params: &'static [(&'static str, &'static str)],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+impl From<TokenResponse> for OAuthTokens {
+    fn from(response: TokenResponse) -> Self {
+        Self {
+            access_token: response.access_token,
+            refresh_token: response.refresh_token,
+            expires_at: Utc::now() + Duration::seconds(response.expires_in.unwrap_or(3600)),
+        }
+    }
+}
+
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE verifier and its S256 challenge
+fn pkce_pair() -> (String, String) {
+    let verifier = random_token();
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+    (verifier, challenge)
+}
+
+/// The authorization code from the request line of the redirect, e.g.
+/// "GET /callback?code=...&state=... HTTP/1.1"
+fn parse_callback(request_line: &str, expected_state: &str) -> Result<String, String> {
+    let target = request_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| "Invalid sign-in response".to_string())?;
+    let url = url::Url::parse(&format!("http://127.0.0.1{}", target)).map_err(|_| "Invalid sign-in response".to_string())?;
+    let param = |name: &str| url.query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.to_string());
+    if let Some(error) = param("error") {
+        return match error.as_str() {
+            "access_denied" => Err("Calendar access was not granted".to_string()),
+            _ => Err(format!("Sign-in failed: {}", error)),
+        };
+    }
+    if param("state").as_deref() != Some(expected_state) {
+        return Err("Sign-in response did not match the request".to_string());
+    }
+    param("code").ok_or_else(|| "Sign-in response has no authorization code".to_string())
+}
+
+/// Open a URL in the default browser
+fn open_in_browser(url: &str) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    let result = std::process::Command::new("rundll32")
+        .args(["url.dll,FileProtocolHandler", url])
+        .spawn();
+    #[cfg(target_os = "macos")]
+    let result = std::process::Command::new("open").arg(url).spawn();
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = std::process::Command::new("xdg-open").arg(url).spawn();
+    result
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open the browser: {}", e))
+}
+
+impl OAuthClient {
+    fn authorization_url(&self, redirect_uri: &str, state: &str, challenge: &str) -> Result<String, String> {
+        let mut url = url::Url::parse(&self.auth_url).map_err(|e| format!("Invalid authorization URL: {}", e))?;
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("scope", &self.scopes.join(" "))
+            .append_pair("state", state)
+            .append_pair("code_challenge", challenge)
+            .append_pair("code_challenge_method", "S256")
+            .extend_pairs(self.extra_params.iter());
+        Ok(url.to_string())
+    }
+
+    async fn request_tokens(&self, params: &[(&str, &str)]) -> Result<OAuthTokens, String> {
+        let mut form: Vec<(&str, &str)> = vec![("client_id", &self.client_id)];
+        if let Some(secret) = &self.client_secret {
+            form.push(("client_secret", secret));
+        }
+        form.extend_from_slice(params);
+        let response = reqwest::Client::new()
+            .post(&self.token_url)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach the sign-in server: {}", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("Sign-in server returned {}: {}", status, body.trim()));
+        }
+        response
+            .json::<TokenResponse>()
+            .await
+            .map(OAuthTokens::from)
+            .map_err(|e| format!("Invalid token response: {}", e))
+    }
+
+    /// Sign in through the browser and return the account's tokens
+    pub async fn authorize(&self) -> Result<OAuthTokens, String> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|e| format!("Failed to listen for the sign-in response: {}", e))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| format!("Failed to listen for the sign-in response: {}", e))?
+            .port();
+        let redirect_uri = format!("http://127.0.0.1:{}/callback", port);
+        let state = random_token();
+        let (verifier, challenge) = pkce_pair();
+        open_in_browser(&self.authorization_url(&redirect_uri, &state, &challenge)?)?;
+
+        let code = tokio::time::timeout(AUTHORIZE_TIMEOUT, async {
+            loop {
+                let (mut stream, _) = listener
+                    .accept()
+                    .await
+                    .map_err(|e| format!("Failed to receive the sign-in response: {}", e))?;
+                let mut buffer = vec![0u8; 8192];
+                let read = stream.read(&mut buffer).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..read]);
+                let request_line = request.lines().next().unwrap_or_default();
+                // Browsers open spare connections and ask for a favicon; only the redirect counts
+                if !request_line.contains(" /callback?") {
+                    let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await;
+                    continue;
+                }
+                let code = parse_callback(request_line, &state);
+                let page = if code.is_ok() { CALLBACK_PAGE } else { CALLBACK_ERROR_PAGE };
+                let reply = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    page.len(),
+                    page
+                );
+                let _ = stream.write_all(reply.as_bytes()).await;
+                return code;
+            }
+        })
+        .await
+        .map_err(|_| "Sign-in timed out".to_string())??;
+
+        self.request_tokens(&[
+            ("grant_type", "authorization_code"),
+            ("code", &code),
+            ("redirect_uri", &redirect_uri),
+            ("code_verifier", &verifier),
+        ])
+        .await
+    }
+
+    /// New tokens for a refresh token; providers that don't rotate it keep the old one
+    async fn refresh(&self, refresh_token: &str) -> Result<OAuthTokens, String> {
+        let mut tokens = self
+            .request_tokens(&[("grant_type", "refresh_token"), ("refresh_token", refresh_token)])
+            .await?;
+        if tokens.refresh_token.is_none() {
+            tokens.refresh_token = Some(refresh_token.to_string());
+        }
+        Ok(tokens)
+    }
+
+    /// A current access token for `account`, refreshed and stored again when it is about
+    /// to expire
+    pub async fn access_token(&self, account: &str) -> Result<String, String> {
+        let tokens = load_tokens(account)?.ok_or_else(|| "The calendar account is not connected".to_string())?;
+        if tokens.expires_at > Utc::now() + Duration::seconds(REFRESH_MARGIN_SECONDS) {
+            return Ok(tokens.access_token);
+        }
+        let refresh_token = tokens
+            .refresh_token
+            .ok_or_else(|| "The calendar sign-in expired; connect the account again".to_string())?;
+        let tokens = self.refresh(&refresh_token).await?;
+        store_tokens(account, &tokens)?;
+        info!("Refreshed the access token of {}", account);
+        Ok(tokens.access_token)
+    }
+}
+
+fn keychain_entry(account: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, account).map_err(|e| format!("Failed to access the system keychain: {}", e))
+}
+
+pub fn store_tokens(account: &str, tokens: &OAuthTokens) -> Result<(), String> {
+    let json = serde_json::to_string(tokens).map_err(|e| format!("Failed to serialize tokens: {}", e))?;
+    keychain_entry(account)?
+        .set_password(&json)
+        .map_err(|e| format!("Failed to save the calendar sign-in to the system keychain: {}", e))
+}
+
+fn load_tokens(account: &str) -> Result<Option<OAuthTokens>, String> {
+    match keychain_entry(account)?.get_password() {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("Invalid calendar sign-in in the system keychain: {}", e)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read the calendar sign-in from the system keychain: {}", e)),
+    }
+}
+
+pub fn delete_tokens(account: &str) -> Result<(), String> {
+    match keychain_entry(account)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(format!("Failed to remove the calendar sign-in from the system keychain: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_and_reads_the_pkce_round_trip() {
+        let (verifier, challenge) = pkce_pair();
+        assert_eq!(verifier.len(), 43);
+        assert_eq!(challenge, URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())));
+
+        let client = OAuthClient {
+            auth_url: "https://accounts.example.com/auth".to_string(),
+            token_url: "https://accounts.example.com/token".to_string(),
+            client_id: "app id".to_string(),
+            client_secret: None,
+            scopes: &["calendar.read", "email"],
+            extra_params: &[("access_type", "offline")],
+        };
+        let url = client
+            .authorization_url("http://127.0.0.1:5000/callback", "xyz", &challenge)
+            .unwrap();
+        assert!(url.starts_with("https://accounts.example.com/auth?client_id=app+id&"));
+        assert!(url.contains("scope=calendar.read+email"));
+        assert!(url.contains("code_challenge_method=S256"));
+        assert!(url.ends_with("&access_type=offline"));
+
+        assert_eq!(
+            parse_callback("GET /callback?state=xyz&code=4%2Fabc HTTP/1.1", "xyz").unwrap(),
+            "4/abc"
+        );
+        assert!(parse_callback("GET /callback?state=other&code=abc HTTP/1.1", "xyz").is_err());
+        assert_eq!(
+            parse_callback("GET /callback?error=access_denied&state=xyz HTTP/1.1", "xyz").unwrap_err(),
+            "Calendar access was not granted"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// Outlook / Microsoft 365 calendar
+// Read-only access to the signed-in user's default calendar through Microsoft Graph, for
+// work, school and personal accounts. Graph gives each occurrence of a recurring event an
+// opaque id, so occurrences are identified as "<series id>_<start>" like Google's: meeting
+// templates then match the whole series.
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde_json::Value;
+
+use super::oauth::OAuthClient;
+use super::{conference_link, CalendarEvent, EventAttendee, OutlookCalendarSettings};
+
+const LOGIN_URL: &str = "https://login.microsoftonline.com";
+const SCOPES: &[&str] = &["offline_access", "User.Read", "Calendars.Read"];
+const GRAPH_URL: &str = "https://graph.microsoft.com/v1.0";
+/// Keychain account holding the tokens
+pub const TOKEN_ACCOUNT: &str = "calendar-outlook";
+const PAGE_SIZE: &str = "100";
+/// Pages of events fetched at most, for very busy calendars
+const MAX_PAGES: usize = 5;
+
+pub fn client(settings: &OutlookCalendarSettings) -> OAuthClient {
+    let tenant = settings.tenant.trim();
+    OAuthClient {
+        auth_url: format!("{}/{}/oauth2/v2.0/authorize", LOGIN_URL, tenant),
+        token_url: format!("{}/{}/oauth2/v2.0/token", LOGIN_URL, tenant),
+        client_id: settings.client_id.trim().to_string(),
+        // Registered as a public client: PKCE instead of a secret
+        client_secret: None,
+        scopes: SCOPES,
+        extra_params: &[("prompt", "select_account")],
+    }
+}
+
+async fn get_json(access_token: &str, url: &str) -> Result<Value, String> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .bearer_auth(access_token)
+        // Times come back in UTC instead of each event's own zone
+        .header("Prefer", "outlook.timezone=\"UTC\"")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Microsoft 365: {}", e))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Microsoft Graph returned {}: {}", status, body.trim()));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| format!("Invalid response from Microsoft Graph: {}", e))
+}
+
+/// Address of the signed-in account
+pub async fn account_email(access_token: &str) -> Result<String, String> {
+    let me = get_json(access_token, &format!("{}/me?$select=mail,userPrincipalName", GRAPH_URL)).await?;
+    ["mail", "userPrincipalName"]
+        .iter()
+        .find_map(|field| me.get(*field).and_then(Value::as_str))
+        .map(str::to_string)
+        .ok_or_else(|| "Microsoft 365 did not return the account".to_string())
+}
+
+/// Events overlapping `from`..`to`, in start order
+pub async fn fetch_events(access_token: &str, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<CalendarEvent>, String> {
+    let mut url = url::Url::parse(&format!("{}/me/calendarView", GRAPH_URL)).map_err(|e| e.to_string())?;
+    url.query_pairs_mut()
+        .append_pair("startDateTime", &from.to_rfc3339())
+        .append_pair("endDateTime", &to.to_rfc3339())
+        .append_pair("$orderby", "start/dateTime")
+        .append_pair("$top", PAGE_SIZE)
+        .append_pair(
+            "$select",
+            "id,seriesMasterId,subject,start,end,isAllDay,isCancelled,body,attendees,location,onlineMeeting,onlineMeetingUrl",
+        );
+
+    let mut events = Vec::new();
+    let mut next = Some(url.to_string());
+    for _ in 0..MAX_PAGES {
+        let Some(page_url) = next.take() else {
+            break;
+        };
+        let page = get_json(access_token, &page_url).await?;
+        events.extend(parse_events(&page));
+        next = page.get("@odata.nextLink").and_then(Value::as_str).map(str::to_string);
+    }
+    Ok(events)
+}
+
+/// A Graph time in UTC, e.g. "2026-02-10T09:00:00.0000000"
+fn parse_time(value: &Value) -> Option<DateTime<Utc>> {
+    let time = value.get("dateTime")?.as_str()?;
+    NaiveDateTime::parse_from_str(time, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .map(|time| Utc.from_utc_datetime(&time))
+}
+
+/// The video call of an event: its Teams or other online meeting, else a link in the
+/// location or body
+fn event_conference_url(item: &Value) -> Option<String> {
+    item.pointer("/onlineMeeting/joinUrl")
+        .and_then(Value::as_str)
+        .or_else(|| item.get("onlineMeetingUrl").and_then(Value::as_str))
+        .map(str::to_string)
+        .or_else(|| {
+            ["/location/displayName", "/body/content"]
+                .iter()
+                .find_map(|pointer| item.pointer(pointer).and_then(Value::as_str).and_then(conference_link))
+        })
+}
+
+/// Events from a calendarView page, without cancelled ones
+fn parse_events(page: &Value) -> Vec<CalendarEvent> {
+    let Some(items) = page.get("value").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter(|item| item.get("isCancelled").and_then(Value::as_bool) != Some(true))
+        .filter_map(|item| {
+            let start = parse_time(item.get("start")?)?;
+            let end = parse_time(item.get("end")?)?;
+            let id = match item.get("seriesMasterId").and_then(Value::as_str) {
+                Some(series) => format!("{}_{}", series, start.format("%Y%m%dT%H%M%SZ")),
+                None => item.get("id")?.as_str()?.to_string(),
+            };
+            let attendees = item
+                .get("attendees")
+                .and_then(Value::as_array)
+                .map(|attendees| {
+                    attendees
+                        .iter()
+                        // Rooms and equipment are listed as attendees too
+                        .filter(|attendee| attendee.get("type").and_then(Value::as_str) != Some("resource"))
+                        .filter(|attendee| {
+                            attendee.pointer("/status/response").and_then(Value::as_str) != Some("declined")
+                        })
+                        .filter_map(|attendee| {
+                            let email = attendee
+                                .pointer("/emailAddress/address")
+                                .and_then(Value::as_str)
+                                .map(str::to_string);
+                            let name = attendee
+                                .pointer("/emailAddress/name")
+                                .and_then(Value::as_str)
+                                .map(str::trim)
+                                .filter(|name| !name.is_empty())
+                                .map(str::to_string)
+                                .or_else(|| email.clone())?;
+                            Some(EventAttendee { name, email })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            Some(CalendarEvent {
+                id,
+                provider: "outlook".to_string(),
+                title: item
+                    .get("subject")
+                    .and_then(Value::as_str)
+                    .map(str::trim)
+                    .filter(|title| !title.is_empty())
+                    .unwrap_or("Untitled event")
+                    .to_string(),
+                start,
+                end,
+                all_day: item.get("isAllDay").and_then(Value::as_bool).unwrap_or(false),
+                description: item.pointer("/body/content").and_then(Value::as_str).map(str::to_string),
+                attendees,
+                conference_url: event_conference_url(item),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_calendar_view() {
+        let page = serde_json::json!({
+            "value": [
+                {
+                    "id": "AAMkOccurrence1",
+                    "seriesMasterId": "AAMkSeries",
+                    "subject": "Weekly sync",
+                    "isAllDay": false,
+                    "isCancelled": false,
+                    "start": { "dateTime": "2026-02-10T09:00:00.0000000", "timeZone": "UTC" },
+                    "end": { "dateTime": "2026-02-10T09:30:00.0000000", "timeZone": "UTC" },
+                    "body": { "contentType": "html", "content": "<p>Status updates</p>" },
+                    "onlineMeeting": { "joinUrl": "https://teams.microsoft.com/l/meetup-join/19%3ameeting" },
+                    "attendees": [
+                        { "type": "required", "status": { "response": "accepted" },
+                          "emailAddress": { "name": "Dana Ruiz", "address": "dana@contoso.com" } },
+                        { "type": "optional", "status": { "response": "declined" },
+                          "emailAddress": { "name": "Eli", "address": "eli@contoso.com" } },
+                        { "type": "resource", "status": { "response": "accepted" },
+                          "emailAddress": { "name": "Room 4", "address": "room4@contoso.com" } },
+                        { "type": "required", "status": { "response": "none" },
+                          "emailAddress": { "name": "", "address": "fay@contoso.com" } }
+                    ]
+                },
+                {
+                    "id": "AAMkSingle",
+                    "subject": "Vendor call",
+                    "start": { "dateTime": "2026-02-10T14:00:00.0000000", "timeZone": "UTC" },
+                    "end": { "dateTime": "2026-02-10T15:00:00.0000000", "timeZone": "UTC" },
+                    "location": { "displayName": "https://acme.zoom.us/j/555" }
+                },
+                { "id": "AAMkCancelled", "isCancelled": true }
+            ]
+        });
+        let events = parse_events(&page);
+        assert_eq!(events.len(), 2);
+
+        let sync = &events[0];
+        assert_eq!(sync.id, "AAMkSeries_20260210T090000Z");
+        assert_eq!(sync.start, Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap());
+        assert_eq!(sync.provider, "outlook");
+        assert!(sync.conference_url.as_deref().unwrap().starts_with("https://teams.microsoft.com/"));
+        let names: Vec<&str> = sync.attendees.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Dana Ruiz", "fay@contoso.com"]);
+
+        assert_eq!(events[1].id, "AAMkSingle");
+        assert_eq!(events[1].conference_url.as_deref(), Some("https://acme.zoom.us/j/555"));
+    }
+}
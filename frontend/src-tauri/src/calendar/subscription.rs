@@ -0,0 +1,172 @@
+// Subscribed calendars
+// Calendars read without signing in to Google or Microsoft: a published .ics feed (the
+// "secret address" most calendar apps offer, including webcal:// links), or a CalDAV
+// calendar such as Nextcloud, Fastmail or iCloud read with an app password. Feeds are
+// downloaded whole; CalDAV servers are asked only for events in the time window.
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use reqwest::{Method, StatusCode};
+
+use super::{ics, CalendarEvent, CalendarSubscription, SubscriptionSource};
+
+const TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+static CALENDAR_DATA_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?s)<(?:[A-Za-z0-9_-]+:)?calendar-data\b[^>]*>(.*?)</(?:[A-Za-z0-9_-]+:)?calendar-data>").unwrap()
+});
+
+/// A feed URL as fetched: webcal:// is plain HTTPS
+pub fn feed_url(url: &str) -> Result<url::Url, String> {
+    let url = url.trim();
+    let url = match url.get(..9) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("webcal://") => format!("https://{}", &url[9..]),
+        _ => url.to_string(),
+    };
+    let parsed = url::Url::parse(&url).map_err(|_| format!("Invalid calendar URL: {}", url))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("Calendar URLs must start with https://, http:// or webcal://, not {}", parsed.scheme()));
+    }
+    Ok(parsed)
+}
+
+/// Body of a CalDAV calendar-query for the events overlapping `from`..`to`
+fn calendar_query(from: DateTime<Utc>, to: DateTime<Utc>) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<C:calendar-query xmlns:D="DAV:" xmlns:C="urn:ietf:params:xml:ns:caldav">
+  <D:prop><C:calendar-data/></D:prop>
+  <C:filter>
+    <C:comp-filter name="VCALENDAR">
+      <C:comp-filter name="VEVENT">
+        <C:time-range start="{}" end="{}"/>
+      </C:comp-filter>
+    </C:comp-filter>
+  </C:filter>
+</C:calendar-query>"#,
+        from.format("%Y%m%dT%H%M%SZ"),
+        to.format("%Y%m%dT%H%M%SZ")
+    )
+}
+
+/// The iCalendar objects in a multistatus response
+fn calendar_data(multistatus: &str) -> Vec<String> {
+    CALENDAR_DATA_REGEX
+        .captures_iter(multistatus)
+        .map(|captures| {
+            let data = captures[1].trim();
+            data.strip_prefix("<![CDATA[")
+                .and_then(|data| data.strip_suffix("]]>"))
+                .map(str::to_string)
+                .unwrap_or_else(|| {
+                    data.replace("&#13;", "\r")
+                        .replace("&#xD;", "\r")
+                        .replace("&lt;", "<")
+                        .replace("&gt;", ">")
+                        .replace("&quot;", "\"")
+                        .replace("&apos;", "'")
+                        .replace("&amp;", "&")
+                })
+        })
+        .collect()
+}
+
+async fn response_text(response: reqwest::Response, name: &str) -> Result<String, String> {
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+        return Err(format!("{} refused the credentials ({})", name, status));
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("{} returned {}: {}", name, status, body.trim().chars().take(200).collect::<String>()));
+    }
+    response.text().await.map_err(|e| format!("Failed to read {}: {}", name, e))
+}
+
+/// Events of a subscribed calendar overlapping `from`..`to`, in start order
+pub async fn fetch_events(
+    subscription: &CalendarSubscription,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<CalendarEvent>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    let name = &subscription.name;
+    match &subscription.source {
+        SubscriptionSource::Ics { url } => {
+            let response = client
+                .get(feed_url(url)?)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to reach {}: {}", name, e))?;
+            let feed = response_text(response, name).await?;
+            if !feed.trim_start_matches('\u{feff}').trim_start().starts_with("BEGIN:VCALENDAR") {
+                return Err(format!("{} is not an iCalendar feed", name));
+            }
+            Ok(ics::parse_events(&feed, "ics", from, to))
+        }
+        SubscriptionSource::Caldav { url, username, password } => {
+            let report = Method::from_bytes(b"REPORT").map_err(|e| e.to_string())?;
+            let response = client
+                .request(report, feed_url(url)?)
+                .basic_auth(username, Some(password))
+                .header("Depth", "1")
+                .header("Content-Type", "application/xml; charset=utf-8")
+                .body(calendar_query(from, to))
+                .send()
+                .await
+                .map_err(|e| format!("Failed to reach {}: {}", name, e))?;
+            let multistatus = response_text(response, name).await?;
+            let mut events: Vec<CalendarEvent> = calendar_data(&multistatus)
+                .iter()
+                .flat_map(|object| ics::parse_events(object, "caldav", from, to))
+                .collect();
+            events.sort_by_key(|event| event.start);
+            Ok(events)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_feed_urls_and_caldav_responses() {
+        assert_eq!(
+            feed_url("webcal://p42-caldav.icloud.com/published/2/abc").unwrap().as_str(),
+            "https://p42-caldav.icloud.com/published/2/abc"
+        );
+        assert!(feed_url("ftp://example.com/cal.ics").is_err());
+        assert!(feed_url("calendar.ics").is_err());
+
+        let multistatus = r#"<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav">
+  <d:response><d:href>/cal/a.ics</d:href><d:propstat><d:prop>
+    <cal:calendar-data>BEGIN:VCALENDAR&#13;
+BEGIN:VEVENT&#13;
+UID:a&#13;
+SUMMARY:R&amp;D review&#13;
+END:VEVENT&#13;
+END:VCALENDAR&#13;
+</cal:calendar-data></d:prop></d:propstat></d:response>
+  <d:response><d:href>/cal/b.ics</d:href><d:propstat><d:prop>
+    <C:calendar-data xmlns:C="urn:ietf:params:xml:ns:caldav"><![CDATA[BEGIN:VCALENDAR
+UID:<b>
+END:VCALENDAR]]></C:calendar-data></d:prop></d:propstat></d:response>
+</d:multistatus>"#;
+        let objects = calendar_data(multistatus);
+        assert_eq!(objects.len(), 2);
+        assert!(objects[0].contains("UID:a\r\nSUMMARY:R&D review\r\n"));
+        assert!(objects[1].contains("UID:<b>"));
+
+        let query = calendar_query(
+            DateTime::parse_from_rfc3339("2026-02-10T09:00:00Z").unwrap().with_timezone(&Utc),
+            DateTime::parse_from_rfc3339("2026-02-11T09:00:00Z").unwrap().with_timezone(&Utc),
+        );
+        assert!(query.contains(r#"<C:time-range start="20260210T090000Z" end="20260211T090000Z"/>"#));
+    }
+}
@@ -0,0 +1,85 @@
+// Segment comments
+// Reviewers annotate what was said in a meeting without touching the transcript: each
+// comment belongs to one segment and carries its author's name. Exports include them when
+// enabled in the export settings.
+
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use crate::database::models::SegmentComment;
+use crate::database::repositories::segment_comment::SegmentCommentsRepository;
+use crate::state::AppState;
+
+const MAX_AUTHOR_CHARS: usize = 100;
+const MAX_COMMENT_CHARS: usize = 5000;
+
+fn validate_author(author: &str) -> Result<String, String> {
+    let author = author.trim();
+    if author.is_empty() {
+        return Err("Enter your name to comment".to_string());
+    }
+    if author.chars().count() > MAX_AUTHOR_CHARS {
+        return Err(format!("Names are at most {} characters", MAX_AUTHOR_CHARS));
+    }
+    Ok(author.to_string())
+}
+
+fn validate_text(text: &str) -> Result<String, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("The comment is empty".to_string());
+    }
+    if text.chars().count() > MAX_COMMENT_CHARS {
+        return Err(format!("Comments are at most {} characters", MAX_COMMENT_CHARS));
+    }
+    Ok(text.to_string())
+}
+
+#[command]
+pub async fn add_segment_comment<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    transcript_id: String,
+    author: String,
+    text: String,
+) -> Result<SegmentComment, String> {
+    let (author, text) = (validate_author(&author)?, validate_text(&text)?);
+    let state = app.state::<AppState>();
+    SegmentCommentsRepository::insert(state.db_manager.pool(), &meeting_id, &transcript_id, &author, &text)
+        .await
+        .map_err(|e| format!("Failed to save comment: {}", e))?
+        .ok_or_else(|| format!("Segment {} not found in meeting {}", transcript_id, meeting_id))
+}
+
+#[command]
+pub async fn update_segment_comment<R: Runtime>(app: AppHandle<R>, comment_id: String, text: String) -> Result<(), String> {
+    let text = validate_text(&text)?;
+    let state = app.state::<AppState>();
+    let updated = SegmentCommentsRepository::update_text(state.db_manager.pool(), &comment_id, &text)
+        .await
+        .map_err(|e| format!("Failed to save comment: {}", e))?;
+    if !updated {
+        return Err(format!("Comment {} not found", comment_id));
+    }
+    Ok(())
+}
+
+#[command]
+pub async fn delete_segment_comment<R: Runtime>(app: AppHandle<R>, comment_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let deleted = SegmentCommentsRepository::delete(state.db_manager.pool(), &comment_id)
+        .await
+        .map_err(|e| format!("Failed to delete comment: {}", e))?;
+    if !deleted {
+        return Err(format!("Comment {} not found", comment_id));
+    }
+    Ok(())
+}
+
+/// A meeting's comments, oldest first
+#[command]
+pub async fn get_meeting_comments<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<Vec<SegmentComment>, String> {
+    let state = app.state::<AppState>();
+    SegmentCommentsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load comments: {}", e))
+}
@@ -0,0 +1,277 @@
+// Database encryption at rest
+// The whole SQLite file (transcripts, summaries and the full-text index alike) is encrypted
+// with SQLCipher under a random 256-bit key kept in the OS keychain. Turning encryption on
+// or off writes a converted copy that replaces the database when the app starts again, so
+// open connections never see the file change under them.
+
+use log::{info, warn};
+use rand::{rngs::OsRng, RngCore};
+use serde::Serialize;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteConnection};
+use sqlx::Connection;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::state::AppState;
+
+pub(crate) const KEYCHAIN_SERVICE: &str = "com.meetily.ai";
+const KEYCHAIN_ACCOUNT: &str = "database-key";
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+#[derive(Debug, Serialize)]
+pub struct DatabaseEncryptionStatus {
+    pub encrypted: bool,
+    /// A converted copy is waiting to replace the database at the next start
+    pub pending_restart: bool,
+}
+
+/// `db_path` with "-suffix" appended, next to the -wal and -shm files
+pub(crate) fn sibling(db_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = db_path.as_os_str().to_owned();
+    name.push(format!("-{}", suffix));
+    PathBuf::from(name)
+}
+
+/// Whether the file exists and is not plain SQLite (a new, empty file counts as plain)
+pub fn is_encrypted(db_path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    match std::fs::File::open(db_path).and_then(|mut file| file.read_exact(&mut header)) {
+        Ok(()) => &header != SQLITE_HEADER,
+        Err(_) => false,
+    }
+}
+
+fn keychain_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)
+        .map_err(|e| format!("Failed to access the system keychain: {}", e))
+}
+
+fn stored_key() -> Result<Option<String>, String> {
+    match keychain_entry()?.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read the database key from the system keychain: {}", e)),
+    }
+}
+
+/// 32 random bytes as hex
+pub(crate) fn generate_key() -> String {
+    let mut key = [0u8; 32];
+    OsRng.fill_bytes(&mut key);
+    key.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// SQLCipher raw-key form, used as-is (no key derivation)
+pub(crate) fn raw_key(hex: &str) -> Result<String, String> {
+    if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err("The database key in the system keychain is malformed".to_string());
+    }
+    Ok(format!("x'{}'", hex))
+}
+
+/// Connection options for the database, keyed from the keychain when the file is encrypted
+pub fn connect_options(db_path: &str) -> Result<SqliteConnectOptions, String> {
+    keyed_options(Path::new(db_path), &current_key(Path::new(db_path))?)
+}
+
+/// Connection options for a database file encrypted with `key` ("" for plain SQLite)
+pub(crate) fn keyed_options(path: &Path, key: &str) -> Result<SqliteConnectOptions, String> {
+    let options = SqliteConnectOptions::from_str(&path.to_string_lossy())
+        .map_err(|e| format!("Invalid database path {}: {}", path.display(), e))?;
+    if key.is_empty() {
+        Ok(options)
+    } else {
+        // sqlx sends `key` before any other pragma, as SQLCipher requires
+        Ok(options.pragma("key", format!("\"{}\"", key)))
+    }
+}
+
+/// Remove copies a conversion, backup or restore left behind when the app stopped midway
+fn remove_leftovers(db_path: &Path) {
+    let (Some(dir), Some(name)) = (db_path.parent(), db_path.file_name()) else {
+        return;
+    };
+    let name = name.to_string_lossy();
+    let leftover_prefixes = ["converting", "restoring", "backup-"].map(|suffix| format!("{}-{}", name, suffix));
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if leftover_prefixes.iter().any(|prefix| file_name.starts_with(prefix.as_str())) {
+            match std::fs::remove_file(entry.path()) {
+                Ok(()) => info!("Removed leftover database copy {}", file_name),
+                Err(e) => warn!("Failed to remove leftover database copy {}: {}", file_name, e),
+            }
+        }
+    }
+}
+
+/// Swap in a copy staged by turning encryption on or off or by restoring a backup; call
+/// before opening
+pub fn apply_pending_conversion(db_path: &Path) -> std::io::Result<()> {
+    remove_leftovers(db_path);
+    let pending = sibling(db_path, "pending");
+    if !pending.exists() {
+        return Ok(());
+    }
+    // The old write-ahead log belongs to the old file; its changes are in the copy
+    for suffix in ["wal", "shm"] {
+        let path = sibling(db_path, suffix);
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    std::fs::rename(&pending, db_path)?;
+
+    let encrypted = is_encrypted(db_path);
+    info!("Switched to the staged {} database", if encrypted { "encrypted" } else { "plain" });
+    if !encrypted {
+        match keychain_entry().map(|entry| entry.delete_credential()) {
+            Ok(Ok(())) | Ok(Err(keyring::Error::NoEntry)) => {}
+            Ok(Err(e)) => warn!("Failed to remove the unused database key from the keychain: {}", e),
+            Err(e) => warn!("{}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Key to stage copies of the database with: the current one when it is encrypted, else
+/// "" (plain SQLite)
+pub(crate) fn current_key(db_path: &Path) -> Result<String, String> {
+    if !is_encrypted(db_path) {
+        return Ok(String::new());
+    }
+    let key = stored_key()?
+        .ok_or_else(|| "The database is encrypted but its key is missing from the system keychain".to_string())?;
+    raw_key(&key)
+}
+
+/// Copy the database open on `conn` to `target`, encrypted with `key` ("" for plain SQLite)
+pub(crate) async fn export_copy(conn: &mut SqliteConnection, target: &Path, key: &str) -> Result<(), String> {
+    if target.exists() {
+        std::fs::remove_file(target).map_err(|e| format!("Failed to remove {}: {}", target.display(), e))?;
+    }
+    // ATTACH is per connection, so the three statements share one
+    sqlx::query("ATTACH DATABASE ? AS converted KEY ?")
+        .bind(target.to_string_lossy().to_string())
+        .bind(key)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Failed to create the database copy: {}", e))?;
+    let exported = sqlx::query("SELECT sqlcipher_export('converted')").execute(&mut *conn).await;
+    let detached = sqlx::query("DETACH DATABASE converted").execute(&mut *conn).await;
+    exported.map_err(|e| format!("Failed to copy the database: {}", e))?;
+    detached.map_err(|e| format!("Failed to close the database copy: {}", e))?;
+    Ok(())
+}
+
+/// Stage a copy of the database open on `conn` to replace `db_path` at the next start
+pub(crate) async fn stage_copy(conn: &mut SqliteConnection, db_path: &Path, key: &str) -> Result<(), String> {
+    let converting = sibling(db_path, "converting");
+    export_copy(conn, &converting, key).await?;
+    std::fs::rename(&converting, sibling(db_path, "pending"))
+        .map_err(|e| format!("Failed to stage the database copy: {}", e))
+}
+
+pub(crate) fn database_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("meeting_minutes.sqlite"))
+        .map_err(|e| format!("Failed to resolve the app data folder: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_database_encryption_status<R: Runtime>(app: AppHandle<R>) -> Result<DatabaseEncryptionStatus, String> {
+    let db_path = database_path(&app)?;
+    Ok(DatabaseEncryptionStatus {
+        encrypted: is_encrypted(&db_path),
+        pending_restart: sibling(&db_path, "pending").exists(),
+    })
+}
+
+/// Turn encryption at rest on or off
+///
+/// A converted copy of the database is written next to it and the app restarts to switch
+/// over. Refused while a recording or summary is running; otherwise the database is closed
+/// for the rest of this run before copying, so nothing written after the copy is lost when
+/// it replaces the database. Turning encryption on first stores a new key in the OS
+/// keychain; turning it off removes the key once the plain database is in place.
+#[tauri::command]
+pub async fn set_database_encryption<R: Runtime>(app: AppHandle<R>, enabled: bool) -> Result<(), String> {
+    let db_path = database_path(&app)?;
+    if is_encrypted(&db_path) == enabled {
+        return Err(if enabled {
+            "The database is already encrypted".to_string()
+        } else {
+            "The database is not encrypted".to_string()
+        });
+    }
+    if crate::audio::recording_commands::is_recording().await {
+        return Err("Stop the recording before changing database encryption".to_string());
+    }
+    if crate::summary::service::SummaryService::is_generating() {
+        return Err("Wait for the summary to finish before changing database encryption".to_string());
+    }
+
+    let key = if enabled {
+        let key = generate_key();
+        keychain_entry()?
+            .set_password(&key)
+            .map_err(|e| format!("Failed to store the database key in the system keychain: {}", e))?;
+        raw_key(&key)?
+    } else {
+        String::new()
+    };
+
+    // Writes fail from here until the restart; waits for those in flight to finish
+    let state = app.state::<AppState>();
+    state.db_manager.pool().close().await;
+
+    let staged = async {
+        let mut conn = SqliteConnection::connect_with(&connect_options(&db_path.to_string_lossy())?)
+            .await
+            .map_err(|e| format!("Failed to open the database: {}", e))?;
+        let staged = stage_copy(&mut conn, &db_path, &key).await;
+        let _ = conn.close().await;
+        staged
+    }
+    .await;
+    match staged {
+        Ok(()) => info!(
+            "{} database copy written; restarting to switch over",
+            if enabled { "Encrypted" } else { "Decrypted" }
+        ),
+        // The database is closed, so restart on the unchanged one
+        Err(e) => warn!("Failed to convert the database, restarting without switching: {}", e),
+    }
+    app.restart()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_plain_and_encrypted_files_by_header() {
+        let dir = std::env::temp_dir().join(format!("meeting-db-encryption-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("meeting_minutes.sqlite");
+
+        assert!(!is_encrypted(&db_path));
+        std::fs::write(&db_path, b"").unwrap();
+        assert!(!is_encrypted(&db_path));
+        std::fs::write(&db_path, [SQLITE_HEADER.as_slice(), &[0u8; 84]].concat()).unwrap();
+        assert!(!is_encrypted(&db_path));
+        std::fs::write(&db_path, [0x5au8; 100]).unwrap();
+        assert!(is_encrypted(&db_path));
+
+        assert_eq!(sibling(&db_path, "pending"), dir.join("meeting_minutes.sqlite-pending"));
+        assert_eq!(generate_key().len(), 64);
+        assert!(raw_key(&generate_key()).unwrap().starts_with("x'"));
+        assert!(raw_key("x'; DROP").is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -30,9 +30,11 @@ impl DatabaseManager {
             }
         }
 
-        let pool = SqlitePool::connect(tauri_db_path).await?;
+        let options = super::encryption::connect_options(tauri_db_path)
+            .map_err(|e| sqlx::Error::Configuration(e.into()))?;
+        let pool = SqlitePool::connect_with(options).await?;
 
-        sqlx::migrate!("./migrations").run(&pool).await?;
+        super::migrations::run(&pool, Path::new(tauri_db_path)).await?;
 
         Ok(DatabaseManager { pool })
     }
@@ -69,6 +71,11 @@ impl DatabaseManager {
         log::info!("Tauri DB path: {}", tauri_db_path);
         log::info!("Legacy backend DB path: {}", backend_db_path);
 
+        // Finish turning encryption on or off when the app restarted for it
+        if let Err(e) = super::encryption::apply_pending_conversion(Path::new(&tauri_db_path)) {
+            log::error!("Failed to switch to the converted database: {}", e);
+        }
+
         // Try to open database with defensive WAL handling
         match Self::new(&tauri_db_path, &backend_db_path).await {
             Ok(db_manager) => {
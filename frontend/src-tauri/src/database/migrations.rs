@@ -0,0 +1,211 @@
+// Schema migrations
+// The SQL files in migrations/ are embedded at build time and applied at startup in
+// version order, each in its own transaction. Before a database holding data is upgraded,
+// a copy is saved under migration_backups/ next to it (the newest few are kept), so a
+// failed or faulty upgrade never costs a user their meetings. A database already migrated
+// by a newer version of the app still opens: its extra migrations are left in place.
+
+use chrono::Utc;
+use serde::Serialize;
+use sqlx::migrate::Migrator;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::encryption::{current_key, database_path, export_copy};
+use crate::state::AppState;
+
+const BACKUP_DIR: &str = "migration_backups";
+const BACKUP_PREFIX: &str = "meeting_minutes-";
+const KEPT_BACKUPS: usize = 3;
+
+#[derive(Debug, Serialize)]
+pub struct SchemaStatus {
+    /// Latest migration applied to the database
+    pub version: i64,
+    /// Latest migration this build ships
+    pub latest_known: i64,
+    /// The database was migrated by a newer version of the app
+    pub newer_than_app: bool,
+    /// Copies saved before upgrades, newest first
+    pub backups: Vec<String>,
+}
+
+fn migrator() -> Migrator {
+    sqlx::migrate!("./migrations")
+}
+
+/// Newest migration this build knows
+pub fn latest_known() -> i64 {
+    migrator()
+        .iter()
+        .map(|migration| migration.version)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Versions successfully applied to the database, oldest first
+pub async fn applied_versions(pool: &SqlitePool) -> Result<Vec<i64>, sqlx::Error> {
+    let tracked: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'")
+            .fetch_one(pool)
+            .await?;
+    if tracked == 0 {
+        return Ok(Vec::new());
+    }
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = 1 ORDER BY version")
+        .fetch_all(pool)
+        .await
+}
+
+pub fn backup_dir(db_path: &Path) -> PathBuf {
+    db_path
+        .parent()
+        .map(|dir| dir.join(BACKUP_DIR))
+        .unwrap_or_else(|| PathBuf::from(BACKUP_DIR))
+}
+
+/// File name of the copy taken before upgrading from `version`; names sort by age
+fn backup_name(version: i64) -> String {
+    format!("{}{}-v{}.sqlite", BACKUP_PREFIX, Utc::now().format("%Y%m%d%H%M%S"), version)
+}
+
+/// Backup file names in `dir`, newest first
+fn list_backups(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| name.starts_with(BACKUP_PREFIX) && name.ends_with(".sqlite"))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_unstable_by(|a, b| b.cmp(a));
+    names
+}
+
+/// Backups beyond the newest `keep`, to delete
+fn backups_to_prune(newest_first: &[String], keep: usize) -> &[String] {
+    newest_first.get(keep..).unwrap_or_default()
+}
+
+/// Save a copy of the database before upgrading it; returns where it went
+async fn backup_before_migrate(pool: &SqlitePool, db_path: &Path, version: i64) -> Result<PathBuf, String> {
+    let dir = backup_dir(db_path);
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let target = dir.join(backup_name(version));
+    // The copy keeps the database's encryption
+    let key = current_key(db_path)?;
+    let mut conn = pool.acquire().await.map_err(|e| format!("Failed to open the database: {}", e))?;
+    export_copy(&mut conn, &target, &key).await?;
+
+    let backups = list_backups(&dir);
+    for name in backups_to_prune(&backups, KEPT_BACKUPS) {
+        if let Err(e) = std::fs::remove_file(dir.join(name)) {
+            log::warn!("Failed to remove old migration backup {}: {}", name, e);
+        }
+    }
+    Ok(target)
+}
+
+/// Bring the database open on `pool` (stored at `db_path`) up to the current schema
+pub async fn run(pool: &SqlitePool, db_path: &Path) -> Result<(), sqlx::Error> {
+    let mut migrator = migrator();
+    let applied = applied_versions(pool).await?;
+    let known: Vec<i64> = migrator.iter().map(|migration| migration.version).collect();
+
+    let unknown: Vec<i64> = applied.iter().copied().filter(|version| !known.contains(version)).collect();
+    if !unknown.is_empty() {
+        log::warn!(
+            "The database was migrated by a newer version of the app ({:?}); keeping its newer schema",
+            unknown
+        );
+        migrator.set_ignore_missing(true);
+    }
+    let pending: Vec<i64> = known.iter().copied().filter(|version| !applied.contains(version)).collect();
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'")
+        .fetch_one(pool)
+        .await?;
+    let backup = if tables > 0 {
+        let version = applied.last().copied().unwrap_or(0);
+        match backup_before_migrate(pool, db_path, version).await {
+            Ok(path) => {
+                log::info!("Saved the database to {} before migrating", path.display());
+                Some(path)
+            }
+            Err(e) => {
+                log::error!("Failed to back up the database before migrating: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    log::info!("Applying {} database migration(s): {:?}", pending.len(), pending);
+    if let Err(e) = migrator.run(pool).await {
+        match &backup {
+            Some(path) => log::error!("Database migration failed: {}; the previous database is at {}", e, path.display()),
+            None => log::error!("Database migration failed: {}", e),
+        }
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_database_schema_status<R: Runtime>(app: AppHandle<R>) -> Result<SchemaStatus, String> {
+    let db_path = database_path(&app)?;
+    let state = app.state::<AppState>();
+    let applied = applied_versions(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to read the database version: {}", e))?;
+    let latest_known = latest_known();
+    let version = applied.last().copied().unwrap_or(0);
+    Ok(SchemaStatus {
+        version,
+        latest_known,
+        newer_than_app: version > latest_known,
+        backups: list_backups(&backup_dir(&db_path)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_newest_backups() {
+        let dir = std::env::temp_dir().join(format!("meeting-migration-backups-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "meeting_minutes-20260101090000-v20260120000000.sqlite",
+            "meeting_minutes-20260301090000-v20260127000000.sqlite",
+            "meeting_minutes-20260201090000-v20260125000000.sqlite",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.join(name), b"").unwrap();
+        }
+
+        let backups = list_backups(&dir);
+        assert_eq!(
+            backups,
+            vec![
+                "meeting_minutes-20260301090000-v20260127000000.sqlite",
+                "meeting_minutes-20260201090000-v20260125000000.sqlite",
+                "meeting_minutes-20260101090000-v20260120000000.sqlite",
+            ]
+        );
+        assert_eq!(backups_to_prune(&backups, 2), &backups[2..]);
+        assert!(backups_to_prune(&backups, 3).is_empty());
+        assert!(backups_to_prune(&backups, 5).is_empty());
+        assert!(backup_name(20260130000000).ends_with("-v20260130000000.sqlite"));
+        assert_eq!(backup_dir(&dir.join("meeting_minutes.sqlite")), dir.join(BACKUP_DIR));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
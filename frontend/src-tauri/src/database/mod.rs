@@ -1,5 +1,8 @@
 pub mod commands;
+pub mod encryption;
 pub mod manager;
+pub mod migrations;
 pub mod models;
 pub mod repositories;
+pub mod search;
 pub mod setup;
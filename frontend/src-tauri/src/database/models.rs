@@ -9,6 +9,33 @@ pub struct MeetingModel {
     pub created_at: DateTimeUtc,
     pub updated_at: DateTimeUtc,
     pub folder_path: Option<String>,
+    // One-line summary, generated when the meeting ends
+    #[sqlx(default)]
+    pub description: Option<String>,
+    // Language the minutes are written in (ISO 639-1), None for the meeting's own language
+    #[sqlx(default)]
+    pub summary_language: Option<String>,
+    // Agenda attached to the meeting, one item per line
+    #[sqlx(default)]
+    pub agenda: Option<String>,
+    // Kept by retention cleanup when the policy exempts starred meetings
+    #[sqlx(default)]
+    pub starred: bool,
+    // Library folder the meeting is filed in, None for unfiled
+    #[sqlx(default)]
+    pub library_folder_id: Option<String>,
+    // When the meeting was scheduled to start, from its calendar event or set by hand
+    #[sqlx(default)]
+    pub scheduled_start: Option<DateTime<Utc>>,
+    // Length of the recording, from the end of its last transcript segment
+    #[sqlx(default)]
+    pub duration_seconds: Option<f64>,
+    // Conferencing app the meeting was held on ("Zoom", "Microsoft Teams", ...)
+    #[sqlx(default)]
+    pub platform: Option<String>,
+    // Id of the calendar event the meeting belongs to
+    #[sqlx(default)]
+    pub calendar_event_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
@@ -35,6 +62,38 @@ pub struct Transcript {
     pub audio_start_time: Option<f64>,
     pub audio_end_time: Option<f64>,
     pub duration: Option<f64>,
+    // Audio quality score of the source chunk (0.0-1.0), used to mark garbled regions
+    pub quality_score: Option<f64>,
+    // Audio source ('mic' / 'system') when channels were transcribed separately
+    pub speaker: Option<String>,
+    // Diarized speaker label ('Speaker 1', ...) when diarization was enabled
+    pub speaker_label: Option<String>,
+    // Spoken over another speaker's segment; least reliable text
+    pub overlapping_speech: bool,
+    // Tone analysis ('positive' / 'neutral' / 'negative', tension 0.0-1.0) once the meeting was analyzed
+    pub sentiment: Option<String>,
+    pub tension: Option<f64>,
+    // Spoken language (ISO 639-1) of the segment
+    pub language: Option<String>,
+    // Transcription confidence and whether the segment is flagged for proofreading
+    pub confidence: Option<f64>,
+    pub needs_review: bool,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct TranscriptRevision {
+    pub id: String,
+    pub meeting_id: String,
+    // 'text', 'merge', 'split', 'speaker' or 'revert'
+    pub kind: String,
+    // JSON arrays of the affected Transcript rows before and after the edit
+    pub before_segments: String,
+    pub after_segments: String,
+    // Revision this one undid (kind 'revert')
+    pub reverts: Option<String>,
+    // Later revision that undid this one
+    pub reverted_by: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -89,6 +148,9 @@ pub struct Setting {
     #[sqlx(rename = "openRouterApiKey")]
     #[serde(rename = "openRouterApiKey")]
     pub open_router_api_key: Option<String>,
+    #[sqlx(rename = "geminiApiKey")]
+    #[serde(rename = "geminiApiKey")]
+    pub gemini_api_key: Option<String>,
     #[sqlx(rename = "ollamaEndpoint")]
     #[serde(rename = "ollamaEndpoint")]
     pub ollama_endpoint: Option<String>,
@@ -96,6 +158,30 @@ pub struct Setting {
     #[sqlx(rename = "customOpenAIConfig")]
     #[serde(rename = "customOpenAIConfig")]
     pub custom_openai_config: Option<String>,
+    /// User prompt templates for minutes generation stored as JSON
+    #[sqlx(rename = "promptTemplates")]
+    #[serde(rename = "promptTemplates")]
+    pub prompt_templates: Option<String>,
+    /// Embedding model for the per-meeting vector index stored as JSON
+    #[sqlx(rename = "embeddingConfig")]
+    #[serde(rename = "embeddingConfig")]
+    pub embedding_config: Option<String>,
+    /// Weekly digest schedule stored as JSON
+    #[sqlx(rename = "digestSchedule")]
+    #[serde(rename = "digestSchedule")]
+    pub digest_schedule: Option<String>,
+    /// Default minutes style ("executive_brief", "detailed_minutes", ...)
+    #[sqlx(rename = "summaryStyle")]
+    #[serde(rename = "summaryStyle")]
+    pub summary_style: Option<String>,
+    /// PII redaction for cloud models stored as JSON
+    #[sqlx(rename = "piiRedaction")]
+    #[serde(rename = "piiRedaction")]
+    pub pii_redaction: Option<String>,
+    /// Meeting export options stored as JSON
+    #[sqlx(rename = "exportSettings")]
+    #[serde(rename = "exportSettings")]
+    pub export_settings: Option<String>,
 }
 
 impl Setting {
@@ -128,3 +214,312 @@ pub struct TranscriptSetting {
     #[serde(rename = "openaiApiKey")]
     pub openai_api_key: Option<String>,
 }
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct VoiceProfile {
+    pub id: String,
+    pub name: String,
+    // Averaged speaker embedding, little-endian f32 values
+    #[serde(skip)]
+    pub embedding: Vec<u8>,
+    pub sample_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingSpeakerFingerprint {
+    pub id: String,
+    pub meeting_id: String,
+    pub label: String,
+    // Speaker centroid embedding, little-endian f32 values
+    #[serde(skip)]
+    pub embedding: Vec<u8>,
+    pub segment_count: i64,
+    // Shared by fingerprints believed to be the same person across meetings
+    pub voice_group_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub description: String,
+    pub assignee: Option<String>,
+    // ISO date (YYYY-MM-DD), only when the meeting mentioned one
+    pub due_date: Option<String>,
+    // Transcript segment the item was extracted from
+    pub segment_id: Option<String>,
+    pub done: bool,
+    // Jira or Linear issue created from the item, e.g. "ENG-123"
+    #[serde(default)]
+    pub issue_key: Option<String>,
+    #[serde(default)]
+    pub issue_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingOutcome {
+    pub id: String,
+    pub meeting_id: String,
+    // "decision" or "open_question"
+    pub kind: String,
+    pub text: String,
+    // JSON array of transcript segment ids
+    pub segment_ids: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingChapter {
+    pub id: String,
+    pub meeting_id: String,
+    pub position: i64,
+    pub title: String,
+    // First transcript segment of the chapter
+    pub start_segment_id: Option<String>,
+    // Recording-relative seconds; end_time is None for the last chapter
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingHighlight {
+    pub id: String,
+    pub meeting_id: String,
+    pub position: i64,
+    // Verbatim from the transcript segment
+    pub quote: String,
+    pub speaker: Option<String>,
+    pub segment_id: Option<String>,
+    // Recording-relative seconds of the quoted segment
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+    // Why the model picked it
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct StandupItem {
+    pub id: String,
+    pub meeting_id: String,
+    pub speaker: String,
+    // "yesterday", "today", "blocker" or "risk"
+    pub kind: String,
+    pub text: String,
+    // JSON array of transcript segment ids
+    pub segment_ids: String,
+    pub start_time: Option<f64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingParticipant {
+    pub id: String,
+    pub meeting_id: String,
+    pub name: String,
+    pub email: Option<String>,
+    // 'manual', 'speaker' or 'calendar'
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingAttachment {
+    pub id: String,
+    pub meeting_id: String,
+    // Name the file was attached under
+    pub file_name: String,
+    // Name of the copy in the meeting's attachments folder
+    pub stored_name: String,
+    pub mime_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AudioBlob {
+    // Stored copy, in the .audio-store folder beside the meeting folders
+    pub path: String,
+    // SHA-256 of the content, hex
+    pub hash: String,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct WatchFolderFile {
+    pub path: String,
+    pub size_bytes: i64,
+    // File modification time when it was picked up
+    pub modified_at: DateTime<Utc>,
+    // "processing", "done" or "failed"
+    pub status: String,
+    pub meeting_id: Option<String>,
+    pub error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A starred transcript segment, with its text and meeting
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SegmentBookmark {
+    pub transcript_id: String,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub meeting_created_at: DateTimeUtc,
+    pub text: String,
+    pub speaker: Option<String>,
+    // Recording-relative seconds of the segment
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SegmentComment {
+    pub id: String,
+    pub meeting_id: String,
+    pub transcript_id: String,
+    pub author: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingFolder {
+    pub id: String,
+    pub name: String,
+    // Enclosing folder, None at the top level
+    pub parent_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingDigest {
+    pub id: String,
+    pub title: String,
+    pub range_start: DateTime<Utc>,
+    pub range_end: DateTime<Utc>,
+    // Only meetings with this tag were included
+    pub tag: Option<String>,
+    // JSON array of the meeting ids covered
+    pub meeting_ids: String,
+    pub markdown: String,
+    // Generated by the digest schedule rather than on request
+    pub scheduled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryVersion {
+    pub id: String,
+    pub meeting_id: String,
+    // 1-based, in the order the versions were saved
+    pub version: i64,
+    // "generated", "edited" or "earlier"
+    pub source: String,
+    pub markdown: String,
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub style: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A full-text match in a meeting's transcript or summary
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingSearchHit {
+    pub meeting_id: String,
+    pub meeting_title: String,
+    // "transcript" or "summary"
+    pub source: String,
+    // Matched segment; None for summary hits
+    pub segment_id: Option<String>,
+    pub speaker: Option<String>,
+    // Recording-relative seconds of the matched segment
+    pub audio_start_time: Option<f64>,
+    // When the segment was transcribed
+    pub timestamp: Option<String>,
+    // Text around the match, matched terms wrapped in **
+    pub snippet: String,
+    // BM25 rank; lower is a better match
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryFeedback {
+    pub id: String,
+    pub meeting_id: String,
+    // Summary version the feedback was given on
+    pub version: Option<i64>,
+    // Section heading; None for the whole summary
+    pub section: Option<String>,
+    // 1 (thumbs up) or -1 (thumbs down)
+    pub rating: i64,
+    pub comment: Option<String>,
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    pub id: String,
+    // "person", "project", "acronym" or "term"
+    pub kind: String,
+    pub term: String,
+    pub definition: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Usage summed over a group of requests
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct LlmUsageTotals {
+    /// Provider, model, operation or meeting id, depending on the grouping
+    pub key: Option<String>,
+    /// Secondary label: the model for provider groups, the title for meeting groups
+    pub label: Option<String>,
+    pub calls: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    /// Sum over priced requests only
+    pub cost_usd: f64,
+    pub unpriced_calls: i64,
+}
+
+/// Encrypted content of a password-protected meeting
+#[derive(Debug, Clone, FromRow)]
+pub struct ProtectedMeeting {
+    pub meeting_id: String,
+    pub salt: Vec<u8>,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+    // Plaintext is written back until the meeting is locked again
+    pub unlocked: bool,
+    pub updated_at: DateTime<Utc>,
+    // The ciphertext holds the meeting's details (`ProtectedContent::details`) too
+    pub covers_details: bool,
+}
+
+/// The text of a protected meeting that is encrypted: transcript segments by id, the
+/// summary, the transcript sent for summarizing, earlier summary versions by id, and the
+/// details extracted from or added to the transcript
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProtectedContent {
+    pub segments: Vec<(String, String)>,
+    pub summary_result: Option<String>,
+    pub summary_source_text: Option<String>,
+    pub summary_versions: Vec<(String, String)>,
+    /// ("table.column", row key, text) of action items, decisions, highlights, stand-up
+    /// items, comments, transcript revisions, redacted originals, chapter titles, bookmark
+    /// notes, meeting notes and summary feedback; missing from meetings locked before
+    #[serde(default)]
+    pub details: Vec<(String, String, Option<String>)>,
+}
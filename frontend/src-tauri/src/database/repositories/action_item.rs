@@ -0,0 +1,91 @@
+use crate::database::models::ActionItem;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Action item fields produced by the extraction pass
+pub struct NewActionItem {
+    pub description: String,
+    pub assignee: Option<String>,
+    pub due_date: Option<String>,
+    pub segment_id: Option<String>,
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Replace a meeting's action items with a fresh extraction, in one transaction
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        items: &[NewActionItem],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM action_items WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        let now = Utc::now();
+        for item in items {
+            sqlx::query(
+                "INSERT INTO action_items (id, meeting_id, description, assignee, due_date, segment_id, done, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, 0, ?)",
+            )
+            .bind(format!("action-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(&item.description)
+            .bind(&item.assignee)
+            .bind(&item.due_date)
+            .bind(&item.segment_id)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, sqlx::Error> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items WHERE meeting_id = ? ORDER BY created_at, rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Open items of every meeting that isn't locked or in the trash, those due soonest first
+    pub async fn list_open(pool: &SqlitePool) -> Result<Vec<ActionItem>, sqlx::Error> {
+        sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items
+             WHERE done = 0
+               AND meeting_id NOT IN (SELECT meeting_id FROM protected_meetings WHERE unlocked = 0)
+               AND meeting_id IN (SELECT id FROM meetings WHERE deleted_at IS NULL)
+             ORDER BY due_date IS NULL, due_date, created_at, rowid",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set_done(pool: &SqlitePool, id: &str, done: bool) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE action_items SET done = ? WHERE id = ?")
+            .bind(done)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Link an item to the issue tracker issue created from it
+    pub async fn set_issue(pool: &SqlitePool, id: &str, issue_key: &str, issue_url: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE action_items SET issue_key = ?, issue_url = ? WHERE id = ?")
+            .bind(issue_key)
+            .bind(issue_url)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
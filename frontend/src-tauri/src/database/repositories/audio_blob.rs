@@ -0,0 +1,80 @@
+use crate::database::models::AudioBlob;
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+pub struct AudioBlobsRepository;
+
+impl AudioBlobsRepository {
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<AudioBlob>, sqlx::Error> {
+        sqlx::query_as::<_, AudioBlob>("SELECT * FROM audio_blobs ORDER BY created_at")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn find_by_hash(pool: &SqlitePool, hash: &str) -> Result<Vec<AudioBlob>, sqlx::Error> {
+        sqlx::query_as::<_, AudioBlob>("SELECT * FROM audio_blobs WHERE hash = ? ORDER BY created_at")
+            .bind(hash)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn insert(pool: &SqlitePool, blob: &AudioBlob) -> Result<(), sqlx::Error> {
+        sqlx::query("INSERT OR REPLACE INTO audio_blobs (path, hash, size_bytes, created_at) VALUES (?, ?, ?, ?)")
+            .bind(&blob.path)
+            .bind(&blob.hash)
+            .bind(blob.size_bytes)
+            .bind(blob.created_at)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Delete a blob record and its links
+    pub async fn delete(pool: &SqlitePool, path: &str) -> Result<(), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        sqlx::query("DELETE FROM audio_blob_links WHERE blob_path = ?")
+            .bind(path)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM audio_blobs WHERE path = ?")
+            .bind(path)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await
+    }
+
+    /// Blob the recording at `path` is linked to, if any
+    pub async fn linked_blob(pool: &SqlitePool, path: &str) -> Result<Option<AudioBlob>, sqlx::Error> {
+        sqlx::query_as::<_, AudioBlob>(
+            "SELECT b.* FROM audio_blob_links l JOIN audio_blobs b ON b.path = l.blob_path WHERE l.path = ?",
+        )
+        .bind(path)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// (recording path, blob path) of every link
+    pub async fn list_links(pool: &SqlitePool) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, String)>("SELECT path, blob_path FROM audio_blob_links")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn add_link(pool: &SqlitePool, path: &str, blob_path: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("INSERT OR REPLACE INTO audio_blob_links (path, blob_path, created_at) VALUES (?, ?, ?)")
+            .bind(path)
+            .bind(blob_path)
+            .bind(Utc::now())
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn remove_link(pool: &SqlitePool, path: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM audio_blob_links WHERE path = ?")
+            .bind(path)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
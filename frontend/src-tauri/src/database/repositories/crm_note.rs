@@ -0,0 +1,32 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+pub struct CrmNotesRepository;
+
+impl CrmNotesRepository {
+    /// Id of the note a meeting became in `provider`, if it was attached before
+    pub async fn get_note_id(pool: &SqlitePool, provider: &str, meeting_id: &str) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT note_id FROM crm_notes WHERE provider = ? AND meeting_id = ?")
+            .bind(provider)
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Record the meeting's note, replacing one that no longer exists
+    pub async fn save(pool: &SqlitePool, provider: &str, meeting_id: &str, note_id: &str) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO crm_notes (provider, meeting_id, note_id, created_at, updated_at) VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(provider, meeting_id) DO UPDATE SET note_id = excluded.note_id, updated_at = excluded.updated_at",
+        )
+        .bind(provider)
+        .bind(meeting_id)
+        .bind(note_id)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
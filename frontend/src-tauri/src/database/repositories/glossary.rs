@@ -0,0 +1,83 @@
+use crate::database::models::GlossaryEntry;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct GlossaryRepository;
+
+impl GlossaryRepository {
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<GlossaryEntry>, sqlx::Error> {
+        sqlx::query_as::<_, GlossaryEntry>("SELECT * FROM glossary_entries ORDER BY kind, term COLLATE NOCASE")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Add an entry, or update the one with `id`. Saving a term that already exists under
+    /// another id updates that entry instead of failing on the unique term.
+    pub async fn save(
+        pool: &SqlitePool,
+        id: Option<&str>,
+        kind: &str,
+        term: &str,
+        definition: Option<&str>,
+    ) -> Result<GlossaryEntry, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let now = Utc::now();
+
+        let existing: Option<String> = match id {
+            Some(id) => Some(id.to_string()),
+            None => sqlx::query_scalar("SELECT id FROM glossary_entries WHERE term = ?")
+                .bind(term)
+                .fetch_optional(&mut *transaction)
+                .await?,
+        };
+
+        let id = match existing {
+            Some(id) => {
+                let updated = sqlx::query(
+                    "UPDATE glossary_entries SET kind = ?, term = ?, definition = ?, updated_at = ? WHERE id = ?",
+                )
+                .bind(kind)
+                .bind(term)
+                .bind(definition)
+                .bind(now)
+                .bind(&id)
+                .execute(&mut *transaction)
+                .await?;
+                if updated.rows_affected() == 0 {
+                    return Err(sqlx::Error::RowNotFound);
+                }
+                id
+            }
+            None => {
+                let id = format!("glossary-{}", Uuid::new_v4());
+                sqlx::query(
+                    "INSERT INTO glossary_entries (id, kind, term, definition, updated_at) VALUES (?, ?, ?, ?, ?)",
+                )
+                .bind(&id)
+                .bind(kind)
+                .bind(term)
+                .bind(definition)
+                .bind(now)
+                .execute(&mut *transaction)
+                .await?;
+                id
+            }
+        };
+
+        let entry = sqlx::query_as::<_, GlossaryEntry>("SELECT * FROM glossary_entries WHERE id = ?")
+            .bind(&id)
+            .fetch_one(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(entry)
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM glossary_entries WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
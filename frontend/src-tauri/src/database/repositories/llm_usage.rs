@@ -0,0 +1,87 @@
+use crate::database::models::LlmUsageTotals;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// One completion request to record
+pub struct NewLlmUsage<'a> {
+    pub meeting_id: Option<&'a str>,
+    pub provider: &'a str,
+    pub model: &'a str,
+    pub operation: &'a str,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    pub cost_usd: Option<f64>,
+}
+
+pub struct LlmUsageRepository;
+
+const TOTALS: &str = "COUNT(*) AS calls, COALESCE(SUM(prompt_tokens), 0) AS prompt_tokens, \
+     COALESCE(SUM(completion_tokens), 0) AS completion_tokens, COALESCE(SUM(cost_usd), 0.0) AS cost_usd, \
+     COALESCE(SUM(CASE WHEN cost_usd IS NULL THEN 1 ELSE 0 END), 0) AS unpriced_calls";
+
+impl LlmUsageRepository {
+    pub async fn insert(pool: &SqlitePool, usage: &NewLlmUsage<'_>) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO llm_usage (id, meeting_id, provider, model, operation, prompt_tokens, completion_tokens, cost_usd, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("usage-{}", Uuid::new_v4()))
+        .bind(usage.meeting_id)
+        .bind(usage.provider)
+        .bind(usage.model)
+        .bind(usage.operation)
+        .bind(usage.prompt_tokens)
+        .bind(usage.completion_tokens)
+        .bind(usage.cost_usd)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Totals per provider and model, for one meeting or (None) everything
+    pub async fn totals_by_model(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+    ) -> Result<Vec<LlmUsageTotals>, sqlx::Error> {
+        sqlx::query_as::<_, LlmUsageTotals>(&format!(
+            "SELECT provider AS key, model AS label, {} FROM llm_usage
+             WHERE ?1 IS NULL OR meeting_id = ?1
+             GROUP BY provider, model ORDER BY cost_usd DESC, calls DESC",
+            TOTALS
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Totals per operation (minutes, action items, ...), for one meeting or everything
+    pub async fn totals_by_operation(
+        pool: &SqlitePool,
+        meeting_id: Option<&str>,
+    ) -> Result<Vec<LlmUsageTotals>, sqlx::Error> {
+        sqlx::query_as::<_, LlmUsageTotals>(&format!(
+            "SELECT operation AS key, NULL AS label, {} FROM llm_usage
+             WHERE ?1 IS NULL OR meeting_id = ?1
+             GROUP BY operation ORDER BY cost_usd DESC, calls DESC",
+            TOTALS
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Totals per meeting, most expensive first; requests without a meeting (digests,
+    /// deleted meetings) are grouped under a NULL key
+    pub async fn totals_by_meeting(pool: &SqlitePool) -> Result<Vec<LlmUsageTotals>, sqlx::Error> {
+        sqlx::query_as::<_, LlmUsageTotals>(&format!(
+            "SELECT u.meeting_id AS key, m.title AS label, {} FROM llm_usage u
+             LEFT JOIN meetings m ON m.id = u.meeting_id
+             GROUP BY u.meeting_id ORDER BY cost_usd DESC, calls DESC",
+            TOTALS
+        ))
+        .fetch_all(pool)
+        .await
+    }
+}
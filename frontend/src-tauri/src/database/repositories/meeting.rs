@@ -1,6 +1,6 @@
 use crate::api::{MeetingDetails, MeetingTranscript};
-use crate::database::models::{MeetingModel, Transcript};
-use chrono::Utc;
+use crate::database::models::{DateTimeUtc, MeetingModel, Transcript};
+use chrono::{DateTime, Utc};
 use sqlx::{Connection, Error as SqlxError, SqliteConnection, SqlitePool};
 use tracing::{error, info};
 
@@ -9,7 +9,7 @@ pub struct MeetingsRepository;
 impl MeetingsRepository {
     pub async fn get_meetings(pool: &SqlitePool) -> Result<Vec<MeetingModel>, sqlx::Error> {
         let meetings =
-            sqlx::query_as::<_, MeetingModel>("SELECT * FROM meetings ORDER BY created_at DESC")
+            sqlx::query_as::<_, MeetingModel>("SELECT * FROM meetings WHERE deleted_at IS NULL ORDER BY created_at DESC")
                 .fetch_all(pool)
                 .await?;
         Ok(meetings)
@@ -47,6 +47,53 @@ impl MeetingsRepository {
         }
     }
 
+    /// Move a meeting to the trash; false when it doesn't exist or is already there
+    pub async fn move_to_trash(pool: &SqlitePool, meeting_id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+            .bind(Utc::now())
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Take a meeting out of the trash; false when it isn't in the trash
+    pub async fn restore_from_trash(pool: &SqlitePool, meeting_id: &str) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// (id, title, created_at, deleted_at) of the meetings in the trash, most recently
+    /// deleted first
+    pub async fn list_trashed(
+        pool: &SqlitePool,
+    ) -> Result<Vec<(String, String, DateTimeUtc, DateTime<Utc>)>, SqlxError> {
+        sqlx::query_as::<_, (String, String, DateTimeUtc, DateTime<Utc>)>(
+            "SELECT id, title, created_at, deleted_at FROM meetings
+             WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Ids of meetings moved to the trash before `cutoff`
+    pub async fn trashed_before(pool: &SqlitePool, cutoff: DateTime<Utc>) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar("SELECT id FROM meetings WHERE deleted_at IS NOT NULL AND deleted_at < ?")
+            .bind(cutoff)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Ids of all meetings, those in the trash included
+    pub async fn all_meeting_ids(pool: &SqlitePool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar("SELECT id FROM meetings ORDER BY created_at DESC")
+            .fetch_all(pool)
+            .await
+    }
+
     pub async fn get_meeting(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -62,7 +109,7 @@ impl MeetingsRepository {
 
         // Get meeting details
         let meeting: Option<MeetingModel> =
-            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path FROM meetings WHERE id = ?")
+            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path, description, summary_language, agenda, starred, library_folder_id, scheduled_start, duration_seconds, platform, calendar_event_id FROM meetings WHERE id = ?")
                 .bind(meeting_id)
                 .fetch_optional(&mut *transaction)
                 .await?;
@@ -92,12 +139,24 @@ impl MeetingsRepository {
                     audio_start_time: t.audio_start_time,
                     audio_end_time: t.audio_end_time,
                     duration: t.duration,
+                    quality_score: t.quality_score,
+                    speaker: t.speaker,
+                    speaker_label: t.speaker_label,
+                    overlapping_speech: t.overlapping_speech,
+                    sentiment: t.sentiment,
+                    tension: t.tension,
+                    language: t.language,
+                    confidence: t.confidence,
+                    needs_review: t.needs_review,
                 })
                 .collect::<Vec<_>>();
 
             Ok(Some(MeetingDetails {
                 id: meeting.id,
                 title: meeting.title,
+                description: meeting.description,
+                summary_language: meeting.summary_language,
+                agenda: meeting.agenda,
                 created_at: meeting.created_at.0.to_rfc3339(),
                 updated_at: meeting.updated_at.0.to_rfc3339(),
                 transcripts: meeting_transcripts,
@@ -120,7 +179,7 @@ impl MeetingsRepository {
         }
 
         let meeting: Option<MeetingModel> =
-            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path FROM meetings WHERE id = ?")
+            sqlx::query_as("SELECT id, title, created_at, updated_at, folder_path, description, summary_language, agenda FROM meetings WHERE id = ?")
                 .bind(meeting_id)
                 .fetch_optional(pool)
                 .await?;
@@ -228,6 +287,197 @@ impl MeetingsRepository {
         transaction.commit().await?;
         Ok(true)
     }
+
+    /// Store a generated description, plus the generated title if the meeting is still
+    /// called `current_title` so a rename made in the meantime is kept. Returns whether
+    /// the title was replaced.
+    pub async fn apply_generated_metadata(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        current_title: &str,
+        title: Option<&str>,
+        description: &str,
+    ) -> Result<bool, SqlxError> {
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("UPDATE meetings SET description = ? WHERE id = ?")
+            .bind(description)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let mut renamed = false;
+        if let Some(title) = title {
+            renamed = sqlx::query("UPDATE meetings SET title = ?, updated_at = ? WHERE id = ? AND title = ?")
+                .bind(title)
+                .bind(Utc::now())
+                .bind(meeting_id)
+                .bind(current_title)
+                .execute(&mut *transaction)
+                .await?
+                .rows_affected()
+                > 0;
+            if renamed {
+                sqlx::query("UPDATE transcript_chunks SET meeting_name = ? WHERE meeting_id = ?")
+                    .bind(title)
+                    .bind(meeting_id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+
+        transaction.commit().await?;
+        Ok(renamed)
+    }
+
+    /// Set the language the meeting's minutes are written in (None for the meeting's own)
+    pub async fn set_summary_language(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        language: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET summary_language = ? WHERE id = ?")
+            .bind(language)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Id of the meeting template applied to the meeting, if any
+    pub async fn get_template_id(pool: &SqlitePool, meeting_id: &str) -> Result<Option<String>, SqlxError> {
+        let template_id: Option<Option<String>> =
+            sqlx::query_scalar("SELECT meeting_template_id FROM meetings WHERE id = ?")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+        Ok(template_id.flatten())
+    }
+
+    /// Record the meeting template applied to the meeting
+    pub async fn set_template_id(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        template_id: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET meeting_template_id = ? WHERE id = ?")
+            .bind(template_id)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Attach an agenda to the meeting, or remove it with None
+    pub async fn set_agenda(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        agenda: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET agenda = ? WHERE id = ?")
+            .bind(agenda)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Star or unstar a meeting
+    pub async fn set_starred(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        starred: bool,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET starred = ? WHERE id = ?")
+            .bind(starred)
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Store a meeting's scheduled start, duration, platform and calendar event; None clears
+    /// a field
+    pub async fn set_details(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        scheduled_start: Option<DateTime<Utc>>,
+        duration_seconds: Option<f64>,
+        platform: Option<&str>,
+        calendar_event_id: Option<&str>,
+    ) -> Result<bool, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE meetings SET scheduled_start = ?, duration_seconds = ?, platform = ?, calendar_event_id = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(scheduled_start)
+        .bind(duration_seconds)
+        .bind(platform)
+        .bind(calendar_event_id)
+        .bind(Utc::now().naive_utc())
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a meeting's transcript and the verbatim text derived from it, keeping the
+    /// meeting, its minutes and extracted items
+    pub async fn delete_transcripts(pool: &SqlitePool, meeting_id: &str) -> Result<u64, SqlxError> {
+        let mut conn = pool.acquire().await?;
+        let mut transaction = conn.begin().await?;
+
+        let deleted = sqlx::query("DELETE FROM transcripts WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        sqlx::query("DELETE FROM transcript_chunks WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM meeting_highlights WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM transcript_revisions WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+        Ok(deleted)
+    }
+
+    /// Meetings that still have transcript segments
+    pub async fn meetings_with_transcripts(pool: &SqlitePool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar("SELECT DISTINCT meeting_id FROM transcripts")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Recording folders in use, each once
+    pub async fn list_recording_folders(pool: &SqlitePool) -> Result<Vec<String>, SqlxError> {
+        sqlx::query_scalar(
+            "SELECT DISTINCT folder_path FROM meetings WHERE folder_path IS NOT NULL AND folder_path != ''",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Point the meetings recorded in `old_folder` at `new_folder`; takes a connection so it
+    /// also works on a database that isn't the app's (e.g. one being restored)
+    pub async fn move_recording_folder(
+        conn: &mut SqliteConnection,
+        old_folder: &str,
+        new_folder: &str,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query("UPDATE meetings SET folder_path = ? WHERE folder_path = ?")
+            .bind(new_folder)
+            .bind(old_folder)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
 }
 
 async fn delete_meeting_with_transaction(
@@ -264,7 +514,103 @@ async fn delete_meeting_with_transaction(
         .execute(&mut *transaction)
         .await?;
 
-    // 4. Finally, delete the meeting
+    // 4. Delete stored speaker fingerprints
+    sqlx::query("DELETE FROM meeting_speakers WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 5. Delete extracted action items
+    sqlx::query("DELETE FROM action_items WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 6. Delete extracted decisions and open questions
+    sqlx::query("DELETE FROM meeting_outcomes WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 7. Delete topic chapters
+    sqlx::query("DELETE FROM meeting_chapters WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 8. Delete tags
+    sqlx::query("DELETE FROM meeting_tags WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 9. Delete summary versions
+    sqlx::query("DELETE FROM summary_versions WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 10. Delete standup updates
+    sqlx::query("DELETE FROM standup_items WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 11. Delete highlight quotes
+    sqlx::query("DELETE FROM meeting_highlights WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 12. Delete redaction mappings
+    sqlx::query("DELETE FROM redaction_mappings WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 13. Delete summary feedback
+    sqlx::query("DELETE FROM summary_feedback WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 14. Delete transcript edit history
+    sqlx::query("DELETE FROM transcript_revisions WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 15. Delete participants
+    sqlx::query("DELETE FROM meeting_participants WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 16. Delete attachment records (the files are removed by the caller)
+    sqlx::query("DELETE FROM meeting_attachments WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 17. Forget which action items were sent to a task manager
+    sqlx::query("DELETE FROM synced_tasks WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 18. Forget the CRM note the meeting became
+    sqlx::query("DELETE FROM crm_notes WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 19. Keep LLM usage for overall spend, detached from the meeting
+    sqlx::query("UPDATE llm_usage SET meeting_id = NULL WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+    // 20. Finally, delete the meeting
     let result = sqlx::query("DELETE FROM meetings WHERE id = ?")
         .bind(meeting_id)
         .execute(&mut *transaction)
@@ -0,0 +1,56 @@
+use crate::database::models::MeetingAttachment;
+use sqlx::SqlitePool;
+
+pub struct MeetingAttachmentsRepository;
+
+impl MeetingAttachmentsRepository {
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<MeetingAttachment>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingAttachment>(
+            "SELECT * FROM meeting_attachments WHERE meeting_id = ? ORDER BY created_at",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<MeetingAttachment>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingAttachment>("SELECT * FROM meeting_attachments WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn insert(pool: &SqlitePool, attachment: &MeetingAttachment) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO meeting_attachments (id, meeting_id, file_name, stored_name, mime_type, size_bytes, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&attachment.id)
+        .bind(&attachment.meeting_id)
+        .bind(&attachment.file_name)
+        .bind(&attachment.stored_name)
+        .bind(&attachment.mime_type)
+        .bind(attachment.size_bytes)
+        .bind(attachment.created_at)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn rename(pool: &SqlitePool, id: &str, file_name: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE meeting_attachments SET file_name = ? WHERE id = ?")
+            .bind(file_name)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM meeting_attachments WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::database::models::MeetingChapter;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Chapter fields produced by topic segmentation
+pub struct NewMeetingChapter {
+    pub title: String,
+    pub start_segment_id: Option<String>,
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+}
+
+pub struct MeetingChaptersRepository;
+
+impl MeetingChaptersRepository {
+    /// Replace a meeting's chapters (given in meeting order), in one transaction
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        chapters: &[NewMeetingChapter],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM meeting_chapters WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        let now = Utc::now();
+        for (position, chapter) in chapters.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO meeting_chapters (id, meeting_id, position, title, start_segment_id, start_time, end_time, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("chapter-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(position as i64)
+            .bind(&chapter.title)
+            .bind(&chapter.start_segment_id)
+            .bind(chapter.start_time)
+            .bind(chapter.end_time)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingChapter>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingChapter>(
+            "SELECT * FROM meeting_chapters WHERE meeting_id = ? ORDER BY position",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,68 @@
+use crate::database::models::MeetingDigest;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Digest fields produced by the generator
+pub struct NewMeetingDigest {
+    pub title: String,
+    pub range_start: DateTime<Utc>,
+    pub range_end: DateTime<Utc>,
+    pub tag: Option<String>,
+    pub meeting_ids: Vec<String>,
+    pub markdown: String,
+    pub scheduled: bool,
+}
+
+pub struct MeetingDigestsRepository;
+
+impl MeetingDigestsRepository {
+    pub async fn insert(pool: &SqlitePool, digest: &NewMeetingDigest) -> Result<String, sqlx::Error> {
+        let id = format!("digest-{}", Uuid::new_v4());
+        sqlx::query(
+            "INSERT INTO meeting_digests (id, title, range_start, range_end, tag, meeting_ids, markdown, scheduled, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(&digest.title)
+        .bind(digest.range_start)
+        .bind(digest.range_end)
+        .bind(&digest.tag)
+        .bind(serde_json::to_string(&digest.meeting_ids).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&digest.markdown)
+        .bind(digest.scheduled)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<MeetingDigest>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingDigest>("SELECT * FROM meeting_digests WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// All digests, newest first
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<MeetingDigest>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingDigest>("SELECT * FROM meeting_digests ORDER BY created_at DESC")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// When the schedule last produced a digest
+    pub async fn last_scheduled_at(pool: &SqlitePool) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        sqlx::query_scalar("SELECT created_at FROM meeting_digests WHERE scheduled = 1 ORDER BY created_at DESC LIMIT 1")
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM meeting_digests WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::database::models::MeetingFolder;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct MeetingFoldersRepository;
+
+impl MeetingFoldersRepository {
+    /// All folders, by name; the caller builds the tree from parent_id
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<MeetingFolder>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingFolder>("SELECT * FROM meeting_folders ORDER BY name COLLATE NOCASE")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<MeetingFolder>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingFolder>("SELECT * FROM meeting_folders WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn insert(pool: &SqlitePool, name: &str, parent_id: Option<&str>) -> Result<MeetingFolder, sqlx::Error> {
+        let folder = MeetingFolder {
+            id: format!("folder-{}", Uuid::new_v4()),
+            name: name.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            created_at: Utc::now(),
+        };
+        sqlx::query("INSERT INTO meeting_folders (id, name, parent_id, created_at) VALUES (?, ?, ?, ?)")
+            .bind(&folder.id)
+            .bind(&folder.name)
+            .bind(&folder.parent_id)
+            .bind(folder.created_at)
+            .execute(pool)
+            .await?;
+        Ok(folder)
+    }
+
+    pub async fn rename(pool: &SqlitePool, id: &str, name: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE meeting_folders SET name = ? WHERE id = ?")
+            .bind(name)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Move a folder under `parent_id` (None for the top level); the caller rules out cycles
+    pub async fn set_parent(pool: &SqlitePool, id: &str, parent_id: Option<&str>) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE meeting_folders SET parent_id = ? WHERE id = ?")
+            .bind(parent_id)
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a folder; its meetings and subfolders move up to its parent
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let parent_id: Option<Option<String>> = sqlx::query_scalar("SELECT parent_id FROM meeting_folders WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *transaction)
+            .await?;
+        let Some(parent_id) = parent_id else {
+            return Ok(false);
+        };
+
+        sqlx::query("UPDATE meetings SET library_folder_id = ? WHERE library_folder_id = ?")
+            .bind(&parent_id)
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("UPDATE meeting_folders SET parent_id = ? WHERE parent_id = ?")
+            .bind(&parent_id)
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        sqlx::query("DELETE FROM meeting_folders WHERE id = ?")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(true)
+    }
+
+    /// File meetings in a folder (None to unfile them); returns how many were moved
+    pub async fn move_meetings(
+        pool: &SqlitePool,
+        meeting_ids: &[String],
+        folder_id: Option<&str>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let mut moved = 0;
+        for meeting_id in meeting_ids {
+            moved += sqlx::query("UPDATE meetings SET library_folder_id = ? WHERE id = ?")
+                .bind(folder_id)
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?
+                .rows_affected();
+        }
+        transaction.commit().await?;
+        Ok(moved)
+    }
+}
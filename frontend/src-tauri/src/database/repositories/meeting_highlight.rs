@@ -0,0 +1,65 @@
+use crate::database::models::MeetingHighlight;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Highlight fields produced by quote selection
+pub struct NewMeetingHighlight {
+    pub quote: String,
+    pub speaker: Option<String>,
+    pub segment_id: Option<String>,
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+    pub reason: Option<String>,
+}
+
+pub struct MeetingHighlightsRepository;
+
+impl MeetingHighlightsRepository {
+    /// Replace a meeting's highlights (given in meeting order), in one transaction
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        highlights: &[NewMeetingHighlight],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM meeting_highlights WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        let now = Utc::now();
+        for (position, highlight) in highlights.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO meeting_highlights (id, meeting_id, position, quote, speaker, segment_id, start_time, end_time, reason, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("highlight-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(position as i64)
+            .bind(&highlight.quote)
+            .bind(&highlight.speaker)
+            .bind(&highlight.segment_id)
+            .bind(highlight.start_time)
+            .bind(highlight.end_time)
+            .bind(&highlight.reason)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<MeetingHighlight>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingHighlight>("SELECT * FROM meeting_highlights WHERE meeting_id = ? ORDER BY position")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<MeetingHighlight>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingHighlight>("SELECT * FROM meeting_highlights WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+}
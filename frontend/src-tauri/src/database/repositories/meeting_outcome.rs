@@ -0,0 +1,49 @@
+use crate::database::models::MeetingOutcome;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct MeetingOutcomesRepository;
+
+impl MeetingOutcomesRepository {
+    /// Replace a meeting's decisions and open questions, given as (kind, text, segment ids)
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        outcomes: &[(String, String, Vec<String>)],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM meeting_outcomes WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        let now = Utc::now();
+        for (kind, text, segment_ids) in outcomes {
+            sqlx::query(
+                "INSERT INTO meeting_outcomes (id, meeting_id, kind, text, segment_ids, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("outcome-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(kind)
+            .bind(text)
+            .bind(serde_json::to_string(segment_ids).unwrap_or_else(|_| "[]".to_string()))
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingOutcome>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingOutcome>(
+            "SELECT * FROM meeting_outcomes WHERE meeting_id = ? ORDER BY kind, created_at, rowid",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,126 @@
+use crate::database::models::MeetingParticipant;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct MeetingParticipantsRepository;
+
+impl MeetingParticipantsRepository {
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<MeetingParticipant>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingParticipant>(
+            "SELECT * FROM meeting_participants WHERE meeting_id = ? ORDER BY name COLLATE NOCASE",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replace a meeting's participants with (name, email) pairs, in one transaction. Names
+    /// that were already listed keep their source; new ones are recorded as `source`.
+    pub async fn set_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        participants: &[(String, Option<String>)],
+        source: &str,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let existing: Vec<(String, String)> =
+            sqlx::query_as("SELECT name, source FROM meeting_participants WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_all(&mut *transaction)
+                .await?;
+        sqlx::query("DELETE FROM meeting_participants WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for (name, email) in participants {
+            let source = existing
+                .iter()
+                .find(|(existing, _)| existing.eq_ignore_ascii_case(name))
+                .map_or(source, |(_, source)| source.as_str());
+            sqlx::query(
+                "INSERT OR IGNORE INTO meeting_participants (id, meeting_id, name, email, source, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("participant-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(name)
+            .bind(email)
+            .bind(source)
+            .bind(Utc::now())
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Add participants that aren't listed yet, leaving existing entries untouched; returns
+    /// how many were added
+    pub async fn add_missing(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        names: &[String],
+        source: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let mut added = 0;
+        for name in names {
+            added += sqlx::query(
+                "INSERT OR IGNORE INTO meeting_participants (id, meeting_id, name, source, created_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(format!("participant-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(name)
+            .bind(source)
+            .bind(Utc::now())
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        }
+        transaction.commit().await?;
+        Ok(added)
+    }
+
+    /// Add (name, email) pairs whose name isn't listed yet; returns how many were added
+    pub async fn add_missing_contacts(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        participants: &[(String, Option<String>)],
+        source: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let mut added = 0;
+        for (name, email) in participants {
+            added += sqlx::query(
+                "INSERT OR IGNORE INTO meeting_participants (id, meeting_id, name, email, source, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("participant-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(name)
+            .bind(email)
+            .bind(source)
+            .bind(Utc::now())
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        }
+        transaction.commit().await?;
+        Ok(added)
+    }
+
+    /// Rename a participant added from a speaker label after the speaker is renamed
+    pub async fn rename_speaker(pool: &SqlitePool, meeting_id: &str, from: &str, to: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE OR IGNORE meeting_participants SET name = ?
+             WHERE meeting_id = ? AND source = 'speaker' AND name = ? COLLATE NOCASE",
+        )
+        .bind(to)
+        .bind(meeting_id)
+        .bind(from)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
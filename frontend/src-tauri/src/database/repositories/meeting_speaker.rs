@@ -0,0 +1,105 @@
+use crate::database::models::{DateTimeUtc, MeetingSpeakerFingerprint};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct MeetingSpeakersRepository;
+
+impl MeetingSpeakersRepository {
+    pub async fn insert(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        label: &str,
+        embedding: &[u8],
+        segment_count: i64,
+        voice_group_id: &str,
+    ) -> Result<String, sqlx::Error> {
+        let id = format!("speaker-{}", Uuid::new_v4());
+        sqlx::query(
+            "INSERT INTO meeting_speakers (id, meeting_id, label, embedding, segment_count, voice_group_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(meeting_id)
+        .bind(label)
+        .bind(embedding)
+        .bind(segment_count)
+        .bind(voice_group_id)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub async fn list_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingSpeakerFingerprint>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingSpeakerFingerprint>(
+            "SELECT * FROM meeting_speakers WHERE meeting_id = ? ORDER BY label",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Fingerprints from every other meeting (candidates for cross-meeting matching)
+    pub async fn list_other_meetings(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingSpeakerFingerprint>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingSpeakerFingerprint>(
+            "SELECT * FROM meeting_speakers WHERE meeting_id != ? ORDER BY created_at DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn list_group(
+        pool: &SqlitePool,
+        voice_group_id: &str,
+    ) -> Result<Vec<MeetingSpeakerFingerprint>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingSpeakerFingerprint>(
+            "SELECT * FROM meeting_speakers WHERE voice_group_id = ? ORDER BY created_at",
+        )
+        .bind(voice_group_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Keep fingerprints in step with speaker renames/merges in the transcript
+    pub async fn rename_label(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("UPDATE meeting_speakers SET label = ? WHERE meeting_id = ? AND label = ?")
+            .bind(to)
+            .bind(meeting_id)
+            .bind(from)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Same-voice speakers in other meetings:
+    /// (label here, other meeting id, other meeting title, other meeting date, label there)
+    pub async fn list_linked_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, String, String, DateTimeUtc, String)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, String, String, DateTimeUtc, String)>(
+            "SELECT s.label, o.meeting_id, m.title, m.created_at, o.label
+             FROM meeting_speakers s
+             JOIN meeting_speakers o ON o.voice_group_id = s.voice_group_id AND o.meeting_id != s.meeting_id
+             JOIN meetings m ON m.id = o.meeting_id
+             WHERE s.meeting_id = ? AND m.deleted_at IS NULL
+             ORDER BY s.label, m.created_at DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
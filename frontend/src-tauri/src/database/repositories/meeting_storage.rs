@@ -0,0 +1,37 @@
+use sqlx::SqlitePool;
+
+pub struct MeetingStorageRepository;
+
+/// Tables holding the bulk of a meeting's data, with the expression whose size is counted
+const MEETING_DATA: &[(&str, &str)] = &[
+    ("transcripts", "transcript"),
+    ("transcript_chunks", "transcript_text"),
+    ("transcript_revisions", "before_segments || after_segments"),
+    ("summary_processes", "result"),
+    ("summary_versions", "markdown"),
+    ("meeting_notes", "COALESCE(notes_markdown, '') || COALESCE(notes_json, '')"),
+    ("meeting_speakers", "embedding"),
+];
+
+impl MeetingStorageRepository {
+    /// (id, title, folder_path, bytes) of every meeting, where bytes is the size of its
+    /// transcript, summaries, notes and speaker data in the database; indexes and row
+    /// overhead aren't counted
+    pub async fn database_bytes(pool: &SqlitePool) -> Result<Vec<(String, String, Option<String>, i64)>, sqlx::Error> {
+        let sizes: Vec<String> = MEETING_DATA
+            .iter()
+            .map(|(table, expression)| {
+                format!(
+                    "COALESCE((SELECT SUM(LENGTH(CAST({} AS BLOB))) FROM {} WHERE meeting_id = m.id), 0)",
+                    expression, table
+                )
+            })
+            .collect();
+        sqlx::query_as::<_, (String, String, Option<String>, i64)>(&format!(
+            "SELECT m.id, m.title, m.folder_path, {} AS bytes FROM meetings m ORDER BY m.created_at DESC",
+            sizes.join(" + ")
+        ))
+        .fetch_all(pool)
+        .await
+    }
+}
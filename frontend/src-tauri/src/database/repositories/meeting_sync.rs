@@ -0,0 +1,181 @@
+use crate::database::repositories::transcript_revision::insert_segment;
+use crate::sync::record::{SyncedHashes, SyncedMeeting};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+pub struct MeetingSyncRepository;
+
+impl MeetingSyncRepository {
+    /// Hashes saved at the last sync, by meeting id; includes meetings deleted since
+    pub async fn list_hashes(pool: &SqlitePool) -> Result<HashMap<String, SyncedHashes>, sqlx::Error> {
+        let rows: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT meeting_id, local_hash, remote_hash FROM sync_state")
+                .fetch_all(pool)
+                .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(meeting_id, local_hash, remote_hash)| {
+                (meeting_id, SyncedHashes { local_hash, remote_hash })
+            })
+            .collect())
+    }
+
+    pub async fn save_hashes(pool: &SqlitePool, meeting_id: &str, hashes: &SyncedHashes) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO sync_state (meeting_id, local_hash, remote_hash, synced_at) VALUES (?, ?, ?, ?)
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                local_hash = excluded.local_hash,
+                remote_hash = excluded.remote_hash,
+                synced_at = excluded.synced_at",
+        )
+        .bind(meeting_id)
+        .bind(&hashes.local_hash)
+        .bind(&hashes.remote_hash)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn forget(pool: &SqlitePool, meeting_id: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM sync_state WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Forget every meeting's sync state, e.g. after switching to other storage
+    pub async fn forget_all(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM sync_state").execute(pool).await?;
+        Ok(())
+    }
+
+    /// Create or replace a meeting from a synced record, in one transaction. The local
+    /// recording folder and library folder are kept.
+    pub async fn apply_record(pool: &SqlitePool, record: &SyncedMeeting) -> Result<(), sqlx::Error> {
+        let meeting = &record.meeting;
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO meetings (id, title, created_at, updated_at, description, summary_language, agenda, starred, scheduled_start, duration_seconds, platform, calendar_event_id)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at,
+                description = excluded.description,
+                summary_language = excluded.summary_language,
+                agenda = excluded.agenda,
+                starred = excluded.starred,
+                scheduled_start = excluded.scheduled_start,
+                duration_seconds = excluded.duration_seconds,
+                platform = excluded.platform,
+                calendar_event_id = excluded.calendar_event_id",
+        )
+        .bind(&meeting.id)
+        .bind(&meeting.title)
+        .bind(&meeting.created_at)
+        .bind(&meeting.updated_at)
+        .bind(&meeting.description)
+        .bind(&meeting.summary_language)
+        .bind(&meeting.agenda)
+        .bind(meeting.starred)
+        .bind(meeting.scheduled_start)
+        .bind(meeting.duration_seconds)
+        .bind(&meeting.platform)
+        .bind(&meeting.calendar_event_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        // Chunks and highlights were derived from the old transcript
+        for table in [
+            "transcripts",
+            "transcript_chunks",
+            "meeting_highlights",
+            "action_items",
+            "meeting_tags",
+            "meeting_participants",
+        ] {
+            sqlx::query(&format!("DELETE FROM {} WHERE meeting_id = ?", table))
+                .bind(&meeting.id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        for segment in &record.transcripts {
+            insert_segment(&mut *transaction, segment).await?;
+        }
+
+        match &record.summary {
+            Some(result) => {
+                let now = Utc::now();
+                sqlx::query(
+                    "INSERT INTO summary_processes (meeting_id, status, created_at, updated_at, result, end_time)
+                     VALUES (?, 'completed', ?, ?, ?, ?)
+                     ON CONFLICT(meeting_id) DO UPDATE SET
+                        status = 'completed',
+                        updated_at = excluded.updated_at,
+                        result = excluded.result,
+                        end_time = excluded.end_time,
+                        error = NULL",
+                )
+                .bind(&meeting.id)
+                .bind(now)
+                .bind(now)
+                .bind(result)
+                .bind(now)
+                .execute(&mut *transaction)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM summary_processes WHERE meeting_id = ? AND status = 'completed'")
+                    .bind(&meeting.id)
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+
+        for item in &record.action_items {
+            sqlx::query(
+                "INSERT INTO action_items (id, meeting_id, description, assignee, due_date, segment_id, done, issue_key, issue_url, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&item.id)
+            .bind(&meeting.id)
+            .bind(&item.description)
+            .bind(&item.assignee)
+            .bind(&item.due_date)
+            .bind(&item.segment_id)
+            .bind(item.done)
+            .bind(&item.issue_key)
+            .bind(&item.issue_url)
+            .bind(item.created_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        for tag in &record.tags {
+            sqlx::query("INSERT OR IGNORE INTO meeting_tags (meeting_id, tag) VALUES (?, ?)")
+                .bind(&meeting.id)
+                .bind(tag)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        for participant in &record.participants {
+            sqlx::query(
+                "INSERT OR IGNORE INTO meeting_participants (id, meeting_id, name, email, source, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&participant.id)
+            .bind(&meeting.id)
+            .bind(&participant.name)
+            .bind(&participant.email)
+            .bind(&participant.source)
+            .bind(participant.created_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await
+    }
+}
@@ -0,0 +1,109 @@
+use sqlx::SqlitePool;
+
+pub struct MeetingTagsRepository;
+
+/// Tags are compared case-insensitively, so they are stored trimmed and lower-case
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+impl MeetingTagsRepository {
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT tag FROM meeting_tags WHERE meeting_id = ? ORDER BY tag")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Replace a meeting's tags, in one transaction
+    pub async fn set_for_meeting(pool: &SqlitePool, meeting_id: &str, tags: &[String]) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM meeting_tags WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        for tag in tags.iter().map(|tag| normalize_tag(tag)).filter(|tag| !tag.is_empty()) {
+            sqlx::query("INSERT OR IGNORE INTO meeting_tags (meeting_id, tag) VALUES (?, ?)")
+                .bind(meeting_id)
+                .bind(tag)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn meeting_ids_with_tag(pool: &SqlitePool, tag: &str) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT meeting_id FROM meeting_tags WHERE tag = ?")
+            .bind(normalize_tag(tag))
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Every (meeting_id, tag) pair
+    pub async fn list_all(pool: &SqlitePool) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as("SELECT meeting_id, tag FROM meeting_tags ORDER BY tag")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Tags in use with how many meetings have each, most used first
+    pub async fn counts(pool: &SqlitePool) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        sqlx::query_as("SELECT tag, COUNT(*) AS meetings FROM meeting_tags GROUP BY tag ORDER BY meetings DESC, tag")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Add tags to several meetings, keeping the tags they already have
+    pub async fn add_to_meetings(pool: &SqlitePool, meeting_ids: &[String], tags: &[String]) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        for meeting_id in meeting_ids {
+            for tag in tags.iter().map(|tag| normalize_tag(tag)).filter(|tag| !tag.is_empty()) {
+                sqlx::query(
+                    "INSERT OR IGNORE INTO meeting_tags (meeting_id, tag)
+                     SELECT id, ? FROM meetings WHERE id = ?",
+                )
+                .bind(tag)
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+            }
+        }
+        transaction.commit().await
+    }
+
+    pub async fn remove_from_meetings(pool: &SqlitePool, meeting_ids: &[String], tags: &[String]) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        for meeting_id in meeting_ids {
+            for tag in tags {
+                sqlx::query("DELETE FROM meeting_tags WHERE meeting_id = ? AND tag = ?")
+                    .bind(meeting_id)
+                    .bind(normalize_tag(tag))
+                    .execute(&mut *transaction)
+                    .await?;
+            }
+        }
+        transaction.commit().await
+    }
+
+    /// Rename a tag on every meeting, merging it into `new_tag` where both are set
+    pub async fn rename(pool: &SqlitePool, old_tag: &str, new_tag: &str) -> Result<u64, sqlx::Error> {
+        let (old_tag, new_tag) = (normalize_tag(old_tag), normalize_tag(new_tag));
+        if old_tag == new_tag {
+            return Ok(0);
+        }
+        let mut transaction = pool.begin().await?;
+        let renamed = sqlx::query("UPDATE OR IGNORE meeting_tags SET tag = ? WHERE tag = ?")
+            .bind(&new_tag)
+            .bind(&old_tag)
+            .execute(&mut *transaction)
+            .await?
+            .rows_affected();
+        // Left over where the meeting already had the new tag
+        sqlx::query("DELETE FROM meeting_tags WHERE tag = ?")
+            .bind(&old_tag)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await?;
+        Ok(renamed)
+    }
+}
@@ -1,5 +1,32 @@
+pub mod action_item;
+pub mod audio_blob;
+pub mod crm_note;
+pub mod glossary;
+pub mod llm_usage;
 pub mod meeting;
+pub mod meeting_attachment;
+pub mod meeting_chapter;
+pub mod meeting_digest;
+pub mod meeting_folder;
+pub mod meeting_highlight;
+pub mod meeting_outcome;
+pub mod meeting_participant;
+pub mod meeting_speaker;
+pub mod meeting_storage;
+pub mod meeting_sync;
+pub mod meeting_tag;
+pub mod protected_meeting;
+pub mod redaction_mapping;
+pub mod segment_bookmark;
+pub mod segment_comment;
 pub mod setting;
+pub mod standup_item;
 pub mod summary;
+pub mod summary_feedback;
+pub mod summary_version;
+pub mod synced_task;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_revision;
+pub mod voice_profile;
+pub mod watch_folder_file;
@@ -0,0 +1,246 @@
+use crate::database::models::{ProtectedContent, ProtectedMeeting};
+use chrono::Utc;
+use sqlx::{SqliteConnection, SqlitePool};
+
+pub struct ProtectedMeetingsRepository;
+
+/// Columns holding a meeting's details, as (table, row key, column, value while locked);
+/// revisions hold JSON segment lists, so they are emptied to a valid one
+const DETAIL_COLUMNS: [(&str, &str, &str, &str); 14] = [
+    ("action_items", "id", "description", "''"),
+    ("meeting_outcomes", "id", "text", "''"),
+    ("meeting_highlights", "id", "quote", "''"),
+    ("meeting_highlights", "id", "reason", "NULL"),
+    ("standup_items", "id", "text", "''"),
+    ("segment_comments", "id", "text", "''"),
+    ("transcript_revisions", "id", "before_segments", "'[]'"),
+    ("transcript_revisions", "id", "after_segments", "'[]'"),
+    ("redaction_mappings", "placeholder", "original", "''"),
+    ("meeting_chapters", "id", "title", "''"),
+    ("segment_bookmarks", "transcript_id", "note", "NULL"),
+    ("meeting_notes", "meeting_id", "notes_markdown", "NULL"),
+    ("meeting_notes", "meeting_id", "notes_json", "NULL"),
+    ("summary_feedback", "id", "comment", "NULL"),
+];
+
+/// Clear the plaintext of the meeting's details
+async fn blank_details(transaction: &mut SqliteConnection, meeting_id: &str) -> Result<(), sqlx::Error> {
+    for (table, _, column, blank) in DETAIL_COLUMNS {
+        sqlx::query(&format!("UPDATE {} SET {} = {} WHERE meeting_id = ?", table, column, blank))
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Clear the plaintext that `ProtectedContent` holds, except the details; segments keep
+/// their ids and timing so bookmarks and comments on them survive
+async fn blank_content(transaction: &mut SqliteConnection, meeting_id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE transcripts SET transcript = '' WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::query("UPDATE summary_processes SET result = NULL WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::query("UPDATE transcript_chunks SET transcript_text = '' WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    sqlx::query("UPDATE summary_versions SET markdown = '' WHERE meeting_id = ?")
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    Ok(())
+}
+
+/// Write decrypted plaintext back; rows deleted since it was encrypted are skipped
+async fn write_content(
+    transaction: &mut SqliteConnection,
+    meeting_id: &str,
+    content: &ProtectedContent,
+) -> Result<(), sqlx::Error> {
+    for (id, text) in &content.segments {
+        sqlx::query("UPDATE transcripts SET transcript = ? WHERE id = ? AND meeting_id = ?")
+            .bind(text)
+            .bind(id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    sqlx::query("UPDATE summary_processes SET result = ? WHERE meeting_id = ?")
+        .bind(&content.summary_result)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+    if let Some(text) = &content.summary_source_text {
+        sqlx::query("UPDATE transcript_chunks SET transcript_text = ? WHERE meeting_id = ?")
+            .bind(text)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    for (id, markdown) in &content.summary_versions {
+        sqlx::query("UPDATE summary_versions SET markdown = ? WHERE id = ? AND meeting_id = ?")
+            .bind(markdown)
+            .bind(id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    for (key, id, text) in &content.details {
+        // Only columns this version blanks are written back
+        let Some((table, row_key, column, _)) = DETAIL_COLUMNS
+            .iter()
+            .find(|(table, _, column, _)| key.split_once('.') == Some((*table, *column)))
+        else {
+            continue;
+        };
+        sqlx::query(&format!("UPDATE {} SET {} = ? WHERE {} = ? AND meeting_id = ?", table, column, row_key))
+            .bind(text)
+            .bind(id)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+    }
+    Ok(())
+}
+
+impl ProtectedMeetingsRepository {
+    pub async fn get(pool: &SqlitePool, meeting_id: &str) -> Result<Option<ProtectedMeeting>, sqlx::Error> {
+        sqlx::query_as::<_, ProtectedMeeting>("SELECT * FROM protected_meetings WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Whether the meeting is protected and currently locked
+    pub async fn is_locked(pool: &SqlitePool, meeting_id: &str) -> Result<bool, sqlx::Error> {
+        let unlocked: Option<bool> = sqlx::query_scalar("SELECT unlocked FROM protected_meetings WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(unlocked == Some(false))
+    }
+
+    /// Ids of protected meetings whose plaintext is currently written back
+    pub async fn list_unlocked(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT meeting_id FROM protected_meetings WHERE unlocked = 1")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// The meeting's current plaintext
+    pub async fn load_content(pool: &SqlitePool, meeting_id: &str) -> Result<ProtectedContent, sqlx::Error> {
+        let segments = sqlx::query_as::<_, (String, String)>("SELECT id, transcript FROM transcripts WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await?;
+        let summary_result: Option<Option<String>> =
+            sqlx::query_scalar("SELECT result FROM summary_processes WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+        let summary_source_text: Option<String> =
+            sqlx::query_scalar("SELECT transcript_text FROM transcript_chunks WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+        let summary_versions =
+            sqlx::query_as::<_, (String, String)>("SELECT id, markdown FROM summary_versions WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_all(pool)
+                .await?;
+        let mut details = Vec::new();
+        for (table, row_key, column, _) in DETAIL_COLUMNS {
+            let rows = sqlx::query_as::<_, (String, Option<String>)>(&format!(
+                "SELECT {}, {} FROM {} WHERE meeting_id = ?",
+                row_key, column, table
+            ))
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await?;
+            let key = format!("{}.{}", table, column);
+            details.extend(rows.into_iter().map(|(id, text)| (key.clone(), id, text)));
+        }
+        Ok(ProtectedContent {
+            segments,
+            summary_result: summary_result.flatten(),
+            summary_source_text,
+            summary_versions,
+            details,
+        })
+    }
+
+    /// Store the encrypted content and blank the plaintext, leaving the meeting locked
+    pub async fn seal(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        salt: &[u8],
+        nonce: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO protected_meetings (meeting_id, salt, nonce, ciphertext, unlocked, updated_at, covers_details)
+             VALUES (?, ?, ?, ?, 0, ?, 1)
+             ON CONFLICT(meeting_id) DO UPDATE SET
+                salt = excluded.salt, nonce = excluded.nonce, ciphertext = excluded.ciphertext,
+                unlocked = 0, updated_at = excluded.updated_at, covers_details = 1",
+        )
+        .bind(meeting_id)
+        .bind(salt)
+        .bind(nonce)
+        .bind(ciphertext)
+        .bind(Utc::now())
+        .execute(&mut *transaction)
+        .await?;
+        blank_content(&mut transaction, meeting_id).await?;
+        blank_details(&mut transaction, meeting_id).await?;
+        transaction.commit().await
+    }
+
+    /// Blank the plaintext of an unlocked meeting without re-encrypting it, going back to
+    /// the stored ciphertext; details are kept when the ciphertext has no copy of them
+    pub async fn relock(pool: &SqlitePool, meeting_id: &str) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let covers_details: Option<bool> =
+            sqlx::query_scalar("SELECT covers_details FROM protected_meetings WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_optional(&mut *transaction)
+                .await?;
+        sqlx::query("UPDATE protected_meetings SET unlocked = 0 WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        blank_content(&mut transaction, meeting_id).await?;
+        if covers_details == Some(true) {
+            blank_details(&mut transaction, meeting_id).await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Write decrypted plaintext back and mark the meeting unlocked
+    pub async fn unlock(pool: &SqlitePool, meeting_id: &str, content: &ProtectedContent) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        write_content(&mut transaction, meeting_id, content).await?;
+        sqlx::query("UPDATE protected_meetings SET unlocked = 1 WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+
+    /// Write decrypted plaintext back and stop protecting the meeting
+    pub async fn remove(pool: &SqlitePool, meeting_id: &str, content: &ProtectedContent) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        write_content(&mut transaction, meeting_id, content).await?;
+        sqlx::query("DELETE FROM protected_meetings WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        transaction.commit().await
+    }
+}
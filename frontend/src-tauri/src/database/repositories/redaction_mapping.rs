@@ -0,0 +1,32 @@
+use sqlx::SqlitePool;
+
+pub struct RedactionMappingsRepository;
+
+impl RedactionMappingsRepository {
+    /// Add (placeholder, original) pairs for a meeting, replacing existing placeholders
+    pub async fn save_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        mappings: &[(String, String)],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        for (placeholder, original) in mappings {
+            sqlx::query(
+                "INSERT OR REPLACE INTO redaction_mappings (meeting_id, placeholder, original) VALUES (?, ?, ?)",
+            )
+            .bind(meeting_id)
+            .bind(placeholder)
+            .bind(original)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as("SELECT placeholder, original FROM redaction_mappings WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+}
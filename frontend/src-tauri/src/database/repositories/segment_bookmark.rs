@@ -0,0 +1,82 @@
+use crate::database::models::SegmentBookmark;
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+pub struct SegmentBookmarksRepository;
+
+const BOOKMARK_SELECT: &str = "SELECT b.transcript_id, b.meeting_id, m.title AS meeting_title, m.created_at AS meeting_created_at,
+        t.transcript AS text,
+        COALESCE(t.speaker_label, CASE t.speaker WHEN 'mic' THEN 'Me' WHEN 'system' THEN 'Remote' END) AS speaker,
+        t.audio_start_time, t.audio_end_time, b.note, b.created_at
+     FROM segment_bookmarks b
+     JOIN transcripts t ON t.id = b.transcript_id
+     JOIN meetings m ON m.id = b.meeting_id";
+
+impl SegmentBookmarksRepository {
+    /// Star a segment of `meeting_id`, or update the note of an existing bookmark; false
+    /// when the segment isn't part of that meeting
+    pub async fn star(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        transcript_id: &str,
+        note: Option<&str>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "INSERT INTO segment_bookmarks (transcript_id, meeting_id, note, created_at)
+             SELECT id, meeting_id, ?, ? FROM transcripts WHERE id = ? AND meeting_id = ?
+             ON CONFLICT(transcript_id) DO UPDATE SET note = excluded.note",
+        )
+        .bind(note)
+        .bind(Utc::now())
+        .bind(transcript_id)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn unstar(pool: &SqlitePool, transcript_id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM segment_bookmarks WHERE transcript_id = ?")
+            .bind(transcript_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// A meeting's bookmarks in recording order
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<SegmentBookmark>, sqlx::Error> {
+        sqlx::query_as::<_, SegmentBookmark>(&format!(
+            "{} WHERE b.meeting_id = ? ORDER BY t.audio_start_time, b.created_at",
+            BOOKMARK_SELECT
+        ))
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Bookmarks across all meetings, most recently starred first
+    pub async fn list_recent(pool: &SqlitePool, limit: i64, offset: i64) -> Result<Vec<SegmentBookmark>, sqlx::Error> {
+        sqlx::query_as::<_, SegmentBookmark>(&format!(
+            "{} WHERE m.deleted_at IS NULL ORDER BY b.created_at DESC LIMIT ? OFFSET ?",
+            BOOKMARK_SELECT
+        ))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// (id, audio_start_time, audio_end_time) of a meeting's timed segments, in order
+    pub async fn timed_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, f64, Option<f64>)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, f64, Option<f64>)>(
+            "SELECT id, audio_start_time, audio_end_time FROM transcripts
+             WHERE meeting_id = ? AND audio_start_time IS NOT NULL ORDER BY audio_start_time",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,70 @@
+use crate::database::models::SegmentComment;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct SegmentCommentsRepository;
+
+impl SegmentCommentsRepository {
+    /// Comment on a segment of `meeting_id`; None when the segment isn't part of that meeting
+    pub async fn insert(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        transcript_id: &str,
+        author: &str,
+        text: &str,
+    ) -> Result<Option<SegmentComment>, sqlx::Error> {
+        let id = format!("comment-{}", Uuid::new_v4());
+        let now = Utc::now();
+        let result = sqlx::query(
+            "INSERT INTO segment_comments (id, meeting_id, transcript_id, author, text, created_at, updated_at)
+             SELECT ?, meeting_id, id, ?, ?, ?, ? FROM transcripts WHERE id = ? AND meeting_id = ?",
+        )
+        .bind(&id)
+        .bind(author)
+        .bind(text)
+        .bind(now)
+        .bind(now)
+        .bind(transcript_id)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Self::get(pool, &id).await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<SegmentComment>, sqlx::Error> {
+        sqlx::query_as::<_, SegmentComment>("SELECT * FROM segment_comments WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn update_text(pool: &SqlitePool, id: &str, text: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE segment_comments SET text = ?, updated_at = ? WHERE id = ?")
+            .bind(text)
+            .bind(Utc::now())
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM segment_comments WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// A meeting's comments, oldest first
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<SegmentComment>, sqlx::Error> {
+        sqlx::query_as::<_, SegmentComment>("SELECT * FROM segment_comments WHERE meeting_id = ? ORDER BY created_at")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+}
@@ -1,5 +1,28 @@
+use crate::calendar::CalendarSettings;
 use crate::database::models::{Setting, TranscriptSetting};
+use crate::export::ExportSettings;
+use crate::integrations::confluence::ConfluenceSettings;
+use crate::integrations::crm::CrmSettings;
+use crate::integrations::email::SmtpSettings;
+use crate::integrations::jira::JiraSettings;
+use crate::integrations::linear::LinearSettings;
+use crate::integrations::notion::NotionSettings;
+use crate::integrations::slack::SlackSettings;
+use crate::integrations::tasks::TaskSyncSettings;
+use crate::integrations::webhooks::WebhookSettings;
+use crate::local_api::LocalApiSettings;
+use crate::meeting_detection::MeetingDetectionSettings;
+use crate::meeting_templates::MeetingTemplate;
+use crate::retention::RetentionPolicy;
+use crate::summary::digest::DigestSchedule;
+use crate::summary::embeddings::EmbeddingConfig;
+use crate::summary::redaction::RedactionSettings;
+use crate::summary::style::SummaryStyle;
+use crate::summary::templates::PromptTemplate;
 use crate::summary::CustomOpenAIConfig;
+use crate::sync::SyncSettings;
+use crate::trash::TrashSettings;
+use crate::watch_folder::WatchFolderSettings;
 use sqlx::SqlitePool;
 
 #[derive(serde::Deserialize, Debug)]
@@ -25,7 +48,7 @@ pub struct SaveTranscriptConfigRequest {
 pub struct SettingsRepository;
 
 // Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
-// Summary providers: openai, claude, ollama, groq, added openrouter
+// Summary providers: openai, claude, ollama, groq, added openrouter, gemini
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
 
 impl SettingsRepository {
@@ -85,6 +108,7 @@ impl SettingsRepository {
             "ollama" => "ollamaApiKey",
             "groq" => "groqApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(()), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -123,6 +147,7 @@ impl SettingsRepository {
             "groq" => "groqApiKey",
             "claude" => "anthropicApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(None), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -249,6 +274,7 @@ impl SettingsRepository {
             "groq" => "groqApiKey",
             "claude" => "anthropicApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(()), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -345,4 +371,964 @@ impl SettingsRepository {
 
         Ok(())
     }
+
+    /// Gets the user's prompt templates (empty if none were saved)
+    pub async fn get_prompt_templates(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Vec<PromptTemplate>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT promptTemplates FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        match json.flatten() {
+            Some(json) => serde_json::from_str(&json).map_err(|e| {
+                sqlx::Error::Protocol(format!("Invalid JSON in promptTemplates: {}", e).into())
+            }),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Saves the user's prompt templates as JSON
+    pub async fn save_prompt_templates(
+        pool: &SqlitePool,
+        templates: &[PromptTemplate],
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(templates).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize prompt templates: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, promptTemplates)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                promptTemplates = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the embedding model for semantic indexing (None if never configured)
+    pub async fn get_embedding_config(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<EmbeddingConfig>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT embeddingConfig FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in embeddingConfig: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the embedding model, or clears it to turn semantic indexing off
+    pub async fn save_embedding_config(
+        pool: &SqlitePool,
+        config: Option<&EmbeddingConfig>,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = config
+            .map(|config| {
+                serde_json::to_string(config).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Failed to serialize embedding config: {}", e).into())
+                })
+            })
+            .transpose()?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, embeddingConfig)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                embeddingConfig = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the weekly digest schedule (None if never configured)
+    pub async fn get_digest_schedule(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<DigestSchedule>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT digestSchedule FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in digestSchedule: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the weekly digest schedule
+    pub async fn save_digest_schedule(
+        pool: &SqlitePool,
+        schedule: &DigestSchedule,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(schedule).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize digest schedule: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, digestSchedule)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                digestSchedule = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the default minutes style (None if never chosen or no longer known)
+    pub async fn get_summary_style(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SummaryStyle>, sqlx::Error> {
+        let style: Option<Option<String>> =
+            sqlx::query_scalar("SELECT summaryStyle FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        Ok(style.flatten().as_deref().and_then(SummaryStyle::parse))
+    }
+
+    /// Saves the default minutes style
+    pub async fn save_summary_style(
+        pool: &SqlitePool,
+        style: SummaryStyle,
+    ) -> std::result::Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, summaryStyle)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                summaryStyle = $1
+            "#,
+        )
+        .bind(style.as_str())
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the PII redaction settings (None if never saved)
+    pub async fn get_redaction_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<RedactionSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT piiRedaction FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in piiRedaction: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the PII redaction settings
+    pub async fn save_redaction_settings(
+        pool: &SqlitePool,
+        settings: &RedactionSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize redaction settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, piiRedaction)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                piiRedaction = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the meeting export settings (None if never saved)
+    pub async fn get_export_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<ExportSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT exportSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in exportSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the meeting export settings
+    pub async fn save_export_settings(
+        pool: &SqlitePool,
+        settings: &ExportSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize export settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, exportSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                exportSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the retention policy (None if never configured)
+    pub async fn get_retention_policy(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<RetentionPolicy>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT retentionPolicy FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in retentionPolicy: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the retention policy
+    pub async fn save_retention_policy(
+        pool: &SqlitePool,
+        policy: &RetentionPolicy,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(policy).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize retention policy: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, retentionPolicy)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                retentionPolicy = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the sync configuration, if sync was ever set up
+    pub async fn get_sync_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SyncSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT syncSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in syncSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the sync configuration
+    pub async fn save_sync_settings(
+        pool: &SqlitePool,
+        settings: &SyncSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize sync settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, syncSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                syncSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the watch folder configuration (None if never set up)
+    pub async fn get_watch_folder_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<WatchFolderSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT watchFolderSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in watchFolderSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the watch folder configuration
+    pub async fn save_watch_folder_settings(
+        pool: &SqlitePool,
+        settings: &WatchFolderSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize watch folder settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, watchFolderSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                watchFolderSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the user's meeting templates (empty if none were defined)
+    pub async fn get_meeting_templates(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Vec<MeetingTemplate>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT meetingTemplates FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in meetingTemplates: {}", e).into())
+                })
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Saves the full list of meeting templates
+    pub async fn save_meeting_templates(
+        pool: &SqlitePool,
+        templates: &[MeetingTemplate],
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(templates).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize meeting templates: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, meetingTemplates)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                meetingTemplates = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the trash settings (None if never changed)
+    pub async fn get_trash_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<TrashSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT trashSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in trashSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the trash settings
+    pub async fn save_trash_settings(
+        pool: &SqlitePool,
+        settings: &TrashSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize trash settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, trashSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                trashSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the calendar settings (None if no calendar was ever set up)
+    pub async fn get_calendar_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<CalendarSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT calendarSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in calendarSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the calendar settings
+    pub async fn save_calendar_settings(
+        pool: &SqlitePool,
+        settings: &CalendarSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize calendar settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, calendarSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                calendarSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the meeting detection settings (None if never saved)
+    pub async fn get_meeting_detection_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<MeetingDetectionSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT meetingDetectionSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in meetingDetectionSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the meeting detection settings
+    pub async fn save_meeting_detection_settings(
+        pool: &SqlitePool,
+        settings: &MeetingDetectionSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize meeting detection settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, meetingDetectionSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                meetingDetectionSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the webhook settings (None if no webhook was ever added)
+    pub async fn get_webhook_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<WebhookSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT webhookSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in webhookSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the webhook settings
+    pub async fn save_webhook_settings(
+        pool: &SqlitePool,
+        settings: &WebhookSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize webhook settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, webhookSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                webhookSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the Slack settings (None until Slack was connected)
+    pub async fn get_slack_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SlackSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT slackSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in slackSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Slack settings
+    pub async fn save_slack_settings(
+        pool: &SqlitePool,
+        settings: &SlackSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize Slack settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, slackSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                slackSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the Notion settings (None until Notion was connected)
+    pub async fn get_notion_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<NotionSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT notionSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in notionSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Notion settings
+    pub async fn save_notion_settings(
+        pool: &SqlitePool,
+        settings: &NotionSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize Notion settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, notionSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                notionSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the Confluence settings (None until Confluence was connected)
+    pub async fn get_confluence_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<ConfluenceSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT confluenceSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in confluenceSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Confluence settings
+    pub async fn save_confluence_settings(
+        pool: &SqlitePool,
+        settings: &ConfluenceSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize Confluence settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, confluenceSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                confluenceSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the SMTP server settings (None until email was set up)
+    pub async fn get_smtp_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<SmtpSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT smtpSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in smtpSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the SMTP server settings
+    pub async fn save_smtp_settings(
+        pool: &SqlitePool,
+        settings: &SmtpSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize SMTP settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, smtpSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                smtpSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the local API settings (None until the local API was set up)
+    pub async fn get_local_api_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<LocalApiSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT localApiSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in localApiSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the local API settings
+    pub async fn save_local_api_settings(
+        pool: &SqlitePool,
+        settings: &LocalApiSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize local API settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, localApiSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                localApiSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the Jira settings (None until Jira was connected)
+    pub async fn get_jira_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<JiraSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT jiraSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in jiraSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Jira settings
+    pub async fn save_jira_settings(
+        pool: &SqlitePool,
+        settings: &JiraSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize Jira settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, jiraSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                jiraSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the Linear settings (None until Linear was connected)
+    pub async fn get_linear_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<LinearSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT linearSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in linearSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the Linear settings
+    pub async fn save_linear_settings(
+        pool: &SqlitePool,
+        settings: &LinearSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize Linear settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, linearSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                linearSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the task sync settings (None until a task manager was connected)
+    pub async fn get_task_sync_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<TaskSyncSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT taskSyncSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in taskSyncSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the task sync settings
+    pub async fn save_task_sync_settings(
+        pool: &SqlitePool,
+        settings: &TaskSyncSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize task sync settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, taskSyncSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                taskSyncSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Gets the CRM settings (None until HubSpot or Salesforce was connected)
+    pub async fn get_crm_settings(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Option<CrmSettings>, sqlx::Error> {
+        let json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT crmSettings FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        json.flatten()
+            .map(|json| {
+                serde_json::from_str(&json).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Invalid JSON in crmSettings: {}", e).into())
+                })
+            })
+            .transpose()
+    }
+
+    /// Saves the CRM settings
+    pub async fn save_crm_settings(
+        pool: &SqlitePool,
+        settings: &CrmSettings,
+    ) -> std::result::Result<(), sqlx::Error> {
+        let json = serde_json::to_string(settings).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize CRM settings: {}", e).into())
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, crmSettings)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                crmSettings = $1
+            "#,
+        )
+        .bind(json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
 }
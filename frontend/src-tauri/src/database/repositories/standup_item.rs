@@ -0,0 +1,56 @@
+use crate::database::models::StandupItem;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// A standup update to store
+pub struct NewStandupItem {
+    pub speaker: String,
+    pub kind: String,
+    pub text: String,
+    pub segment_ids: Vec<String>,
+    pub start_time: Option<f64>,
+}
+
+pub struct StandupItemsRepository;
+
+impl StandupItemsRepository {
+    /// Replace a meeting's standup updates, in one transaction
+    pub async fn replace_for_meeting(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        items: &[NewStandupItem],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        sqlx::query("DELETE FROM standup_items WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+        let now = Utc::now();
+        for item in items {
+            sqlx::query(
+                "INSERT INTO standup_items (id, meeting_id, speaker, kind, text, segment_ids, start_time, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("standup-{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(&item.speaker)
+            .bind(&item.kind)
+            .bind(&item.text)
+            .bind(serde_json::to_string(&item.segment_ids).unwrap_or_else(|_| "[]".to_string()))
+            .bind(item.start_time)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// A meeting's standup updates in the order they were extracted
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<StandupItem>, sqlx::Error> {
+        sqlx::query_as::<_, StandupItem>("SELECT * FROM standup_items WHERE meeting_id = ? ORDER BY rowid")
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+}
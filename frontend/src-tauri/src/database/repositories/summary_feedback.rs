@@ -0,0 +1,74 @@
+use crate::database::models::SummaryFeedback;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Feedback to save on a meeting's summary
+pub struct NewSummaryFeedback<'a> {
+    pub meeting_id: &'a str,
+    pub version: Option<i64>,
+    pub section: Option<&'a str>,
+    pub rating: i64,
+    pub comment: Option<&'a str>,
+    pub template_id: Option<&'a str>,
+    pub provider: Option<&'a str>,
+    pub model: Option<&'a str>,
+}
+
+pub struct SummaryFeedbackRepository;
+
+impl SummaryFeedbackRepository {
+    pub async fn insert(pool: &SqlitePool, feedback: &NewSummaryFeedback<'_>) -> Result<SummaryFeedback, sqlx::Error> {
+        let id = format!("feedback-{}", Uuid::new_v4());
+        sqlx::query(
+            "INSERT INTO summary_feedback (id, meeting_id, version, section, rating, comment, template_id, provider, model, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(feedback.meeting_id)
+        .bind(feedback.version)
+        .bind(feedback.section)
+        .bind(feedback.rating)
+        .bind(feedback.comment)
+        .bind(feedback.template_id)
+        .bind(feedback.provider)
+        .bind(feedback.model)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+
+        sqlx::query_as::<_, SummaryFeedback>("SELECT * FROM summary_feedback WHERE id = ?")
+            .bind(&id)
+            .fetch_one(pool)
+            .await
+    }
+
+    /// All feedback, optionally for one template, newest first
+    pub async fn list(pool: &SqlitePool, template_id: Option<&str>) -> Result<Vec<SummaryFeedback>, sqlx::Error> {
+        match template_id {
+            Some(template_id) => {
+                sqlx::query_as::<_, SummaryFeedback>(
+                    "SELECT * FROM summary_feedback WHERE template_id = ? ORDER BY created_at DESC",
+                )
+                .bind(template_id)
+                .fetch_all(pool)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, SummaryFeedback>("SELECT * FROM summary_feedback ORDER BY created_at DESC")
+                    .fetch_all(pool)
+                    .await
+            }
+        }
+    }
+
+    /// A meeting's feedback, newest first
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<SummaryFeedback>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryFeedback>(
+            "SELECT * FROM summary_feedback WHERE meeting_id = ? ORDER BY created_at DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,73 @@
+use crate::database::models::SummaryVersion;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// A summary to save as the meeting's next version
+pub struct NewSummaryVersion<'a> {
+    pub meeting_id: &'a str,
+    pub source: &'a str,
+    pub markdown: &'a str,
+    pub template_id: Option<&'a str>,
+    pub provider: Option<&'a str>,
+    pub model: Option<&'a str>,
+    pub style: Option<&'a str>,
+}
+
+pub struct SummaryVersionsRepository;
+
+impl SummaryVersionsRepository {
+    /// Save a version, numbered after the meeting's latest; returns its number
+    pub async fn insert(pool: &SqlitePool, version: &NewSummaryVersion<'_>) -> Result<i64, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+        let number: i64 =
+            sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) + 1 FROM summary_versions WHERE meeting_id = ?")
+                .bind(version.meeting_id)
+                .fetch_one(&mut *transaction)
+                .await?;
+        sqlx::query(
+            "INSERT INTO summary_versions (id, meeting_id, version, source, markdown, template_id, provider, model, style, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(format!("version-{}", Uuid::new_v4()))
+        .bind(version.meeting_id)
+        .bind(number)
+        .bind(version.source)
+        .bind(version.markdown)
+        .bind(version.template_id)
+        .bind(version.provider)
+        .bind(version.model)
+        .bind(version.style)
+        .bind(Utc::now())
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+        Ok(number)
+    }
+
+    /// A meeting's versions, newest first
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get(pool: &SqlitePool, meeting_id: &str, version: i64) -> Result<Option<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>("SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?")
+            .bind(meeting_id)
+            .bind(version)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Markdown of the meeting's most recent version
+    pub async fn latest_markdown(pool: &SqlitePool, meeting_id: &str) -> Result<Option<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT markdown FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC LIMIT 1")
+            .bind(meeting_id)
+            .fetch_optional(pool)
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+pub struct SyncedTasksRepository;
+
+impl SyncedTasksRepository {
+    /// Keys of a meeting's action items already sent to `provider`
+    pub async fn list_keys(pool: &SqlitePool, provider: &str, meeting_id: &str) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar("SELECT item_key FROM synced_tasks WHERE provider = ? AND meeting_id = ?")
+            .bind(provider)
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Remember that an action item became task `task_id`
+    pub async fn insert(
+        pool: &SqlitePool,
+        provider: &str,
+        meeting_id: &str,
+        item_key: &str,
+        task_id: &str,
+        task_url: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO synced_tasks (provider, meeting_id, item_key, task_id, task_url, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(provider)
+        .bind(meeting_id)
+        .bind(item_key)
+        .bind(task_id)
+        .bind(task_url)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
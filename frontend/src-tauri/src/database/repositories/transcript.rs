@@ -1,4 +1,5 @@
 use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::database::models::{MeetingSearchHit, Transcript};
 use chrono::Utc;
 use sqlx::{Connection, Error as SqlxError, SqlitePool};
 use tracing::{error, info};
@@ -6,6 +7,13 @@ use uuid::Uuid;
 
 pub struct TranscriptsRepository;
 
+/// (id, text, audio_start_time, speaker, confidence, quality_score, overlapping_speech)
+pub type ReviewRow = (String, String, Option<f64>, Option<String>, Option<f64>, Option<f64>, bool);
+
+/// Speaker shown for a segment: the diarized label, else the audio source label
+const EFFECTIVE_SPEAKER_LABEL: &str =
+    "COALESCE(speaker_label, CASE speaker WHEN 'mic' THEN 'Me' WHEN 'system' THEN 'Remote' END)";
+
 impl TranscriptsRepository {
     /// Saves a new meeting and its associated transcript segments.
     /// This function uses a transaction to ensure that either both the meeting
@@ -43,12 +51,34 @@ impl TranscriptsRepository {
 
         info!("Successfully created meeting with id: {}", meeting_id);
 
+        // Crosstalk between separately transcribed mic/system segments (untimed segments never overlap)
+        let spans: Vec<(f64, f64, Option<&str>)> = transcripts
+            .iter()
+            .map(|s| match s.audio_start_time {
+                Some(start) => (
+                    start,
+                    s.audio_end_time.unwrap_or(start + s.duration.unwrap_or(0.0)),
+                    s.speaker.as_deref(),
+                ),
+                None => (0.0, 0.0, None),
+            })
+            .collect();
+        let overlaps = crate::diarization::overlap::overlapping_flags(&spans);
+
         // 2. Save each transcript segment with audio timing fields
-        for segment in transcripts {
+        for (segment, overlapping) in transcripts.iter().zip(overlaps) {
             let transcript_id = format!("transcript-{}", Uuid::new_v4());
+            let language = segment.language.clone().or_else(|| {
+                crate::audio::transcription::language_id::detect_language(&segment.text).map(str::to_string)
+            });
+            let needs_review = crate::audio::transcription::review::needs_review(
+                segment.confidence,
+                segment.quality_score,
+                overlapping,
+            );
             let result = sqlx::query(
-                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration)
-                 VALUES (?, ?, ?, ?, ?, ?, ?)"
+                "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, audio_start_time, audio_end_time, duration, quality_score, speaker, speaker_label, overlapping_speech, language, confidence, needs_review)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
             )
             .bind(&transcript_id)
             .bind(&meeting_id)
@@ -57,6 +87,13 @@ impl TranscriptsRepository {
             .bind(segment.audio_start_time)
             .bind(segment.audio_end_time)
             .bind(segment.duration)
+            .bind(segment.quality_score)
+            .bind(&segment.speaker)
+            .bind(&segment.speaker_label)
+            .bind(overlapping)
+            .bind(&language)
+            .bind(segment.confidence)
+            .bind(needs_review)
             .execute(&mut *transaction)
             .await;
 
@@ -98,7 +135,7 @@ impl TranscriptsRepository {
             "SELECT m.id, m.title, t.transcript, t.timestamp
              FROM meetings m
              JOIN transcripts t ON m.id = t.meeting_id
-             WHERE LOWER(t.transcript) LIKE ?",
+             WHERE LOWER(t.transcript) LIKE ? AND m.deleted_at IS NULL",
         )
         .bind(&search_query)
         .fetch_all(pool)
@@ -120,6 +157,279 @@ impl TranscriptsRepository {
         Ok(results)
     }
 
+    /// Ranked full-text matches over transcript segments and summaries. `fts_query` is an
+    /// FTS5 MATCH expression; the best matches come first.
+    pub async fn search_meetings(
+        pool: &SqlitePool,
+        fts_query: &str,
+        limit: i64,
+    ) -> Result<Vec<MeetingSearchHit>, SqlxError> {
+        let query = format!(
+            "SELECT * FROM (
+                 SELECT t.meeting_id, m.title AS meeting_title, 'transcript' AS source, t.id AS segment_id,
+                        {} AS speaker, t.audio_start_time, t.timestamp,
+                        snippet(transcripts_fts, 0, '**', '**', '…', 16) AS snippet,
+                        bm25(transcripts_fts) AS score
+                 FROM transcripts_fts
+                 JOIN transcripts t ON t.id = transcripts_fts.segment_id
+                 JOIN meetings m ON m.id = t.meeting_id
+                 WHERE transcripts_fts MATCH ? AND m.deleted_at IS NULL
+                 UNION ALL
+                 SELECT s.meeting_id, m.title, 'summary', NULL, NULL, NULL, NULL,
+                        snippet(summaries_fts, 0, '**', '**', '…', 16),
+                        bm25(summaries_fts)
+                 FROM summaries_fts s
+                 JOIN meetings m ON m.id = s.meeting_id
+                 WHERE summaries_fts MATCH ? AND m.deleted_at IS NULL
+             )
+             ORDER BY score
+             LIMIT ?",
+            EFFECTIVE_SPEAKER_LABEL
+        );
+        sqlx::query_as::<_, MeetingSearchHit>(&query)
+            .bind(fts_query)
+            .bind(fts_query)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Loads segments by id as (id, meeting_id, text, audio_start_time, speaker), e.g. for
+    /// semantic search hits
+    pub async fn get_segments_by_ids(
+        pool: &SqlitePool,
+        ids: &[String],
+    ) -> Result<Vec<(String, String, String, Option<f64>, Option<String>)>, SqlxError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = format!(
+            "SELECT id, meeting_id, transcript, audio_start_time, {} FROM transcripts WHERE id IN ({})",
+            EFFECTIVE_SPEAKER_LABEL,
+            vec!["?"; ids.len()].join(", ")
+        );
+        let mut query = sqlx::query_as::<_, (String, String, String, Option<f64>, Option<String>)>(&query);
+        for id in ids {
+            query = query.bind(id);
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// Lists the speakers of a meeting with their segment counts and total speaking time.
+    /// Segments without a diarized label fall back to their audio source ("Me" / "Remote").
+    pub async fn list_speakers(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, i64, f64)>, SqlxError> {
+        let query = format!(
+            "SELECT {label} AS label, COUNT(*), COALESCE(SUM(duration), 0.0)
+             FROM transcripts
+             WHERE meeting_id = ? AND {label} IS NOT NULL
+             GROUP BY label
+             ORDER BY MIN(audio_start_time)",
+            label = EFFECTIVE_SPEAKER_LABEL
+        );
+        sqlx::query_as::<_, (String, i64, f64)>(&query)
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Moves every segment of speaker `from` to speaker `to` (rename, or merge when `to` already exists).
+    /// Returns the number of segments changed.
+    pub async fn relabel_speaker(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<u64, SqlxError> {
+        let query = format!(
+            "UPDATE transcripts SET speaker_label = ? WHERE meeting_id = ? AND {} = ?",
+            EFFECTIVE_SPEAKER_LABEL
+        );
+        let result = sqlx::query(&query)
+            .bind(to)
+            .bind(meeting_id)
+            .bind(from)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Assigns every segment lying within [start_time, end_time] (recording-relative seconds)
+    /// to `speaker`. Returns the number of segments changed.
+    pub async fn reassign_speaker_range(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        start_time: f64,
+        end_time: f64,
+        speaker: &str,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query(
+            "UPDATE transcripts SET speaker_label = ?
+             WHERE meeting_id = ? AND audio_start_time >= ? AND COALESCE(audio_end_time, audio_start_time) <= ?",
+        )
+        .bind(speaker)
+        .bind(meeting_id)
+        .bind(start_time)
+        .bind(end_time)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Returns (speaker, start, end, text) for every timed, speaker-attributed segment in audio order.
+    pub async fn list_speaker_segments(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, f64, f64, String)>, SqlxError> {
+        let query = format!(
+            "SELECT {label} AS label, audio_start_time, COALESCE(audio_end_time, audio_start_time + COALESCE(duration, 0.0)), transcript
+             FROM transcripts
+             WHERE meeting_id = ? AND {label} IS NOT NULL AND audio_start_time IS NOT NULL
+             ORDER BY audio_start_time",
+            label = EFFECTIVE_SPEAKER_LABEL
+        );
+        sqlx::query_as::<_, (String, f64, f64, String)>(&query)
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Segments of a meeting still flagged for review, in audio order.
+    pub async fn list_needing_review(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<ReviewRow>, SqlxError> {
+        let query = format!(
+            "SELECT id, transcript, audio_start_time, {}, confidence, quality_score, overlapping_speech
+             FROM transcripts
+             WHERE meeting_id = ? AND needs_review = 1
+             ORDER BY audio_start_time, timestamp",
+            EFFECTIVE_SPEAKER_LABEL
+        );
+        sqlx::query_as(&query).bind(meeting_id).fetch_all(pool).await
+    }
+
+    pub async fn set_needs_review(
+        pool: &SqlitePool,
+        transcript_id: &str,
+        needs_review: bool,
+    ) -> Result<u64, SqlxError> {
+        let result = sqlx::query("UPDATE transcripts SET needs_review = ? WHERE id = ?")
+            .bind(needs_review)
+            .bind(transcript_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Returns (language, segment count) for the identified segments of a meeting, most used first.
+    pub async fn language_breakdown(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, i64)>, SqlxError> {
+        sqlx::query_as::<_, (String, i64)>(
+            "SELECT language, COUNT(*) FROM transcripts
+             WHERE meeting_id = ? AND language IS NOT NULL
+             GROUP BY language
+             ORDER BY COUNT(*) DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get_segment(pool: &SqlitePool, id: &str) -> Result<Option<Transcript>, SqlxError> {
+        sqlx::query_as::<_, Transcript>("SELECT * FROM transcripts WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Every segment of a meeting in audio order, e.g. for exports
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<Transcript>, SqlxError> {
+        sqlx::query_as::<_, Transcript>(
+            "SELECT * FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time, timestamp",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Returns (id, text, audio_start_time, speaker) for every segment in audio order,
+    /// the input of per-segment analysis passes.
+    pub async fn list_segments_for_analysis(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<(String, String, Option<f64>, Option<String>)>, SqlxError> {
+        let query = format!(
+            "SELECT id, transcript, audio_start_time, {} FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time, timestamp",
+            EFFECTIVE_SPEAKER_LABEL
+        );
+        sqlx::query_as::<_, (String, String, Option<f64>, Option<String>)>(&query)
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Stores tone analysis results as (transcript id, sentiment, tension) in one transaction.
+    pub async fn save_segment_tones(
+        pool: &SqlitePool,
+        tones: &[(String, String, f64)],
+    ) -> Result<(), SqlxError> {
+        let mut transaction = pool.begin().await?;
+        for (id, sentiment, tension) in tones {
+            sqlx::query("UPDATE transcripts SET sentiment = ?, tension = ? WHERE id = ?")
+                .bind(sentiment)
+                .bind(tension)
+                .bind(id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await
+    }
+
+    /// Returns the meeting transcript as "Speaker: text" lines in audio order,
+    /// merging consecutive segments from the same speaker.
+    pub async fn get_speaker_labeled_text(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<String, SqlxError> {
+        let query = format!(
+            "SELECT {}, transcript FROM transcripts WHERE meeting_id = ? ORDER BY audio_start_time, timestamp",
+            EFFECTIVE_SPEAKER_LABEL
+        );
+        let rows = sqlx::query_as::<_, (Option<String>, String)>(&query)
+            .bind(meeting_id)
+            .fetch_all(pool)
+            .await?;
+
+        let mut lines: Vec<(Option<String>, String)> = Vec::new();
+        for (speaker, text) in rows {
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            match lines.last_mut() {
+                Some((last_speaker, last_text)) if *last_speaker == speaker => {
+                    last_text.push(' ');
+                    last_text.push_str(text);
+                }
+                _ => lines.push((speaker, text.to_string())),
+            }
+        }
+
+        Ok(lines
+            .into_iter()
+            .map(|(speaker, text)| match speaker {
+                Some(speaker) => format!("{}: {}", speaker, text),
+                None => text,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
     /// Helper function to extract a snippet of text around the first match of a query.
     fn get_match_context(transcript: &str, query: &str) -> String {
         let transcript_lower = transcript.to_lowercase();
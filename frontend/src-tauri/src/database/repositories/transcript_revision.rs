@@ -0,0 +1,115 @@
+use crate::database::models::{Transcript, TranscriptRevision};
+use chrono::Utc;
+use sqlx::{Error as SqlxError, SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+pub struct TranscriptRevisionsRepository;
+
+pub(crate) async fn insert_segment(conn: &mut SqliteConnection, segment: &Transcript) -> Result<(), SqlxError> {
+    sqlx::query(
+        "INSERT INTO transcripts (id, meeting_id, transcript, timestamp, summary, action_items, key_points, audio_start_time, audio_end_time, duration, quality_score, speaker, speaker_label, overlapping_speech, sentiment, tension, language, confidence, needs_review)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(&segment.id)
+    .bind(&segment.meeting_id)
+    .bind(&segment.transcript)
+    .bind(&segment.timestamp)
+    .bind(&segment.summary)
+    .bind(&segment.action_items)
+    .bind(&segment.key_points)
+    .bind(segment.audio_start_time)
+    .bind(segment.audio_end_time)
+    .bind(segment.duration)
+    .bind(segment.quality_score)
+    .bind(&segment.speaker)
+    .bind(&segment.speaker_label)
+    .bind(segment.overlapping_speech)
+    .bind(&segment.sentiment)
+    .bind(segment.tension)
+    .bind(&segment.language)
+    .bind(segment.confidence)
+    .bind(segment.needs_review)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+fn to_json(segments: &[Transcript]) -> Result<String, SqlxError> {
+    serde_json::to_string(segments)
+        .map_err(|e| SqlxError::Protocol(format!("Failed to serialize transcript segments: {}", e)))
+}
+
+impl TranscriptRevisionsRepository {
+    /// Replace the `before` segments with the `after` ones and record the change, in one
+    /// transaction. With `reverts`, that revision is marked as undone by this one.
+    pub async fn apply(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        kind: &str,
+        before: &[Transcript],
+        after: &[Transcript],
+        reverts: Option<&str>,
+    ) -> Result<TranscriptRevision, SqlxError> {
+        let revision = TranscriptRevision {
+            id: format!("revision-{}", Uuid::new_v4()),
+            meeting_id: meeting_id.to_string(),
+            kind: kind.to_string(),
+            before_segments: to_json(before)?,
+            after_segments: to_json(after)?,
+            reverts: reverts.map(str::to_string),
+            reverted_by: None,
+            created_at: Utc::now(),
+        };
+
+        let mut transaction = pool.begin().await?;
+        for segment in before {
+            sqlx::query("DELETE FROM transcripts WHERE id = ? AND meeting_id = ?")
+                .bind(&segment.id)
+                .bind(meeting_id)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        for segment in after {
+            insert_segment(&mut *transaction, segment).await?;
+        }
+        sqlx::query(
+            "INSERT INTO transcript_revisions (id, meeting_id, kind, before_segments, after_segments, reverts, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&revision.id)
+        .bind(&revision.meeting_id)
+        .bind(&revision.kind)
+        .bind(&revision.before_segments)
+        .bind(&revision.after_segments)
+        .bind(&revision.reverts)
+        .bind(revision.created_at)
+        .execute(&mut *transaction)
+        .await?;
+        if let Some(reverts) = reverts {
+            sqlx::query("UPDATE transcript_revisions SET reverted_by = ? WHERE id = ?")
+                .bind(&revision.id)
+                .bind(reverts)
+                .execute(&mut *transaction)
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(revision)
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<TranscriptRevision>, SqlxError> {
+        sqlx::query_as::<_, TranscriptRevision>("SELECT * FROM transcript_revisions WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// A meeting's revisions, newest first
+    pub async fn list_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Vec<TranscriptRevision>, SqlxError> {
+        sqlx::query_as::<_, TranscriptRevision>(
+            "SELECT * FROM transcript_revisions WHERE meeting_id = ? ORDER BY created_at DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
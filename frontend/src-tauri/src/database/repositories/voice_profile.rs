@@ -0,0 +1,82 @@
+use crate::database::models::VoiceProfile;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct VoiceProfilesRepository;
+
+impl VoiceProfilesRepository {
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<VoiceProfile>, sqlx::Error> {
+        sqlx::query_as::<_, VoiceProfile>("SELECT * FROM voice_profiles ORDER BY name COLLATE NOCASE")
+            .fetch_all(pool)
+            .await
+    }
+
+    pub async fn get(pool: &SqlitePool, id: &str) -> Result<Option<VoiceProfile>, sqlx::Error> {
+        sqlx::query_as::<_, VoiceProfile>("SELECT * FROM voice_profiles WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    pub async fn get_by_name(pool: &SqlitePool, name: &str) -> Result<Option<VoiceProfile>, sqlx::Error> {
+        sqlx::query_as::<_, VoiceProfile>("SELECT * FROM voice_profiles WHERE name = ? COLLATE NOCASE")
+            .bind(name)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Create a profile from a first enrollment sample, returning its id
+    pub async fn create(pool: &SqlitePool, name: &str, embedding: &[u8]) -> Result<String, sqlx::Error> {
+        let id = format!("voice-{}", Uuid::new_v4());
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO voice_profiles (id, name, embedding, sample_count, created_at, updated_at)
+             VALUES (?, ?, ?, 1, ?, ?)",
+        )
+        .bind(&id)
+        .bind(name)
+        .bind(embedding)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await?;
+        Ok(id)
+    }
+
+    pub async fn update_embedding(
+        pool: &SqlitePool,
+        id: &str,
+        embedding: &[u8],
+        sample_count: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE voice_profiles SET embedding = ?, sample_count = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(embedding)
+        .bind(sample_count)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn rename(pool: &SqlitePool, id: &str, name: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("UPDATE voice_profiles SET name = ?, updated_at = ? WHERE id = ?")
+            .bind(name)
+            .bind(Utc::now())
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM voice_profiles WHERE id = ?")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
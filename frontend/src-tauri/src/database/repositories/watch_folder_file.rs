@@ -0,0 +1,80 @@
+use crate::database::models::WatchFolderFile;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+pub struct WatchFolderFilesRepository;
+
+impl WatchFolderFilesRepository {
+    pub async fn get(pool: &SqlitePool, path: &str) -> Result<Option<WatchFolderFile>, sqlx::Error> {
+        sqlx::query_as::<_, WatchFolderFile>("SELECT * FROM watch_folder_files WHERE path = ?")
+            .bind(path)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// Most recently processed first
+    pub async fn list_recent(pool: &SqlitePool, limit: i64) -> Result<Vec<WatchFolderFile>, sqlx::Error> {
+        sqlx::query_as::<_, WatchFolderFile>("SELECT * FROM watch_folder_files ORDER BY updated_at DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Record a file as being processed, replacing what was recorded for an earlier version
+    pub async fn start(
+        pool: &SqlitePool,
+        path: &str,
+        size_bytes: i64,
+        modified_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT OR REPLACE INTO watch_folder_files (path, size_bytes, modified_at, status, meeting_id, error, updated_at)
+             VALUES (?, ?, ?, 'processing', NULL, NULL, ?)",
+        )
+        .bind(path)
+        .bind(size_bytes)
+        .bind(modified_at)
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn finish(
+        pool: &SqlitePool,
+        path: &str,
+        meeting_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE watch_folder_files SET status = ?, meeting_id = ?, error = ?, updated_at = ? WHERE path = ?")
+            .bind(if error.is_some() { "failed" } else { "done" })
+            .bind(meeting_id)
+            .bind(error)
+            .bind(Utc::now())
+            .bind(path)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Forget a file so it is processed again
+    pub async fn delete(pool: &SqlitePool, path: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM watch_folder_files WHERE path = ?")
+            .bind(path)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Files left "processing" when the app quit are failed, not stuck
+    pub async fn fail_interrupted(pool: &SqlitePool) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE watch_folder_files SET status = 'failed', error = 'Interrupted when the app closed', updated_at = ?
+             WHERE status = 'processing'",
+        )
+        .bind(Utc::now())
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,110 @@
+// Full-text search across meetings
+// Turns what the user typed into an FTS5 query over the transcript and summary indexes
+
+use log::info;
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::models::MeetingSearchHit;
+use super::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+/// Words of a query as the FTS5 tokenizer sees them (letters and digits; anything else
+/// separates words)
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// FTS5 expression for free text typed by the user, None if it has no words
+///
+/// Every word is quoted so FTS syntax in the input is taken literally, "quoted phrases"
+/// stay phrases, and a trailing word also matches as a prefix (search as you type).
+/// With `any` the terms are joined with OR instead of all being required.
+fn build_fts_query(query: &str, any: bool) -> Option<String> {
+    let mut terms = Vec::new();
+    let mut trailing_word = false;
+    for (index, part) in query.split('"').enumerate() {
+        let in_phrase = index % 2 == 1;
+        if in_phrase {
+            let phrase = words(part);
+            if !phrase.is_empty() {
+                terms.push(format!("\"{}\"", phrase.join(" ")));
+            }
+            trailing_word = false;
+        } else {
+            let part_words = words(part);
+            trailing_word = !part_words.is_empty() && part.ends_with(|c: char| c.is_alphanumeric());
+            terms.extend(part_words.into_iter().map(|word| format!("\"{}\"", word)));
+        }
+    }
+    if terms.is_empty() {
+        return None;
+    }
+    if trailing_word {
+        if let Some(last) = terms.last_mut() {
+            last.push('*');
+        }
+    }
+    Some(terms.join(if any { " OR " } else { " " }))
+}
+
+/// Hits for `query`, best first: meetings matching every word, or any word when none does
+pub(crate) async fn search(pool: &sqlx::SqlitePool, query: &str, limit: Option<i64>) -> Result<Vec<MeetingSearchHit>, String> {
+    let Some(all_words) = build_fts_query(query, false) else {
+        return Ok(Vec::new());
+    };
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let hits = TranscriptsRepository::search_meetings(pool, &all_words, limit)
+        .await
+        .map_err(|e| format!("Failed to search meetings: {}", e))?;
+    if hits.is_empty() {
+        if let Some(any_word) = build_fts_query(query, true).filter(|any_word| *any_word != all_words) {
+            return TranscriptsRepository::search_meetings(pool, &any_word, limit)
+                .await
+                .map_err(|e| format!("Failed to search meetings: {}", e));
+        }
+    }
+    Ok(hits)
+}
+
+/// Search every meeting's transcript and summary by keyword
+///
+/// Hits are ranked by relevance and carry a snippet, the meeting and, for transcript hits,
+/// the segment, speaker and time. Meetings matching every word come first; when no
+/// meeting does, any word is enough.
+#[tauri::command]
+pub async fn search_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    query: String,
+    limit: Option<i64>,
+) -> Result<Vec<MeetingSearchHit>, String> {
+    let state = app.state::<AppState>();
+    let hits = search(state.db_manager.pool(), &query, limit).await?;
+    info!("Full-text search for '{}' found {} hit(s)", query, hits.len());
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_text_becomes_a_literal_fts_query() {
+        assert_eq!(
+            build_fts_query("that thing about the migra", false).as_deref(),
+            Some("\"that\" \"thing\" \"about\" \"the\" \"migra\"*")
+        );
+        assert_eq!(
+            build_fts_query("\"data migration\" NEAR(postgres) ", true).as_deref(),
+            Some("\"data migration\" OR \"NEAR\" OR \"postgres\"")
+        );
+        assert_eq!(build_fts_query("café \"", false).as_deref(), Some("\"café\""));
+        assert_eq!(build_fts_query(" *-\" \"", false), None);
+    }
+}
@@ -0,0 +1,149 @@
+// diarization/clustering.rs
+//
+// Online speaker clustering: each new segment embedding joins the most similar
+// known speaker if it is close enough, otherwise it starts a new speaker.
+// Centroids are running means so speakers become more stable as a meeting goes on.
+
+use serde::{Deserialize, Serialize};
+
+/// Cosine similarity above which an embedding is attributed to an existing speaker
+pub const DEFAULT_SIMILARITY_THRESHOLD: f32 = 0.5;
+
+/// Running centroid of one speaker's embeddings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerCluster {
+    sum: Vec<f32>,
+    pub segment_count: usize,
+}
+
+impl SpeakerCluster {
+    fn new(embedding: &[f32]) -> Self {
+        Self {
+            sum: embedding.to_vec(),
+            segment_count: 1,
+        }
+    }
+
+    fn add(&mut self, embedding: &[f32]) {
+        for (s, e) in self.sum.iter_mut().zip(embedding) {
+            *s += e;
+        }
+        self.segment_count += 1;
+    }
+
+    /// Normalized centroid embedding
+    pub fn centroid(&self) -> Vec<f32> {
+        let norm = self.sum.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm == 0.0 {
+            return self.sum.clone();
+        }
+        self.sum.iter().map(|x| x / norm).collect()
+    }
+}
+
+/// Incremental clusterer assigning segments to speaker indices (0-based)
+#[derive(Debug, Clone)]
+pub struct SpeakerClusterer {
+    threshold: f32,
+    speakers: Vec<SpeakerCluster>,
+}
+
+impl Default for SpeakerClusterer {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIMILARITY_THRESHOLD)
+    }
+}
+
+impl SpeakerClusterer {
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            speakers: Vec::new(),
+        }
+    }
+
+    /// Assign an embedding to a speaker, creating a new one if nobody is similar enough
+    pub fn assign(&mut self, embedding: &[f32]) -> usize {
+        let best = self
+            .speakers
+            .iter()
+            .enumerate()
+            .map(|(i, speaker)| (i, cosine_similarity(&speaker.centroid(), embedding)))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        match best {
+            Some((index, similarity)) if similarity >= self.threshold => {
+                self.speakers[index].add(embedding);
+                index
+            }
+            _ => {
+                self.speakers.push(SpeakerCluster::new(embedding));
+                self.speakers.len() - 1
+            }
+        }
+    }
+
+    pub fn speaker_count(&self) -> usize {
+        self.speakers.len()
+    }
+
+    pub fn speakers(&self) -> &[SpeakerCluster] {
+        &self.speakers
+    }
+}
+
+/// Display label for a diarized speaker index ("Speaker 1", "Speaker 2", ...)
+pub fn speaker_display_label(index: usize) -> String {
+    format!("Speaker {}", index + 1)
+}
+
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(values: &[f32]) -> Vec<f32> {
+        let norm = values.iter().map(|x| x * x).sum::<f32>().sqrt();
+        values.iter().map(|x| x / norm).collect()
+    }
+
+    #[test]
+    fn test_similar_embeddings_share_a_speaker() {
+        let mut clusterer = SpeakerClusterer::default();
+        assert_eq!(clusterer.assign(&unit(&[1.0, 0.1, 0.0])), 0);
+        assert_eq!(clusterer.assign(&unit(&[0.0, 0.1, 1.0])), 1);
+        assert_eq!(clusterer.assign(&unit(&[0.9, 0.2, 0.1])), 0);
+        assert_eq!(clusterer.assign(&unit(&[0.1, 0.0, 0.9])), 1);
+        assert_eq!(clusterer.speaker_count(), 2);
+        assert_eq!(clusterer.speakers()[0].segment_count, 2);
+    }
+
+    #[test]
+    fn test_threshold_controls_splitting() {
+        let a = unit(&[1.0, 0.0]);
+        let b = unit(&[0.7, 0.7]); // ~0.71 similar to a
+
+        let mut strict = SpeakerClusterer::new(0.9);
+        strict.assign(&a);
+        assert_eq!(strict.assign(&b), 1);
+
+        let mut loose = SpeakerClusterer::new(0.5);
+        loose.assign(&a);
+        assert_eq!(loose.assign(&b), 0);
+    }
+
+    #[test]
+    fn test_labels_are_one_based() {
+        assert_eq!(speaker_display_label(0), "Speaker 1");
+        assert_eq!(speaker_display_label(3), "Speaker 4");
+    }
+}
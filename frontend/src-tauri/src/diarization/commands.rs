@@ -0,0 +1,172 @@
+// diarization/commands.rs
+//
+// Speaker embedding model management (download, load, status) and the
+// Tauri command interface for the frontend.
+
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tokio::io::AsyncWriteExt;
+
+use super::embedding::SpeakerEmbeddingModel;
+
+const MODEL_FILE: &str = "wespeaker_resnet34.onnx";
+const MODEL_URL: &str =
+    "https://huggingface.co/Wespeaker/wespeaker-voxceleb-resnet34-LM/resolve/main/voxceleb_resnet34_LM.onnx";
+// Sanity floor for the downloaded file (the real model is ~25 MB)
+const MODEL_MIN_SIZE: u64 = 5 * 1024 * 1024;
+
+// Global speaker embedding model (loaded on first diarized recording)
+pub static EMBEDDING_MODEL: Mutex<Option<Arc<Mutex<SpeakerEmbeddingModel>>>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiarizationModelStatus {
+    pub downloaded: bool,
+    pub loaded: bool,
+    pub size_bytes: Option<u64>,
+}
+
+fn model_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("models").join("diarization").join(MODEL_FILE))
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+/// Return the loaded embedding model, loading it from disk if needed
+pub async fn get_or_load_embedding_model<R: Runtime>(
+    app: &AppHandle<R>,
+) -> Result<Arc<Mutex<SpeakerEmbeddingModel>>, String> {
+    if let Some(model) = EMBEDDING_MODEL.lock().unwrap().as_ref() {
+        return Ok(model.clone());
+    }
+
+    let path = model_path(app)?;
+    if !path.exists() {
+        return Err("Speaker diarization model not downloaded".to_string());
+    }
+
+    let model = tokio::task::spawn_blocking(move || SpeakerEmbeddingModel::new(&path))
+        .await
+        .map_err(|e| format!("Speaker model loading task failed: {}", e))?
+        .map_err(|e| format!("Failed to load speaker diarization model: {}", e))?;
+
+    let model = Arc::new(Mutex::new(model));
+    *EMBEDDING_MODEL.lock().unwrap() = Some(model.clone());
+    log::info!("✅ Speaker diarization model loaded");
+    Ok(model)
+}
+
+#[command]
+pub async fn diarization_get_model_status<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<DiarizationModelStatus, String> {
+    let path = model_path(&app)?;
+    let size_bytes = std::fs::metadata(&path).ok().map(|m| m.len());
+    Ok(DiarizationModelStatus {
+        downloaded: size_bytes.map_or(false, |size| size >= MODEL_MIN_SIZE),
+        loaded: EMBEDDING_MODEL.lock().unwrap().is_some(),
+        size_bytes,
+    })
+}
+
+#[command]
+pub async fn diarization_download_model<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let path = model_path(&app)?;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create diarization model directory: {}", e))?;
+    }
+
+    log::info!("Downloading speaker diarization model from {}", MODEL_URL);
+    let response = reqwest::Client::new()
+        .get(MODEL_URL)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download speaker diarization model: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to download speaker diarization model: HTTP {}",
+            response.status()
+        ));
+    }
+
+    let total_bytes = response.content_length().unwrap_or(0);
+    let part_path = path.with_extension("onnx.part");
+    let mut file = tokio::fs::File::create(&part_path)
+        .await
+        .map_err(|e| format!("Failed to create model file: {}", e))?;
+
+    let mut downloaded: u64 = 0;
+    let mut last_percent: u8 = 0;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| format!("Download interrupted: {}", e))?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| format!("Failed to write model file: {}", e))?;
+        downloaded += chunk.len() as u64;
+
+        let percent = if total_bytes > 0 {
+            ((downloaded * 100) / total_bytes).min(100) as u8
+        } else {
+            0
+        };
+        if percent > last_percent {
+            last_percent = percent;
+            let _ = app.emit(
+                "diarization-model-download-progress",
+                serde_json::json!({
+                    "progress": percent,
+                    "downloaded_bytes": downloaded,
+                    "total_bytes": total_bytes,
+                    "status": "downloading",
+                }),
+            );
+        }
+    }
+    file.flush()
+        .await
+        .map_err(|e| format!("Failed to write model file: {}", e))?;
+    drop(file);
+
+    if downloaded < MODEL_MIN_SIZE {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(format!(
+            "Downloaded speaker diarization model is too small ({} bytes)",
+            downloaded
+        ));
+    }
+
+    tokio::fs::rename(&part_path, &path)
+        .await
+        .map_err(|e| format!("Failed to finalize model file: {}", e))?;
+
+    log::info!("✅ Speaker diarization model downloaded ({} bytes)", downloaded);
+    let _ = app.emit(
+        "diarization-model-download-progress",
+        serde_json::json!({
+            "progress": 100,
+            "downloaded_bytes": downloaded,
+            "total_bytes": total_bytes,
+            "status": "completed",
+        }),
+    );
+    Ok(())
+}
+
+#[command]
+pub async fn diarization_delete_model<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    *EMBEDDING_MODEL.lock().unwrap() = None;
+
+    let path = model_path(&app)?;
+    if path.exists() {
+        tokio::fs::remove_file(&path)
+            .await
+            .map_err(|e| format!("Failed to delete speaker diarization model: {}", e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// diarization/embedding.rs
+//
+// ONNX speaker embedding model (WeSpeaker ResNet34 export, also compatible with
+// pyannote's WeSpeaker-based embedding). Turns a speech segment into a
+// fixed-size voice vector that can be compared with cosine similarity.
+
+use ndarray::Axis;
+use ort::execution_providers::CPUExecutionProvider;
+use ort::inputs;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::session::Session;
+use ort::value::TensorRef;
+use std::path::Path;
+
+use super::features::{compute_fbank, SAMPLE_RATE};
+
+/// Shortest segment that yields a usable embedding (0.5s)
+pub const MIN_EMBEDDING_SAMPLES: usize = SAMPLE_RATE as usize / 2;
+/// Longer segments are truncated to bound inference time (20s)
+const MAX_EMBEDDING_SAMPLES: usize = SAMPLE_RATE as usize * 20;
+
+#[derive(thiserror::Error, Debug)]
+pub enum DiarizationError {
+    #[error("ORT error")]
+    Ort(#[from] ort::Error),
+    #[error("I/O error")]
+    Io(#[from] std::io::Error),
+    #[error("ndarray shape error")]
+    Shape(#[from] ndarray::ShapeError),
+    #[error("Model has no outputs")]
+    OutputNotFound,
+    #[error("Segment too short for a speaker embedding: {samples} samples (minimum {minimum})")]
+    AudioTooShort { samples: usize, minimum: usize },
+}
+
+pub struct SpeakerEmbeddingModel {
+    session: Session,
+    output_name: String,
+}
+
+impl SpeakerEmbeddingModel {
+    pub fn new<P: AsRef<Path>>(model_path: P) -> Result<Self, DiarizationError> {
+        log::info!("Loading speaker embedding model from {}...", model_path.as_ref().display());
+
+        let session = Session::builder()?
+            .with_optimization_level(GraphOptimizationLevel::Level3)?
+            .with_execution_providers(vec![CPUExecutionProvider::default().build()])?
+            .with_intra_threads(2)?
+            .commit_from_file(model_path.as_ref())?;
+
+        let output_name = session
+            .outputs
+            .first()
+            .map(|output| output.name.clone())
+            .ok_or(DiarizationError::OutputNotFound)?;
+
+        Ok(Self {
+            session,
+            output_name,
+        })
+    }
+
+    /// Compute an L2-normalized embedding for a mono 16kHz speech segment
+    pub fn embed(&mut self, samples: &[f32]) -> Result<Vec<f32>, DiarizationError> {
+        if samples.len() < MIN_EMBEDDING_SAMPLES {
+            return Err(DiarizationError::AudioTooShort {
+                samples: samples.len(),
+                minimum: MIN_EMBEDDING_SAMPLES,
+            });
+        }
+
+        let samples = &samples[..samples.len().min(MAX_EMBEDDING_SAMPLES)];
+        // (frames, mel) -> (batch, frames, mel)
+        let features = compute_fbank(samples).insert_axis(Axis(0));
+
+        let outputs = self
+            .session
+            .run(inputs![TensorRef::from_array_view(features.view())?])?;
+        let embedding = outputs
+            .get(&self.output_name)
+            .ok_or(DiarizationError::OutputNotFound)?
+            .try_extract_array::<f32>()?;
+
+        let mut embedding: Vec<f32> = embedding.iter().copied().collect();
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            embedding.iter_mut().for_each(|x| *x /= norm);
+        }
+        Ok(embedding)
+    }
+}
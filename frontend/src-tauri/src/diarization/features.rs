@@ -0,0 +1,149 @@
+// diarization/features.rs
+//
+// Kaldi-compatible log mel filterbank features, the input format expected by
+// WeSpeaker / pyannote speaker embedding models.
+
+use ndarray::Array2;
+use realfft::RealFftPlanner;
+
+pub const SAMPLE_RATE: u32 = 16000;
+pub const NUM_MEL_BINS: usize = 80;
+// 25ms frames every 10ms at 16kHz
+const FRAME_LENGTH: usize = 400;
+const FRAME_SHIFT: usize = 160;
+const FFT_SIZE: usize = 512;
+const PREEMPHASIS: f32 = 0.97;
+const LOW_FREQ: f32 = 20.0;
+const HIGH_FREQ: f32 = 8000.0;
+
+/// Compute log mel filterbank features with per-utterance mean normalization
+///
+/// Returns a (frames, NUM_MEL_BINS) matrix; empty if the audio is shorter than one frame.
+pub fn compute_fbank(samples: &[f32]) -> Array2<f32> {
+    let mut features = log_mel_frames(samples);
+
+    // Cepstral mean normalization across the utterance
+    if let Some(mean) = features.mean_axis(ndarray::Axis(0)) {
+        features -= &mean;
+    }
+    features
+}
+
+fn log_mel_frames(samples: &[f32]) -> Array2<f32> {
+    if samples.len() < FRAME_LENGTH {
+        return Array2::zeros((0, NUM_MEL_BINS));
+    }
+
+    let num_frames = 1 + (samples.len() - FRAME_LENGTH) / FRAME_SHIFT;
+    let window = povey_window();
+    let filters = mel_filterbank();
+
+    let mut planner = RealFftPlanner::<f32>::new();
+    let fft = planner.plan_fft_forward(FFT_SIZE);
+    let mut frame = fft.make_input_vec();
+    let mut spectrum = fft.make_output_vec();
+
+    let mut features = Array2::<f32>::zeros((num_frames, NUM_MEL_BINS));
+    for f in 0..num_frames {
+        let start = f * FRAME_SHIFT;
+        // Models are trained on int16-scaled waveforms
+        let raw: Vec<f32> = samples[start..start + FRAME_LENGTH]
+            .iter()
+            .map(|&s| s * 32768.0)
+            .collect();
+
+        let mean = raw.iter().sum::<f32>() / FRAME_LENGTH as f32;
+        frame.iter_mut().for_each(|x| *x = 0.0);
+        for i in 0..FRAME_LENGTH {
+            let prev = raw[i.saturating_sub(1)] - mean;
+            frame[i] = ((raw[i] - mean) - PREEMPHASIS * prev) * window[i];
+        }
+
+        if fft.process(&mut frame, &mut spectrum).is_err() {
+            continue;
+        }
+
+        let power: Vec<f32> = spectrum.iter().map(|c| c.norm_sqr()).collect();
+        for (m, filter) in filters.iter().enumerate() {
+            let energy: f32 = filter.iter().map(|&(bin, weight)| power[bin] * weight).sum();
+            features[[f, m]] = energy.max(f32::EPSILON).ln();
+        }
+    }
+    features
+}
+
+fn povey_window() -> Vec<f32> {
+    (0..FRAME_LENGTH)
+        .map(|i| {
+            let hann = 0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LENGTH - 1) as f32).cos();
+            hann.powf(0.85)
+        })
+        .collect()
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    1127.0 * (1.0 + hz / 700.0).ln()
+}
+
+/// Triangular mel filters as sparse (fft bin, weight) lists
+fn mel_filterbank() -> Vec<Vec<(usize, f32)>> {
+    let bin_width = SAMPLE_RATE as f32 / FFT_SIZE as f32;
+    let mel_low = hz_to_mel(LOW_FREQ);
+    let mel_high = hz_to_mel(HIGH_FREQ);
+    let mel_delta = (mel_high - mel_low) / (NUM_MEL_BINS + 1) as f32;
+
+    (0..NUM_MEL_BINS)
+        .map(|m| {
+            let left = mel_low + m as f32 * mel_delta;
+            let center = left + mel_delta;
+            let right = center + mel_delta;
+
+            // Kaldi ignores the Nyquist bin
+            (0..FFT_SIZE / 2)
+                .filter_map(|bin| {
+                    let mel = hz_to_mel(bin as f32 * bin_width);
+                    if mel <= left || mel >= right {
+                        None
+                    } else if mel <= center {
+                        Some((bin, (mel - left) / (center - left)))
+                    } else {
+                        Some((bin, (right - mel) / (right - center)))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_count() {
+        let samples = vec![0.0f32; 16000];
+        let features = compute_fbank(&samples);
+        assert_eq!(features.dim(), (98, NUM_MEL_BINS));
+        assert_eq!(compute_fbank(&samples[..300]).dim(), (0, NUM_MEL_BINS));
+    }
+
+    #[test]
+    fn test_tone_energy_lands_in_matching_band() {
+        let samples: Vec<f32> = (0..16000)
+            .map(|i| 0.3 * (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 16000.0).sin())
+            .collect();
+
+        // Before mean normalization the 1kHz band must dominate every frame
+        let features = log_mel_frames(&samples);
+        let frame = features.row(50);
+        let peak = frame
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .map(|(i, _)| i)
+            .unwrap();
+        let filters = mel_filterbank();
+        let tone_bin = (1000.0 / (SAMPLE_RATE as f32 / FFT_SIZE as f32)) as usize;
+        assert!(filters[peak].iter().any(|&(bin, _)| bin.abs_diff(tone_bin) <= 1));
+    }
+}
@@ -0,0 +1,254 @@
+// diarization/fingerprints.rs
+//
+// Cross-meeting voice fingerprints: the centroid embedding of every diarized speaker
+// is stored with the meeting, and speakers whose voices match across meetings share a
+// voice group. Naming a speaker once ("Speaker 2" -> "Ana") then carries over to the
+// same voice in earlier and later meetings that are still unlabeled.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::{command, AppHandle, Manager, Runtime};
+use uuid::Uuid;
+
+use super::clustering::cosine_similarity;
+use super::profiles::{embedding_from_bytes, embedding_to_bytes};
+use crate::database::repositories::meeting_speaker::MeetingSpeakersRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+/// Similarity two meeting-level centroids need to be considered the same voice.
+/// Stricter than live profile matching since a wrong link renames past meetings.
+pub const FINGERPRINT_MATCH_THRESHOLD: f32 = 0.65;
+/// Written to the meeting folder at the end of a diarized recording
+pub const SPEAKERS_FILE: &str = "speakers.json";
+
+static GENERIC_LABEL: Lazy<Regex> = Lazy::new(|| Regex::new(r"^Speaker \d+$").unwrap());
+
+// Speakers of the last finished recording, waiting for its meeting folder
+static PENDING_SESSION_SPEAKERS: Mutex<Option<Vec<SessionSpeaker>>> = Mutex::new(None);
+
+/// One diarized speaker of a recording with its centroid embedding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSpeaker {
+    pub label: String,
+    pub embedding: Vec<f32>,
+    pub segment_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedSpeaker {
+    pub label: String,
+    pub other_meeting_id: String,
+    pub other_meeting_title: String,
+    pub other_meeting_date: String,
+    pub other_label: String,
+}
+
+/// True for auto-assigned labels ("Speaker 3") that a user hasn't named yet
+pub fn is_generic_speaker_label(label: &str) -> bool {
+    GENERIC_LABEL.is_match(label)
+}
+
+/// Hold the speakers of a just-finished recording until stop_recording knows its folder
+pub fn stash_session_speakers(speakers: Vec<SessionSpeaker>) {
+    *PENDING_SESSION_SPEAKERS.lock().unwrap() = Some(speakers);
+}
+
+/// Write the stashed speakers to the meeting folder so they can be imported with the meeting
+pub fn write_pending_session_speakers(folder: &Path) {
+    let speakers = match PENDING_SESSION_SPEAKERS.lock().unwrap().take() {
+        Some(speakers) if !speakers.is_empty() => speakers,
+        _ => return,
+    };
+    let result = serde_json::to_string_pretty(&speakers)
+        .map_err(|e| e.to_string())
+        .and_then(|json| std::fs::write(folder.join(SPEAKERS_FILE), json).map_err(|e| e.to_string()));
+    match result {
+        Ok(()) => info!("🗣️ Saved {} speaker fingerprint(s) with the recording", speakers.len()),
+        Err(e) => warn!("Failed to save speaker fingerprints: {}", e),
+    }
+}
+
+/// Index of the candidate closest to `embedding`, if it clears the match threshold
+fn best_fingerprint_match(embedding: &[f32], candidates: &[Vec<f32>]) -> Option<usize> {
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(i, candidate)| (i, cosine_similarity(candidate, embedding)))
+        .filter(|(_, similarity)| *similarity >= FINGERPRINT_MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+}
+
+/// Most recent user-given name in a voice group, if any
+async fn group_name(pool: &SqlitePool, voice_group_id: &str) -> Option<String> {
+    match MeetingSpeakersRepository::list_group(pool, voice_group_id).await {
+        Ok(group) => group
+            .into_iter()
+            .rev()
+            .map(|f| f.label)
+            .find(|label| !is_generic_speaker_label(label)),
+        Err(e) => {
+            warn!("Failed to load voice group {}: {}", voice_group_id, e);
+            None
+        }
+    }
+}
+
+/// Store a saved meeting's speaker fingerprints, link them to matching voices from other
+/// meetings and apply names already given to those voices. Returns the (from, to) renames.
+pub async fn import_meeting_speakers(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    folder: &Path,
+) -> Result<Vec<(String, String)>, String> {
+    let path = folder.join(SPEAKERS_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", SPEAKERS_FILE, e))?;
+    let speakers: Vec<SessionSpeaker> =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", SPEAKERS_FILE, e))?;
+
+    let candidates = MeetingSpeakersRepository::list_other_meetings(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load speaker fingerprints: {}", e))?;
+    let candidate_embeddings: Vec<Vec<f32>> = candidates
+        .iter()
+        .map(|c| embedding_from_bytes(&c.embedding))
+        .collect();
+
+    let mut used_labels: Vec<String> = speakers.iter().map(|s| s.label.clone()).collect();
+    let mut renames = Vec::new();
+    for speaker in &speakers {
+        let voice_group_id = match best_fingerprint_match(&speaker.embedding, &candidate_embeddings) {
+            Some(index) => candidates[index].voice_group_id.clone(),
+            None => format!("voice-group-{}", Uuid::new_v4()),
+        };
+
+        // Reuse a name given to this voice elsewhere, unless it would collide with another speaker here
+        let mut label = speaker.label.clone();
+        if is_generic_speaker_label(&label) {
+            if let Some(name) = group_name(pool, &voice_group_id).await {
+                if !used_labels.contains(&name) {
+                    TranscriptsRepository::relabel_speaker(pool, meeting_id, &label, &name)
+                        .await
+                        .map_err(|e| format!("Failed to apply speaker name: {}", e))?;
+                    info!("🗣️ Recognized {} in {} as '{}' from an earlier meeting", label, meeting_id, name);
+                    renames.push((label.clone(), name.clone()));
+                    used_labels.push(name.clone());
+                    label = name;
+                }
+            }
+        }
+
+        MeetingSpeakersRepository::insert(
+            pool,
+            meeting_id,
+            &label,
+            &embedding_to_bytes(&speaker.embedding),
+            speaker.segment_count as i64,
+            &voice_group_id,
+        )
+        .await
+        .map_err(|e| format!("Failed to save speaker fingerprint: {}", e))?;
+    }
+    Ok(renames)
+}
+
+/// After a speaker of `meeting_id` was named `name`, give the same name to still-unlabeled
+/// fingerprints of that voice in other meetings. Returns (meeting id, old label) per change.
+pub async fn propagate_speaker_name(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    name: &str,
+) -> Result<Vec<(String, String)>, String> {
+    if is_generic_speaker_label(name) {
+        return Ok(Vec::new());
+    }
+    let own = MeetingSpeakersRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load speaker fingerprints: {}", e))?;
+    let voice_group_id = match own.into_iter().find(|f| f.label == name) {
+        Some(fingerprint) => fingerprint.voice_group_id,
+        None => return Ok(Vec::new()),
+    };
+
+    let group = MeetingSpeakersRepository::list_group(pool, &voice_group_id)
+        .await
+        .map_err(|e| format!("Failed to load voice group: {}", e))?;
+    let mut changed = Vec::new();
+    for fingerprint in group {
+        if fingerprint.meeting_id == meeting_id || !is_generic_speaker_label(&fingerprint.label) {
+            continue;
+        }
+        // Don't silently merge two speakers of another meeting
+        let taken = TranscriptsRepository::list_speakers(pool, &fingerprint.meeting_id)
+            .await
+            .map_err(|e| format!("Failed to list speakers: {}", e))?
+            .iter()
+            .any(|(label, _, _)| label == name);
+        if taken {
+            continue;
+        }
+
+        TranscriptsRepository::relabel_speaker(pool, &fingerprint.meeting_id, &fingerprint.label, name)
+            .await
+            .map_err(|e| format!("Failed to rename speaker: {}", e))?;
+        MeetingSpeakersRepository::rename_label(pool, &fingerprint.meeting_id, &fingerprint.label, name)
+            .await
+            .map_err(|e| format!("Failed to update speaker fingerprint: {}", e))?;
+        info!(
+            "🗣️ Named {} in meeting {} '{}' (same voice)",
+            fingerprint.label, fingerprint.meeting_id, name
+        );
+        changed.push((fingerprint.meeting_id, fingerprint.label));
+    }
+    Ok(changed)
+}
+
+/// Speakers of a meeting that were recognized in other meetings
+#[command]
+pub async fn meeting_linked_speakers<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<LinkedSpeaker>, String> {
+    let state = app.state::<AppState>();
+    let rows = MeetingSpeakersRepository::list_linked_speakers(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load linked speakers: {}", e))?;
+    Ok(rows
+        .into_iter()
+        .map(|(label, other_meeting_id, other_meeting_title, date, other_label)| LinkedSpeaker {
+            label,
+            other_meeting_id,
+            other_meeting_title,
+            other_meeting_date: date.0.to_rfc3339(),
+            other_label,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generic_labels() {
+        assert!(is_generic_speaker_label("Speaker 12"));
+        assert!(!is_generic_speaker_label("Ana"));
+        assert!(!is_generic_speaker_label("Speaker 2 (guest)"));
+    }
+
+    #[test]
+    fn test_best_fingerprint_match() {
+        let candidates = vec![vec![1.0, 0.0], vec![0.6, 0.8]];
+        assert_eq!(best_fingerprint_match(&[0.7, 0.7], &candidates), Some(1));
+        assert_eq!(best_fingerprint_match(&[0.0, -1.0], &candidates), None);
+    }
+}
@@ -0,0 +1,34 @@
+//! Speaker diarization module.
+//!
+//! Assigns speaker labels ("Speaker 1", "Speaker 2", ...) to transcript segments
+//! using an ONNX speaker embedding model and online clustering.
+//!
+//! # Module Structure
+//!
+//! - `features`: Kaldi-style log mel filterbank front end
+//! - `embedding`: ONNX speaker embedding model wrapper
+//! - `clustering`: Online clustering of embeddings into speakers
+//! - `profiles`: Enrolled voice profiles matched against diarized speakers
+//! - `fingerprints`: Per-meeting speaker embeddings linked across meetings
+//! - `overlap`: Detection of segments spoken over another speaker
+//! - `session`: Per-recording diarizer used by the transcription worker
+//! - `speaker_edits`: Rename, merge and reassign speakers after a meeting
+//! - `talk_time`: Per-speaker talk time, turns and longest monologue
+//! - `commands`: Model management and Tauri command interface
+
+pub mod features;
+pub mod embedding;
+pub mod clustering;
+pub mod profiles;
+pub mod fingerprints;
+pub mod overlap;
+pub mod session;
+pub mod speaker_edits;
+pub mod talk_time;
+pub mod commands;
+
+pub use embedding::{DiarizationError, SpeakerEmbeddingModel};
+pub use clustering::{cosine_similarity, speaker_display_label, SpeakerClusterer};
+pub use profiles::{ProfileMatcher, VoiceProfileInfo};
+pub use session::SessionDiarizer;
+pub use commands::*;
@@ -0,0 +1,79 @@
+// diarization/overlap.rs
+//
+// Overlapping-speech detection. When mic and system audio are transcribed separately,
+// each channel is segmented independently, so segments from different speakers whose
+// time ranges intersect mark places where people talked over each other. Those are the
+// least reliable parts of a transcript, so affected segments are flagged for review.
+
+/// Shorter intersections are usually just VAD padding at turn boundaries
+pub const MIN_OVERLAP_SECONDS: f64 = 0.5;
+
+/// Length of the intersection of two time ranges (0 if they don't intersect)
+pub fn overlap_seconds(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.1.min(b.1) - a.0.max(b.0)).max(0.0)
+}
+
+/// For each (start, end, speaker) span, whether it overlaps a span from a different speaker.
+/// Spans without a speaker (mixed audio) are never flagged.
+pub fn overlapping_flags(spans: &[(f64, f64, Option<&str>)]) -> Vec<bool> {
+    let mut flags = vec![false; spans.len()];
+    let mut order: Vec<usize> = (0..spans.len()).collect();
+    order.sort_by(|&a, &b| {
+        spans[a]
+            .0
+            .partial_cmp(&spans[b].0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    for (position, &i) in order.iter().enumerate() {
+        let (start, end, speaker) = spans[i];
+        let speaker = match speaker {
+            Some(speaker) => speaker,
+            None => continue,
+        };
+        // Sorted by start: stop once later spans begin after this one ends
+        for &j in order[position + 1..].iter() {
+            let (other_start, other_end, other_speaker) = spans[j];
+            if other_start >= end {
+                break;
+            }
+            match other_speaker {
+                Some(other) if other != speaker => {}
+                _ => continue,
+            }
+            if overlap_seconds((start, end), (other_start, other_end)) >= MIN_OVERLAP_SECONDS {
+                flags[i] = true;
+                flags[j] = true;
+            }
+        }
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flags_cross_speaker_overlap_only() {
+        let spans = [
+            (0.0, 4.0, Some("Me")),
+            (3.0, 6.0, Some("Remote")),  // talks over the end of the first segment
+            (6.0, 8.0, Some("Me")),      // touches but doesn't overlap
+            (10.0, 14.0, Some("Remote")),
+            (11.0, 12.0, Some("Remote")), // same speaker, not an overlap
+            (13.8, 15.0, Some("Me")),     // below the minimum overlap
+            (20.0, 25.0, None),
+        ];
+        assert_eq!(
+            overlapping_flags(&spans),
+            vec![true, true, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn test_overlap_seconds() {
+        assert_eq!(overlap_seconds((0.0, 4.0), (3.0, 6.0)), 1.0);
+        assert_eq!(overlap_seconds((0.0, 2.0), (3.0, 6.0)), 0.0);
+    }
+}
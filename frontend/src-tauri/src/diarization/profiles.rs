@@ -0,0 +1,295 @@
+// diarization/profiles.rs
+//
+// Voice profiles: users enroll a short sample of their own voice (or a colleague's)
+// and diarized speakers whose voice matches a profile are labeled with that name
+// instead of "Speaker N".
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::clustering::cosine_similarity;
+use super::commands::get_or_load_embedding_model;
+use super::features::SAMPLE_RATE;
+use crate::database::models::VoiceProfile;
+use crate::database::repositories::voice_profile::VoiceProfilesRepository;
+use crate::state::AppState;
+
+/// Similarity a speaker centroid needs to be attributed to an enrolled voice
+pub const PROFILE_MATCH_THRESHOLD: f32 = 0.6;
+/// Enrollment samples need a few seconds of speech for a stable embedding
+const MIN_ENROLLMENT_SECONDS: f32 = 3.0;
+
+/// Voice profile as shown in the UI (embedding omitted)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceProfileInfo {
+    pub id: String,
+    pub name: String,
+    pub sample_count: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&VoiceProfile> for VoiceProfileInfo {
+    fn from(profile: &VoiceProfile) -> Self {
+        Self {
+            id: profile.id.clone(),
+            name: profile.name.clone(),
+            sample_count: profile.sample_count,
+            created_at: profile.created_at.to_rfc3339(),
+            updated_at: profile.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+pub fn embedding_to_bytes(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|v| v.to_le_bytes()).collect()
+}
+
+pub fn embedding_from_bytes(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+/// Enrolled voices loaded for matching during a recording
+#[derive(Debug, Clone, Default)]
+pub struct ProfileMatcher {
+    profiles: Vec<(String, Vec<f32>)>,
+    threshold: f32,
+}
+
+impl ProfileMatcher {
+    pub fn new(profiles: Vec<(String, Vec<f32>)>) -> Self {
+        Self {
+            profiles,
+            threshold: PROFILE_MATCH_THRESHOLD,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// Name of the enrolled voice closest to `embedding`, if it clears the threshold
+    pub fn best_match(&self, embedding: &[f32]) -> Option<&str> {
+        self.profiles
+            .iter()
+            .map(|(name, profile)| (name, cosine_similarity(profile, embedding)))
+            .filter(|(_, similarity)| *similarity >= self.threshold)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Load all enrolled voices from the database
+pub async fn load_profile_matcher(pool: &SqlitePool) -> ProfileMatcher {
+    match VoiceProfilesRepository::list(pool).await {
+        Ok(profiles) => ProfileMatcher::new(
+            profiles
+                .into_iter()
+                .map(|p| (p.name, embedding_from_bytes(&p.embedding)))
+                .collect(),
+        ),
+        Err(e) => {
+            warn!("Failed to load voice profiles: {}", e);
+            ProfileMatcher::default()
+        }
+    }
+}
+
+/// Embed an enrollment sample and add it to the named profile (created if new)
+async fn enroll<R: Runtime>(
+    app: &AppHandle<R>,
+    name: &str,
+    samples: Vec<f32>,
+    sample_rate: u32,
+) -> Result<VoiceProfileInfo, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Voice profile name cannot be empty".to_string());
+    }
+
+    let samples = if sample_rate != SAMPLE_RATE {
+        crate::audio::audio_processing::resample_audio(&samples, sample_rate, SAMPLE_RATE)
+    } else {
+        samples
+    };
+    let seconds = samples.len() as f32 / SAMPLE_RATE as f32;
+    if seconds < MIN_ENROLLMENT_SECONDS {
+        return Err(format!(
+            "Voice sample is too short ({:.1}s); record at least {:.0} seconds of speech",
+            seconds, MIN_ENROLLMENT_SECONDS
+        ));
+    }
+
+    let model = get_or_load_embedding_model(app).await?;
+    let embedding = tokio::task::spawn_blocking(move || model.lock().unwrap().embed(&samples))
+        .await
+        .map_err(|e| format!("Voice enrollment task failed: {}", e))?
+        .map_err(|e| format!("Failed to compute voice embedding: {}", e))?;
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let existing = VoiceProfilesRepository::get_by_name(pool, name)
+        .await
+        .map_err(|e| format!("Failed to look up voice profile: {}", e))?;
+
+    let id = match existing {
+        Some(profile) => {
+            // Running mean over all enrollment samples for this person
+            let previous = embedding_from_bytes(&profile.embedding);
+            let count = profile.sample_count.max(1) as f32;
+            let mut merged: Vec<f32> = previous
+                .iter()
+                .zip(&embedding)
+                .map(|(p, e)| p * count + e)
+                .collect();
+            let norm = merged.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                merged.iter_mut().for_each(|x| *x /= norm);
+            }
+
+            VoiceProfilesRepository::update_embedding(
+                pool,
+                &profile.id,
+                &embedding_to_bytes(&merged),
+                profile.sample_count + 1,
+            )
+            .await
+            .map_err(|e| format!("Failed to update voice profile: {}", e))?;
+            info!("🗣️ Added enrollment sample {} to voice profile '{}'", profile.sample_count + 1, name);
+            profile.id
+        }
+        None => {
+            let id = VoiceProfilesRepository::create(pool, name, &embedding_to_bytes(&embedding))
+                .await
+                .map_err(|e| format!("Failed to create voice profile: {}", e))?;
+            info!("🗣️ Created voice profile '{}'", name);
+            id
+        }
+    };
+
+    let profile = VoiceProfilesRepository::get(pool, &id)
+        .await
+        .map_err(|e| format!("Failed to load voice profile: {}", e))?
+        .ok_or_else(|| format!("Voice profile {} not found", id))?;
+    Ok(VoiceProfileInfo::from(&profile))
+}
+
+/// Read a WAV file as mono f32 samples
+fn read_wav_mono(path: &str) -> Result<(Vec<f32>, u32), String> {
+    let mut reader =
+        hound::WavReader::open(path).map_err(|e| format!("Failed to open audio file: {}", e))?;
+    let spec = reader.spec();
+
+    let interleaved: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("Failed to read audio file: {}", e))?,
+        hound::SampleFormat::Int => {
+            let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f32 / scale))
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Failed to read audio file: {}", e))?
+        }
+    };
+
+    let channels = spec.channels.max(1) as usize;
+    let mono = interleaved
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok((mono, spec.sample_rate))
+}
+
+#[command]
+pub async fn voice_profile_list<R: Runtime>(app: AppHandle<R>) -> Result<Vec<VoiceProfileInfo>, String> {
+    let state = app.state::<AppState>();
+    let profiles = VoiceProfilesRepository::list(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to list voice profiles: {}", e))?;
+    Ok(profiles.iter().map(VoiceProfileInfo::from).collect())
+}
+
+/// Enroll raw samples recorded by the frontend
+#[command]
+pub async fn voice_profile_enroll<R: Runtime>(
+    app: AppHandle<R>,
+    name: String,
+    samples: Vec<f32>,
+    sample_rate: u32,
+) -> Result<VoiceProfileInfo, String> {
+    enroll(&app, &name, samples, sample_rate).await
+}
+
+/// Enroll from a WAV file on disk
+#[command]
+pub async fn voice_profile_enroll_file<R: Runtime>(
+    app: AppHandle<R>,
+    name: String,
+    path: String,
+) -> Result<VoiceProfileInfo, String> {
+    let (samples, sample_rate) = read_wav_mono(&path)?;
+    enroll(&app, &name, samples, sample_rate).await
+}
+
+#[command]
+pub async fn voice_profile_rename<R: Runtime>(
+    app: AppHandle<R>,
+    id: String,
+    name: String,
+) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Voice profile name cannot be empty".to_string());
+    }
+    let state = app.state::<AppState>();
+    match VoiceProfilesRepository::rename(state.db_manager.pool(), &id, name).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(format!("Voice profile {} not found", id)),
+        Err(e) => Err(format!("Failed to rename voice profile: {}", e)),
+    }
+}
+
+#[command]
+pub async fn voice_profile_delete<R: Runtime>(app: AppHandle<R>, id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    match VoiceProfilesRepository::delete(state.db_manager.pool(), &id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(format!("Voice profile {} not found", id)),
+        Err(e) => Err(format!("Failed to delete voice profile: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_bytes_round_trip() {
+        let embedding = vec![0.25f32, -1.5, 3.0e-5, 0.0];
+        assert_eq!(embedding_from_bytes(&embedding_to_bytes(&embedding)), embedding);
+    }
+
+    #[test]
+    fn test_best_match_respects_threshold() {
+        let matcher = ProfileMatcher::new(vec![
+            ("Marc".to_string(), vec![1.0, 0.0, 0.0]),
+            ("Ana".to_string(), vec![0.0, 1.0, 0.0]),
+        ]);
+        assert_eq!(matcher.best_match(&[0.9, 0.1, 0.0]), Some("Marc"));
+        assert_eq!(matcher.best_match(&[0.1, 0.8, 0.2]), Some("Ana"));
+        assert_eq!(matcher.best_match(&[0.0, 0.0, 1.0]), None);
+    }
+}
@@ -0,0 +1,154 @@
+// diarization/session.rs
+//
+// Per-recording diarizer used by the transcription worker: embeds each
+// finalized segment and assigns it a speaker label, using the enrolled
+// person's name when the speaker matches a voice profile.
+
+use log::{info, warn};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use super::clustering::{speaker_display_label, SpeakerClusterer};
+use super::commands::get_or_load_embedding_model;
+use super::embedding::{DiarizationError, SpeakerEmbeddingModel};
+use super::fingerprints::SessionSpeaker;
+use super::profiles::{load_profile_matcher, ProfileMatcher};
+use crate::state::AppState;
+
+/// A cluster needs this many segments before it counts as a participant, so a single
+/// noisy segment doesn't bump the live estimate
+const MIN_SEGMENTS_PER_PARTICIPANT: usize = 2;
+
+pub struct SessionDiarizer {
+    model: Arc<Mutex<SpeakerEmbeddingModel>>,
+    clusterer: Mutex<SpeakerClusterer>,
+    profiles: ProfileMatcher,
+    // Local user heard on the mic channel (split mode, where mic audio isn't clustered)
+    local_speaker_heard: AtomicBool,
+    last_reported_participants: AtomicUsize,
+}
+
+impl SessionDiarizer {
+    /// Create a diarizer for a new recording if diarization is enabled and the model is available
+    pub async fn start<R: Runtime>(app: &AppHandle<R>) -> Option<Arc<Self>> {
+        let enabled = crate::audio::recording_preferences::load_recording_preferences(app)
+            .await
+            .map(|prefs| prefs.speaker_diarization)
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        match get_or_load_embedding_model(app).await {
+            Ok(model) => {
+                let profiles = match app.try_state::<AppState>() {
+                    Some(state) => load_profile_matcher(state.db_manager.pool()).await,
+                    None => ProfileMatcher::default(),
+                };
+                info!(
+                    "🗣️ Speaker diarization enabled for this recording ({} voice profile(s))",
+                    profiles.len()
+                );
+                Some(Arc::new(Self {
+                    model,
+                    clusterer: Mutex::new(SpeakerClusterer::default()),
+                    profiles,
+                    local_speaker_heard: AtomicBool::new(false),
+                    last_reported_participants: AtomicUsize::new(0),
+                }))
+            }
+            Err(e) => {
+                warn!("⚠️ Speaker diarization disabled for this recording: {}", e);
+                let _ = app.emit("transcription-warning", format!("Speaker diarization unavailable: {}", e));
+                None
+            }
+        }
+    }
+
+    /// Speaker label for a segment (profile name or "Speaker N"), or None if it is too short
+    /// or inference failed
+    pub async fn label_segment(&self, samples: &[f32]) -> Option<String> {
+        let model = self.model.clone();
+        let samples = samples.to_vec();
+        let embedding = tokio::task::spawn_blocking(move || model.lock().unwrap().embed(&samples)).await;
+
+        match embedding {
+            Ok(Ok(embedding)) => {
+                let (index, centroid) = {
+                    let mut clusterer = self.clusterer.lock().unwrap();
+                    let index = clusterer.assign(&embedding);
+                    (index, clusterer.speakers()[index].centroid())
+                };
+                Some(self.cluster_label(index, &centroid))
+            }
+            Ok(Err(DiarizationError::AudioTooShort { .. })) => None,
+            Ok(Err(e)) => {
+                warn!("Speaker embedding failed: {}", e);
+                None
+            }
+            Err(e) => {
+                warn!("Speaker embedding task failed: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Profile name or "Speaker N" for a cluster. Matches the whole cluster rather than
+    /// a single segment, which is noisier.
+    fn cluster_label(&self, index: usize, centroid: &[f32]) -> String {
+        match self.profiles.best_match(centroid) {
+            Some(name) => name.to_string(),
+            None => speaker_display_label(index),
+        }
+    }
+
+    /// Final speakers of the session with their centroids, for cross-meeting matching
+    pub fn session_speakers(&self) -> Vec<SessionSpeaker> {
+        let clusterer = self.clusterer.lock().unwrap();
+        clusterer
+            .speakers()
+            .iter()
+            .enumerate()
+            .map(|(index, speaker)| {
+                let centroid = speaker.centroid();
+                SessionSpeaker {
+                    label: self.cluster_label(index, &centroid),
+                    embedding: centroid,
+                    segment_count: speaker.segment_count,
+                }
+            })
+            .collect()
+    }
+
+    /// Number of distinct speakers heard so far
+    pub fn speaker_count(&self) -> usize {
+        self.clusterer.lock().unwrap().speaker_count()
+    }
+
+    /// Record that the local user spoke on the (separately transcribed) microphone channel
+    pub fn note_local_speech(&self) {
+        self.local_speaker_heard.store(true, Ordering::SeqCst);
+    }
+
+    /// Estimated number of participants heard so far: established speaker clusters
+    /// plus the local user when their mic is transcribed separately
+    pub fn estimated_participants(&self) -> usize {
+        let clustered = self
+            .clusterer
+            .lock()
+            .unwrap()
+            .speakers()
+            .iter()
+            .filter(|s| s.segment_count >= MIN_SEGMENTS_PER_PARTICIPANT)
+            .count();
+        clustered + usize::from(self.local_speaker_heard.load(Ordering::SeqCst))
+    }
+
+    /// New participant estimate if it changed since the last call
+    pub fn participant_count_change(&self) -> Option<usize> {
+        let count = self.estimated_participants();
+        let previous = self.last_reported_participants.swap(count, Ordering::SeqCst);
+        (count != previous).then_some(count)
+    }
+}
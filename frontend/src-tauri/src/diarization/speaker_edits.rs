@@ -0,0 +1,385 @@
+// diarization/speaker_edits.rs
+//
+// Manual corrections to diarization results: rename a speaker, merge two speakers
+// that were over-split, or reassign a time range to another speaker. Changes are
+// written to the database and propagated to the meeting folder's transcripts.json
+// and, for renames/merges, to the stored summary text and to the same voice in
+// other meetings.
+
+use log::{info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use tauri::{command, AppHandle, Emitter, Runtime};
+
+use crate::audio::recording_saver::{format_labeled_transcript, speaker_label, TranscriptSegment};
+use super::fingerprints::propagate_speaker_name;
+use crate::database::models::MeetingParticipant;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_speaker::MeetingSpeakersRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingSpeaker {
+    pub label: String,
+    pub segment_count: i64,
+    pub total_duration: f64,
+}
+
+/// Replace whole-word occurrences of `from` with `to` in every string of a JSON value
+fn replace_label_in_json(value: &mut serde_json::Value, pattern: &Regex, to: &str) -> bool {
+    match value {
+        serde_json::Value::String(text) => {
+            if pattern.is_match(text) {
+                *text = pattern.replace_all(text, regex::NoExpand(to)).into_owned();
+                true
+            } else {
+                false
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .fold(false, |changed, item| replace_label_in_json(item, pattern, to) || changed),
+        serde_json::Value::Object(map) => map
+            .values_mut()
+            .fold(false, |changed, item| replace_label_in_json(item, pattern, to) || changed),
+        _ => false,
+    }
+}
+
+fn label_pattern(label: &str) -> Result<Regex, String> {
+    Regex::new(&format!(r"\b{}\b", regex::escape(label)))
+        .map_err(|e| format!("Invalid speaker label '{}': {}", label, e))
+}
+
+/// Rewrite speaker labels in the stored summary after a rename/merge
+async fn propagate_to_summary(pool: &SqlitePool, meeting_id: &str, from: &str, to: &str) -> Result<(), String> {
+    let process = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?;
+    let result = match process.and_then(|p| p.result) {
+        Some(result) => result,
+        None => return Ok(()),
+    };
+
+    let mut summary: serde_json::Value = match serde_json::from_str(&result) {
+        Ok(summary) => summary,
+        Err(e) => {
+            warn!("Summary for {} is not valid JSON, leaving speaker names untouched: {}", meeting_id, e);
+            return Ok(());
+        }
+    };
+
+    if replace_label_in_json(&mut summary, &label_pattern(from)?, to) {
+        SummaryProcessesRepository::update_meeting_summary(pool, meeting_id, &summary)
+            .await
+            .map_err(|e| format!("Failed to update summary: {}", e))?;
+        info!("Updated speaker '{}' -> '{}' in summary for {}", from, to, meeting_id);
+    }
+    Ok(())
+}
+
+/// Sync speaker labels in the meeting folder's transcripts.json with the database
+async fn propagate_to_transcript_file(pool: &SqlitePool, meeting_id: &str) -> Result<(), String> {
+    let folder = match MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .and_then(|m| m.folder_path)
+    {
+        Some(folder) => folder,
+        None => return Ok(()),
+    };
+    let path = Path::new(&folder).join("transcripts.json");
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let meeting = MeetingsRepository::get_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting transcripts: {}", e))?;
+    let labels: Vec<(f64, Option<String>)> = meeting
+        .map(|m| m.transcripts)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|t| {
+            let label = t.speaker_label.or_else(|| t.speaker.as_deref().map(|s| speaker_label(s).to_string()));
+            t.audio_start_time.map(|start| (start, label))
+        })
+        .collect();
+
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read transcripts.json: {}", e))?;
+    let mut json: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse transcripts.json: {}", e))?;
+    let mut segments: Vec<TranscriptSegment> = serde_json::from_value(json["segments"].take())
+        .map_err(|e| format!("Failed to parse transcript segments: {}", e))?;
+
+    // Saved segments and file segments share recording-relative start times
+    for segment in segments.iter_mut() {
+        if let Some((_, label)) = labels
+            .iter()
+            .find(|(start, _)| (start - segment.audio_start_time).abs() < 0.01)
+        {
+            segment.speaker_label = label.clone();
+        }
+    }
+
+    json["segments"] = serde_json::to_value(&segments).map_err(|e| e.to_string())?;
+    match format_labeled_transcript(&segments) {
+        Some(labeled) => json["labeled_transcript"] = serde_json::Value::String(labeled),
+        None => {
+            if let Some(map) = json.as_object_mut() {
+                map.remove("labeled_transcript");
+            }
+        }
+    }
+
+    let output = serde_json::to_string_pretty(&json).map_err(|e| e.to_string())?;
+    std::fs::write(&path, output).map_err(|e| format!("Failed to write transcripts.json: {}", e))
+}
+
+async fn after_speaker_change<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    renamed: Option<(&str, &str)>,
+) {
+    if let Err(e) = propagate_to_transcript_file(pool, meeting_id).await {
+        warn!("Failed to update transcripts.json for {}: {}", meeting_id, e);
+    }
+    if let Some((from, to)) = renamed {
+        if let Err(e) = propagate_to_summary(pool, meeting_id, from, to).await {
+            warn!("Failed to update summary speakers for {}: {}", meeting_id, e);
+        }
+        if let Err(e) = MeetingSpeakersRepository::rename_label(pool, meeting_id, from, to).await {
+            warn!("Failed to update speaker fingerprint for {}: {}", meeting_id, e);
+        }
+        if let Err(e) = MeetingParticipantsRepository::rename_speaker(pool, meeting_id, from, to).await {
+            warn!("Failed to rename participant in {}: {}", meeting_id, e);
+        }
+    }
+    if let Err(e) = crate::meeting_info::add_speaker_participants(pool, meeting_id).await {
+        warn!("Failed to update participants of {}: {}", meeting_id, e);
+    }
+    let _ = app.emit("meeting-speakers-updated", serde_json::json!({ "meeting_id": meeting_id }));
+}
+
+/// Carry a newly given name over to the same voice in other meetings
+async fn propagate_name_to_other_meetings<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    name: &str,
+) {
+    match propagate_speaker_name(pool, meeting_id, name).await {
+        Ok(changed) => {
+            for (other_meeting_id, old_label) in changed {
+                after_speaker_change(app, pool, &other_meeting_id, Some((&old_label, name))).await;
+            }
+        }
+        Err(e) => warn!("Failed to propagate speaker name '{}': {}", name, e),
+    }
+}
+
+fn validate_label(label: &str) -> Result<&str, String> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err("Speaker name cannot be empty".to_string());
+    }
+    Ok(label)
+}
+
+#[command]
+pub async fn meeting_list_speakers(
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<MeetingSpeaker>, String> {
+    let speakers = TranscriptsRepository::list_speakers(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list speakers: {}", e))?;
+    Ok(speakers
+        .into_iter()
+        .map(|(label, segment_count, total_duration)| MeetingSpeaker {
+            label,
+            segment_count,
+            total_duration,
+        })
+        .collect())
+}
+
+/// Rename a speaker throughout a meeting (the new name must not already be in use; use merge for that)
+#[command]
+pub async fn speaker_rename<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    from: String,
+    to: String,
+) -> Result<u64, String> {
+    let pool = state.db_manager.pool();
+    let to = validate_label(&to)?;
+
+    let speakers = TranscriptsRepository::list_speakers(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list speakers: {}", e))?;
+    if speakers.iter().any(|(label, _, _)| label == to && label != &from) {
+        return Err(format!("Speaker '{}' already exists; merge the speakers instead", to));
+    }
+
+    let changed = TranscriptsRepository::relabel_speaker(pool, &meeting_id, &from, to)
+        .await
+        .map_err(|e| format!("Failed to rename speaker: {}", e))?;
+    if changed == 0 {
+        return Err(format!("Speaker '{}' not found in this meeting", from));
+    }
+
+    info!("Renamed speaker '{}' -> '{}' in {} ({} segments)", from, to, meeting_id, changed);
+    after_speaker_change(&app, pool, &meeting_id, Some((&from, to))).await;
+    propagate_name_to_other_meetings(&app, pool, &meeting_id, to).await;
+    Ok(changed)
+}
+
+/// Merge `source` into `target` (both must exist in the meeting)
+#[command]
+pub async fn speaker_merge<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    source: String,
+    target: String,
+) -> Result<u64, String> {
+    let pool = state.db_manager.pool();
+    if source == target {
+        return Err("Cannot merge a speaker into itself".to_string());
+    }
+
+    let speakers = TranscriptsRepository::list_speakers(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list speakers: {}", e))?;
+    for label in [&source, &target] {
+        if !speakers.iter().any(|(existing, _, _)| existing == label) {
+            return Err(format!("Speaker '{}' not found in this meeting", label));
+        }
+    }
+
+    let changed = TranscriptsRepository::relabel_speaker(pool, &meeting_id, &source, &target)
+        .await
+        .map_err(|e| format!("Failed to merge speakers: {}", e))?;
+
+    info!("Merged speaker '{}' into '{}' in {} ({} segments)", source, target, meeting_id, changed);
+    after_speaker_change(&app, pool, &meeting_id, Some((&source, &target))).await;
+    propagate_name_to_other_meetings(&app, pool, &meeting_id, &target).await;
+    Ok(changed)
+}
+
+/// Assign all segments inside a time range (seconds from recording start) to a speaker
+#[command]
+pub async fn speaker_reassign_segments<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    start_time: f64,
+    end_time: f64,
+    speaker: String,
+) -> Result<u64, String> {
+    let pool = state.db_manager.pool();
+    let speaker = validate_label(&speaker)?;
+    if end_time < start_time {
+        return Err("End time must not be before start time".to_string());
+    }
+
+    let changed = TranscriptsRepository::reassign_speaker_range(pool, &meeting_id, start_time, end_time, speaker)
+        .await
+        .map_err(|e| format!("Failed to reassign segments: {}", e))?;
+
+    info!(
+        "Reassigned {} segment(s) in {:.1}s-{:.1}s of {} to '{}'",
+        changed, start_time, end_time, meeting_id, speaker
+    );
+    // Summary text can't be patched for a partial reassignment; it is regenerated from the labeled transcript
+    after_speaker_change(&app, pool, &meeting_id, None).await;
+    Ok(changed)
+}
+
+/// Names to offer when renaming a meeting's speakers: its invitees and other listed
+/// participants who aren't a speaker label yet, invitees first
+fn speaker_name_suggestions(participants: &[MeetingParticipant], labels: &[String]) -> Vec<String> {
+    let mut suggestions: Vec<&MeetingParticipant> = participants
+        .iter()
+        .filter(|participant| participant.source != "speaker")
+        .filter(|participant| !labels.iter().any(|label| label.eq_ignore_ascii_case(&participant.name)))
+        .collect();
+    // Stable, so names stay alphabetical within each group
+    suggestions.sort_by_key(|participant| participant.source != "calendar");
+    suggestions.into_iter().map(|participant| participant.name.clone()).collect()
+}
+
+#[command]
+pub async fn meeting_speaker_name_suggestions(
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<String>, String> {
+    let pool = state.db_manager.pool();
+    let participants = MeetingParticipantsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?;
+    let labels: Vec<String> = TranscriptsRepository::list_speakers(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to list speakers: {}", e))?
+        .into_iter()
+        .map(|(label, _, _)| label)
+        .collect();
+    Ok(speaker_name_suggestions(&participants, &labels))
+}
+
+/// Speaker-labeled transcript text for summaries and exports
+#[command]
+pub async fn get_meeting_labeled_transcript(
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<String, String> {
+    TranscriptsRepository::get_speaker_labeled_text(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to build labeled transcript: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_label_in_json_whole_words_only() {
+        let mut summary = serde_json::json!({
+            "markdown": "Speaker 2 will send the deck. Speaker 21 disagreed.",
+            "items": [{ "owner": "Speaker 2" }, 3]
+        });
+        let changed = replace_label_in_json(&mut summary, &label_pattern("Speaker 2").unwrap(), "Ana");
+
+        assert!(changed);
+        assert_eq!(summary["markdown"], "Ana will send the deck. Speaker 21 disagreed.");
+        assert_eq!(summary["items"][0]["owner"], "Ana");
+    }
+
+    #[test]
+    fn test_speaker_name_suggestions_put_invitees_first() {
+        let participant = |name: &str, source: &str| MeetingParticipant {
+            id: format!("participant-{}", name),
+            meeting_id: "m1".to_string(),
+            name: name.to_string(),
+            email: None,
+            source: source.to_string(),
+            created_at: chrono::Utc::now(),
+        };
+        let participants = vec![
+            participant("Ana", "calendar"),
+            participant("Ben", "manual"),
+            participant("Cleo", "calendar"),
+            participant("Dev", "speaker"),
+        ];
+        let labels = vec!["ana".to_string(), "Speaker 2".to_string()];
+        assert_eq!(speaker_name_suggestions(&participants, &labels), ["Cleo", "Ben"]);
+    }
+}
@@ -0,0 +1,419 @@
+// diarization/talk_time.rs
+//
+// Per-speaker talk-time analytics computed from speaker-attributed segments:
+// speaking time, share of the conversation, number of turns, longest monologue,
+// speaking pace and filler words (useful for presentation coaching), plus
+// turn-taking statistics and interruptions. Interruptions need overlapping segment
+// times, so they show up for split mic/system transcription, not mixed diarization.
+// Also renders the optional "Speaker Analytics" section appended to generated minutes.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::overlap::MIN_OVERLAP_SECONDS;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+/// One speaker-attributed transcript segment (times in seconds from recording start)
+#[derive(Debug, Clone)]
+pub struct SpeakerSegment {
+    pub speaker: String,
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpeakerTalkTime {
+    pub speaker: String,
+    /// Total speaking time in seconds
+    pub speaking_seconds: f64,
+    /// Share of total speaking time (0.0-1.0)
+    pub share: f64,
+    pub turn_count: usize,
+    pub longest_monologue_seconds: f64,
+    /// Recording-relative start of the longest monologue
+    pub longest_monologue_start: f64,
+    pub word_count: usize,
+    /// Speaking pace over the speaker's own talk time
+    pub words_per_minute: f64,
+    pub filler_count: usize,
+    /// Occurrences per filler ("um", "you know", ...)
+    pub fillers: BTreeMap<String, usize>,
+    /// Times this speaker started talking while someone else was mid-segment
+    pub interruptions_made: usize,
+    /// Times someone else started talking while this speaker was mid-segment
+    pub times_interrupted: usize,
+}
+
+/// Speaker B started talking before speaker A's segment ended
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interruption {
+    pub interrupter: String,
+    pub interrupted: String,
+    /// Recording-relative time the interrupter started
+    pub at: f64,
+    /// How long both talked at once
+    pub overlap_seconds: f64,
+}
+
+/// How often the floor passed from one speaker to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnHandoff {
+    pub from: String,
+    pub to: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TalkTimeAnalytics {
+    pub speakers: Vec<SpeakerTalkTime>,
+    pub total_speaking_seconds: f64,
+    pub total_turns: usize,
+    pub average_turn_seconds: f64,
+    /// Speaker changes per minute of meeting time (first to last segment)
+    pub turns_per_minute: f64,
+    pub handoffs: Vec<TurnHandoff>,
+    pub interruptions: Vec<Interruption>,
+}
+
+// Hesitation sounds always count; "like" only when set off by commas, since it is
+// usually a real word otherwise
+static FILLER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(um+|uh+|erm|er|ah|hmm+|you know|i mean|sort of|kind of|basically|literally)\b|(?:^|,\s*)(like)\s*,")
+        .unwrap()
+});
+
+/// Filler words in a piece of text, normalized to lowercase ("umm" counts as "um")
+pub fn count_fillers(text: &str) -> Vec<String> {
+    FILLER_RE
+        .captures_iter(text)
+        .filter_map(|c| c.get(1).or_else(|| c.get(2)))
+        .map(|m| {
+            let filler = m.as_str().to_lowercase();
+            match filler.as_str() {
+                f if f.starts_with("um") => "um".to_string(),
+                f if f.starts_with("uh") => "uh".to_string(),
+                f if f.starts_with("hmm") => "hmm".to_string(),
+                _ => filler,
+            }
+        })
+        .collect()
+}
+
+/// Compute analytics from speaker segments sorted by start time.
+/// Consecutive segments from the same speaker form one turn.
+pub fn compute_talk_time(segments: &[SpeakerSegment]) -> TalkTimeAnalytics {
+    let mut speakers: Vec<SpeakerTalkTime> = Vec::new();
+    let mut total_turns: usize = 0;
+    let mut handoffs: Vec<TurnHandoff> = Vec::new();
+    // (speaker index, turn start, turn end)
+    let mut current_turn: Option<(usize, f64, f64)> = None;
+
+    let close_turn = |speakers: &mut Vec<SpeakerTalkTime>, turn: (usize, f64, f64)| {
+        let (index, start, end) = turn;
+        let length = (end - start).max(0.0);
+        let entry = &mut speakers[index];
+        entry.turn_count += 1;
+        if length > entry.longest_monologue_seconds {
+            entry.longest_monologue_seconds = length;
+            entry.longest_monologue_start = start;
+        }
+    };
+
+    for SpeakerSegment { speaker, start, end, text } in segments {
+        let index = match speakers.iter().position(|s| &s.speaker == speaker) {
+            Some(index) => index,
+            None => {
+                speakers.push(SpeakerTalkTime {
+                    speaker: speaker.clone(),
+                    speaking_seconds: 0.0,
+                    share: 0.0,
+                    turn_count: 0,
+                    longest_monologue_seconds: 0.0,
+                    longest_monologue_start: *start,
+                    word_count: 0,
+                    words_per_minute: 0.0,
+                    filler_count: 0,
+                    fillers: BTreeMap::new(),
+                    interruptions_made: 0,
+                    times_interrupted: 0,
+                });
+                speakers.len() - 1
+            }
+        };
+        let entry = &mut speakers[index];
+        entry.speaking_seconds += (end - start).max(0.0);
+        entry.word_count += text.split_whitespace().count();
+        for filler in count_fillers(text) {
+            entry.filler_count += 1;
+            *entry.fillers.entry(filler).or_insert(0) += 1;
+        }
+
+        current_turn = match current_turn {
+            Some((turn_index, turn_start, turn_end)) if turn_index == index => {
+                Some((turn_index, turn_start, turn_end.max(*end)))
+            }
+            Some(previous) => {
+                close_turn(&mut speakers, previous);
+                total_turns += 1;
+                let from = speakers[previous.0].speaker.clone();
+                match handoffs.iter_mut().find(|h| h.from == from && &h.to == speaker) {
+                    Some(handoff) => handoff.count += 1,
+                    None => handoffs.push(TurnHandoff {
+                        from,
+                        to: speaker.clone(),
+                        count: 1,
+                    }),
+                }
+                Some((index, *start, *end))
+            }
+            None => Some((index, *start, *end)),
+        };
+    }
+    if let Some(turn) = current_turn {
+        close_turn(&mut speakers, turn);
+        total_turns += 1;
+    }
+
+    let interruptions = find_interruptions(segments);
+    for interruption in &interruptions {
+        for speaker in speakers.iter_mut() {
+            if speaker.speaker == interruption.interrupter {
+                speaker.interruptions_made += 1;
+            } else if speaker.speaker == interruption.interrupted {
+                speaker.times_interrupted += 1;
+            }
+        }
+    }
+
+    let total_speaking_seconds: f64 = speakers.iter().map(|s| s.speaking_seconds).sum();
+    for speaker in speakers.iter_mut() {
+        if total_speaking_seconds > 0.0 {
+            speaker.share = speaker.speaking_seconds / total_speaking_seconds;
+        }
+        if speaker.speaking_seconds > 0.0 {
+            speaker.words_per_minute = speaker.word_count as f64 * 60.0 / speaker.speaking_seconds;
+        }
+    }
+    speakers.sort_by(|a, b| {
+        b.speaking_seconds
+            .partial_cmp(&a.speaking_seconds)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let meeting_minutes = match (segments.first(), segments.iter().map(|s| s.end).reduce(f64::max)) {
+        (Some(first), Some(last)) if last > first.start => (last - first.start) / 60.0,
+        _ => 0.0,
+    };
+    handoffs.sort_by_key(|h| std::cmp::Reverse(h.count));
+
+    TalkTimeAnalytics {
+        average_turn_seconds: if total_turns > 0 {
+            total_speaking_seconds / total_turns as f64
+        } else {
+            0.0
+        },
+        turns_per_minute: if meeting_minutes > 0.0 {
+            total_turns.saturating_sub(1) as f64 / meeting_minutes
+        } else {
+            0.0
+        },
+        speakers,
+        total_speaking_seconds,
+        total_turns,
+        handoffs,
+        interruptions,
+    }
+}
+
+/// Segments (sorted by start) that begin while another speaker's segment is still running.
+/// Each one counts once, against the speaker whose segment runs longest past its start.
+pub fn find_interruptions(segments: &[SpeakerSegment]) -> Vec<Interruption> {
+    let mut interruptions = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        let interrupted = segments[..i]
+            .iter()
+            .filter(|earlier| earlier.speaker != segment.speaker && earlier.start < segment.start)
+            .map(|earlier| (earlier, earlier.end.min(segment.end) - segment.start))
+            .filter(|(_, overlap)| *overlap >= MIN_OVERLAP_SECONDS)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        if let Some((earlier, overlap)) = interrupted {
+            interruptions.push(Interruption {
+                interrupter: segment.speaker.clone(),
+                interrupted: earlier.speaker.clone(),
+                at: segment.start,
+                overlap_seconds: overlap,
+            });
+        }
+    }
+    interruptions
+}
+
+fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    if total >= 3600 {
+        format!("{}:{:02}:{:02}", total / 3600, (total % 3600) / 60, total % 60)
+    } else {
+        format!("{}:{:02}", total / 60, total % 60)
+    }
+}
+
+/// Markdown section for the generated minutes, or None if fewer than two speakers were heard
+pub fn render_talk_time_markdown(analytics: &TalkTimeAnalytics) -> Option<String> {
+    if analytics.speakers.len() < 2 {
+        return None;
+    }
+
+    let mut markdown = String::from("## Speaker Analytics\n\n");
+    markdown.push_str("| Speaker | Talk time | Share | Turns | Longest monologue | Pace | Fillers |\n");
+    markdown.push_str("|---|---|---|---|---|---|---|\n");
+    for speaker in &analytics.speakers {
+        markdown.push_str(&format!(
+            "| {} | {} | {:.0}% | {} | {} (at {}) | {:.0} wpm | {} |\n",
+            speaker.speaker,
+            format_duration(speaker.speaking_seconds),
+            speaker.share * 100.0,
+            speaker.turn_count,
+            format_duration(speaker.longest_monologue_seconds),
+            format_duration(speaker.longest_monologue_start),
+            speaker.words_per_minute,
+            speaker.filler_count,
+        ));
+    }
+
+    markdown.push_str(&format!(
+        "\n**Turn-taking:** {} turns, {} per turn on average, {:.1} speaker changes per minute",
+        analytics.total_turns,
+        format_duration(analytics.average_turn_seconds),
+        analytics.turns_per_minute,
+    ));
+    if !analytics.interruptions.is_empty() {
+        let interrupters: Vec<String> = analytics
+            .speakers
+            .iter()
+            .filter(|s| s.interruptions_made > 0)
+            .map(|s| format!("{} {}", s.speaker, s.interruptions_made))
+            .collect();
+        markdown.push_str(&format!(
+            "; {} interruption(s) ({})",
+            analytics.interruptions.len(),
+            interrupters.join(", ")
+        ));
+    }
+    markdown.push('\n');
+    Some(markdown)
+}
+
+/// Load a meeting's segments from the database and compute its talk-time analytics
+pub async fn meeting_talk_time(pool: &SqlitePool, meeting_id: &str) -> Result<TalkTimeAnalytics, String> {
+    let segments = TranscriptsRepository::list_speaker_segments(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load speaker segments: {}", e))?
+        .into_iter()
+        .map(|(speaker, start, end, text)| SpeakerSegment { speaker, start, end, text })
+        .collect::<Vec<_>>();
+    Ok(compute_talk_time(&segments))
+}
+
+#[command]
+pub async fn get_meeting_talk_time<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<TalkTimeAnalytics, String> {
+    let state = app.state::<AppState>();
+    meeting_talk_time(state.db_manager.pool(), &meeting_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(speaker: &str, start: f64, end: f64) -> SpeakerSegment {
+        SpeakerSegment {
+            speaker: speaker.to_string(),
+            start,
+            end,
+            text: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_turns_and_longest_monologue() {
+        let analytics = compute_talk_time(&[
+            seg("Me", 0.0, 5.0),
+            seg("Me", 5.5, 12.0),
+            seg("Speaker 1", 12.0, 15.0),
+            seg("Me", 16.0, 18.0),
+            seg("Speaker 1", 18.0, 38.0),
+        ]);
+
+        assert_eq!(analytics.total_turns, 4);
+        let me = analytics.speakers.iter().find(|s| s.speaker == "Me").unwrap();
+        assert_eq!(me.turn_count, 2);
+        assert!((me.speaking_seconds - 13.5).abs() < 1e-9);
+        assert!((me.longest_monologue_seconds - 12.0).abs() < 1e-9);
+
+        let remote = &analytics.speakers[0];
+        assert_eq!(remote.speaker, "Speaker 1");
+        assert!((remote.longest_monologue_seconds - 20.0).abs() < 1e-9);
+        assert!((remote.longest_monologue_start - 18.0).abs() < 1e-9);
+        assert!((remote.share + me.share - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_markdown_needs_two_speakers() {
+        assert!(render_talk_time_markdown(&compute_talk_time(&[seg("Me", 0.0, 3.0)])).is_none());
+
+        let markdown =
+            render_talk_time_markdown(&compute_talk_time(&[seg("Me", 0.0, 90.0), seg("Ana", 90.0, 120.0)]))
+                .unwrap();
+        assert!(markdown.contains("| Me | 1:30 | 75% | 1 | 1:30 (at 0:00) | 0 wpm | 0 |"));
+    }
+
+    #[test]
+    fn test_fillers_and_pace() {
+        assert_eq!(
+            count_fillers("Umm, so, like, we should, you know, ship it. I like it."),
+            vec!["um", "like", "you know"]
+        );
+
+        let mut segment = seg("Me", 0.0, 30.0);
+        segment.text = "Uh I think we are basically ready to go live next week".to_string();
+        let analytics = compute_talk_time(&[segment]);
+        let me = &analytics.speakers[0];
+        assert_eq!(me.word_count, 12);
+        assert!((me.words_per_minute - 24.0).abs() < 1e-9);
+        assert_eq!(me.filler_count, 2);
+        assert_eq!(me.fillers.get("basically"), Some(&1));
+    }
+
+    #[test]
+    fn test_interruptions_and_handoffs() {
+        let analytics = compute_talk_time(&[
+            seg("Me", 0.0, 10.0),
+            seg("Remote", 8.0, 15.0), // cuts in 2s before Me finishes
+            seg("Me", 14.8, 20.0),    // below the minimum overlap: a normal handoff
+            seg("Remote", 21.0, 30.0),
+        ]);
+
+        assert_eq!(analytics.interruptions.len(), 1);
+        let interruption = &analytics.interruptions[0];
+        assert_eq!((interruption.interrupter.as_str(), interruption.interrupted.as_str()), ("Remote", "Me"));
+        assert!((interruption.overlap_seconds - 2.0).abs() < 1e-9);
+
+        let me = analytics.speakers.iter().find(|s| s.speaker == "Me").unwrap();
+        assert_eq!((me.interruptions_made, me.times_interrupted), (0, 1));
+        assert_eq!(analytics.handoffs[0].from, "Me");
+        assert_eq!(analytics.handoffs[0].count, 2);
+        assert!((analytics.turns_per_minute - 6.0).abs() < 1e-9);
+
+        let markdown = render_talk_time_markdown(&analytics).unwrap();
+        assert!(markdown.contains("4 turns"));
+        assert!(markdown.contains("1 interruption(s) (Remote 1)"));
+    }
+}
@@ -0,0 +1,310 @@
+// Bulk export
+// Writes every meeting, or the ones matching a library filter, to one directory in a single
+// format, as a plain-files archive that stays readable without the app. Files can be laid
+// out flat, by month, or mirroring the library folders; recordings can be copied alongside.
+// Each meeting emits a "bulk-export-progress" event, and one failing meeting doesn't stop
+// the others.
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::document::MeetingDocument;
+use super::markdown::{render_markdown, DEFAULT_MARKDOWN_TEMPLATE};
+use super::{docx, html, json, load_document, load_export_settings, pdf, write_export};
+use crate::audio::audio_processing::sanitize_filename;
+use crate::database::models::MeetingFolder;
+use crate::database::repositories::meeting_folder::MeetingFoldersRepository;
+use crate::library::{matching_meetings, LibraryMeeting, MeetingFilter};
+use crate::state::AppState;
+
+/// Longest file or folder name written, in characters
+const MAX_NAME_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkExportFormat {
+    Markdown,
+    Html,
+    Pdf,
+    Docx,
+    Json,
+}
+
+impl BulkExportFormat {
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Pdf => "pdf",
+            Self::Docx => "docx",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkExportLayout {
+    /// Every file directly in the chosen directory
+    #[default]
+    Flat,
+    /// "2026/2026-02/" folders by the month a meeting was recorded
+    ByMonth,
+    /// The library folder tree; unfiled meetings stay at the top
+    ByLibraryFolder,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkExportOptions {
+    pub format: BulkExportFormat,
+    #[serde(default)]
+    pub layout: BulkExportLayout,
+    /// Only meetings matching this filter; None exports all of them
+    #[serde(default)]
+    pub filter: Option<MeetingFilter>,
+    /// Copy each meeting's recording next to its file
+    #[serde(default)]
+    pub include_audio: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkExportProgress {
+    /// Meetings handled so far, this one included
+    pub done: usize,
+    pub total: usize,
+    pub meeting_id: String,
+    pub title: String,
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct BulkExportReport {
+    pub directory: String,
+    pub exported: usize,
+    pub audio_files: usize,
+    pub errors: Vec<String>,
+}
+
+/// A file or folder name: unsafe characters replaced and the length capped
+fn safe_name(name: &str, fallback: &str) -> String {
+    let name: String = sanitize_filename(name)
+        .chars()
+        .take(MAX_NAME_CHARS)
+        .collect::<String>()
+        .trim_end_matches(['.', ' '])
+        .to_string();
+    if name.is_empty() {
+        fallback.to_string()
+    } else {
+        name
+    }
+}
+
+/// "2026-02-01 Standup", so files sort by date
+pub(crate) fn file_stem(title: &str, created_at: DateTime<Utc>) -> String {
+    let date = created_at.with_timezone(&Local).format("%Y-%m-%d");
+    format!("{} {}", date, safe_name(title, "Untitled meeting"))
+}
+
+/// Names of `folder_id` and its enclosing folders, outermost first
+fn library_path(folders: &HashMap<String, MeetingFolder>, folder_id: &str) -> PathBuf {
+    let mut names = Vec::new();
+    let mut current = folders.get(folder_id);
+    // The depth cap guards against a cycle in damaged data
+    while let Some(folder) = current.filter(|_| names.len() < folders.len()) {
+        names.push(safe_name(&folder.name, "Folder"));
+        current = folder.parent_id.as_ref().and_then(|parent_id| folders.get(parent_id));
+    }
+    names.iter().rev().collect()
+}
+
+/// Folder of a meeting's file, relative to the export directory
+fn meeting_dir(layout: BulkExportLayout, meeting: &LibraryMeeting, folders: &HashMap<String, MeetingFolder>) -> PathBuf {
+    match layout {
+        BulkExportLayout::Flat => PathBuf::new(),
+        BulkExportLayout::ByMonth => {
+            let local = meeting.created_at.with_timezone(&Local);
+            PathBuf::from(local.format("%Y").to_string()).join(local.format("%Y-%m").to_string())
+        }
+        BulkExportLayout::ByLibraryFolder => meeting
+            .folder_id
+            .as_deref()
+            .map(|folder_id| library_path(folders, folder_id))
+            .unwrap_or_default(),
+    }
+}
+
+/// `dir/stem.extension`, numbered when an earlier meeting in this export took the name
+fn unique_path(taken: &mut HashSet<PathBuf>, dir: &Path, stem: &str, extension: &str) -> PathBuf {
+    let mut path = dir.join(format!("{}.{}", stem, extension));
+    let mut number = 2;
+    while !taken.insert(path.to_string_lossy().to_lowercase().into()) {
+        path = dir.join(format!("{} ({}).{}", stem, number, extension));
+        number += 1;
+    }
+    path
+}
+
+pub(crate) fn render(format: BulkExportFormat, document: &MeetingDocument, markdown_template: &str, pdf: &pdf::PdfSettings) -> Result<Vec<u8>, String> {
+    Ok(match format {
+        BulkExportFormat::Markdown => render_markdown(document, markdown_template).into_bytes(),
+        BulkExportFormat::Html => html::render_html(document, None).into_bytes(),
+        BulkExportFormat::Pdf => pdf::render_pdf(document, pdf),
+        BulkExportFormat::Docx => docx::render_docx(document),
+        BulkExportFormat::Json => json::render_json(document)
+            .map_err(|e| format!("Failed to serialize meeting: {}", e))?
+            .into_bytes(),
+    })
+}
+
+/// Export every meeting, or those matching `options.filter`, into `directory`
+///
+/// # Arguments
+/// * `directory` - Folder chosen by the user; created if missing
+/// * `options` - Format, folder layout, filter and whether to copy recordings
+///
+/// # Returns
+/// How many meetings were written, and the meetings that failed
+#[command]
+pub async fn export_all_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    directory: String,
+    options: BulkExportOptions,
+) -> Result<BulkExportReport, String> {
+    let root = PathBuf::from(directory.trim());
+    if root.as_os_str().is_empty() {
+        return Err("Choose a folder to export to".to_string());
+    }
+    std::fs::create_dir_all(&root).map_err(|e| format!("Failed to create {}: {}", root.display(), e))?;
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_export_settings(pool).await?;
+    let markdown_template = settings
+        .markdown_template
+        .clone()
+        .unwrap_or_else(|| DEFAULT_MARKDOWN_TEMPLATE.to_string());
+    let folders: HashMap<String, MeetingFolder> = MeetingFoldersRepository::list(pool)
+        .await
+        .map_err(|e| format!("Failed to load folders: {}", e))?
+        .into_iter()
+        .map(|folder| (folder.id.clone(), folder))
+        .collect();
+    let mut meetings = matching_meetings(pool, &options.filter.clone().unwrap_or_default()).await?;
+    meetings.sort_by_key(|meeting| meeting.created_at);
+
+    let total = meetings.len();
+    let extension = options.format.extension();
+    let mut taken = HashSet::new();
+    let mut report = BulkExportReport {
+        directory: root.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    for (index, meeting) in meetings.iter().enumerate() {
+        let dir = root.join(meeting_dir(options.layout, meeting, &folders));
+        let stem = file_stem(&meeting.title, meeting.created_at);
+        let path = unique_path(&mut taken, &dir, &stem, extension);
+
+        let exported = async {
+            let document = load_document(pool, &meeting.id, &settings).await?;
+            write_export(&path, &render(options.format, &document, &markdown_template, &settings.pdf)?)?;
+            Ok::<_, String>(document)
+        }
+        .await;
+        let error = match exported {
+            Ok(document) => {
+                report.exported += 1;
+                let audio = document
+                    .folder_path
+                    .as_deref()
+                    .map(|folder| Path::new(folder).join("audio.mp4"))
+                    .filter(|audio| options.include_audio && audio.is_file());
+                if let Some(audio) = audio {
+                    let copy = path.with_extension("m4a");
+                    match std::fs::copy(&audio, &copy) {
+                        Ok(_) => report.audio_files += 1,
+                        Err(e) => warn!("Failed to copy the recording of {} to {}: {}", meeting.id, copy.display(), e),
+                    }
+                }
+                None
+            }
+            Err(e) => {
+                warn!("Failed to export meeting {}: {}", meeting.id, e);
+                report.errors.push(format!("{}: {}", meeting.title, e));
+                Some(e)
+            }
+        };
+
+        let _ = app.emit(
+            "bulk-export-progress",
+            BulkExportProgress {
+                done: index + 1,
+                total,
+                meeting_id: meeting.id.clone(),
+                title: meeting.title.clone(),
+                path: error.is_none().then(|| path.to_string_lossy().to_string()),
+                error,
+            },
+        );
+    }
+
+    info!(
+        "Exported {} of {} meeting(s) to {} ({} recording(s) copied)",
+        report.exported,
+        total,
+        root.display(),
+        report.audio_files
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn folder(id: &str, name: &str, parent_id: Option<&str>) -> (String, MeetingFolder) {
+        let folder = MeetingFolder {
+            id: id.to_string(),
+            name: name.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            created_at: Utc::now(),
+        };
+        (id.to_string(), folder)
+    }
+
+    #[test]
+    fn lays_out_export_files() {
+        let folders: HashMap<String, MeetingFolder> =
+            [folder("f1", "Clients", None), folder("f2", "Acme: Q1", Some("f1"))].into_iter().collect();
+        let meeting = LibraryMeeting {
+            id: "meeting-1".to_string(),
+            title: "Design review / API".to_string(),
+            description: None,
+            created_at: Local.with_ymd_and_hms(2026, 2, 3, 10, 0, 0).unwrap().with_timezone(&Utc),
+            folder_id: Some("f2".to_string()),
+            tags: Vec::new(),
+            starred: false,
+        };
+
+        assert_eq!(meeting_dir(BulkExportLayout::Flat, &meeting, &folders), PathBuf::new());
+        assert_eq!(meeting_dir(BulkExportLayout::ByMonth, &meeting, &folders), PathBuf::from("2026").join("2026-02"));
+        assert_eq!(
+            meeting_dir(BulkExportLayout::ByLibraryFolder, &meeting, &folders),
+            PathBuf::from("Clients").join("Acme_ Q1")
+        );
+        assert_eq!(file_stem(&meeting.title, meeting.created_at), "2026-02-03 Design review _ API");
+        assert_eq!(safe_name(" ... ", "Untitled"), "Untitled");
+
+        let mut taken = HashSet::new();
+        let dir = Path::new("out");
+        assert_eq!(unique_path(&mut taken, dir, "2026-02-03 Standup", "md"), dir.join("2026-02-03 Standup.md"));
+        assert_eq!(unique_path(&mut taken, dir, "2026-02-03 standup", "md"), dir.join("2026-02-03 standup (2).md"));
+        assert_eq!(unique_path(&mut taken, dir, "2026-02-03 Standup", "md"), dir.join("2026-02-03 Standup (3).md"));
+    }
+}
@@ -0,0 +1,219 @@
+// Spreadsheet export
+// Action items and speaker analytics of every meeting in a date range as CSV, one row per
+// item or per speaker and meeting, so they can be filtered and pivoted in a spreadsheet.
+// Files are UTF-8 with a byte order mark, which Excel needs to read non-ASCII names.
+
+use chrono::Local;
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::{export_path, write_export};
+use crate::database::models::{ActionItem, MeetingModel};
+use crate::database::repositories::action_item::ActionItemsRepository;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_tag::{normalize_tag, MeetingTagsRepository};
+use crate::diarization::talk_time::{meeting_talk_time, SpeakerTalkTime};
+use crate::state::AppState;
+use crate::summary::digest::local_day_range;
+
+const ACTION_ITEM_COLUMNS: &[&str] = &["Owner", "Description", "Status", "Due date", "Meeting", "Meeting date"];
+const SPEAKER_COLUMNS: &[&str] = &[
+    "Meeting",
+    "Meeting date",
+    "Speaker",
+    "Talk time (min)",
+    "Share (%)",
+    "Turns",
+    "Longest monologue (s)",
+    "Words",
+    "Words per minute",
+    "Fillers",
+    "Interruptions made",
+    "Times interrupted",
+];
+
+/// One CSV field, quoted when needed. Text starting like a formula gets a leading
+/// apostrophe so spreadsheets show it instead of evaluating it.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) || value.starts_with(' ') || value.ends_with(' ') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// A CSV document (CRLF line ends, as in RFC 4180)
+fn render_csv(columns: &[&str], rows: &[Vec<String>]) -> String {
+    let mut csv = String::from("\u{feff}");
+    let header: Vec<String> = columns.iter().map(|column| csv_field(column)).collect();
+    csv.push_str(&header.join(","));
+    csv.push_str("\r\n");
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+fn meeting_date(meeting: &MeetingModel) -> String {
+    meeting.created_at.0.with_timezone(&Local).format("%Y-%m-%d").to_string()
+}
+
+fn action_item_row(meeting: &MeetingModel, item: &ActionItem) -> Vec<String> {
+    vec![
+        item.assignee.clone().unwrap_or_default(),
+        item.description.clone(),
+        if item.done { "Done" } else { "Open" }.to_string(),
+        item.due_date.clone().unwrap_or_default(),
+        meeting.title.clone(),
+        meeting_date(meeting),
+    ]
+}
+
+fn speaker_row(meeting: &MeetingModel, speaker: &SpeakerTalkTime) -> Vec<String> {
+    vec![
+        meeting.title.clone(),
+        meeting_date(meeting),
+        speaker.speaker.clone(),
+        format!("{:.2}", speaker.speaking_seconds / 60.0),
+        format!("{:.1}", speaker.share * 100.0),
+        speaker.turn_count.to_string(),
+        format!("{:.0}", speaker.longest_monologue_seconds),
+        speaker.word_count.to_string(),
+        format!("{:.0}", speaker.words_per_minute),
+        speaker.filler_count.to_string(),
+        speaker.interruptions_made.to_string(),
+        speaker.times_interrupted.to_string(),
+    ]
+}
+
+/// Meetings recorded on the local days `start_date`..=`end_date`, oldest first
+async fn meetings_in_range(
+    pool: &SqlitePool,
+    start_date: &str,
+    end_date: &str,
+    tag: Option<&str>,
+) -> Result<Vec<MeetingModel>, String> {
+    let (start, end) = local_day_range(start_date, end_date)?;
+    let mut meetings: Vec<MeetingModel> = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?
+        .into_iter()
+        .filter(|meeting| meeting.created_at.0 >= start && meeting.created_at.0 < end)
+        .collect();
+    if let Some(tag) = tag.map(normalize_tag).filter(|tag| !tag.is_empty()) {
+        let tagged = MeetingTagsRepository::meeting_ids_with_tag(pool, &tag)
+            .await
+            .map_err(|e| format!("Failed to load tags: {}", e))?;
+        meetings.retain(|meeting| tagged.contains(&meeting.id));
+    }
+    meetings.sort_by_key(|meeting| meeting.created_at.0);
+    Ok(meetings)
+}
+
+/// Export the action items of meetings in a date range
+///
+/// # Arguments
+/// * `start_date` / `end_date` - Local days ("YYYY-MM-DD"), both included
+/// * `tag` - Only meetings with this tag
+/// * `path` - File chosen by the user; ".csv" is added when it has no extension
+///
+/// # Returns
+/// The path written
+#[command]
+pub async fn export_action_items_csv<R: Runtime>(
+    app: AppHandle<R>,
+    start_date: String,
+    end_date: String,
+    tag: Option<String>,
+    path: String,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let meetings = meetings_in_range(pool, &start_date, &end_date, tag.as_deref()).await?;
+
+    let mut rows = Vec::new();
+    for meeting in &meetings {
+        let items = ActionItemsRepository::list_for_meeting(pool, &meeting.id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?;
+        rows.extend(items.iter().map(|item| action_item_row(meeting, item)));
+    }
+
+    let path = export_path(&path, "csv");
+    write_export(&path, render_csv(ACTION_ITEM_COLUMNS, &rows).as_bytes())?;
+    info!(
+        "Exported {} action item(s) from {} meeting(s) to {}",
+        rows.len(),
+        meetings.len(),
+        path.display()
+    );
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Export per-speaker talk-time analytics of meetings in a date range, one row per speaker
+/// and meeting
+///
+/// # Arguments
+/// * `start_date` / `end_date` - Local days ("YYYY-MM-DD"), both included
+/// * `tag` - Only meetings with this tag
+/// * `path` - File chosen by the user; ".csv" is added when it has no extension
+///
+/// # Returns
+/// The path written
+#[command]
+pub async fn export_speaker_analytics_csv<R: Runtime>(
+    app: AppHandle<R>,
+    start_date: String,
+    end_date: String,
+    tag: Option<String>,
+    path: String,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let meetings = meetings_in_range(pool, &start_date, &end_date, tag.as_deref()).await?;
+
+    let mut rows = Vec::new();
+    for meeting in &meetings {
+        let analytics = meeting_talk_time(pool, &meeting.id).await?;
+        rows.extend(analytics.speakers.iter().map(|speaker| speaker_row(meeting, speaker)));
+    }
+
+    let path = export_path(&path, "csv");
+    write_export(&path, render_csv(SPEAKER_COLUMNS, &rows).as_bytes())?;
+    info!(
+        "Exported speaker analytics of {} meeting(s) to {}",
+        meetings.len(),
+        path.display()
+    );
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(csv_field("Send the deck"), "Send the deck");
+        assert_eq!(csv_field("Ana, Bo"), "\"Ana, Bo\"");
+        assert_eq!(csv_field("the \"final\" draft"), "\"the \"\"final\"\" draft\"");
+        assert_eq!(csv_field("line one\nline two"), "\"line one\nline two\"");
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+        assert_eq!(csv_field("-5% budget"), "'-5% budget");
+        assert_eq!(csv_field(""), "");
+
+        let csv = render_csv(
+            &["Owner", "Description"],
+            &[vec!["Ana".to_string(), "Book the room, again".to_string()], vec![String::new(), "Ship".to_string()]],
+        );
+        assert_eq!(csv, "\u{feff}Owner,Description\r\nAna,\"Book the room, again\"\r\n,Ship\r\n");
+    }
+}
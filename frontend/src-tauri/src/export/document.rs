@@ -0,0 +1,161 @@
+// Meeting document shared by the exporters
+// Everything an export can show about one meeting, loaded from the database in one place
+
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use crate::database::models::{ActionItem, Transcript};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository, segment_comment::SegmentCommentsRepository,
+    summary::SummaryProcessesRepository, transcript::TranscriptsRepository,
+};
+use crate::summary::follow_up::stored_minutes;
+
+/// One transcript segment as exported
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportSegment {
+    pub id: String,
+    /// Recording-relative seconds
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    pub speaker: Option<String>,
+    pub text: String,
+    pub confidence: Option<f64>,
+    pub language: Option<String>,
+    /// Reviewers' comments, when the export includes them
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<ExportComment>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportComment {
+    pub author: String,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MeetingDocument {
+    pub id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub description: Option<String>,
+    pub agenda: Option<String>,
+    /// Recording folder (holds audio.mp4)
+    pub folder_path: Option<String>,
+    /// Minutes markdown, None until a summary was generated
+    pub summary: Option<String>,
+    pub action_items: Vec<ActionItem>,
+    /// In audio order
+    pub segments: Vec<ExportSegment>,
+}
+
+/// Speaker shown for a segment: the diarized label, else the audio source
+fn segment_speaker(transcript: &Transcript) -> Option<String> {
+    transcript.speaker_label.clone().or_else(|| match transcript.speaker.as_deref() {
+        Some("mic") => Some("Me".to_string()),
+        Some("system") => Some("Remote".to_string()),
+        _ => None,
+    })
+}
+
+impl MeetingDocument {
+    pub async fn load(pool: &SqlitePool, meeting_id: &str) -> Result<Self, String> {
+        let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load meeting: {}", e))?
+            .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+        let process = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load summary: {}", e))?;
+        let action_items = ActionItemsRepository::list_for_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?;
+        let transcripts = TranscriptsRepository::list_for_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load transcript: {}", e))?;
+
+        let segments = transcripts
+            .iter()
+            .map(|transcript| ExportSegment {
+                id: transcript.id.clone(),
+                start: transcript.audio_start_time,
+                end: transcript.audio_end_time,
+                speaker: segment_speaker(transcript),
+                text: transcript.transcript.trim().to_string(),
+                confidence: transcript.confidence,
+                language: transcript.language.clone(),
+                comments: Vec::new(),
+            })
+            .collect();
+
+        Ok(Self {
+            id: meeting.id,
+            title: meeting.title,
+            created_at: meeting.created_at.0,
+            description: meeting.description.filter(|description| !description.trim().is_empty()),
+            agenda: meeting.agenda.filter(|agenda| !agenda.trim().is_empty()),
+            folder_path: meeting.folder_path,
+            summary: process.and_then(|process| stored_minutes(process.result.as_deref())),
+            action_items,
+            segments,
+        })
+    }
+
+    /// Attach the meeting's segment comments to their segments
+    pub async fn load_comments(&mut self, pool: &SqlitePool) -> Result<(), String> {
+        let comments = SegmentCommentsRepository::list_for_meeting(pool, &self.id)
+            .await
+            .map_err(|e| format!("Failed to load comments: {}", e))?;
+        for comment in comments {
+            if let Some(segment) = self.segments.iter_mut().find(|segment| segment.id == comment.transcript_id) {
+                segment.comments.push(ExportComment {
+                    author: comment.author,
+                    text: comment.text,
+                    created_at: comment.created_at,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Speakers in the order they first spoke
+    pub fn participants(&self) -> Vec<String> {
+        let mut participants: Vec<String> = Vec::new();
+        for speaker in self.segments.iter().filter_map(|segment| segment.speaker.as_ref()) {
+            if !participants.contains(speaker) {
+                participants.push(speaker.clone());
+            }
+        }
+        participants
+    }
+
+    /// Seconds from the first segment's start to the last segment's end
+    pub fn duration_seconds(&self) -> Option<f64> {
+        let start = self.segments.iter().filter_map(|segment| segment.start).reduce(f64::min)?;
+        let end = self
+            .segments
+            .iter()
+            .filter_map(|segment| segment.end.or(segment.start))
+            .reduce(f64::max)?;
+        Some((end - start).max(0.0))
+    }
+
+    /// Meeting start in local time, e.g. "2026-01-09 14:30"
+    pub fn local_date(&self) -> String {
+        self.created_at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+    }
+}
+
+/// "1 h 05 min", "12 min" or "45 s"
+pub fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    if total >= 3600 {
+        format!("{} h {:02} min", total / 3600, (total % 3600) / 60)
+    } else if total >= 60 {
+        format!("{} min", (total + 30) / 60)
+    } else {
+        format!("{} s", total)
+    }
+}
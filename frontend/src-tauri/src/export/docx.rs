@@ -0,0 +1,454 @@
+// Word export
+// Writes the minutes as a .docx (Office Open XML) file: styled headings, the action items
+// as a table and the transcript as an appendix. The package is a plain stored ZIP, so no
+// Office library is needed.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::document::{format_duration, MeetingDocument};
+use super::{export_path, load_document, load_export_settings, write_export};
+use crate::database::models::ActionItem;
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+const ACCENT: &str = "1F4E79";
+const BORDER: &str = "BFBFBF";
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/><Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/></Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/></Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+/// Word's built-in style ids, so the headings show up in the navigation pane and a
+/// corporate template applied later restyles them
+static STYLES: Lazy<String> = Lazy::new(|| {
+    let heading = |level: u8, size: u8, before: u16| {
+        format!(
+            r#"<w:style w:type="paragraph" w:styleId="Heading{level}"><w:name w:val="heading {level}"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:keepNext/><w:spacing w:before="{before}" w:after="80"/><w:outlineLvl w:val="{outline}"/></w:pPr><w:rPr><w:b/><w:color w:val="{ACCENT}"/><w:sz w:val="{size}"/></w:rPr></w:style>"#,
+            outline = level - 1,
+        )
+    };
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:eastAsia="Calibri" w:cs="Calibri"/><w:sz w:val="22"/><w:lang w:val="en-US"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="100" w:line="264" w:lineRule="auto"/></w:pPr></w:pPrDefault></w:docDefaults><w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/></w:style><w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="60"/></w:pPr><w:rPr><w:b/><w:color w:val="{ACCENT}"/><w:sz w:val="40"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Subtitle"><w:name w:val="Subtitle"/><w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="240"/></w:pPr><w:rPr><w:color w:val="595959"/><w:sz w:val="19"/></w:rPr></w:style>{h1}{h2}{h3}<w:style w:type="paragraph" w:styleId="ListParagraph"><w:name w:val="List Paragraph"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="40"/></w:pPr></w:style><w:style w:type="paragraph" w:styleId="Quote"><w:name w:val="Quote"/><w:basedOn w:val="Normal"/><w:qFormat/><w:pPr><w:ind w:left="567"/><w:pBdr><w:left w:val="single" w:sz="12" w:space="8" w:color="{BORDER}"/></w:pBdr></w:pPr><w:rPr><w:i/><w:color w:val="404040"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Transcript"><w:name w:val="Transcript"/><w:basedOn w:val="Normal"/><w:pPr><w:spacing w:after="60"/></w:pPr><w:rPr><w:sz w:val="19"/></w:rPr></w:style></w:styles>"#,
+        h1 = heading(1, 32, 360),
+        h2 = heading(2, 27, 280),
+        h3 = heading(3, 23, 200),
+    )
+});
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            // Control characters are not allowed in XML 1.0
+            '\t' | '\n' | '\r' => escaped.push(' '),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn run(text: &str, bold: bool) -> String {
+    format!(
+        r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+        if bold { "<w:rPr><w:b/></w:rPr>" } else { "" },
+        escape(text)
+    )
+}
+
+static LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\([^)]*\)").unwrap());
+static NUMBERED: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+[.)])\s+(.*)$").unwrap());
+
+/// Runs for one line of markdown: **bold** spans stay bold, other markup is dropped
+fn inline_runs(text: &str) -> String {
+    let text = LINK.replace_all(text, "$1").replace("__", "").replace('`', "");
+    text.split("**")
+        .enumerate()
+        .filter(|(_, part)| !part.is_empty())
+        .map(|(index, part)| run(part, index % 2 == 1))
+        .collect()
+}
+
+fn paragraph(style: Option<&str>, properties: &str, runs: &str) -> String {
+    let style = style.map(|style| format!(r#"<w:pStyle w:val="{}"/>"#, style)).unwrap_or_default();
+    if style.is_empty() && properties.is_empty() {
+        format!("<w:p>{}</w:p>", runs)
+    } else {
+        format!("<w:p><w:pPr>{}{}</w:pPr>{}</w:p>", style, properties, runs)
+    }
+}
+
+/// A list item with its marker hanging in the margin, nested two spaces per level
+fn list_item(marker: &str, runs: &str, level: usize) -> String {
+    let indent = format!(r#"<w:ind w:left="{}" w:hanging="360"/>"#, 360 * (level + 1));
+    paragraph(Some("ListParagraph"), &indent, &format!("{}<w:r><w:tab/></w:r>{}", run(marker, false), runs))
+}
+
+/// `widths` in twentieths of a point; the first row is the repeated, shaded header
+fn table(widths: &[u32], rows: &[Vec<String>]) -> String {
+    let border = |side: &str| format!(r#"<w:{} w:val="single" w:sz="4" w:space="0" w:color="{}"/>"#, side, BORDER);
+    let borders: String = ["top", "left", "bottom", "right", "insideH", "insideV"].iter().map(|side| border(side)).collect();
+    let grid: String = widths.iter().map(|width| format!(r#"<w:gridCol w:w="{}"/>"#, width)).collect();
+
+    let mut xml = format!(
+        r#"<w:tbl><w:tblPr><w:tblW w:w="{}" w:type="dxa"/><w:tblBorders>{}</w:tblBorders><w:tblCellMar><w:left w:w="80" w:type="dxa"/><w:right w:w="80" w:type="dxa"/></w:tblCellMar></w:tblPr><w:tblGrid>{}</w:tblGrid>"#,
+        widths.iter().sum::<u32>(),
+        borders,
+        grid
+    );
+    for (row_index, row) in rows.iter().enumerate() {
+        let header = row_index == 0;
+        xml.push_str(if header { "<w:tr><w:trPr><w:tblHeader/></w:trPr>" } else { "<w:tr>" });
+        for (cell_index, cell) in row.iter().enumerate() {
+            let width = widths.get(cell_index).copied().unwrap_or(0);
+            let shading = if header { format!(r#"<w:shd w:val="clear" w:color="auto" w:fill="{}"/>"#, ACCENT) } else { String::new() };
+            let runs = if header {
+                format!(r#"<w:r><w:rPr><w:b/><w:color w:val="FFFFFF"/></w:rPr><w:t xml:space="preserve">{}</w:t></w:r>"#, escape(cell))
+            } else {
+                inline_runs(cell)
+            };
+            xml.push_str(&format!(
+                r#"<w:tc><w:tcPr><w:tcW w:w="{}" w:type="dxa"/>{}</w:tcPr>{}</w:tc>"#,
+                width,
+                shading,
+                paragraph(None, r#"<w:spacing w:before="40" w:after="40"/>"#, &runs)
+            ));
+        }
+        xml.push_str("</w:tr>");
+    }
+    xml.push_str("</w:tbl>");
+    xml
+}
+
+/// Usable width of an A4 page with the margins below
+const TEXT_WIDTH: u32 = 11906 - 2 * 1134;
+
+/// Markdown table rows (separator row dropped) as a Word table with equal columns
+fn markdown_table(rows: &[&str]) -> String {
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .filter(|row| !row.trim_matches(|c| c == '|' || c == '-' || c == ':' || c == ' ').is_empty())
+        .map(|row| row.trim().trim_matches('|').split('|').map(|cell| cell.trim().to_string()).collect())
+        .collect();
+    let columns = cells.iter().map(Vec::len).max().unwrap_or(0).max(1);
+    let widths = vec![TEXT_WIDTH / columns as u32; columns];
+    let rows: Vec<Vec<String>> = cells
+        .into_iter()
+        .map(|mut row| {
+            row.resize(columns, String::new());
+            row
+        })
+        .collect();
+    table(&widths, &rows)
+}
+
+/// Minutes markdown as Word paragraphs: headings, bullet, numbered and task lists, quotes,
+/// tables and paragraphs
+fn markdown_body(markdown: &str) -> String {
+    let mut body = String::new();
+    let mut table_rows: Vec<&str> = Vec::new();
+    for raw in markdown.lines() {
+        let line = raw.trim();
+        if line.starts_with('|') {
+            table_rows.push(line);
+            continue;
+        }
+        if !table_rows.is_empty() {
+            body.push_str(&markdown_table(&table_rows));
+            table_rows.clear();
+        }
+        if line.is_empty() || (line.len() >= 3 && line.chars().all(|c| c == '-' || c == '*' || c == '_')) {
+            continue;
+        }
+
+        let level = (raw.len() - raw.trim_start().len()) / 2;
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            let style = format!("Heading{}", hashes.min(3));
+            body.push_str(&paragraph(Some(&style), "", &inline_runs(line[hashes..].trim())));
+        } else if let Some(item) = line.strip_prefix("- [ ] ").or_else(|| line.strip_prefix("* [ ] ")) {
+            body.push_str(&list_item("☐", &inline_runs(item), level));
+        } else if let Some(item) = ["- [x] ", "- [X] ", "* [x] "].iter().find_map(|marker| line.strip_prefix(marker)) {
+            body.push_str(&list_item("☑", &inline_runs(item), level));
+        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            body.push_str(&list_item("•", &inline_runs(item), level));
+        } else if let Some(captures) = NUMBERED.captures(line) {
+            body.push_str(&list_item(&captures[1], &inline_runs(&captures[2]), level));
+        } else if let Some(quote) = line.strip_prefix('>') {
+            body.push_str(&paragraph(Some("Quote"), "", &inline_runs(quote.trim())));
+        } else {
+            body.push_str(&paragraph(None, "", &inline_runs(line)));
+        }
+    }
+    if !table_rows.is_empty() {
+        body.push_str(&markdown_table(&table_rows));
+    }
+    body
+}
+
+fn action_item_table(items: &[ActionItem]) -> String {
+    let mut rows = vec![vec!["".to_string(), "Action".to_string(), "Owner".to_string(), "Due".to_string()]];
+    rows.extend(items.iter().map(|item| {
+        vec![
+            if item.done { "☑" } else { "☐" }.to_string(),
+            item.description.trim().to_string(),
+            item.assignee.clone().unwrap_or_default(),
+            item.due_date.clone().unwrap_or_default(),
+        ]
+    }));
+    table(&[500, TEXT_WIDTH - 500 - 2000 - 1500, 2000, 1500], &rows)
+}
+
+fn document_xml(document: &MeetingDocument) -> String {
+    let mut body = paragraph(Some("Title"), "", &run(&document.title, false));
+
+    let mut details = vec![document.local_date()];
+    if let Some(duration) = document.duration_seconds() {
+        details.push(format_duration(duration));
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        details.push(participants.join(", "));
+    }
+    body.push_str(&paragraph(Some("Subtitle"), "", &run(&details.join("  •  "), false)));
+    if let Some(description) = &document.description {
+        for line in description.lines().filter(|line| !line.trim().is_empty()) {
+            body.push_str(&paragraph(None, "", &run(line.trim(), false)));
+        }
+    }
+
+    match &document.summary {
+        Some(summary) => body.push_str(&markdown_body(summary)),
+        None => body.push_str(&paragraph(None, "", &run("No summary has been generated for this meeting yet.", false))),
+    }
+
+    if !document.action_items.is_empty() {
+        body.push_str(&paragraph(Some("Heading2"), "", &run("Action Items", false)));
+        body.push_str(&action_item_table(&document.action_items));
+    }
+
+    let segments: Vec<_> = document.segments.iter().filter(|segment| !segment.text.is_empty()).collect();
+    if !segments.is_empty() {
+        body.push_str(&paragraph(Some("Heading1"), "<w:pageBreakBefore/>", &run("Appendix: Transcript", false)));
+        for segment in segments {
+            let mut label = segment.start.map(|start| format!("[{}] ", format_timestamp(start))).unwrap_or_default();
+            if let Some(speaker) = &segment.speaker {
+                label.push_str(&format!("{}: ", speaker));
+            }
+            let runs = format!("{}{}", if label.is_empty() { String::new() } else { run(&label, true) }, run(&segment.text, false));
+            body.push_str(&paragraph(Some("Transcript"), "", &runs));
+            for comment in &segment.comments {
+                let runs = format!("{}{}", run(&format!("{}: ", comment.author), true), run(&comment.text, false));
+                body.push_str(&paragraph(Some("Quote"), "", &runs));
+            }
+        }
+    }
+    // Word wants a paragraph after a table that ends the body
+    body.push_str("<w:p/>");
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1134" w:right="1134" w:bottom="1134" w:left="1134" w:header="567" w:footer="567" w:gutter="0"/></w:sectPr></w:body></w:document>"#,
+        body
+    )
+}
+
+fn core_xml(document: &MeetingDocument) -> String {
+    let created = document.created_at.format("%Y-%m-%dT%H:%M:%SZ");
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><dc:title>{}</dc:title><dcterms:created xsi:type="dcterms:W3CDTF">{}</dcterms:created></cp:coreProperties>"#,
+        escape(&document.title),
+        created
+    )
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// A ZIP archive with every entry stored uncompressed
+fn zip_stored(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    // 1980-01-01 00:00, the earliest DOS timestamp
+    const DOS_TIME: u16 = 0;
+    const DOS_DATE: u16 = 0x21;
+
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in entries {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+        let mut common = Vec::with_capacity(26);
+        common.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        common.extend_from_slice(&0u16.to_le_bytes()); // flags
+        common.extend_from_slice(&0u16.to_le_bytes()); // method: stored
+        common.extend_from_slice(&DOS_TIME.to_le_bytes());
+        common.extend_from_slice(&DOS_DATE.to_le_bytes());
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&common);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central.extend_from_slice(&common);
+        central.extend_from_slice(&[0; 6]); // comment length, disk, internal attributes
+        central.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // comment length
+    out
+}
+
+/// Render a meeting's minutes as a Word document
+pub fn render_docx(document: &MeetingDocument) -> Vec<u8> {
+    let document_xml = document_xml(document);
+    let core_xml = core_xml(document);
+    // [Content_Types].xml must come first for some readers
+    zip_stored(&[
+        ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+        ("_rels/.rels", PACKAGE_RELS.as_bytes()),
+        ("docProps/core.xml", core_xml.as_bytes()),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS.as_bytes()),
+        ("word/styles.xml", STYLES.as_bytes()),
+        ("word/document.xml", document_xml.as_bytes()),
+    ])
+}
+
+/// Export a meeting's minutes, action items and transcript to a Word document
+///
+/// # Returns
+/// The path written (".docx" is added when the chosen path has no extension)
+#[command]
+pub async fn export_meeting_docx<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let document = load_document(pool, &meeting_id, &load_export_settings(pool).await?).await?;
+    let docx = render_docx(&document);
+
+    let path = export_path(&path, "docx");
+    write_export(&path, &docx)?;
+    info!("Exported meeting {} to {} ({} bytes)", meeting_id, path.display(), docx.len());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::document::ExportSegment;
+    use chrono::Utc;
+
+    /// Entries of a stored ZIP, checking each CRC
+    fn unzip(archive: &[u8]) -> Vec<(String, String)> {
+        let mut entries = Vec::new();
+        let mut at = 0;
+        while archive[at..].starts_with(&0x0403_4b50u32.to_le_bytes()) {
+            let field = |offset: usize, len: usize| {
+                archive[at + offset..at + offset + len].iter().rev().fold(0usize, |value, &byte| value << 8 | byte as usize)
+            };
+            let (crc, size, name_len) = (field(14, 4) as u32, field(18, 4), field(26, 2));
+            let name = String::from_utf8(archive[at + 30..at + 30 + name_len].to_vec()).unwrap();
+            let data = &archive[at + 30 + name_len..at + 30 + name_len + size];
+            assert_eq!(crc32(data), crc, "{}", name);
+            entries.push((name, String::from_utf8(data.to_vec()).unwrap()));
+            at += 30 + name_len + size;
+        }
+        assert!(archive[at..].starts_with(&0x0201_4b50u32.to_le_bytes()));
+        entries
+    }
+
+    #[test]
+    fn crc32_matches_the_standard_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn packages_minutes_action_items_and_transcript() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "R&D <sync>".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- Move to **Postgres** in March\n\n| Risk | Owner |\n|---|---|\n| Load test | Ana |\n".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Draft the migration plan".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: Some("2026-02-01".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![ExportSegment {
+                id: "segment-1".to_string(),
+                start: Some(65.0),
+                end: Some(70.0),
+                speaker: Some("Ana".to_string()),
+                text: "Postgres in March.".to_string(),
+                confidence: None,
+                language: None,
+                comments: Vec::new(),
+            }],
+        };
+
+        let entries = unzip(&render_docx(&document));
+        assert_eq!(entries[0].0, "[Content_Types].xml");
+        let (_, xml) = entries.iter().find(|(name, _)| name == "word/document.xml").unwrap();
+        assert!(xml.contains(r#"<w:pStyle w:val="Title"/></w:pPr><w:r><w:t xml:space="preserve">R&amp;D &lt;sync&gt;</w:t>"#));
+        assert!(xml.contains(r#"<w:pStyle w:val="Heading2"/></w:pPr><w:r><w:t xml:space="preserve">Decisions</w:t>"#));
+        assert!(xml.contains(r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Postgres</w:t></w:r>"#));
+        assert_eq!(xml.matches("<w:tbl>").count(), 2);
+        assert!(xml.contains("Load test"));
+        assert!(!xml.contains("|---"));
+        assert!(xml.contains("Draft the migration plan"));
+        assert!(xml.contains(r#"<w:t xml:space="preserve">[00:01:05] Ana: </w:t>"#));
+        assert!(entries.iter().any(|(name, xml)| name == "docProps/core.xml" && xml.contains("R&amp;D &lt;sync&gt;")));
+    }
+}
@@ -0,0 +1,380 @@
+// HTML export
+// A single self-contained page: summary, action items and transcript, with the recording
+// embedded as a data URI so clicking a segment seeks the player to that moment. Opens in
+// any browser, no app or network needed.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::document::{format_duration, MeetingDocument};
+use super::{export_path, load_document, load_export_settings, write_export};
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+const STYLE: &str = r#"
+body { font: 15px/1.55 -apple-system, "Segoe UI", Roboto, Helvetica, Arial, sans-serif; color: #1a1a1a; max-width: 820px; margin: 0 auto; padding: 0 20px 60px; }
+header { padding: 28px 0 8px; }
+h1 { font-size: 26px; margin: 0 0 4px; color: #1f4e79; }
+h2 { font-size: 19px; margin: 28px 0 8px; color: #1f4e79; }
+h3, h4, h5, h6 { font-size: 16px; margin: 20px 0 6px; }
+.details { color: #666; font-size: 13px; }
+.player { position: sticky; top: 0; background: #fff; padding: 10px 0; border-bottom: 1px solid #e5e5e5; z-index: 1; }
+.player audio { width: 100%; }
+ul.tasks { list-style: none; padding-left: 4px; }
+blockquote { margin: 8px 0; padding-left: 12px; border-left: 3px solid #ccc; color: #444; }
+table { border-collapse: collapse; margin: 8px 0; }
+th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }
+th { background: #f3f6f9; }
+code { background: #f3f3f3; padding: 0 3px; border-radius: 3px; }
+.segment { margin: 0; padding: 6px 8px; border-radius: 6px; }
+.segment .time { color: #1f4e79; font-variant-numeric: tabular-nums; font-size: 12px; margin-right: 6px; }
+.segment .speaker { font-weight: 600; margin-right: 4px; }
+.seekable .segment[data-start] { cursor: pointer; }
+.seekable .segment[data-start]:hover { background: #f3f6f9; }
+.segment.playing { background: #e4eef8; }
+.comment { margin: 2px 0 6px 24px; padding: 4px 10px; border-left: 3px solid #e0b252; background: #fdf8ec; font-size: 13px; color: #444; }
+.comment .author { font-weight: 600; margin-right: 4px; }
+"#;
+
+/// Click a segment to play from it; the segment being played is highlighted
+const SCRIPT: &str = r#"
+(function () {
+  var player = document.getElementById("player");
+  if (!player) return;
+  var segments = Array.prototype.slice.call(document.querySelectorAll(".segment[data-start]"));
+  segments.forEach(function (segment) {
+    segment.addEventListener("click", function () {
+      player.currentTime = parseFloat(segment.dataset.start);
+      player.play();
+    });
+  });
+  player.addEventListener("timeupdate", function () {
+    var time = player.currentTime;
+    segments.forEach(function (segment) {
+      var start = parseFloat(segment.dataset.start);
+      var end = parseFloat(segment.dataset.end || segment.dataset.start);
+      segment.classList.toggle("playing", time >= start && time < end);
+    });
+  });
+})();
+"#;
+
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+static BOLD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\*\*(.+?)\*\*").unwrap());
+static CODE: Lazy<Regex> = Lazy::new(|| Regex::new(r"`([^`]+)`").unwrap());
+static WEB_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\((https?://[^)\s]+)\)").unwrap());
+static OTHER_LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\([^)]*\)").unwrap());
+static NUMBERED: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+[.)]\s+(.*)$").unwrap());
+
+/// Escaped inline markdown with bold, code and web links kept
+fn inline_html(text: &str) -> String {
+    let html = escape(text);
+    let html = WEB_LINK.replace_all(&html, r#"<a href="$2">$1</a>"#);
+    let html = OTHER_LINK.replace_all(&html, "$1");
+    let html = BOLD.replace_all(&html, "<strong>$1</strong>");
+    CODE.replace_all(&html, "<code>$1</code>").into_owned()
+}
+
+fn table_html(rows: &[&str]) -> String {
+    let mut html = String::from("<table>");
+    let mut header = true;
+    for row in rows {
+        if row.trim_matches(|c| c == '|' || c == '-' || c == ':' || c == ' ').is_empty() {
+            continue;
+        }
+        let tag = if header { "th" } else { "td" };
+        html.push_str("<tr>");
+        for cell in row.trim().trim_matches('|').split('|') {
+            html.push_str(&format!("<{tag}>{}</{tag}>", inline_html(cell.trim())));
+        }
+        html.push_str("</tr>");
+        header = false;
+    }
+    html.push_str("</table>");
+    html
+}
+
+/// Minutes markdown as HTML: headings, bullet, numbered and task lists, quotes, tables and
+/// paragraphs (nested list items are indented rather than nested)
+pub(crate) fn markdown_html(markdown: &str) -> String {
+    let mut html = String::new();
+    let mut open_list: Option<&str> = None;
+    let mut table_rows: Vec<&str> = Vec::new();
+    for raw in markdown.lines() {
+        let line = raw.trim();
+        if line.starts_with('|') {
+            table_rows.push(line);
+            continue;
+        }
+        if !table_rows.is_empty() {
+            html.push_str(&table_html(&table_rows));
+            table_rows.clear();
+        }
+
+        let level = (raw.len() - raw.trim_start().len()) / 2;
+        let indent = if level > 0 { format!(r#" style="margin-left:{}px""#, level * 20) } else { String::new() };
+        let task = ["- [ ] ", "* [ ] "]
+            .iter()
+            .find_map(|marker| line.strip_prefix(marker))
+            .map(|item| (false, item))
+            .or_else(|| ["- [x] ", "- [X] ", "* [x] "].iter().find_map(|marker| line.strip_prefix(marker)).map(|item| (true, item)));
+        let (list, item) = if let Some((done, item)) = task {
+            let checkbox = format!(r#"<input type="checkbox" disabled{}> "#, if done { " checked" } else { "" });
+            (Some(r#"ul class="tasks""#), Some(format!("{}{}", checkbox, inline_html(item))))
+        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            (Some("ul"), Some(inline_html(item)))
+        } else if let Some(captures) = NUMBERED.captures(line) {
+            (Some("ol"), Some(inline_html(&captures[1])))
+        } else {
+            (None, None)
+        };
+
+        if open_list.is_some() && open_list != list {
+            let tag = open_list.take().unwrap_or_default();
+            html.push_str(&format!("</{}>", tag.split(' ').next().unwrap_or(tag)));
+        }
+        if let (Some(list), Some(item)) = (list, item) {
+            if open_list.is_none() {
+                html.push_str(&format!("<{}>", list));
+                open_list = Some(list);
+            }
+            html.push_str(&format!("<li{}>{}</li>", indent, item));
+            continue;
+        }
+
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if line.is_empty() || (line.len() >= 3 && line.chars().all(|c| c == '-' || c == '*' || c == '_')) {
+            continue;
+        } else if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            // The page title is the only h1
+            let level = (hashes + 1).min(6);
+            html.push_str(&format!("<h{level}>{}</h{level}>", inline_html(line[hashes..].trim())));
+        } else if let Some(quote) = line.strip_prefix('>') {
+            html.push_str(&format!("<blockquote>{}</blockquote>", inline_html(quote.trim())));
+        } else {
+            html.push_str(&format!("<p>{}</p>", inline_html(line)));
+        }
+    }
+    if let Some(tag) = open_list {
+        html.push_str(&format!("</{}>", tag.split(' ').next().unwrap_or(tag)));
+    }
+    if !table_rows.is_empty() {
+        html.push_str(&table_html(&table_rows));
+    }
+    html
+}
+
+/// The page; with `audio` (mime type, bytes) the recording is embedded and segments seek it
+pub fn render_html(document: &MeetingDocument, audio: Option<(&str, &[u8])>) -> String {
+    let mut body = format!("<header><h1>{}</h1>", escape(&document.title));
+    let mut details = vec![document.local_date()];
+    if let Some(duration) = document.duration_seconds() {
+        details.push(format_duration(duration));
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        details.push(participants.join(", "));
+    }
+    body.push_str(&format!(r#"<div class="details">{}</div>"#, escape(&details.join(" · "))));
+    if let Some(description) = &document.description {
+        body.push_str(&format!("<p>{}</p>", escape(description)));
+    }
+    body.push_str("</header>");
+
+    if let Some((mime, bytes)) = audio {
+        body.push_str(&format!(
+            r#"<div class="player"><audio id="player" controls preload="metadata" src="data:{};base64,{}"></audio></div>"#,
+            mime,
+            STANDARD.encode(bytes)
+        ));
+    }
+
+    body.push_str(r#"<section class="summary">"#);
+    match &document.summary {
+        Some(summary) => body.push_str(&markdown_html(summary)),
+        None => body.push_str("<p><em>No summary has been generated for this meeting yet.</em></p>"),
+    }
+    body.push_str("</section>");
+
+    if !document.action_items.is_empty() {
+        body.push_str(r#"<section class="action-items"><h2>Action Items</h2><ul class="tasks">"#);
+        for item in &document.action_items {
+            let mut text = escape(item.description.trim());
+            if let Some(assignee) = &item.assignee {
+                text.push_str(&format!(" — <strong>{}</strong>", escape(assignee)));
+            }
+            if let Some(due_date) = &item.due_date {
+                text.push_str(&format!(" (due {})", escape(due_date)));
+            }
+            body.push_str(&format!(
+                r#"<li><input type="checkbox" disabled{}> {}</li>"#,
+                if item.done { " checked" } else { "" },
+                text
+            ));
+        }
+        body.push_str("</ul></section>");
+    }
+
+    let segments: Vec<_> = document.segments.iter().filter(|segment| !segment.text.is_empty()).collect();
+    if !segments.is_empty() {
+        let class = if audio.is_some() { "transcript seekable" } else { "transcript" };
+        body.push_str(&format!(r#"<section class="{}"><h2>Transcript</h2>"#, class));
+        for segment in segments {
+            let mut attributes = String::new();
+            let mut time = String::new();
+            if let Some(start) = segment.start {
+                attributes.push_str(&format!(r#" data-start="{:.2}""#, start));
+                time = format!(r#"<span class="time">{}</span>"#, format_timestamp(start));
+            }
+            if let Some(end) = segment.end {
+                attributes.push_str(&format!(r#" data-end="{:.2}""#, end));
+            }
+            let speaker = segment
+                .speaker
+                .as_ref()
+                .map(|speaker| format!(r#"<span class="speaker">{}:</span>"#, escape(speaker)))
+                .unwrap_or_default();
+            body.push_str(&format!(
+                r#"<p class="segment"{}>{}{}{}</p>"#,
+                attributes,
+                time,
+                speaker,
+                escape(&segment.text)
+            ));
+            for comment in &segment.comments {
+                body.push_str(&format!(
+                    r#"<p class="comment"><span class="author">{}:</span> {}</p>"#,
+                    escape(&comment.author),
+                    escape(&comment.text).replace('\n', "<br>")
+                ));
+            }
+        }
+        body.push_str("</section>");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n{}\n<script>{}</script>\n</body>\n</html>\n",
+        escape(&document.title),
+        STYLE,
+        body,
+        SCRIPT
+    )
+}
+
+/// Export a meeting to one HTML file that can be shared with people who don't use the app
+///
+/// # Arguments
+/// * `include_audio` - Embed the recording (default true); it makes the file roughly a
+///   third larger than the recording itself
+///
+/// # Returns
+/// The path written (".html" is added when the chosen path has no extension)
+#[command]
+pub async fn export_meeting_html<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+    include_audio: Option<bool>,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let document = load_document(pool, &meeting_id, &load_export_settings(pool).await?).await?;
+
+    let audio = match (&document.folder_path, include_audio.unwrap_or(true)) {
+        (Some(folder), true) => {
+            let audio_path = Path::new(folder).join("audio.mp4");
+            match std::fs::read(&audio_path) {
+                Ok(bytes) => Some(bytes),
+                Err(e) => {
+                    warn!("Exporting meeting {} without audio, {} unreadable: {}", meeting_id, audio_path.display(), e);
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+    let html = render_html(&document, audio.as_deref().map(|bytes| ("audio/mp4", bytes)));
+
+    let path = export_path(&path, "html");
+    write_export(&path, html.as_bytes())?;
+    info!(
+        "Exported meeting {} to {} ({} bytes, audio {})",
+        meeting_id,
+        path.display(),
+        html.len(),
+        if audio.is_some() { "embedded" } else { "not included" }
+    );
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::ActionItem;
+    use crate::export::document::ExportSegment;
+    use chrono::Utc;
+
+    #[test]
+    fn embeds_audio_and_makes_segments_seekable() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "R&D <sync>".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some(
+                "## Decisions\n- Move to **Postgres** in March\n- See [the plan](https://example.com/plan?a=1&b=2)\n\n<script>alert(1)</script>\n"
+                    .to_string(),
+            ),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Draft the migration plan".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: None,
+                segment_id: None,
+                done: true,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![ExportSegment {
+                id: "segment-1".to_string(),
+                start: Some(65.0),
+                end: Some(70.5),
+                speaker: Some("Ana".to_string()),
+                text: "Postgres in March.".to_string(),
+                confidence: None,
+                language: None,
+                comments: Vec::new(),
+            }],
+        };
+
+        let html = render_html(&document, Some(("audio/mp4", b"abc")));
+        assert!(html.contains("<title>R&amp;D &lt;sync&gt;</title>"));
+        assert!(html.contains(r#"src="data:audio/mp4;base64,YWJj""#));
+        assert!(html.contains(
+            r#"<h3>Decisions</h3><ul><li>Move to <strong>Postgres</strong> in March</li><li>See <a href="https://example.com/plan?a=1&amp;b=2">the plan</a></li></ul>"#
+        ));
+        assert!(html.contains("<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>"));
+        assert!(html.contains(r#"<li><input type="checkbox" disabled checked> Draft the migration plan — <strong>Ana</strong></li>"#));
+        assert!(html.contains(
+            r#"<section class="transcript seekable"><h2>Transcript</h2><p class="segment" data-start="65.00" data-end="70.50"><span class="time">00:01:05</span><span class="speaker">Ana:</span>Postgres in March.</p>"#
+        ));
+
+        let without_audio = render_html(&document, None);
+        assert!(!without_audio.contains("<audio"));
+        assert!(without_audio.contains(r#"<section class="transcript">"#));
+    }
+}
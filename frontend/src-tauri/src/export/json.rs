@@ -0,0 +1,206 @@
+// Structured export
+// Machine-readable meeting data for people feeding meetings into their own tools: one JSON
+// document, or JSON Lines with one record (meeting, summary, action item, segment) per line
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::document::{ExportSegment, MeetingDocument};
+use super::{export_path, load_document, load_export_settings, write_export};
+use crate::database::models::ActionItem;
+use crate::state::AppState;
+
+/// Bumped when a field is renamed or removed; new fields don't change it
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonExportFormat {
+    Json,
+    Jsonl,
+}
+
+#[derive(Serialize)]
+pub(crate) struct MeetingRecord<'a> {
+    id: &'a str,
+    title: &'a str,
+    created_at: DateTime<Utc>,
+    description: Option<&'a str>,
+    agenda: Option<&'a str>,
+    duration_seconds: Option<f64>,
+    participants: Vec<String>,
+}
+
+impl<'a> MeetingRecord<'a> {
+    pub(crate) fn new(document: &'a MeetingDocument) -> Self {
+        Self {
+            id: &document.id,
+            title: &document.title,
+            created_at: document.created_at,
+            description: document.description.as_deref(),
+            agenda: document.agenda.as_deref(),
+            duration_seconds: document.duration_seconds(),
+            participants: document.participants(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    format_version: u32,
+    meeting: MeetingRecord<'a>,
+    /// Minutes markdown
+    summary: Option<&'a str>,
+    action_items: &'a [ActionItem],
+    segments: &'a [ExportSegment],
+}
+
+/// One JSON Lines record; each carries the meeting id so lines can be processed alone
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonlRecord<'a> {
+    Meeting {
+        format_version: u32,
+        #[serde(flatten)]
+        meeting: MeetingRecord<'a>,
+    },
+    Summary {
+        meeting_id: &'a str,
+        markdown: &'a str,
+    },
+    ActionItem(&'a ActionItem),
+    Segment {
+        meeting_id: &'a str,
+        #[serde(flatten)]
+        segment: &'a ExportSegment,
+    },
+}
+
+pub(crate) fn render_json(document: &MeetingDocument) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&JsonExport {
+        format_version: FORMAT_VERSION,
+        meeting: MeetingRecord::new(document),
+        summary: document.summary.as_deref(),
+        action_items: &document.action_items,
+        segments: &document.segments,
+    })
+}
+
+fn render_jsonl(document: &MeetingDocument) -> Result<String, serde_json::Error> {
+    let mut records = vec![JsonlRecord::Meeting {
+        format_version: FORMAT_VERSION,
+        meeting: MeetingRecord::new(document),
+    }];
+    if let Some(summary) = &document.summary {
+        records.push(JsonlRecord::Summary {
+            meeting_id: &document.id,
+            markdown: summary,
+        });
+    }
+    records.extend(document.action_items.iter().map(JsonlRecord::ActionItem));
+    records.extend(document.segments.iter().map(|segment| JsonlRecord::Segment {
+        meeting_id: &document.id,
+        segment,
+    }));
+
+    let mut jsonl = String::new();
+    for record in &records {
+        jsonl.push_str(&serde_json::to_string(record)?);
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+/// Export a meeting as structured data: segments with times, speaker, confidence and
+/// language, plus the summary and action items
+///
+/// # Arguments
+/// * `format` - "json" for one document, "jsonl" for one record per line
+///
+/// # Returns
+/// The path written
+#[command]
+pub async fn export_meeting_json<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+    format: JsonExportFormat,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let document = load_document(pool, &meeting_id, &load_export_settings(pool).await?).await?;
+
+    let (contents, extension) = match format {
+        JsonExportFormat::Json => (render_json(&document), "json"),
+        JsonExportFormat::Jsonl => (render_jsonl(&document), "jsonl"),
+    };
+    let contents = contents.map_err(|e| format!("Failed to serialize meeting: {}", e))?;
+
+    let path = export_path(&path, extension);
+    write_export(&path, contents.as_bytes())?;
+    info!("Exported meeting {} to {}", meeting_id, path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn jsonl_has_one_self_contained_record_per_line() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Platform sync".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- Postgres in March\n".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Draft the migration plan".to_string(),
+                assignee: None,
+                due_date: None,
+                segment_id: Some("segment-1".to_string()),
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![ExportSegment {
+                id: "segment-1".to_string(),
+                start: Some(65.0),
+                end: Some(70.5),
+                speaker: Some("Ana".to_string()),
+                text: "Postgres in March.".to_string(),
+                confidence: Some(0.92),
+                language: Some("en".to_string()),
+                comments: Vec::new(),
+            }],
+        };
+
+        let records: Vec<Value> = render_jsonl(&document)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let types: Vec<&str> = records.iter().map(|record| record["type"].as_str().unwrap()).collect();
+        assert_eq!(types, ["meeting", "summary", "action_item", "segment"]);
+        assert_eq!(records[0]["format_version"], 1);
+        assert_eq!(records[0]["participants"][0], "Ana");
+        assert_eq!(records[0]["duration_seconds"], 5.5);
+        assert_eq!(records[2]["segment_id"], "segment-1");
+        assert_eq!(records[3]["meeting_id"], "meeting-1");
+        assert_eq!(records[3]["confidence"], 0.92);
+        assert_eq!(records[3]["language"], "en");
+
+        let json: Value = serde_json::from_str(&render_json(&document).unwrap()).unwrap();
+        assert_eq!(json["meeting"]["title"], "Platform sync");
+        assert_eq!(json["segments"][0]["start"], 65.0);
+        assert_eq!(json["action_items"][0]["description"], "Draft the migration plan");
+    }
+}
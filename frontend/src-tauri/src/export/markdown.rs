@@ -0,0 +1,244 @@
+// Markdown export
+// Renders a meeting through a Markdown template with {{placeholders}}; the template can be
+// customized in the export settings or passed per export
+
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::document::{format_duration, MeetingDocument};
+use super::{export_path, load_document, load_export_settings, write_export};
+use crate::database::models::ActionItem;
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+pub const DEFAULT_MARKDOWN_TEMPLATE: &str = "\
+# {{title}}
+
+- **Date:** {{date}}
+- **Duration:** {{duration}}
+- **Participants:** {{participants}}
+
+{{description}}
+
+## Summary
+
+{{summary}}
+
+## Action Items
+
+{{action_items}}
+
+## Transcript
+
+{{transcript}}
+";
+
+/// Placeholders a template can use
+pub const MARKDOWN_PLACEHOLDERS: &[&str] = &[
+    "title",
+    "date",
+    "duration",
+    "participants",
+    "description",
+    "agenda",
+    "summary",
+    "action_items",
+    "transcript",
+];
+
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z_]+)\s*\}\}").unwrap());
+static EXTRA_BLANK_LINES: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n{3,}").unwrap());
+
+pub fn validate_template(template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("The export template is empty".to_string());
+    }
+    match PLACEHOLDER
+        .captures_iter(template)
+        .find(|captures| !MARKDOWN_PLACEHOLDERS.contains(&&captures[1]))
+    {
+        Some(captures) => Err(format!(
+            "Unknown placeholder {{{{{}}}}}. Available: {}",
+            &captures[1],
+            MARKDOWN_PLACEHOLDERS.join(", ")
+        )),
+        None => Ok(()),
+    }
+}
+
+fn render_action_items(items: &[ActionItem]) -> String {
+    if items.is_empty() {
+        return "_No action items._".to_string();
+    }
+    items
+        .iter()
+        .map(|item| {
+            let mut line = format!("- [{}] {}", if item.done { "x" } else { " " }, item.description.trim());
+            if let Some(assignee) = &item.assignee {
+                line.push_str(&format!(" — **{}**", assignee));
+            }
+            if let Some(due_date) = &item.due_date {
+                line.push_str(&format!(" (due {})", due_date));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_transcript(document: &MeetingDocument) -> String {
+    if document.segments.is_empty() {
+        return "_No transcript._".to_string();
+    }
+    document
+        .segments
+        .iter()
+        .filter(|segment| !segment.text.is_empty())
+        .map(|segment| {
+            let time = segment.start.map(|start| format!("[{}] ", format_timestamp(start)));
+            let mut text = match &segment.speaker {
+                Some(speaker) => format!("**{}{}:** {}", time.unwrap_or_default(), speaker, segment.text),
+                None => format!("{}{}", time.unwrap_or_default(), segment.text),
+            };
+            for comment in &segment.comments {
+                let quoted = comment.text.lines().collect::<Vec<_>>().join("\n> ");
+                text.push_str(&format!("\n\n> **{}:** {}", comment.author, quoted));
+            }
+            text
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Fill a template with the meeting; placeholders without a value render empty
+pub fn render_markdown(document: &MeetingDocument, template: &str) -> String {
+    let participants = document.participants();
+    let rendered = PLACEHOLDER.replace_all(template, |captures: &Captures| match &captures[1] {
+        "title" => document.title.clone(),
+        "date" => document.local_date(),
+        "duration" => document
+            .duration_seconds()
+            .map(format_duration)
+            .unwrap_or_else(|| "Unknown".to_string()),
+        "participants" if participants.is_empty() => "Not recorded".to_string(),
+        "participants" => participants.join(", "),
+        "description" => document.description.clone().unwrap_or_default(),
+        "agenda" => document.agenda.clone().unwrap_or_default(),
+        "summary" => document
+            .summary
+            .as_deref()
+            .map(|summary| summary.trim().to_string())
+            .unwrap_or_else(|| "_No summary yet._".to_string()),
+        "action_items" => render_action_items(&document.action_items),
+        "transcript" => render_transcript(document),
+        _ => captures[0].to_string(),
+    });
+    let mut markdown = EXTRA_BLANK_LINES.replace_all(rendered.trim(), "\n\n").into_owned();
+    markdown.push('\n');
+    markdown
+}
+
+/// Export a meeting to a Markdown file
+///
+/// # Arguments
+/// * `path` - File chosen by the user; ".md" is added when it has no extension
+/// * `template` - Template for this export only; defaults to the one in export settings
+///
+/// # Returns
+/// The path written
+#[command]
+pub async fn export_meeting_markdown<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+    template: Option<String>,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let settings = load_export_settings(pool).await?;
+    let template = match template.filter(|template| !template.trim().is_empty()) {
+        Some(template) => {
+            validate_template(&template)?;
+            template
+        }
+        None => settings
+            .markdown_template
+            .clone()
+            .unwrap_or_else(|| DEFAULT_MARKDOWN_TEMPLATE.to_string()),
+    };
+    let document = load_document(pool, &meeting_id, &settings).await?;
+    let markdown = render_markdown(&document, &template);
+
+    let path = export_path(&path, "md");
+    write_export(&path, markdown.as_bytes())?;
+    info!("Exported meeting {} to {}", meeting_id, path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::document::{ExportComment, ExportSegment};
+    use chrono::Utc;
+
+    fn segment(start: f64, speaker: Option<&str>, text: &str) -> ExportSegment {
+        ExportSegment {
+            id: format!("segment-{}", start),
+            start: Some(start),
+            end: Some(start + 4.0),
+            speaker: speaker.map(str::to_string),
+            text: text.to_string(),
+            confidence: None,
+            language: None,
+            comments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn renders_meeting_through_template() {
+        let mut document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Platform sync".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- Move to Postgres in March\n".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Draft the migration plan".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: Some("2026-02-01".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![
+                segment(0.0, Some("Ana"), "Let's start."),
+                segment(65.0, Some("Speaker 2"), "Postgres in March."),
+                segment(70.0, Some("Ana"), "Agreed."),
+            ],
+        };
+        document.segments[1].comments.push(ExportComment {
+            author: "Bo".to_string(),
+            text: "Check the licence cost\nfirst".to_string(),
+            created_at: Utc::now(),
+        });
+
+        let markdown = render_markdown(&document, DEFAULT_MARKDOWN_TEMPLATE);
+        assert!(markdown.starts_with("# Platform sync\n"));
+        assert!(markdown.contains("- **Duration:** 1 min\n- **Participants:** Ana, Speaker 2\n\n## Summary"));
+        assert!(markdown.contains("- [ ] Draft the migration plan — **Ana** (due 2026-02-01)"));
+        assert!(markdown.contains("**[00:01:05] Speaker 2:** Postgres in March.\n\n> **Bo:** Check the licence cost\n> first\n\n"));
+        assert!(!markdown.contains("\n\n\n"));
+
+        assert!(validate_template("# {{ title }}\n{{summary}}").is_ok());
+        assert!(validate_template("{{title}} {{attendees}}").unwrap_err().contains("{{attendees}}"));
+    }
+}
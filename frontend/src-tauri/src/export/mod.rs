@@ -0,0 +1,102 @@
+// Meeting export
+// Renders a stored meeting (metadata, minutes, action items, transcript) to files that can
+// be shared with people who don't use the app
+
+pub mod bulk;
+pub mod csv;
+pub mod document;
+pub mod docx;
+pub mod html;
+pub mod json;
+pub mod markdown;
+pub mod obsidian;
+pub mod pdf;
+pub mod subtitles;
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportSettings {
+    /// Markdown export template with {{placeholders}}; None uses the built-in one
+    #[serde(default)]
+    pub markdown_template: Option<String>,
+    /// Header, footer and branding of PDF exports
+    #[serde(default)]
+    pub pdf: pdf::PdfSettings,
+    /// Show reviewers' segment comments under the transcript segments
+    #[serde(default)]
+    pub include_comments: bool,
+    /// Vault and folder of Obsidian notes
+    #[serde(default)]
+    pub obsidian: obsidian::ObsidianSettings,
+}
+
+/// The chosen path, with `extension` added when it has none
+pub(crate) fn export_path(path: &str, extension: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.extension().is_none() {
+        path.with_extension(extension)
+    } else {
+        path
+    }
+}
+
+/// Write an export file, creating its folder if needed
+pub(crate) fn write_export(path: &Path, contents: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+pub(crate) async fn load_export_settings(pool: &sqlx::SqlitePool) -> Result<ExportSettings, String> {
+    SettingsRepository::get_export_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load export settings: {}", e))
+}
+
+/// A meeting to export, with segment comments when `settings` include them
+pub(crate) async fn load_document(
+    pool: &sqlx::SqlitePool,
+    meeting_id: &str,
+    settings: &ExportSettings,
+) -> Result<document::MeetingDocument, String> {
+    crate::protection::ensure_unlocked(pool, meeting_id).await?;
+    let mut document = document::MeetingDocument::load(pool, meeting_id).await?;
+    if settings.include_comments {
+        document.load_comments(pool).await?;
+    }
+    Ok(document)
+}
+
+#[command]
+pub async fn get_export_settings<R: Runtime>(app: AppHandle<R>) -> Result<ExportSettings, String> {
+    let state = app.state::<AppState>();
+    load_export_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_export_settings<R: Runtime>(app: AppHandle<R>, settings: ExportSettings) -> Result<(), String> {
+    let mut settings = settings;
+    settings.markdown_template = settings.markdown_template.filter(|template| !template.trim().is_empty());
+    if let Some(template) = &settings.markdown_template {
+        markdown::validate_template(template)?;
+    }
+    if let Some(color) = &settings.pdf.accent_color {
+        if pdf::parse_color(color).is_none() {
+            return Err(format!("Invalid accent color '{}'; use the #RRGGBB form", color));
+        }
+    }
+    settings.obsidian.validate()?;
+
+    let state = app.state::<AppState>();
+    SettingsRepository::save_export_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save export settings: {}", e))
+}
@@ -0,0 +1,317 @@
+// Obsidian export
+// Writes meetings as notes into an Obsidian vault folder. Each note starts with YAML
+// frontmatter Obsidian shows as properties (date, duration, tags, participants, topics) and
+// links participants and topics (the meeting's chapters) as [[wiki-links]], so people and
+// subjects get backlinks across meetings. A meeting's note is found again by its
+// meetily_id, so exporting again updates the note even after it was renamed in the vault.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::bulk::{file_stem, BulkExportReport};
+use super::document::{format_duration, MeetingDocument};
+use super::{load_document, load_export_settings, write_export, ExportSettings};
+use crate::database::repositories::meeting_chapter::MeetingChaptersRepository;
+use crate::database::repositories::meeting_tag::MeetingTagsRepository;
+use crate::library::{matching_meetings, MeetingFilter};
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObsidianSettings {
+    /// Vault folder (the one holding .obsidian)
+    #[serde(default)]
+    pub vault_path: Option<String>,
+    /// Folder inside the vault for meeting notes, e.g. "Meetings"; None writes to the vault root
+    #[serde(default)]
+    pub folder: Option<String>,
+    #[serde(default)]
+    pub include_transcript: bool,
+}
+
+impl ObsidianSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(vault) = &self.vault_path {
+            if !Path::new(vault).is_dir() {
+                return Err(format!("The Obsidian vault folder {} does not exist", vault));
+            }
+        }
+        if let Some(folder) = &self.folder {
+            let folder = Path::new(folder);
+            if folder.is_absolute() || folder.components().any(|part| part == std::path::Component::ParentDir) {
+                return Err("The notes folder must be a folder inside the vault".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// Folder the notes are written to
+    fn notes_dir(&self) -> Result<PathBuf, String> {
+        let vault = self
+            .vault_path
+            .as_deref()
+            .filter(|vault| !vault.trim().is_empty())
+            .ok_or_else(|| "Choose your Obsidian vault in the export settings".to_string())?;
+        let mut dir = PathBuf::from(vault.trim());
+        if let Some(folder) = self.folder.as_deref().map(str::trim).filter(|folder| !folder.is_empty()) {
+            dir.push(folder);
+        }
+        Ok(dir)
+    }
+}
+
+/// A note or link target: characters Obsidian rejects in note names removed
+fn link_target(name: &str) -> String {
+    name.replace(['[', ']', '|', '#', '^', '\\', '/', ':'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn wiki_link(name: &str) -> String {
+    format!("[[{}]]", link_target(name))
+}
+
+/// A double-quoted YAML string
+fn yaml_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " "))
+}
+
+/// Obsidian tags can't contain spaces; "Sales call" becomes "sales-call"
+fn obsidian_tag(tag: &str) -> String {
+    tag.split_whitespace().collect::<Vec<_>>().join("-").replace([',', '#'], "")
+}
+
+fn yaml_list(key: &str, values: &[String]) -> String {
+    if values.is_empty() {
+        return format!("{}: []\n", key);
+    }
+    let items: String = values.iter().map(|value| format!("  - {}\n", yaml_string(value))).collect();
+    format!("{}:\n{}", key, items)
+}
+
+/// The note for a meeting: frontmatter, then minutes, action items and optionally the transcript
+fn render_note(document: &MeetingDocument, tags: &[String], topics: &[String], include_transcript: bool) -> String {
+    let participants = document.participants();
+    let participant_links: Vec<String> = participants.iter().map(|name| wiki_link(name)).collect();
+    let topic_links: Vec<String> = topics.iter().map(|topic| wiki_link(topic)).collect();
+    let mut note_tags = vec!["meeting".to_string()];
+    for tag in tags.iter().map(|tag| obsidian_tag(tag)).filter(|tag| !tag.is_empty()) {
+        if !note_tags.contains(&tag) {
+            note_tags.push(tag);
+        }
+    }
+
+    let mut note = String::from("---\n");
+    note.push_str(&format!("title: {}\n", yaml_string(&document.title)));
+    note.push_str(&format!("date: {}\n", document.created_at.with_timezone(&chrono::Local).format("%Y-%m-%dT%H:%M")));
+    if let Some(duration) = document.duration_seconds() {
+        note.push_str(&format!("duration: {}\n", yaml_string(&format_duration(duration))));
+    }
+    note.push_str(&yaml_list("tags", &note_tags));
+    note.push_str(&yaml_list("participants", &participant_links));
+    note.push_str(&yaml_list("topics", &topic_links));
+    note.push_str(&format!("meetily_id: {}\n", yaml_string(&document.id)));
+    note.push_str("---\n\n");
+
+    note.push_str(&format!("# {}\n\n", document.title));
+    if !participant_links.is_empty() {
+        note.push_str(&format!("**Participants:** {}\n", participant_links.join(", ")));
+    }
+    if !topic_links.is_empty() {
+        note.push_str(&format!("**Topics:** {}\n", topic_links.join(", ")));
+    }
+    if let Some(description) = document.description.as_deref().map(str::trim).filter(|text| !text.is_empty()) {
+        note.push_str(&format!("\n{}\n", description));
+    }
+
+    note.push_str("\n## Summary\n\n");
+    note.push_str(document.summary.as_deref().map(str::trim).unwrap_or("_No summary yet._"));
+    note.push('\n');
+
+    if !document.action_items.is_empty() {
+        note.push_str("\n## Action Items\n\n");
+        for item in &document.action_items {
+            note.push_str(&format!("- [{}] {}", if item.done { "x" } else { " " }, item.description.trim()));
+            if let Some(assignee) = &item.assignee {
+                note.push_str(&format!(" — {}", wiki_link(assignee)));
+            }
+            // Tasks-plugin style due date
+            if let Some(due_date) = &item.due_date {
+                note.push_str(&format!(" 📅 {}", due_date));
+            }
+            note.push('\n');
+        }
+    }
+
+    if include_transcript && !document.segments.is_empty() {
+        note.push_str("\n## Transcript\n\n");
+        for segment in document.segments.iter().filter(|segment| !segment.text.is_empty()) {
+            let time = segment.start.map(|start| format!("[{}] ", format_timestamp(start))).unwrap_or_default();
+            match &segment.speaker {
+                Some(speaker) => note.push_str(&format!("**{}{}:** {}\n\n", time, speaker, segment.text)),
+                None => note.push_str(&format!("{}{}\n\n", time, segment.text)),
+            }
+        }
+    }
+    format!("{}\n", note.trim_end())
+}
+
+/// The note in `dir` exported from this meeting before, found by its frontmatter id
+fn existing_note(dir: &Path, meeting_id: &str) -> Option<PathBuf> {
+    let id_line = format!("meetily_id: {}", yaml_string(meeting_id));
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "md"))
+        .find(|path| {
+            std::fs::read_to_string(path).is_ok_and(|text| {
+                let frontmatter = text.strip_prefix("---\n").and_then(|rest| rest.split("\n---").next()).unwrap_or_default();
+                frontmatter.lines().any(|line| line.trim_end() == id_line)
+            })
+        })
+}
+
+/// Write a meeting's note; `taken` keeps meetings in one export from sharing a file
+async fn write_note(
+    pool: &sqlx::SqlitePool,
+    settings: &ExportSettings,
+    dir: &Path,
+    meeting_id: &str,
+    taken: &mut HashSet<PathBuf>,
+) -> Result<PathBuf, String> {
+    let document = load_document(pool, meeting_id, settings).await?;
+    let tags = MeetingTagsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))?;
+    let topics: Vec<String> = MeetingChaptersRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load chapters: {}", e))?
+        .into_iter()
+        .map(|chapter| chapter.title)
+        .collect();
+
+    let path = match existing_note(dir, meeting_id) {
+        Some(path) => path,
+        None => {
+            let stem = link_target(&file_stem(&document.title, document.created_at));
+            let mut path = dir.join(format!("{}.md", stem));
+            let mut number = 2;
+            while path.exists() || taken.contains(&path) {
+                path = dir.join(format!("{} ({}).md", stem, number));
+                number += 1;
+            }
+            path
+        }
+    };
+    taken.insert(path.clone());
+    let note = render_note(&document, &tags, &topics, settings.obsidian.include_transcript);
+    write_export(&path, note.as_bytes())?;
+    Ok(path)
+}
+
+/// Write a meeting as a note in the Obsidian vault; returns the note's path
+#[command]
+pub async fn export_meeting_obsidian<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_export_settings(pool).await?;
+    let dir = settings.obsidian.notes_dir()?;
+
+    let path = write_note(pool, &settings, &dir, &meeting_id, &mut HashSet::new()).await?;
+    info!("Exported meeting {} to {}", meeting_id, path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Write every meeting, or those matching `filter`, as notes in the Obsidian vault
+#[command]
+pub async fn export_meetings_obsidian<R: Runtime>(
+    app: AppHandle<R>,
+    filter: Option<MeetingFilter>,
+) -> Result<BulkExportReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_export_settings(pool).await?;
+    let dir = settings.obsidian.notes_dir()?;
+    let meetings = matching_meetings(pool, &filter.unwrap_or_default()).await?;
+
+    let mut report = BulkExportReport {
+        directory: dir.to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    let mut taken = HashSet::new();
+    for meeting in meetings.iter().rev() {
+        match write_note(pool, &settings, &dir, &meeting.id, &mut taken).await {
+            Ok(_) => report.exported += 1,
+            Err(e) => {
+                warn!("Failed to export meeting {} to Obsidian: {}", meeting.id, e);
+                report.errors.push(format!("{}: {}", meeting.title, e));
+            }
+        }
+    }
+    info!("Exported {} meetings to {}", report.exported, report.directory);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::ActionItem;
+    use crate::export::document::ExportSegment;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn renders_note_with_frontmatter_and_links() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Roadmap \"Q2\" review".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- Ship v2\n".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Draft the plan".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: Some("2026-02-20".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![ExportSegment {
+                id: "segment-1".to_string(),
+                start: Some(0.0),
+                end: Some(90.0),
+                speaker: Some("Ana".to_string()),
+                text: "Let's start.".to_string(),
+                confidence: None,
+                language: None,
+                comments: Vec::new(),
+            }],
+        };
+        let note = render_note(&document, &["product team".to_string()], &["Pricing [draft]".to_string()], false);
+        assert!(note.starts_with("---\ntitle: \"Roadmap \\\"Q2\\\" review\"\ndate: "));
+        assert!(note.contains("duration: \"2 min\"\ntags:\n  - \"meeting\"\n  - \"product-team\"\n"));
+        assert!(note.contains("participants:\n  - \"[[Ana]]\"\ntopics:\n  - \"[[Pricing draft]]\"\nmeetily_id: \"meeting-1\"\n---\n"));
+        assert!(note.contains("**Topics:** [[Pricing draft]]\n"));
+        assert!(note.contains("- [ ] Draft the plan — [[Ana]] 📅 2026-02-20\n"));
+        assert!(!note.contains("## Transcript"));
+        assert!(render_note(&document, &[], &[], true).contains("## Transcript\n\n**[00:00:00] Ana:** Let's start.\n"));
+
+        let dir = std::env::temp_dir().join(format!("obsidian-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Renamed.md"), &note).unwrap();
+        assert_eq!(existing_note(&dir, "meeting-1"), Some(dir.join("Renamed.md")));
+        assert_eq!(existing_note(&dir, "meeting-2"), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,590 @@
+// PDF export
+// Lays the minutes out on A4 pages with the standard Helvetica fonts every PDF reader
+// ships, so no font files or rendering engine are bundled. Header, footer, accent color
+// and whether the transcript is appended come from the export settings.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::document::{format_duration, MeetingDocument};
+use super::{export_path, load_document, load_export_settings, write_export};
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+const PAGE_WIDTH: f32 = 595.28;
+const PAGE_HEIGHT: f32 = 841.89;
+const MARGIN: f32 = 56.0;
+const HEADER_Y: f32 = PAGE_HEIGHT - 34.0;
+const FOOTER_Y: f32 = 28.0;
+const LEADING: f32 = 1.35;
+const TEXT_COLOR: (f32, f32, f32) = (0.1, 0.1, 0.1);
+const MUTED_COLOR: (f32, f32, f32) = (0.45, 0.45, 0.45);
+const DEFAULT_ACCENT: &str = "#1F4E79";
+const DEFAULT_FOOTER: &str = "Page {{page}} of {{pages}}";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PdfSettings {
+    /// Text at the top of every page, e.g. the company name; {{title}} and {{date}} work
+    #[serde(default)]
+    pub header: Option<String>,
+    /// Text at the bottom of every page; adds {{page}} and {{pages}} to the header ones
+    #[serde(default)]
+    pub footer: Option<String>,
+    /// Color of headings and the header rule, "#RRGGBB"
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    /// Append the full transcript after the minutes
+    #[serde(default)]
+    pub include_transcript: bool,
+}
+
+/// "#RRGGBB" as PDF color components
+pub fn parse_color(hex: &str) -> Option<(f32, f32, f32)> {
+    let hex = hex.trim().strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let component = |range: std::ops::Range<usize>| {
+        u8::from_str_radix(hex.get(range)?, 16).ok().map(|value| value as f32 / 255.0)
+    };
+    Some((component(0..2)?, component(2..4)?, component(4..6)?))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+}
+
+// Glyph widths (1/1000 em) of printable ASCII in Helvetica and Helvetica-Bold
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556, 556, 556, 556,
+    556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667,
+    556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556, 333, 556,
+    556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722,
+    500, 500, 500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556, 556, 556, 556,
+    556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722,
+    611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556, 333, 556,
+    611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778,
+    556, 556, 500, 389, 280, 389, 584,
+];
+
+fn char_width(byte: u8, font: Font) -> u16 {
+    let table = match font {
+        Font::Regular => &HELVETICA_WIDTHS,
+        Font::Bold => &HELVETICA_BOLD_WIDTHS,
+    };
+    match byte {
+        32..=126 => table[(byte - 32) as usize],
+        0x95 => 350,
+        0x85 | 0x97 => 1000,
+        // Accented letters and other Latin-1 glyphs are close to a digit's width
+        _ => 556,
+    }
+}
+
+fn text_width(text: &[u8], font: Font, size: f32) -> f32 {
+    text.iter().map(|&byte| char_width(byte, font) as f32).sum::<f32>() * size / 1000.0
+}
+
+/// Text in WinAnsi, the encoding of the standard fonts; characters it lacks become '?'
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' => c as u8,
+            '\t' => b' ',
+            '\u{A0}'..='\u{FF}' => c as u32 as u8,
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '™' => 0x99,
+            'Œ' => 0x8C,
+            'œ' => 0x9C,
+            'Š' => 0x8A,
+            'š' => 0x9A,
+            'Ž' => 0x8E,
+            'ž' => 0x9E,
+            'Ÿ' => 0x9F,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Greedy word wrap to `width` points; words longer than a line are broken
+fn wrap(text: &[u8], font: Font, size: f32, width: f32) -> Vec<Vec<u8>> {
+    let mut lines = Vec::new();
+    let mut line: Vec<u8> = Vec::new();
+    for word in text.split(|&byte| byte == b' ').filter(|word| !word.is_empty()) {
+        let candidate_width = text_width(&line, font, size) + text_width(b" ", font, size) + text_width(word, font, size);
+        if !line.is_empty() && candidate_width <= width {
+            line.push(b' ');
+            line.extend_from_slice(word);
+            continue;
+        }
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        for &byte in word {
+            if !line.is_empty() && text_width(&line, font, size) + text_width(&[byte], font, size) > width {
+                lines.push(std::mem::take(&mut line));
+            }
+            line.push(byte);
+        }
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+struct Line {
+    text: Vec<u8>,
+    font: Font,
+    size: f32,
+    indent: f32,
+    accent: bool,
+    gap_before: f32,
+    /// Headings move to the next page rather than end one
+    keep_with_next: bool,
+}
+
+#[derive(Default)]
+struct Layout {
+    lines: Vec<Line>,
+}
+
+impl Layout {
+    /// Add wrapped text; `prefix` (a bullet or number) hangs left of the wrapped lines
+    #[allow(clippy::too_many_arguments)]
+    fn text(&mut self, text: &str, font: Font, size: f32, indent: f32, prefix: &str, accent: bool, gap_before: f32) {
+        let prefix = win_ansi(prefix);
+        let prefix_width = text_width(&prefix, font, size);
+        let width = PAGE_WIDTH - 2.0 * MARGIN - indent - prefix_width;
+        for (index, wrapped) in wrap(&win_ansi(text), font, size, width).into_iter().enumerate() {
+            let (text, indent) = if index == 0 {
+                ([prefix.clone(), wrapped].concat(), indent)
+            } else {
+                (wrapped, indent + prefix_width)
+            };
+            self.lines.push(Line {
+                text,
+                font,
+                size,
+                indent,
+                accent,
+                gap_before: if index == 0 { gap_before } else { 0.0 },
+                keep_with_next: accent && font == Font::Bold,
+            });
+        }
+    }
+
+    fn heading(&mut self, text: &str, size: f32) {
+        self.text(text, Font::Bold, size, 0.0, "", true, size * 0.9);
+    }
+}
+
+static LINK: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\([^)]*\)").unwrap());
+static NUMBERED: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\d+[.)])\s+(.*)$").unwrap());
+
+/// Markdown inline markup removed: emphasis markers, code ticks, link targets
+fn plain_inline(text: &str) -> String {
+    LINK.replace_all(text, "$1").replace("**", "").replace("__", "").replace('`', "")
+}
+
+/// Lay out minutes markdown: headings, bullets, numbered and task lists, quotes, tables
+/// as text, paragraphs
+fn layout_markdown(layout: &mut Layout, markdown: &str) {
+    let mut gap = 0.0;
+    for raw in markdown.lines() {
+        let indent_level = (raw.len() - raw.trim_start().len()) / 2;
+        let indent = indent_level as f32 * 14.0;
+        let line = raw.trim();
+        if line.is_empty() {
+            gap = 6.0;
+            continue;
+        }
+        if line.starts_with('|') && line.trim_matches(|c| c == '|' || c == '-' || c == ':' || c == ' ').is_empty() {
+            continue;
+        }
+
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            let size = match hashes {
+                1 => 16.0,
+                2 => 13.5,
+                _ => 11.5,
+            };
+            layout.heading(&plain_inline(line[hashes..].trim()), size);
+        } else if let Some(item) = line.strip_prefix("- [ ] ").or_else(|| line.strip_prefix("* [ ] ")) {
+            layout.text(&plain_inline(item), Font::Regular, 10.5, indent + 4.0, "[ ]  ", false, gap);
+        } else if let Some(item) = ["- [x] ", "- [X] ", "* [x] "].iter().find_map(|marker| line.strip_prefix(marker)) {
+            layout.text(&plain_inline(item), Font::Regular, 10.5, indent + 4.0, "[x]  ", false, gap);
+        } else if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            layout.text(&plain_inline(item), Font::Regular, 10.5, indent + 4.0, "•  ", false, gap);
+        } else if let Some(captures) = NUMBERED.captures(line) {
+            let prefix = format!("{}  ", &captures[1]);
+            layout.text(&plain_inline(&captures[2]), Font::Regular, 10.5, indent + 4.0, &prefix, false, gap);
+        } else if let Some(quote) = line.strip_prefix('>') {
+            layout.text(&plain_inline(quote.trim()), Font::Regular, 10.5, indent + 14.0, "", false, gap);
+        } else if line.starts_with('|') {
+            let cells: Vec<String> = line
+                .trim_matches('|')
+                .split('|')
+                .map(|cell| plain_inline(cell.trim()))
+                .collect();
+            layout.text(&cells.join("   |   "), Font::Regular, 10.0, indent, "", false, gap);
+        } else if line.chars().all(|c| c == '-' || c == '*' || c == '_') {
+            gap = 10.0;
+            continue;
+        } else {
+            layout.text(&plain_inline(line), Font::Regular, 10.5, indent, "", false, gap);
+        }
+        gap = 0.0;
+    }
+}
+
+fn layout_document(document: &MeetingDocument, include_transcript: bool) -> Layout {
+    let mut layout = Layout::default();
+    layout.text(&document.title, Font::Bold, 20.0, 0.0, "", true, 0.0);
+
+    let mut details = vec![document.local_date()];
+    if let Some(duration) = document.duration_seconds() {
+        details.push(format_duration(duration));
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        details.push(participants.join(", "));
+    }
+    layout.text(&details.join("  •  "), Font::Regular, 9.5, 0.0, "", false, 4.0);
+    if let Some(description) = &document.description {
+        layout.text(description, Font::Regular, 10.5, 0.0, "", false, 10.0);
+    }
+
+    match &document.summary {
+        Some(summary) => layout_markdown(&mut layout, summary),
+        None => layout.text("No summary has been generated for this meeting yet.", Font::Regular, 10.5, 0.0, "", false, 12.0),
+    }
+
+    if !document.action_items.is_empty() {
+        layout.heading("Action Items", 13.5);
+        for item in &document.action_items {
+            let mut text = item.description.trim().to_string();
+            if let Some(assignee) = &item.assignee {
+                text.push_str(&format!(" — {}", assignee));
+            }
+            if let Some(due_date) = &item.due_date {
+                text.push_str(&format!(" (due {})", due_date));
+            }
+            let marker = if item.done { "[x]  " } else { "[ ]  " };
+            layout.text(&text, Font::Regular, 10.5, 4.0, marker, false, 0.0);
+        }
+    }
+
+    if include_transcript && !document.segments.is_empty() {
+        layout.heading("Transcript", 13.5);
+        for segment in document.segments.iter().filter(|segment| !segment.text.is_empty()) {
+            let mut prefix = segment.start.map(|start| format!("{}  ", format_timestamp(start))).unwrap_or_default();
+            if let Some(speaker) = &segment.speaker {
+                prefix.push_str(&format!("{}: ", speaker));
+            }
+            layout.text(&format!("{}{}", prefix, segment.text), Font::Regular, 9.5, 0.0, "", false, 3.0);
+            for comment in &segment.comments {
+                let text = format!("{}: {}", comment.author, comment.text.replace('\n', " "));
+                layout.text(&text, Font::Regular, 9.0, 18.0, "\u{bb} ", true, 1.5);
+            }
+        }
+    }
+    layout
+}
+
+/// Lines per page as (line index, baseline y)
+fn paginate(lines: &[Line], top: f32) -> Vec<Vec<(usize, f32)>> {
+    let bottom = MARGIN;
+    let mut pages = vec![Vec::new()];
+    let mut y = top;
+    for (index, line) in lines.iter().enumerate() {
+        let at_top = pages.last().is_some_and(|page: &Vec<(usize, f32)>| page.is_empty());
+        let height = line.size * LEADING;
+        let mut needed = height + if at_top { 0.0 } else { line.gap_before };
+        if line.keep_with_next {
+            needed += lines.get(index + 1).map_or(0.0, |next| next.gap_before + next.size * LEADING);
+        }
+        if !at_top && y - needed < bottom {
+            pages.push(Vec::new());
+            y = top;
+        }
+        let at_top = pages.last().is_some_and(|page| page.is_empty());
+        y -= height + if at_top { 0.0 } else { line.gap_before };
+        pages.last_mut().expect("at least one page").push((index, y));
+    }
+    pages
+}
+
+fn escape(text: &[u8]) -> Vec<u8> {
+    let mut escaped = Vec::with_capacity(text.len());
+    for &byte in text {
+        match byte {
+            b'\\' | b'(' | b')' => escaped.extend_from_slice(&[b'\\', byte]),
+            b'\r' | b'\n' => escaped.push(b' '),
+            _ => escaped.push(byte),
+        }
+    }
+    escaped
+}
+
+fn text_op(out: &mut Vec<u8>, text: &[u8], font: Font, size: f32, color: (f32, f32, f32), x: f32, y: f32) {
+    out.extend_from_slice(
+        format!(
+            "BT /{} {:.1} Tf {:.3} {:.3} {:.3} rg {:.2} {:.2} Td (",
+            font.resource(),
+            size,
+            color.0,
+            color.1,
+            color.2,
+            x,
+            y
+        )
+        .as_bytes(),
+    );
+    out.extend_from_slice(&escape(text));
+    out.extend_from_slice(b") Tj ET\n");
+}
+
+/// Serialize pages (content streams) into a PDF file with an xref table
+fn write_pdf(pages: &[Vec<u8>], title: &str) -> Vec<u8> {
+    let font = |name: &str| {
+        format!("<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>", name).into_bytes()
+    };
+    let kids: Vec<String> = (0..pages.len()).map(|index| format!("{} 0 R", 6 + 2 * index)).collect();
+
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()).into_bytes(),
+        font("Helvetica"),
+        font("Helvetica-Bold"),
+        [b"<< /Title (".as_slice(), escape(&win_ansi(title)).as_slice(), b") >>".as_slice()].concat(),
+    ];
+    for (index, content) in pages.iter().enumerate() {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                7 + 2 * index
+            )
+            .into_bytes(),
+        );
+        objects.push(
+            [
+                format!("<< /Length {} >>\nstream\n", content.len()).as_bytes(),
+                content.as_slice(),
+                b"\nendstream".as_slice(),
+            ]
+            .concat(),
+        );
+    }
+
+    let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        out.extend_from_slice(object);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+    let xref = out.len();
+    out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .as_bytes(),
+    );
+    out
+}
+
+fn fill_page_text(template: &str, document: &MeetingDocument, page: usize, pages: usize) -> String {
+    template
+        .replace("{{title}}", &document.title)
+        .replace("{{date}}", &document.local_date())
+        .replace("{{page}}", &page.to_string())
+        .replace("{{pages}}", &pages.to_string())
+}
+
+/// Render a meeting's minutes as a PDF file
+pub fn render_pdf(document: &MeetingDocument, settings: &PdfSettings) -> Vec<u8> {
+    let accent = settings
+        .accent_color
+        .as_deref()
+        .and_then(parse_color)
+        .or_else(|| parse_color(DEFAULT_ACCENT))
+        .expect("default accent color is valid");
+    let header = settings.header.as_deref().map(str::trim).filter(|header| !header.is_empty());
+    let footer = settings.footer.as_deref().unwrap_or(DEFAULT_FOOTER).trim();
+
+    let layout = layout_document(document, settings.include_transcript);
+    let top = if header.is_some() { HEADER_Y - 24.0 } else { PAGE_HEIGHT - MARGIN };
+    let pages = paginate(&layout.lines, top);
+    let page_count = pages.len();
+
+    let contents: Vec<Vec<u8>> = pages
+        .iter()
+        .enumerate()
+        .map(|(page_index, page)| {
+            let mut content = Vec::new();
+            if let Some(header) = header {
+                let text = win_ansi(&fill_page_text(header, document, page_index + 1, page_count));
+                text_op(&mut content, &text, Font::Bold, 9.0, accent, MARGIN, HEADER_Y);
+                content.extend_from_slice(
+                    format!(
+                        "{:.3} {:.3} {:.3} RG 0.8 w {:.2} {:.2} m {:.2} {:.2} l S\n",
+                        accent.0,
+                        accent.1,
+                        accent.2,
+                        MARGIN,
+                        HEADER_Y - 8.0,
+                        PAGE_WIDTH - MARGIN,
+                        HEADER_Y - 8.0
+                    )
+                    .as_bytes(),
+                );
+            }
+            for &(index, y) in page {
+                let line = &layout.lines[index];
+                let color = if line.accent { accent } else { TEXT_COLOR };
+                text_op(&mut content, &line.text, line.font, line.size, color, MARGIN + line.indent, y);
+            }
+            if !footer.is_empty() {
+                let text = win_ansi(&fill_page_text(footer, document, page_index + 1, page_count));
+                let x = (PAGE_WIDTH - text_width(&text, Font::Regular, 8.0)) / 2.0;
+                text_op(&mut content, &text, Font::Regular, 8.0, MUTED_COLOR, x, FOOTER_Y);
+            }
+            content
+        })
+        .collect();
+
+    write_pdf(&contents, &document.title)
+}
+
+/// Export a meeting's minutes to a PDF file using the PDF export settings
+///
+/// # Returns
+/// The path written (".pdf" is added when the chosen path has no extension)
+#[command]
+pub async fn export_meeting_pdf<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let settings = load_export_settings(pool).await?;
+    let document = load_document(pool, &meeting_id, &settings).await?;
+    let pdf = render_pdf(&document, &settings.pdf);
+
+    let path = export_path(&path, "pdf");
+    write_export(&path, &pdf)?;
+    info!("Exported meeting {} to {} ({} bytes)", meeting_id, path.display(), pdf.len());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::document::ExportSegment;
+    use chrono::Utc;
+
+    #[test]
+    fn wraps_words_to_width_and_breaks_long_ones() {
+        let lines = wrap(b"the quick brown fox jumps", Font::Regular, 10.0, 60.0);
+        assert_eq!(lines, vec![b"the quick".to_vec(), b"brown fox".to_vec(), b"jumps".to_vec()]);
+        assert!(lines.iter().all(|line| text_width(line, Font::Regular, 10.0) <= 60.0));
+        assert_eq!(wrap(b"abcdefghij", Font::Regular, 10.0, 20.0).len(), 3);
+        assert_eq!(win_ansi("café — “ok” ✓"), b"caf\xE9 \x97 \x93ok\x94 ?".to_vec());
+    }
+
+    #[test]
+    fn writes_a_well_formed_multi_page_pdf() {
+        let segments = (0..120)
+            .map(|index| ExportSegment {
+                id: format!("segment-{}", index),
+                start: Some(index as f64 * 10.0),
+                end: Some(index as f64 * 10.0 + 8.0),
+                speaker: Some("Ana (lead)".to_string()),
+                text: "We agreed the migration waits until March, pending the load test results.".to_string(),
+                confidence: None,
+                language: None,
+                comments: Vec::new(),
+            })
+            .collect();
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Platform sync".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("# Minutes\n\n## Decisions\n- **Postgres** in March\n1. Load test first\n".to_string()),
+            action_items: Vec::new(),
+            segments,
+        };
+        let settings = PdfSettings {
+            header: Some("Acme Corp — {{title}}".to_string()),
+            footer: None,
+            accent_color: Some("#AA3300".to_string()),
+            include_transcript: true,
+        };
+
+        let pdf = render_pdf(&document, &settings);
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with(b"%PDF-1.4\n") && text.ends_with("%%EOF\n"));
+        assert!(text.contains("Ana \\(lead\\): We agreed"));
+        assert!(text.contains("(Page 1 of "));
+
+        // Every xref entry points at its object
+        let marker = b"startxref\n";
+        let marker_at = pdf.windows(marker.len()).rposition(|window| window == marker).unwrap();
+        let tail = std::str::from_utf8(&pdf[marker_at + marker.len()..]).unwrap();
+        let xref_at: usize = tail.lines().next().unwrap().parse().unwrap();
+        let entries: Vec<usize> = std::str::from_utf8(&pdf[xref_at..])
+            .unwrap()
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        assert!(entries.len() > 7, "expected several pages");
+        for (index, offset) in entries.iter().enumerate() {
+            assert!(pdf[*offset..].starts_with(format!("{} 0 obj\n", index + 1).as_bytes()));
+        }
+        assert_eq!(parse_color("#1f4e79"), Some((31.0 / 255.0, 78.0 / 255.0, 121.0 / 255.0)));
+        assert_eq!(parse_color("1F4E79"), None);
+    }
+}
@@ -0,0 +1,251 @@
+// Subtitle export
+// Writes the timestamped transcript as SRT or WebVTT captions. Segment times are relative to
+// the start of the recording, so the files line up with the exported audio.
+
+use serde::Deserialize;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::document::MeetingDocument;
+use super::{export_path, write_export};
+use crate::state::AppState;
+
+/// Longest caption line, the usual broadcast guideline
+const MAX_LINE_CHARS: usize = 42;
+const MAX_LINES_PER_CUE: usize = 2;
+/// Reading time assumed for a segment without an end time
+const SECONDS_PER_WORD: f64 = 0.4;
+const MIN_CUE_SECONDS: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            SubtitleFormat::Srt => "srt",
+            SubtitleFormat::Vtt => "vtt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Cue {
+    start: f64,
+    end: f64,
+    speaker: Option<String>,
+    lines: Vec<String>,
+}
+
+/// Greedy word wrap to `MAX_LINE_CHARS` characters; longer words get a line of their own
+fn caption_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > MAX_LINE_CHARS {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Cues for the timed segments, in audio order
+///
+/// A segment without an end time lasts until the next one starts (or for its estimated
+/// reading time), cues never overlap the next segment, and text longer than two lines is
+/// spread over several cues with the segment's time split by length.
+fn build_cues(document: &MeetingDocument) -> Vec<Cue> {
+    let mut timed: Vec<_> = document
+        .segments
+        .iter()
+        .filter(|segment| !segment.text.is_empty())
+        .filter_map(|segment| segment.start.map(|start| (start.max(0.0), segment)))
+        .collect();
+    timed.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut cues = Vec::new();
+    for (index, &(start, segment)) in timed.iter().enumerate() {
+        let next_start = timed.get(index + 1).map(|&(next, _)| next).filter(|&next| next > start);
+        let estimated = start + (segment.text.split_whitespace().count() as f64 * SECONDS_PER_WORD).max(MIN_CUE_SECONDS);
+        let mut end = segment.end.filter(|&end| end > start).or(next_start).unwrap_or(estimated);
+        if let Some(next_start) = next_start {
+            end = end.min(next_start);
+        }
+
+        let lines = caption_lines(&segment.text);
+        let chunks: Vec<&[String]> = lines.chunks(MAX_LINES_PER_CUE).collect();
+        let total_chars: usize = lines.iter().map(|line| line.chars().count()).sum();
+        let mut chunk_start = start;
+        for (chunk_index, chunk) in chunks.iter().enumerate() {
+            let chunk_chars: usize = chunk.iter().map(|line| line.chars().count()).sum();
+            let chunk_end = if chunk_index + 1 == chunks.len() {
+                end
+            } else {
+                chunk_start + (end - start) * chunk_chars as f64 / total_chars.max(1) as f64
+            };
+            cues.push(Cue {
+                start: chunk_start,
+                end: chunk_end,
+                speaker: segment.speaker.clone(),
+                lines: chunk.to_vec(),
+            });
+            chunk_start = chunk_end;
+        }
+    }
+    cues
+}
+
+/// "HH:MM:SS,mmm" (SRT) or "HH:MM:SS.mmm" (WebVTT)
+fn cue_time(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        (millis / 60_000) % 60,
+        (millis / 1000) % 60,
+        separator,
+        millis % 1000
+    )
+}
+
+fn render_srt(cues: &[Cue]) -> String {
+    let mut srt = String::new();
+    for (index, cue) in cues.iter().enumerate() {
+        srt.push_str(&format!("{}\n{} --> {}\n", index + 1, cue_time(cue.start, ','), cue_time(cue.end, ',')));
+        for (line_index, line) in cue.lines.iter().enumerate() {
+            match &cue.speaker {
+                Some(speaker) if line_index == 0 => srt.push_str(&format!("{}: {}\n", speaker, line)),
+                _ => srt.push_str(&format!("{}\n", line)),
+            }
+        }
+        srt.push('\n');
+    }
+    srt
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn render_vtt(cues: &[Cue], title: &str) -> String {
+    let mut vtt = format!("WEBVTT\n\nNOTE {}\n\n", title.replace("-->", "->"));
+    for cue in cues {
+        vtt.push_str(&format!("{} --> {}\n", cue_time(cue.start, '.'), cue_time(cue.end, '.')));
+        let text = cue.lines.iter().map(|line| escape_vtt(line)).collect::<Vec<_>>().join("\n");
+        match &cue.speaker {
+            Some(speaker) => vtt.push_str(&format!("<v {}>{}\n\n", escape_vtt(speaker), text)),
+            None => vtt.push_str(&format!("{}\n\n", text)),
+        }
+    }
+    vtt
+}
+
+/// The transcript as SRT or WebVTT; fails when no segment has a time
+pub(crate) fn render_subtitles(document: &MeetingDocument, format: SubtitleFormat) -> Result<String, String> {
+    let cues = build_cues(document);
+    if cues.is_empty() {
+        return Err("This meeting has no timestamped transcript to export as subtitles".to_string());
+    }
+    Ok(match format {
+        SubtitleFormat::Srt => render_srt(&cues),
+        SubtitleFormat::Vtt => render_vtt(&cues, &document.title),
+    })
+}
+
+/// Export a meeting's transcript as captions
+///
+/// # Arguments
+/// * `format` - "srt" or "vtt"; also the extension added when `path` has none
+///
+/// # Returns
+/// The path written
+#[command]
+pub async fn export_meeting_subtitles<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    path: String,
+    format: SubtitleFormat,
+) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let document = MeetingDocument::load(state.db_manager.pool(), &meeting_id).await?;
+    let contents = render_subtitles(&document, format)?;
+
+    let path = export_path(&path, format.extension());
+    write_export(&path, contents.as_bytes())?;
+    info!("Exported the subtitles of meeting {} to {}", meeting_id, path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::document::ExportSegment;
+    use chrono::Utc;
+
+    fn segment(start: Option<f64>, end: Option<f64>, speaker: Option<&str>, text: &str) -> ExportSegment {
+        ExportSegment {
+            id: format!("segment-{:?}", start),
+            start,
+            end,
+            speaker: speaker.map(str::to_string),
+            text: text.to_string(),
+            confidence: None,
+            language: None,
+            comments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn timed_segments_become_non_overlapping_readable_cues() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Platform sync".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: None,
+            action_items: Vec::new(),
+            segments: vec![
+                segment(Some(0.0), Some(9.5), Some("Ana"), "Let's start."),
+                segment(None, None, None, "No timing, left out."),
+                segment(
+                    Some(8.0),
+                    Some(20.0),
+                    Some("Speaker 2"),
+                    "We agreed the migration waits until March, pending the load test results from the staging cluster next week.",
+                ),
+                segment(Some(3725.0), None, None, "Bye <all>"),
+            ],
+        };
+
+        let cues = build_cues(&document);
+        assert_eq!(cues.len(), 4);
+        assert_eq!((cues[0].start, cues[0].end), (0.0, 8.0));
+        assert!(cues[1..3].iter().all(|cue| cue.lines.iter().all(|line| line.chars().count() <= MAX_LINE_CHARS)));
+        assert_eq!(cues[1].lines.len(), 2);
+        assert_eq!(cues[2].start, cues[1].end);
+        assert_eq!(cues[2].end, 20.0);
+        assert_eq!((cues[3].start, cues[3].end), (3725.0, 3726.0));
+
+        let srt = render_srt(&cues);
+        assert!(srt.starts_with("1\n00:00:00,000 --> 00:00:08,000\nAna: Let's start.\n\n2\n00:00:08,000 --> "));
+        assert!(srt.ends_with("4\n01:02:05,000 --> 01:02:06,000\nBye <all>\n\n"));
+
+        let vtt = render_vtt(&cues, &document.title);
+        assert!(vtt.starts_with("WEBVTT\n\nNOTE Platform sync\n\n00:00:00.000 --> 00:00:08.000\n<v Ana>Let's start.\n\n"));
+        assert!(vtt.contains("<v Speaker 2>We agreed the migration waits until March,\n"));
+        assert!(vtt.ends_with("01:02:05.000 --> 01:02:06.000\nBye &lt;all&gt;\n\n"));
+    }
+}
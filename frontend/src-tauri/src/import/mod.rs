@@ -0,0 +1,172 @@
+// Transcript import
+// Creates a meeting from a transcript made elsewhere (captions, JSON from other tools or a
+// previous export), optionally with its recording, so it can be summarized like any other
+
+pub mod parse;
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::api::TranscriptSegment;
+use crate::audio::audio_processing::create_meeting_folder;
+use crate::audio::ffmpeg::find_ffmpeg_path;
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::integrations::MeetingEvent;
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+use parse::{parse_transcript, ImportedSegment, TranscriptFormat};
+
+#[derive(Debug, Serialize)]
+pub struct ImportedMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    pub segments: usize,
+    pub has_audio: bool,
+}
+
+fn transcript_segment(segment: ImportedSegment) -> TranscriptSegment {
+    TranscriptSegment {
+        id: format!("import-{}", Uuid::new_v4()),
+        timestamp: segment.start.map(format_timestamp).unwrap_or_default(),
+        audio_start_time: segment.start,
+        audio_end_time: segment.end,
+        duration: segment.start.zip(segment.end).map(|(start, end)| end - start),
+        text: segment.text,
+        quality_score: None,
+        speaker: None,
+        speaker_label: segment.speaker,
+        language: segment.language,
+        confidence: segment.confidence,
+    }
+}
+
+/// Put the recording in the meeting folder as audio.mp4: MP4/M4A files are copied, any
+/// other format is converted to AAC with FFmpeg
+pub(crate) async fn import_audio(source: &Path, folder: &Path) -> Result<(), String> {
+    let destination = folder.join("audio.mp4");
+    let extension = source.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase);
+    if matches!(extension.as_deref(), Some("mp4") | Some("m4a")) {
+        std::fs::copy(source, &destination).map_err(|e| format!("Failed to copy {}: {}", source.display(), e))?;
+        return Ok(());
+    }
+
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| "FFmpeg not found".to_string())?;
+    let source = source.to_path_buf();
+    let output = tokio::task::spawn_blocking(move || {
+        let mut command = std::process::Command::new(ffmpeg_path);
+        command.arg("-i").arg(&source).args(["-vn", "-c:a", "aac", "-b:a", "128k", "-y"]).arg(&destination);
+
+        // Hide console window on Windows
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        command.output()
+    })
+    .await
+    .map_err(|e| format!("Audio import task failed: {}", e))?
+    .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "FFmpeg failed to convert the recording: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(())
+}
+
+/// Create a meeting from a transcript file
+///
+/// # Arguments
+/// * `path` - .srt, .vtt, .json or .jsonl file; the format is sniffed for other extensions
+/// * `title` - Meeting title; defaults to the title stored in the file, else the file name
+/// * `audio_path` - Recording of the meeting, copied (or converted) into a new recording
+///   folder so playback and audio features work
+#[command]
+pub async fn import_transcript<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+    title: Option<String>,
+    audio_path: Option<String>,
+) -> Result<ImportedMeeting, String> {
+    let path = PathBuf::from(path);
+    let contents = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let format = TranscriptFormat::detect(path.extension().and_then(|extension| extension.to_str()), &contents)
+        .ok_or_else(|| format!("{} is not an SRT, WebVTT or JSON transcript", path.display()))?;
+    let parsed = parse_transcript(&contents, format)?;
+    if parsed.segments.is_empty() {
+        return Err(format!("No transcript segments found in {}", path.display()));
+    }
+
+    let title = title
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .or(parsed.title)
+        .or_else(|| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Imported meeting".to_string());
+
+    let folder = match audio_path.filter(|audio_path| !audio_path.trim().is_empty()) {
+        Some(audio_path) => {
+            let preferences = load_recording_preferences(&app)
+                .await
+                .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+            let folder = create_meeting_folder(&preferences.save_folder, &title, false)
+                .map_err(|e| format!("Failed to create recording folder: {}", e))?;
+            if let Err(e) = import_audio(Path::new(&audio_path), &folder).await {
+                if let Err(remove_error) = std::fs::remove_dir_all(&folder) {
+                    warn!("Failed to remove {}: {}", folder.display(), remove_error);
+                }
+                return Err(e);
+            }
+            Some(folder)
+        }
+        None => None,
+    };
+
+    let segments: Vec<TranscriptSegment> = parsed.segments.into_iter().map(transcript_segment).collect();
+    let state = app.state::<AppState>();
+    let meeting_id = TranscriptsRepository::save_transcript(
+        state.db_manager.pool(),
+        &title,
+        &segments,
+        folder.as_ref().map(|folder| folder.to_string_lossy().to_string()),
+    )
+    .await
+    .map_err(|e| format!("Failed to save imported transcript: {}", e))?;
+
+    // The same recording may already be stored for another meeting
+    if let Some(folder) = &folder {
+        if let Err(e) = crate::audio::dedup::deduplicate_file(state.db_manager.pool(), &folder.join("audio.mp4")).await {
+            warn!("Failed to deduplicate the imported recording: {}", e);
+        }
+    }
+
+    info!(
+        "Imported {} segment(s) from {} as meeting {}",
+        segments.len(),
+        path.display(),
+        meeting_id
+    );
+    if let Err(e) = crate::meeting_templates::apply_matching(state.db_manager.pool(), &meeting_id).await {
+        warn!("{}", e);
+    }
+    crate::meeting_info::spawn_autofill(&app, meeting_id.clone(), false);
+    crate::integrations::spawn_meeting_event(&app, MeetingEvent::TranscriptReady, meeting_id.clone());
+    crate::summary::auto_title::spawn_for_meeting(&app, meeting_id.clone());
+    crate::summary::vector_index::spawn_index_update(&app, meeting_id.clone());
+
+    Ok(ImportedMeeting {
+        meeting_id,
+        title,
+        segments: segments.len(),
+        has_audio: folder.is_some(),
+    })
+}
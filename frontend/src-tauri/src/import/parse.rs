@@ -0,0 +1,281 @@
+// Transcript file parsing
+// SRT and WebVTT captions, this app's JSON/JSONL exports and the generic segment JSON other
+// tools write (e.g. Whisper's {"segments": [{"start", "end", "text"}]})
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedSegment {
+    /// Seconds from the start of the recording
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    pub speaker: Option<String>,
+    pub text: String,
+    pub confidence: Option<f64>,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedTranscript {
+    /// Meeting title stored in the file, if the format has one
+    pub title: Option<String>,
+    pub segments: Vec<ImportedSegment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    Srt,
+    Vtt,
+    Json,
+    Jsonl,
+}
+
+impl TranscriptFormat {
+    /// By file extension, else by sniffing the contents
+    pub fn detect(extension: Option<&str>, contents: &str) -> Option<Self> {
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("srt") => return Some(Self::Srt),
+            Some("vtt") => return Some(Self::Vtt),
+            Some("json") => return Some(Self::Json),
+            Some("jsonl") | Some("ndjson") => return Some(Self::Jsonl),
+            _ => {}
+        }
+        let start = contents.trim_start_matches('\u{feff}').trim_start();
+        if start.starts_with("WEBVTT") {
+            Some(Self::Vtt)
+        } else if start.starts_with('{') && start.lines().nth(1).is_some_and(|line| line.trim_start().starts_with('{')) {
+            Some(Self::Jsonl)
+        } else if start.starts_with('{') || start.starts_with('[') {
+            Some(Self::Json)
+        } else if start.contains("-->") {
+            Some(Self::Srt)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn parse_transcript(contents: &str, format: TranscriptFormat) -> Result<ParsedTranscript, String> {
+    let contents = contents.trim_start_matches('\u{feff}');
+    match format {
+        TranscriptFormat::Srt | TranscriptFormat::Vtt => Ok(parse_captions(contents)),
+        TranscriptFormat::Json => {
+            let value: Value = serde_json::from_str(contents).map_err(|e| format!("Invalid JSON: {}", e))?;
+            Ok(parse_json_value(&value))
+        }
+        TranscriptFormat::Jsonl => parse_jsonl(contents),
+    }
+}
+
+/// "01:02:03,450", "02:03.450" or "1:02:03.5" in seconds
+fn parse_cue_time(text: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    let parts: Vec<&str> = text.trim().split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+    for part in &parts {
+        let value: f64 = part.replace(',', ".").parse().ok()?;
+        seconds = seconds * 60.0 + value;
+    }
+    Some(seconds)
+}
+
+static TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+static VOICE: Lazy<Regex> = Lazy::new(|| Regex::new(r"<v(?:\.[^ >]*)?\s+([^>]+)>").unwrap());
+static SPEAKER_PREFIX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([^:\s][^:]{0,39}):\s+(\S.*)$").unwrap());
+
+fn unescape_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+/// SRT or WebVTT cues; blocks without a timing line (headers, NOTE, STYLE) are skipped
+///
+/// WebVTT voice tags give the speaker. A "Name: " prefix is taken as the speaker only when
+/// the same name starts at least two cues, so a one-off "Note: ..." stays text.
+fn parse_captions(contents: &str) -> ParsedTranscript {
+    let normalized = contents.replace("\r\n", "\n").replace('\r', "\n");
+    let mut segments = Vec::new();
+    for block in normalized.split("\n\n") {
+        let lines: Vec<&str> = block.lines().collect();
+        let Some(timing_index) = lines.iter().position(|line| line.contains("-->")) else {
+            continue;
+        };
+        let Some((start, rest)) = lines[timing_index].split_once("-->") else {
+            continue;
+        };
+        let Some(start) = parse_cue_time(start) else {
+            continue;
+        };
+        let end = rest.split_whitespace().next().and_then(parse_cue_time);
+
+        let raw_text = lines[timing_index + 1..].join(" ");
+        let speaker = VOICE.captures(&raw_text).map(|captures| unescape_entities(captures[1].trim()));
+        let text = unescape_entities(&TAG.replace_all(&raw_text, ""));
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        segments.push(ImportedSegment {
+            start: Some(start),
+            end: end.filter(|&end| end >= start),
+            speaker,
+            text,
+            confidence: None,
+            language: None,
+        });
+    }
+
+    let mut prefix_counts: HashMap<String, usize> = HashMap::new();
+    for segment in segments.iter().filter(|segment| segment.speaker.is_none()) {
+        if let Some(captures) = SPEAKER_PREFIX.captures(&segment.text) {
+            if captures[1].split_whitespace().count() <= 4 {
+                *prefix_counts.entry(captures[1].to_string()).or_default() += 1;
+            }
+        }
+    }
+    for segment in segments.iter_mut().filter(|segment| segment.speaker.is_none()) {
+        let Some(captures) = SPEAKER_PREFIX.captures(&segment.text) else {
+            continue;
+        };
+        if prefix_counts.get(&captures[1]).copied().unwrap_or(0) >= 2 {
+            let (speaker, text) = (captures[1].to_string(), captures[2].to_string());
+            segment.speaker = Some(speaker);
+            segment.text = text;
+        }
+    }
+
+    ParsedTranscript { title: None, segments }
+}
+
+/// The first of `keys` holding a value
+fn field<'a>(object: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|key| object.get(key).filter(|value| !value.is_null()))
+}
+
+/// Seconds as a number, or as an "HH:MM:SS.mmm" string
+fn seconds_field(object: &Value, keys: &[&str]) -> Option<f64> {
+    field(object, keys).and_then(|value| value.as_f64().or_else(|| value.as_str().and_then(parse_cue_time)))
+}
+
+fn string_field(object: &Value, keys: &[&str]) -> Option<String> {
+    field(object, keys)
+        .and_then(|value| value.as_str().map(str::to_string).or_else(|| value.as_i64().map(|number| number.to_string())))
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty())
+}
+
+fn json_segment(object: &Value) -> Option<ImportedSegment> {
+    let text = string_field(object, &["text", "transcript", "content"])?;
+    Some(ImportedSegment {
+        start: seconds_field(object, &["start", "start_time", "audio_start_time", "startTime"]),
+        end: seconds_field(object, &["end", "end_time", "audio_end_time", "endTime"]),
+        speaker: string_field(object, &["speaker", "speaker_label", "speaker_name", "speakerName"]),
+        text: text.split_whitespace().collect::<Vec<_>>().join(" "),
+        confidence: field(object, &["confidence"]).and_then(Value::as_f64),
+        language: string_field(object, &["language"]),
+    })
+}
+
+/// A segment array, or an object holding one under "segments" (with the title under
+/// "meeting"/"title" when present)
+fn parse_json_value(value: &Value) -> ParsedTranscript {
+    let (title, segments) = match value {
+        Value::Array(items) => (None, items.as_slice()),
+        Value::Object(_) => {
+            let title = value
+                .get("meeting")
+                .and_then(|meeting| string_field(meeting, &["title"]))
+                .or_else(|| string_field(value, &["title"]));
+            let segments = value.get("segments").and_then(Value::as_array).map(Vec::as_slice).unwrap_or(&[]);
+            (title, segments)
+        }
+        _ => (None, &[][..]),
+    };
+    ParsedTranscript {
+        title,
+        segments: segments.iter().filter_map(json_segment).collect(),
+    }
+}
+
+/// One record per line: records typed "meeting" give the title, "segment" or untyped ones
+/// with text are segments, any other type (summary, action items) is skipped
+fn parse_jsonl(contents: &str) -> Result<ParsedTranscript, String> {
+    let mut parsed = ParsedTranscript::default();
+    for (index, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let record: Value =
+            serde_json::from_str(line).map_err(|e| format!("Invalid JSON on line {}: {}", index + 1, e))?;
+        match record.get("type").and_then(Value::as_str) {
+            Some("meeting") => parsed.title = string_field(&record, &["title"]),
+            Some("segment") | None => parsed.segments.extend(json_segment(&record)),
+            Some(_) => {}
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_srt_and_vtt_cues_with_speakers() {
+        let srt = "1\r\n00:00:01,000 --> 00:00:04,500\r\nAna: Let's start\r\nwith the roadmap.\r\n\r\n2\r\n00:00:05,000 --> 00:00:07,000\r\nNote: slides shared\r\n\r\n3\r\n00:01:05,250 --> 00:01:08,000\r\nAna: Agreed.\r\n";
+        let parsed = parse_transcript(srt, TranscriptFormat::detect(Some("SRT"), srt).unwrap()).unwrap();
+        assert_eq!(parsed.segments.len(), 3);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Ana"));
+        assert_eq!(parsed.segments[0].text, "Let's start with the roadmap.");
+        assert_eq!((parsed.segments[0].start, parsed.segments[0].end), (Some(1.0), Some(4.5)));
+        assert_eq!(parsed.segments[1].speaker, None);
+        assert_eq!(parsed.segments[1].text, "Note: slides shared");
+        assert_eq!(parsed.segments[2].start, Some(65.25));
+
+        let vtt = "WEBVTT\n\nNOTE exported captions\n\nintro\n00:01.000 --> 00:04.000 align:start\n<v Speaker 2>Postgres &amp; <i>Redis</i></v>\n\n01:00:00.000 --> 01:00:02.000\nBye\n";
+        let parsed = parse_transcript(vtt, TranscriptFormat::detect(None, vtt).unwrap()).unwrap();
+        assert_eq!(parsed.segments.len(), 2);
+        assert_eq!(parsed.segments[0].speaker.as_deref(), Some("Speaker 2"));
+        assert_eq!(parsed.segments[0].text, "Postgres & Redis");
+        assert_eq!(parsed.segments[1].start, Some(3600.0));
+    }
+
+    #[test]
+    fn parses_exported_and_generic_json() {
+        let jsonl = concat!(
+            r#"{"type":"meeting","format_version":1,"id":"meeting-1","title":"Platform sync"}"#,
+            "\n",
+            r#"{"type":"summary","meeting_id":"meeting-1","markdown":"- Postgres"}"#,
+            "\n",
+            r#"{"type":"segment","meeting_id":"meeting-1","id":"s1","start":65.0,"end":70.5,"speaker":"Ana","text":"Postgres in March.","confidence":0.9,"language":"en"}"#,
+            "\n"
+        );
+        let parsed = parse_transcript(jsonl, TranscriptFormat::detect(Some("jsonl"), jsonl).unwrap()).unwrap();
+        assert_eq!(parsed.title.as_deref(), Some("Platform sync"));
+        assert_eq!(
+            parsed.segments,
+            vec![ImportedSegment {
+                start: Some(65.0),
+                end: Some(70.5),
+                speaker: Some("Ana".to_string()),
+                text: "Postgres in March.".to_string(),
+                confidence: Some(0.9),
+                language: Some("en".to_string()),
+            }]
+        );
+
+        let whisper = r#"{"text": "...", "segments": [{"id": 0, "start": 0.0, "end": 2.5, "text": " Hello there."}, {"start": "00:00:03.000", "text": "   "}]}"#;
+        let parsed = parse_transcript(whisper, TranscriptFormat::detect(None, whisper).unwrap()).unwrap();
+        assert_eq!(parsed.title, None);
+        assert_eq!(parsed.segments.len(), 1);
+        assert_eq!(parsed.segments[0].text, "Hello there.");
+
+        assert!(parse_transcript("{not json", TranscriptFormat::Json).is_err());
+        assert_eq!(TranscriptFormat::detect(Some("txt"), "plain notes"), None);
+    }
+}
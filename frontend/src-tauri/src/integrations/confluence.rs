@@ -0,0 +1,362 @@
+// Confluence
+// Publishes a meeting's minutes as a Confluence Cloud page in a configured space, optionally
+// under a parent page. The page is written in Confluence's storage format: the minutes'
+// headings, lists and tables, an action-item table, and the transcript folded into an
+// expand macro. Publishing again updates the meeting's page instead of adding another one.
+// Authenticates with the user's Atlassian email and an API token.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::{load_meeting, MeetingEvent};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::{format_duration, MeetingDocument};
+use crate::export::html::{escape, markdown_html};
+use crate::state::AppState;
+use crate::utils::format_timestamp;
+
+static PAGE_ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+$|/pages/(\d+)|pageId=(\d+)").unwrap());
+
+fn default_include_transcript() -> bool {
+    true
+}
+
+/// Confluence settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfluenceSettings {
+    /// Site URL, e.g. "https://acme.atlassian.net"
+    pub site_url: String,
+    /// Atlassian account email the API token belongs to
+    pub email: String,
+    pub api_token: String,
+    pub space_key: String,
+    /// Page id, or the parent page's link; None publishes at the top of the space
+    #[serde(default)]
+    pub parent_page: Option<String>,
+    #[serde(default = "default_include_transcript")]
+    pub include_transcript: bool,
+    /// Publish every meeting once its minutes are ready
+    #[serde(default)]
+    pub automatic: bool,
+}
+
+impl ConfluenceSettings {
+    fn validate(&self) -> Result<(), String> {
+        if !self.site_url.trim().starts_with("https://") {
+            return Err("Enter your Confluence site URL, e.g. https://your-team.atlassian.net".to_string());
+        }
+        if self.email.trim().is_empty() || self.api_token.trim().is_empty() {
+            return Err("Enter your Atlassian email and an API token".to_string());
+        }
+        if self.space_key.trim().is_empty() {
+            return Err("Enter the key of the space to publish to".to_string());
+        }
+        self.parent_page_id()?;
+        Ok(())
+    }
+
+    /// "https://acme.atlassian.net/wiki"; the site URL may be given with or without "/wiki"
+    fn wiki_url(&self) -> String {
+        let site = self.site_url.trim().trim_end_matches('/');
+        let site = site.strip_suffix("/wiki").unwrap_or(site);
+        format!("{}/wiki", site)
+    }
+
+    fn parent_page_id(&self) -> Result<Option<String>, String> {
+        let Some(parent) = self.parent_page.as_deref().map(str::trim).filter(|parent| !parent.is_empty()) else {
+            return Ok(None);
+        };
+        PAGE_ID_REGEX
+            .captures(parent)
+            .map(|captures| {
+                captures
+                    .get(1)
+                    .or_else(|| captures.get(2))
+                    .map_or_else(|| parent.to_string(), |id| id.as_str().to_string())
+            })
+            .map(Some)
+            .ok_or_else(|| "Enter the parent page's id or its link".to_string())
+    }
+}
+
+/// Page title; titles are unique within a space, so the date keeps same-named meetings apart
+fn page_title(document: &MeetingDocument) -> String {
+    format!("{} – {}", document.title.trim(), document.local_date())
+}
+
+/// The minutes in storage format
+fn storage_body(document: &MeetingDocument, include_transcript: bool) -> String {
+    let mut body = String::new();
+    let mut details = vec![format!("<strong>Date:</strong> {}", escape(&document.local_date()))];
+    if let Some(duration) = document.duration_seconds() {
+        details.push(format!("<strong>Duration:</strong> {}", format_duration(duration)));
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        details.push(format!("<strong>Participants:</strong> {}", escape(&participants.join(", "))));
+    }
+    body.push_str(&format!("<p>{}</p>", details.join("<br />")));
+    if let Some(description) = document.description.as_deref().filter(|text| !text.trim().is_empty()) {
+        body.push_str(&format!("<p>{}</p>", escape(description.trim())));
+    }
+
+    match &document.summary {
+        // Storage format is XHTML and has no form inputs, so task boxes become symbols
+        Some(summary) => body.push_str(
+            &markdown_html(summary)
+                .replace(r#"<input type="checkbox" disabled checked> "#, "☑ ")
+                .replace(r#"<input type="checkbox" disabled> "#, "☐ ")
+                .replace(r#"<ul class="tasks">"#, "<ul>"),
+        ),
+        None => body.push_str("<p><em>No summary has been generated for this meeting yet.</em></p>"),
+    }
+
+    if !document.action_items.is_empty() {
+        body.push_str("<h2>Action Items</h2><table><tbody><tr><th>Action item</th><th>Assignee</th><th>Due</th><th>Done</th></tr>");
+        for item in &document.action_items {
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                escape(item.description.trim()),
+                escape(item.assignee.as_deref().unwrap_or("")),
+                escape(item.due_date.as_deref().unwrap_or("")),
+                if item.done { "✓" } else { "" }
+            ));
+        }
+        body.push_str("</tbody></table>");
+    }
+
+    let segments: Vec<_> = document.segments.iter().filter(|segment| !segment.text.is_empty()).collect();
+    if include_transcript && !segments.is_empty() {
+        body.push_str(r#"<ac:structured-macro ac:name="expand"><ac:parameter ac:name="title">Transcript</ac:parameter><ac:rich-text-body>"#);
+        for segment in segments {
+            let time = segment
+                .start
+                .map(|start| format!("<code>{}</code> ", format_timestamp(start)))
+                .unwrap_or_default();
+            let speaker = segment
+                .speaker
+                .as_ref()
+                .map(|speaker| format!("<strong>{}:</strong> ", escape(speaker)))
+                .unwrap_or_default();
+            body.push_str(&format!("<p>{}{}{}</p>", time, speaker, escape(&segment.text)));
+        }
+        body.push_str("</ac:rich-text-body></ac:structured-macro>");
+    }
+    body
+}
+
+async fn call_api(
+    settings: &ConfluenceSettings,
+    method: reqwest::Method,
+    path: &str,
+    query: &[(&str, &str)],
+    body: Option<&Value>,
+) -> Result<Value, String> {
+    let mut request = reqwest::Client::new()
+        .request(method, format!("{}/rest/api/{}", settings.wiki_url(), path))
+        .query(query)
+        .basic_auth(settings.email.trim(), Some(settings.api_token.trim()))
+        .header("Accept", "application/json");
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await.map_err(|e| format!("Failed to reach Confluence: {}", e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err("Confluence rejected the email or API token".to_string());
+    }
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|body| body.get("message").and_then(Value::as_str).map(str::to_string))
+            .unwrap_or(text);
+        return Err(format!("Confluence returned {}: {}", status, message.trim()));
+    }
+    serde_json::from_str(&text).map_err(|e| format!("Invalid response from Confluence: {}", e))
+}
+
+/// Create the meeting's page, or update it when one with its title exists; returns its URL
+async fn publish_meeting(pool: &SqlitePool, settings: &ConfluenceSettings, meeting_id: &str) -> Result<String, String> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let title = page_title(&document);
+    let space_key = settings.space_key.trim();
+    let storage = json!({ "storage": { "value": storage_body(&document, settings.include_transcript), "representation": "storage" } });
+
+    let existing = call_api(
+        settings,
+        reqwest::Method::GET,
+        "content",
+        &[("type", "page"), ("spaceKey", space_key), ("title", &title), ("expand", "version")],
+        None,
+    )
+    .await?;
+    let existing = existing.get("results").and_then(Value::as_array).and_then(|pages| pages.first());
+
+    let page = match existing {
+        Some(page) => {
+            let id = page.get("id").and_then(Value::as_str).ok_or("Confluence returned a page without an id")?;
+            let version = page.pointer("/version/number").and_then(Value::as_i64).unwrap_or(1);
+            call_api(
+                settings,
+                reqwest::Method::PUT,
+                &format!("content/{}", id),
+                &[],
+                Some(&json!({
+                    "type": "page",
+                    "title": title,
+                    "version": { "number": version + 1 },
+                    "body": storage,
+                })),
+            )
+            .await?
+        }
+        None => {
+            let mut page = json!({
+                "type": "page",
+                "title": title,
+                "space": { "key": space_key },
+                "body": storage,
+            });
+            if let Some(parent_id) = settings.parent_page_id()? {
+                page["ancestors"] = json!([{ "id": parent_id }]);
+            }
+            call_api(settings, reqwest::Method::POST, "content", &[], Some(&page)).await?
+        }
+    };
+    let link = page.pointer("/_links/webui").and_then(Value::as_str).unwrap_or_default();
+    Ok(format!("{}{}", settings.wiki_url(), link))
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<ConfluenceSettings>, String> {
+    SettingsRepository::get_confluence_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load Confluence settings: {}", e))
+}
+
+/// Publish a meeting whose minutes are ready when automatic publishing is on
+pub async fn handle_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    if event != MeetingEvent::SummaryReady {
+        return;
+    }
+    let settings = match load_settings(pool).await {
+        Ok(Some(settings)) if settings.automatic => settings,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    match publish_meeting(pool, &settings, meeting_id).await {
+        Ok(url) => info!("Published meeting {} to Confluence: {}", meeting_id, url),
+        Err(e) => warn!("Failed to publish meeting {} to Confluence: {}", meeting_id, e),
+    }
+}
+
+#[command]
+pub async fn get_confluence_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<ConfluenceSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_confluence_settings<R: Runtime>(app: AppHandle<R>, mut settings: ConfluenceSettings) -> Result<(), String> {
+    settings.validate()?;
+    settings.parent_page = settings.parent_page_id()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_confluence_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save Confluence settings: {}", e))
+}
+
+/// Check the credentials and the space; returns the space's name
+#[command]
+pub async fn test_confluence_connection(settings: ConfluenceSettings) -> Result<String, String> {
+    settings.validate()?;
+    let path = format!("space/{}", settings.space_key.trim());
+    let space = call_api(&settings, reqwest::Method::GET, &path, &[], None).await?;
+    Ok(space.get("name").and_then(Value::as_str).unwrap_or_default().to_string())
+}
+
+/// Publish a meeting's minutes to Confluence; returns the page's URL
+#[command]
+pub async fn publish_meeting_to_confluence<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Connect Confluence first".to_string())?;
+    let url = publish_meeting(pool, &settings, &meeting_id).await?;
+    info!("Published meeting {} to Confluence", meeting_id);
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::ActionItem;
+    use crate::export::document::ExportSegment;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn renders_storage_format() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "R&D sync".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- [x] Ship v2\n| Owner | Area |\n|---|---|\n| Ana | API |".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Check <limits>".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: None,
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: vec![ExportSegment {
+                id: "segment-1".to_string(),
+                start: Some(5.0),
+                end: Some(9.0),
+                speaker: Some("Ana".to_string()),
+                text: "Let's start.".to_string(),
+                confidence: None,
+                language: None,
+                comments: Vec::new(),
+            }],
+        };
+        let body = storage_body(&document, true);
+        assert!(body.contains("<h3>Decisions</h3><ul><li>☑ Ship v2</li></ul><table><tr><th>Owner</th><th>Area</th></tr>"));
+        assert!(body.contains("<tr><td>Check &lt;limits&gt;</td><td>Ana</td><td></td><td></td></tr>"));
+        assert!(body.contains(
+            r#"<ac:parameter ac:name="title">Transcript</ac:parameter><ac:rich-text-body><p><code>00:00:05</code> <strong>Ana:</strong> Let's start.</p>"#
+        ));
+        assert!(!body.contains("<input"));
+        assert!(!storage_body(&document, false).contains("ac:structured-macro"));
+
+        let mut settings = ConfluenceSettings {
+            site_url: "https://acme.atlassian.net/wiki/".to_string(),
+            email: "ana@acme.com".to_string(),
+            api_token: "token".to_string(),
+            space_key: "ENG".to_string(),
+            parent_page: Some("https://acme.atlassian.net/wiki/spaces/ENG/pages/123456/Meetings".to_string()),
+            include_transcript: true,
+            automatic: false,
+        };
+        assert_eq!(settings.wiki_url(), "https://acme.atlassian.net/wiki");
+        assert_eq!(settings.parent_page_id().unwrap().as_deref(), Some("123456"));
+        settings.parent_page = Some("98765".to_string());
+        assert_eq!(settings.parent_page_id().unwrap().as_deref(), Some("98765"));
+        settings.parent_page = Some("Meetings".to_string());
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,635 @@
+// CRM notes
+// Attaches a meeting's minutes as a note to the HubSpot or Salesforce contacts whose emails
+// match the meeting's participants (as filled in from the calendar event), and optionally to
+// the open deals or opportunities those contacts are on. Participants from the user's own
+// email domains are never looked up. Each meeting becomes one note: attaching it again
+// rewrites the note and links contacts matched since. HubSpot is reached with a private
+// app's access token, Salesforce through a connected app using the client credentials flow.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chrono::SecondsFormat;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::{load_meeting, MeetingEvent};
+use crate::database::models::MeetingParticipant;
+use crate::database::repositories::crm_note::CrmNotesRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::{format_duration, MeetingDocument};
+use crate::export::html::{escape, markdown_html};
+use crate::state::AppState;
+
+const HUBSPOT_API: &str = "https://api.hubapi.com";
+const SALESFORCE_API_VERSION: &str = "v60.0";
+/// HubSpot's association types from a note to a contact and to a deal
+const HUBSPOT_NOTE_TO_CONTACT: u32 = 202;
+const HUBSPOT_NOTE_TO_DEAL: u32 = 214;
+/// Most participant emails looked up; HubSpot's IN filter takes 100 values
+const MAX_EMAILS: usize = 100;
+/// Longest Salesforce note title
+const MAX_TITLE_CHARS: usize = 255;
+
+/// Which CRM notes go to, and its credentials
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum CrmConnection {
+    /// Private app access token ("pat-…") with the contacts, deals and notes scopes
+    HubSpot { access_token: String },
+    Salesforce {
+        /// My Domain URL, e.g. "https://acme.my.salesforce.com"
+        domain_url: String,
+        /// Consumer key and secret of a connected app that allows the client credentials flow
+        client_id: String,
+        client_secret: String,
+    },
+}
+
+impl CrmConnection {
+    /// Key the meetings' notes are remembered under
+    fn id(&self) -> &'static str {
+        match self {
+            CrmConnection::HubSpot { .. } => "hubspot",
+            CrmConnection::Salesforce { .. } => "salesforce",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            CrmConnection::HubSpot { .. } => "HubSpot",
+            CrmConnection::Salesforce { .. } => "Salesforce",
+        }
+    }
+}
+
+/// CRM settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrmSettings {
+    pub connection: CrmConnection,
+    /// Also attach notes to the open deals (opportunities) of the matched contacts
+    #[serde(default)]
+    pub include_deals: bool,
+    /// Email domains of the user's own organization, e.g. "acme.com"
+    #[serde(default)]
+    pub internal_domains: Vec<String>,
+    /// Attach every meeting once its minutes are ready
+    #[serde(default)]
+    pub automatic: bool,
+}
+
+impl CrmSettings {
+    fn validate(&self) -> Result<(), String> {
+        match &self.connection {
+            CrmConnection::HubSpot { access_token } if access_token.trim().is_empty() => {
+                Err("Enter the access token of a HubSpot private app".to_string())
+            }
+            CrmConnection::Salesforce { domain_url, .. } if !domain_url.trim().starts_with("https://") => {
+                Err("Enter your Salesforce My Domain URL, e.g. https://your-company.my.salesforce.com".to_string())
+            }
+            CrmConnection::Salesforce { client_id, client_secret, .. }
+                if client_id.trim().is_empty() || client_secret.trim().is_empty() =>
+            {
+                Err("Enter the consumer key and secret of your Salesforce connected app".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A meeting's note in the CRM
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CrmNote {
+    pub note_id: String,
+    /// Contacts the note is attached to
+    pub contacts: usize,
+    /// Deals or opportunities the note is attached to
+    pub deals: usize,
+    /// The meeting's earlier note was rewritten rather than a new one created
+    pub updated: bool,
+}
+
+/// Participants' emails to look up: lowercased, once each, leaving out internal domains
+fn participant_emails(participants: &[MeetingParticipant], internal_domains: &[String]) -> Vec<String> {
+    let internal: Vec<String> = internal_domains
+        .iter()
+        .map(|domain| domain.trim().trim_start_matches('@').to_lowercase())
+        .filter(|domain| !domain.is_empty())
+        .collect();
+    let mut emails: Vec<String> = Vec::new();
+    for email in participants.iter().filter_map(|participant| participant.email.as_deref()) {
+        let email = email.trim().to_lowercase();
+        let Some((_, domain)) = email.split_once('@') else { continue };
+        if domain.is_empty() || internal.iter().any(|internal| internal == domain) || emails.contains(&email) {
+            continue;
+        }
+        emails.push(email);
+    }
+    emails.truncate(MAX_EMAILS);
+    emails
+}
+
+fn note_title(document: &MeetingDocument) -> String {
+    let title = format!("Meeting minutes: {} ({})", document.title.trim(), document.local_date());
+    title.chars().take(MAX_TITLE_CHARS).collect()
+}
+
+/// The note in the plain HTML both CRMs show: headings become bold paragraphs and task boxes
+/// symbols
+fn note_html(document: &MeetingDocument) -> String {
+    let mut details = escape(&document.local_date());
+    if let Some(duration) = document.duration_seconds() {
+        details.push_str(&format!(" · {}", format_duration(duration)));
+    }
+    let mut html = format!("<p><b>{}</b><br>{}</p>", escape(document.title.trim()), details);
+    match &document.summary {
+        Some(summary) => {
+            let mut minutes = markdown_html(summary)
+                .replace(r#"<input type="checkbox" disabled checked> "#, "☑ ")
+                .replace(r#"<input type="checkbox" disabled> "#, "☐ ")
+                .replace(r#"<ul class="tasks">"#, "<ul>");
+            for level in 2..=6 {
+                minutes = minutes
+                    .replace(&format!("<h{}>", level), "<p><b>")
+                    .replace(&format!("</h{}>", level), "</b></p>");
+            }
+            html.push_str(&minutes);
+        }
+        None => html.push_str("<p><i>No summary has been generated for this meeting yet.</i></p>"),
+    }
+
+    if !document.action_items.is_empty() {
+        html.push_str("<p><b>Action items</b></p><ul>");
+        for item in &document.action_items {
+            let mut details: Vec<String> = Vec::new();
+            if let Some(assignee) = item.assignee.as_deref().filter(|assignee| !assignee.trim().is_empty()) {
+                details.push(escape(assignee.trim()));
+            }
+            if let Some(due_date) = &item.due_date {
+                details.push(format!("due {}", escape(due_date)));
+            }
+            let details = if details.is_empty() { String::new() } else { format!(" ({})", details.join(", ")) };
+            let done = if item.done { "☑ " } else { "" };
+            html.push_str(&format!("<li>{}{}{}</li>", done, escape(item.description.trim()), details));
+        }
+        html.push_str("</ul>");
+    }
+    html
+}
+
+/// A string literal for a SOQL query
+fn soql_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn soql_list(values: &[String]) -> String {
+    values.iter().map(|value| soql_quote(value)).collect::<Vec<_>>().join(", ")
+}
+
+/// The reason in an error response: HubSpot's message, Salesforce's list of errors, or an
+/// OAuth error description
+fn error_message(body: &Value) -> Option<String> {
+    if let Some(message) = body.get("message").or_else(|| body.get("error_description")).and_then(Value::as_str) {
+        return Some(message.to_string());
+    }
+    let messages: Vec<&str> = body
+        .as_array()?
+        .iter()
+        .filter_map(|error| error.get("message").and_then(Value::as_str))
+        .collect();
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Ids as strings, once each; HubSpot returns some as numbers
+fn ids<'a>(values: impl Iterator<Item = &'a Value>) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for value in values {
+        let id = match value {
+            Value::String(id) => id.clone(),
+            Value::Number(id) => id.to_string(),
+            _ => continue,
+        };
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    ids
+}
+
+/// Send a request; None when what it addressed doesn't exist (404)
+async fn send(label: &str, request: reqwest::RequestBuilder) -> Result<Option<Value>, String> {
+    let response = request.send().await.map_err(|e| format!("Failed to reach {}: {}", label, e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(format!("{} rejected the credentials", label));
+    }
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|body| error_message(&body))
+            .unwrap_or(text);
+        return Err(format!("{} returned {}: {}", label, status, message.trim()));
+    }
+    if text.trim().is_empty() {
+        return Ok(Some(Value::Null));
+    }
+    serde_json::from_str(&text)
+        .map(Some)
+        .map_err(|e| format!("Invalid response from {}: {}", label, e))
+}
+
+/// A response for a request whose target must exist
+fn found(label: &str, response: Option<Value>) -> Result<Value, String> {
+    response.ok_or_else(|| format!("{} returned 404 Not Found", label))
+}
+
+struct HubSpot<'a> {
+    access_token: &'a str,
+}
+
+impl HubSpot<'_> {
+    async fn call(&self, method: reqwest::Method, path: &str, body: Option<&Value>) -> Result<Option<Value>, String> {
+        let mut request = reqwest::Client::new()
+            .request(method, format!("{}{}", HUBSPOT_API, path))
+            .bearer_auth(self.access_token.trim());
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        send("HubSpot", request).await
+    }
+
+    async fn contacts(&self, emails: &[String]) -> Result<Vec<String>, String> {
+        let search = json!({
+            "filterGroups": [{ "filters": [{ "propertyName": "email", "operator": "IN", "values": emails }] }],
+            "properties": ["email"],
+            "limit": 100,
+        });
+        let found = found("HubSpot", self.call(reqwest::Method::POST, "/crm/v3/objects/contacts/search", Some(&search)).await?)?;
+        let results = found["results"].as_array().cloned().unwrap_or_default();
+        Ok(ids(results.iter().map(|contact| &contact["id"])))
+    }
+
+    async fn open_deals(&self, contacts: &[String]) -> Result<Vec<String>, String> {
+        let inputs: Vec<Value> = contacts.iter().map(|id| json!({ "id": id })).collect();
+        let associations = self
+            .call(reqwest::Method::POST, "/crm/v4/associations/contacts/deals/batch/read", Some(&json!({ "inputs": inputs })))
+            .await?;
+        let associations = found("HubSpot", associations)?;
+        let results = associations["results"].as_array().cloned().unwrap_or_default();
+        let deals = ids(results.iter().flat_map(|result| result["to"].as_array().into_iter().flatten()).map(|to| &to["toObjectId"]));
+        if deals.is_empty() {
+            return Ok(deals);
+        }
+
+        let inputs: Vec<Value> = deals.iter().map(|id| json!({ "id": id })).collect();
+        let read = json!({ "properties": ["hs_is_closed"], "inputs": inputs });
+        let deals = found("HubSpot", self.call(reqwest::Method::POST, "/crm/v3/objects/deals/batch/read", Some(&read)).await?)?;
+        let open = deals["results"].as_array().cloned().unwrap_or_default();
+        let open = open.iter().filter(|deal| deal.pointer("/properties/hs_is_closed").and_then(Value::as_str) != Some("true"));
+        Ok(ids(open.map(|deal| &deal["id"])))
+    }
+
+    /// Rewrite the meeting's note when it still exists, else create one; returns its id and
+    /// whether it was rewritten
+    async fn write_note(
+        &self,
+        existing: Option<&str>,
+        properties: Value,
+        contacts: &[String],
+        deals: &[String],
+    ) -> Result<(String, bool), String> {
+        let targets = contacts
+            .iter()
+            .map(|id| ("contacts", HUBSPOT_NOTE_TO_CONTACT, id))
+            .chain(deals.iter().map(|id| ("deals", HUBSPOT_NOTE_TO_DEAL, id)));
+
+        if let Some(note_id) = existing {
+            let path = format!("/crm/v3/objects/notes/{}", note_id);
+            let body = json!({ "properties": properties });
+            if self.call(reqwest::Method::PATCH, &path, Some(&body)).await?.is_some() {
+                for (object, _, id) in targets {
+                    let path = format!("/crm/v4/objects/notes/{}/associations/default/{}/{}", note_id, object, id);
+                    found("HubSpot", self.call(reqwest::Method::PUT, &path, None).await?)?;
+                }
+                return Ok((note_id.to_string(), true));
+            }
+        }
+
+        let associations: Vec<Value> = targets
+            .map(|(_, association_type, id)| {
+                json!({
+                    "to": { "id": id },
+                    "types": [{ "associationCategory": "HUBSPOT_DEFINED", "associationTypeId": association_type }],
+                })
+            })
+            .collect();
+        let body = json!({ "properties": properties, "associations": associations });
+        let note = found("HubSpot", self.call(reqwest::Method::POST, "/crm/v3/objects/notes", Some(&body)).await?)?;
+        let note_id = ids(std::iter::once(&note["id"])).pop().ok_or("HubSpot returned a note without an id")?;
+        Ok((note_id, false))
+    }
+}
+
+struct Salesforce {
+    instance_url: String,
+    access_token: String,
+}
+
+impl Salesforce {
+    async fn connect(domain_url: &str, client_id: &str, client_secret: &str) -> Result<Self, String> {
+        let request = reqwest::Client::new()
+            .post(format!("{}/services/oauth2/token", domain_url.trim().trim_end_matches('/')))
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", client_id.trim()),
+                ("client_secret", client_secret.trim()),
+            ]);
+        let token = found("Salesforce", send("Salesforce", request).await?)?;
+        let field = |name: &str| token.get(name).and_then(Value::as_str).map(str::to_string);
+        match (field("instance_url"), field("access_token")) {
+            (Some(instance_url), Some(access_token)) => Ok(Self { instance_url, access_token }),
+            _ => Err("Salesforce did not return an access token".to_string()),
+        }
+    }
+
+    async fn call(&self, method: reqwest::Method, path: &str, body: Option<&Value>) -> Result<Option<Value>, String> {
+        let url = format!("{}/services/data/{}/{}", self.instance_url.trim_end_matches('/'), SALESFORCE_API_VERSION, path);
+        let mut request = reqwest::Client::new().request(method, url).bearer_auth(&self.access_token);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        send("Salesforce", request).await
+    }
+
+    /// Records a SOQL query returns; one page is plenty for a meeting's participants
+    async fn query(&self, soql: &str) -> Result<Vec<Value>, String> {
+        let url = format!("{}/services/data/{}/query", self.instance_url.trim_end_matches('/'), SALESFORCE_API_VERSION);
+        let request = reqwest::Client::new().get(url).query(&[("q", soql)]).bearer_auth(&self.access_token);
+        let result = found("Salesforce", send("Salesforce", request).await?)?;
+        Ok(result["records"].as_array().cloned().unwrap_or_default())
+    }
+
+    async fn contacts(&self, emails: &[String]) -> Result<Vec<String>, String> {
+        let records = self
+            .query(&format!("SELECT Id FROM Contact WHERE Email IN ({})", soql_list(emails)))
+            .await?;
+        Ok(ids(records.iter().map(|record| &record["Id"])))
+    }
+
+    async fn open_opportunities(&self, contacts: &[String]) -> Result<Vec<String>, String> {
+        let records = self
+            .query(&format!(
+                "SELECT OpportunityId FROM OpportunityContactRole WHERE ContactId IN ({}) AND Opportunity.IsClosed = false",
+                soql_list(contacts)
+            ))
+            .await?;
+        Ok(ids(records.iter().map(|record| &record["OpportunityId"])))
+    }
+
+    /// Rewrite the meeting's note when it still exists, else create one, then link it to the
+    /// records it isn't linked to yet; returns its id and whether it was rewritten
+    async fn write_note(&self, existing: Option<&str>, note: Value, records: &[String]) -> Result<(String, bool), String> {
+        let mut rewritten = None;
+        if let Some(note_id) = existing {
+            let path = format!("sobjects/ContentNote/{}", note_id);
+            if self.call(reqwest::Method::PATCH, &path, Some(&note)).await?.is_some() {
+                rewritten = Some(note_id.to_string());
+            }
+        }
+        let (note_id, updated) = match rewritten {
+            Some(note_id) => (note_id, true),
+            None => {
+                let created = found("Salesforce", self.call(reqwest::Method::POST, "sobjects/ContentNote", Some(&note)).await?)?;
+                let note_id = created.get("id").and_then(Value::as_str).ok_or("Salesforce returned a note without an id")?;
+                (note_id.to_string(), false)
+            }
+        };
+
+        // A note is its own ContentDocument; linking a record twice is an error
+        let linked: HashSet<String> = self
+            .query(&format!(
+                "SELECT LinkedEntityId FROM ContentDocumentLink WHERE ContentDocumentId = {}",
+                soql_quote(&note_id)
+            ))
+            .await?
+            .iter()
+            .filter_map(|link| link["LinkedEntityId"].as_str().map(str::to_string))
+            .collect();
+        for record in records.iter().filter(|record| !linked.contains(*record)) {
+            let link = json!({ "ContentDocumentId": note_id, "LinkedEntityId": record, "ShareType": "V" });
+            found("Salesforce", self.call(reqwest::Method::POST, "sobjects/ContentDocumentLink", Some(&link)).await?)?;
+        }
+        Ok((note_id, updated))
+    }
+}
+
+/// Attach the meeting's minutes to its participants' contacts; None when no participant
+/// email matches one
+async fn attach_meeting(pool: &SqlitePool, settings: &CrmSettings, meeting_id: &str) -> Result<Option<CrmNote>, String> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let participants = MeetingParticipantsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?;
+    let emails = participant_emails(&participants, &settings.internal_domains);
+    if emails.is_empty() {
+        return Ok(None);
+    }
+    let provider = settings.connection.id();
+    let existing = CrmNotesRepository::get_note_id(pool, provider, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load the meeting's {} note: {}", settings.connection.label(), e))?;
+    let html = note_html(&document);
+
+    let (note_id, contacts, deals, updated) = match &settings.connection {
+        CrmConnection::HubSpot { access_token } => {
+            let hubspot = HubSpot { access_token };
+            let contacts = hubspot.contacts(&emails).await?;
+            if contacts.is_empty() {
+                return Ok(None);
+            }
+            let deals = if settings.include_deals { hubspot.open_deals(&contacts).await? } else { Vec::new() };
+            let properties = json!({
+                "hs_timestamp": document.created_at.to_rfc3339_opts(SecondsFormat::Millis, true),
+                "hs_note_body": html,
+            });
+            let (note_id, updated) = hubspot.write_note(existing.as_deref(), properties, &contacts, &deals).await?;
+            (note_id, contacts.len(), deals.len(), updated)
+        }
+        CrmConnection::Salesforce { domain_url, client_id, client_secret } => {
+            let salesforce = Salesforce::connect(domain_url, client_id, client_secret).await?;
+            let contacts = salesforce.contacts(&emails).await?;
+            if contacts.is_empty() {
+                return Ok(None);
+            }
+            let opportunities = if settings.include_deals {
+                salesforce.open_opportunities(&contacts).await?
+            } else {
+                Vec::new()
+            };
+            let note = json!({ "Title": note_title(&document), "Content": STANDARD.encode(html) });
+            let records: Vec<String> = contacts.iter().chain(&opportunities).cloned().collect();
+            let (note_id, updated) = salesforce.write_note(existing.as_deref(), note, &records).await?;
+            (note_id, contacts.len(), opportunities.len(), updated)
+        }
+    };
+
+    CrmNotesRepository::save(pool, provider, meeting_id, &note_id)
+        .await
+        .map_err(|e| format!("Failed to remember {} note {}: {}", settings.connection.label(), note_id, e))?;
+    Ok(Some(CrmNote { note_id, contacts, deals, updated }))
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<CrmSettings>, String> {
+    SettingsRepository::get_crm_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load CRM settings: {}", e))
+}
+
+/// Attach a meeting whose minutes are ready when automatic attaching is on
+pub async fn handle_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    if event != MeetingEvent::SummaryReady {
+        return;
+    }
+    let settings = match load_settings(pool).await {
+        Ok(Some(settings)) if settings.automatic => settings,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    let label = settings.connection.label();
+    match attach_meeting(pool, &settings, meeting_id).await {
+        Ok(Some(note)) => info!("Attached meeting {} to {} contacts in {}", meeting_id, note.contacts, label),
+        Ok(None) => info!("No participant of meeting {} is a {} contact", meeting_id, label),
+        Err(e) => warn!("Failed to attach meeting {} in {}: {}", meeting_id, label, e),
+    }
+}
+
+#[command]
+pub async fn get_crm_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<CrmSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_crm_settings<R: Runtime>(app: AppHandle<R>, settings: CrmSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_crm_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save CRM settings: {}", e))
+}
+
+/// Check the credentials; returns the HubSpot account id or the Salesforce instance URL
+#[command]
+pub async fn test_crm_connection(settings: CrmSettings) -> Result<String, String> {
+    settings.validate()?;
+    match &settings.connection {
+        CrmConnection::HubSpot { access_token } => {
+            let hubspot = HubSpot { access_token };
+            let account = found("HubSpot", hubspot.call(reqwest::Method::GET, "/account-info/v3/details", None).await?)?;
+            Ok(ids(std::iter::once(&account["portalId"])).pop().unwrap_or_default())
+        }
+        CrmConnection::Salesforce { domain_url, client_id, client_secret } => {
+            Ok(Salesforce::connect(domain_url, client_id, client_secret).await?.instance_url)
+        }
+    }
+}
+
+/// Attach a meeting's minutes as a note to its participants' contacts
+#[command]
+pub async fn attach_meeting_to_crm<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<CrmNote, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool)
+        .await?
+        .ok_or_else(|| "Connect HubSpot or Salesforce first".to_string())?;
+    let label = settings.connection.label();
+    let note = attach_meeting(pool, &settings, &meeting_id)
+        .await?
+        .ok_or_else(|| format!("No participant email of this meeting matches a {} contact", label))?;
+    info!("Attached meeting {} to {} contacts in {}", meeting_id, note.contacts, label);
+    Ok(note)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::ActionItem;
+    use chrono::Utc;
+
+    #[test]
+    fn matches_participants_and_formats_notes() {
+        let participant = |name: &str, email: Option<&str>| MeetingParticipant {
+            id: format!("participant-{}", name),
+            meeting_id: "meeting-1".to_string(),
+            name: name.to_string(),
+            email: email.map(str::to_string),
+            source: "calendar".to_string(),
+            created_at: Utc::now(),
+        };
+        let participants = [
+            participant("Ana Ruiz", Some("ana@acme.com")),
+            participant("Dana Lee", Some(" Dana.Lee@Client.io ")),
+            participant("Dana", Some("dana.lee@client.io")),
+            participant("Bo Chen", None),
+            participant("Room", Some("not-an-email")),
+        ];
+        assert_eq!(participant_emails(&participants, &["@Acme.com".to_string()]), ["dana.lee@client.io"]);
+        assert_eq!(participant_emails(&participants, &[]), ["ana@acme.com", "dana.lee@client.io"]);
+
+        assert_eq!(soql_list(&["o'neil@client.io".to_string(), "a\\b@c.io".to_string()]), r"'o\'neil@client.io', 'a\\b@c.io'");
+        assert_eq!(error_message(&json!([{ "message": "Bad id", "errorCode": "INVALID_ID_FIELD" }])).as_deref(), Some("Bad id"));
+        assert_eq!(error_message(&json!({ "error": "invalid_client", "error_description": "invalid client credentials" })).as_deref(), Some("invalid client credentials"));
+        assert_eq!(ids([json!(12), json!("12"), json!("ab"), Value::Null].iter()), ["12", "ab"]);
+
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Renewal <call>".to_string(),
+            created_at: Utc::now(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Decisions\n- [x] Offer the 3-year plan".to_string()),
+            action_items: vec![ActionItem {
+                id: "action-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Send the quote".to_string(),
+                assignee: Some("Ana".to_string()),
+                due_date: Some("2026-03-02".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc::now(),
+            }],
+            segments: Vec::new(),
+        };
+        let html = note_html(&document);
+        assert!(html.starts_with("<p><b>Renewal &lt;call&gt;</b><br>"));
+        assert!(html.contains("<p><b>Decisions</b></p><ul><li>☑ Offer the 3-year plan</li></ul>"));
+        assert!(html.ends_with("<p><b>Action items</b></p><ul><li>Send the quote (Ana, due 2026-03-02)</li></ul>"));
+        assert!(note_title(&document).starts_with("Meeting minutes: Renewal <call> ("));
+
+        let settings: CrmSettings =
+            serde_json::from_str(r#"{"connection": {"kind": "hubspot", "access_token": "pat-na1-123"}}"#).unwrap();
+        assert!(settings.validate().is_ok() && !settings.include_deals && !settings.automatic);
+        let salesforce = CrmSettings {
+            connection: CrmConnection::Salesforce {
+                domain_url: "acme.my.salesforce.com".to_string(),
+                client_id: "key".to_string(),
+                client_secret: "secret".to_string(),
+            },
+            ..settings
+        };
+        assert!(salesforce.validate().is_err());
+    }
+}
@@ -0,0 +1,310 @@
+// Email
+// Sends a meeting's minutes to its participants through the user's own SMTP server. The
+// server settings live in the settings table; the SMTP password is kept in the OS keychain
+// next to the database key, never in the database. Unless the user wrote their own
+// message, the email is the follow-up drafted from the minutes by the summary model.
+
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Address, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::encryption::KEYCHAIN_SERVICE;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::summary::follow_up::draft_follow_up_email;
+
+const KEYCHAIN_ACCOUNT: &str = "smtp-password";
+/// Most recipients of one email
+const MAX_RECIPIENTS: usize = 100;
+
+/// How the connection to the SMTP server is secured
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS, usually port 587
+    #[default]
+    Starttls,
+    /// TLS from the start, usually port 465
+    Tls,
+    /// Unencrypted; only for servers on the local machine or network
+    None,
+}
+
+/// SMTP server settings, stored as JSON in settings; the password is in the keychain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SmtpSettings {
+    pub host: String,
+    pub port: u16,
+    #[serde(default)]
+    pub security: SmtpSecurity,
+    /// Login; None sends without authenticating
+    #[serde(default)]
+    pub username: Option<String>,
+    pub from_address: String,
+    #[serde(default)]
+    pub from_name: Option<String>,
+}
+
+impl SmtpSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.host.trim().is_empty() || self.host.contains(char::is_whitespace) {
+            return Err("Enter the SMTP server's host name".to_string());
+        }
+        if self.port == 0 {
+            return Err("Enter the SMTP server's port".to_string());
+        }
+        self.sender()?;
+        Ok(())
+    }
+
+    fn sender(&self) -> Result<Mailbox, String> {
+        let address: Address = self
+            .from_address
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid sender address: {}", self.from_address))?;
+        let name = self.from_name.as_deref().map(str::trim).filter(|name| !name.is_empty());
+        Ok(Mailbox::new(name.map(str::to_string), address))
+    }
+
+    fn username(&self) -> Option<&str> {
+        self.username.as_deref().map(str::trim).filter(|username| !username.is_empty())
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SmtpSettingsResponse {
+    #[serde(flatten)]
+    pub settings: SmtpSettings,
+    /// A password is stored in the keychain
+    pub has_password: bool,
+}
+
+fn keychain_entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT).map_err(|e| format!("Failed to access the system keychain: {}", e))
+}
+
+fn stored_password() -> Result<Option<String>, String> {
+    match keychain_entry()?.get_password() {
+        Ok(password) => Ok(Some(password)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read the SMTP password from the system keychain: {}", e)),
+    }
+}
+
+/// Recipients as mailboxes: addresses checked, duplicates dropped
+fn parse_recipients(addresses: &[String]) -> Result<Vec<Mailbox>, String> {
+    let mut mailboxes: Vec<Mailbox> = Vec::new();
+    for address in addresses.iter().map(|address| address.trim()).filter(|address| !address.is_empty()) {
+        let mailbox: Mailbox = address.parse().map_err(|_| format!("Invalid email address: {}", address))?;
+        let email: &str = mailbox.email.as_ref();
+        if !mailboxes.iter().any(|known| AsRef::<str>::as_ref(&known.email).eq_ignore_ascii_case(email)) {
+            mailboxes.push(mailbox);
+        }
+    }
+    if mailboxes.is_empty() {
+        return Err("Add at least one recipient".to_string());
+    }
+    if mailboxes.len() > MAX_RECIPIENTS {
+        return Err(format!("An email can go to at most {} recipients", MAX_RECIPIENTS));
+    }
+    Ok(mailboxes)
+}
+
+fn build_message(sender: Mailbox, to: Vec<Mailbox>, subject: &str, body: &str) -> Result<Message, String> {
+    let mut builder = Message::builder().from(sender).subject(subject.trim());
+    for mailbox in to {
+        builder = builder.to(mailbox);
+    }
+    builder
+        .header(ContentType::TEXT_PLAIN)
+        .body(body.trim().to_string())
+        .map_err(|e| format!("Failed to build the email: {}", e))
+}
+
+fn transport(settings: &SmtpSettings) -> Result<AsyncSmtpTransport<Tokio1Executor>, String> {
+    let host = settings.host.trim();
+    let builder = match settings.security {
+        SmtpSecurity::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host),
+        SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(host),
+        SmtpSecurity::None => Ok(AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)),
+    }
+    .map_err(|e| format!("Failed to set up the connection to {}: {}", host, e))?;
+    let mut builder = builder.port(settings.port);
+    if let Some(username) = settings.username() {
+        let password = stored_password()?.ok_or_else(|| "Enter the SMTP password".to_string())?;
+        builder = builder.credentials(Credentials::new(username.to_string(), password));
+    }
+    Ok(builder.build())
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<SmtpSettings>, String> {
+    SettingsRepository::get_smtp_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load email settings: {}", e))
+}
+
+#[command]
+pub async fn get_smtp_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<SmtpSettingsResponse>, String> {
+    let state = app.state::<AppState>();
+    let Some(settings) = load_settings(state.db_manager.pool()).await? else {
+        return Ok(None);
+    };
+    let has_password = stored_password()?.is_some();
+    Ok(Some(SmtpSettingsResponse { settings, has_password }))
+}
+
+/// Save the SMTP server settings
+///
+/// # Arguments
+/// * `password` - New password for the keychain; an empty one removes it, None keeps the stored one
+#[command]
+pub async fn save_smtp_settings<R: Runtime>(app: AppHandle<R>, settings: SmtpSettings, password: Option<String>) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_smtp_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save email settings: {}", e))?;
+
+    match password {
+        Some(password) if password.is_empty() => match keychain_entry()?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => {}
+            Err(e) => return Err(format!("Failed to remove the SMTP password from the system keychain: {}", e)),
+        },
+        Some(password) => keychain_entry()?
+            .set_password(&password)
+            .map_err(|e| format!("Failed to store the SMTP password in the system keychain: {}", e))?,
+        None => {}
+    }
+    Ok(())
+}
+
+/// Connect and log in to the saved SMTP server without sending anything
+#[command]
+pub async fn test_smtp_connection<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let settings = load_settings(state.db_manager.pool())
+        .await?
+        .ok_or_else(|| "Set up an SMTP server first".to_string())?;
+    let connected = transport(&settings)?
+        .test_connection()
+        .await
+        .map_err(|e| format!("Failed to connect to {}: {}", settings.host, e))?;
+    if !connected {
+        return Err(format!("{} did not accept the connection", settings.host));
+    }
+    Ok(())
+}
+
+/// Email a meeting's minutes
+///
+/// # Arguments
+/// * `recipients` - Addresses to send to; None sends to the meeting's participants that have one
+/// * `subject`, `body` - The message; a missing body is drafted as a follow-up email from the
+///   minutes, a missing subject comes from that draft or the meeting title
+///
+/// # Returns
+/// How many people the email was sent to
+#[command]
+pub async fn email_meeting_minutes<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    recipients: Option<Vec<String>>,
+    subject: Option<String>,
+    body: Option<String>,
+) -> Result<usize, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Set up an SMTP server first".to_string())?;
+    crate::protection::ensure_unlocked(pool, &meeting_id).await?;
+
+    let addresses = match recipients {
+        Some(addresses) => addresses,
+        None => {
+            let addresses: Vec<String> = MeetingParticipantsRepository::list_for_meeting(pool, &meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load participants: {}", e))?
+                .into_iter()
+                .filter_map(|participant| {
+                    let name = participant.name.replace(['<', '>', '"'], "");
+                    participant.email.map(|email| format!("{} <{}>", name, email.trim()))
+                })
+                .collect();
+            if addresses.is_empty() {
+                return Err("None of the participants has an email address; add the recipients".to_string());
+            }
+            addresses
+        }
+    };
+    let to = parse_recipients(&addresses)?;
+
+    let (subject, body) = match body.filter(|body| !body.trim().is_empty()) {
+        Some(body) => {
+            let subject = match subject.filter(|subject| !subject.trim().is_empty()) {
+                Some(subject) => subject,
+                None => {
+                    let document = super::load_meeting(pool, &meeting_id).await?;
+                    format!("Minutes: {}", document.title)
+                }
+            };
+            (subject, body)
+        }
+        None => {
+            let draft = draft_follow_up_email(&app, &meeting_id).await?;
+            (subject.filter(|subject| !subject.trim().is_empty()).unwrap_or(draft.subject), draft.body)
+        }
+    };
+
+    let count = to.len();
+    let message = build_message(settings.sender()?, to, &subject, &body)?;
+    transport(&settings)?
+        .send(message)
+        .await
+        .map_err(|e| format!("Failed to send the email: {}", e))?;
+    info!("Emailed the minutes of {} to {} recipients", meeting_id, count);
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_minutes_email() {
+        let to = parse_recipients(&[
+            "Ana Ruiz <ana@example.com>".to_string(),
+            " bo@example.com ".to_string(),
+            "ANA@example.com".to_string(),
+            String::new(),
+        ])
+        .unwrap();
+        assert_eq!(to.len(), 2);
+        assert_eq!(to[0].name.as_deref(), Some("Ana Ruiz"));
+        assert!(parse_recipients(&["not an address".to_string()]).unwrap_err().contains("not an address"));
+        assert!(parse_recipients(&[]).is_err());
+
+        let settings = SmtpSettings {
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            security: SmtpSecurity::Starttls,
+            username: Some("ana".to_string()),
+            from_address: "ana@example.com".to_string(),
+            from_name: Some("Ana Ruiz".to_string()),
+        };
+        assert!(settings.validate().is_ok());
+        let message = build_message(settings.sender().unwrap(), to, "Recap: Q3 planning", "Hi all,\n\nThanks!\n").unwrap();
+        let raw = String::from_utf8(message.formatted()).unwrap();
+        assert!(raw.contains("From: \"Ana Ruiz\" <ana@example.com>\r\n"));
+        assert!(raw.contains("To: \"Ana Ruiz\" <ana@example.com>, bo@example.com\r\n"));
+        assert!(raw.contains("Subject: Recap: Q3 planning\r\n"));
+        assert!(raw.ends_with("Hi all,\r\n\r\nThanks!"));
+
+        let invalid = SmtpSettings { from_address: "ana".to_string(), ..settings };
+        assert!(invalid.validate().is_err());
+    }
+}
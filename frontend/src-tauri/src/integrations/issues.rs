@@ -0,0 +1,200 @@
+// Issues from action items
+// Shared by the issue tracker integrations (Jira, Linear): the selected action items of a
+// meeting become issue drafts with a title, a description pointing back to the meeting and
+// the assignee's email when a participant of that name has one. Once an issue exists its
+// key and link are stored on the action item, and items that already have one are skipped.
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use super::load_meeting;
+use crate::database::models::{ActionItem, MeetingParticipant};
+use crate::database::repositories::action_item::ActionItemsRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::meeting_tag::MeetingTagsRepository;
+
+/// Longest issue title; Jira refuses longer summaries
+const MAX_TITLE_CHARS: usize = 255;
+
+/// An issue created from an action item
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedIssue {
+    pub action_item_id: String,
+    /// e.g. "ENG-123"
+    pub issue_key: String,
+    pub issue_url: String,
+}
+
+/// What the issue for one action item says
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct IssueDraft {
+    pub action_item_id: String,
+    pub title: String,
+    /// Paragraphs of the issue's description
+    pub description: Vec<String>,
+    /// "YYYY-MM-DD"
+    pub due_date: Option<String>,
+    /// The assignee's email, to find their account in the tracker
+    pub assignee_email: Option<String>,
+}
+
+/// Drafts for a meeting's selected action items, and the meeting's tags to map them by
+pub(super) struct MeetingIssues {
+    pub tags: Vec<String>,
+    pub drafts: Vec<IssueDraft>,
+}
+
+/// The item's first line, shortened to what trackers accept as a title
+pub(super) fn issue_title(description: &str) -> String {
+    let line = description.trim().lines().next().unwrap_or_default().trim();
+    if line.chars().count() <= MAX_TITLE_CHARS {
+        return line.to_string();
+    }
+    let mut title: String = line.chars().take(MAX_TITLE_CHARS - 1).collect();
+    title.push('…');
+    title
+}
+
+/// Email of the participant an assignee names: an address itself, the participant's full
+/// name, or a first name only one participant has
+fn assignee_email(assignee: &str, participants: &[MeetingParticipant]) -> Option<String> {
+    let assignee = assignee.trim();
+    if assignee.contains('@') && !assignee.contains(char::is_whitespace) {
+        return Some(assignee.to_string());
+    }
+    let with_email: Vec<(&str, &str)> = participants
+        .iter()
+        .filter_map(|participant| Some((participant.name.trim(), participant.email.as_deref()?.trim())))
+        .filter(|(_, email)| !email.is_empty())
+        .collect();
+    if let Some((_, email)) = with_email.iter().find(|(name, _)| name.eq_ignore_ascii_case(assignee)) {
+        return Some(email.to_string());
+    }
+    let mut first_names = with_email.iter().filter(|(name, _)| {
+        name.split_whitespace()
+            .next()
+            .is_some_and(|first| first.eq_ignore_ascii_case(assignee))
+    });
+    match (first_names.next(), first_names.next()) {
+        (Some((_, email)), None) => Some(email.to_string()),
+        _ => None,
+    }
+}
+
+fn draft(item: &ActionItem, meeting_title: &str, meeting_date: &str, participants: &[MeetingParticipant]) -> IssueDraft {
+    let assignee = item.assignee.as_deref().map(str::trim).filter(|assignee| !assignee.is_empty());
+    let assignee_email = assignee.and_then(|assignee| assignee_email(assignee, participants));
+    let mut description = vec![
+        item.description.trim().to_string(),
+        format!("From the meeting \"{}\" on {}.", meeting_title.trim(), meeting_date),
+    ];
+    if let (Some(assignee), None) = (assignee, &assignee_email) {
+        description.push(format!("Assigned in the meeting to {}.", assignee));
+    }
+    IssueDraft {
+        action_item_id: item.id.clone(),
+        title: issue_title(&item.description),
+        description,
+        due_date: item.due_date.clone(),
+        assignee_email,
+    }
+}
+
+/// Drafts for the meeting's action items among `action_item_ids` that have no issue yet
+pub(super) async fn load_drafts(pool: &SqlitePool, meeting_id: &str, action_item_ids: &[String]) -> Result<MeetingIssues, String> {
+    if action_item_ids.is_empty() {
+        return Err("Select the action items to create issues for".to_string());
+    }
+    let document = load_meeting(pool, meeting_id).await?;
+    let participants = MeetingParticipantsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?;
+    let tags = MeetingTagsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))?;
+
+    let date = document.local_date();
+    let mut drafts = Vec::new();
+    for id in action_item_ids {
+        let item = document
+            .action_items
+            .iter()
+            .find(|item| item.id == *id)
+            .ok_or_else(|| format!("Action item {} not found in this meeting", id))?;
+        if item.issue_key.is_none() {
+            drafts.push(draft(item, &document.title, &date, &participants));
+        }
+    }
+    Ok(MeetingIssues { tags, drafts })
+}
+
+/// Store the issue's key and link on its action item
+pub(super) async fn record_issue(pool: &SqlitePool, action_item_id: &str, issue_key: &str, issue_url: &str) -> Result<CreatedIssue, String> {
+    ActionItemsRepository::set_issue(pool, action_item_id, issue_key, issue_url)
+        .await
+        .map_err(|e| format!("Failed to link the action item to {}: {}", issue_key, e))?;
+    Ok(CreatedIssue {
+        action_item_id: action_item_id.to_string(),
+        issue_key: issue_key.to_string(),
+        issue_url: issue_url.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn drafts_issues_from_action_items() {
+        let participant = |name: &str, email: Option<&str>| MeetingParticipant {
+            id: format!("participant-{}", name),
+            meeting_id: "meeting-1".to_string(),
+            name: name.to_string(),
+            email: email.map(str::to_string),
+            source: "manual".to_string(),
+            created_at: Utc::now(),
+        };
+        let participants = [
+            participant("Ana Ruiz", Some("ana@acme.com")),
+            participant("Bo Chen", None),
+            participant("Carla Diaz", Some("carla@acme.com")),
+            participant("Carla Mendes", Some("cmendes@acme.com")),
+        ];
+        assert_eq!(assignee_email("ana ruiz", &participants).as_deref(), Some("ana@acme.com"));
+        assert_eq!(assignee_email("Ana", &participants).as_deref(), Some("ana@acme.com"));
+        assert_eq!(assignee_email("Carla", &participants), None);
+        assert_eq!(assignee_email("Bo Chen", &participants), None);
+        assert_eq!(assignee_email("dev@acme.com", &participants).as_deref(), Some("dev@acme.com"));
+
+        let item = |description: &str, assignee: Option<&str>| ActionItem {
+            id: "action-1".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            description: description.to_string(),
+            assignee: assignee.map(str::to_string),
+            due_date: Some("2026-03-02".to_string()),
+            segment_id: None,
+            done: false,
+            issue_key: None,
+            issue_url: None,
+            created_at: Utc::now(),
+        };
+        let issue = draft(&item("Draft the migration plan", Some("Ana")), "Platform sync", "2026-02-23 10:00", &participants);
+        assert_eq!(issue.title, "Draft the migration plan");
+        assert_eq!(
+            issue.description,
+            ["Draft the migration plan", "From the meeting \"Platform sync\" on 2026-02-23 10:00."]
+        );
+        assert_eq!(issue.due_date.as_deref(), Some("2026-03-02"));
+        assert_eq!(issue.assignee_email.as_deref(), Some("ana@acme.com"));
+
+        let issue = draft(&item("Ask legal\nabout the DPA", Some("Bo Chen")), "Platform sync", "2026-02-23 10:00", &participants);
+        assert_eq!(issue.title, "Ask legal");
+        assert_eq!(issue.description[2], "Assigned in the meeting to Bo Chen.");
+        assert_eq!(issue.assignee_email, None);
+
+        let title = issue_title(&"a".repeat(300));
+        assert_eq!(title.chars().count(), MAX_TITLE_CHARS);
+        assert!(title.ends_with('…'));
+    }
+}
@@ -0,0 +1,287 @@
+// Jira
+// Creates Jira Cloud issues from a meeting's selected action items, in the project mapped to
+// one of the meeting's tags or else the default project. An item's assignee is assigned when
+// their email, found through the meeting's participants, belongs to a Jira user. Authenticates
+// with the user's Atlassian email and an API token.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::issues::{self, CreatedIssue};
+use crate::database::repositories::meeting_tag::normalize_tag;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+
+fn default_issue_type() -> String {
+    "Task".to_string()
+}
+
+/// Issues from meetings with `tag` go to `project_key`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraProjectRule {
+    pub tag: String,
+    pub project_key: String,
+}
+
+/// Jira settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraSettings {
+    /// Site URL, e.g. "https://acme.atlassian.net"
+    pub site_url: String,
+    /// Atlassian account email the API token belongs to
+    pub email: String,
+    pub api_token: String,
+    /// Project for meetings no rule maps
+    pub project_key: String,
+    #[serde(default = "default_issue_type")]
+    pub issue_type: String,
+    /// Checked in order; the first whose tag the meeting has picks the project
+    #[serde(default)]
+    pub project_rules: Vec<JiraProjectRule>,
+}
+
+impl JiraSettings {
+    fn validate(&self) -> Result<(), String> {
+        if !self.site_url.trim().starts_with("https://") {
+            return Err("Enter your Jira site URL, e.g. https://your-team.atlassian.net".to_string());
+        }
+        if self.email.trim().is_empty() || self.api_token.trim().is_empty() {
+            return Err("Enter your Atlassian email and an API token".to_string());
+        }
+        if self.project_key.trim().is_empty() {
+            return Err("Enter the key of the project to create issues in".to_string());
+        }
+        if self.issue_type.trim().is_empty() {
+            return Err("Enter the issue type to create, e.g. Task".to_string());
+        }
+        if self
+            .project_rules
+            .iter()
+            .any(|rule| normalize_tag(&rule.tag).is_empty() || rule.project_key.trim().is_empty())
+        {
+            return Err("Every project mapping needs a tag and a project key".to_string());
+        }
+        Ok(())
+    }
+
+    fn site_url(&self) -> &str {
+        self.site_url.trim().trim_end_matches('/')
+    }
+
+    fn project_for(&self, tags: &[String]) -> &str {
+        self.project_rules
+            .iter()
+            .find(|rule| tags.contains(&normalize_tag(&rule.tag)))
+            .map_or(&self.project_key, |rule| &rule.project_key)
+            .trim()
+    }
+}
+
+/// Paragraphs in the Atlassian Document Format Jira expects for descriptions
+fn adf_document(paragraphs: &[String]) -> Value {
+    let content: Vec<Value> = paragraphs
+        .iter()
+        .filter(|paragraph| !paragraph.trim().is_empty())
+        .map(|paragraph| json!({ "type": "paragraph", "content": [{ "type": "text", "text": paragraph }] }))
+        .collect();
+    json!({ "type": "doc", "version": 1, "content": content })
+}
+
+/// Jira's reasons from an error response: general messages and per-field errors
+fn error_message(body: &Value) -> Option<String> {
+    let mut messages: Vec<String> = body
+        .get("errorMessages")
+        .and_then(Value::as_array)
+        .map(|messages| messages.iter().filter_map(Value::as_str).map(str::to_string).collect())
+        .unwrap_or_default();
+    if let Some(errors) = body.get("errors").and_then(Value::as_object) {
+        messages.extend(
+            errors
+                .iter()
+                .filter_map(|(field, message)| Some(format!("{}: {}", field, message.as_str()?))),
+        );
+    }
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+async fn call_api(
+    settings: &JiraSettings,
+    method: reqwest::Method,
+    path: &str,
+    query: &[(&str, &str)],
+    body: Option<&Value>,
+) -> Result<Value, String> {
+    let mut request = reqwest::Client::new()
+        .request(method, format!("{}/rest/api/3/{}", settings.site_url(), path))
+        .query(query)
+        .basic_auth(settings.email.trim(), Some(settings.api_token.trim()))
+        .header("Accept", "application/json");
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await.map_err(|e| format!("Failed to reach Jira: {}", e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err("Jira rejected the email or API token".to_string());
+    }
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|body| error_message(&body))
+            .unwrap_or(text);
+        return Err(format!("Jira returned {}: {}", status, message.trim()));
+    }
+    serde_json::from_str(&text).map_err(|e| format!("Invalid response from Jira: {}", e))
+}
+
+/// The Jira account with this email, when there is one the API token may see
+async fn account_id(settings: &JiraSettings, email: &str) -> Result<Option<String>, String> {
+    let users = call_api(settings, reqwest::Method::GET, "user/search", &[("query", email)], None).await?;
+    Ok(users
+        .as_array()
+        .and_then(|users| {
+            users
+                .iter()
+                .find(|user| user.get("accountType").and_then(Value::as_str) == Some("atlassian"))
+        })
+        .and_then(|user| user.get("accountId").and_then(Value::as_str))
+        .map(str::to_string))
+}
+
+async fn create_issues(
+    pool: &SqlitePool,
+    settings: &JiraSettings,
+    meeting_id: &str,
+    action_item_ids: &[String],
+) -> Result<Vec<CreatedIssue>, String> {
+    let meeting = issues::load_drafts(pool, meeting_id, action_item_ids).await?;
+    let project = settings.project_for(&meeting.tags);
+    let mut accounts: HashMap<String, Option<String>> = HashMap::new();
+    let mut created = Vec::new();
+    for draft in meeting.drafts {
+        let mut fields = json!({
+            "project": { "key": project },
+            "issuetype": { "name": settings.issue_type.trim() },
+            "summary": draft.title,
+            "description": adf_document(&draft.description),
+        });
+        if let Some(due_date) = &draft.due_date {
+            fields["duedate"] = json!(due_date);
+        }
+        if let Some(email) = &draft.assignee_email {
+            let account = match accounts.get(email) {
+                Some(account) => account.clone(),
+                None => {
+                    let account = account_id(settings, email).await?;
+                    accounts.insert(email.clone(), account.clone());
+                    account
+                }
+            };
+            if let Some(account) = account {
+                fields["assignee"] = json!({ "accountId": account });
+            }
+        }
+
+        let issue = call_api(settings, reqwest::Method::POST, "issue", &[], Some(&json!({ "fields": fields }))).await?;
+        let key = issue.get("key").and_then(Value::as_str).ok_or("Jira returned an issue without a key")?;
+        let url = format!("{}/browse/{}", settings.site_url(), key);
+        created.push(issues::record_issue(pool, &draft.action_item_id, key, &url).await?);
+    }
+    Ok(created)
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<JiraSettings>, String> {
+    SettingsRepository::get_jira_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load Jira settings: {}", e))
+}
+
+#[command]
+pub async fn get_jira_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<JiraSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_jira_settings<R: Runtime>(app: AppHandle<R>, settings: JiraSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_jira_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save Jira settings: {}", e))
+}
+
+/// Check the credentials and the default project; returns the project's name
+#[command]
+pub async fn test_jira_connection(settings: JiraSettings) -> Result<String, String> {
+    settings.validate()?;
+    let path = format!("project/{}", settings.project_key.trim());
+    let project = call_api(&settings, reqwest::Method::GET, &path, &[], None).await?;
+    Ok(project.get("name").and_then(Value::as_str).unwrap_or_default().to_string())
+}
+
+/// Create a Jira issue for each selected action item of a meeting that has none yet
+///
+/// # Returns
+/// The issues created; their keys are also stored on the action items
+#[command]
+pub async fn create_jira_issues<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    action_item_ids: Vec<String>,
+) -> Result<Vec<CreatedIssue>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Connect Jira first".to_string())?;
+    let created = create_issues(pool, &settings, &meeting_id, &action_item_ids).await?;
+    info!("Created {} Jira issues from meeting {}", created.len(), meeting_id);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_projects_and_formats_requests() {
+        let settings = JiraSettings {
+            site_url: "https://acme.atlassian.net/".to_string(),
+            email: "ana@acme.com".to_string(),
+            api_token: "token".to_string(),
+            project_key: "OPS".to_string(),
+            issue_type: default_issue_type(),
+            project_rules: vec![
+                JiraProjectRule { tag: "Platform".to_string(), project_key: "PLAT".to_string() },
+                JiraProjectRule { tag: "sales".to_string(), project_key: "CRM".to_string() },
+            ],
+        };
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.site_url(), "https://acme.atlassian.net");
+        assert_eq!(settings.project_for(&["platform".to_string(), "sales".to_string()]), "PLAT");
+        assert_eq!(settings.project_for(&["hiring".to_string()]), "OPS");
+        let invalid = JiraSettings {
+            project_rules: vec![JiraProjectRule { tag: " ".to_string(), project_key: "X".to_string() }],
+            ..settings
+        };
+        assert!(invalid.validate().is_err());
+
+        assert_eq!(
+            adf_document(&["Draft the plan".to_string(), String::new()]),
+            json!({
+                "type": "doc",
+                "version": 1,
+                "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Draft the plan" }] }],
+            })
+        );
+        assert_eq!(
+            error_message(&json!({ "errorMessages": [], "errors": { "duedate": "Field 'duedate' cannot be set." } })).as_deref(),
+            Some("duedate: Field 'duedate' cannot be set.")
+        );
+        assert_eq!(error_message(&json!({ "errorMessages": [] })), None);
+    }
+}
@@ -0,0 +1,262 @@
+// Linear
+// Creates Linear issues from a meeting's selected action items, in the team mapped to one of
+// the meeting's tags or else the default team. An item's assignee is assigned when their
+// email, found through the meeting's participants, belongs to a Linear user. Talks to
+// Linear's GraphQL API with a personal API key.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use super::issues::{self, CreatedIssue};
+use crate::database::repositories::meeting_tag::normalize_tag;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+
+const API_URL: &str = "https://api.linear.app/graphql";
+
+/// Issues from meetings with `tag` go to `team`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearTeamRule {
+    pub tag: String,
+    /// Team key ("ENG"), name or id
+    pub team: String,
+}
+
+/// Linear settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearSettings {
+    /// Personal API key ("lin_api_…")
+    pub api_key: String,
+    /// Team for meetings no rule maps: its key, name or id
+    pub team: String,
+    /// Checked in order; the first whose tag the meeting has picks the team
+    #[serde(default)]
+    pub team_rules: Vec<LinearTeamRule>,
+}
+
+impl LinearSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.api_key.trim().is_empty() {
+            return Err("Enter a Linear API key".to_string());
+        }
+        if self.team.trim().is_empty() {
+            return Err("Choose the team to create issues in".to_string());
+        }
+        if self
+            .team_rules
+            .iter()
+            .any(|rule| normalize_tag(&rule.tag).is_empty() || rule.team.trim().is_empty())
+        {
+            return Err("Every team mapping needs a tag and a team".to_string());
+        }
+        Ok(())
+    }
+
+    fn team_for(&self, tags: &[String]) -> &str {
+        self.team_rules
+            .iter()
+            .find(|rule| tags.contains(&normalize_tag(&rule.tag)))
+            .map_or(&self.team, |rule| &rule.team)
+            .trim()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinearTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+/// The team a setting names by key, name or id
+fn find_team<'a>(teams: &'a [LinearTeam], wanted: &str) -> Option<&'a LinearTeam> {
+    teams.iter().find(|team| {
+        team.id == wanted || team.key.eq_ignore_ascii_case(wanted) || team.name.eq_ignore_ascii_case(wanted)
+    })
+}
+
+async fn graphql(settings: &LinearSettings, query: &str, variables: Value) -> Result<Value, String> {
+    let response = reqwest::Client::new()
+        .post(API_URL)
+        // Personal API keys are sent as they are, without "Bearer"
+        .header("Authorization", settings.api_key.trim())
+        .json(&json!({ "query": query, "variables": variables }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Linear: {}", e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err("Linear rejected the API key".to_string());
+    }
+    let text = response.text().await.unwrap_or_default();
+    let body: Value = serde_json::from_str(&text)
+        .map_err(|_| format!("Linear returned {}: {}", status, text.trim()))?;
+    let errors: Vec<&str> = body
+        .get("errors")
+        .and_then(Value::as_array)
+        .map(|errors| errors.iter().filter_map(|error| error.get("message").and_then(Value::as_str)).collect())
+        .unwrap_or_default();
+    if !errors.is_empty() {
+        return Err(format!("Linear returned an error: {}", errors.join("; ")));
+    }
+    if !status.is_success() {
+        return Err(format!("Linear returned {}", status));
+    }
+    Ok(body.get("data").cloned().unwrap_or(Value::Null))
+}
+
+async fn teams(settings: &LinearSettings) -> Result<Vec<LinearTeam>, String> {
+    let data = graphql(settings, "query { teams(first: 250) { nodes { id key name } } }", json!({})).await?;
+    serde_json::from_value(data.pointer("/teams/nodes").cloned().unwrap_or_default())
+        .map_err(|e| format!("Invalid response from Linear: {}", e))
+}
+
+/// The Linear user with this email, when there is one
+async fn user_id(settings: &LinearSettings, email: &str) -> Result<Option<String>, String> {
+    let data = graphql(
+        settings,
+        "query($email: String!) { users(filter: { email: { eq: $email } }) { nodes { id } } }",
+        json!({ "email": email }),
+    )
+    .await?;
+    Ok(data.pointer("/users/nodes/0/id").and_then(Value::as_str).map(str::to_string))
+}
+
+async fn create_issues(
+    pool: &SqlitePool,
+    settings: &LinearSettings,
+    meeting_id: &str,
+    action_item_ids: &[String],
+) -> Result<Vec<CreatedIssue>, String> {
+    let meeting = issues::load_drafts(pool, meeting_id, action_item_ids).await?;
+    if meeting.drafts.is_empty() {
+        return Ok(Vec::new());
+    }
+    let teams = teams(settings).await?;
+    let wanted = settings.team_for(&meeting.tags);
+    let team = find_team(&teams, wanted).ok_or_else(|| format!("Linear has no team {}", wanted))?;
+
+    let mut users: HashMap<String, Option<String>> = HashMap::new();
+    let mut created = Vec::new();
+    for draft in meeting.drafts {
+        let mut input = json!({
+            "teamId": team.id,
+            "title": draft.title,
+            "description": draft.description.join("\n\n"),
+        });
+        if let Some(due_date) = &draft.due_date {
+            input["dueDate"] = json!(due_date);
+        }
+        if let Some(email) = &draft.assignee_email {
+            let user = match users.get(email) {
+                Some(user) => user.clone(),
+                None => {
+                    let user = user_id(settings, email).await?;
+                    users.insert(email.clone(), user.clone());
+                    user
+                }
+            };
+            if let Some(user) = user {
+                input["assigneeId"] = json!(user);
+            }
+        }
+
+        let data = graphql(
+            settings,
+            "mutation($input: IssueCreateInput!) { issueCreate(input: $input) { success issue { identifier url } } }",
+            json!({ "input": input }),
+        )
+        .await?;
+        let issue = data.pointer("/issueCreate/issue").ok_or("Linear did not create the issue")?;
+        let key = issue
+            .get("identifier")
+            .and_then(Value::as_str)
+            .ok_or("Linear returned an issue without an identifier")?;
+        let url = issue.get("url").and_then(Value::as_str).unwrap_or_default();
+        created.push(issues::record_issue(pool, &draft.action_item_id, key, url).await?);
+    }
+    Ok(created)
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<LinearSettings>, String> {
+    SettingsRepository::get_linear_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load Linear settings: {}", e))
+}
+
+#[command]
+pub async fn get_linear_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<LinearSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_linear_settings<R: Runtime>(app: AppHandle<R>, settings: LinearSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_linear_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save Linear settings: {}", e))
+}
+
+/// Teams the API key can create issues in, to choose from; also checks the key
+#[command]
+pub async fn list_linear_teams(api_key: String) -> Result<Vec<LinearTeam>, String> {
+    let settings = LinearSettings {
+        api_key,
+        team: String::new(),
+        team_rules: Vec::new(),
+    };
+    if settings.api_key.trim().is_empty() {
+        return Err("Enter a Linear API key".to_string());
+    }
+    teams(&settings).await
+}
+
+/// Create a Linear issue for each selected action item of a meeting that has none yet
+///
+/// # Returns
+/// The issues created; their identifiers are also stored on the action items
+#[command]
+pub async fn create_linear_issues<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    action_item_ids: Vec<String>,
+) -> Result<Vec<CreatedIssue>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Connect Linear first".to_string())?;
+    let created = create_issues(pool, &settings, &meeting_id, &action_item_ids).await?;
+    info!("Created {} Linear issues from meeting {}", created.len(), meeting_id);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_meetings_to_teams() {
+        let settings = LinearSettings {
+            api_key: "lin_api_123".to_string(),
+            team: "ENG".to_string(),
+            team_rules: vec![LinearTeamRule { tag: "Design Review".to_string(), team: "Design".to_string() }],
+        };
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.team_for(&["design review".to_string()]), "Design");
+        assert_eq!(settings.team_for(&[]), "ENG");
+
+        let teams = vec![
+            LinearTeam { id: "0a1b".to_string(), key: "ENG".to_string(), name: "Engineering".to_string() },
+            LinearTeam { id: "2c3d".to_string(), key: "DES".to_string(), name: "Design".to_string() },
+        ];
+        assert_eq!(find_team(&teams, "eng").map(|team| team.id.as_str()), Some("0a1b"));
+        assert_eq!(find_team(&teams, "Design").map(|team| team.id.as_str()), Some("2c3d"));
+        assert_eq!(find_team(&teams, "2c3d").map(|team| team.key.as_str()), Some("DES"));
+        assert!(find_team(&teams, "Sales").is_none());
+    }
+}
@@ -0,0 +1,68 @@
+// Integrations
+// Other systems told about meetings as they progress. Saving a recording, finishing a
+// transcript and finishing the minutes each raise a meeting event; the integrations set up
+// to react to it run in the background, so a slow or failing service never holds up the
+// recording flow. Protected meetings that are locked are never sent anywhere.
+
+pub mod confluence;
+pub mod crm;
+pub mod email;
+pub mod issues;
+pub mod jira;
+pub mod linear;
+pub mod notion;
+pub mod slack;
+pub mod tasks;
+pub mod webhook_template;
+pub mod webhooks;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Runtime};
+
+use crate::export::document::MeetingDocument;
+use crate::state;
+
+/// Points in a meeting's life other systems can react to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingEvent {
+    /// A recording was stopped and saved
+    MeetingFinished,
+    /// A meeting's transcript is complete: after recording, importing or a watch folder file
+    TranscriptReady,
+    /// Minutes were generated
+    SummaryReady,
+}
+
+impl MeetingEvent {
+    pub fn name(self) -> &'static str {
+        match self {
+            MeetingEvent::MeetingFinished => "meeting_finished",
+            MeetingEvent::TranscriptReady => "transcript_ready",
+            MeetingEvent::SummaryReady => "summary_ready",
+        }
+    }
+}
+
+/// A meeting as sent to other systems; fails while the meeting is locked
+pub(crate) async fn load_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<MeetingDocument, String> {
+    crate::protection::ensure_unlocked(pool, meeting_id).await?;
+    MeetingDocument::load(pool, meeting_id).await
+}
+
+/// Hand a meeting event to the integrations in the background
+pub fn spawn_meeting_event<R: Runtime>(app: &AppHandle<R>, event: MeetingEvent, meeting_id: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(pool) = state::try_pool(&app) else {
+            return;
+        };
+        webhooks::deliver_event(&pool, event, &meeting_id).await;
+        slack::handle_event(&pool, event, &meeting_id).await;
+        notion::handle_event(&pool, event, &meeting_id).await;
+        confluence::handle_event(&pool, event, &meeting_id).await;
+        tasks::handle_event(&pool, event, &meeting_id).await;
+        crm::handle_event(&pool, event, &meeting_id).await;
+    });
+}
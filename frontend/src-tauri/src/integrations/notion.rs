@@ -0,0 +1,424 @@
+// Notion
+// Creates a page per meeting in a Notion database the user shared with their internal
+// integration. The database's title property gets the meeting title; a date property and a
+// participants property (multi-select or text) are filled when the database has them under
+// the configured names. The page body holds the minutes as Notion blocks followed by a
+// table of action items. Pages are created on request, or for every meeting once its
+// minutes are ready when automatic export is on.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::{load_meeting, MeetingEvent};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::MeetingDocument;
+use crate::state::AppState;
+
+const API_URL: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+/// Longest content of one rich text object
+const MAX_TEXT_CHARS: usize = 2000;
+/// Most children sent in one request
+const MAX_CHILDREN: usize = 100;
+
+static ID_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"([0-9a-fA-F]{32})|([0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12})").unwrap());
+static INLINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\*\*(?P<bold>.+?)\*\*|\[(?P<label>[^\]]+)\]\((?P<url>[^)\s]+)\)|`(?P<code>[^`]+)`|\*(?P<italic>[^*\s][^*]*)\*").unwrap()
+});
+static HEADING_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(#{1,6})\s+(.+?)\s*#*$").unwrap());
+static TODO_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[-*+]\s+\[([ xX])\]\s+(.*)$").unwrap());
+static BULLET_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[-*+]\s+(.*)$").unwrap());
+static NUMBERED_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d+[.)]\s+(.*)$").unwrap());
+
+fn default_date_property() -> String {
+    "Date".to_string()
+}
+
+fn default_participants_property() -> String {
+    "Participants".to_string()
+}
+
+/// Notion settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotionSettings {
+    /// Internal integration secret ("ntn_…", or "secret_…" for older integrations)
+    pub token: String,
+    /// Database id, or the database's URL
+    pub database_id: String,
+    /// Date property set to the meeting start, if the database has it
+    #[serde(default = "default_date_property")]
+    pub date_property: String,
+    /// Multi-select or text property listing the speakers, if the database has it
+    #[serde(default = "default_participants_property")]
+    pub participants_property: String,
+    /// Export every meeting once its minutes are ready
+    #[serde(default)]
+    pub automatic: bool,
+}
+
+impl NotionSettings {
+    fn validate(&self) -> Result<(), String> {
+        let token = self.token.trim();
+        if !token.starts_with("ntn_") && !token.starts_with("secret_") {
+            return Err("Enter the internal integration secret from your Notion integration".to_string());
+        }
+        database_id(&self.database_id)?;
+        Ok(())
+    }
+}
+
+/// The database id in an id or a database URL ("notion.so/workspace/Name-<32 hex>?v=…")
+fn database_id(input: &str) -> Result<String, String> {
+    // The view id after "?v=" is also 32 hex digits, so only the path is searched
+    let path = input.trim().split(['?', '#']).next().unwrap_or_default();
+    ID_REGEX
+        .find_iter(path)
+        .last()
+        .map(|id| id.as_str().replace('-', "").to_lowercase())
+        .ok_or_else(|| "Enter the Notion database id or the database's link".to_string())
+}
+
+fn text(content: &str, annotations: Option<Value>, link: Option<&str>) -> Vec<Value> {
+    let chars: Vec<char> = content.chars().collect();
+    chars
+        .chunks(MAX_TEXT_CHARS)
+        .map(|chunk| {
+            let mut item = json!({
+                "type": "text",
+                "text": { "content": chunk.iter().collect::<String>(), "link": link.map(|url| json!({ "url": url })) },
+            });
+            if let Some(annotations) = &annotations {
+                item["annotations"] = annotations.clone();
+            }
+            item
+        })
+        .collect()
+}
+
+/// Rich text for a line of Markdown: bold, italic, inline code and links
+fn rich_text(line: &str) -> Vec<Value> {
+    let mut items = Vec::new();
+    let mut last = 0;
+    for captures in INLINE_REGEX.captures_iter(line) {
+        let matched = captures.get(0).unwrap();
+        if matched.start() > last {
+            items.extend(text(&line[last..matched.start()], None, None));
+        }
+        if let Some(bold) = captures.name("bold") {
+            items.extend(text(bold.as_str(), Some(json!({ "bold": true })), None));
+        } else if let (Some(label), Some(url)) = (captures.name("label"), captures.name("url")) {
+            // Notion rejects links that are not absolute URLs
+            let url = url.as_str();
+            let link = (url.starts_with("http://") || url.starts_with("https://")).then_some(url);
+            items.extend(text(label.as_str(), None, link));
+        } else if let Some(code) = captures.name("code") {
+            items.extend(text(code.as_str(), Some(json!({ "code": true })), None));
+        } else if let Some(italic) = captures.name("italic") {
+            items.extend(text(italic.as_str(), Some(json!({ "italic": true })), None));
+        }
+        last = matched.end();
+    }
+    if last < line.len() {
+        items.extend(text(&line[last..], None, None));
+    }
+    items
+}
+
+fn block(kind: &str, content: Value) -> Value {
+    let mut block = Map::new();
+    block.insert("object".to_string(), json!("block"));
+    block.insert("type".to_string(), json!(kind));
+    block.insert(kind.to_string(), content);
+    Value::Object(block)
+}
+
+/// Notion blocks for the minutes' Markdown, one per line; nesting is flattened
+fn markdown_blocks(markdown: &str) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    for line in markdown.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(captures) = HEADING_REGEX.captures(line) {
+            let kind = match captures[1].len() {
+                1 => "heading_1",
+                2 => "heading_2",
+                _ => "heading_3",
+            };
+            blocks.push(block(kind, json!({ "rich_text": rich_text(&captures[2]) })));
+        } else if let Some(captures) = TODO_REGEX.captures(line) {
+            blocks.push(block(
+                "to_do",
+                json!({ "rich_text": rich_text(&captures[2]), "checked": &captures[1] != " " }),
+            ));
+        } else if let Some(captures) = BULLET_REGEX.captures(line) {
+            blocks.push(block("bulleted_list_item", json!({ "rich_text": rich_text(&captures[1]) })));
+        } else if let Some(captures) = NUMBERED_REGEX.captures(line) {
+            blocks.push(block("numbered_list_item", json!({ "rich_text": rich_text(&captures[1]) })));
+        } else if let Some(quote) = line.strip_prefix('>') {
+            blocks.push(block("quote", json!({ "rich_text": rich_text(quote.trim_start()) })));
+        } else if line.chars().all(|c| c == '-' || c == '*' || c == '_') && line.len() >= 3 {
+            blocks.push(block("divider", json!({})));
+        } else {
+            blocks.push(block("paragraph", json!({ "rich_text": rich_text(line) })));
+        }
+    }
+    blocks
+}
+
+/// Table of action items: description, assignee, due date and whether it is done
+fn action_item_table(document: &MeetingDocument) -> Value {
+    let row = |cells: [&str; 4]| {
+        let cells: Vec<Vec<Value>> = cells.iter().map(|cell| text(cell, None, None)).collect();
+        block("table_row", json!({ "cells": cells }))
+    };
+    let mut rows = vec![row(["Action item", "Assignee", "Due", "Done"])];
+    rows.extend(document.action_items.iter().take(MAX_CHILDREN - 1).map(|item| {
+        row([
+            &item.description,
+            item.assignee.as_deref().unwrap_or(""),
+            item.due_date.as_deref().unwrap_or(""),
+            if item.done { "✓" } else { "" },
+        ])
+    }));
+    block(
+        "table",
+        json!({ "table_width": 4, "has_column_header": true, "has_row_header": false, "children": rows }),
+    )
+}
+
+/// Page body: minutes, then action items
+fn page_blocks(document: &MeetingDocument) -> Vec<Value> {
+    let mut blocks = match &document.summary {
+        Some(summary) => markdown_blocks(summary),
+        None => vec![block("paragraph", json!({ "rich_text": text("No minutes yet", Some(json!({ "italic": true })), None) }))],
+    };
+    if !document.action_items.is_empty() {
+        blocks.push(block("heading_2", json!({ "rich_text": text("Action items", None, None) })));
+        blocks.push(action_item_table(document));
+    }
+    blocks
+}
+
+/// Page properties for the database's schema; properties it lacks are left out
+fn page_properties(document: &MeetingDocument, schema: &Map<String, Value>, settings: &NotionSettings) -> Result<Value, String> {
+    let kind_of = |name: &str| schema.get(name).and_then(|property| property.get("type")).and_then(Value::as_str);
+    let title_property = schema
+        .iter()
+        .find(|(_, property)| property.get("type").and_then(Value::as_str) == Some("title"))
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| "The Notion database has no title property".to_string())?;
+
+    let mut properties = Map::new();
+    properties.insert(title_property, json!({ "title": text(&document.title, None, None) }));
+    if kind_of(&settings.date_property) == Some("date") {
+        properties.insert(
+            settings.date_property.clone(),
+            json!({ "date": { "start": document.created_at.to_rfc3339() } }),
+        );
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        match kind_of(&settings.participants_property) {
+            // Option names cannot contain commas
+            Some("multi_select") => {
+                let options: Vec<Value> = participants.iter().map(|name| json!({ "name": name.replace(',', " ") })).collect();
+                properties.insert(settings.participants_property.clone(), json!({ "multi_select": options }));
+            }
+            Some("rich_text") => {
+                properties.insert(
+                    settings.participants_property.clone(),
+                    json!({ "rich_text": text(&participants.join(", "), None, None) }),
+                );
+            }
+            _ => {}
+        }
+    }
+    Ok(Value::Object(properties))
+}
+
+async fn call_api(token: &str, method: reqwest::Method, path: &str, body: Option<&Value>) -> Result<Value, String> {
+    let mut request = reqwest::Client::new()
+        .request(method, format!("{}/{}", API_URL, path))
+        .bearer_auth(token.trim())
+        .header("Notion-Version", NOTION_VERSION);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await.map_err(|e| format!("Failed to reach Notion: {}", e))?;
+    let status = response.status();
+    let body: Value = response.json().await.map_err(|e| format!("Invalid response from Notion: {}", e))?;
+    if !status.is_success() {
+        let message = body.get("message").and_then(Value::as_str).unwrap_or("unknown error");
+        return Err(format!("Notion returned {}: {}", status, message));
+    }
+    Ok(body)
+}
+
+/// The database's properties by name, plus its title
+async fn fetch_database(settings: &NotionSettings) -> Result<(String, Map<String, Value>), String> {
+    let database = call_api(&settings.token, reqwest::Method::GET, &format!("databases/{}", database_id(&settings.database_id)?), None).await?;
+    let title = database
+        .get("title")
+        .and_then(Value::as_array)
+        .map(|parts| parts.iter().filter_map(|part| part.get("plain_text").and_then(Value::as_str)).collect::<String>())
+        .unwrap_or_default();
+    let schema = database.get("properties").and_then(Value::as_object).cloned().unwrap_or_default();
+    Ok((title, schema))
+}
+
+/// Create the meeting's page and return its URL
+async fn export_meeting(pool: &SqlitePool, settings: &NotionSettings, meeting_id: &str) -> Result<String, String> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let (_, schema) = fetch_database(settings).await?;
+    let properties = page_properties(&document, &schema, settings)?;
+    let mut blocks = page_blocks(&document);
+    let rest = blocks.split_off(blocks.len().min(MAX_CHILDREN));
+
+    let page = call_api(
+        &settings.token,
+        reqwest::Method::POST,
+        "pages",
+        Some(&json!({
+            "parent": { "database_id": database_id(&settings.database_id)? },
+            "properties": properties,
+            "children": blocks,
+        })),
+    )
+    .await?;
+    let page_id = page.get("id").and_then(Value::as_str).ok_or("Notion did not return the new page")?;
+
+    // Long minutes do not fit in the creating request
+    for chunk in rest.chunks(MAX_CHILDREN) {
+        call_api(
+            &settings.token,
+            reqwest::Method::PATCH,
+            &format!("blocks/{}/children", page_id),
+            Some(&json!({ "children": chunk })),
+        )
+        .await?;
+    }
+    Ok(page.get("url").and_then(Value::as_str).unwrap_or_default().to_string())
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<NotionSettings>, String> {
+    SettingsRepository::get_notion_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load Notion settings: {}", e))
+}
+
+/// Export a meeting whose minutes are ready when automatic export is on
+pub async fn handle_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    if event != MeetingEvent::SummaryReady {
+        return;
+    }
+    let settings = match load_settings(pool).await {
+        Ok(Some(settings)) if settings.automatic => settings,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    match export_meeting(pool, &settings, meeting_id).await {
+        Ok(url) => info!("Exported meeting {} to Notion: {}", meeting_id, url),
+        Err(e) => warn!("Failed to export meeting {} to Notion: {}", meeting_id, e),
+    }
+}
+
+#[command]
+pub async fn get_notion_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<NotionSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_notion_settings<R: Runtime>(app: AppHandle<R>, mut settings: NotionSettings) -> Result<(), String> {
+    settings.validate()?;
+    settings.database_id = database_id(&settings.database_id)?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_notion_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save Notion settings: {}", e))
+}
+
+/// Check that the integration can see the database; returns the database's title
+#[command]
+pub async fn test_notion_connection(settings: NotionSettings) -> Result<String, String> {
+    settings.validate()?;
+    let (title, _) = fetch_database(&settings).await?;
+    Ok(title)
+}
+
+/// Create a Notion page for a meeting; returns the page's URL
+#[command]
+pub async fn export_meeting_to_notion<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<String, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Connect Notion first".to_string())?;
+    let url = export_meeting(pool, &settings, &meeting_id).await?;
+    info!("Exported meeting {} to Notion", meeting_id);
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn converts_markdown_to_blocks() {
+        assert_eq!(
+            database_id("https://www.notion.so/acme/Meetings-0123456789abcdef0123456789ABCDEF?v=fedcba9876543210fedcba9876543210").unwrap(),
+            "0123456789abcdef0123456789abcdef"
+        );
+        assert!(database_id("https://www.notion.so/acme").is_err());
+
+        let blocks = markdown_blocks("# Minutes\n\n- Ship **v2** by [Friday](https://example.com)\n- [x] Budget\n1. First\n---\nPlain `code`");
+        let kinds: Vec<&str> = blocks.iter().filter_map(|block| block["type"].as_str()).collect();
+        assert_eq!(kinds, ["heading_1", "bulleted_list_item", "to_do", "numbered_list_item", "divider", "paragraph"]);
+
+        let bullet = blocks[1]["bulleted_list_item"]["rich_text"].as_array().unwrap();
+        let contents: Vec<&str> = bullet.iter().filter_map(|item| item["text"]["content"].as_str()).collect();
+        assert_eq!(contents, ["Ship ", "v2", " by ", "Friday"]);
+        assert_eq!(bullet[1]["annotations"]["bold"], true);
+        assert_eq!(bullet[3]["text"]["link"]["url"], "https://example.com");
+        assert_eq!(blocks[2]["to_do"]["checked"], true);
+        assert_eq!(blocks[5]["paragraph"]["rich_text"][1]["annotations"]["code"], true);
+
+        // Rich text content is capped at 2000 characters per object
+        assert_eq!(rich_text(&"a".repeat(4500)).len(), 3);
+    }
+
+    #[test]
+    fn maps_properties_to_the_database() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Sprint review".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: None,
+            action_items: Vec::new(),
+            segments: Vec::new(),
+        };
+        let settings = NotionSettings {
+            token: "ntn_abc".to_string(),
+            database_id: "0123456789abcdef0123456789abcdef".to_string(),
+            date_property: default_date_property(),
+            participants_property: default_participants_property(),
+            automatic: false,
+        };
+        let schema: Value = json!({ "Name": { "type": "title" }, "Date": { "type": "date" }, "Tags": { "type": "multi_select" } });
+        let properties = page_properties(&document, schema.as_object().unwrap(), &settings).unwrap();
+        assert_eq!(properties["Name"]["title"][0]["text"]["content"], "Sprint review");
+        assert_eq!(properties["Date"]["date"]["start"], "2026-02-10T09:00:00+00:00");
+        assert!(properties.get("Participants").is_none());
+
+        let schema: Value = json!({ "Date": { "type": "date" } });
+        assert!(page_properties(&document, schema.as_object().unwrap(), &settings).is_err());
+    }
+}
@@ -0,0 +1,418 @@
+// Slack
+// Posts a meeting's minutes and action items to Slack, either through a bot (a "xoxb-"
+// token from a Slack app with chat:write, and channels:read to list channels) which can
+// post to any channel it was invited to, or through an incoming webhook bound to one
+// channel. Meetings are posted on request, and automatically once their minutes are ready
+// when they carry a tag with an auto-post rule. The minutes' Markdown is rewritten in
+// Slack's own markup.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::{load_meeting, MeetingEvent};
+use crate::database::repositories::meeting_tag::{normalize_tag, MeetingTagsRepository};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::{format_duration, MeetingDocument};
+use crate::state::AppState;
+
+const API_URL: &str = "https://slack.com/api";
+/// Longest text of a section block
+const MAX_SECTION_CHARS: usize = 3000;
+/// Most blocks in one message
+const MAX_BLOCKS: usize = 50;
+const MAX_HEADER_CHARS: usize = 150;
+/// Pages of channels listed at most
+const MAX_CHANNEL_PAGES: usize = 10;
+
+static HEADING_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^#{1,6}\s+(.+?)\s*#*$").unwrap());
+static BULLET_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^(\s*)[-*+]\s+").unwrap());
+static CHECKBOX_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)^(\s*)• \[([ xX])\]\s+").unwrap());
+static BOLD_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\*\*(.+?)\*\*|__(.+?)__").unwrap());
+static LINK_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[([^\]]+)\]\(([^)\s]+)\)").unwrap());
+
+/// How messages reach Slack
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SlackConnection {
+    /// Bot user OAuth token ("xoxb-…")
+    Bot { token: String },
+    /// Incoming webhook URL; always posts to the channel it was created for
+    Webhook { url: String },
+}
+
+/// Post meetings with this tag as soon as their minutes are ready
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlackAutoPost {
+    pub tag: String,
+    /// Channel id or name; None uses the default channel
+    #[serde(default)]
+    pub channel: Option<String>,
+}
+
+fn default_include_action_items() -> bool {
+    true
+}
+
+/// Slack settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlackSettings {
+    pub connection: SlackConnection,
+    /// Channel id or name used when none is chosen (bots only)
+    #[serde(default)]
+    pub default_channel: Option<String>,
+    #[serde(default = "default_include_action_items")]
+    pub include_action_items: bool,
+    #[serde(default)]
+    pub auto_post: Vec<SlackAutoPost>,
+}
+
+impl SlackSettings {
+    fn validate(&self) -> Result<(), String> {
+        match &self.connection {
+            SlackConnection::Bot { token } => {
+                if !token.trim().starts_with("xoxb-") {
+                    return Err("Enter the bot token of your Slack app; it starts with xoxb-".to_string());
+                }
+            }
+            SlackConnection::Webhook { url } => {
+                if !url.trim().starts_with("https://hooks.slack.com/") {
+                    return Err("Enter an incoming webhook URL; it starts with https://hooks.slack.com/".to_string());
+                }
+            }
+        }
+        if self.auto_post.iter().any(|rule| normalize_tag(&rule.tag).is_empty()) {
+            return Err("Choose a tag for each auto-post rule".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Slack's mrkdwn for the minutes' Markdown: bold headings, bullets, <url|text> links
+fn to_mrkdwn(markdown: &str) -> String {
+    // Slack wants these three escaped; links are added after
+    let text = markdown.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    let text = BULLET_REGEX.replace_all(&text, "$1• ");
+    let text = CHECKBOX_REGEX.replace_all(&text, |captures: &regex::Captures| {
+        let mark = if &captures[2] == " " { "☐" } else { "☑" };
+        format!("{}{} ", &captures[1], mark)
+    });
+    let text = HEADING_REGEX.replace_all(&text, "*$1*");
+    let text = BOLD_REGEX.replace_all(&text, |captures: &regex::Captures| {
+        format!("*{}*", captures.get(1).or_else(|| captures.get(2)).map_or("", |m| m.as_str()))
+    });
+    LINK_REGEX.replace_all(&text, "<$2|$1>").into_owned()
+}
+
+/// Split text into pieces of at most `limit` characters, at line breaks where possible
+fn split_text(text: &str, limit: usize) -> Vec<String> {
+    let mut pieces: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for line in text.lines() {
+        let mut line: String = line.to_string();
+        // A single line longer than the limit is cut
+        while line.chars().count() > limit {
+            let head: String = line.chars().take(limit).collect();
+            line = line.chars().skip(limit).collect();
+            if !current.is_empty() {
+                pieces.push(std::mem::take(&mut current));
+            }
+            pieces.push(head);
+        }
+        if current.chars().count() + line.chars().count() + 1 > limit {
+            pieces.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
+    }
+    if !current.trim().is_empty() {
+        pieces.push(current);
+    }
+    pieces.retain(|piece| !piece.trim().is_empty());
+    pieces
+}
+
+fn section(text: &str) -> Value {
+    json!({ "type": "section", "text": { "type": "mrkdwn", "text": text } })
+}
+
+/// Message blocks for a meeting: title, date and participants, minutes, action items
+fn meeting_blocks(document: &MeetingDocument, include_action_items: bool) -> Vec<Value> {
+    let title: String = document.title.chars().take(MAX_HEADER_CHARS).collect();
+    let mut blocks = vec![json!({ "type": "header", "text": { "type": "plain_text", "text": title } })];
+
+    let mut context = vec![document.local_date()];
+    if let Some(duration) = document.duration_seconds() {
+        context.push(format_duration(duration));
+    }
+    let participants = document.participants();
+    if !participants.is_empty() {
+        context.push(participants.join(", "));
+    }
+    blocks.push(json!({
+        "type": "context",
+        "elements": [{ "type": "mrkdwn", "text": to_mrkdwn(&context.join(" · ")) }]
+    }));
+
+    match &document.summary {
+        Some(summary) => blocks.extend(split_text(&to_mrkdwn(summary), MAX_SECTION_CHARS).iter().map(|text| section(text))),
+        None => blocks.push(section("_No minutes yet_")),
+    }
+
+    if include_action_items && !document.action_items.is_empty() {
+        let items: Vec<String> = document
+            .action_items
+            .iter()
+            .map(|item| {
+                let mut line = format!("{} {}", if item.done { "☑" } else { "☐" }, item.description);
+                if let Some(assignee) = &item.assignee {
+                    line.push_str(&format!(" — {}", assignee));
+                }
+                if let Some(due_date) = &item.due_date {
+                    line.push_str(&format!(" (due {})", due_date));
+                }
+                line
+            })
+            .collect();
+        blocks.push(json!({ "type": "divider" }));
+        let text = format!("*Action items*\n{}", to_mrkdwn(&items.join("\n")));
+        blocks.extend(split_text(&text, MAX_SECTION_CHARS).iter().map(|text| section(text)));
+    }
+
+    if blocks.len() > MAX_BLOCKS {
+        blocks.truncate(MAX_BLOCKS - 1);
+        blocks.push(section("_Truncated; open the meeting in Meetily for the rest_"));
+    }
+    blocks
+}
+
+async fn call_api(token: &str, method: &str, body: &Value) -> Result<Value, String> {
+    let response = reqwest::Client::new()
+        .post(format!("{}/{}", API_URL, method))
+        .bearer_auth(token.trim())
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Slack: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("Slack returned {}", response.status()));
+    }
+    let body: Value = response.json().await.map_err(|e| format!("Invalid response from Slack: {}", e))?;
+    // Slack answers 200 with "ok": false on errors
+    if body.get("ok").and_then(Value::as_bool) != Some(true) {
+        let error = body.get("error").and_then(Value::as_str).unwrap_or("unknown error");
+        return Err(format!("Slack refused the request: {}", error));
+    }
+    Ok(body)
+}
+
+async fn post_message(settings: &SlackSettings, channel: Option<&str>, text: &str, blocks: Vec<Value>) -> Result<(), String> {
+    match &settings.connection {
+        SlackConnection::Bot { token } => {
+            let channel = channel
+                .or(settings.default_channel.as_deref())
+                .map(|channel| channel.trim().trim_start_matches('#'))
+                .filter(|channel| !channel.is_empty())
+                .ok_or_else(|| "Choose a Slack channel to post to".to_string())?;
+            call_api(token, "chat.postMessage", &json!({ "channel": channel, "text": text, "blocks": blocks })).await?;
+        }
+        SlackConnection::Webhook { url } => {
+            let response = reqwest::Client::new()
+                .post(url.trim())
+                .json(&json!({ "text": text, "blocks": blocks }))
+                .send()
+                .await
+                .map_err(|e| format!("Failed to reach Slack: {}", e))?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(format!("Slack returned {}: {}", status, body.trim()));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn post_meeting(pool: &SqlitePool, settings: &SlackSettings, meeting_id: &str, channel: Option<&str>) -> Result<(), String> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let blocks = meeting_blocks(&document, settings.include_action_items);
+    post_message(settings, channel, &format!("Minutes: {}", document.title), blocks).await
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<SlackSettings>, String> {
+    SettingsRepository::get_slack_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load Slack settings: {}", e))
+}
+
+/// Post a meeting whose minutes are ready to the channels of its tags' auto-post rules
+pub async fn handle_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    if event != MeetingEvent::SummaryReady {
+        return;
+    }
+    let settings = match load_settings(pool).await {
+        Ok(Some(settings)) if !settings.auto_post.is_empty() => settings,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    let tags = match MeetingTagsRepository::list_for_meeting(pool, meeting_id).await {
+        Ok(tags) => tags,
+        Err(e) => {
+            warn!("Failed to load tags of meeting {}: {}", meeting_id, e);
+            return;
+        }
+    };
+    // One post per channel, even when several of the meeting's tags lead there
+    let mut channels: Vec<Option<&str>> = Vec::new();
+    for rule in settings.auto_post.iter().filter(|rule| tags.contains(&normalize_tag(&rule.tag))) {
+        let channel = rule.channel.as_deref().filter(|channel| !channel.trim().is_empty());
+        if !channels.contains(&channel) {
+            channels.push(channel);
+        }
+    }
+    for channel in channels {
+        match post_meeting(pool, &settings, meeting_id, channel).await {
+            Ok(()) => info!("Posted meeting {} to Slack", meeting_id),
+            Err(e) => warn!("Failed to post meeting {} to Slack: {}", meeting_id, e),
+        }
+    }
+}
+
+#[command]
+pub async fn get_slack_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<SlackSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_slack_settings<R: Runtime>(app: AppHandle<R>, settings: SlackSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_slack_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save Slack settings: {}", e))
+}
+
+#[derive(Debug, Serialize)]
+pub struct SlackChannel {
+    pub id: String,
+    pub name: String,
+    pub is_private: bool,
+}
+
+/// Channels the bot can see, by name; webhooks have none to choose from
+#[command]
+pub async fn list_slack_channels<R: Runtime>(app: AppHandle<R>) -> Result<Vec<SlackChannel>, String> {
+    let state = app.state::<AppState>();
+    let settings = load_settings(state.db_manager.pool())
+        .await?
+        .ok_or_else(|| "Connect Slack first".to_string())?;
+    let SlackConnection::Bot { token } = &settings.connection else {
+        return Ok(Vec::new());
+    };
+    let mut channels = Vec::new();
+    let mut cursor = String::new();
+    for _ in 0..MAX_CHANNEL_PAGES {
+        let page = call_api(
+            token,
+            "conversations.list",
+            &json!({ "types": "public_channel,private_channel", "exclude_archived": true, "limit": 200, "cursor": cursor }),
+        )
+        .await?;
+        if let Some(items) = page.get("channels").and_then(Value::as_array) {
+            channels.extend(items.iter().filter_map(|channel| {
+                Some(SlackChannel {
+                    id: channel.get("id")?.as_str()?.to_string(),
+                    name: channel.get("name")?.as_str()?.to_string(),
+                    is_private: channel.get("is_private").and_then(Value::as_bool).unwrap_or(false),
+                })
+            }));
+        }
+        cursor = page
+            .pointer("/response_metadata/next_cursor")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        if cursor.is_empty() {
+            break;
+        }
+    }
+    channels.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(channels)
+}
+
+/// Post a meeting's minutes and action items to Slack
+///
+/// # Arguments
+/// * `channel` - Channel id or name; None uses the default channel
+#[command]
+pub async fn post_meeting_to_slack<R: Runtime>(app: AppHandle<R>, meeting_id: String, channel: Option<String>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?.ok_or_else(|| "Connect Slack first".to_string())?;
+    post_meeting(pool, &settings, &meeting_id, channel.as_deref()).await?;
+    info!("Posted meeting {} to Slack", meeting_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::ActionItem;
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn converts_markdown_to_mrkdwn() {
+        let markdown = "## Decisions\n- Ship **v2** on Friday\n- [x] Budget <approved> & signed\n\
+            * See [the plan](https://example.com/plan?a=1&b=2)\n### Next steps ###";
+        assert_eq!(
+            to_mrkdwn(markdown),
+            "*Decisions*\n• Ship *v2* on Friday\n☑ Budget &lt;approved&gt; &amp; signed\n\
+             • See <https://example.com/plan?a=1&amp;b=2|the plan>\n*Next steps*"
+        );
+
+        let pieces = split_text(&format!("{}\n{}\n{}", "a".repeat(6), "b".repeat(3), "c".repeat(12)), 10);
+        assert_eq!(pieces, ["aaaaaa\nbbb", "cccccccccc", "cc"]);
+    }
+
+    #[test]
+    fn builds_meeting_blocks() {
+        let document = MeetingDocument {
+            id: "meeting-1".to_string(),
+            title: "Sprint review".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+            description: None,
+            agenda: None,
+            folder_path: None,
+            summary: Some("## Summary\nAll good".to_string()),
+            action_items: vec![ActionItem {
+                id: "item-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Send notes".to_string(),
+                assignee: Some("Dana".to_string()),
+                due_date: Some("2026-02-12".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at: Utc.with_ymd_and_hms(2026, 2, 10, 9, 0, 0).unwrap(),
+            }],
+            segments: Vec::new(),
+        };
+        let blocks = meeting_blocks(&document, true);
+        let types: Vec<&str> = blocks.iter().filter_map(|block| block["type"].as_str()).collect();
+        assert_eq!(types, ["header", "context", "section", "divider", "section"]);
+        assert_eq!(blocks[2]["text"]["text"], "*Summary*\nAll good");
+        assert_eq!(blocks[4]["text"]["text"], "*Action items*\n☐ Send notes — Dana (due 2026-02-12)");
+        assert_eq!(meeting_blocks(&document, false).len(), 3);
+    }
+}
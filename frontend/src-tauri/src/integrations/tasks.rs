@@ -0,0 +1,444 @@
+// Task managers
+// Sends the open action items assigned to the user to Todoist or Asana as tasks with their due
+// dates, on request or for every meeting once its minutes are ready. Which items are the
+// user's comes from the names and emails they go by in meetings. Once sent, an item is
+// remembered by its text, so syncing a meeting again only adds items that are new, even
+// after the action items were extracted anew.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use super::issues::issue_title;
+use super::{load_meeting, MeetingEvent};
+use crate::database::models::ActionItem;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::repositories::synced_task::SyncedTasksRepository;
+use crate::state::AppState;
+
+const TODOIST_API: &str = "https://api.todoist.com/api/v1";
+const ASANA_API: &str = "https://app.asana.com/api/1.0";
+/// Assignees that stand for whoever took the notes
+const SELF_REFERENCES: [&str; 3] = ["me", "myself", "i"];
+
+/// The task manager and where new tasks go
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TaskProvider {
+    Todoist {
+        /// API token from Todoist's integration settings
+        token: String,
+        /// None puts tasks in the inbox
+        #[serde(default)]
+        project_id: Option<String>,
+    },
+    Asana {
+        /// Personal access token
+        token: String,
+        workspace_gid: String,
+        /// None keeps tasks in My Tasks only
+        #[serde(default)]
+        project_gid: Option<String>,
+    },
+}
+
+impl TaskProvider {
+    /// Key synced items are remembered under
+    fn id(&self) -> &'static str {
+        match self {
+            TaskProvider::Todoist { .. } => "todoist",
+            TaskProvider::Asana { .. } => "asana",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            TaskProvider::Todoist { .. } => "Todoist",
+            TaskProvider::Asana { .. } => "Asana",
+        }
+    }
+
+    fn token(&self) -> &str {
+        match self {
+            TaskProvider::Todoist { token, .. } | TaskProvider::Asana { token, .. } => token.trim(),
+        }
+    }
+}
+
+/// Task sync settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskSyncSettings {
+    pub provider: TaskProvider,
+    /// Names and emails the user goes by; items assigned to one of them are synced
+    pub my_names: Vec<String>,
+    /// Sync every meeting once its minutes are ready
+    #[serde(default)]
+    pub automatic: bool,
+}
+
+impl TaskSyncSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.provider.token().is_empty() {
+            return Err(format!("Enter a {} token", self.provider.label()));
+        }
+        if let TaskProvider::Asana { workspace_gid, .. } = &self.provider {
+            if workspace_gid.trim().is_empty() {
+                return Err("Choose the Asana workspace to create tasks in".to_string());
+            }
+        }
+        if self.my_names.iter().all(|name| name.trim().is_empty()) {
+            return Err("Enter the name you are assigned action items by".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A project or workspace to choose from
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskProject {
+    pub id: String,
+    pub name: String,
+}
+
+/// Outcome of syncing one meeting
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TaskSyncReport {
+    /// Tasks created now
+    pub created: usize,
+    /// The user's items that were already sent before
+    pub already_synced: usize,
+}
+
+/// The assignee is the user: "me", one of their names or emails, or the first name of one
+fn is_mine(assignee: &str, my_names: &[String]) -> bool {
+    let assignee = assignee.trim();
+    if assignee.is_empty() {
+        return false;
+    }
+    SELF_REFERENCES.iter().any(|word| assignee.eq_ignore_ascii_case(word))
+        || my_names.iter().map(|name| name.trim()).any(|name| {
+            name.eq_ignore_ascii_case(assignee)
+                || (!name.contains('@')
+                    && name
+                        .split_whitespace()
+                        .next()
+                        .is_some_and(|first| first.eq_ignore_ascii_case(assignee)))
+        })
+}
+
+/// Identifies an action item across extractions: its text, lowercased with spaces collapsed
+fn item_key(description: &str) -> String {
+    description.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Request body creating the task for an action item
+fn task_body(provider: &TaskProvider, item: &ActionItem, meeting_title: &str, meeting_date: &str) -> Value {
+    let title = issue_title(&item.description);
+    let notes = format!("From the meeting \"{}\" on {}.", meeting_title.trim(), meeting_date);
+    match provider {
+        TaskProvider::Todoist { project_id, .. } => {
+            let mut body = json!({ "content": title, "description": notes });
+            if let Some(due_date) = &item.due_date {
+                body["due_date"] = json!(due_date);
+            }
+            if let Some(project_id) = project_id.as_deref().filter(|id| !id.trim().is_empty()) {
+                body["project_id"] = json!(project_id.trim());
+            }
+            body
+        }
+        TaskProvider::Asana { workspace_gid, project_gid, .. } => {
+            let mut data = json!({
+                "name": title,
+                "notes": notes,
+                "workspace": workspace_gid.trim(),
+                "assignee": "me",
+            });
+            if let Some(due_date) = &item.due_date {
+                data["due_on"] = json!(due_date);
+            }
+            if let Some(project_gid) = project_gid.as_deref().filter(|gid| !gid.trim().is_empty()) {
+                data["projects"] = json!([project_gid.trim()]);
+            }
+            json!({ "data": data })
+        }
+    }
+}
+
+/// Asana's reasons from an error response
+fn asana_error(body: &Value) -> Option<String> {
+    let messages: Vec<&str> = body
+        .get("errors")?
+        .as_array()?
+        .iter()
+        .filter_map(|error| error.get("message").and_then(Value::as_str))
+        .collect();
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Projects and workspaces as listed by either API
+fn projects(items: &Value) -> Vec<TaskProject> {
+    items
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| {
+                    let id = item.get("id").or_else(|| item.get("gid")).and_then(Value::as_str)?;
+                    let name = item.get("name").and_then(Value::as_str).unwrap_or_default();
+                    Some(TaskProject { id: id.to_string(), name: name.to_string() })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn call_api(
+    provider: &TaskProvider,
+    method: reqwest::Method,
+    url: &str,
+    query: &[(&str, &str)],
+    body: Option<&Value>,
+) -> Result<Value, String> {
+    let label = provider.label();
+    let mut request = reqwest::Client::new()
+        .request(method, url)
+        .query(query)
+        .bearer_auth(provider.token());
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await.map_err(|e| format!("Failed to reach {}: {}", label, e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(format!("{} rejected the token", label));
+    }
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        // Todoist answers errors in plain text
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|body| asana_error(&body))
+            .unwrap_or(text);
+        return Err(format!("{} returned {}: {}", label, status, message.trim()));
+    }
+    serde_json::from_str(&text).map_err(|e| format!("Invalid response from {}: {}", label, e))
+}
+
+/// Create the task; returns its id and link
+async fn create_task(provider: &TaskProvider, body: &Value) -> Result<(String, Option<String>), String> {
+    match provider {
+        TaskProvider::Todoist { .. } => {
+            let url = format!("{}/tasks", TODOIST_API);
+            let task = call_api(provider, reqwest::Method::POST, &url, &[], Some(body)).await?;
+            let id = task.get("id").and_then(Value::as_str).ok_or("Todoist returned a task without an id")?;
+            Ok((id.to_string(), Some(format!("https://app.todoist.com/app/task/{}", id))))
+        }
+        TaskProvider::Asana { .. } => {
+            let url = format!("{}/tasks", ASANA_API);
+            let query = [("opt_fields", "permalink_url")];
+            let task = call_api(provider, reqwest::Method::POST, &url, &query, Some(body)).await?;
+            let id = task.pointer("/data/gid").and_then(Value::as_str).ok_or("Asana returned a task without an id")?;
+            let link = task.pointer("/data/permalink_url").and_then(Value::as_str).map(str::to_string);
+            Ok((id.to_string(), link))
+        }
+    }
+}
+
+async fn sync_meeting(pool: &SqlitePool, settings: &TaskSyncSettings, meeting_id: &str) -> Result<TaskSyncReport, String> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let provider = &settings.provider;
+    let mut synced: HashSet<String> = SyncedTasksRepository::list_keys(pool, provider.id(), meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load synced tasks: {}", e))?
+        .into_iter()
+        .collect();
+
+    let date = document.local_date();
+    let mut report = TaskSyncReport::default();
+    let mine = document.action_items.iter().filter(|item| {
+        !item.done && item.assignee.as_deref().is_some_and(|assignee| is_mine(assignee, &settings.my_names))
+    });
+    for item in mine {
+        let key = item_key(&item.description);
+        if key.is_empty() {
+            continue;
+        }
+        if synced.contains(&key) {
+            report.already_synced += 1;
+            continue;
+        }
+        let body = task_body(provider, item, &document.title, &date);
+        let (task_id, task_url) = create_task(provider, &body).await?;
+        SyncedTasksRepository::insert(pool, provider.id(), meeting_id, &key, &task_id, task_url.as_deref())
+            .await
+            .map_err(|e| format!("Failed to remember {} task {}: {}", provider.label(), task_id, e))?;
+        synced.insert(key);
+        report.created += 1;
+    }
+    Ok(report)
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<TaskSyncSettings>, String> {
+    SettingsRepository::get_task_sync_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load task sync settings: {}", e))
+}
+
+/// Sync the meeting's action items once its minutes are ready, when automatic sync is on
+pub async fn handle_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    if event != MeetingEvent::SummaryReady {
+        return;
+    }
+    let settings = match load_settings(pool).await {
+        Ok(Some(settings)) if settings.automatic => settings,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    let label = settings.provider.label();
+    match sync_meeting(pool, &settings, meeting_id).await {
+        Ok(report) => info!("Created {} {} tasks from meeting {}", report.created, label, meeting_id),
+        Err(e) => warn!("Failed to sync meeting {} to {}: {}", meeting_id, label, e),
+    }
+}
+
+#[command]
+pub async fn get_task_sync_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<TaskSyncSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_task_sync_settings<R: Runtime>(app: AppHandle<R>, settings: TaskSyncSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_task_sync_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save task sync settings: {}", e))
+}
+
+/// Asana workspaces the token can see, to choose from; also checks the token
+#[command]
+pub async fn list_asana_workspaces(token: String) -> Result<Vec<TaskProject>, String> {
+    let provider = TaskProvider::Asana {
+        token,
+        workspace_gid: String::new(),
+        project_gid: None,
+    };
+    if provider.token().is_empty() {
+        return Err("Enter an Asana token".to_string());
+    }
+    let url = format!("{}/workspaces", ASANA_API);
+    let workspaces = call_api(&provider, reqwest::Method::GET, &url, &[], None).await?;
+    Ok(projects(&workspaces["data"]))
+}
+
+/// Projects tasks can be added to: Todoist's projects or the Asana workspace's
+#[command]
+pub async fn list_task_projects(provider: TaskProvider) -> Result<Vec<TaskProject>, String> {
+    if provider.token().is_empty() {
+        return Err(format!("Enter a {} token", provider.label()));
+    }
+    match &provider {
+        TaskProvider::Todoist { .. } => {
+            let url = format!("{}/projects", TODOIST_API);
+            let listed = call_api(&provider, reqwest::Method::GET, &url, &[("limit", "200")], None).await?;
+            Ok(projects(&listed["results"]))
+        }
+        TaskProvider::Asana { workspace_gid, .. } => {
+            let url = format!("{}/projects", ASANA_API);
+            let query = [("workspace", workspace_gid.trim()), ("archived", "false"), ("opt_fields", "name")];
+            let listed = call_api(&provider, reqwest::Method::GET, &url, &query, None).await?;
+            Ok(projects(&listed["data"]))
+        }
+    }
+}
+
+/// Create tasks for the meeting's open action items assigned to the user; items sent before
+/// are skipped
+#[command]
+pub async fn sync_meeting_tasks<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<TaskSyncReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool)
+        .await?
+        .ok_or_else(|| "Connect Todoist or Asana first".to_string())?;
+    let report = sync_meeting(pool, &settings, &meeting_id).await?;
+    info!(
+        "Created {} {} tasks from meeting {}",
+        report.created,
+        settings.provider.label(),
+        meeting_id
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn picks_the_users_items_and_formats_tasks() {
+        let my_names = vec!["Ana Ruiz".to_string(), "ana@acme.com".to_string()];
+        assert!(is_mine("Me", &my_names));
+        assert!(is_mine("ana ruiz", &my_names));
+        assert!(is_mine("Ana", &my_names));
+        assert!(is_mine("ANA@acme.com", &my_names));
+        assert!(!is_mine("ana@acme", &my_names));
+        assert!(!is_mine("Bo Chen", &my_names));
+        assert!(!is_mine(" ", &my_names));
+
+        assert_eq!(item_key("  Send the\n deck to  Legal "), "send the deck to legal");
+        assert_eq!(item_key("Send the deck to legal"), item_key("send  the deck to Legal"));
+
+        let item = ActionItem {
+            id: "action-1".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            description: "Send the deck to legal".to_string(),
+            assignee: Some("Ana".to_string()),
+            due_date: Some("2026-03-02".to_string()),
+            segment_id: None,
+            done: false,
+            issue_key: None,
+            issue_url: None,
+            created_at: Utc::now(),
+        };
+        let todoist = TaskProvider::Todoist {
+            token: "token".to_string(),
+            project_id: Some("6Jf8VQXxpwv56VQ7".to_string()),
+        };
+        assert_eq!(
+            task_body(&todoist, &item, "Platform sync", "2026-02-23 10:00"),
+            json!({
+                "content": "Send the deck to legal",
+                "description": "From the meeting \"Platform sync\" on 2026-02-23 10:00.",
+                "due_date": "2026-03-02",
+                "project_id": "6Jf8VQXxpwv56VQ7",
+            })
+        );
+        let asana = TaskProvider::Asana {
+            token: "token".to_string(),
+            workspace_gid: "1200".to_string(),
+            project_gid: None,
+        };
+        let body = task_body(&asana, &item, "Platform sync", "2026-02-23 10:00");
+        assert_eq!(body["data"]["due_on"], "2026-03-02");
+        assert_eq!(body["data"]["assignee"], "me");
+        assert!(body["data"].get("projects").is_none());
+
+        assert_eq!(
+            projects(&json!([{ "gid": "1200", "name": "Acme" }, { "name": "No id" }])),
+            [TaskProject { id: "1200".to_string(), name: "Acme".to_string() }]
+        );
+        assert_eq!(
+            asana_error(&json!({ "errors": [{ "message": "workspace: Not a recognized ID" }] })).as_deref(),
+            Some("workspace: Not a recognized ID")
+        );
+    }
+}
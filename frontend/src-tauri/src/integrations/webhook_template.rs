@@ -0,0 +1,296 @@
+// Webhook payload templates
+// Handlebars-style templates that shape a webhook's JSON body, so it can match what a
+// Zapier, Make or n8n flow expects. Templates are rendered over the same fields as the
+// default payload (event, delivery_id, created_at, meeting, summary, action_items,
+// transcript):
+//   {{meeting.title}}              the value as text, escaped to sit inside a JSON string
+//   {{json action_items}}          the value as JSON: objects, arrays, quoted strings, null
+//   {{#each action_items}}…{{/each}}
+//                                  once per element; inside, {{this}} is the element, its
+//                                  fields are looked up first, and {{@index}}, {{@first}} and
+//                                  {{@last}} are set ({{#unless @last}},{{/unless}} separates)
+//   {{#if summary}}…{{else}}…{{/if}}, {{#unless …}}…{{/unless}}
+//   {{! comment }}
+// Missing fields render empty (or null with json); the result must be valid JSON.
+
+use serde_json::Value;
+
+/// Largest template accepted
+const MAX_TEMPLATE_CHARS: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Value { path: String, json: bool },
+    Each { path: String, body: Vec<Node> },
+    If { path: String, negate: bool, then: Vec<Node>, otherwise: Vec<Node> },
+}
+
+/// A block whose closing tag hasn't been reached yet
+struct OpenBlock {
+    helper: &'static str,
+    path: String,
+    then: Vec<Node>,
+    otherwise: Option<Vec<Node>>,
+}
+
+impl OpenBlock {
+    fn nodes(&mut self) -> &mut Vec<Node> {
+        self.otherwise.as_mut().unwrap_or(&mut self.then)
+    }
+
+    fn close(self) -> Node {
+        match self.helper {
+            "each" => Node::Each { path: self.path, body: self.then },
+            helper => Node::If {
+                path: self.path,
+                negate: helper == "unless",
+                then: self.then,
+                otherwise: self.otherwise.unwrap_or_default(),
+            },
+        }
+    }
+}
+
+/// A parsed payload template
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayloadTemplate {
+    nodes: Vec<Node>,
+}
+
+/// A variable path: "this", "@index", or dot-separated fields and array indices
+fn parse_path(path: &str) -> Result<String, String> {
+    let valid = !path.is_empty()
+        && path
+            .split('.')
+            .enumerate()
+            .all(|(i, part)| {
+                let name = part.strip_prefix('@').filter(|_| i == 0).unwrap_or(part);
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+    if valid {
+        Ok(path.to_string())
+    } else {
+        Err(format!("Invalid variable '{{{{{}}}}}'", path))
+    }
+}
+
+impl PayloadTemplate {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        if template.chars().count() > MAX_TEMPLATE_CHARS {
+            return Err(format!("Payload templates are limited to {} characters", MAX_TEMPLATE_CHARS));
+        }
+        let mut root = Vec::new();
+        let mut open: Vec<OpenBlock> = Vec::new();
+        let mut rest = template;
+        while !rest.is_empty() {
+            let (text, tag) = match rest.find("{{") {
+                Some(start) => {
+                    let end = rest[start..]
+                        .find("}}")
+                        .map(|end| start + end)
+                        .ok_or_else(|| "A '{{' is never closed with '}}'".to_string())?;
+                    let tag = &rest[start + 2..end];
+                    let text = &rest[..start];
+                    rest = &rest[end + 2..];
+                    (text, Some(tag.trim()))
+                }
+                None => (std::mem::take(&mut rest), None),
+            };
+            let nodes = match open.last_mut() {
+                Some(block) => block.nodes(),
+                None => &mut root,
+            };
+            if !text.is_empty() {
+                nodes.push(Node::Text(text.to_string()));
+            }
+            let Some(tag) = tag else { break };
+
+            if tag.starts_with('!') {
+                continue;
+            } else if let Some(block) = tag.strip_prefix('#') {
+                let (helper, path) = block.split_once(char::is_whitespace).unwrap_or((block, ""));
+                let helper = match helper {
+                    "each" => "each",
+                    "if" => "if",
+                    "unless" => "unless",
+                    _ => return Err(format!("Unknown block '{{{{#{}}}}}'; use each, if or unless", helper)),
+                };
+                open.push(OpenBlock {
+                    helper,
+                    path: parse_path(path.trim())?,
+                    then: Vec::new(),
+                    otherwise: None,
+                });
+            } else if tag == "else" {
+                match open.last_mut() {
+                    Some(block) if block.helper != "each" && block.otherwise.is_none() => block.otherwise = Some(Vec::new()),
+                    _ => return Err("'{{else}}' only belongs inside {{#if}} or {{#unless}}, once".to_string()),
+                }
+            } else if let Some(helper) = tag.strip_prefix('/') {
+                let block = open
+                    .pop()
+                    .filter(|block| block.helper == helper.trim())
+                    .ok_or_else(|| format!("'{{{{/{}}}}}' closes a block that isn't open", helper.trim()))?;
+                let node = block.close();
+                match open.last_mut() {
+                    Some(parent) => parent.nodes().push(node),
+                    None => root.push(node),
+                }
+            } else if let Some(path) = tag.strip_prefix("json ") {
+                nodes.push(Node::Value { path: parse_path(path.trim())?, json: true });
+            } else {
+                nodes.push(Node::Value { path: parse_path(tag)?, json: false });
+            }
+        }
+        if let Some(block) = open.last() {
+            return Err(format!("'{{{{#{} {}}}}}' is never closed", block.helper, block.path));
+        }
+        Ok(Self { nodes: root })
+    }
+
+    /// The body for `context`; fails when the result isn't valid JSON
+    pub fn render(&self, context: &Value) -> Result<String, String> {
+        let mut output = String::new();
+        render_nodes(&self.nodes, &[Scope { value: context, position: None }], &mut output);
+        serde_json::from_str::<Value>(&output).map_err(|e| format!("The payload template doesn't produce valid JSON: {}", e))?;
+        Ok(output)
+    }
+}
+
+/// A value templates look fields up in, and its place in the array {{#each}} is walking
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    value: &'a Value,
+    position: Option<(usize, usize)>,
+}
+
+fn field<'a>(value: &'a Value, part: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(object) => object.get(part),
+        Value::Array(array) => array.get(part.parse::<usize>().ok()?),
+        _ => None,
+    }
+}
+
+/// Resolve a path from the innermost scope outwards
+fn lookup(path: &str, scopes: &[Scope]) -> Option<Value> {
+    let inner = scopes.last()?;
+    if let Some(variable) = path.strip_prefix('@') {
+        let (index, length) = scopes.iter().rev().find_map(|scope| scope.position)?;
+        return match variable {
+            "index" => Some(Value::from(index)),
+            "first" => Some(Value::from(index == 0)),
+            "last" => Some(Value::from(index + 1 == length)),
+            _ => None,
+        };
+    }
+    let mut parts = path.split('.');
+    let first = parts.next()?;
+    let start = match first {
+        "this" => inner.value,
+        _ => scopes.iter().rev().find_map(|scope| field(scope.value, first))?,
+    };
+    parts.try_fold(start, field).cloned()
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(value) => *value,
+        Value::Number(number) => number.as_f64().is_some_and(|number| number != 0.0),
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+/// The value as it reads inside a JSON string
+fn text(value: &Value) -> String {
+    let plain = match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    let quoted = serde_json::to_string(&plain).unwrap_or_default();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+fn render_nodes(nodes: &[Node], scopes: &[Scope], output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(literal) => output.push_str(literal),
+            Node::Value { path, json: true } => {
+                output.push_str(&lookup(path, scopes).unwrap_or(Value::Null).to_string());
+            }
+            Node::Value { path, json: false } => {
+                output.push_str(&lookup(path, scopes).map(|value| text(&value)).unwrap_or_default());
+            }
+            Node::Each { path, body } => {
+                let Some(Value::Array(items)) = lookup(path, scopes) else { continue };
+                for (index, item) in items.iter().enumerate() {
+                    let mut inner = scopes.to_vec();
+                    inner.push(Scope { value: item, position: Some((index, items.len())) });
+                    render_nodes(body, &inner, output);
+                }
+            }
+            Node::If { path, negate, then, otherwise } => {
+                let truthy = lookup(path, scopes).is_some_and(|value| is_truthy(&value));
+                render_nodes(if truthy != *negate { then } else { otherwise }, scopes, output);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_templates_over_meeting_fields() {
+        let context = json!({
+            "event": "summary_ready",
+            "meeting": { "title": "Q3 \"kickoff\"", "participants": ["Ana", "Bo"], "duration_seconds": 1800.0 },
+            "summary": "## Decisions\nShip it",
+            "action_items": [
+                { "description": "Send the deck", "assignee": "Ana", "due_date": null },
+                { "description": "Book the room", "assignee": null, "due_date": "2026-03-02" },
+            ],
+        });
+        let template = PayloadTemplate::parse(
+            r#"{"text": "{{ meeting.title }} ({{meeting.duration_seconds}} s)", "notes": "{{summary}}",
+                "people": {{json meeting.participants}}, "first": "{{meeting.participants.0}}",
+                "tasks": [{{#each action_items}}{"n": {{@index}}, "task": "{{description}}",
+                  "who": "{{#if assignee}}{{assignee}}{{else}}nobody{{/if}}", "meeting": "{{meeting.title}}"}{{#unless @last}},{{/unless}}{{/each}}],
+                {{! unknown fields are empty }}"missing": "{{transcript.0.text}}", "none": {{json transcript}}}"#,
+        )
+        .unwrap();
+        let body: Value = serde_json::from_str(&template.render(&context).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "text": "Q3 \"kickoff\" (1800.0 s)",
+                "notes": "## Decisions\nShip it",
+                "people": ["Ana", "Bo"],
+                "first": "Ana",
+                "tasks": [
+                    { "n": 0, "task": "Send the deck", "who": "Ana", "meeting": "Q3 \"kickoff\"" },
+                    { "n": 1, "task": "Book the room", "who": "nobody", "meeting": "Q3 \"kickoff\"" },
+                ],
+                "missing": "",
+                "none": null,
+            })
+        );
+
+        assert!(PayloadTemplate::parse("{{#each action_items}}").is_err());
+        assert!(PayloadTemplate::parse("{{/if}}").is_err());
+        assert!(PayloadTemplate::parse("{{#if summary}}{{/each}}").is_err());
+        assert!(PayloadTemplate::parse("{{#with meeting}}{{/with}}").is_err());
+        assert!(PayloadTemplate::parse("{{#each x}}{{else}}{{/each}}").is_err());
+        assert!(PayloadTemplate::parse("{\"a\": \"{{meeting.title\"}").is_err());
+        assert!(PayloadTemplate::parse("{{meeting title}}").is_err());
+        // Parses, but a value left unquoted doesn't make JSON
+        assert!(PayloadTemplate::parse(r#"{"title": {{meeting.title}}}"#).unwrap().render(&context).is_err());
+    }
+}
@@ -0,0 +1,457 @@
+// Outbound webhooks
+// JSON POSTed to user-defined URLs when meetings finish, transcripts are ready or minutes
+// are generated, for automation tools and home-grown services. Each webhook picks its
+// events and whether the minutes, action items and transcript are included, or replaces the
+// payload with its own template (see webhook_template.rs) to match what a flow expects.
+//
+// Requests carry "X-Meetily-Event", "X-Meetily-Delivery" and "X-Meetily-Signature:
+// t=<unix time>,v1=<hex HMAC-SHA256 of "<t>.<body>" keyed with the webhook's secret>", so
+// receivers can check where a request came from and reject replays. Failed deliveries
+// (network errors, timeouts, 408, 429 and 5xx answers) are retried with growing delays;
+// other answers are final. Retries are held in memory, so those still pending when the
+// app quits are dropped. The latest deliveries are kept for the settings screen.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sqlx::SqlitePool;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use super::webhook_template::PayloadTemplate;
+use super::{load_meeting, MeetingEvent};
+use crate::database::models::ActionItem;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::{ExportSegment, MeetingDocument};
+use crate::export::json::MeetingRecord;
+use crate::state::AppState;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+/// Waits before each retry; a delivery is tried once more than there are delays
+const RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_secs(10),
+    Duration::from_secs(60),
+    Duration::from_secs(5 * 60),
+    Duration::from_secs(30 * 60),
+];
+const HISTORY_LIMIT: usize = 100;
+const MAX_ERROR_CHARS: usize = 300;
+
+// Latest deliveries, newest first
+static DELIVERIES: Lazy<Mutex<VecDeque<WebhookDelivery>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Webhook {
+    /// Assigned when first saved
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    /// Signing key; generated when saved empty
+    #[serde(default)]
+    pub secret: String,
+    pub events: Vec<MeetingEvent>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Send the minutes and action items, once there are any
+    #[serde(default = "default_true")]
+    pub include_summary: bool,
+    /// Send every transcript segment; payloads of long meetings get large
+    #[serde(default)]
+    pub include_transcript: bool,
+    /// Handlebars-style template for the body, instead of the default payload; it may use
+    /// every field whatever the include options say
+    #[serde(default)]
+    pub payload_template: Option<String>,
+}
+
+impl Webhook {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Name the webhook".to_string());
+        }
+        let url = url::Url::parse(self.url.trim()).map_err(|_| format!("Invalid webhook URL: {}", self.url.trim()))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("Webhook URLs must start with https:// or http://, not {}", url.scheme()));
+        }
+        if self.enabled && self.events.is_empty() {
+            return Err(format!("Choose at least one event for {}", self.name.trim()));
+        }
+        self.template()?;
+        Ok(())
+    }
+
+    /// The parsed payload template, when the webhook has one
+    fn template(&self) -> Result<Option<PayloadTemplate>, String> {
+        self.payload_template
+            .as_deref()
+            .filter(|template| !template.trim().is_empty())
+            .map(PayloadTemplate::parse)
+            .transpose()
+            .map_err(|e| format!("{}: {}", self.name.trim(), e))
+    }
+}
+
+/// Webhook settings, stored as JSON in settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSettings {
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Being sent, or waiting for a retry
+    Pending,
+    Delivered,
+    Failed,
+}
+
+/// One event sent to one webhook
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub webhook_name: String,
+    pub event: String,
+    pub meeting_id: Option<String>,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    /// HTTP status of the last answer
+    pub response_status: Option<u16>,
+    pub error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+struct Payload<'a> {
+    event: &'a str,
+    delivery_id: &'a str,
+    created_at: DateTime<Utc>,
+    meeting: MeetingRecord<'a>,
+    /// Minutes markdown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action_items: Option<&'a [ActionItem]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transcript: Option<&'a [ExportSegment]>,
+}
+
+fn render_payload(webhook: &Webhook, event: MeetingEvent, delivery_id: &str, document: &MeetingDocument) -> Result<String, String> {
+    if let Some(template) = webhook.template()? {
+        let payload = Payload {
+            event: event.name(),
+            delivery_id,
+            created_at: Utc::now(),
+            meeting: MeetingRecord::new(document),
+            summary: document.summary.as_deref(),
+            action_items: Some(document.action_items.as_slice()),
+            transcript: Some(document.segments.as_slice()),
+        };
+        let context = serde_json::to_value(&payload).map_err(|e| format!("Failed to serialize webhook payload: {}", e))?;
+        return template.render(&context).map_err(|e| format!("{}: {}", webhook.name.trim(), e));
+    }
+    let payload = Payload {
+        event: event.name(),
+        delivery_id,
+        created_at: Utc::now(),
+        meeting: MeetingRecord::new(document),
+        summary: document.summary.as_deref().filter(|_| webhook.include_summary),
+        action_items: Some(document.action_items.as_slice()).filter(|_| webhook.include_summary),
+        transcript: Some(document.segments.as_slice()).filter(|_| webhook.include_transcript),
+    };
+    serde_json::to_string(&payload).map_err(|e| format!("Failed to serialize webhook payload: {}", e))
+}
+
+/// Value of the X-Meetily-Signature header
+fn signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    let digest: String = mac.finalize().into_bytes().iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("t={},v1={}", timestamp, digest)
+}
+
+fn generate_secret() -> String {
+    let mut bytes = [0u8; 24];
+    OsRng.fill_bytes(&mut bytes);
+    format!("whsec_{}", bytes.iter().map(|byte| format!("{:02x}", byte)).collect::<String>())
+}
+
+/// Whether a failed attempt may succeed later: no answer, a timeout, rate limiting or a
+/// server error
+fn is_retryable(status: Option<u16>) -> bool {
+    match status {
+        None => true,
+        Some(status) => status == 408 || status == 429 || status >= 500,
+    }
+}
+
+/// Send one request; the answer's status, or None when there was none
+async fn post(webhook: &Webhook, event: &str, delivery_id: &str, body: &str) -> (Option<u16>, Result<(), String>) {
+    let client = match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => return (None, Err(format!("Failed to create HTTP client: {}", e))),
+    };
+    let response = client
+        .post(webhook.url.trim())
+        .header("Content-Type", "application/json")
+        .header("User-Agent", concat!("Meetily/", env!("CARGO_PKG_VERSION")))
+        .header("X-Meetily-Event", event)
+        .header("X-Meetily-Delivery", delivery_id)
+        .header("X-Meetily-Signature", signature(&webhook.secret, Utc::now().timestamp(), body))
+        .body(body.to_string())
+        .send()
+        .await;
+    match response {
+        Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), Ok(())),
+        Ok(response) => {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            let text: String = text.trim().chars().take(MAX_ERROR_CHARS).collect();
+            (Some(status.as_u16()), Err(format!("{} answered {}: {}", webhook.url.trim(), status, text)))
+        }
+        Err(e) => (None, Err(format!("Failed to reach {}: {}", webhook.url.trim(), e))),
+    }
+}
+
+fn record(delivery: &WebhookDelivery) {
+    let mut deliveries = DELIVERIES.lock().unwrap();
+    deliveries.retain(|existing| existing.id != delivery.id);
+    deliveries.push_front(delivery.clone());
+    deliveries.truncate(HISTORY_LIMIT);
+}
+
+/// Deliver one payload, retrying while failures look temporary
+async fn deliver(webhook: Webhook, event: MeetingEvent, meeting_id: String, delivery_id: String, body: String) {
+    let mut delivery = WebhookDelivery {
+        id: delivery_id,
+        webhook_id: webhook.id.clone(),
+        webhook_name: webhook.name.clone(),
+        event: event.name().to_string(),
+        meeting_id: Some(meeting_id),
+        status: DeliveryStatus::Pending,
+        attempts: 0,
+        response_status: None,
+        error: None,
+        updated_at: Utc::now(),
+    };
+    record(&delivery);
+    let mut delays = RETRY_DELAYS.iter();
+    loop {
+        let (status, result) = post(&webhook, event.name(), &delivery.id, &body).await;
+        delivery.attempts += 1;
+        delivery.response_status = status;
+        delivery.updated_at = Utc::now();
+        let retry_in = match &result {
+            Ok(()) => None,
+            Err(_) if is_retryable(status) => delays.next(),
+            Err(_) => None,
+        };
+        delivery.error = result.as_ref().err().cloned();
+        delivery.status = match (&result, retry_in) {
+            (Ok(()), _) => DeliveryStatus::Delivered,
+            (Err(_), Some(_)) => DeliveryStatus::Pending,
+            (Err(_), None) => DeliveryStatus::Failed,
+        };
+        record(&delivery);
+        match (result, retry_in) {
+            (Ok(()), _) => {
+                info!("Delivered {} to webhook {:?}", event.name(), webhook.name);
+                return;
+            }
+            (Err(e), Some(delay)) => {
+                warn!("Webhook {:?} failed (attempt {}), retrying in {:?}: {}", webhook.name, delivery.attempts, delay, e);
+                tokio::time::sleep(*delay).await;
+            }
+            (Err(e), None) => {
+                warn!("Webhook {:?} failed after {} attempt(s): {}", webhook.name, delivery.attempts, e);
+                return;
+            }
+        }
+    }
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<WebhookSettings, String> {
+    SettingsRepository::get_webhook_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load webhook settings: {}", e))
+}
+
+/// Send a meeting event to the webhooks subscribed to it
+pub async fn deliver_event(pool: &SqlitePool, event: MeetingEvent, meeting_id: &str) {
+    let settings = match load_settings(pool).await {
+        Ok(settings) => settings,
+        Err(e) => {
+            warn!("{}", e);
+            return;
+        }
+    };
+    let webhooks: Vec<Webhook> = settings
+        .webhooks
+        .into_iter()
+        .filter(|webhook| webhook.enabled && webhook.events.contains(&event))
+        .collect();
+    if webhooks.is_empty() {
+        return;
+    }
+    let document = match load_meeting(pool, meeting_id).await {
+        Ok(document) => document,
+        Err(e) => {
+            warn!("Not sending {} for meeting {} to webhooks: {}", event.name(), meeting_id, e);
+            return;
+        }
+    };
+    for webhook in webhooks {
+        let delivery_id = Uuid::new_v4().to_string();
+        match render_payload(&webhook, event, &delivery_id, &document) {
+            Ok(body) => {
+                // Each webhook retries on its own schedule
+                tauri::async_runtime::spawn(deliver(webhook, event, meeting_id.to_string(), delivery_id, body));
+            }
+            Err(e) => warn!("{}", e),
+        }
+    }
+}
+
+#[command]
+pub async fn get_webhook_settings<R: Runtime>(app: AppHandle<R>) -> Result<WebhookSettings, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+/// Save webhooks; new ones get an id, and a secret when none was entered
+#[command]
+pub async fn save_webhook_settings<R: Runtime>(app: AppHandle<R>, mut settings: WebhookSettings) -> Result<WebhookSettings, String> {
+    for webhook in &mut settings.webhooks {
+        webhook.validate()?;
+        webhook.name = webhook.name.trim().to_string();
+        webhook.url = webhook.url.trim().to_string();
+        webhook.secret = webhook.secret.trim().to_string();
+        if webhook.id.is_empty() {
+            webhook.id = Uuid::new_v4().to_string();
+        }
+        if webhook.secret.is_empty() {
+            webhook.secret = generate_secret();
+        }
+    }
+    let state = app.state::<AppState>();
+    SettingsRepository::save_webhook_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save webhook settings: {}", e))?;
+    Ok(settings)
+}
+
+/// The body a webhook would receive for one of its events on a meeting
+async fn sample_payload(pool: &SqlitePool, webhook: &Webhook, meeting_id: &str, delivery_id: &str) -> Result<(MeetingEvent, String), String> {
+    let event = webhook.events.first().copied().unwrap_or(MeetingEvent::SummaryReady);
+    let document = load_meeting(pool, meeting_id).await?;
+    Ok((event, render_payload(webhook, event, delivery_id, &document)?))
+}
+
+/// Render a webhook's payload for a meeting without sending it, to check a template
+#[command]
+pub async fn preview_webhook_payload<R: Runtime>(app: AppHandle<R>, webhook: Webhook, meeting_id: String) -> Result<String, String> {
+    webhook.validate()?;
+    let state = app.state::<AppState>();
+    let delivery_id = Uuid::new_v4().to_string();
+    let (_, body) = sample_payload(state.db_manager.pool(), &webhook, &meeting_id, &delivery_id).await?;
+    Ok(body)
+}
+
+/// Send a webhook one request, without retrying: a "ping" event, or the payload for
+/// `meeting_id` so a flow can learn the fields its template sends
+///
+/// # Returns
+/// The HTTP status it answered with
+#[command]
+pub async fn test_webhook<R: Runtime>(app: AppHandle<R>, webhook: Webhook, meeting_id: Option<String>) -> Result<u16, String> {
+    webhook.validate()?;
+    let delivery_id = Uuid::new_v4().to_string();
+    let (event, body) = match meeting_id {
+        Some(meeting_id) => {
+            let state = app.state::<AppState>();
+            let (event, body) = sample_payload(state.db_manager.pool(), &webhook, &meeting_id, &delivery_id).await?;
+            (event.name(), body)
+        }
+        None => {
+            let body = serde_json::json!({
+                "event": "ping",
+                "delivery_id": delivery_id,
+                "created_at": Utc::now(),
+                "webhook": webhook.name.trim(),
+            })
+            .to_string();
+            ("ping", body)
+        }
+    };
+    let (status, result) = post(&webhook, event, &delivery_id, &body).await;
+    result?;
+    Ok(status.unwrap_or_default())
+}
+
+/// Latest deliveries, newest first
+#[command]
+pub async fn list_webhook_deliveries() -> Result<Vec<WebhookDelivery>, String> {
+    Ok(DELIVERIES.lock().unwrap().iter().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_and_classifies_deliveries() {
+        // Receivers recompute the HMAC over "<t>.<body>"
+        let header = signature("whsec_test", 1_770_000_000, r#"{"event":"ping"}"#);
+        let (timestamp, digest) = header.split_once(",v1=").unwrap();
+        assert_eq!(timestamp, "t=1770000000");
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec_test").unwrap();
+        mac.update(br#"1770000000.{"event":"ping"}"#);
+        let expected: String = mac.finalize().into_bytes().iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(digest, expected);
+        assert_ne!(signature("other", 1_770_000_000, r#"{"event":"ping"}"#), header);
+
+        assert!(is_retryable(None));
+        assert!(is_retryable(Some(503)));
+        assert!(is_retryable(Some(429)));
+        assert!(!is_retryable(Some(404)));
+        assert!(!is_retryable(Some(401)));
+
+        let secret = generate_secret();
+        assert!(secret.starts_with("whsec_") && secret.len() == 6 + 48);
+    }
+
+    #[test]
+    fn validates_webhooks() {
+        let webhook: Webhook = serde_json::from_str(
+            r#"{"name": "n8n", "url": "https://n8n.example.com/webhook/minutes", "events": ["summary_ready"]}"#,
+        )
+        .unwrap();
+        assert!(webhook.enabled && webhook.include_summary && !webhook.include_transcript);
+        assert!(webhook.validate().is_ok());
+
+        let no_events = Webhook { events: Vec::new(), ..webhook.clone() };
+        assert!(no_events.validate().is_err());
+        assert!(Webhook { enabled: false, ..no_events }.validate().is_ok());
+        assert!(Webhook { url: "ftp://example.com".to_string(), ..webhook.clone() }.validate().is_err());
+        assert!(Webhook { name: " ".to_string(), ..webhook.clone() }.validate().is_err());
+        let templated = |template: &str| Webhook { payload_template: Some(template.to_string()), ..webhook.clone() };
+        assert!(templated(r#"{"title": "{{meeting.title}}"}"#).validate().is_ok());
+        assert!(templated("  ").validate().is_ok());
+        assert!(templated("{{#each action_items}}").validate().is_err());
+    }
+}
@@ -37,9 +37,23 @@ pub(crate) use perf_trace;
 // Declare audio module
 pub mod analytics;
 pub mod api;
+pub mod attachments;
 pub mod audio;
+pub mod backup;
+pub mod bookmarks;
+pub mod calendar;
+pub mod comments;
 pub mod console_utils;
 pub mod database;
+pub mod diarization;
+pub mod export;
+pub mod import;
+pub mod integrations;
+pub mod library;
+pub mod local_api;
+pub mod meeting_detection;
+pub mod meeting_info;
+pub mod meeting_templates;
 pub mod notifications;
 pub mod ollama;
 pub mod onboarding;
@@ -48,10 +62,16 @@ pub mod anthropic;
 pub mod groq;
 pub mod openrouter;
 pub mod parakeet_engine;
+pub mod protection;
+pub mod retention;
 pub mod state;
+pub mod storage;
 pub mod summary;
+pub mod sync;
+pub mod trash;
 pub mod tray;
 pub mod utils;
+pub mod watch_folder;
 pub mod whisper_engine;
 
 use audio::{list_audio_devices, AudioDevice, trigger_audio_permission};
@@ -405,6 +425,8 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
+        // Meeting recordings, streamed with range requests
+        .register_asynchronous_uri_scheme_protocol(audio::playback::SCHEME, audio::playback::handle_audio_request)
         .manage(whisper_engine::parallel_commands::ParallelProcessorState::new())
         .manage(Arc::new(RwLock::new(
             None::<notifications::manager::NotificationManager<tauri::Wry>>,
@@ -524,6 +546,19 @@ pub fn run() {
             })
             .expect("Failed to initialize database");
 
+            // Weekly digests, when scheduled in settings
+            summary::digest::start_scheduler(_app.handle().clone());
+
+            // Retention cleanup, when a policy is enabled in settings
+            retention::start_cleanup_task(_app.handle().clone());
+            trash::start_purge_task(_app.handle().clone());
+            protection::start_auto_lock_task(_app.handle().clone());
+            sync::start_sync_task(_app.handle().clone());
+            watch_folder::start_watch_task(_app.handle().clone());
+            calendar::start_calendar_task(_app.handle().clone());
+            meeting_detection::start_detection_task(_app.handle().clone());
+            local_api::start_local_api(_app.handle().clone());
+
             // Initialize bundled templates directory for dynamic template discovery
             log::info!("Initializing bundled templates directory...");
             if let Ok(resource_path) = _app.handle().path().resource_dir() {
@@ -629,6 +664,125 @@ pub fn run() {
             audio::recording_commands::attempt_device_reconnect,
             // Playback device detection (Bluetooth warning)
             audio::recording_commands::get_active_audio_output,
+            // Transcript replacement dictionary
+            audio::transcription::replacements::get_replacement_dictionary,
+            audio::transcription::replacements::set_replacement_dictionary,
+            // Provider A/B comparison
+            audio::transcription::comparison::get_transcription_comparison_config,
+            audio::transcription::comparison::set_transcription_comparison_config,
+            audio::transcription::comparison::list_transcript_comparisons,
+            audio::transcription::comparison::get_transcript_comparison,
+            // Speaker diarization model management
+            diarization::diarization_get_model_status,
+            diarization::diarization_download_model,
+            diarization::diarization_delete_model,
+            // Voice profiles (speaker enrollment)
+            diarization::profiles::voice_profile_list,
+            diarization::profiles::voice_profile_enroll,
+            diarization::profiles::voice_profile_enroll_file,
+            diarization::profiles::voice_profile_rename,
+            diarization::profiles::voice_profile_delete,
+            // Speaker correction commands
+            diarization::speaker_edits::meeting_list_speakers,
+            diarization::speaker_edits::meeting_speaker_name_suggestions,
+            diarization::speaker_edits::speaker_rename,
+            diarization::speaker_edits::speaker_merge,
+            diarization::speaker_edits::speaker_reassign_segments,
+            diarization::speaker_edits::get_meeting_labeled_transcript,
+            diarization::talk_time::get_meeting_talk_time,
+            diarization::fingerprints::meeting_linked_speakers,
+            // Tone analysis
+            summary::tone::analyze_meeting_tone,
+            summary::tone::get_meeting_tone,
+            // Action items
+            summary::action_items::extract_action_items,
+            summary::action_items::get_action_items,
+            summary::action_items::set_action_item_done,
+            // Decisions and open questions
+            summary::outcomes::extract_meeting_outcomes,
+            summary::outcomes::get_meeting_outcomes,
+            // Chapters
+            summary::chapters::generate_meeting_chapters,
+            summary::chapters::get_meeting_chapters,
+            // Live summary
+            summary::live_summary::get_live_summary,
+            // Follow-up email
+            summary::follow_up::generate_follow_up_email,
+            // Transcript Q&A
+            summary::qa::ask_meeting_question,
+            // Embeddings
+            summary::embeddings::get_embedding_config,
+            summary::embeddings::save_embedding_config,
+            summary::vector_index::index_meeting_embeddings,
+            summary::semantic_search::semantic_search_meetings,
+            // Full-text search
+            database::search::search_meetings,
+            // Digests
+            summary::digest::generate_meeting_digest,
+            summary::digest::list_meeting_digests,
+            summary::digest::delete_meeting_digest,
+            summary::digest::get_digest_schedule,
+            summary::digest::save_digest_schedule,
+            // Summary styles
+            summary::style::list_summary_styles,
+            summary::style::get_summary_style,
+            summary::style::save_summary_style,
+            // LLM usage
+            summary::usage::get_llm_usage_report,
+            // Summary versions
+            summary::versions::list_summary_versions,
+            summary::versions::get_summary_version,
+            summary::versions::diff_summary_versions,
+            summary::versions::restore_summary_version,
+            // Summary feedback
+            summary::feedback::submit_summary_feedback,
+            summary::feedback::list_summary_feedback,
+            summary::feedback::get_summary_feedback_report,
+            // Glossary
+            summary::glossary::list_glossary_entries,
+            summary::glossary::save_glossary_entry,
+            summary::glossary::delete_glossary_entry,
+            // Agenda coverage
+            summary::agenda::set_meeting_agenda,
+            summary::agenda::analyze_agenda_coverage,
+            // Standup reports
+            summary::standup::get_standup_report,
+            summary::standup::extract_standup_report,
+            // Highlights
+            summary::highlights::get_meeting_highlights,
+            summary::highlights::generate_meeting_highlights,
+            summary::highlights::get_highlight_snippet,
+            summary::highlights::export_highlight_clip,
+            // Bookmarked segments
+            bookmarks::bookmark_current_moment,
+            bookmarks::set_segment_bookmark,
+            bookmarks::get_meeting_bookmarks,
+            bookmarks::list_bookmarks,
+            // Segment comments
+            comments::add_segment_comment,
+            comments::update_segment_comment,
+            comments::delete_segment_comment,
+            comments::get_meeting_comments,
+            // PII redaction
+            summary::redaction::get_redaction_settings,
+            summary::redaction::save_redaction_settings,
+            summary::redaction::restore_redacted_summary,
+            // Meeting export
+            export::get_export_settings,
+            export::save_export_settings,
+            export::markdown::export_meeting_markdown,
+            export::pdf::export_meeting_pdf,
+            export::docx::export_meeting_docx,
+            export::subtitles::export_meeting_subtitles,
+            export::json::export_meeting_json,
+            export::html::export_meeting_html,
+            export::csv::export_action_items_csv,
+            export::csv::export_speaker_analytics_csv,
+            export::bulk::export_all_meetings,
+            export::obsidian::export_meeting_obsidian,
+            export::obsidian::export_meetings_obsidian,
+            // Transcript import
+            import::import_transcript,
             // Audio recovery commands (for transcript recovery feature)
             audio::incremental_saver::recover_audio_from_checkpoints,
             audio::incremental_saver::cleanup_checkpoints,
@@ -679,6 +833,9 @@ pub fn run() {
             summary::api_list_templates,
             summary::api_get_template_details,
             summary::api_validate_template,
+            summary::api_list_prompt_templates,
+            summary::api_save_prompt_template,
+            summary::api_delete_prompt_template,
             // Built-in AI commands
             summary::summary_engine::builtin_ai_list_models,
             summary::summary_engine::builtin_ai_get_model_info,
@@ -698,6 +855,24 @@ pub fn run() {
             audio::recording_preferences::get_current_audio_backend,
             audio::recording_preferences::set_audio_backend,
             audio::recording_preferences::get_audio_backend_info,
+            // Transcript review
+            audio::transcription::review::get_segments_needing_review,
+            audio::transcription::review::set_segment_reviewed,
+            audio::playback::get_segment_audio,
+            audio::playback::read_segment_audio,
+            audio::playback::export_audio_clip,
+            // Deduplicated audio storage
+            audio::dedup::deduplicate_recordings,
+            audio::dedup::collect_audio_garbage,
+            audio::transcription::edits::edit_transcript_segment,
+            audio::transcription::edits::split_transcript_segment,
+            audio::transcription::edits::merge_transcript_segments,
+            audio::transcription::edits::set_segments_speaker,
+            audio::transcription::edits::list_transcript_revisions,
+            audio::transcription::edits::revert_transcript_revision,
+            // Keyword alerts
+            audio::keyword_alerts::get_watch_keywords,
+            audio::keyword_alerts::set_watch_keywords,
             // Language preference commands
             get_language_preference,
             set_language_preference,
@@ -739,6 +914,131 @@ pub fn run() {
             database::commands::get_database_directory,
             database::commands::open_database_folder,
             whisper_engine::commands::open_models_folder,
+            // Database encryption at rest
+            database::encryption::get_database_encryption_status,
+            database::encryption::set_database_encryption,
+            // Database schema migrations
+            database::migrations::get_database_schema_status,
+            // Backup and restore
+            backup::create_backup,
+            backup::restore_backup,
+            // Retention policy
+            retention::get_retention_policy,
+            retention::save_retention_policy,
+            retention::preview_retention_cleanup,
+            retention::run_retention_cleanup,
+            retention::set_meeting_starred,
+            // Password-protected meetings
+            protection::get_meeting_protection,
+            protection::protect_meeting,
+            protection::unlock_meeting,
+            protection::lock_meeting,
+            protection::unprotect_meeting,
+            // Trash
+            trash::list_trash,
+            trash::restore_meeting,
+            trash::delete_meeting_permanently,
+            trash::empty_trash,
+            trash::get_trash_settings,
+            trash::save_trash_settings,
+            // Sync to user-provided storage
+            sync::get_sync_settings,
+            sync::save_sync_settings,
+            sync::test_sync_connection,
+            sync::sync_now,
+            // Storage usage
+            storage::get_storage_usage,
+            // Watch folder
+            watch_folder::get_watch_folder_settings,
+            watch_folder::save_watch_folder_settings,
+            watch_folder::list_watch_folder_files,
+            watch_folder::retry_watch_folder_file,
+            // Calendar integration
+            calendar::get_calendar_settings,
+            calendar::save_calendar_settings,
+            calendar::connect_google_calendar,
+            calendar::disconnect_google_calendar,
+            calendar::connect_outlook_calendar,
+            calendar::disconnect_outlook_calendar,
+            calendar::test_calendar_subscription,
+            calendar::list_upcoming_events,
+            calendar::get_current_calendar_event,
+            calendar::record_calendar_event,
+            calendar::link_meeting_to_calendar_event,
+            meeting_detection::get_meeting_detection_settings,
+            meeting_detection::save_meeting_detection_settings,
+            meeting_detection::get_detected_calls,
+            integrations::webhooks::get_webhook_settings,
+            integrations::webhooks::save_webhook_settings,
+            integrations::webhooks::test_webhook,
+            integrations::webhooks::preview_webhook_payload,
+            integrations::webhooks::list_webhook_deliveries,
+            integrations::slack::get_slack_settings,
+            integrations::slack::save_slack_settings,
+            integrations::slack::list_slack_channels,
+            integrations::slack::post_meeting_to_slack,
+            integrations::notion::get_notion_settings,
+            integrations::notion::save_notion_settings,
+            integrations::notion::test_notion_connection,
+            integrations::notion::export_meeting_to_notion,
+            integrations::confluence::get_confluence_settings,
+            integrations::confluence::save_confluence_settings,
+            integrations::confluence::test_confluence_connection,
+            integrations::confluence::publish_meeting_to_confluence,
+            integrations::email::get_smtp_settings,
+            integrations::email::save_smtp_settings,
+            integrations::email::test_smtp_connection,
+            integrations::email::email_meeting_minutes,
+            integrations::jira::get_jira_settings,
+            integrations::jira::save_jira_settings,
+            integrations::jira::test_jira_connection,
+            integrations::jira::create_jira_issues,
+            integrations::linear::get_linear_settings,
+            integrations::linear::save_linear_settings,
+            integrations::linear::list_linear_teams,
+            integrations::linear::create_linear_issues,
+            integrations::tasks::get_task_sync_settings,
+            integrations::tasks::save_task_sync_settings,
+            integrations::tasks::list_asana_workspaces,
+            integrations::tasks::list_task_projects,
+            integrations::tasks::sync_meeting_tasks,
+            integrations::crm::get_crm_settings,
+            integrations::crm::save_crm_settings,
+            integrations::crm::test_crm_connection,
+            integrations::crm::attach_meeting_to_crm,
+            local_api::get_local_api_settings,
+            local_api::save_local_api_settings,
+            local_api::regenerate_local_api_token,
+            local_api::get_local_api_status,
+            // Meeting library: folders, tags and filtering
+            library::filter_meetings,
+            library::list_library_folders,
+            library::create_library_folder,
+            library::rename_library_folder,
+            library::move_library_folder,
+            library::delete_library_folder,
+            library::move_meetings_to_folder,
+            library::list_meeting_tags,
+            library::get_meeting_tags,
+            library::set_meeting_tags,
+            library::add_meeting_tags,
+            library::remove_meeting_tags,
+            library::rename_meeting_tag,
+            // Meeting attachments
+            attachments::add_meeting_attachments,
+            attachments::list_meeting_attachments,
+            attachments::open_meeting_attachment,
+            attachments::save_meeting_attachment_as,
+            attachments::rename_meeting_attachment,
+            attachments::remove_meeting_attachment,
+            // Meeting details
+            meeting_info::get_meeting_info,
+            meeting_info::update_meeting_info,
+            // Meeting templates for recurring meetings
+            meeting_templates::list_meeting_templates,
+            meeting_templates::save_meeting_template,
+            meeting_templates::delete_meeting_template,
+            meeting_templates::apply_meeting_template,
             // Onboarding commands
             onboarding::get_onboarding_status,
             onboarding::save_onboarding_status_cmd,
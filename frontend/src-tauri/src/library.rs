@@ -0,0 +1,443 @@
+// Meeting library
+// Organizes meetings with tags and nested library folders (a top-level folder works as a
+// workspace) and filters them by folder, tags, star, date and title. Library folders are
+// only an index: moving a meeting never moves its recording on disk.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use crate::database::models::{MeetingFolder, MeetingModel};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_folder::MeetingFoldersRepository;
+use crate::database::repositories::meeting_tag::{normalize_tag, MeetingTagsRepository};
+use crate::state::AppState;
+use crate::summary::digest::local_day_range;
+
+const MAX_FOLDER_NAME_CHARS: usize = 100;
+
+/// A meeting as listed in the library
+#[derive(Debug, Clone, Serialize)]
+pub struct LibraryMeeting {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub folder_id: Option<String>,
+    pub tags: Vec<String>,
+    pub starred: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagCount {
+    pub tag: String,
+    pub meetings: i64,
+}
+
+fn default_include_subfolders() -> bool {
+    true
+}
+
+/// Which meetings to list; empty fields don't filter
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MeetingFilter {
+    /// Only meetings filed in this folder
+    #[serde(default)]
+    pub folder_id: Option<String>,
+    /// With `folder_id`, also meetings in its subfolders
+    #[serde(default = "default_include_subfolders")]
+    pub include_subfolders: bool,
+    /// Only meetings not filed in any folder (ignored with `folder_id`)
+    #[serde(default)]
+    pub unfiled: bool,
+    /// Only meetings with every one of these tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub starred: Option<bool>,
+    /// First and last local day ("YYYY-MM-DD"), inclusive
+    #[serde(default)]
+    pub from_date: Option<String>,
+    #[serde(default)]
+    pub to_date: Option<String>,
+    /// Case-insensitive text the title must contain
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+/// `folder_id` and every folder nested inside it
+fn folder_and_descendants(folders: &[MeetingFolder], folder_id: &str) -> HashSet<String> {
+    let mut found = HashSet::from([folder_id.to_string()]);
+    let mut pending = vec![folder_id.to_string()];
+    while let Some(parent) = pending.pop() {
+        for folder in folders.iter().filter(|folder| folder.parent_id.as_deref() == Some(parent.as_str())) {
+            if found.insert(folder.id.clone()) {
+                pending.push(folder.id.clone());
+            }
+        }
+    }
+    found
+}
+
+fn validate_folder_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Folder name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_FOLDER_NAME_CHARS {
+        return Err(format!("Folder names are at most {} characters", MAX_FOLDER_NAME_CHARS));
+    }
+    Ok(name.to_string())
+}
+
+/// The filter resolved against the library
+struct ResolvedFilter {
+    folders: Option<HashSet<String>>,
+    unfiled: bool,
+    tags: Vec<String>,
+    starred: Option<bool>,
+    range: (Option<DateTime<Utc>>, Option<DateTime<Utc>>),
+    title: Option<String>,
+}
+
+impl ResolvedFilter {
+    fn new(filter: &MeetingFilter, folders: &[MeetingFolder]) -> Result<Self, String> {
+        let day = |date: &Option<String>| {
+            date.as_deref()
+                .filter(|date| !date.trim().is_empty())
+                .map(|date| local_day_range(date, date))
+                .transpose()
+        };
+        let range = (
+            day(&filter.from_date)?.map(|(start, _)| start),
+            day(&filter.to_date)?.map(|(_, end)| end),
+        );
+        let folders = filter.folder_id.as_deref().map(|folder_id| {
+            if filter.include_subfolders {
+                folder_and_descendants(folders, folder_id)
+            } else {
+                HashSet::from([folder_id.to_string()])
+            }
+        });
+        Ok(Self {
+            folders,
+            unfiled: filter.unfiled,
+            tags: filter.tags.iter().map(|tag| normalize_tag(tag)).filter(|tag| !tag.is_empty()).collect(),
+            starred: filter.starred,
+            range,
+            title: filter
+                .title
+                .as_deref()
+                .map(|title| title.trim().to_lowercase())
+                .filter(|title| !title.is_empty()),
+        })
+    }
+
+    fn matches(&self, meeting: &MeetingModel, tags: &[String]) -> bool {
+        let folder = meeting.library_folder_id.as_deref();
+        let in_folder = match &self.folders {
+            Some(folders) => folder.is_some_and(|folder| folders.contains(folder)),
+            None => !self.unfiled || folder.is_none(),
+        };
+        let created_at = meeting.created_at.0;
+        in_folder
+            && self.tags.iter().all(|tag| tags.contains(tag))
+            && self.starred.iter().all(|&starred| meeting.starred == starred)
+            && self.range.0.iter().all(|&start| created_at >= start)
+            && self.range.1.iter().all(|&end| created_at < end)
+            && self
+                .title
+                .iter()
+                .all(|title| meeting.title.to_lowercase().contains(title.as_str()))
+    }
+}
+
+async fn load_folders(pool: &sqlx::SqlitePool) -> Result<Vec<MeetingFolder>, String> {
+    MeetingFoldersRepository::list(pool)
+        .await
+        .map_err(|e| format!("Failed to load folders: {}", e))
+}
+
+async fn check_folder_exists(pool: &sqlx::SqlitePool, folder_id: Option<&str>) -> Result<(), String> {
+    if let Some(folder_id) = folder_id {
+        let folder = MeetingFoldersRepository::get(pool, folder_id)
+            .await
+            .map_err(|e| format!("Failed to load folder: {}", e))?;
+        if folder.is_none() {
+            return Err(format!("Folder {} not found", folder_id));
+        }
+    }
+    Ok(())
+}
+
+fn folder_error(e: sqlx::Error, name: &str) -> String {
+    match &e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            format!("A folder named '{}' already exists there", name)
+        }
+        _ => format!("Failed to save folder: {}", e),
+    }
+}
+
+/// Meetings matching `filter`, newest first
+pub(crate) async fn matching_meetings(pool: &sqlx::SqlitePool, filter: &MeetingFilter) -> Result<Vec<LibraryMeeting>, String> {
+    let folders = load_folders(pool).await?;
+    let filter = ResolvedFilter::new(filter, &folders)?;
+
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    for (meeting_id, tag) in MeetingTagsRepository::list_all(pool)
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))?
+    {
+        tags.entry(meeting_id).or_default().push(tag);
+    }
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+
+    Ok(meetings
+        .into_iter()
+        .filter_map(|meeting| {
+            let tags = tags.remove(&meeting.id).unwrap_or_default();
+            filter.matches(&meeting, &tags).then(|| LibraryMeeting {
+                id: meeting.id,
+                title: meeting.title,
+                description: meeting.description,
+                created_at: meeting.created_at.0,
+                folder_id: meeting.library_folder_id,
+                tags,
+                starred: meeting.starred,
+            })
+        })
+        .collect())
+}
+
+/// Meetings matching `filter`, newest first
+#[command]
+pub async fn filter_meetings<R: Runtime>(app: AppHandle<R>, filter: MeetingFilter) -> Result<Vec<LibraryMeeting>, String> {
+    let state = app.state::<AppState>();
+    matching_meetings(state.db_manager.pool(), &filter).await
+}
+
+#[command]
+pub async fn list_library_folders<R: Runtime>(app: AppHandle<R>) -> Result<Vec<MeetingFolder>, String> {
+    let state = app.state::<AppState>();
+    load_folders(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn create_library_folder<R: Runtime>(
+    app: AppHandle<R>,
+    name: String,
+    parent_id: Option<String>,
+) -> Result<MeetingFolder, String> {
+    let name = validate_folder_name(&name)?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    check_folder_exists(pool, parent_id.as_deref()).await?;
+    MeetingFoldersRepository::insert(pool, &name, parent_id.as_deref())
+        .await
+        .map_err(|e| folder_error(e, &name))
+}
+
+#[command]
+pub async fn rename_library_folder<R: Runtime>(app: AppHandle<R>, folder_id: String, name: String) -> Result<(), String> {
+    let name = validate_folder_name(&name)?;
+    let state = app.state::<AppState>();
+    let renamed = MeetingFoldersRepository::rename(state.db_manager.pool(), &folder_id, &name)
+        .await
+        .map_err(|e| folder_error(e, &name))?;
+    if !renamed {
+        return Err(format!("Folder {} not found", folder_id));
+    }
+    Ok(())
+}
+
+/// Move a folder (with everything in it) under `parent_id`, or to the top level with None
+#[command]
+pub async fn move_library_folder<R: Runtime>(
+    app: AppHandle<R>,
+    folder_id: String,
+    parent_id: Option<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let folders = load_folders(pool).await?;
+    let folder = folders
+        .iter()
+        .find(|folder| folder.id == folder_id)
+        .ok_or_else(|| format!("Folder {} not found", folder_id))?;
+    if let Some(parent_id) = parent_id.as_deref() {
+        if !folders.iter().any(|folder| folder.id == parent_id) {
+            return Err(format!("Folder {} not found", parent_id));
+        }
+        if folder_and_descendants(&folders, &folder_id).contains(parent_id) {
+            return Err("A folder cannot be moved into itself or one of its subfolders".to_string());
+        }
+    }
+    MeetingFoldersRepository::set_parent(pool, &folder_id, parent_id.as_deref())
+        .await
+        .map_err(|e| folder_error(e, &folder.name))?;
+    Ok(())
+}
+
+/// Delete a folder; its meetings and subfolders move to the folder it was in
+#[command]
+pub async fn delete_library_folder<R: Runtime>(app: AppHandle<R>, folder_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let deleted = MeetingFoldersRepository::delete(state.db_manager.pool(), &folder_id)
+        .await
+        .map_err(|e| format!("Failed to delete folder: {}", e))?;
+    if !deleted {
+        return Err(format!("Folder {} not found", folder_id));
+    }
+    Ok(())
+}
+
+/// File meetings in a folder, or take them out of any folder with None
+#[command]
+pub async fn move_meetings_to_folder<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_ids: Vec<String>,
+    folder_id: Option<String>,
+) -> Result<u64, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    check_folder_exists(pool, folder_id.as_deref()).await?;
+    MeetingFoldersRepository::move_meetings(pool, &meeting_ids, folder_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to move meetings: {}", e))
+}
+
+#[command]
+pub async fn list_meeting_tags<R: Runtime>(app: AppHandle<R>) -> Result<Vec<TagCount>, String> {
+    let state = app.state::<AppState>();
+    let counts = MeetingTagsRepository::counts(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))?;
+    Ok(counts.into_iter().map(|(tag, meetings)| TagCount { tag, meetings }).collect())
+}
+
+#[command]
+pub async fn get_meeting_tags<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<Vec<String>, String> {
+    let state = app.state::<AppState>();
+    MeetingTagsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))
+}
+
+/// Replace a meeting's tags
+#[command]
+pub async fn set_meeting_tags<R: Runtime>(app: AppHandle<R>, meeting_id: String, tags: Vec<String>) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    MeetingTagsRepository::set_for_meeting(state.db_manager.pool(), &meeting_id, &tags)
+        .await
+        .map_err(|e| format!("Failed to save tags: {}", e))
+}
+
+/// Add tags to several meetings at once
+#[command]
+pub async fn add_meeting_tags<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_ids: Vec<String>,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    MeetingTagsRepository::add_to_meetings(state.db_manager.pool(), &meeting_ids, &tags)
+        .await
+        .map_err(|e| format!("Failed to add tags: {}", e))
+}
+
+#[command]
+pub async fn remove_meeting_tags<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_ids: Vec<String>,
+    tags: Vec<String>,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    MeetingTagsRepository::remove_from_meetings(state.db_manager.pool(), &meeting_ids, &tags)
+        .await
+        .map_err(|e| format!("Failed to remove tags: {}", e))
+}
+
+/// Rename a tag everywhere; meetings that already had `new_tag` keep just one
+#[command]
+pub async fn rename_meeting_tag<R: Runtime>(app: AppHandle<R>, old_tag: String, new_tag: String) -> Result<u64, String> {
+    if normalize_tag(&new_tag).is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    let state = app.state::<AppState>();
+    MeetingTagsRepository::rename(state.db_manager.pool(), &old_tag, &new_tag)
+        .await
+        .map_err(|e| format!("Failed to rename tag: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+    use chrono::TimeZone;
+
+    fn folder(id: &str, parent_id: Option<&str>) -> MeetingFolder {
+        MeetingFolder {
+            id: id.to_string(),
+            name: id.to_string(),
+            parent_id: parent_id.map(str::to_string),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_filter_by_folder_tree_tags_and_title() {
+        let folders = vec![
+            folder("work", None),
+            folder("clients", Some("work")),
+            folder("acme", Some("clients")),
+            folder("personal", None),
+        ];
+        let subtree = folder_and_descendants(&folders, "clients");
+        assert_eq!(subtree, HashSet::from(["clients".to_string(), "acme".to_string()]));
+
+        let created_at = DateTimeUtc(Utc.with_ymd_and_hms(2026, 3, 10, 15, 0, 0).unwrap());
+        let meeting = MeetingModel {
+            id: "meeting-1".to_string(),
+            title: "Acme Kickoff".to_string(),
+            created_at: created_at.clone(),
+            updated_at: created_at,
+            folder_path: None,
+            description: None,
+            summary_language: None,
+            agenda: None,
+            starred: true,
+            library_folder_id: Some("acme".to_string()),
+            scheduled_start: None,
+            duration_seconds: None,
+            platform: None,
+            calendar_event_id: None,
+        };
+        let tags = vec!["sales".to_string(), "q1".to_string()];
+        let matches = |filter: MeetingFilter| ResolvedFilter::new(&filter, &folders).unwrap().matches(&meeting, &tags);
+
+        assert!(matches(MeetingFilter {
+            folder_id: Some("work".to_string()),
+            include_subfolders: true,
+            tags: vec![" Sales ".to_string()],
+            title: Some("kickoff".to_string()),
+            starred: Some(true),
+            ..MeetingFilter::default()
+        }));
+        assert!(!matches(MeetingFilter {
+            folder_id: Some("work".to_string()),
+            include_subfolders: false,
+            ..MeetingFilter::default()
+        }));
+        assert!(!matches(MeetingFilter { unfiled: true, ..MeetingFilter::default() }));
+        assert!(!matches(MeetingFilter {
+            tags: vec!["sales".to_string(), "hiring".to_string()],
+            ..MeetingFilter::default()
+        }));
+        assert!(validate_folder_name("  ").is_err());
+        assert_eq!(validate_folder_name(" Clients ").unwrap(), "Clients");
+    }
+}
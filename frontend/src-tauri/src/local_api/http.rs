@@ -0,0 +1,263 @@
+// Minimal HTTP/1.1 for the local API
+// Reads one request per connection and writes one response, then closes the connection.
+// Only what local clients need: a query string, headers, and a Content-Length body.
+
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Largest request head accepted
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+/// Largest request body accepted
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+/// How long a client has to send its request
+const READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: String,
+    /// Path without the query string, e.g. "/api/v1/meetings"
+    pub path: String,
+    /// Decoded query parameters, in order
+    pub query: Vec<(String, String)>,
+    /// Header names lowercased
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// First value of a query parameter; empty values count as missing
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params(name).into_iter().next()
+    }
+
+    pub fn params(&self, name: &str) -> Vec<&str> {
+        self.query
+            .iter()
+            .filter(|(key, value)| key == name && !value.trim().is_empty())
+            .map(|(_, value)| value.trim())
+            .collect()
+    }
+
+    /// The bearer token of the Authorization header
+    pub fn bearer_token(&self) -> Option<&str> {
+        let authorization = self.header("authorization")?.trim();
+        let (scheme, token) = authorization.split_once(' ')?;
+        scheme.eq_ignore_ascii_case("bearer").then(|| token.trim())
+    }
+}
+
+/// Parse a request head (request line and headers)
+pub fn parse_request(head: &str) -> Result<Request, String> {
+    let mut lines = head.split("\r\n");
+    let request_line = lines.next().unwrap_or_default();
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(target), Some(version)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err("Malformed request line".to_string());
+    };
+    if !version.starts_with("HTTP/1.") || !target.starts_with('/') {
+        return Err("Malformed request line".to_string());
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let headers = lines
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+    Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        query: url::form_urlencoded::parse(query.as_bytes()).into_owned().collect(),
+        headers,
+        body: Vec::new(),
+    })
+}
+
+async fn read_more(stream: &mut TcpStream, received: &mut Vec<u8>) -> Result<(), Response> {
+    let mut buffer = [0u8; 4096];
+    let read = stream
+        .read(&mut buffer)
+        .await
+        .map_err(|_| Response::error(400, "Failed to read the request"))?;
+    if read == 0 {
+        return Err(Response::error(400, "Incomplete request"));
+    }
+    received.extend_from_slice(&buffer[..read]);
+    Ok(())
+}
+
+/// Read and parse the request sent on `stream`
+pub async fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let read = async {
+        let mut received = Vec::new();
+        let head_end = loop {
+            if let Some(end) = received.windows(4).position(|window| window == b"\r\n\r\n") {
+                break end;
+            }
+            if received.len() > MAX_HEAD_BYTES {
+                return Err(Response::error(431, "Request headers are too large"));
+            }
+            read_more(stream, &mut received).await?;
+        };
+        let mut request =
+            parse_request(&String::from_utf8_lossy(&received[..head_end])).map_err(|e| Response::error(400, &e))?;
+
+        if request.header("transfer-encoding").is_some() {
+            return Err(Response::error(411, "Send the body with a Content-Length"));
+        }
+        let length = match request.header("content-length") {
+            Some(length) => length.parse().map_err(|_| Response::error(400, "Invalid Content-Length"))?,
+            None => 0,
+        };
+        if length > MAX_BODY_BYTES {
+            return Err(Response::error(413, "Request body is too large"));
+        }
+        let body_start = head_end + 4;
+        while received.len() < body_start + length {
+            read_more(stream, &mut received).await?;
+        }
+        request.body = received[body_start..body_start + length].to_vec();
+        Ok(request)
+    };
+    tokio::time::timeout(READ_TIMEOUT, read)
+        .await
+        .map_err(|_| Response::error(408, "Timed out reading the request"))?
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: Vec<u8>,
+    /// Sent as an attachment with this file name
+    pub filename: Option<String>,
+}
+
+impl Response {
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Self::bytes("application/json", body),
+            Err(e) => Self::error(500, &format!("Failed to serialize the response: {}", e)),
+        }
+    }
+
+    pub fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            ..Self::json(&serde_json::json!({ "error": message }))
+        }
+    }
+
+    pub fn bytes(content_type: &'static str, body: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body,
+            filename: None,
+        }
+    }
+
+    /// 202 with no body, the answer to a message that expects no reply
+    pub fn accepted() -> Self {
+        Self {
+            status: 202,
+            ..Self::bytes("application/json", Vec::new())
+        }
+    }
+
+    pub fn attachment(mut self, filename: String) -> Self {
+        self.filename = Some(filename);
+        self
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            202 => "Accepted",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            426 => "Upgrade Required",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// Status line, headers and body
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n",
+            self.status,
+            self.reason(),
+            self.content_type,
+            self.body.len()
+        );
+        if self.status == 401 {
+            head.push_str("WWW-Authenticate: Bearer\r\n");
+        }
+        if let Some(filename) = &self.filename {
+            // Quotes and line breaks would end the header value early
+            let filename: String = filename.chars().filter(|c| *c != '"' && !c.is_control()).collect();
+            head.push_str(&format!("Content-Disposition: attachment; filename=\"{}\"\r\n", filename));
+        }
+        head.push_str("\r\n");
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+
+    pub async fn write(&self, stream: &mut TcpStream) {
+        let _ = stream.write_all(&self.to_bytes()).await;
+        let _ = stream.shutdown().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_requests_and_renders_responses() {
+        let request = parse_request(
+            "GET /api/v1/search?q=data+migration&tag=sales&tag=q3%20review&limit= HTTP/1.1\r\n\
+             Host: 127.0.0.1:5170\r\nAuthorization: Bearer  abc123 \r\n",
+        )
+        .unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/api/v1/search");
+        assert_eq!(request.param("q"), Some("data migration"));
+        assert_eq!(request.params("tag"), ["sales", "q3 review"]);
+        assert_eq!(request.param("limit"), None);
+        assert_eq!(request.header("host"), Some("127.0.0.1:5170"));
+        assert_eq!(request.bearer_token(), Some("abc123"));
+        assert!(parse_request("GET api HTTP/1.1").is_err());
+        assert!(parse_request("hello").is_err());
+
+        let response = Response::error(404, "Meeting not found").to_bytes();
+        assert_eq!(
+            String::from_utf8(response).unwrap(),
+            "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 29\r\nCache-Control: no-store\r\n\
+             Connection: close\r\n\r\n{\"error\":\"Meeting not found\"}"
+        );
+        let download = Response::bytes("text/markdown; charset=utf-8", b"# Hi".to_vec())
+            .attachment("2026-02-10 \"Sync\".md".to_string())
+            .to_bytes();
+        assert!(String::from_utf8(download)
+            .unwrap()
+            .contains("Content-Disposition: attachment; filename=\"2026-02-10 Sync.md\"\r\n"));
+    }
+}
@@ -0,0 +1,268 @@
+// Live transcript stream
+// GET /api/v1/live upgrades to a WebSocket that pushes the current recording's transcript as
+// it is produced, for caption overlays and note apps. Every message is a JSON text frame
+// {"type": …, "data": …}:
+//   snapshot            on connect: recording, meeting_name and the segments so far
+//   segment             a final transcript segment, as the app's transcript-update event
+//   partial             interim caption of speech still in progress
+//   segment_final       settles a segment's interim captions (or discards them)
+//   recording_started, recording_paused, recording_resumed, recording_stopped
+//   lagged              the client read too slowly and `skipped` messages were dropped
+// Browsers can't set headers on a WebSocket, so the token may also be sent as ?token=.
+// ?partials=false leaves out the interim captions.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+use sha1::{Digest, Sha1};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Listener, Runtime};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::{broadcast, mpsc};
+
+use super::http::{Request, Response};
+use super::routes::{self, Route};
+use crate::audio::recording_commands::{get_recording_meeting_name, get_transcript_history, is_recording};
+
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+/// App events sent to clients, and the message type each becomes
+const FORWARDED_EVENTS: [(&str, &str); 7] = [
+    ("transcript-update", "segment"),
+    ("transcript-partial", "partial"),
+    ("transcript-segment-final", "segment_final"),
+    ("recording-started", "recording_started"),
+    ("recording-paused", "recording_paused"),
+    ("recording-resumed", "recording_resumed"),
+    ("recording-stopped", "recording_stopped"),
+];
+/// Most clients connected at once
+const MAX_CLIENTS: usize = 16;
+/// Largest frame accepted from a client; clients only need to send control frames
+const MAX_CLIENT_FRAME: u64 = 64 * 1024;
+/// Pings let a client that vanished be noticed while nothing is being said
+const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xA;
+
+/// A message serialized once for every connected client
+#[derive(Debug, Clone)]
+struct LiveMessage {
+    partial: bool,
+    text: Arc<str>,
+}
+
+static MESSAGES: Lazy<broadcast::Sender<LiveMessage>> = Lazy::new(|| broadcast::channel(256).0);
+
+fn live_message(kind: &str, data: Value) -> String {
+    json!({ "type": kind, "data": data }).to_string()
+}
+
+/// Pass transcript and recording events on to connected clients; call once at startup
+pub fn forward_events<R: Runtime>(app: &AppHandle<R>) {
+    for (event, kind) in FORWARDED_EVENTS {
+        app.listen(event, move |event: tauri::Event| {
+            if MESSAGES.receiver_count() == 0 {
+                return;
+            }
+            let data = serde_json::from_str(event.payload()).unwrap_or(Value::Null);
+            let _ = MESSAGES.send(LiveMessage {
+                partial: kind == "partial",
+                text: live_message(kind, data).into(),
+            });
+        });
+    }
+}
+
+/// The request asks to switch to the WebSocket protocol
+pub fn is_upgrade(request: &Request) -> bool {
+    request
+        .header("upgrade")
+        .is_some_and(|upgrade| upgrade.eq_ignore_ascii_case("websocket"))
+}
+
+/// Sec-WebSocket-Accept for a client's Sec-WebSocket-Key
+fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.trim().as_bytes());
+    hasher.update(WEBSOCKET_GUID.as_bytes());
+    STANDARD.encode(hasher.finalize())
+}
+
+/// The 101 response completing the opening handshake
+fn handshake(request: &Request) -> Result<Vec<u8>, Response> {
+    if request.header("sec-websocket-version") != Some("13") {
+        return Err(Response::error(400, "Only WebSocket version 13 is supported"));
+    }
+    let key = request
+        .header("sec-websocket-key")
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| Response::error(400, "Missing Sec-WebSocket-Key header"))?;
+    Ok(format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(key)
+    )
+    .into_bytes())
+}
+
+/// An unfragmented server frame; servers never mask
+fn frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        length @ 0..=125 => frame.push(length as u8),
+        length @ 126..=0xFFFF => {
+            frame.push(126);
+            frame.extend_from_slice(&(length as u16).to_be_bytes());
+        }
+        length => {
+            frame.push(127);
+            frame.extend_from_slice(&(length as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Read one client frame: its opcode and unmasked payload
+async fn read_frame<S: AsyncRead + Unpin>(stream: &mut S) -> std::io::Result<(u8, Vec<u8>)> {
+    let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string());
+    let mut head = [0u8; 2];
+    stream.read_exact(&mut head).await?;
+    if head[1] & 0x80 == 0 {
+        return Err(invalid("Client frames must be masked"));
+    }
+    let length = match head[1] & 0x7F {
+        126 => stream.read_u16().await? as u64,
+        127 => stream.read_u64().await?,
+        length => length as u64,
+    };
+    if length > MAX_CLIENT_FRAME {
+        return Err(invalid("Frame too large"));
+    }
+    let mut mask = [0u8; 4];
+    stream.read_exact(&mut mask).await?;
+    let mut payload = vec![0u8; length as usize];
+    stream.read_exact(&mut payload).await?;
+    for (i, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[i % 4];
+    }
+    Ok((head[0] & 0x0F, payload))
+}
+
+/// What a client joining mid-meeting has missed
+async fn snapshot() -> String {
+    live_message(
+        "snapshot",
+        json!({
+            "recording": is_recording().await,
+            "meeting_name": get_recording_meeting_name().await.unwrap_or_default(),
+            "segments": get_transcript_history().await.unwrap_or_default(),
+        }),
+    )
+}
+
+/// Answer a WebSocket upgrade request, then stream to the client until it disconnects
+pub async fn serve(mut stream: TcpStream, request: &Request, token: &str) {
+    let accepted = match routes::authorize(request, token) {
+        Ok(Route::Live) if MESSAGES.receiver_count() >= MAX_CLIENTS => {
+            Err(Response::error(503, "Too many live transcript clients are connected"))
+        }
+        Ok(Route::Live) => handshake(request),
+        Ok(_) => Err(Response::error(400, "Only /api/v1/live accepts WebSocket connections")),
+        Err(response) => Err(response),
+    };
+    let handshake = match accepted {
+        Ok(handshake) => handshake,
+        Err(response) => return response.write(&mut stream).await,
+    };
+    let include_partials = request.param("partials").and_then(routes::parse_bool).unwrap_or(true);
+
+    // Subscribe before taking the snapshot so nothing said in between is missed
+    let mut messages = MESSAGES.subscribe();
+    if stream.write_all(&handshake).await.is_err() {
+        return;
+    }
+    if stream.write_all(&frame(OPCODE_TEXT, snapshot().await.as_bytes())).await.is_err() {
+        return;
+    }
+
+    // Frames are read on their own task: a read cut short by select! would lose bytes
+    let (mut reader, mut writer) = stream.into_split();
+    let (frames_tx, mut frames) = mpsc::channel(8);
+    let reader_task = tauri::async_runtime::spawn(async move {
+        while let Ok(frame) = read_frame(&mut reader).await {
+            if frames_tx.send(frame).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut ping = tokio::time::interval(PING_INTERVAL);
+    ping.tick().await;
+    loop {
+        let outgoing = tokio::select! {
+            received = messages.recv() => match received {
+                Ok(message) if message.partial && !include_partials => continue,
+                Ok(message) => frame(OPCODE_TEXT, message.text.as_bytes()),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    frame(OPCODE_TEXT, live_message("lagged", json!({ "skipped": skipped })).as_bytes())
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = frames.recv() => match incoming {
+                Some((OPCODE_PING, payload)) => frame(OPCODE_PONG, &payload),
+                Some((OPCODE_CLOSE, payload)) => {
+                    // Echo the status code to complete the closing handshake
+                    let _ = writer.write_all(&frame(OPCODE_CLOSE, &payload[..payload.len().min(2)])).await;
+                    break;
+                }
+                Some(_) => continue,
+                None => break,
+            },
+            _ = ping.tick() => frame(OPCODE_PING, b""),
+        };
+        if writer.write_all(&outgoing).await.is_err() {
+            break;
+        }
+    }
+    reader_task.abort();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_api::http::parse_request;
+
+    #[tokio::test]
+    async fn speaks_the_websocket_protocol() {
+        // Example from RFC 6455
+        assert_eq!(accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        let request = parse_request(
+            "GET /api/v1/live?token=abc HTTP/1.1\r\nHost: localhost:5170\r\nUpgrade: websocket\r\n\
+             Connection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n",
+        )
+        .unwrap();
+        assert!(is_upgrade(&request));
+        let response = String::from_utf8(handshake(&request).unwrap()).unwrap();
+        assert!(response.starts_with("HTTP/1.1 101 Switching Protocols\r\n"));
+        assert!(response.contains("Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n"));
+
+        assert_eq!(frame(OPCODE_TEXT, b"Hi"), [0x81, 2, b'H', b'i']);
+        let long = frame(OPCODE_TEXT, &[b'a'; 300]);
+        assert_eq!(long[..4], [0x81, 126, 1, 44]);
+        assert_eq!(long.len(), 304);
+
+        // A masked "Hello" from a client, then a close frame with status 1000
+        let mut client: &[u8] = &[
+            0x81, 0x85, 0x37, 0xfa, 0x21, 0x3d, 0x7f, 0x9f, 0x4d, 0x51, 0x58, 0x88, 0x82, 0, 0, 0, 0, 0x03, 0xe8,
+        ];
+        assert_eq!(read_frame(&mut client).await.unwrap(), (OPCODE_TEXT, b"Hello".to_vec()));
+        assert_eq!(read_frame(&mut client).await.unwrap(), (OPCODE_CLOSE, vec![0x03, 0xe8]));
+        let mut unmasked: &[u8] = &[0x81, 0x02, b'H', b'i'];
+        assert!(read_frame(&mut unmasked).await.is_err());
+    }
+}
@@ -0,0 +1,347 @@
+// MCP server
+// Lets AI assistants that speak the Model Context Protocol search and read the meeting
+// library. It uses MCP's Streamable HTTP transport at POST /api/v1/mcp, answering each
+// JSON-RPC message with plain JSON, and takes the local API's bearer token. Clients that
+// only start stdio servers can reach it through a bridge, e.g.
+//   npx mcp-remote http://127.0.0.1:5170/api/v1/mcp --header "Authorization: Bearer <token>"
+// The tools only read, and locked meetings stay unreadable.
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+use super::http::Response;
+use super::routes::transcript_text;
+use crate::database::repositories::action_item::ActionItemsRepository;
+use crate::database::repositories::meeting_tag::MeetingTagsRepository;
+use crate::database::search;
+use crate::export::{load_document, load_export_settings};
+use crate::library::{matching_meetings, MeetingFilter};
+
+/// Protocol revisions understood, newest first
+const PROTOCOL_VERSIONS: [&str; 3] = ["2025-06-18", "2025-03-26", "2024-11-05"];
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+const DEFAULT_LIST_LIMIT: usize = 50;
+const MAX_LIST_LIMIT: usize = 200;
+
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    /// None for notifications, which get no reply
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCall {
+    name: String,
+    #[serde(default)]
+    arguments: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchArgs {
+    query: String,
+    #[serde(default)]
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListArgs {
+    #[serde(flatten)]
+    filter: MeetingFilter,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MeetingArgs {
+    meeting_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActionItemArgs {
+    #[serde(default)]
+    meeting_id: Option<String>,
+    #[serde(default)]
+    include_done: bool,
+}
+
+fn tools() -> Value {
+    let meeting_id = json!({
+        "type": "object",
+        "properties": { "meeting_id": { "type": "string", "description": "Id from search_meetings or list_meetings" } },
+        "required": ["meeting_id"],
+    });
+    json!([
+        {
+            "name": "search_meetings",
+            "description": "Full-text search over every meeting's transcript and summary. Returns the best \
+                matching passages with their meeting id and title, speaker and time.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "Words to find; \"quoted phrases\" match exactly" },
+                    "limit": { "type": "integer", "minimum": 1, "maximum": 200 },
+                },
+                "required": ["query"],
+            },
+        },
+        {
+            "name": "list_meetings",
+            "description": "List meetings, newest first, optionally filtered by title, tags, starred or date range.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string", "description": "Text the title contains" },
+                    "tags": { "type": "array", "items": { "type": "string" }, "description": "Tags the meeting has, all of them" },
+                    "starred": { "type": "boolean" },
+                    "from_date": { "type": "string", "description": "First day, YYYY-MM-DD" },
+                    "to_date": { "type": "string", "description": "Last day, YYYY-MM-DD" },
+                    "limit": { "type": "integer", "minimum": 1, "maximum": 200 },
+                },
+            },
+        },
+        {
+            "name": "get_meeting",
+            "description": "A meeting's title, date, tags, minutes (markdown summary) and action items.",
+            "inputSchema": meeting_id,
+        },
+        {
+            "name": "get_transcript",
+            "description": "A meeting's full transcript, one line per segment with its time and speaker.",
+            "inputSchema": meeting_id,
+        },
+        {
+            "name": "get_action_items",
+            "description": "Action items of one meeting, or the open action items of every meeting when no \
+                meeting_id is given, those due soonest first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "meeting_id": { "type": "string" },
+                    "include_done": { "type": "boolean", "description": "Also list completed items" },
+                },
+            },
+        },
+    ])
+}
+
+fn arguments<T: DeserializeOwned>(arguments: Value) -> Result<T, String> {
+    // A tool without parameters may be called with no arguments at all
+    let arguments = if arguments.is_null() { json!({}) } else { arguments };
+    serde_json::from_value(arguments).map_err(|e| format!("Invalid arguments: {}", e))
+}
+
+fn pretty(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_default()
+}
+
+async fn search_meetings(pool: &SqlitePool, args: SearchArgs) -> Result<String, String> {
+    let hits = search::search(pool, &args.query, args.limit).await?;
+    if hits.is_empty() {
+        return Ok(format!("No meetings mention \"{}\".", args.query));
+    }
+    Ok(pretty(&json!(hits)))
+}
+
+async fn list_meetings(pool: &SqlitePool, args: ListArgs) -> Result<String, String> {
+    let limit = args.limit.unwrap_or(DEFAULT_LIST_LIMIT).clamp(1, MAX_LIST_LIMIT);
+    let meetings = matching_meetings(pool, &args.filter).await?;
+    let total = meetings.len();
+    let meetings: Vec<Value> = meetings
+        .into_iter()
+        .take(limit)
+        .map(|meeting| {
+            json!({
+                "id": meeting.id,
+                "title": meeting.title,
+                "created_at": meeting.created_at.to_rfc3339(),
+                "tags": meeting.tags,
+                "starred": meeting.starred,
+            })
+        })
+        .collect();
+    Ok(pretty(&json!({ "total": total, "meetings": meetings })))
+}
+
+async fn get_meeting(pool: &SqlitePool, args: MeetingArgs) -> Result<String, String> {
+    let settings = load_export_settings(pool).await?;
+    let document = load_document(pool, &args.meeting_id, &settings).await?;
+    let tags = MeetingTagsRepository::list_for_meeting(pool, &document.id)
+        .await
+        .map_err(|e| format!("Failed to load tags: {}", e))?;
+    Ok(pretty(&json!({
+        "id": document.id,
+        "title": document.title,
+        "created_at": document.created_at.to_rfc3339(),
+        "description": document.description,
+        "tags": tags,
+        "summary": document.summary,
+        "action_items": document.action_items,
+    })))
+}
+
+async fn get_transcript(pool: &SqlitePool, args: MeetingArgs) -> Result<String, String> {
+    let settings = load_export_settings(pool).await?;
+    let document = load_document(pool, &args.meeting_id, &settings).await?;
+    let transcript = transcript_text(&document.segments);
+    if transcript.is_empty() {
+        return Ok(format!("\"{}\" has no transcript.", document.title));
+    }
+    Ok(format!("Transcript of \"{}\" ({})\n\n{}", document.title, document.created_at.format("%Y-%m-%d"), transcript))
+}
+
+async fn get_action_items(pool: &SqlitePool, args: ActionItemArgs) -> Result<String, String> {
+    let items = match &args.meeting_id {
+        Some(meeting_id) => {
+            let settings = load_export_settings(pool).await?;
+            load_document(pool, meeting_id, &settings).await?.action_items
+        }
+        None => ActionItemsRepository::list_open(pool)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?,
+    };
+    let titles: HashMap<String, String> = matching_meetings(pool, &MeetingFilter::default())
+        .await?
+        .into_iter()
+        .map(|meeting| (meeting.id, meeting.title))
+        .collect();
+    let items: Vec<Value> = items
+        .into_iter()
+        .filter(|item| args.include_done || !item.done)
+        .map(|item| {
+            json!({
+                "description": item.description,
+                "assignee": item.assignee,
+                "due_date": item.due_date,
+                "done": item.done,
+                "meeting_id": item.meeting_id,
+                "meeting_title": titles.get(&item.meeting_id),
+            })
+        })
+        .collect();
+    if items.is_empty() {
+        return Ok("No action items.".to_string());
+    }
+    Ok(pretty(&json!(items)))
+}
+
+async fn call_tool(pool: &SqlitePool, name: &str, args: Value) -> Result<String, String> {
+    match name {
+        "search_meetings" => search_meetings(pool, arguments(args)?).await,
+        "list_meetings" => list_meetings(pool, arguments(args)?).await,
+        "get_meeting" => get_meeting(pool, arguments(args)?).await,
+        "get_transcript" => get_transcript(pool, arguments(args)?).await,
+        "get_action_items" => get_action_items(pool, arguments(args)?).await,
+        _ => Err(format!("Unknown tool {}", name)),
+    }
+}
+
+fn initialize(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = requested
+        .filter(|requested| PROTOCOL_VERSIONS.contains(requested))
+        .unwrap_or(PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": { "listChanged": false } },
+        "serverInfo": { "name": "meetily", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": "Meetily holds the user's recorded meetings: transcripts, minutes and action items. \
+            Find meetings with search_meetings or list_meetings, then read them with get_meeting or get_transcript.",
+    })
+}
+
+async fn dispatch(pool: &SqlitePool, method: &str, params: Value) -> Result<Value, (i64, String)> {
+    match method {
+        "initialize" => Ok(initialize(&params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tools() })),
+        "tools/call" => {
+            let call: ToolCall =
+                serde_json::from_value(params).map_err(|e| (INVALID_PARAMS, format!("Invalid tool call: {}", e)))?;
+            if !tools().as_array().is_some_and(|tools| tools.iter().any(|tool| tool["name"] == call.name.as_str())) {
+                return Err((INVALID_PARAMS, format!("Unknown tool {}", call.name)));
+            }
+            // Tool failures go back to the model as results it can read and act on
+            let (text, is_error) = match call_tool(pool, &call.name, call.arguments).await {
+                Ok(text) => (text, false),
+                Err(error) => (error, true),
+            };
+            Ok(json!({ "content": [{ "type": "text", "text": text }], "isError": is_error }))
+        }
+        _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+    }
+}
+
+fn rpc_error(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+/// Answer one JSON-RPC message posted to the MCP endpoint
+pub async fn handle(pool: &SqlitePool, body: &[u8]) -> Response {
+    let invalid = |code: i64, message: &str| Response {
+        status: 400,
+        ..Response::json(&rpc_error(Value::Null, code, message))
+    };
+    let message: Value = match serde_json::from_slice(body) {
+        Ok(message) => message,
+        Err(e) => return invalid(PARSE_ERROR, &format!("Invalid JSON: {}", e)),
+    };
+    if message.is_array() {
+        return invalid(INVALID_REQUEST, "Batched messages are not supported");
+    }
+    // Responses to server requests are never expected, so they need no handling either
+    if message.get("method").is_none() && (message.get("result").is_some() || message.get("error").is_some()) {
+        return Response::accepted();
+    }
+    let request: RpcRequest = match serde_json::from_value(message) {
+        Ok(request) => request,
+        Err(e) => return invalid(INVALID_REQUEST, &format!("Invalid JSON-RPC request: {}", e)),
+    };
+    let Some(id) = request.id else {
+        return Response::accepted();
+    };
+    match dispatch(pool, &request.method, request.params).await {
+        Ok(result) => Response::json(&json!({ "jsonrpc": "2.0", "id": id, "result": result })),
+        Err((code, message)) => Response::json(&rpc_error(id, code, &message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiates_and_describes_tools() {
+        assert_eq!(initialize(&json!({ "protocolVersion": "2025-03-26" }))["protocolVersion"], "2025-03-26");
+        assert_eq!(initialize(&json!({ "protocolVersion": "2099-01-01" }))["protocolVersion"], PROTOCOL_VERSIONS[0]);
+        assert_eq!(initialize(&Value::Null)["serverInfo"]["name"], "meetily");
+
+        let tools = tools();
+        let names: Vec<&str> = tools.as_array().unwrap().iter().map(|tool| tool["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["search_meetings", "list_meetings", "get_meeting", "get_transcript", "get_action_items"]);
+        assert!(tools.as_array().unwrap().iter().all(|tool| tool["inputSchema"]["type"] == "object"));
+
+        let list: ListArgs = arguments(json!({ "tags": ["sales"], "starred": true, "limit": 5 })).unwrap();
+        assert_eq!(list.filter.tags, ["sales"]);
+        assert_eq!(list.filter.starred, Some(true));
+        assert_eq!(list.limit, Some(5));
+        let items: ActionItemArgs = arguments(Value::Null).unwrap();
+        assert!(items.meeting_id.is_none() && !items.include_done);
+        assert!(arguments::<MeetingArgs>(json!({})).unwrap_err().contains("meeting_id"));
+        assert_eq!(
+            rpc_error(json!(7), METHOD_NOT_FOUND, "Method not found: x"),
+            json!({ "jsonrpc": "2.0", "id": 7, "error": { "code": -32601, "message": "Method not found: x" } })
+        );
+    }
+}
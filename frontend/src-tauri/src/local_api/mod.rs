@@ -0,0 +1,203 @@
+// Local REST API
+// An optional HTTP server on 127.0.0.1 for scripts and other tools on the same machine to
+// read meetings, transcripts and search results, and to export meetings, without opening
+// the database. It is off by default. Every endpoint but /api/v1/status needs the API token
+// generated in the settings, and it never listens on other network interfaces. The same
+// port serves a WebSocket with the live transcript of the current recording, and an MCP
+// endpoint for AI assistants.
+
+pub mod http;
+pub mod live;
+pub mod mcp;
+pub mod routes;
+
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::{self, AppState};
+
+fn default_port() -> u16 {
+    5170
+}
+
+/// Local API settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalApiSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Bearer token clients send; generated on first save
+    #[serde(default)]
+    pub token: String,
+}
+
+impl Default for LocalApiSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: default_port(),
+            token: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LocalApiStatus {
+    pub running: bool,
+    /// e.g. "http://127.0.0.1:5170/api/v1"
+    pub url: Option<String>,
+    /// Why the server could not start
+    pub error: Option<String>,
+}
+
+struct Server {
+    address: SocketAddr,
+    task: tauri::async_runtime::JoinHandle<()>,
+}
+
+static SERVER: Lazy<Mutex<Option<Server>>> = Lazy::new(|| Mutex::new(None));
+static START_ERROR: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+
+fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn status() -> LocalApiStatus {
+    match SERVER.lock().unwrap().as_ref() {
+        Some(server) => LocalApiStatus {
+            running: true,
+            url: Some(format!("http://{}/api/v1", server.address)),
+            error: None,
+        },
+        None => LocalApiStatus {
+            error: START_ERROR.lock().unwrap().clone(),
+            ..Default::default()
+        },
+    }
+}
+
+fn stop() {
+    if let Some(server) = SERVER.lock().unwrap().take() {
+        server.task.abort();
+        info!("Local API stopped");
+    }
+}
+
+/// Start the server with `settings`, replacing a running one; off when not enabled
+async fn restart<R: Runtime>(app: &AppHandle<R>, settings: &LocalApiSettings) {
+    stop();
+    *START_ERROR.lock().unwrap() = None;
+    if !settings.enabled || settings.token.is_empty() {
+        return;
+    }
+
+    let listener = match TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, settings.port))).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            warn!("Failed to start the local API on port {}: {}", settings.port, e);
+            *START_ERROR.lock().unwrap() = Some(format!("Port {} is not available: {}", settings.port, e));
+            return;
+        }
+    };
+    let address = listener.local_addr().unwrap_or_else(|_| SocketAddr::from((Ipv4Addr::LOCALHOST, settings.port)));
+    let app = app.clone();
+    let token = settings.token.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        loop {
+            let mut stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    // Errors like running out of file descriptors persist, so back off
+                    warn!("Local API failed to accept a connection: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    continue;
+                }
+            };
+            let app = app.clone();
+            let token = token.clone();
+            tauri::async_runtime::spawn(async move {
+                let response = match http::read_request(&mut stream).await {
+                    Ok(request) if live::is_upgrade(&request) => return live::serve(stream, &request, &token).await,
+                    Ok(request) => match app.try_state::<AppState>() {
+                        Some(state) => routes::handle(state.db_manager.pool(), &request, &token).await,
+                        None => http::Response::error(500, "Meetily is still starting"),
+                    },
+                    Err(response) => response,
+                };
+                response.write(&mut stream).await;
+            });
+        }
+    });
+    info!("Local API listening on http://{}", address);
+    *SERVER.lock().unwrap() = Some(Server { address, task });
+}
+
+async fn load_settings(pool: &sqlx::SqlitePool) -> Result<LocalApiSettings, String> {
+    SettingsRepository::get_local_api_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load local API settings: {}", e))
+}
+
+/// Start the local API at launch when it is enabled, and relay live transcript events to it
+pub fn start_local_api<R: Runtime>(app: AppHandle<R>) {
+    live::forward_events(&app);
+    tauri::async_runtime::spawn(async move {
+        let Some(pool) = state::try_pool(&app) else {
+            return;
+        };
+        match load_settings(&pool).await {
+            Ok(settings) => restart(&app, &settings).await,
+            Err(e) => warn!("{}", e),
+        }
+    });
+}
+
+#[command]
+pub async fn get_local_api_settings<R: Runtime>(app: AppHandle<R>) -> Result<LocalApiSettings, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+/// Save the settings and start, restart or stop the server to match
+#[command]
+pub async fn save_local_api_settings<R: Runtime>(app: AppHandle<R>, mut settings: LocalApiSettings) -> Result<LocalApiStatus, String> {
+    if settings.port < 1024 {
+        return Err("Choose a port from 1024 to 65535".to_string());
+    }
+    if settings.token.trim().is_empty() {
+        settings.token = generate_token();
+    }
+    let state = app.state::<AppState>();
+    SettingsRepository::save_local_api_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save local API settings: {}", e))?;
+    restart(&app, &settings).await;
+    Ok(status())
+}
+
+/// Replace the API token; clients using the old one are refused from now on
+#[command]
+pub async fn regenerate_local_api_token<R: Runtime>(app: AppHandle<R>) -> Result<String, String> {
+    let pool = app.state::<AppState>().db_manager.pool().clone();
+    let mut settings = load_settings(&pool).await?;
+    settings.token = generate_token();
+    save_local_api_settings(app, settings.clone()).await?;
+    Ok(settings.token)
+}
+
+#[command]
+pub async fn get_local_api_status() -> Result<LocalApiStatus, String> {
+    Ok(status())
+}
@@ -0,0 +1,335 @@
+// Local API endpoints
+// Read-only access to the meeting library, under /api/v1:
+//   GET /status                              app name and version; needs no token
+//   GET /meetings                            library list; filters as query parameters
+//   GET /meetings/{id}                       details, minutes and action items
+//   GET /meetings/{id}/transcript            segments as JSON, or ?format=txt
+//   GET /meetings/{id}/export/{format}       markdown, html, pdf, docx, json, srt or vtt
+//   GET /search?q=…                          full-text search hits
+//   GET /live                                WebSocket stream of the live transcript (see live.rs)
+//   POST /mcp                                Model Context Protocol server (see mcp.rs)
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+
+use super::http::{Request, Response};
+use crate::database::models::ActionItem;
+use crate::database::repositories::meeting_tag::MeetingTagsRepository;
+use crate::database::search;
+use crate::export::bulk::{self, BulkExportFormat};
+use crate::export::document::{ExportSegment, MeetingDocument};
+use crate::export::json::MeetingRecord;
+use crate::export::markdown::DEFAULT_MARKDOWN_TEMPLATE;
+use crate::export::subtitles::{render_subtitles, SubtitleFormat};
+use crate::export::{load_document, load_export_settings};
+use crate::library::{matching_meetings, MeetingFilter};
+use crate::utils::format_timestamp;
+
+const DEFAULT_PAGE_SIZE: usize = 100;
+const MAX_PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, PartialEq)]
+pub(super) enum Route<'a> {
+    Status,
+    Meetings,
+    Meeting(&'a str),
+    Transcript(&'a str),
+    Export(&'a str, &'a str),
+    Search,
+    Live,
+    Mcp,
+}
+
+fn route(path: &str) -> Option<Route<'_>> {
+    let rest = path.strip_prefix("/api/v1/")?;
+    let segments: Vec<&str> = rest.trim_end_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["status"] => Some(Route::Status),
+        ["meetings"] => Some(Route::Meetings),
+        ["meetings", id] => Some(Route::Meeting(id)),
+        ["meetings", id, "transcript"] => Some(Route::Transcript(id)),
+        ["meetings", id, "export", format] => Some(Route::Export(id, format)),
+        ["search"] => Some(Route::Search),
+        ["live"] => Some(Route::Live),
+        ["mcp"] => Some(Route::Mcp),
+        _ => None,
+    }
+}
+
+/// Only requests addressed to this machine; a page on another site that resolves its own
+/// host name to 127.0.0.1 (DNS rebinding) still sends that host name
+fn is_local_host(host: Option<&str>) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    let name = match host.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    matches!(name.to_ascii_lowercase().as_str(), "127.0.0.1" | "localhost" | "::1")
+}
+
+/// Compare tokens without returning early at the first differing byte
+fn token_matches(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+/// A command error as an HTTP error
+fn failure(error: String) -> Response {
+    let status = if error.contains("not found") {
+        404
+    } else if error.contains("password-protected") {
+        403
+    } else if error.contains("no timestamped transcript") {
+        422
+    } else {
+        500
+    };
+    Response::error(status, &error)
+}
+
+pub(super) fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" => Some(true),
+        "false" | "0" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Library filter from query parameters named like `MeetingFilter`'s fields
+fn meeting_filter(request: &Request) -> Result<MeetingFilter, String> {
+    let flag = |name: &str| {
+        request
+            .param(name)
+            .map(|value| parse_bool(value).ok_or_else(|| format!("{} must be true or false", name)))
+            .transpose()
+    };
+    Ok(MeetingFilter {
+        folder_id: request.param("folder_id").map(str::to_string),
+        include_subfolders: flag("include_subfolders")?.unwrap_or(true),
+        unfiled: flag("unfiled")?.unwrap_or(false),
+        tags: request.params("tag").into_iter().map(str::to_string).collect(),
+        starred: flag("starred")?,
+        from_date: request.param("from_date").map(str::to_string),
+        to_date: request.param("to_date").map(str::to_string),
+        title: request.param("title").map(str::to_string),
+    })
+}
+
+fn number_param(request: &Request, name: &str, default: usize) -> Result<usize, String> {
+    request
+        .param(name)
+        .map(|value| value.parse().map_err(|_| format!("{} must be a number", name)))
+        .unwrap_or(Ok(default))
+}
+
+#[derive(Serialize)]
+struct MeetingDetails<'a> {
+    #[serde(flatten)]
+    meeting: MeetingRecord<'a>,
+    tags: Vec<String>,
+    /// Minutes markdown
+    summary: Option<&'a str>,
+    action_items: &'a [ActionItem],
+}
+
+pub(super) fn transcript_text(segments: &[ExportSegment]) -> String {
+    segments
+        .iter()
+        .filter(|segment| !segment.text.is_empty())
+        .map(|segment| {
+            let time = segment.start.map(|start| format!("[{}] ", format_timestamp(start))).unwrap_or_default();
+            match &segment.speaker {
+                Some(speaker) => format!("{}{}: {}\n", time, speaker, segment.text),
+                None => format!("{}{}\n", time, segment.text),
+            }
+        })
+        .collect()
+}
+
+async fn load_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<MeetingDocument, Response> {
+    let settings = load_export_settings(pool).await.map_err(failure)?;
+    load_document(pool, meeting_id, &settings).await.map_err(failure)
+}
+
+async fn list_meetings(pool: &SqlitePool, request: &Request) -> Result<Response, Response> {
+    let bad_request = |e: String| Response::error(400, &e);
+    let filter = meeting_filter(request).map_err(bad_request)?;
+    let limit = number_param(request, "limit", DEFAULT_PAGE_SIZE).map_err(bad_request)?.clamp(1, MAX_PAGE_SIZE);
+    let offset = number_param(request, "offset", 0).map_err(bad_request)?;
+
+    let meetings = matching_meetings(pool, &filter).await.map_err(|e| Response::error(400, &e))?;
+    let total = meetings.len();
+    let page: Vec<_> = meetings.into_iter().skip(offset).take(limit).collect();
+    Ok(Response::json(&json!({ "total": total, "offset": offset, "meetings": page })))
+}
+
+async fn meeting_details(pool: &SqlitePool, meeting_id: &str) -> Result<Response, Response> {
+    let document = load_meeting(pool, meeting_id).await?;
+    let tags = MeetingTagsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| failure(format!("Failed to load tags: {}", e)))?;
+    Ok(Response::json(&MeetingDetails {
+        meeting: MeetingRecord::new(&document),
+        tags,
+        summary: document.summary.as_deref(),
+        action_items: &document.action_items,
+    }))
+}
+
+async fn transcript(pool: &SqlitePool, meeting_id: &str, request: &Request) -> Result<Response, Response> {
+    let document = load_meeting(pool, meeting_id).await?;
+    match request.param("format").unwrap_or("json") {
+        "json" => Ok(Response::json(&json!({ "meeting_id": document.id, "segments": document.segments }))),
+        "txt" => Ok(Response::bytes("text/plain; charset=utf-8", transcript_text(&document.segments).into_bytes())),
+        other => Err(Response::error(400, &format!("Unknown transcript format '{}'; use json or txt", other))),
+    }
+}
+
+async fn export(pool: &SqlitePool, meeting_id: &str, format: &str) -> Result<Response, Response> {
+    let settings = load_export_settings(pool).await.map_err(failure)?;
+    let document = load_document(pool, meeting_id, &settings).await.map_err(failure)?;
+    let stem = bulk::file_stem(&document.title, document.created_at);
+
+    if let Ok(subtitles) = serde_json::from_value::<SubtitleFormat>(Value::from(format)) {
+        let contents = render_subtitles(&document, subtitles).map_err(failure)?;
+        let content_type = match subtitles {
+            SubtitleFormat::Srt => "application/x-subrip; charset=utf-8",
+            SubtitleFormat::Vtt => "text/vtt; charset=utf-8",
+        };
+        let filename = format!("{}.{}", stem, subtitles.extension());
+        return Ok(Response::bytes(content_type, contents.into_bytes()).attachment(filename));
+    }
+
+    let Ok(format) = serde_json::from_value::<BulkExportFormat>(Value::from(format)) else {
+        return Err(Response::error(
+            404,
+            &format!("Unknown export format '{}'; use markdown, html, pdf, docx, json, srt or vtt", format),
+        ));
+    };
+    let template = settings.markdown_template.as_deref().unwrap_or(DEFAULT_MARKDOWN_TEMPLATE);
+    let contents = bulk::render(format, &document, template, &settings.pdf).map_err(failure)?;
+    let content_type = match format {
+        BulkExportFormat::Markdown => "text/markdown; charset=utf-8",
+        BulkExportFormat::Html => "text/html; charset=utf-8",
+        BulkExportFormat::Pdf => "application/pdf",
+        BulkExportFormat::Docx => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        BulkExportFormat::Json => "application/json",
+    };
+    let filename = format!("{}.{}", stem, format.extension());
+    Ok(Response::bytes(content_type, contents).attachment(filename))
+}
+
+async fn search_meetings(pool: &SqlitePool, request: &Request) -> Result<Response, Response> {
+    let query = request.param("q").ok_or_else(|| Response::error(400, "Add the search text as ?q="))?;
+    let limit = number_param(request, "limit", 50).map_err(|e| Response::error(400, &e))?;
+    let hits = search::search(pool, query, Some(limit as i64)).await.map_err(failure)?;
+    Ok(Response::json(&hits))
+}
+
+/// Host, method and token checks every request passes before it is routed
+pub(super) fn authorize<'a>(request: &'a Request, token: &str) -> Result<Route<'a>, Response> {
+    if !is_local_host(request.header("host")) {
+        return Err(Response::error(403, "Requests must be addressed to localhost"));
+    }
+    let route = route(&request.path).ok_or_else(|| Response::error(404, "No such endpoint"))?;
+    let method = if route == Route::Mcp { "POST" } else { "GET" };
+    if request.method != method {
+        return Err(Response::error(405, &format!("Use {} for this endpoint", method)));
+    }
+    // MCP clients may run in a browser; only pages served from this machine may call it
+    let origin = request.header("origin").map(|origin| origin.split_once("://").map_or(origin, |(_, host)| host));
+    if route == Route::Mcp && origin.is_some_and(|origin| !is_local_host(Some(origin))) {
+        return Err(Response::error(403, "Requests from other websites are not allowed"));
+    }
+    // Tools may check that Meetily is running before they have a token
+    if route == Route::Status {
+        return Ok(route);
+    }
+    // Browsers can't send headers with a WebSocket request
+    let given = match route {
+        Route::Live => request.bearer_token().or_else(|| request.param("token")),
+        _ => request.bearer_token(),
+    };
+    if !given.is_some_and(|given| token_matches(given, token)) {
+        return Err(Response::error(401, "Send the API token from Meetily's settings as a bearer token"));
+    }
+    Ok(route)
+}
+
+/// Answer one request; `token` is the one clients must send as a bearer token
+pub async fn handle(pool: &SqlitePool, request: &Request, token: &str) -> Response {
+    let route = match authorize(request, token) {
+        Ok(route) => route,
+        Err(response) => return response,
+    };
+
+    let response = match route {
+        Route::Status => Ok(Response::json(&json!({ "app": "meetily", "version": env!("CARGO_PKG_VERSION") }))),
+        Route::Meetings => list_meetings(pool, request).await,
+        Route::Meeting(meeting_id) => meeting_details(pool, meeting_id).await,
+        Route::Transcript(meeting_id) => transcript(pool, meeting_id, request).await,
+        Route::Export(meeting_id, format) => export(pool, meeting_id, format).await,
+        Route::Search => search_meetings(pool, request).await,
+        Route::Live => Err(Response::error(426, "Connect to /api/v1/live with a WebSocket client")),
+        Route::Mcp => Ok(super::mcp::handle(pool, &request.body).await),
+    };
+    response.unwrap_or_else(|error| error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_api::http::parse_request;
+
+    #[test]
+    fn routes_and_checks_requests() {
+        assert_eq!(route("/api/v1/meetings/"), Some(Route::Meetings));
+        assert_eq!(route("/api/v1/meetings/abc"), Some(Route::Meeting("abc")));
+        assert_eq!(route("/api/v1/meetings/abc/transcript"), Some(Route::Transcript("abc")));
+        assert_eq!(route("/api/v1/meetings/abc/export/pdf"), Some(Route::Export("abc", "pdf")));
+        assert_eq!(route("/api/v1/search"), Some(Route::Search));
+        assert_eq!(route("/api/v1/live"), Some(Route::Live));
+        assert_eq!(route("/api/v2/meetings"), None);
+        assert_eq!(route("/api/v1/meetings/abc/audio"), None);
+
+        assert!(is_local_host(Some("127.0.0.1:5170")));
+        assert!(is_local_host(Some("localhost")));
+        assert!(is_local_host(Some("[::1]:5170")));
+        assert!(!is_local_host(Some("attacker.example:5170")));
+        assert!(!is_local_host(None));
+
+        assert!(token_matches("secret", "secret"));
+        assert!(!token_matches("secreT", "secret"));
+        assert!(!token_matches("", "secret"));
+
+        let request =
+            parse_request("GET /api/v1/meetings?tag=sales&starred=yes&include_subfolders=false HTTP/1.1\r\nHost: localhost\r\n").unwrap();
+        let filter = meeting_filter(&request).unwrap();
+        assert_eq!(filter.tags, ["sales"]);
+        assert_eq!(filter.starred, Some(true));
+        assert!(!filter.include_subfolders);
+        let request = parse_request("GET /api/v1/meetings?starred=maybe HTTP/1.1\r\n").unwrap();
+        assert!(meeting_filter(&request).is_err());
+
+        let live = parse_request("GET /api/v1/live?token=secret HTTP/1.1\r\nHost: localhost\r\n").unwrap();
+        assert_eq!(authorize(&live, "secret").unwrap(), Route::Live);
+        let listing = parse_request("GET /api/v1/meetings?token=secret HTTP/1.1\r\nHost: localhost\r\n").unwrap();
+        assert_eq!(authorize(&listing, "secret").unwrap_err().status, 401);
+        let mcp = "POST /api/v1/mcp HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer secret\r\n";
+        assert_eq!(authorize(&parse_request(mcp).unwrap(), "secret").unwrap(), Route::Mcp);
+        let from_site = parse_request(&format!("{}Origin: https://attacker.example\r\n", mcp)).unwrap();
+        assert_eq!(authorize(&from_site, "secret").unwrap_err().status, 403);
+        let get_mcp = parse_request("GET /api/v1/mcp HTTP/1.1\r\nHost: localhost\r\n").unwrap();
+        assert_eq!(authorize(&get_mcp, "secret").unwrap_err().status, 405);
+
+        assert_eq!(failure("Meeting x not found".to_string()).status, 404);
+        assert_eq!(failure("This meeting is password-protected; unlock it first".to_string()).status, 403);
+    }
+}
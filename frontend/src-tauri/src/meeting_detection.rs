@@ -0,0 +1,468 @@
+// Meeting app detection
+// Notices when a call starts in Zoom, Google Meet, Microsoft Teams or Webex and offers to
+// record it. Every few seconds the running processes, window titles and (on Linux) the
+// apps using the microphone are checked against per-app signs of a call in progress:
+// Zoom's meeting window and helper processes, the "Meet - abc-defg-hij" browser tab,
+// Teams' "Meeting with …" windows, Webex's meeting manager. When a call appears,
+// "meeting-call-detected" is emitted and, unless turned off, a notification asks to record;
+// "meeting-call-ended" follows when it goes away. Only apps on the allowlist are watched.
+//
+// Window titles come from tasklist on Windows, System Events on macOS (which needs the
+// Accessibility permission) and wmctrl on X11; without them detection falls back to what
+// processes and audio show.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::process::Command;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::notifications::commands::NotificationManagerState;
+use crate::state::{self, AppState};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Browser tab of a Meet call: "Meet - abc-defg-hij" or "Meet – Weekly sync"
+static MEET_TITLE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:\(\d+\)\s*)?meet\s*[-–—]\s*(.+?)(?:\s+[-–—]\s+[^-–—]+)?$").unwrap());
+/// Teams call windows, in the desktop app or a browser
+static TEAMS_TITLE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:\(\d+\)\s*)?(?:meeting|call) (?:with|in) .*\|\s*microsoft teams").unwrap());
+/// Webex meeting windows; the home window is just "Webex" or "Cisco Webex Meetings"
+static WEBEX_TITLE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\bwebex meeting\b|\|\s*webex$|personal room").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingApp {
+    Zoom,
+    GoogleMeet,
+    Teams,
+    Webex,
+}
+
+impl MeetingApp {
+    const ALL: [MeetingApp; 4] = [MeetingApp::Zoom, MeetingApp::GoogleMeet, MeetingApp::Teams, MeetingApp::Webex];
+
+    /// Platform name used for meeting details
+    pub fn platform(self) -> &'static str {
+        match self {
+            MeetingApp::Zoom => "Zoom",
+            MeetingApp::GoogleMeet => "Google Meet",
+            MeetingApp::Teams => "Microsoft Teams",
+            MeetingApp::Webex => "Webex",
+        }
+    }
+
+    /// Process names (lower-case, without ".exe") of the desktop app
+    fn processes(self) -> &'static [&'static str] {
+        match self {
+            MeetingApp::Zoom => &["zoom", "zoom.us"],
+            MeetingApp::GoogleMeet => &[],
+            MeetingApp::Teams => &["teams", "ms-teams", "msteams"],
+            MeetingApp::Webex => &["webex", "ciscowebex", "ciscocollabhost", "webexmta"],
+        }
+    }
+
+    /// Helper processes that only run while the app is in a call
+    fn call_processes(self) -> &'static [&'static str] {
+        match self {
+            MeetingApp::Zoom => &["cpthost", "aomhost"],
+            MeetingApp::Webex => &["atmgr"],
+            MeetingApp::GoogleMeet | MeetingApp::Teams => &[],
+        }
+    }
+}
+
+fn default_apps() -> Vec<MeetingApp> {
+    MeetingApp::ALL.to_vec()
+}
+
+fn default_notify() -> bool {
+    true
+}
+
+/// Meeting detection settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingDetectionSettings {
+    pub enabled: bool,
+    /// Apps watched for calls
+    #[serde(default = "default_apps")]
+    pub apps: Vec<MeetingApp>,
+    /// Show a notification offering to record; the event is emitted either way
+    #[serde(default = "default_notify")]
+    pub notify: bool,
+}
+
+impl Default for MeetingDetectionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            apps: default_apps(),
+            notify: default_notify(),
+        }
+    }
+}
+
+/// A call in progress in one of the meeting apps
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DetectedCall {
+    pub app: MeetingApp,
+    pub platform: String,
+    /// Meeting name or code, when the window shows one
+    pub title: Option<String>,
+}
+
+/// What could be seen of the desktop at one moment; names are lower-case without ".exe"
+#[derive(Debug, Default)]
+struct Snapshot {
+    processes: Vec<String>,
+    window_titles: Vec<String>,
+    /// Apps recording from an input device, by process and application name
+    audio_clients: Vec<String>,
+}
+
+impl Snapshot {
+    fn running(&self, names: &[&str]) -> bool {
+        self.processes.iter().any(|process| names.contains(&process.as_str()))
+    }
+
+    fn using_microphone(&self, names: &[&str]) -> bool {
+        self.audio_clients
+            .iter()
+            .any(|client| names.iter().any(|name| client.contains(name)))
+    }
+}
+
+fn process_key(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    name.strip_suffix(".exe").map(str::to_string).unwrap_or(name)
+}
+
+/// The call `app` has in progress, if any
+fn detect_call(app: MeetingApp, snapshot: &Snapshot) -> Option<DetectedCall> {
+    let mut titles = snapshot.window_titles.iter().map(|title| title.trim());
+    let running = snapshot.running(app.processes());
+    let (in_call, title) = match app {
+        MeetingApp::Zoom => {
+            let window = titles
+                .find(|title| title.starts_with("Zoom Meeting") || title.starts_with("Zoom Webinar"));
+            let in_call = window.is_some() || snapshot.running(app.call_processes()) || snapshot.using_microphone(&["zoom"]);
+            (running && in_call, None)
+        }
+        MeetingApp::GoogleMeet => {
+            let code = titles
+                .filter_map(|title| MEET_TITLE_REGEX.captures(title))
+                .map(|captures| captures[1].trim().to_string())
+                .next();
+            (code.is_some(), code)
+        }
+        MeetingApp::Teams => {
+            let window = titles.find(|title| TEAMS_TITLE_REGEX.is_match(title));
+            let title = window.and_then(|title| title.split('|').next()).map(|name| name.trim().to_string());
+            // The web app has no process of its own, but its call windows say so
+            (title.is_some() || (running && snapshot.using_microphone(&["teams"])), title)
+        }
+        MeetingApp::Webex => {
+            let window = titles.find(|title| WEBEX_TITLE_REGEX.is_match(title));
+            let in_call = window.is_some() || snapshot.running(app.call_processes()) || snapshot.using_microphone(&["webex"]);
+            (running && in_call, None)
+        }
+    };
+    in_call.then(|| DetectedCall {
+        app,
+        platform: app.platform().to_string(),
+        title: title.filter(|title| !title.is_empty()),
+    })
+}
+
+/// Rows of `tasklist /v /fo csv /nh`; the window title is the last column
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_tasklist(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.trim().trim_matches('"').split("\",\"").collect();
+            let title = fields.last()?.trim();
+            (fields.len() >= 9 && title != "N/A" && !title.is_empty()).then(|| title.to_string())
+        })
+        .collect()
+}
+
+/// Rows of `wmctrl -l`: id, desktop and host, then the title
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_wmctrl(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut rest = line.trim();
+            for _ in 0..3 {
+                rest = rest.split_once(char::is_whitespace)?.1.trim_start();
+            }
+            Some(rest.to_string()).filter(|title| !title.is_empty())
+        })
+        .collect()
+}
+
+/// Application names and binaries from `pactl list source-outputs`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_source_outputs(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.trim().split_once(" = ")?;
+            matches!(key, "application.name" | "application.process.binary")
+                .then(|| value.trim_matches('"').to_lowercase())
+        })
+        .collect()
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    #[allow(unused_mut)]
+    let mut command = Command::new(program);
+    command.args(args);
+    // Hide console window on Windows
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(target_os = "windows")]
+fn window_titles() -> Vec<String> {
+    command_output("tasklist", &["/v", "/fo", "csv", "/nh"])
+        .map(|output| parse_tasklist(&output))
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "macos")]
+fn window_titles() -> Vec<String> {
+    const SCRIPT: &str = "JSON.stringify(Application('System Events').processes.whose({backgroundOnly: false})\
+        .windows.name().flat().filter(name => name))";
+    command_output("osascript", &["-l", "JavaScript", "-e", SCRIPT])
+        .and_then(|output| serde_json::from_str(&output).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn window_titles() -> Vec<String> {
+    command_output("wmctrl", &["-l"])
+        .map(|output| parse_wmctrl(&output))
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn audio_clients() -> Vec<String> {
+    command_output("pactl", &["list", "source-outputs"])
+        .map(|output| parse_source_outputs(&output))
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn audio_clients() -> Vec<String> {
+    Vec::new()
+}
+
+fn take_snapshot() -> Snapshot {
+    let system = sysinfo::System::new_with_specifics(
+        sysinfo::RefreshKind::new().with_processes(sysinfo::ProcessRefreshKind::new()),
+    );
+    Snapshot {
+        processes: system
+            .processes()
+            .values()
+            .map(|process| process_key(&process.name().to_string_lossy()))
+            .collect(),
+        window_titles: window_titles(),
+        audio_clients: audio_clients(),
+    }
+}
+
+/// Calls in progress in the allowed apps
+async fn detect_calls(apps: &[MeetingApp]) -> Vec<DetectedCall> {
+    let snapshot = tokio::task::spawn_blocking(take_snapshot).await.unwrap_or_default();
+    apps.iter().filter_map(|app| detect_call(*app, &snapshot)).collect()
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<MeetingDetectionSettings, String> {
+    SettingsRepository::get_meeting_detection_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load meeting detection settings: {}", e))
+}
+
+/// Offer to record a call, when notifications are set up
+async fn notify_call<R: Runtime>(app: &AppHandle<R>, call: &DetectedCall) {
+    let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() else {
+        return;
+    };
+    let manager = manager_state.read().await;
+    if let Some(manager) = manager.as_ref() {
+        if let Err(e) = manager.show_call_started(&call.platform).await {
+            warn!("Failed to show the record prompt for the {} call: {}", call.platform, e);
+        }
+    }
+}
+
+/// Start the background task that watches meeting apps for calls
+pub fn start_detection_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let mut active: Vec<DetectedCall> = Vec::new();
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+            let Some(pool) = state::try_pool(&app) else {
+                continue;
+            };
+            let settings = match load_settings(&pool).await {
+                Ok(settings) if settings.enabled => settings,
+                Ok(_) => {
+                    active.clear();
+                    continue;
+                }
+                Err(e) => {
+                    warn!("{}", e);
+                    continue;
+                }
+            };
+
+            let calls = detect_calls(&settings.apps).await;
+            for ended in active.iter().filter(|call| !calls.iter().any(|current| current.app == call.app)) {
+                info!("{} call ended", ended.platform);
+                let _ = app.emit("meeting-call-ended", ended);
+            }
+            let started: Vec<&DetectedCall> = calls
+                .iter()
+                .filter(|call| !active.iter().any(|previous| previous.app == call.app))
+                .collect();
+            // A call joined mid-recording is most likely the one being recorded
+            if !started.is_empty() && !crate::audio::recording_commands::is_recording().await {
+                for call in started {
+                    info!("{} call started", call.platform);
+                    let _ = app.emit("meeting-call-detected", call);
+                    if settings.notify {
+                        notify_call(&app, call).await;
+                    }
+                }
+            }
+            active = calls;
+        }
+    });
+}
+
+#[command]
+pub async fn get_meeting_detection_settings<R: Runtime>(app: AppHandle<R>) -> Result<MeetingDetectionSettings, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_meeting_detection_settings<R: Runtime>(
+    app: AppHandle<R>,
+    mut settings: MeetingDetectionSettings,
+) -> Result<(), String> {
+    let mut apps = Vec::new();
+    for meeting_app in settings.apps {
+        if !apps.contains(&meeting_app) {
+            apps.push(meeting_app);
+        }
+    }
+    if settings.enabled && apps.is_empty() {
+        return Err("Choose at least one meeting app to watch".to_string());
+    }
+    settings.apps = apps;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_meeting_detection_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save meeting detection settings: {}", e))
+}
+
+/// Calls in progress right now in the allowed apps, whether or not detection is on
+#[command]
+pub async fn get_detected_calls<R: Runtime>(app: AppHandle<R>) -> Result<Vec<DetectedCall>, String> {
+    let state = app.state::<AppState>();
+    let settings = load_settings(state.db_manager.pool()).await?;
+    Ok(detect_calls(&settings.apps).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(processes: &[&str], window_titles: &[&str], audio_clients: &[&str]) -> Snapshot {
+        Snapshot {
+            processes: processes.iter().map(|name| process_key(name)).collect(),
+            window_titles: window_titles.iter().map(|title| title.to_string()).collect(),
+            audio_clients: audio_clients.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    fn detected(snapshot: &Snapshot) -> Vec<(MeetingApp, Option<String>)> {
+        MeetingApp::ALL
+            .iter()
+            .filter_map(|app| detect_call(*app, snapshot))
+            .map(|call| (call.app, call.title))
+            .collect()
+    }
+
+    #[test]
+    fn detects_calls_per_app() {
+        // Apps open but idle
+        let idle = snapshot(
+            &["Zoom.exe", "ms-teams.exe", "CiscoCollabHost.exe", "chrome.exe"],
+            &["Zoom Workplace", "Chat | General | Microsoft Teams", "Webex", "Google Meet - Google Chrome", "Inbox - Gmail"],
+            &[],
+        );
+        assert!(detected(&idle).is_empty());
+
+        let calls = snapshot(
+            &["zoom.us", "CptHost", "firefox"],
+            &[
+                "Meet - abc-defg-hij - Google Chrome",
+                "Meeting with Dana Ruiz | Microsoft Teams",
+                "Meeting | Webex",
+            ],
+            &[],
+        );
+        assert_eq!(
+            detected(&calls),
+            [
+                (MeetingApp::Zoom, None),
+                (MeetingApp::GoogleMeet, Some("abc-defg-hij".to_string())),
+                (MeetingApp::Teams, Some("Meeting with Dana Ruiz".to_string())),
+            ]
+        );
+
+        // Zoom and Webex on Linux, seen only through the microphone
+        let linux = snapshot(&["zoom", "webex"], &[], &["zoom voiceengine", "zoom", "webex"]);
+        assert_eq!(detected(&linux), [(MeetingApp::Zoom, None), (MeetingApp::Webex, None)]);
+    }
+
+    #[test]
+    fn reads_tool_output() {
+        let tasklist = "\"chrome.exe\",\"1204\",\"Console\",\"1\",\"250,112 K\",\"Running\",\"PC\\dana\",\"0:01:12\",\"Meet - abc-defg-hij - Google Chrome\"\r\n\
+            \"svchost.exe\",\"964\",\"Services\",\"0\",\"9,012 K\",\"Unknown\",\"N/A\",\"0:00:01\",\"N/A\"\r\n";
+        assert_eq!(parse_tasklist(tasklist), ["Meet - abc-defg-hij - Google Chrome"]);
+
+        let wmctrl = "0x03a00003  0 laptop Zoom Meeting\n0x04200007 -1 laptop   \n";
+        assert_eq!(parse_wmctrl(wmctrl), ["Zoom Meeting"]);
+
+        let pactl = "Source Output #42\n\tDriver: protocol-native.c\n\tProperties:\n\
+            \t\tapplication.name = \"ZOOM VoiceEngine\"\n\t\tapplication.process.binary = \"zoom\"\n";
+        assert_eq!(parse_source_outputs(pactl), ["zoom voiceengine", "zoom"]);
+
+        let settings: MeetingDetectionSettings = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+        assert_eq!(settings.apps, MeetingApp::ALL);
+        assert!(settings.notify);
+    }
+}
@@ -0,0 +1,307 @@
+// Meeting details
+// Structured facts kept with each meeting: title, scheduled start, duration, participants,
+// conferencing platform and calendar event. After a meeting is saved the gaps are filled
+// in from what the app can see (the transcript's length, named speakers, the conferencing
+// app running at the time); anything set by hand is left alone.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::MeetingParticipant;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+const MAX_PLATFORM_CHARS: usize = 60;
+
+/// Process names (lower-case, without ".exe") of conferencing apps, by platform. When
+/// several are running, the first platform listed wins: chat apps like Slack stay open all
+/// day, so they rank below dedicated meeting apps.
+const PLATFORM_PROCESSES: &[(&str, &[&str])] = &[
+    ("Zoom", &["zoom", "zoom.us", "zoomwebinar"]),
+    ("Microsoft Teams", &["teams", "ms-teams", "msteams"]),
+    ("Webex", &["webex", "ciscowebex", "webexmta", "ciscocollabhost"]),
+    ("GoTo Meeting", &["g2mcomm", "gotomeeting"]),
+    ("Skype", &["skype"]),
+    ("Discord", &["discord"]),
+    ("Slack", &["slack"]),
+];
+
+/// Everything known about a meeting
+#[derive(Debug, Serialize)]
+pub struct MeetingInfo {
+    pub meeting_id: String,
+    pub title: String,
+    /// When recording started
+    pub created_at: DateTime<Utc>,
+    pub scheduled_start: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<f64>,
+    pub platform: Option<String>,
+    pub calendar_event_id: Option<String>,
+    pub participants: Vec<MeetingParticipant>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParticipantInput {
+    pub name: String,
+    pub email: Option<String>,
+}
+
+/// Editable details; every field is replaced, so None clears it
+#[derive(Debug, Deserialize)]
+pub struct MeetingInfoUpdate {
+    pub title: String,
+    pub scheduled_start: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<f64>,
+    pub platform: Option<String>,
+    pub calendar_event_id: Option<String>,
+    pub participants: Vec<ParticipantInput>,
+}
+
+/// The conferencing platform among running processes
+fn platform_from_processes<'a>(names: impl IntoIterator<Item = &'a str>) -> Option<&'static str> {
+    let running: Vec<String> = names
+        .into_iter()
+        .map(|name| {
+            let name = name.trim().to_lowercase();
+            name.strip_suffix(".exe").map(str::to_string).unwrap_or(name)
+        })
+        .collect();
+    PLATFORM_PROCESSES
+        .iter()
+        .find(|(_, processes)| running.iter().any(|name| processes.contains(&name.as_str())))
+        .map(|(platform, _)| *platform)
+}
+
+/// Conferencing app running right now, if any
+fn detect_platform() -> Option<&'static str> {
+    let system = sysinfo::System::new_all();
+    let names: Vec<String> = system
+        .processes()
+        .values()
+        .map(|process| process.name().to_string_lossy().to_string())
+        .collect();
+    platform_from_processes(names.iter().map(String::as_str))
+}
+
+/// Whether a speaker label is a person's name rather than a placeholder ("Speaker 2",
+/// "Me", "Remote")
+fn is_named_speaker(label: &str) -> bool {
+    let label = label.trim();
+    let placeholder = label
+        .strip_prefix("Speaker ")
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+    !label.is_empty() && !placeholder && label != "Me" && label != "Remote"
+}
+
+/// Trimmed participants without blank names, duplicate names or malformed emails
+fn clean_participants(participants: &[ParticipantInput]) -> Result<Vec<(String, Option<String>)>, String> {
+    let mut cleaned: Vec<(String, Option<String>)> = Vec::new();
+    for participant in participants {
+        let name = participant.name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let email = participant.email.as_deref().map(str::trim).filter(|email| !email.is_empty());
+        if let Some(email) = email {
+            if !email.contains('@') || email.contains(char::is_whitespace) {
+                return Err(format!("Invalid email address for {}: {}", name, email));
+            }
+        }
+        if !cleaned.iter().any(|(existing, _)| existing.eq_ignore_ascii_case(name)) {
+            cleaned.push((name.to_string(), email.map(str::to_string)));
+        }
+    }
+    Ok(cleaned)
+}
+
+async fn load_info(pool: &SqlitePool, meeting_id: &str) -> Result<MeetingInfo, String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let participants = MeetingParticipantsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load participants: {}", e))?;
+    Ok(MeetingInfo {
+        meeting_id: meeting.id,
+        title: meeting.title,
+        created_at: meeting.created_at.0,
+        scheduled_start: meeting.scheduled_start,
+        duration_seconds: meeting.duration_seconds,
+        platform: meeting.platform,
+        calendar_event_id: meeting.calendar_event_id,
+        participants,
+    })
+}
+
+/// List the meeting's named speakers as participants; returns how many were added
+pub async fn add_speaker_participants(pool: &SqlitePool, meeting_id: &str) -> Result<u64, sqlx::Error> {
+    let names: Vec<String> = TranscriptsRepository::list_speakers(pool, meeting_id)
+        .await?
+        .into_iter()
+        .map(|(label, _, _)| label.trim().to_string())
+        .filter(|label| is_named_speaker(label))
+        .collect();
+    MeetingParticipantsRepository::add_missing(pool, meeting_id, &names, "speaker").await
+}
+
+/// Fill in the details of a saved meeting that aren't set yet
+async fn autofill(pool: &SqlitePool, meeting_id: &str, platform: Option<&str>) -> Result<(), String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+    let duration_seconds = match meeting.duration_seconds {
+        Some(duration) => Some(duration),
+        None => TranscriptsRepository::list_for_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load transcript: {}", e))?
+            .iter()
+            .filter_map(|segment| {
+                segment
+                    .audio_end_time
+                    .or_else(|| Some(segment.audio_start_time? + segment.duration?))
+            })
+            .reduce(f64::max),
+    };
+    let platform = meeting.platform.or(platform.map(str::to_string));
+    MeetingsRepository::set_details(
+        pool,
+        meeting_id,
+        meeting.scheduled_start,
+        duration_seconds,
+        platform.as_deref(),
+        meeting.calendar_event_id.as_deref(),
+    )
+    .await
+    .map_err(|e| format!("Failed to save meeting details: {}", e))?;
+
+    add_speaker_participants(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to save participants: {}", e))?;
+    Ok(())
+}
+
+/// Fill in a newly saved meeting's details in the background. Only right after a live
+/// recording (`live`) is the running conferencing app taken as the meeting's platform.
+pub fn spawn_autofill<R: Runtime>(app: &AppHandle<R>, meeting_id: String, live: bool) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let platform = if live {
+            tokio::task::spawn_blocking(detect_platform).await.ok().flatten()
+        } else {
+            None
+        };
+        let pool = app.state::<AppState>().db_manager.pool().clone();
+        match autofill(&pool, &meeting_id, platform).await {
+            Ok(()) => {
+                info!("Filled in details of meeting {} (platform: {:?})", meeting_id, platform);
+                let _ = app.emit("meeting-info-updated", serde_json::json!({ "meeting_id": meeting_id }));
+            }
+            Err(e) => warn!("Failed to fill in details of meeting {}: {}", meeting_id, e),
+        }
+    });
+}
+
+#[command]
+pub async fn get_meeting_info<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<MeetingInfo, String> {
+    let state = app.state::<AppState>();
+    load_info(state.db_manager.pool(), &meeting_id).await
+}
+
+/// Replace a meeting's editable details, participants included
+#[command]
+pub async fn update_meeting_info<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    update: MeetingInfoUpdate,
+) -> Result<MeetingInfo, String> {
+    let title = update.title.trim();
+    if title.is_empty() {
+        return Err("Meeting title cannot be empty".to_string());
+    }
+    if update.duration_seconds.is_some_and(|duration| !duration.is_finite() || duration < 0.0) {
+        return Err("Duration must be zero or more seconds".to_string());
+    }
+    let platform = update.platform.as_deref().map(str::trim).filter(|platform| !platform.is_empty());
+    if platform.is_some_and(|platform| platform.chars().count() > MAX_PLATFORM_CHARS) {
+        return Err(format!("Platform name must be at most {} characters", MAX_PLATFORM_CHARS));
+    }
+    let calendar_event_id = update
+        .calendar_event_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty());
+    let participants = clean_participants(&update.participants)?;
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    if meeting.title != title {
+        MeetingsRepository::update_meeting_title(pool, &meeting_id, title)
+            .await
+            .map_err(|e| format!("Failed to rename meeting: {}", e))?;
+    }
+    MeetingsRepository::set_details(
+        pool,
+        &meeting_id,
+        update.scheduled_start,
+        update.duration_seconds,
+        platform,
+        calendar_event_id,
+    )
+    .await
+    .map_err(|e| format!("Failed to save meeting details: {}", e))?;
+    MeetingParticipantsRepository::set_for_meeting(pool, &meeting_id, &participants, "manual")
+        .await
+        .map_err(|e| format!("Failed to save participants: {}", e))?;
+    // A new title or calendar event may match a meeting template
+    crate::meeting_templates::apply_matching(pool, &meeting_id).await?;
+
+    let _ = app.emit("meeting-info-updated", serde_json::json!({ "meeting_id": meeting_id }));
+    load_info(pool, &meeting_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detection_helpers() {
+        assert_eq!(platform_from_processes(["bash", "Zoom.exe", "slack"]), Some("Zoom"));
+        assert_eq!(platform_from_processes(["Slack", "ms-teams"]), Some("Microsoft Teams"));
+        assert_eq!(platform_from_processes(["zoom.us"]), Some("Zoom"));
+        assert_eq!(platform_from_processes(["firefox", "code"]), None);
+
+        assert!(is_named_speaker("Alice"));
+        assert!(is_named_speaker("Speaker Bob"));
+        assert!(!is_named_speaker("Speaker 3"));
+        assert!(!is_named_speaker("Me"));
+        assert!(!is_named_speaker("  "));
+
+        let participants = clean_participants(&[
+            ParticipantInput { name: " Alice ".to_string(), email: Some("alice@example.com".to_string()) },
+            ParticipantInput { name: "alice".to_string(), email: None },
+            ParticipantInput { name: "".to_string(), email: None },
+            ParticipantInput { name: "Bob".to_string(), email: Some(" ".to_string()) },
+        ])
+        .unwrap();
+        assert_eq!(
+            participants,
+            vec![
+                ("Alice".to_string(), Some("alice@example.com".to_string())),
+                ("Bob".to_string(), None),
+            ]
+        );
+        assert!(clean_participants(&[ParticipantInput { name: "Eve".to_string(), email: Some("eve".to_string()) }]).is_err());
+    }
+}
@@ -0,0 +1,408 @@
+// Meeting templates
+// Settings for a recurring meeting, defined once: tags, participants, the summary template,
+// output language and model. A template matches a meeting by its calendar event series or
+// by a title pattern, and is applied when a matching meeting is saved, retitled or linked
+// to a calendar event. Each meeting gets at most one template, the first that matches, and
+// keeps it; later summaries of the meeting use the template's summary settings.
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::audio::transcription::language_id::normalize_language_code;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_participant::MeetingParticipantsRepository;
+use crate::database::repositories::meeting_tag::MeetingTagsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::summary::LLMProvider;
+
+const MAX_NAME_CHARS: usize = 100;
+const MAX_PATTERN_CHARS: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingTemplate {
+    /// Assigned when the template is first saved
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    /// Meeting titles this applies to, case-insensitive; `*` matches any text, e.g.
+    /// "Weekly sync*"
+    #[serde(default)]
+    pub title_pattern: Option<String>,
+    /// Calendar event of the recurring meeting; any occurrence of the series matches
+    #[serde(default)]
+    pub calendar_event_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub participants: Vec<String>,
+    /// Summary template for the minutes, e.g. "daily_standup" or "prompt:<id>"
+    #[serde(default)]
+    pub summary_template_id: Option<String>,
+    /// Language code the minutes are written in
+    #[serde(default)]
+    pub summary_language: Option<String>,
+    /// Summary provider and model, set together
+    #[serde(default)]
+    pub summary_provider: Option<String>,
+    #[serde(default)]
+    pub summary_model: Option<String>,
+}
+
+impl MeetingTemplate {
+    /// Summary provider and model, when the template chooses them
+    pub fn summary_model(&self) -> Option<(String, String)> {
+        self.summary_provider.clone().zip(self.summary_model.clone())
+    }
+
+    /// Trimmed copy with empty fields cleared; errors name what is invalid
+    fn cleaned(self) -> Result<Self, String> {
+        fn clean(value: Option<String>) -> Option<String> {
+            value.map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+        }
+
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err("Give the template a name".to_string());
+        }
+        if name.chars().count() > MAX_NAME_CHARS {
+            return Err(format!("Template names are at most {} characters", MAX_NAME_CHARS));
+        }
+        let title_pattern = clean(self.title_pattern);
+        let calendar_event_id = clean(self.calendar_event_id);
+        if title_pattern.is_none() && calendar_event_id.is_none() {
+            return Err("Enter a title pattern or a calendar event for the template to match".to_string());
+        }
+        if let Some(pattern) = &title_pattern {
+            if pattern.chars().all(|c| c == '*') {
+                return Err("The title pattern would match every meeting".to_string());
+            }
+            if pattern.chars().count() > MAX_PATTERN_CHARS {
+                return Err(format!("Title patterns are at most {} characters", MAX_PATTERN_CHARS));
+            }
+        }
+        let summary_language = match clean(self.summary_language) {
+            Some(language) => Some(normalize_language_code(&language).ok_or_else(|| {
+                format!("Invalid summary language '{}', expected a language code like \"en\"", language)
+            })?),
+            None => None,
+        };
+        let (summary_provider, summary_model) = match (clean(self.summary_provider), clean(self.summary_model)) {
+            (Some(provider), Some(model)) => {
+                LLMProvider::from_str(&provider)?;
+                (Some(provider), Some(model))
+            }
+            (None, None) => (None, None),
+            _ => return Err("Choose both a summary provider and a model, or neither".to_string()),
+        };
+
+        let mut participants: Vec<String> = Vec::new();
+        for participant in self.participants.iter().map(|name| name.trim()).filter(|name| !name.is_empty()) {
+            if !participants.iter().any(|existing| existing.eq_ignore_ascii_case(participant)) {
+                participants.push(participant.to_string());
+            }
+        }
+
+        Ok(Self {
+            id: self.id.trim().to_string(),
+            name,
+            title_pattern,
+            calendar_event_id: calendar_event_id.map(|id| calendar_series(&id).to_string()),
+            tags: self.tags.iter().map(|tag| tag.trim().to_string()).filter(|tag| !tag.is_empty()).collect(),
+            participants,
+            summary_template_id: clean(self.summary_template_id),
+            summary_language,
+            summary_provider,
+            summary_model,
+        })
+    }
+}
+
+/// The recurring series of a calendar event: occurrences of a recurring event carry the
+/// series id followed by "_<date>" or "_<date>T<time>Z", e.g. "abc123_20260203T100000Z"
+fn calendar_series(event_id: &str) -> &str {
+    let Some((series, occurrence)) = event_id.trim().rsplit_once('_') else {
+        return event_id.trim();
+    };
+    let (date, time) = occurrence.split_once('T').unwrap_or((occurrence, ""));
+    let time = time.strip_suffix('Z').unwrap_or(time);
+    let is_occurrence = date.len() == 8
+        && date.bytes().all(|b| b.is_ascii_digit())
+        && (time.is_empty() || (time.len() == 6 && time.bytes().all(|b| b.is_ascii_digit())));
+    if is_occurrence && !series.is_empty() {
+        series
+    } else {
+        event_id.trim()
+    }
+}
+
+/// Whether `title` matches `pattern` as a whole, ignoring case; `*` matches any text
+fn title_matches(pattern: &str, title: &str) -> bool {
+    let pattern = pattern.trim().to_lowercase();
+    let title = title.trim().to_lowercase();
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if parts.len() == 1 {
+        return title == first;
+    }
+    if !title.starts_with(first) || title.len() < first.len() + last.len() || !title.ends_with(last) {
+        return false;
+    }
+    // Middle parts in order, between the fixed start and end
+    let mut rest = &title[first.len()..title.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// The template for a meeting: one for its calendar series first, else the first whose title
+/// pattern matches
+fn find_template<'a>(
+    templates: &'a [MeetingTemplate],
+    title: &str,
+    calendar_event_id: Option<&str>,
+) -> Option<&'a MeetingTemplate> {
+    let series = calendar_event_id.map(calendar_series);
+    templates
+        .iter()
+        .find(|template| series.is_some() && template.calendar_event_id.as_deref() == series)
+        .or_else(|| {
+            templates.iter().find(|template| {
+                template.title_pattern.as_deref().is_some_and(|pattern| title_matches(pattern, title))
+            })
+        })
+}
+
+/// Apply a template's tags, participants and summary language to a meeting and record it as
+/// the meeting's template; a language chosen for the meeting is kept
+async fn apply(pool: &SqlitePool, meeting_id: &str, template: &MeetingTemplate) -> Result<(), String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+    MeetingTagsRepository::add_to_meetings(pool, &[meeting_id.to_string()], &template.tags)
+        .await
+        .map_err(|e| format!("Failed to tag meeting: {}", e))?;
+    MeetingParticipantsRepository::add_missing(pool, meeting_id, &template.participants, "manual")
+        .await
+        .map_err(|e| format!("Failed to add participants: {}", e))?;
+    if meeting.summary_language.is_none() && template.summary_language.is_some() {
+        MeetingsRepository::set_summary_language(pool, meeting_id, template.summary_language.as_deref())
+            .await
+            .map_err(|e| format!("Failed to save summary language: {}", e))?;
+    }
+    MeetingsRepository::set_template_id(pool, meeting_id, Some(&template.id))
+        .await
+        .map_err(|e| format!("Failed to save meeting template: {}", e))?;
+    Ok(())
+}
+
+/// Apply the matching template to a meeting that has none yet; returns the template applied
+pub async fn apply_matching(pool: &SqlitePool, meeting_id: &str) -> Result<Option<MeetingTemplate>, String> {
+    let templates = SettingsRepository::get_meeting_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))?;
+    if templates.is_empty() {
+        return Ok(None);
+    }
+    let applied = MeetingsRepository::get_template_id(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting template: {}", e))?;
+    if applied.is_some() {
+        return Ok(None);
+    }
+    let Some(meeting) = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+    else {
+        return Ok(None);
+    };
+
+    let Some(template) = find_template(&templates, &meeting.title, meeting.calendar_event_id.as_deref()) else {
+        return Ok(None);
+    };
+    apply(pool, meeting_id, template).await?;
+    info!("Applied meeting template {:?} to meeting {}", template.name, meeting_id);
+    Ok(Some(template.clone()))
+}
+
+/// Apply the matching template in the background; emits "meeting-template-applied"
+pub fn spawn_apply_matching<R: Runtime>(app: &AppHandle<R>, meeting_id: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let pool = app.state::<AppState>().db_manager.pool().clone();
+        match apply_matching(&pool, &meeting_id).await {
+            Ok(Some(template)) => {
+                let _ = app.emit(
+                    "meeting-template-applied",
+                    serde_json::json!({ "meeting_id": meeting_id, "template_id": template.id }),
+                );
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to apply a meeting template to {}: {}", meeting_id, e),
+        }
+    });
+}
+
+/// The template applied to a meeting, if it still exists
+pub async fn template_for_meeting(pool: &SqlitePool, meeting_id: &str) -> Result<Option<MeetingTemplate>, String> {
+    let Some(template_id) = MeetingsRepository::get_template_id(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting template: {}", e))?
+    else {
+        return Ok(None);
+    };
+    let templates = SettingsRepository::get_meeting_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))?;
+    Ok(templates.into_iter().find(|template| template.id == template_id))
+}
+
+async fn save_all(pool: &SqlitePool, templates: &[MeetingTemplate]) -> Result<(), String> {
+    SettingsRepository::save_meeting_templates(pool, templates)
+        .await
+        .map_err(|e| format!("Failed to save meeting templates: {}", e))
+}
+
+#[command]
+pub async fn list_meeting_templates<R: Runtime>(app: AppHandle<R>) -> Result<Vec<MeetingTemplate>, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_meeting_templates(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))
+}
+
+/// Add a template, or replace the one with the same id
+#[command]
+pub async fn save_meeting_template<R: Runtime>(app: AppHandle<R>, template: MeetingTemplate) -> Result<MeetingTemplate, String> {
+    let mut template = template.cleaned()?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut templates = SettingsRepository::get_meeting_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))?;
+    if templates
+        .iter()
+        .any(|existing| existing.id != template.id && existing.name.eq_ignore_ascii_case(&template.name))
+    {
+        return Err(format!("A template named \"{}\" already exists", template.name));
+    }
+
+    match templates.iter_mut().find(|existing| !template.id.is_empty() && existing.id == template.id) {
+        Some(existing) => *existing = template.clone(),
+        None => {
+            template.id = format!("meeting-template-{}", Uuid::new_v4());
+            templates.push(template.clone());
+        }
+    }
+    save_all(pool, &templates).await?;
+    Ok(template)
+}
+
+/// Delete a template; meetings it was applied to keep their tags and participants
+#[command]
+pub async fn delete_meeting_template<R: Runtime>(app: AppHandle<R>, template_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut templates = SettingsRepository::get_meeting_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))?;
+    let count = templates.len();
+    templates.retain(|template| template.id != template_id);
+    if templates.len() == count {
+        return Err(format!("Meeting template {} not found", template_id));
+    }
+    save_all(pool, &templates).await
+}
+
+/// Apply a template to a meeting by hand, replacing the one recorded for it
+#[command]
+pub async fn apply_meeting_template<R: Runtime>(app: AppHandle<R>, meeting_id: String, template_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let templates = SettingsRepository::get_meeting_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load meeting templates: {}", e))?;
+    let template = templates
+        .iter()
+        .find(|template| template.id == template_id)
+        .ok_or_else(|| format!("Meeting template {} not found", template_id))?;
+    apply(pool, &meeting_id, template).await?;
+    let _ = app.emit(
+        "meeting-template-applied",
+        serde_json::json!({ "meeting_id": meeting_id, "template_id": template.id }),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(id: &str, title_pattern: Option<&str>, calendar_event_id: Option<&str>) -> MeetingTemplate {
+        MeetingTemplate {
+            id: id.to_string(),
+            name: id.to_string(),
+            title_pattern: title_pattern.map(str::to_string),
+            calendar_event_id: calendar_event_id.map(str::to_string),
+            tags: Vec::new(),
+            participants: Vec::new(),
+            summary_template_id: None,
+            summary_language: None,
+            summary_provider: None,
+            summary_model: None,
+        }
+    }
+
+    #[test]
+    fn matches_recurring_meetings() {
+        assert_eq!(calendar_series("abc123_20260203T100000Z"), "abc123");
+        assert_eq!(calendar_series("abc123_20260203"), "abc123");
+        assert_eq!(calendar_series("team_sync"), "team_sync");
+        assert_eq!(calendar_series("AAMkAGI2"), "AAMkAGI2");
+
+        assert!(title_matches("Weekly sync*", "weekly sync – Feb 3"));
+        assert!(title_matches("*standup*", "Platform standup notes"));
+        assert!(title_matches("1:1 * / Alex", "1:1 Sam / Alex"));
+        assert!(title_matches("Design review", " design REVIEW "));
+        assert!(!title_matches("Design review", "Design review follow-up"));
+        assert!(!title_matches("a*a", "a"));
+
+        let templates = vec![
+            template("standup", Some("*standup*"), None),
+            template("planning", Some("Sprint planning*"), Some("series9")),
+        ];
+        let found = |title: &str, event: Option<&str>| find_template(&templates, title, event).map(|t| t.id.as_str());
+        assert_eq!(found("Team standup", None), Some("standup"));
+        assert_eq!(found("Team standup", Some("series9_20260210T090000Z")), Some("planning"));
+        assert_eq!(found("Retro", Some("other")), None);
+    }
+
+    #[test]
+    fn cleans_templates() {
+        let mut input = template("", Some("  Weekly sync*  "), Some(" series9_20260210 "));
+        input.name = " Weekly sync ".to_string();
+        input.participants = vec!["Alice".to_string(), " alice ".to_string(), " ".to_string()];
+        input.summary_language = Some(" ".to_string());
+        let cleaned = input.clone().cleaned().unwrap();
+        assert_eq!(cleaned.name, "Weekly sync");
+        assert_eq!(cleaned.title_pattern.as_deref(), Some("Weekly sync*"));
+        assert_eq!(cleaned.calendar_event_id.as_deref(), Some("series9"));
+        assert_eq!(cleaned.participants, vec!["Alice".to_string()]);
+        assert_eq!(cleaned.summary_language, None);
+
+        assert!(template("x", None, None).cleaned().is_err());
+        assert!(template("x", Some("**"), None).cleaned().is_err());
+        let mut half_model = input;
+        half_model.summary_provider = Some("openai".to_string());
+        assert!(half_model.cleaned().is_err());
+    }
+}
@@ -172,6 +172,28 @@ impl<R: Runtime> NotificationManager<R> {
         self.show_notification(notification).await
     }
 
+    /// Show a prompt to record a calendar meeting that is starting
+    pub async fn show_meeting_starting(&self, meeting_title: &str) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !settings.meeting_reminders || !settings.notification_preferences.show_meeting_reminders {
+            return Ok(());
+        }
+
+        let notification = Notification::meeting_starting(meeting_title);
+        self.show_notification(notification).await
+    }
+
+    /// Show a prompt to record a call detected in a meeting app
+    pub async fn show_call_started(&self, platform: &str) -> Result<()> {
+        let settings = self.settings.read().await;
+        if !settings.meeting_reminders || !settings.notification_preferences.show_meeting_reminders {
+            return Ok(());
+        }
+
+        let notification = Notification::call_started(platform);
+        self.show_notification(notification).await
+    }
+
     /// Show a system error notification
     pub async fn show_system_error(&self, error: String) -> Result<()> {
         let settings = self.settings.read().await;
@@ -176,6 +176,24 @@ impl Notification {
             .with_timeout(NotificationTimeout::Seconds(10))
     }
 
+    /// A calendar event with a video call is starting
+    pub fn meeting_starting(meeting_title: &str) -> Self {
+        let body = format!("'{}' is starting. Open Meetily to record it.", meeting_title);
+
+        Notification::new("Meetily", body, NotificationType::MeetingReminder(0))
+            .with_priority(NotificationPriority::High)
+            .with_timeout(NotificationTimeout::Seconds(10))
+    }
+
+    /// A call started in a meeting app
+    pub fn call_started(platform: &str) -> Self {
+        let body = format!("A {} call started. Open Meetily to record it.", platform);
+
+        Notification::new("Meetily", body, NotificationType::MeetingReminder(0))
+            .with_priority(NotificationPriority::High)
+            .with_timeout(NotificationTimeout::Seconds(10))
+    }
+
     pub fn system_error(error: impl Into<String>) -> Self {
         let error_string = error.into();
         Notification::new(
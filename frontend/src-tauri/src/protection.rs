@@ -0,0 +1,318 @@
+// Password-protected meetings
+// A protected meeting keeps its transcript text and summaries only encrypted, under a key
+// derived from a passphrase chosen for that meeting (separate from database encryption,
+// which uses one key for everything), and so are the details kept alongside: action items,
+// decisions, highlights, stand-up items, comments, transcript revisions, redacted originals,
+// chapter titles, bookmark notes, meeting notes and summary feedback. While locked,
+// the text is blanked in the database: reading or exporting the meeting is refused, search
+// doesn't find it and sync skips it, deleting any copy synced before it was protected.
+//
+// Unlocking writes the plaintext back and keeps the key in memory; locking again (by hand,
+// after AUTO_LOCK_AFTER, or at the next start) re-encrypts whatever the meeting holds then.
+// If the app quits while a meeting is unlocked the key is gone, so at the next start the
+// meeting goes back to the content it had when it was last locked.
+
+use argon2::Argon2;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{Key, KeyInit, XChaCha20Poly1305, XNonce};
+use once_cell::sync::Lazy;
+use rand::{rngs::OsRng, RngCore};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::ProtectedContent;
+use crate::database::repositories::protected_meeting::ProtectedMeetingsRepository;
+use crate::state::{self, AppState};
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const MIN_PASSPHRASE_CHARS: usize = 8;
+/// Unlocked meetings lock again after this long
+const AUTO_LOCK_AFTER: Duration = Duration::from_secs(15 * 60);
+const AUTO_LOCK_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+struct UnlockedKey {
+    key: [u8; 32],
+    salt: Vec<u8>,
+    unlocked_at: Instant,
+}
+
+// Keys of the meetings unlocked in this session
+static UNLOCKED_KEYS: Lazy<Mutex<HashMap<String, UnlockedKey>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtectionStatus {
+    pub protected: bool,
+    pub unlocked: bool,
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive the meeting key: {}", e))?;
+    Ok(key)
+}
+
+/// (nonce, ciphertext) of the content, under a fresh random nonce
+fn encrypt(key: &[u8; 32], content: &ProtectedContent) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let plaintext = serde_json::to_vec(content).map_err(|e| format!("Failed to serialize meeting content: {}", e))?;
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = XChaCha20Poly1305::new(Key::from_slice(key))
+        .encrypt(XNonce::from_slice(&nonce), plaintext.as_slice())
+        .map_err(|_| "Failed to encrypt the meeting".to_string())?;
+    Ok((nonce.to_vec(), ciphertext))
+}
+
+fn decrypt(key: &[u8; 32], nonce: &[u8], ciphertext: &[u8]) -> Result<ProtectedContent, String> {
+    if nonce.len() != NONCE_LEN {
+        return Err("The protected meeting is damaged".to_string());
+    }
+    let plaintext = XChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Wrong passphrase".to_string())?;
+    serde_json::from_slice(&plaintext).map_err(|e| format!("The protected meeting is damaged: {}", e))
+}
+
+fn validate_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+        return Err(format!("Passphrases are at least {} characters", MIN_PASSPHRASE_CHARS));
+    }
+    Ok(())
+}
+
+/// Key and content of a protected meeting, checked against the passphrase
+async fn open(pool: &SqlitePool, meeting_id: &str, passphrase: &str) -> Result<(UnlockedKey, ProtectedContent, bool), String> {
+    let protected = ProtectedMeetingsRepository::get(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting protection: {}", e))?
+        .ok_or_else(|| format!("Meeting {} is not password-protected", meeting_id))?;
+    let key = derive_key(passphrase, &protected.salt)?;
+    let content = decrypt(&key, &protected.nonce, &protected.ciphertext)?;
+    let unlocked = UnlockedKey {
+        key,
+        salt: protected.salt,
+        unlocked_at: Instant::now(),
+    };
+    Ok((unlocked, content, protected.unlocked))
+}
+
+/// Refuse to read or export a locked meeting
+pub async fn ensure_unlocked(pool: &SqlitePool, meeting_id: &str) -> Result<(), String> {
+    let locked = ProtectedMeetingsRepository::is_locked(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting protection: {}", e))?;
+    if locked {
+        return Err("This meeting is password-protected; unlock it first".to_string());
+    }
+    Ok(())
+}
+
+/// Drop the meeting's semantic index, which holds transcript text too; it is rebuilt when
+/// protection is removed
+fn delete_vector_index<R: Runtime>(app: &AppHandle<R>, meeting_id: &str) {
+    if let Ok(app_data_dir) = app.path().app_data_dir() {
+        let index_dir = crate::summary::vector_index::index_dir(&app_data_dir);
+        if let Err(e) = crate::summary::vector_index::VectorIndex::delete(&index_dir, meeting_id) {
+            warn!("Failed to delete vector index for {}: {}", meeting_id, e);
+        }
+    }
+}
+
+/// Lock an unlocked meeting: re-encrypt its current content with the key from unlocking,
+/// or go back to the stored ciphertext when the key isn't known. Edits while unlocked may
+/// have rebuilt the semantic index, so it is dropped again
+async fn lock<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, meeting_id: &str) -> Result<(), String> {
+    let unlocked = UNLOCKED_KEYS.lock().unwrap().remove(meeting_id);
+    match unlocked {
+        Some(unlocked) => {
+            let content = ProtectedMeetingsRepository::load_content(pool, meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load meeting content: {}", e))?;
+            let (nonce, ciphertext) = encrypt(&unlocked.key, &content)?;
+            ProtectedMeetingsRepository::seal(pool, meeting_id, &unlocked.salt, &nonce, &ciphertext)
+                .await
+                .map_err(|e| format!("Failed to lock meeting: {}", e))?;
+        }
+        None => ProtectedMeetingsRepository::relock(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to lock meeting: {}", e))?,
+    }
+    delete_vector_index(app, meeting_id);
+    Ok(())
+}
+
+/// Whether an unlocked meeting is due to lock: its key was lost with a restart or it has
+/// been open longer than AUTO_LOCK_AFTER
+fn due_to_lock(meeting_id: &str, now: Instant) -> bool {
+    match UNLOCKED_KEYS.lock().unwrap().get(meeting_id) {
+        Some(unlocked) => now.duration_since(unlocked.unlocked_at) >= AUTO_LOCK_AFTER,
+        None => true,
+    }
+}
+
+/// Start the background task that locks meetings left unlocked; the first pass, right at
+/// startup, locks those still unlocked from the previous run
+pub fn start_auto_lock_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                match ProtectedMeetingsRepository::list_unlocked(&pool).await {
+                    Ok(unlocked) => {
+                        let now = Instant::now();
+                        for meeting_id in unlocked.iter().filter(|meeting_id| due_to_lock(meeting_id, now)) {
+                            match lock(&app, &pool, meeting_id).await {
+                                Ok(()) => {
+                                    info!("Locked protected meeting {}", meeting_id);
+                                    let _ = app.emit("meeting-locked", serde_json::json!({ "meeting_id": meeting_id }));
+                                }
+                                Err(e) => warn!("Failed to lock meeting {}: {}", meeting_id, e),
+                            }
+                        }
+                    }
+                    Err(e) => warn!("Failed to load unlocked meetings: {}", e),
+                }
+            }
+            tokio::time::sleep(AUTO_LOCK_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+#[command]
+pub async fn get_meeting_protection<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<ProtectionStatus, String> {
+    let state = app.state::<AppState>();
+    let protected = ProtectedMeetingsRepository::get(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting protection: {}", e))?;
+    Ok(ProtectionStatus {
+        protected: protected.is_some(),
+        unlocked: protected.is_some_and(|protected| protected.unlocked),
+    })
+}
+
+/// Protect a meeting with a passphrase; it is locked right away
+#[command]
+pub async fn protect_meeting<R: Runtime>(app: AppHandle<R>, meeting_id: String, passphrase: String) -> Result<(), String> {
+    validate_passphrase(&passphrase)?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let existing = ProtectedMeetingsRepository::get(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting protection: {}", e))?;
+    if existing.is_some() {
+        return Err("This meeting is already password-protected".to_string());
+    }
+
+    let content = ProtectedMeetingsRepository::load_content(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting content: {}", e))?;
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(&passphrase, &salt)?;
+    let (nonce, ciphertext) = encrypt(&key, &content)?;
+    ProtectedMeetingsRepository::seal(pool, &meeting_id, &salt, &nonce, &ciphertext)
+        .await
+        .map_err(|e| format!("Failed to protect meeting: {}", e))?;
+
+    delete_vector_index(&app, &meeting_id);
+    info!("Protected meeting {} with a passphrase", meeting_id);
+    let _ = app.emit("meeting-locked", serde_json::json!({ "meeting_id": meeting_id }));
+    Ok(())
+}
+
+/// Unlock a protected meeting for reading and exporting until it is locked again
+#[command]
+pub async fn unlock_meeting<R: Runtime>(app: AppHandle<R>, meeting_id: String, passphrase: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let (unlocked, content, already_unlocked) = open(pool, &meeting_id, &passphrase).await?;
+    if !already_unlocked {
+        ProtectedMeetingsRepository::unlock(pool, &meeting_id, &content)
+            .await
+            .map_err(|e| format!("Failed to unlock meeting: {}", e))?;
+    }
+    UNLOCKED_KEYS.lock().unwrap().insert(meeting_id.clone(), unlocked);
+    info!("Unlocked protected meeting {}", meeting_id);
+    let _ = app.emit("meeting-unlocked", serde_json::json!({ "meeting_id": meeting_id }));
+    Ok(())
+}
+
+/// Lock an unlocked meeting again, keeping changes made while it was unlocked
+#[command]
+pub async fn lock_meeting<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let protected = ProtectedMeetingsRepository::get(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting protection: {}", e))?
+        .ok_or_else(|| format!("Meeting {} is not password-protected", meeting_id))?;
+    if protected.unlocked {
+        lock(&app, pool, &meeting_id).await?;
+    }
+    let _ = app.emit("meeting-locked", serde_json::json!({ "meeting_id": meeting_id }));
+    Ok(())
+}
+
+/// Stop protecting a meeting; its text is stored unencrypted again
+#[command]
+pub async fn unprotect_meeting<R: Runtime>(app: AppHandle<R>, meeting_id: String, passphrase: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let (_, decrypted, unlocked) = open(pool, &meeting_id, &passphrase).await?;
+    // An unlocked meeting may have been edited since its content was encrypted
+    let content = if unlocked {
+        ProtectedMeetingsRepository::load_content(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load meeting content: {}", e))?
+    } else {
+        decrypted
+    };
+    ProtectedMeetingsRepository::remove(pool, &meeting_id, &content)
+        .await
+        .map_err(|e| format!("Failed to remove meeting protection: {}", e))?;
+    UNLOCKED_KEYS.lock().unwrap().remove(&meeting_id);
+
+    crate::summary::vector_index::spawn_index_update(&app, meeting_id.clone());
+    info!("Removed the passphrase of meeting {}", meeting_id);
+    let _ = app.emit("meeting-unlocked", serde_json::json!({ "meeting_id": meeting_id }));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypts_meeting_content() {
+        let content = ProtectedContent {
+            segments: vec![("t1".to_string(), "Acquisition closes in March".to_string())],
+            summary_result: Some("{\"markdown\":\"# Minutes\"}".to_string()),
+            summary_source_text: None,
+            summary_versions: vec![("v1".to_string(), "# Draft".to_string())],
+            details: vec![(
+                "action_items.description".to_string(),
+                "a1".to_string(),
+                Some("Brief the board on the acquisition".to_string()),
+            )],
+        };
+        let salt = [7u8; SALT_LEN];
+        let key = derive_key("correct horse battery", &salt).unwrap();
+        let (nonce, ciphertext) = encrypt(&key, &content).unwrap();
+        assert!(!String::from_utf8_lossy(&ciphertext).contains("Acquisition"));
+        assert_eq!(decrypt(&key, &nonce, &ciphertext).unwrap(), content);
+
+        let wrong = derive_key("wrong horse battery", &salt).unwrap();
+        assert_eq!(decrypt(&wrong, &nonce, &ciphertext), Err("Wrong passphrase".to_string()));
+        assert!(decrypt(&key, &nonce[..12], &ciphertext).is_err());
+
+        assert!(validate_passphrase("short").is_err());
+        assert!(validate_passphrase("long enough").is_ok());
+    }
+}
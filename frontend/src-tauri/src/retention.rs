@@ -0,0 +1,330 @@
+// Retention policy
+// Deletes recordings after a number of days and transcripts after a number of months,
+// counted from when the meeting was recorded. Minutes, action items and the meeting
+// itself are kept, and starred meetings can be exempted. A background task applies the
+// policy while the app runs; the same report is available as a dry run first.
+
+use chrono::{DateTime, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::path::Path;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::MeetingModel;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::{self, AppState};
+use crate::summary::vector_index::{index_dir, VectorIndex};
+
+/// How often the background task applies the policy
+const CLEANUP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+
+/// Recording files removed from a meeting folder; transcripts.json and metadata.json stay
+const AUDIO_EXTENSIONS: &[&str] = &["mp4", "m4a", "wav", "mp3", "aac", "ogg", "opus", "flac", "webm"];
+
+fn default_exempt_starred() -> bool {
+    true
+}
+
+/// Retention policy, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    /// Apply the policy automatically in the background
+    pub enabled: bool,
+    /// Delete recordings of meetings older than this many days (None keeps them)
+    #[serde(default)]
+    pub delete_audio_after_days: Option<u32>,
+    /// Delete transcripts of meetings older than this many months (None keeps them)
+    #[serde(default)]
+    pub delete_transcripts_after_months: Option<u32>,
+    #[serde(default = "default_exempt_starred")]
+    pub exempt_starred: bool,
+}
+
+impl RetentionPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.delete_audio_after_days.is_some_and(|days| !(1..=3650).contains(&days)) {
+            return Err("Recordings are kept between 1 and 3650 days".to_string());
+        }
+        if self.delete_transcripts_after_months.is_some_and(|months| !(1..=120).contains(&months)) {
+            return Err("Transcripts are kept between 1 and 120 months".to_string());
+        }
+        if self.enabled && self.delete_audio_after_days.is_none() && self.delete_transcripts_after_months.is_none() {
+            return Err("Choose when recordings or transcripts are deleted".to_string());
+        }
+        Ok(())
+    }
+
+    /// Meetings recorded before these times have expired recordings and transcripts
+    fn cutoffs(&self, now: DateTime<Utc>) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        let audio = self
+            .delete_audio_after_days
+            .map(|days| now - Duration::days(i64::from(days)));
+        let transcripts = self
+            .delete_transcripts_after_months
+            .and_then(|months| now.checked_sub_months(Months::new(months)));
+        (audio, transcripts)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpiredMeeting {
+    pub meeting_id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    /// Size of the recording files (0 for transcripts)
+    pub bytes: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RetentionReport {
+    /// Meetings whose recording is (or would be) deleted
+    pub audio: Vec<ExpiredMeeting>,
+    /// Meetings whose transcript is (or would be) deleted
+    pub transcripts: Vec<ExpiredMeeting>,
+    pub audio_bytes: u64,
+    /// False for a dry run
+    pub applied: bool,
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| AUDIO_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+}
+
+/// Recording files in a meeting folder, with their total size
+fn recording_files(folder: &Path) -> (Vec<std::path::PathBuf>, u64) {
+    let mut files = Vec::new();
+    let mut bytes = 0;
+    for dir in [folder.to_path_buf(), folder.join(".checkpoints")] {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            match entry.metadata() {
+                Ok(metadata) if metadata.is_file() && is_audio_file(&path) => {
+                    bytes += metadata.len();
+                    files.push(path);
+                }
+                _ => {}
+            }
+        }
+    }
+    (files, bytes)
+}
+
+fn expired(meeting: &MeetingModel, bytes: u64) -> ExpiredMeeting {
+    ExpiredMeeting {
+        meeting_id: meeting.id.clone(),
+        title: meeting.title.clone(),
+        created_at: meeting.created_at.0,
+        bytes,
+    }
+}
+
+/// What the policy deletes now; with `apply` it is also deleted
+async fn run_policy<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    policy: &RetentionPolicy,
+    apply: bool,
+) -> Result<RetentionReport, String> {
+    let (audio_cutoff, transcript_cutoff) = policy.cutoffs(Utc::now());
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+    let with_transcripts: HashSet<String> = MeetingsRepository::meetings_with_transcripts(pool)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?
+        .into_iter()
+        .collect();
+
+    let mut report = RetentionReport {
+        applied: apply,
+        ..RetentionReport::default()
+    };
+    for meeting in meetings.iter().filter(|meeting| !(policy.exempt_starred && meeting.starred)) {
+        let recorded = meeting.created_at.0;
+        let folder = meeting.folder_path.as_deref().filter(|folder| !folder.is_empty());
+
+        if let (Some(cutoff), Some(folder)) = (audio_cutoff, folder) {
+            let (files, bytes) = if recorded < cutoff {
+                recording_files(Path::new(folder))
+            } else {
+                (Vec::new(), 0)
+            };
+            if !files.is_empty() {
+                if apply {
+                    for file in &files {
+                        if let Err(e) = std::fs::remove_file(file) {
+                            warn!("Failed to delete recording {}: {}", file.display(), e);
+                        }
+                    }
+                }
+                report.audio_bytes += bytes;
+                report.audio.push(expired(meeting, bytes));
+            }
+        }
+
+        if let Some(cutoff) = transcript_cutoff {
+            if recorded < cutoff && with_transcripts.contains(&meeting.id) {
+                if apply {
+                    MeetingsRepository::delete_transcripts(pool, &meeting.id)
+                        .await
+                        .map_err(|e| format!("Failed to delete transcript of {}: {}", meeting.id, e))?;
+                    // Copies of the transcript outside the database
+                    if let Some(folder) = folder {
+                        let _ = std::fs::remove_file(Path::new(folder).join("transcripts.json"));
+                    }
+                    if let Ok(app_data_dir) = app.path().app_data_dir() {
+                        if let Err(e) = VectorIndex::delete(&index_dir(&app_data_dir), &meeting.id) {
+                            warn!("Failed to delete vector index for {}: {}", meeting.id, e);
+                        }
+                    }
+                }
+                report.transcripts.push(expired(meeting, 0));
+            }
+        }
+    }
+
+    // A deduplicated recording is only freed once no meeting links to its stored copy
+    if apply && !report.audio.is_empty() {
+        if let Err(e) = crate::audio::dedup::collect_garbage(pool).await {
+            warn!("Failed to free unused recordings: {}", e);
+        }
+    }
+    if apply && (!report.audio.is_empty() || !report.transcripts.is_empty()) {
+        info!(
+            "Retention cleanup deleted {} recording(s) ({} bytes) and {} transcript(s)",
+            report.audio.len(),
+            report.audio_bytes,
+            report.transcripts.len()
+        );
+    }
+    Ok(report)
+}
+
+async fn load_policy(pool: &SqlitePool) -> Result<Option<RetentionPolicy>, String> {
+    SettingsRepository::get_retention_policy(pool)
+        .await
+        .map_err(|e| format!("Failed to load retention policy: {}", e))
+}
+
+/// Apply the retention policy periodically for as long as the app runs
+pub fn start_cleanup_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                match load_policy(&pool).await {
+                    Ok(Some(policy)) if policy.enabled => match run_policy(&app, &pool, &policy, true).await {
+                        Ok(report) if !report.audio.is_empty() || !report.transcripts.is_empty() => {
+                            let _ = app.emit("retention-cleanup-completed", &report);
+                        }
+                        Ok(_) => {}
+                        Err(e) => warn!("Retention cleanup failed: {}", e),
+                    },
+                    Ok(_) => {}
+                    Err(e) => warn!("{}", e),
+                }
+            }
+            tokio::time::sleep(CLEANUP_INTERVAL).await;
+        }
+    });
+}
+
+#[command]
+pub async fn get_retention_policy<R: Runtime>(app: AppHandle<R>) -> Result<Option<RetentionPolicy>, String> {
+    let state = app.state::<AppState>();
+    load_policy(state.db_manager.pool()).await
+}
+
+/// Save the policy; enabling it deletes expired data at the next background run, so show
+/// `preview_retention_cleanup` first
+#[command]
+pub async fn save_retention_policy<R: Runtime>(app: AppHandle<R>, policy: RetentionPolicy) -> Result<(), String> {
+    policy.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_retention_policy(state.db_manager.pool(), &policy)
+        .await
+        .map_err(|e| format!("Failed to save retention policy: {}", e))
+}
+
+/// Dry run: what `policy` (or the saved policy when None) would delete now
+#[command]
+pub async fn preview_retention_cleanup<R: Runtime>(
+    app: AppHandle<R>,
+    policy: Option<RetentionPolicy>,
+) -> Result<RetentionReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let policy = match policy {
+        Some(policy) => policy,
+        None => load_policy(pool).await?.ok_or_else(|| "No retention policy is set".to_string())?,
+    };
+    policy.validate()?;
+    run_policy(&app, pool, &policy, false).await
+}
+
+/// Apply the saved policy now, even if background cleanup is off
+#[command]
+pub async fn run_retention_cleanup<R: Runtime>(app: AppHandle<R>) -> Result<RetentionReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let policy = load_policy(pool).await?.ok_or_else(|| "No retention policy is set".to_string())?;
+    run_policy(&app, pool, &policy, true).await
+}
+
+#[command]
+pub async fn set_meeting_starred<R: Runtime>(app: AppHandle<R>, meeting_id: String, starred: bool) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let updated = MeetingsRepository::set_starred(state.db_manager.pool(), &meeting_id, starred)
+        .await
+        .map_err(|e| format!("Failed to star meeting: {}", e))?;
+    if !updated {
+        return Err(format!("Meeting {} not found", meeting_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_cutoffs_and_validation() {
+        let policy = RetentionPolicy {
+            enabled: true,
+            delete_audio_after_days: Some(30),
+            delete_transcripts_after_months: Some(6),
+            exempt_starred: true,
+        };
+        let now = Utc.with_ymd_and_hms(2026, 8, 31, 12, 0, 0).unwrap();
+        let (audio, transcripts) = policy.cutoffs(now);
+        assert_eq!(audio, Some(Utc.with_ymd_and_hms(2026, 8, 1, 12, 0, 0).unwrap()));
+        // Clamped to the end of the shorter month
+        assert_eq!(transcripts, Some(Utc.with_ymd_and_hms(2026, 2, 28, 12, 0, 0).unwrap()));
+
+        let keep_transcripts = RetentionPolicy {
+            delete_transcripts_after_months: None,
+            ..policy.clone()
+        };
+        assert_eq!(keep_transcripts.cutoffs(now).1, None);
+        assert!(RetentionPolicy { delete_audio_after_days: Some(0), ..policy.clone() }.validate().is_err());
+        assert!(RetentionPolicy {
+            delete_audio_after_days: None,
+            delete_transcripts_after_months: None,
+            ..policy
+        }
+        .validate()
+        .is_err());
+
+        assert!(is_audio_file(Path::new("/meetings/Standup/audio.MP4")));
+        assert!(!is_audio_file(Path::new("/meetings/Standup/transcripts.json")));
+    }
+}
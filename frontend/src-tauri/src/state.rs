@@ -1,5 +1,14 @@
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Manager, Runtime};
+
 use crate::database::manager::DatabaseManager;
 
 pub struct AppState {
     pub db_manager: DatabaseManager,
 }
+
+/// The database pool for background work; None until the database is set up, which on
+/// first launch happens later, from the onboarding screen
+pub fn try_pool<R: Runtime>(app: &AppHandle<R>) -> Option<SqlitePool> {
+    app.try_state::<AppState>().map(|state| state.db_manager.pool().clone())
+}
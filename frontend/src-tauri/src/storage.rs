@@ -0,0 +1,261 @@
+// Storage usage
+// How much disk each meeting takes, split between its recording folder and its data in the
+// database, and how much space is left where recordings are saved. Before each recording
+// the free space is checked: when it is low the user is warned and, if they opted in, the
+// recording is encoded at a lower bitrate so it fits.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::audio::encode::{set_recording_bitrate, DEFAULT_BITRATE_KBPS, LOW_SPACE_BITRATE_KBPS};
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::database::encryption::database_path;
+use crate::database::repositories::meeting_storage::MeetingStorageRepository;
+use crate::state::AppState;
+
+/// Below this much free space in the recordings folder, recording starts with a warning
+const LOW_SPACE_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingStorage {
+    pub meeting_id: String,
+    pub title: String,
+    /// Size of the meeting's recording folder
+    pub audio_bytes: u64,
+    /// Transcript, summaries, notes and speaker data in the database
+    pub database_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StorageUsage {
+    /// Largest first
+    pub meetings: Vec<MeetingStorage>,
+    /// All recording folders; recordings shared by several meetings count once
+    pub audio_bytes: u64,
+    /// The database files, including data not tied to a meeting
+    pub database_bytes: u64,
+    pub total_bytes: u64,
+    pub recordings_folder: String,
+    /// Free space on the drive holding the recordings folder, when it can be read
+    pub free_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LowDiskSpace {
+    pub folder: String,
+    pub free_bytes: u64,
+    /// Hours of recording that fit at the bitrate used
+    pub hours_left: f64,
+    /// Whether this recording is encoded at the lower bitrate
+    pub lowered_quality: bool,
+}
+
+/// Bytes one hour of recording takes at `kbps`
+fn bytes_per_hour(kbps: u32) -> u64 {
+    kbps as u64 * 1000 / 8 * 3600
+}
+
+/// Bitrate to record at, with the warning to show when space is low
+fn recording_plan(folder: &Path, free_bytes: Option<u64>, lower_quality: bool) -> (u32, Option<LowDiskSpace>) {
+    match free_bytes {
+        Some(free_bytes) if free_bytes < LOW_SPACE_BYTES => {
+            let bitrate = if lower_quality {
+                LOW_SPACE_BITRATE_KBPS
+            } else {
+                DEFAULT_BITRATE_KBPS
+            };
+            let warning = LowDiskSpace {
+                folder: folder.to_string_lossy().to_string(),
+                free_bytes,
+                hours_left: free_bytes as f64 / bytes_per_hour(bitrate) as f64,
+                lowered_quality: lower_quality,
+            };
+            (bitrate, Some(warning))
+        }
+        _ => (DEFAULT_BITRATE_KBPS, None),
+    }
+}
+
+/// Free space on the drive holding `path`: the disk with the longest mount point containing it
+fn free_space(path: &Path) -> Option<u64> {
+    let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+/// Identity of a file, so hard links to one recording are counted once
+#[cfg(unix)]
+fn file_key(_path: &Path, metadata: &std::fs::Metadata) -> (u64, u64, PathBuf) {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino(), PathBuf::new())
+}
+
+#[cfg(not(unix))]
+fn file_key(path: &Path, _metadata: &std::fs::Metadata) -> (u64, u64, PathBuf) {
+    (0, 0, path.to_path_buf())
+}
+
+/// Total size of the files under `dir`; files not yet in `seen` are also added to
+/// `unique_bytes`
+fn folder_size(dir: &Path, seen: &mut HashSet<(u64, u64, PathBuf)>, unique_bytes: &mut u64) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut size = 0;
+    for entry in entries.flatten() {
+        let Ok(metadata) = std::fs::symlink_metadata(entry.path()) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            size += folder_size(&entry.path(), seen, unique_bytes);
+        } else if metadata.is_file() {
+            size += metadata.len();
+            if seen.insert(file_key(&entry.path(), &metadata)) {
+                *unique_bytes += metadata.len();
+            }
+        }
+    }
+    size
+}
+
+/// Check the space left for recordings and choose the bitrate of the next recording; emits
+/// "low-disk-space" when it is low
+pub async fn check_before_recording<R: Runtime>(app: &AppHandle<R>) {
+    let preferences = match load_recording_preferences(app).await {
+        Ok(preferences) => preferences,
+        Err(e) => {
+            warn!("Failed to load recording preferences for the disk space check: {}", e);
+            return;
+        }
+    };
+    let folder = preferences.save_folder.clone();
+    let free_bytes = tokio::task::spawn_blocking({
+        let folder = folder.clone();
+        move || free_space(&folder)
+    })
+    .await
+    .ok()
+    .flatten();
+
+    let (bitrate, warning) = recording_plan(&folder, free_bytes, preferences.lower_quality_on_low_space);
+    set_recording_bitrate(bitrate);
+    if let Some(warning) = warning {
+        warn!(
+            "Low disk space in {}: {} MB free, about {:.1} h of recording at {} kbit/s",
+            warning.folder,
+            warning.free_bytes / (1024 * 1024),
+            warning.hours_left,
+            bitrate
+        );
+        let _ = app.emit("low-disk-space", &warning);
+    }
+}
+
+/// Disk used by each meeting and in total, and the space left for recordings
+#[command]
+pub async fn get_storage_usage<R: Runtime>(app: AppHandle<R>) -> Result<StorageUsage, String> {
+    let state = app.state::<AppState>();
+    let rows = MeetingStorageRepository::database_bytes(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to measure meeting data: {}", e))?;
+    let recordings_folder = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?
+        .save_folder;
+    let db_path = database_path(&app)?;
+
+    tokio::task::spawn_blocking(move || {
+        let mut seen = HashSet::new();
+        let mut audio_bytes = 0;
+        let mut meetings: Vec<MeetingStorage> = rows
+            .into_iter()
+            .map(|(meeting_id, title, folder_path, database_bytes)| MeetingStorage {
+                meeting_id,
+                title,
+                audio_bytes: folder_path
+                    .map(|folder| folder_size(Path::new(&folder), &mut seen, &mut audio_bytes))
+                    .unwrap_or(0),
+                database_bytes: database_bytes.max(0) as u64,
+            })
+            .collect();
+        meetings.sort_by_key(|meeting| std::cmp::Reverse(meeting.audio_bytes + meeting.database_bytes));
+
+        // The write-ahead log holds changes not yet merged into the database file
+        let database_bytes: u64 = ["", "-wal", "-shm"]
+            .iter()
+            .filter_map(|suffix| {
+                let mut path = db_path.clone().into_os_string();
+                path.push(suffix);
+                std::fs::metadata(path).ok().map(|metadata| metadata.len())
+            })
+            .sum();
+        let usage = StorageUsage {
+            meetings,
+            audio_bytes,
+            database_bytes,
+            total_bytes: audio_bytes + database_bytes,
+            recordings_folder: recordings_folder.to_string_lossy().to_string(),
+            free_bytes: free_space(&recordings_folder),
+        };
+        info!(
+            "Storage: {} MB of recordings, {} MB of database",
+            usage.audio_bytes / (1024 * 1024),
+            usage.database_bytes / (1024 * 1024)
+        );
+        usage
+    })
+    .await
+    .map_err(|e| format!("Failed to measure storage: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plans_recording_quality() {
+        let folder = Path::new("recordings");
+        assert_eq!(recording_plan(folder, Some(LOW_SPACE_BYTES * 2), true), (DEFAULT_BITRATE_KBPS, None));
+        assert_eq!(recording_plan(folder, None, true), (DEFAULT_BITRATE_KBPS, None));
+
+        let free = 500 * 1024 * 1024;
+        let (bitrate, warning) = recording_plan(folder, Some(free), false);
+        assert_eq!(bitrate, DEFAULT_BITRATE_KBPS);
+        let warning = warning.unwrap();
+        assert!(!warning.lowered_quality);
+        assert!((warning.hours_left - 6.07).abs() < 0.01);
+
+        let (bitrate, warning) = recording_plan(folder, Some(free), true);
+        assert_eq!(bitrate, LOW_SPACE_BITRATE_KBPS);
+        assert!(warning.unwrap().lowered_quality);
+    }
+
+    #[test]
+    fn counts_shared_recordings_once() {
+        let root = std::env::temp_dir().join(format!("meeting-storage-usage-{}", std::process::id()));
+        let (first, second) = (root.join("a"), root.join("b"));
+        std::fs::create_dir_all(first.join(".checkpoints")).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        std::fs::write(first.join("audio.mp4"), vec![0u8; 1000]).unwrap();
+        std::fs::write(first.join(".checkpoints").join("chunk.mp4"), vec![0u8; 200]).unwrap();
+        std::fs::hard_link(first.join("audio.mp4"), second.join("audio.mp4")).unwrap();
+
+        let mut seen = HashSet::new();
+        let mut unique = 0;
+        assert_eq!(folder_size(&first, &mut seen, &mut unique), 1200);
+        assert_eq!(folder_size(&second, &mut seen, &mut unique), 1000);
+        if cfg!(unix) {
+            assert_eq!(unique, 1200);
+        }
+        assert_eq!(folder_size(&root.join("missing"), &mut seen, &mut unique), 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
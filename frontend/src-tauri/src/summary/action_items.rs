@@ -0,0 +1,361 @@
+// summary/action_items.rs
+//
+// Structured action-item extraction. The configured summary model reads the numbered
+// transcript and returns a JSON array that must satisfy ACTION_ITEMS_SCHEMA; each item
+// keeps a reference to the segment it came from and is stored in its own table, apart
+// from the free-text minutes.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::models::ActionItem;
+use crate::database::repositories::action_item::{ActionItemsRepository, NewActionItem};
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::processor::rough_token_count;
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+/// JSON schema every reply must satisfy (also shown to the model)
+pub const ACTION_ITEMS_SCHEMA: &str = r#"{
+  "type": "array",
+  "items": {
+    "type": "object",
+    "additionalProperties": false,
+    "required": ["description", "assignee", "due_date", "segment"],
+    "properties": {
+      "description": { "type": "string", "minLength": 1 },
+      "assignee": { "type": ["string", "null"] },
+      "due_date": { "type": ["string", "null"], "format": "date" },
+      "segment": { "type": "integer", "minimum": 0 }
+    }
+  }
+}"#;
+
+const SYSTEM_PROMPT: &str = "You extract action items from meeting transcripts. An action item is a \
+concrete task someone committed to or was asked to do. Each transcript line starts with its segment \
+number in brackets. For every action item return the task description, the assignee exactly as named \
+in the transcript (null if nobody was named), the due date as YYYY-MM-DD only if one was stated or \
+clearly implied (null otherwise), and the number of the segment where it was agreed. Reply with only \
+a JSON array (an empty array if there are none) that validates against this JSON schema:";
+
+// Keep batches well below large context windows so the model's attention stays on detail
+const MAX_BATCH_TOKENS: usize = 24_000;
+// Room for the system prompt, schema and reply within a batch
+const PROMPT_OVERHEAD_TOKENS: usize = 1_500;
+
+/// An action item that passed schema validation. `segment` indexes the meeting's segments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedActionItem {
+    pub description: String,
+    pub assignee: Option<String>,
+    pub due_date: Option<String>,
+    pub segment: usize,
+}
+
+fn optional_string(item: &serde_json::Map<String, Value>, key: &str, index: usize) -> Result<Option<String>, String> {
+    match item.get(key) {
+        Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) if value.trim().is_empty() => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.trim().to_string())),
+        Some(_) => Err(format!("item {}: \"{}\" must be a string or null", index, key)),
+        None => Err(format!("item {}: missing required property \"{}\"", index, key)),
+    }
+}
+
+/// Validate a model reply against ACTION_ITEMS_SCHEMA. Prose or code fences around the
+/// array are tolerated; anything inside it that breaks the schema is an error, so the
+/// model can be asked to correct it. `segments` is the range of segment numbers the
+/// model was shown.
+pub fn validate_action_items(reply: &str, segments: Range<usize>) -> Result<Vec<ExtractedActionItem>, String> {
+    let json = match (reply.find('['), reply.rfind(']')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON array".to_string()),
+    };
+    let items: Vec<Value> = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+
+    let mut validated = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let item = item
+            .as_object()
+            .ok_or_else(|| format!("item {}: must be an object", index))?;
+        if let Some(key) = item
+            .keys()
+            .find(|key| !["description", "assignee", "due_date", "segment"].contains(&key.as_str()))
+        {
+            return Err(format!("item {}: unexpected property \"{}\"", index, key));
+        }
+
+        let description = match item.get("description") {
+            Some(Value::String(text)) if !text.trim().is_empty() => text.trim().to_string(),
+            Some(_) => return Err(format!("item {}: \"description\" must be a non-empty string", index)),
+            None => return Err(format!("item {}: missing required property \"description\"", index)),
+        };
+        let assignee = optional_string(item, "assignee", index)?;
+        let due_date = optional_string(item, "due_date", index)?;
+        if let Some(date) = &due_date {
+            if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                return Err(format!("item {}: \"due_date\" must be YYYY-MM-DD, got \"{}\"", index, date));
+            }
+        }
+        let segment = match item.get("segment").and_then(Value::as_u64) {
+            Some(segment) if segments.contains(&(segment as usize)) => segment as usize,
+            Some(segment) => {
+                return Err(format!(
+                    "item {}: \"segment\" {} is outside the transcript lines {}..{}",
+                    index, segment, segments.start, segments.end
+                ))
+            }
+            None => return Err(format!("item {}: \"segment\" must be a non-negative integer", index)),
+        };
+
+        validated.push(ExtractedActionItem {
+            description,
+            assignee,
+            due_date,
+            segment,
+        });
+    }
+    Ok(validated)
+}
+
+/// Drop items repeated across overlapping batches or restated later in the meeting
+fn dedup_action_items(items: Vec<ExtractedActionItem>) -> Vec<ExtractedActionItem> {
+    let mut seen = std::collections::HashSet::new();
+    items
+        .into_iter()
+        .filter(|item| {
+            let key: String = item
+                .description
+                .to_lowercase()
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect();
+            seen.insert((key, item.assignee.as_ref().map(|a| a.to_lowercase())))
+        })
+        .collect()
+}
+
+/// Transcript lines prefixed with their segment number, as "[i] Speaker: text"
+pub(crate) fn numbered_lines(segments: &[(String, String, Option<f64>, Option<String>)]) -> Vec<String> {
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, (_, text, _, speaker))| match speaker {
+            Some(speaker) => format!("[{}] {}: {}", i, speaker, text.trim()),
+            None => format!("[{}] {}", i, text.trim()),
+        })
+        .collect()
+}
+
+/// Transcript tokens per extraction request for this provider
+pub(crate) fn batch_budget(provider: &dyn SummaryProvider) -> usize {
+    provider
+        .context_tokens()
+        .unwrap_or(MAX_BATCH_TOKENS)
+        .min(MAX_BATCH_TOKENS)
+        .saturating_sub(PROMPT_OVERHEAD_TOKENS)
+        .max(PROMPT_OVERHEAD_TOKENS)
+}
+
+/// Group numbered transcript lines into batches that fit the token budget
+pub(crate) fn batch_lines(lines: &[String], budget: usize) -> Vec<Range<usize>> {
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut tokens = 0;
+    for (i, line) in lines.iter().enumerate() {
+        let line_tokens = rough_token_count(line);
+        if i > start && tokens + line_tokens > budget {
+            batches.push(start..i);
+            start = i;
+            tokens = 0;
+        }
+        tokens += line_tokens;
+    }
+    if start < lines.len() {
+        batches.push(start..lines.len());
+    }
+    batches
+}
+
+/// Ask the model for one batch of structured output, feeding a validation error back
+/// once before giving up
+pub(crate) async fn complete_validated<T>(
+    provider: &dyn SummaryProvider,
+    system_prompt: &str,
+    user_prompt: &str,
+    validate: impl Fn(&str) -> Result<T, String>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<T, SummaryError> {
+    let reply = provider.complete(system_prompt, user_prompt, cancellation_token).await?;
+    let error = match validate(&reply) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+    warn!("Structured reply failed validation ({}), retrying once", error);
+
+    let retry_prompt = format!(
+        "{}\n\nYour previous reply was:\n{}\n\nIt is invalid: {}. Reply again with only the corrected JSON.",
+        user_prompt, reply, error
+    );
+    let reply = provider.complete(system_prompt, &retry_prompt, cancellation_token).await?;
+    validate(&reply).map_err(SummaryError::InvalidResponse)
+}
+
+async fn extract_for_meeting<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+) -> Result<Vec<NewActionItem>, String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let summary_provider =
+        SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let summary_provider = metered(summary_provider, pool, Some(meeting_id), "action_items");
+
+    let lines = numbered_lines(&segments);
+    let batches = batch_lines(&lines, batch_budget(summary_provider.as_ref()));
+
+    // The meeting date lets the model resolve "by Friday" or "next week" to a real date
+    let system_prompt = format!(
+        "{}\n{}\n\nThe meeting took place on {}.",
+        SYSTEM_PROMPT,
+        ACTION_ITEMS_SCHEMA,
+        meeting.created_at.0.format("%A, %Y-%m-%d")
+    );
+
+    let mut extracted = Vec::new();
+    for (batch_index, range) in batches.iter().enumerate() {
+        let user_prompt = format!("<transcript>\n{}\n</transcript>", lines[range.clone()].join("\n"));
+        let validate = |reply: &str| validate_action_items(reply, range.clone());
+        match complete_validated(summary_provider.as_ref(), &system_prompt, &user_prompt, validate, None).await {
+            Ok(items) => extracted.extend(items),
+            Err(SummaryError::InvalidResponse(e)) => {
+                warn!("Skipping action items of batch {} for {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Action item extraction failed: {}", e)),
+        }
+        let _ = app.emit(
+            "action-items-progress",
+            serde_json::json!({
+                "meeting_id": meeting_id,
+                "completed_batches": batch_index + 1,
+                "total_batches": batches.len(),
+            }),
+        );
+    }
+
+    Ok(dedup_action_items(extracted)
+        .into_iter()
+        .map(|item| NewActionItem {
+            description: item.description,
+            assignee: item.assignee,
+            due_date: item.due_date,
+            segment_id: segments.get(item.segment).map(|(id, _, _, _)| id.clone()),
+        })
+        .collect())
+}
+
+/// Extract a meeting's action items with the configured summary model, replacing any
+/// previously extracted ones
+#[command]
+pub async fn extract_action_items<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<ActionItem>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let items = extract_for_meeting(&app, pool, &meeting_id).await?;
+    ActionItemsRepository::replace_for_meeting(pool, &meeting_id, &items)
+        .await
+        .map_err(|e| format!("Failed to save action items: {}", e))?;
+    info!("Extracted {} action item(s) for {}", items.len(), meeting_id);
+
+    ActionItemsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))
+}
+
+#[command]
+pub async fn get_action_items<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<Vec<ActionItem>, String> {
+    let state = app.state::<AppState>();
+    ActionItemsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))
+}
+
+#[command]
+pub async fn set_action_item_done<R: Runtime>(app: AppHandle<R>, id: String, done: bool) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let updated = ActionItemsRepository::set_done(state.db_manager.pool(), &id, done)
+        .await
+        .map_err(|e| format!("Failed to update action item: {}", e))?;
+    if !updated {
+        return Err(format!("Action item {} not found", id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_action_items() {
+        let reply = "```json\n[{\"description\":\"Send the deck\",\"assignee\":\"Ana\",\"due_date\":\"2026-01-09\",\"segment\":12},\
+                     {\"description\":\"Book a room\",\"assignee\":null,\"due_date\":null,\"segment\":10}]\n```";
+        let items = validate_action_items(reply, 10..20).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].assignee.as_deref(), Some("Ana"));
+        assert_eq!(items[1].due_date, None);
+
+        assert!(validate_action_items("[]", 0..5).unwrap().is_empty());
+        assert!(validate_action_items("No action items.", 0..5).is_err());
+        // Out-of-range segment, bad date, extra property, empty description
+        for bad in [
+            r#"[{"description":"x","assignee":null,"due_date":null,"segment":7}]"#,
+            r#"[{"description":"x","assignee":null,"due_date":"next Friday","segment":1}]"#,
+            r#"[{"description":"x","assignee":null,"due_date":null,"segment":1,"priority":"high"}]"#,
+            r#"[{"description":" ","assignee":null,"due_date":null,"segment":1}]"#,
+        ] {
+            assert!(validate_action_items(bad, 0..5).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_batches_and_dedup() {
+        let lines: Vec<String> = (0..10).map(|i| format!("[{}] {}", i, "word ".repeat(20))).collect();
+        let batches = batch_lines(&lines, 100);
+        assert!(batches.len() > 1);
+        assert_eq!(batches.first().unwrap().start, 0);
+        assert_eq!(batches.last().unwrap().end, 10);
+
+        let item = |description: &str, segment| ExtractedActionItem {
+            description: description.to_string(),
+            assignee: Some("Ana".to_string()),
+            due_date: None,
+            segment,
+        };
+        let deduped = dedup_action_items(vec![item("Send the deck.", 1), item("send the deck", 8), item("Book a room", 9)]);
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].segment, 1);
+    }
+}
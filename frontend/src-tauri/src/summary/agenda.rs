@@ -0,0 +1,318 @@
+// summary/agenda.rs
+//
+// Agenda coverage: when a meeting has an agenda, the summary model marks which transcript
+// segments discussed each agenda item. Time spent is measured from the segments' recording
+// timestamps rather than estimated by the model, and the result is appended to the minutes
+// as a table of discussed, brief and skipped items plus the time spent off the agenda.
+
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, batch_lines, complete_validated, numbered_lines};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You check which agenda items a meeting discussed. You get the numbered agenda \
+and part of the transcript, where each line starts with its segment number in brackets. For every stretch of \
+the transcript that discusses an agenda item, give the item number and the first and last segment of the \
+stretch. An item may have several stretches; leave out small talk and topics that are not on the agenda. \
+Reply with only a JSON array of {\"item\": <agenda number>, \"start_segment\": <number>, \"end_segment\": \
+<number>} objects, or [] when no agenda item is discussed in this part.";
+
+/// Items discussed for less than this are reported as only touched on
+const BRIEF_SECONDS: f64 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgendaStatus {
+    Discussed,
+    Brief,
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgendaItemCoverage {
+    pub item: String,
+    pub status: AgendaStatus,
+    /// Recording time spent on the item
+    pub seconds: f64,
+    /// Recording time where the item was first discussed
+    pub starts_at: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgendaCoverage {
+    pub items: Vec<AgendaItemCoverage>,
+    /// Time spent on topics that aren't on the agenda
+    pub off_agenda_seconds: f64,
+}
+
+/// Agenda items from free text: one per line, without bullets, numbering or checkboxes
+pub fn parse_agenda_items(agenda: &str) -> Vec<String> {
+    agenda
+        .lines()
+        .map(|line| {
+            let line = line.trim().trim_start_matches(['-', '*', '•']).trim_start();
+            let line = line.strip_prefix("[ ]").or_else(|| line.strip_prefix("[x]")).unwrap_or(line);
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            match line[digits..].strip_prefix(['.', ')']) {
+                Some(rest) if digits > 0 => rest.trim(),
+                _ => line.trim(),
+            }
+        })
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| !line.trim_end_matches(':').eq_ignore_ascii_case("agenda"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Validate a reply: an array of {item, start_segment, end_segment} with 1-based items
+/// and segments inside `segments`. Returns (item index, first segment, last segment).
+pub fn validate_agenda_spans(
+    reply: &str,
+    item_count: usize,
+    segments: Range<usize>,
+) -> Result<Vec<(usize, usize, usize)>, String> {
+    let json = match (reply.find('['), reply.rfind(']')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON array".to_string()),
+    };
+    let spans: Vec<Value> = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+
+    let mut result = Vec::with_capacity(spans.len());
+    for (index, span) in spans.iter().enumerate() {
+        let item = match span.get("item").and_then(Value::as_u64) {
+            Some(item) if item >= 1 && item as usize <= item_count => item as usize - 1,
+            _ => return Err(format!("span {}: \"item\" must be an agenda number from 1 to {}", index, item_count)),
+        };
+        let segment = |key: &str| match span.get(key).and_then(Value::as_u64) {
+            Some(segment) if segments.contains(&(segment as usize)) => Ok(segment as usize),
+            _ => Err(format!(
+                "span {}: \"{}\" must be one of the transcript lines {}..{}",
+                index, key, segments.start, segments.end
+            )),
+        };
+        let (start, end) = (segment("start_segment")?, segment("end_segment")?);
+        if end < start {
+            return Err(format!("span {}: \"end_segment\" is before \"start_segment\"", index));
+        }
+        result.push((item, start, end));
+    }
+    Ok(result)
+}
+
+/// Coverage of each item from the spans found, timed by segment start times (a segment
+/// lasts until the next one starts)
+pub fn measure_coverage(items: &[String], spans: &[(usize, usize, usize)], starts: &[Option<f64>]) -> AgendaCoverage {
+    let duration = |i: usize| match (starts[i], starts.get(i + 1).copied().flatten()) {
+        (Some(start), Some(next)) => (next - start).max(0.0),
+        _ => 0.0,
+    };
+    let timed = starts.iter().any(Option::is_some);
+
+    let mut on_agenda = vec![false; starts.len()];
+    let items = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let mut covered = vec![false; starts.len()];
+            for &(_, start, end) in spans.iter().filter(|(span_item, _, _)| *span_item == index) {
+                for segment in start..=end.min(starts.len().saturating_sub(1)) {
+                    covered[segment] = true;
+                    on_agenda[segment] = true;
+                }
+            }
+            let first = covered.iter().position(|covered| *covered);
+            let seconds: f64 = (0..starts.len()).filter(|i| covered[*i]).map(duration).sum();
+            let status = match first {
+                None => AgendaStatus::Skipped,
+                Some(_) if timed && seconds < BRIEF_SECONDS => AgendaStatus::Brief,
+                Some(_) => AgendaStatus::Discussed,
+            };
+            AgendaItemCoverage {
+                item: item.clone(),
+                status,
+                seconds,
+                starts_at: first.and_then(|segment| starts[segment]),
+            }
+        })
+        .collect();
+
+    AgendaCoverage {
+        items,
+        off_agenda_seconds: (0..starts.len()).filter(|i| !on_agenda[*i]).map(duration).sum(),
+    }
+}
+
+fn format_minutes(seconds: f64) -> String {
+    if seconds < 60.0 {
+        "<1 min".to_string()
+    } else {
+        format!("{} min", (seconds / 60.0).round())
+    }
+}
+
+/// "## Agenda Coverage" section for the minutes
+pub fn render_coverage_markdown(coverage: &AgendaCoverage) -> String {
+    let mut markdown = String::from("## Agenda Coverage\n\n| Agenda item | Status | Time | Starts at |\n|---|---|---|---|\n");
+    for item in &coverage.items {
+        let (status, time) = match item.status {
+            AgendaStatus::Discussed => ("Discussed", format_minutes(item.seconds)),
+            AgendaStatus::Brief => ("Briefly", format_minutes(item.seconds)),
+            AgendaStatus::Skipped => ("Not discussed", "–".to_string()),
+        };
+        let starts_at = item
+            .starts_at
+            .map(crate::utils::format_timestamp)
+            .unwrap_or_else(|| "–".to_string());
+        markdown.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            item.item.replace('|', "\\|"),
+            status,
+            time,
+            starts_at
+        ));
+    }
+    if coverage.off_agenda_seconds >= 60.0 {
+        markdown.push_str(&format!(
+            "\nOff-agenda discussion: {}\n",
+            format_minutes(coverage.off_agenda_seconds)
+        ));
+    }
+    markdown.trim_end().to_string()
+}
+
+/// Check which agenda items the meeting discussed. None when the agenda has no items or
+/// the meeting has no transcript segments.
+pub async fn analyze(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    agenda: &str,
+    provider: &dyn SummaryProvider,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<AgendaCoverage>, String> {
+    let items = parse_agenda_items(agenda);
+    if items.is_empty() {
+        return Ok(None);
+    }
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let numbered_agenda: Vec<String> = items.iter().enumerate().map(|(i, item)| format!("{}. {}", i + 1, item)).collect();
+    let lines = numbered_lines(&segments);
+    let mut spans = Vec::new();
+    for (batch_index, range) in batch_lines(&lines, batch_budget(provider)).into_iter().enumerate() {
+        let user_prompt = format!(
+            "<agenda>\n{}\n</agenda>\n\n<transcript>\n{}\n</transcript>",
+            numbered_agenda.join("\n"),
+            lines[range.clone()].join("\n")
+        );
+        let validate = |reply: &str| validate_agenda_spans(reply, items.len(), range.clone());
+        match complete_validated(provider, SYSTEM_PROMPT, &user_prompt, validate, cancellation_token).await {
+            Ok(batch_spans) => spans.extend(batch_spans),
+            Err(SummaryError::InvalidResponse(e)) => {
+                warn!("No agenda coverage for batch {} of {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Agenda coverage failed: {}", e)),
+        }
+    }
+
+    let starts: Vec<Option<f64>> = segments.iter().map(|(_, _, start, _)| *start).collect();
+    let coverage = measure_coverage(&items, &spans, &starts);
+    info!(
+        "Agenda coverage for {}: {} of {} item(s) discussed",
+        meeting_id,
+        coverage.items.iter().filter(|item| item.status != AgendaStatus::Skipped).count(),
+        items.len()
+    );
+    Ok(Some(coverage))
+}
+
+/// Attach an agenda to a meeting (one item per line); an empty agenda removes it
+#[command]
+pub async fn set_meeting_agenda<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    agenda: String,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let agenda = Some(agenda.trim()).filter(|agenda| !agenda.is_empty());
+    let updated = MeetingsRepository::set_agenda(state.db_manager.pool(), &meeting_id, agenda)
+        .await
+        .map_err(|e| format!("Failed to save agenda: {}", e))?;
+    if !updated {
+        return Err("Meeting not found".to_string());
+    }
+    Ok(())
+}
+
+/// Check the meeting's stored agenda against its transcript with the configured model
+#[command]
+pub async fn analyze_agenda_coverage<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<AgendaCoverage, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let agenda = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .and_then(|meeting| meeting.agenda)
+        .ok_or_else(|| "Attach an agenda to the meeting first".to_string())?;
+
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "agenda_coverage");
+    analyze(pool, &meeting_id, &agenda, provider.as_ref(), None)
+        .await?
+        .ok_or_else(|| "The agenda has no items or the meeting has no transcript".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_agenda_items() {
+        let agenda = "# Weekly sync\nAgenda:\n1. Budget review\n2) Hiring plan\n- [ ] Launch date\n* Q&A | misc\n\n";
+        assert_eq!(
+            parse_agenda_items(agenda),
+            vec!["Budget review", "Hiring plan", "Launch date", "Q&A | misc"]
+        );
+        assert_eq!(parse_agenda_items("2025 roadmap"), vec!["2025 roadmap"]);
+    }
+
+    #[test]
+    fn test_measure_coverage() {
+        let items = vec!["Budget".to_string(), "Hiring".to_string(), "Launch".to_string()];
+        // Segments start every 30s; the last one has no end so it counts for nothing
+        let starts: Vec<Option<f64>> = (0..8).map(|i| Some(i as f64 * 30.0)).collect();
+        let spans = vec![(0, 1, 3), (0, 2, 4), (1, 6, 6)];
+        let coverage = measure_coverage(&items, &spans, &starts);
+
+        assert_eq!(coverage.items[0].status, AgendaStatus::Discussed);
+        assert_eq!(coverage.items[0].seconds, 120.0);
+        assert_eq!(coverage.items[0].starts_at, Some(30.0));
+        assert_eq!(coverage.items[1].status, AgendaStatus::Brief);
+        assert_eq!(coverage.items[2].status, AgendaStatus::Skipped);
+        // Segments 0 and 5 are off the agenda
+        assert_eq!(coverage.off_agenda_seconds, 60.0);
+
+        assert!(validate_agenda_spans(r#"[{"item":3,"start_segment":10,"end_segment":12}]"#, 3, 10..20).is_ok());
+        assert!(validate_agenda_spans(r#"[{"item":4,"start_segment":10,"end_segment":12}]"#, 3, 10..20).is_err());
+        assert!(validate_agenda_spans(r#"[{"item":1,"start_segment":12,"end_segment":10}]"#, 3, 10..20).is_err());
+        assert_eq!(validate_agenda_spans("[]", 3, 10..20), Ok(Vec::new()));
+    }
+}
@@ -0,0 +1,184 @@
+// summary/auto_title.rs
+//
+// Automatic meeting titles: once a recording is saved, the summary model reads the
+// transcript and proposes a concise title plus a one-line description. The description is
+// always stored; the title only replaces the default timestamp name ("Meeting 2026-01-12_
+// 10-30-00"), never a title the user typed.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, complete_validated, numbered_lines};
+use crate::summary::processor::rough_token_count;
+use crate::summary::provider::SummaryProvider;
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You name meeting recordings. Read the transcript and reply with only a JSON \
+object {\"title\": \"...\", \"description\": \"...\"}. The title names the main topic in at most eight words, \
+without dates, quotes or a trailing period. The description is one sentence of at most 25 words saying what \
+was discussed or decided.";
+
+const MAX_TITLE_CHARS: usize = 80;
+const MAX_DESCRIPTION_CHARS: usize = 240;
+
+// Default names given when recording starts: "Meeting 2026-01-12_10-30-00" from the
+// backend and "Meeting 12_01_26_10_30_00" from the recording button
+static DEFAULT_TITLE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^Meeting \d{2,4}[-_]\d{2}[-_]\d{2,4}_\d{2}[-_]\d{2}[-_]\d{2}$").unwrap());
+
+/// Whether a meeting still has the name it was given automatically
+pub fn is_default_title(title: &str) -> bool {
+    let title = title.trim();
+    title.is_empty() || DEFAULT_TITLE_REGEX.is_match(title)
+}
+
+/// Validate a reply: a JSON object with a non-empty title and description
+fn validate_title_reply(reply: &str) -> Result<(String, String), String> {
+    let json = match (reply.find('{'), reply.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON object".to_string()),
+    };
+    let object: Value = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+    let field = |name: &str, max_chars: usize| match object.get(name).and_then(Value::as_str).map(str::trim) {
+        Some(text) if !text.is_empty() && text.chars().count() <= max_chars => Ok(text.to_string()),
+        Some(text) if !text.is_empty() => Err(format!("\"{}\" must be at most {} characters", name, max_chars)),
+        _ => Err(format!("\"{}\" must be a non-empty string", name)),
+    };
+    let title = field("title", MAX_TITLE_CHARS)?
+        .trim_matches(|c: char| c == '"' || c == '.' || c == '#')
+        .trim()
+        .to_string();
+    if title.is_empty() {
+        return Err("\"title\" must be a non-empty string".to_string());
+    }
+    Ok((title, field("description", MAX_DESCRIPTION_CHARS)?))
+}
+
+/// Transcript lines that fit the budget, evenly spread over the meeting so the end of a
+/// long meeting is represented as well as its start
+fn sample_lines(lines: &[String], budget: usize) -> Vec<&String> {
+    let total: usize = lines.iter().map(|line| rough_token_count(line)).sum();
+    let stride = total.div_ceil(budget.max(1)).max(1);
+    lines.iter().step_by(stride).collect()
+}
+
+/// Generate and store the title and description of a saved meeting. Returns the new
+/// (title, description), with the title only when it replaced the default name.
+async fn generate_for_meeting(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &dyn SummaryProvider,
+) -> Result<Option<(Option<String>, String)>, String> {
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let lines = numbered_lines(&segments);
+    let transcript = sample_lines(&lines, batch_budget(provider))
+        .into_iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let user_prompt = format!("<transcript>\n{}\n</transcript>", transcript);
+    let (title, description) = complete_validated(provider, SYSTEM_PROMPT, &user_prompt, validate_title_reply, None)
+        .await
+        .map_err(|e| format!("Title generation failed: {}", e))?;
+
+    let keep_user_title = !is_default_title(&meeting.title);
+    let renamed = MeetingsRepository::apply_generated_metadata(
+        pool,
+        meeting_id,
+        &meeting.title,
+        (!keep_user_title).then_some(title.as_str()),
+        &description,
+    )
+    .await
+    .map_err(|e| format!("Failed to save meeting title: {}", e))?;
+
+    Ok(Some((renamed.then_some(title), description)))
+}
+
+/// Title a just-saved meeting in the background. Emits `meeting-title-generated` so the
+/// sidebar and meeting view can refresh.
+pub fn spawn_for_meeting<R: Runtime>(app: &AppHandle<R>, meeting_id: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let pool = app.state::<AppState>().db_manager.pool().clone();
+        let provider = match SummaryService::configured_summary_provider(&pool, app.path().app_data_dir().ok()).await
+        {
+            Ok(provider) => metered(provider, &pool, Some(&meeting_id), "title"),
+            Err(e) => {
+                info!("Skipping automatic title for {}: {}", meeting_id, e);
+                return;
+            }
+        };
+
+        match generate_for_meeting(&pool, &meeting_id, provider.as_ref()).await {
+            Ok(Some((title, description))) => {
+                info!("Generated title {:?} for {}", title, meeting_id);
+                // The new title may match a meeting template the timestamp name didn't
+                crate::meeting_templates::spawn_apply_matching(&app, meeting_id.clone());
+                let _ = app.emit(
+                    "meeting-title-generated",
+                    serde_json::json!({
+                        "meeting_id": meeting_id,
+                        "title": title,
+                        "description": description,
+                    }),
+                );
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Automatic title for {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_default_title() {
+        assert!(is_default_title("Meeting 2026-01-12_10-30-00"));
+        assert!(is_default_title("Meeting 12_01_26_10_30_00"));
+        assert!(is_default_title("  "));
+        assert!(!is_default_title("Meeting with Acme"));
+        assert!(!is_default_title("Meeting 2026-01-12 budget review"));
+    }
+
+    #[test]
+    fn test_validate_title_reply() {
+        let reply = r#"Sure: {"title": "Q3 budget review.", "description": "Agreed to cut travel spend by 10%."}"#;
+        assert_eq!(
+            validate_title_reply(reply).unwrap(),
+            (
+                "Q3 budget review".to_string(),
+                "Agreed to cut travel spend by 10%.".to_string()
+            )
+        );
+        assert!(validate_title_reply(r#"{"title": "", "description": "x"}"#).is_err());
+        assert!(validate_title_reply(r#"{"title": "Budget"}"#).is_err());
+        assert!(validate_title_reply("Budget review").is_err());
+
+        let lines: Vec<String> = (0..10).map(|i| format!("[{}] Speaker 1: {}", i, "word ".repeat(20))).collect();
+        let sampled = sample_lines(&lines, 200);
+        assert!(sampled.len() < lines.len());
+        assert_eq!(sampled[0], &lines[0]);
+        assert_eq!(sample_lines(&lines, 100_000).len(), lines.len());
+    }
+}
@@ -0,0 +1,258 @@
+// summary/chapters.rs
+//
+// Topic segmentation: the summary model splits the numbered transcript into topical
+// chapters with short headings. Chapters are stored with their recording timestamps for
+// chapter-jump playback, passed to the minutes prompt as an outline so the discussion is
+// summarized topic by topic, and listed as a table of contents in the minutes.
+
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::models::MeetingChapter;
+use crate::database::repositories::meeting_chapter::{MeetingChaptersRepository, NewMeetingChapter};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, batch_lines, complete_validated, numbered_lines};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You split meeting transcripts into topical chapters. Each transcript line \
+starts with its segment number in brackets. Start a new chapter only where the conversation clearly moves \
+to a different topic; small meetings may have a single chapter. Give each chapter a heading of at most six \
+words. Reply with only a JSON array of {\"start_segment\": <number>, \"title\": \"<heading>\"} objects in \
+transcript order, where the first chapter starts at the first segment shown.";
+
+/// A chapter as segment indices into the meeting's segments (`end` exclusive)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub title: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Validate a reply: an array of {start_segment, title} with strictly increasing starts,
+/// the first at the start of `segments`
+pub fn validate_chapter_starts(reply: &str, segments: Range<usize>) -> Result<Vec<(usize, String)>, String> {
+    let json = match (reply.find('['), reply.rfind(']')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON array".to_string()),
+    };
+    let items: Vec<Value> = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+    if items.is_empty() {
+        return Err("there must be at least one chapter".to_string());
+    }
+
+    let mut starts: Vec<(usize, String)> = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let start = match item.get("start_segment").and_then(Value::as_u64) {
+            Some(start) if segments.contains(&(start as usize)) => start as usize,
+            _ => {
+                return Err(format!(
+                    "chapter {}: \"start_segment\" must be one of the transcript lines {}..{}",
+                    index, segments.start, segments.end
+                ))
+            }
+        };
+        let title = match item.get("title").and_then(Value::as_str).map(str::trim) {
+            Some(title) if !title.is_empty() => title.trim_matches('#').trim().to_string(),
+            _ => return Err(format!("chapter {}: \"title\" must be a non-empty string", index)),
+        };
+        if starts.last().is_some_and(|(previous, _)| start <= *previous) {
+            return Err(format!("chapter {}: \"start_segment\" values must increase", index));
+        }
+        starts.push((start, title));
+    }
+    if starts[0].0 != segments.start {
+        return Err(format!("the first chapter must start at segment {}", segments.start));
+    }
+    Ok(starts)
+}
+
+/// Turn chapter starts from all batches into chapters covering every segment, joining a
+/// chapter that continues across a batch boundary under the same heading
+pub fn build_chapters(mut starts: Vec<(usize, String)>, segment_count: usize) -> Vec<Chapter> {
+    starts.sort_by_key(|(start, _)| *start);
+    let mut chapters: Vec<Chapter> = Vec::new();
+    for (start, title) in starts {
+        if start >= segment_count {
+            continue;
+        }
+        if let Some(previous) = chapters.last_mut() {
+            if previous.title.eq_ignore_ascii_case(&title) {
+                continue;
+            }
+            previous.end = start;
+        }
+        chapters.push(Chapter {
+            title,
+            start,
+            end: segment_count,
+        });
+    }
+    chapters
+}
+
+fn format_start(start_time: Option<f64>) -> String {
+    start_time
+        .map(crate::utils::format_timestamp)
+        .unwrap_or_else(|| "--:--:--".to_string())
+}
+
+/// Outline passed to the minutes prompt so the discussion is summarized per chapter
+pub fn chapter_outline(chapters: &[NewMeetingChapter]) -> String {
+    let mut outline = String::from(
+        "The meeting covered these topics in order. Organize the discussion in the minutes by these topics:\n",
+    );
+    for (i, chapter) in chapters.iter().enumerate() {
+        outline.push_str(&format!("{}. {} (from {})\n", i + 1, chapter.title, format_start(chapter.start_time)));
+    }
+    outline.trim_end().to_string()
+}
+
+/// "## Chapters" table of contents for the minutes
+pub fn render_chapters_markdown(chapters: &[NewMeetingChapter]) -> String {
+    let mut markdown = String::from("## Chapters\n\n");
+    for chapter in chapters {
+        markdown.push_str(&format!("- **{}** {}\n", format_start(chapter.start_time), chapter.title));
+    }
+    markdown.trim_end().to_string()
+}
+
+/// Split a meeting into chapters and store them. None when the meeting has no
+/// transcript segments.
+pub async fn extract_and_store(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &dyn SummaryProvider,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<Vec<NewMeetingChapter>>, String> {
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let lines = numbered_lines(&segments);
+    let mut starts: Vec<(usize, String)> = Vec::new();
+    for (batch_index, range) in batch_lines(&lines, batch_budget(provider)).into_iter().enumerate() {
+        // Tell the model where the previous batch left off so a running topic keeps its heading
+        let mut user_prompt = String::new();
+        if let Some((_, title)) = starts.last() {
+            user_prompt.push_str(&format!(
+                "The previous part of the meeting ended in the chapter \"{}\". If this part continues that \
+                 topic, use exactly that heading for its first chapter.\n\n",
+                title
+            ));
+        }
+        user_prompt.push_str(&format!("<transcript>\n{}\n</transcript>", lines[range.clone()].join("\n")));
+
+        let validate = |reply: &str| validate_chapter_starts(reply, range.clone());
+        match complete_validated(provider, SYSTEM_PROMPT, &user_prompt, validate, cancellation_token).await {
+            Ok(batch_starts) => starts.extend(batch_starts),
+            Err(SummaryError::InvalidResponse(e)) => {
+                // Keep the previous chapter running over this batch rather than leaving a gap
+                warn!("No chapters for batch {} of {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Topic segmentation failed: {}", e)),
+        }
+    }
+    if starts.is_empty() {
+        return Err("Topic segmentation returned no valid chapters".to_string());
+    }
+    // Make sure the first chapter starts with the meeting even if the first batch failed
+    starts[0].0 = 0;
+
+    let chapters = build_chapters(starts, segments.len());
+    let rows: Vec<NewMeetingChapter> = chapters
+        .iter()
+        .enumerate()
+        .map(|(i, chapter)| NewMeetingChapter {
+            title: chapter.title.clone(),
+            start_segment_id: segments.get(chapter.start).map(|(id, _, _, _)| id.clone()),
+            start_time: segments.get(chapter.start).and_then(|(_, _, start, _)| *start),
+            end_time: chapters
+                .get(i + 1)
+                .and_then(|next| segments.get(next.start))
+                .and_then(|(_, _, start, _)| *start),
+        })
+        .collect();
+    MeetingChaptersRepository::replace_for_meeting(pool, meeting_id, &rows)
+        .await
+        .map_err(|e| format!("Failed to save chapters: {}", e))?;
+    info!("Split meeting {} into {} chapter(s)", meeting_id, rows.len());
+
+    Ok(Some(rows))
+}
+
+/// Chapters of a meeting in order, with start/end times for chapter-jump playback
+#[command]
+pub async fn get_meeting_chapters<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingChapter>, String> {
+    let state = app.state::<AppState>();
+    MeetingChaptersRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load chapters: {}", e))
+}
+
+/// Re-run topic segmentation for a meeting with the configured model
+#[command]
+pub async fn generate_meeting_chapters<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingChapter>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "chapters");
+    extract_and_store(pool, &meeting_id, provider.as_ref(), None).await?;
+    get_meeting_chapters(app, meeting_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_chapter_starts() {
+        let reply = r##"[{"start_segment":20,"title":"# Budget"},{"start_segment":31,"title":"Hiring plan"}]"##;
+        let starts = validate_chapter_starts(reply, 20..40).unwrap();
+        assert_eq!(starts, vec![(20, "Budget".to_string()), (31, "Hiring plan".to_string())]);
+
+        for bad in [
+            "[]",
+            r#"[{"start_segment":21,"title":"Budget"}]"#,
+            r#"[{"start_segment":20,"title":"Budget"},{"start_segment":20,"title":"Hiring"}]"#,
+            r#"[{"start_segment":20,"title":"Budget"},{"start_segment":45,"title":"Hiring"}]"#,
+        ] {
+            assert!(validate_chapter_starts(bad, 20..40).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_build_chapters_joins_across_batches() {
+        let starts = vec![
+            (0, "Intro".to_string()),
+            (5, "Budget".to_string()),
+            // Second batch continues the budget discussion
+            (20, "budget".to_string()),
+            (27, "Hiring plan".to_string()),
+        ];
+        let chapters = build_chapters(starts, 40);
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter { title: "Intro".to_string(), start: 0, end: 5 },
+                Chapter { title: "Budget".to_string(), start: 5, end: 27 },
+                Chapter { title: "Hiring plan".to_string(), start: 27, end: 40 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+// summary/claude_provider.rs
+//
+// Anthropic Claude summary provider on the Messages API. Claude's 200k-token context
+// fits the transcript of a full-day workshop in one request, so chunking only kicks in
+// for transcripts beyond that; the output budget is sized for complete minutes.
+
+use async_trait::async_trait;
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::summary::llm_client::ChatMessage;
+use crate::summary::openai_provider::StreamLine;
+use crate::summary::provider::{
+    cloud_http_client, read_stream_lines, send_with_retry, SummaryError, SummaryProvider, TokenCallback,
+};
+
+const CLAUDE_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+pub const DEFAULT_CLAUDE_MODEL: &str = "claude-3-5-sonnet-latest";
+/// Context window shared by all current Claude models
+pub const CLAUDE_CONTEXT_TOKENS: usize = 200_000;
+// Long meetings produce long minutes; the old 2048 limit cut them off mid-section
+const MAX_OUTPUT_TOKENS: u32 = 8192;
+// Room for the system prompt and template on top of the transcript
+const PROMPT_OVERHEAD_TOKENS: usize = 4000;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Serialize)]
+struct MessagesRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    system: &'a str,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    content: Vec<ContentBlock>,
+    stop_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    text: String,
+}
+
+/// Concatenated text blocks of a Messages API response
+fn response_text(blocks: &[ContentBlock]) -> String {
+    blocks
+        .iter()
+        .filter(|block| block.kind == "text")
+        .map(|block| block.text.as_str())
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// Parse one line of a Messages API event stream. Text arrives in
+/// `content_block_delta` events; `message_stop` ends the stream.
+fn parse_event_line(line: &str) -> std::result::Result<StreamLine, SummaryError> {
+    let data = match line.trim().strip_prefix("data:") {
+        Some(data) => data.trim(),
+        None => return Ok(StreamLine::Ignore),
+    };
+    let event: serde_json::Value = match serde_json::from_str(data) {
+        Ok(event) => event,
+        Err(_) => return Ok(StreamLine::Ignore),
+    };
+    match event["type"].as_str() {
+        Some("content_block_delta") => Ok(event["delta"]["text"]
+            .as_str()
+            .filter(|text| !text.is_empty())
+            .map(|text| StreamLine::Delta(text.to_string()))
+            .unwrap_or(StreamLine::Ignore)),
+        Some("message_delta") => {
+            if event["delta"]["stop_reason"].as_str() == Some("max_tokens") {
+                warn!("Claude response hit the {}-token output limit and may be truncated", MAX_OUTPUT_TOKENS);
+            }
+            Ok(StreamLine::Ignore)
+        }
+        Some("message_stop") => Ok(StreamLine::Done),
+        // Overloaded or failing mid-stream
+        Some("error") => Err(SummaryError::RequestFailed(format!(
+            "Claude stream error: {}",
+            event["error"]["message"].as_str().unwrap_or("unknown error")
+        ))),
+        _ => Ok(StreamLine::Ignore),
+    }
+}
+
+pub struct ClaudeSummaryProvider {
+    client: Client,
+    api_key: String,
+    model_name: String,
+}
+
+impl ClaudeSummaryProvider {
+    /// # Arguments
+    /// * `api_key` - Anthropic API key
+    /// * `model_name` - Claude model; empty uses the default
+    pub fn new(api_key: String, model_name: String) -> Self {
+        let model_name = if model_name.trim().is_empty() {
+            DEFAULT_CLAUDE_MODEL.to_string()
+        } else {
+            model_name
+        };
+        info!("🌐 Claude summary provider initialized with model: {}", model_name);
+        Self {
+            client: cloud_http_client(),
+            api_key,
+            model_name,
+        }
+    }
+
+    async fn send(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        stream: bool,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<Response, SummaryError> {
+        let request = MessagesRequest {
+            model: &self.model_name,
+            max_tokens: MAX_OUTPUT_TOKENS,
+            system: system_prompt,
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: user_prompt.to_string(),
+            }],
+            stream,
+        };
+
+        info!("🌐 Claude request: model={}", self.model_name);
+        send_with_retry(
+            "Claude",
+            || {
+                self.client
+                    .post(CLAUDE_MESSAGES_URL)
+                    .header("x-api-key", &self.api_key)
+                    .header("anthropic-version", ANTHROPIC_VERSION)
+                    .json(&request)
+                    .timeout(REQUEST_TIMEOUT)
+            },
+            cancellation_token,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for ClaudeSummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, false, cancellation_token).await?;
+        let messages_response = response
+            .json::<MessagesResponse>()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Failed to parse Claude response: {}", e)))?;
+        if messages_response.stop_reason.as_deref() == Some("max_tokens") {
+            warn!("Claude response hit the {}-token output limit and may be truncated", MAX_OUTPUT_TOKENS);
+        }
+
+        let text = response_text(&messages_response.content);
+        if text.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse("Claude returned no text".to_string()));
+        }
+        info!("🌐 Claude response received ({} chars)", text.len());
+        Ok(text.trim().to_string())
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, true, cancellation_token).await?;
+        let mut text = String::new();
+        let mut stream_error = None;
+        read_stream_lines(response, "Claude", cancellation_token, |line| match parse_event_line(line) {
+            Ok(StreamLine::Delta(delta)) => {
+                on_token(&delta);
+                text.push_str(&delta);
+                false
+            }
+            Ok(StreamLine::Done) => true,
+            Ok(StreamLine::Ignore) => false,
+            Err(e) => {
+                stream_error = Some(e);
+                true
+            }
+        })
+        .await?;
+        if let Some(e) = stream_error {
+            return Err(e);
+        }
+        if text.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse("Claude returned no text".to_string()));
+        }
+        Ok(text.trim().to_string())
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        Some(CLAUDE_CONTEXT_TOKENS - MAX_OUTPUT_TOKENS as usize - PROMPT_OVERHEAD_TOKENS)
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Claude"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_text_joins_text_blocks() {
+        let response: MessagesResponse = serde_json::from_str(
+            r#"{"content":[{"type":"text","text":"Decisions: "},{"type":"tool_use","id":"x"},{"type":"text","text":"ship it"}],"stop_reason":"end_turn"}"#,
+        )
+        .unwrap();
+        assert_eq!(response_text(&response.content), "Decisions: ship it");
+    }
+
+    #[test]
+    fn test_parse_event_line() {
+        let delta = r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Owner: Ana"}}"#;
+        assert_eq!(parse_event_line(delta).unwrap(), StreamLine::Delta("Owner: Ana".to_string()));
+        assert_eq!(parse_event_line("event: content_block_delta").unwrap(), StreamLine::Ignore);
+        assert_eq!(parse_event_line(r#"data: {"type":"message_stop"}"#).unwrap(), StreamLine::Done);
+        assert!(parse_event_line(r#"data: {"type":"error","error":{"message":"Overloaded"}}"#).is_err());
+    }
+}
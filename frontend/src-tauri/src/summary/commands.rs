@@ -2,8 +2,11 @@ use crate::database::repositories::{
     meeting::MeetingsRepository, summary::SummaryProcessesRepository,
     transcript_chunk::TranscriptChunksRepository,
 };
+use crate::audio::transcription::language_id::normalize_language_code;
 use crate::state::AppState;
 use crate::summary::service::SummaryService;
+use crate::summary::style::SummaryStyle;
+use crate::summary::versions;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Runtime};
@@ -79,6 +82,7 @@ pub async fn api_get_summary<R: Runtime>(
         meeting_id
     );
     let pool = state.db_manager.pool();
+    crate::protection::ensure_unlocked(pool, &meeting_id).await?;
 
     match SummaryProcessesRepository::get_summary_data_for_meeting(pool, &meeting_id).await {
         Ok(Some(process)) => {
@@ -176,6 +180,11 @@ pub async fn api_process_transcript<R: Runtime>(
     custom_prompt: Option<String>,
     template_id: Option<String>,
     _auth_token: Option<String>,
+    include_speaker_analytics: Option<bool>,
+    meeting_type: Option<String>,
+    agenda: Option<String>,
+    summary_style: Option<SummaryStyle>,
+    summary_language: Option<String>,
 ) -> Result<ProcessTranscriptResponse, String> {
     use uuid::Uuid;
 
@@ -188,7 +197,54 @@ pub async fn api_process_transcript<R: Runtime>(
 
     let pool = state.db_manager.pool().clone();
     let final_prompt = custom_prompt.unwrap_or_else(|| "".to_string());
-    let final_template_id = template_id.unwrap_or_else(|| "daily_standup".to_string());
+
+    // Unless a summary template was chosen, the meeting template's template and model are used
+    let meeting_template = match crate::meeting_templates::template_for_meeting(&pool, &m_id).await {
+        Ok(meeting_template) => meeting_template.filter(|_| template_id.is_none()),
+        Err(e) => {
+            log_warn!("{}", e);
+            None
+        }
+    };
+    let (model, model_name) = meeting_template
+        .as_ref()
+        .and_then(|meeting_template| meeting_template.summary_model())
+        .unwrap_or((model, model_name));
+    let final_template_id = template_id
+        .or_else(|| meeting_template.and_then(|meeting_template| meeting_template.summary_template_id))
+        .unwrap_or_else(|| "daily_standup".to_string());
+
+    // A chosen output language is remembered for the meeting; "" goes back to the meeting's own
+    let summary_language = match summary_language.as_deref().map(str::trim) {
+        None => None,
+        Some("") => {
+            MeetingsRepository::set_summary_language(&pool, &m_id, None)
+                .await
+                .map_err(|e| format!("Failed to save summary language: {}", e))?;
+            None
+        }
+        Some(language) => {
+            let code = normalize_language_code(language)
+                .ok_or_else(|| format!("Invalid summary language '{}', expected a language code like \"en\"", language))?;
+            MeetingsRepository::set_summary_language(&pool, &m_id, Some(&code))
+                .await
+                .map_err(|e| format!("Failed to save summary language: {}", e))?;
+            Some(code)
+        }
+    };
+
+    // A given agenda is attached to the meeting; "" removes it
+    if let Some(agenda) = agenda.as_deref().map(str::trim) {
+        let agenda = Some(agenda).filter(|agenda| !agenda.is_empty());
+        MeetingsRepository::set_agenda(&pool, &m_id, agenda)
+            .await
+            .map_err(|e| format!("Failed to save agenda: {}", e))?;
+    }
+
+    // Keep the summary being replaced (including manual edits) as a version
+    versions::snapshot_current(&pool, &m_id)
+        .await
+        .map_err(|e| format!("Failed to save the current summary version: {}", e))?;
 
     // Create or reset the process entry in the database
     SummaryProcessesRepository::create_or_reset_process(&pool, &m_id)
@@ -227,6 +283,11 @@ pub async fn api_process_transcript<R: Runtime>(
             model_name,
             final_prompt,
             final_template_id,
+            meeting_type,
+            agenda,
+            include_speaker_analytics.unwrap_or(false),
+            summary_style,
+            summary_language,
         )
         .await;
     });
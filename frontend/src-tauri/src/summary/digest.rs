@@ -0,0 +1,494 @@
+// summary/digest.rs
+//
+// Multi-meeting digests: one report over every meeting in a date range (optionally only
+// those with a tag). The summary model writes the overview and recurring themes from each
+// meeting's stored minutes; decisions and outstanding action items are listed from their
+// tables so nothing is paraphrased away. Digests can be generated on request or by a
+// weekly schedule that runs while the app is open.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::models::{ActionItem, MeetingDigest, MeetingModel, MeetingOutcome};
+use crate::database::repositories::action_item::ActionItemsRepository;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_digest::{MeetingDigestsRepository, NewMeetingDigest};
+use crate::database::repositories::meeting_outcome::MeetingOutcomesRepository;
+use crate::database::repositories::meeting_tag::{normalize_tag, MeetingTagsRepository};
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::state::{self, AppState};
+use crate::summary::action_items::batch_budget;
+use crate::summary::follow_up::stored_minutes;
+use crate::summary::processor::{clean_llm_markdown_output, rough_token_count};
+use crate::summary::provider::SummaryProvider;
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You write digests that combine the minutes of several meetings for someone who \
+missed them. Reply in Markdown with exactly two sections: \"## Overview\" (three to five sentences on what \
+happened across the meetings) and \"## Themes\" (bullets for topics that came up in more than one meeting or \
+matter most, naming the meetings involved). Do not list individual decisions or action items; those are added \
+separately. Use only what the minutes say.";
+
+const CONDENSE_SYSTEM_PROMPT: &str = "Condense these meeting minutes to at most 150 words, keeping the topics \
+discussed, decisions and unresolved issues. Output only the condensed notes.";
+
+/// How often the scheduler checks whether a digest is due
+const SCHEDULER_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
+fn default_days() -> u32 {
+    7
+}
+
+/// Weekly digest schedule, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestSchedule {
+    pub enabled: bool,
+    /// Day of the week the digest is generated, 0 = Monday
+    pub weekday: u32,
+    /// Local hour of the day (0-23)
+    pub hour: u32,
+    /// Days covered, ending at the scheduled time
+    #[serde(default = "default_days")]
+    pub days: u32,
+    /// Only include meetings with this tag
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl DigestSchedule {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.weekday > 6 {
+            return Err("Weekday must be between 0 (Monday) and 6 (Sunday)".to_string());
+        }
+        if self.hour > 23 {
+            return Err("Hour must be between 0 and 23".to_string());
+        }
+        if !(1..=31).contains(&self.days) {
+            return Err("A digest covers between 1 and 31 days".to_string());
+        }
+        Ok(())
+    }
+
+    /// The most recent scheduled time at or before `now` (local time)
+    fn last_slot(&self, now: NaiveDateTime) -> NaiveDateTime {
+        let days_back = (now.weekday().num_days_from_monday() + 7 - self.weekday) % 7;
+        let slot = (now.date() - Duration::days(i64::from(days_back)))
+            .and_hms_opt(self.hour, 0, 0)
+            .unwrap_or(now);
+        if slot > now {
+            slot - Duration::days(7)
+        } else {
+            slot
+        }
+    }
+}
+
+fn to_utc(local: NaiveDateTime) -> DateTime<Utc> {
+    Local
+        .from_local_datetime(&local)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
+
+/// UTC bounds of the local days `start`..=`end` ("YYYY-MM-DD")
+pub(crate) fn local_day_range(start: &str, end: &str) -> Result<(DateTime<Utc>, DateTime<Utc>), String> {
+    let parse = |date: &str| {
+        NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", date))
+    };
+    let (start, end) = (parse(start)?, parse(end)?);
+    if end < start {
+        return Err("The end date is before the start date".to_string());
+    }
+    let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap_or_default();
+    Ok((to_utc(midnight(start)), to_utc(midnight(end + Duration::days(1)))))
+}
+
+fn digest_title(start: DateTime<Utc>, end: DateTime<Utc>, tag: Option<&str>) -> String {
+    let first = start.with_timezone(&Local).date_naive();
+    // The range end is exclusive
+    let last = (end - Duration::seconds(1)).with_timezone(&Local).date_naive();
+    let range = if first == last {
+        first.format("%b %-d, %Y").to_string()
+    } else if first.year() == last.year() {
+        format!("{} – {}", first.format("%b %-d"), last.format("%b %-d, %Y"))
+    } else {
+        format!("{} – {}", first.format("%b %-d, %Y"), last.format("%b %-d, %Y"))
+    };
+    match tag {
+        Some(tag) => format!("Digest #{}: {}", tag, range),
+        None => format!("Digest: {}", range),
+    }
+}
+
+/// One meeting's share of the digest
+struct DigestMeeting {
+    meeting: MeetingModel,
+    notes: Option<String>,
+    decisions: Vec<MeetingOutcome>,
+    open_action_items: Vec<ActionItem>,
+}
+
+impl DigestMeeting {
+    fn label(&self) -> String {
+        format!(
+            "{}, {}",
+            self.meeting.title,
+            self.meeting.created_at.0.with_timezone(&Local).format("%a %b %-d")
+        )
+    }
+}
+
+/// Meetings, decisions and outstanding action items, listed from the database
+fn render_listed_sections(meetings: &[DigestMeeting]) -> String {
+    let mut markdown = String::from("## Meetings\n\n");
+    for meeting in meetings {
+        markdown.push_str(&format!("- **{}**", meeting.label()));
+        if let Some(description) = &meeting.meeting.description {
+            markdown.push_str(&format!(" — {}", description));
+        }
+        markdown.push('\n');
+    }
+
+    let decisions: Vec<String> = meetings
+        .iter()
+        .flat_map(|meeting| {
+            meeting
+                .decisions
+                .iter()
+                .map(move |decision| format!("- {} _({})_", decision.text, meeting.meeting.title))
+        })
+        .collect();
+    if !decisions.is_empty() {
+        markdown.push_str(&format!("\n## Decisions\n\n{}\n", decisions.join("\n")));
+    }
+
+    let action_items: Vec<String> = meetings
+        .iter()
+        .flat_map(|meeting| {
+            meeting.open_action_items.iter().map(move |item| {
+                let mut line = format!(
+                    "- [ ] **{}**: {}",
+                    item.assignee.as_deref().unwrap_or("Unassigned"),
+                    item.description
+                );
+                if let Some(due_date) = &item.due_date {
+                    line.push_str(&format!(" (due {})", due_date));
+                }
+                line.push_str(&format!(" _({})_", meeting.meeting.title));
+                line
+            })
+        })
+        .collect();
+    if !action_items.is_empty() {
+        markdown.push_str(&format!("\n## Outstanding Action Items\n\n{}\n", action_items.join("\n")));
+    }
+    markdown.trim_end().to_string()
+}
+
+async fn load_digest_meetings(
+    pool: &SqlitePool,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<&str>,
+) -> Result<Vec<DigestMeeting>, String> {
+    let mut meetings: Vec<MeetingModel> = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?
+        .into_iter()
+        .filter(|meeting| meeting.created_at.0 >= start && meeting.created_at.0 < end)
+        .collect();
+    if let Some(tag) = tag {
+        let tagged = MeetingTagsRepository::meeting_ids_with_tag(pool, tag)
+            .await
+            .map_err(|e| format!("Failed to load tags: {}", e))?;
+        meetings.retain(|meeting| tagged.contains(&meeting.id));
+    }
+    meetings.sort_by_key(|meeting| meeting.created_at.0);
+
+    let mut digest_meetings = Vec::with_capacity(meetings.len());
+    for meeting in meetings {
+        let process = SummaryProcessesRepository::get_summary_data(pool, &meeting.id)
+            .await
+            .map_err(|e| format!("Failed to load summary: {}", e))?;
+        let decisions = MeetingOutcomesRepository::list_for_meeting(pool, &meeting.id)
+            .await
+            .map_err(|e| format!("Failed to load decisions: {}", e))?
+            .into_iter()
+            .filter(|outcome| outcome.kind == "decision")
+            .collect();
+        let open_action_items = ActionItemsRepository::list_for_meeting(pool, &meeting.id)
+            .await
+            .map_err(|e| format!("Failed to load action items: {}", e))?
+            .into_iter()
+            .filter(|item| !item.done)
+            .collect();
+        digest_meetings.push(DigestMeeting {
+            notes: stored_minutes(process.as_ref().and_then(|p| p.result.as_deref()))
+                .or_else(|| meeting.description.clone()),
+            meeting,
+            decisions,
+            open_action_items,
+        });
+    }
+    Ok(digest_meetings)
+}
+
+/// Overview and themes written by the model from the meetings' minutes, condensing each
+/// meeting first when all of them don't fit the model's context
+async fn synthesize(provider: &dyn SummaryProvider, meetings: &[DigestMeeting]) -> Result<String, String> {
+    let mut notes: Vec<(String, String)> = meetings
+        .iter()
+        .filter_map(|meeting| Some((meeting.label(), meeting.notes.clone()?)))
+        .collect();
+    if notes.is_empty() {
+        return Ok("## Overview\n\nNone of these meetings have minutes yet.".to_string());
+    }
+
+    let budget = batch_budget(provider);
+    if notes.iter().map(|(_, text)| rough_token_count(text)).sum::<usize>() > budget {
+        info!("Condensing {} meeting(s) to fit the digest prompt", notes.len());
+        for (_, text) in notes.iter_mut() {
+            *text = provider
+                .complete(CONDENSE_SYSTEM_PROMPT, text, None)
+                .await
+                .map_err(|e| format!("Failed to condense minutes: {}", e))?;
+        }
+    }
+
+    let user_prompt = notes
+        .iter()
+        .map(|(label, text)| format!("<meeting name=\"{}\">\n{}\n</meeting>", label, text.trim()))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let reply = provider
+        .complete(SYSTEM_PROMPT, &user_prompt, None)
+        .await
+        .map_err(|e| format!("Failed to generate digest: {}", e))?;
+    Ok(clean_llm_markdown_output(&reply))
+}
+
+/// Generate and store a digest of the meetings between `start` and `end`
+pub async fn generate_digest(
+    pool: &SqlitePool,
+    provider: &dyn SummaryProvider,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    tag: Option<&str>,
+    scheduled: bool,
+) -> Result<MeetingDigest, String> {
+    let tag = tag.map(normalize_tag).filter(|tag| !tag.is_empty());
+    let meetings = load_digest_meetings(pool, start, end, tag.as_deref()).await?;
+    let title = digest_title(start, end, tag.as_deref());
+
+    let body = if meetings.is_empty() {
+        "No meetings were recorded in this period.".to_string()
+    } else {
+        format!(
+            "{}\n\n{}",
+            synthesize(provider, &meetings).await?,
+            render_listed_sections(&meetings)
+        )
+    };
+    let digest = NewMeetingDigest {
+        markdown: format!("# {}\n\n{}", title, body),
+        title,
+        range_start: start,
+        range_end: end,
+        tag,
+        meeting_ids: meetings.iter().map(|meeting| meeting.meeting.id.clone()).collect(),
+        scheduled,
+    };
+    let id = MeetingDigestsRepository::insert(pool, &digest)
+        .await
+        .map_err(|e| format!("Failed to save digest: {}", e))?;
+    info!("Generated digest {} over {} meeting(s)", id, digest.meeting_ids.len());
+
+    MeetingDigestsRepository::get(pool, &id)
+        .await
+        .map_err(|e| format!("Failed to load digest: {}", e))?
+        .ok_or_else(|| format!("Digest {} not found", id))
+}
+
+/// Run the scheduled digest if one is due
+async fn run_schedule_if_due<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool) -> Result<(), String> {
+    let schedule = match SettingsRepository::get_digest_schedule(pool)
+        .await
+        .map_err(|e| format!("Failed to load digest schedule: {}", e))?
+    {
+        Some(schedule) if schedule.enabled => schedule,
+        _ => return Ok(()),
+    };
+    let slot = schedule.last_slot(Local::now().naive_local());
+    let end = to_utc(slot);
+    let last_run = MeetingDigestsRepository::last_scheduled_at(pool)
+        .await
+        .map_err(|e| format!("Failed to load digests: {}", e))?;
+    if last_run.is_some_and(|last_run| last_run >= end) {
+        return Ok(());
+    }
+
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, None, "digest");
+    let start = to_utc(slot - Duration::days(i64::from(schedule.days)));
+    let digest = generate_digest(pool, provider.as_ref(), start, end, schedule.tag.as_deref(), true).await?;
+    let _ = app.emit("digest-generated", &digest);
+    Ok(())
+}
+
+/// Check the digest schedule periodically for as long as the app runs
+pub fn start_scheduler<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                if let Err(e) = run_schedule_if_due(&app, &pool).await {
+                    warn!("Scheduled digest failed: {}", e);
+                }
+            }
+            tokio::time::sleep(SCHEDULER_INTERVAL).await;
+        }
+    });
+}
+
+/// Generate a digest of all meetings from `start_date` to `end_date` (inclusive,
+/// "YYYY-MM-DD"), optionally only those tagged `tag`
+#[command]
+pub async fn generate_meeting_digest<R: Runtime>(
+    app: AppHandle<R>,
+    start_date: String,
+    end_date: String,
+    tag: Option<String>,
+) -> Result<MeetingDigest, String> {
+    let (start, end) = local_day_range(&start_date, &end_date)?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, None, "digest");
+    generate_digest(pool, provider.as_ref(), start, end, tag.as_deref(), false).await
+}
+
+#[command]
+pub async fn list_meeting_digests<R: Runtime>(app: AppHandle<R>) -> Result<Vec<MeetingDigest>, String> {
+    let state = app.state::<AppState>();
+    MeetingDigestsRepository::list(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load digests: {}", e))
+}
+
+#[command]
+pub async fn delete_meeting_digest<R: Runtime>(app: AppHandle<R>, id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let deleted = MeetingDigestsRepository::delete(state.db_manager.pool(), &id)
+        .await
+        .map_err(|e| format!("Failed to delete digest: {}", e))?;
+    if !deleted {
+        return Err(format!("Digest {} not found", id));
+    }
+    Ok(())
+}
+
+#[command]
+pub async fn get_digest_schedule<R: Runtime>(app: AppHandle<R>) -> Result<Option<DigestSchedule>, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_digest_schedule(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load digest schedule: {}", e))
+}
+
+#[command]
+pub async fn save_digest_schedule<R: Runtime>(app: AppHandle<R>, schedule: DigestSchedule) -> Result<(), String> {
+    schedule.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_digest_schedule(state.db_manager.pool(), &schedule)
+        .await
+        .map_err(|e| format!("Failed to save digest schedule: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::models::DateTimeUtc;
+
+    #[test]
+    fn test_last_slot() {
+        // Fridays at 17:00
+        let schedule = DigestSchedule {
+            enabled: true,
+            weekday: 4,
+            hour: 17,
+            days: 7,
+            tag: None,
+        };
+        let at = |date: &str, hour: u32| {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        // 2026-01-16 is a Friday
+        assert_eq!(schedule.last_slot(at("2026-01-16", 17)), at("2026-01-16", 17));
+        assert_eq!(schedule.last_slot(at("2026-01-16", 9)), at("2026-01-09", 17));
+        assert_eq!(schedule.last_slot(at("2026-01-19", 8)), at("2026-01-16", 17));
+        assert!(DigestSchedule { hour: 24, ..schedule }.validate().is_err());
+    }
+
+    #[test]
+    fn test_render_listed_sections() {
+        let created_at = Utc.with_ymd_and_hms(2026, 1, 12, 10, 0, 0).unwrap();
+        let meeting = DigestMeeting {
+            meeting: MeetingModel {
+                id: "meeting-1".to_string(),
+                title: "Pricing sync".to_string(),
+                created_at: DateTimeUtc(created_at),
+                updated_at: DateTimeUtc(created_at),
+                folder_path: None,
+                description: Some("Agreed on the new tiers.".to_string()),
+                summary_language: None,
+                agenda: None,
+                starred: false,
+                library_folder_id: None,
+                scheduled_start: None,
+                duration_seconds: None,
+                platform: None,
+                calendar_event_id: None,
+            },
+            notes: None,
+            decisions: vec![MeetingOutcome {
+                id: "outcome-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                kind: "decision".to_string(),
+                text: "Launch three tiers".to_string(),
+                segment_ids: "[]".to_string(),
+                created_at,
+            }],
+            open_action_items: vec![ActionItem {
+                id: "action-1".to_string(),
+                meeting_id: "meeting-1".to_string(),
+                description: "Update the pricing page".to_string(),
+                assignee: None,
+                due_date: Some("2026-01-20".to_string()),
+                segment_id: None,
+                done: false,
+                issue_key: None,
+                issue_url: None,
+                created_at,
+            }],
+        };
+        let markdown = render_listed_sections(&[meeting]);
+        assert!(markdown.contains("— Agreed on the new tiers."));
+        assert!(markdown.contains("## Decisions\n\n- Launch three tiers _(Pricing sync)_"));
+        assert!(markdown.ends_with(
+            "## Outstanding Action Items\n\n- [ ] **Unassigned**: Update the pricing page (due 2026-01-20) _(Pricing sync)_"
+        ));
+
+        assert!(local_day_range("2026-01-12", "2026-01-11").is_err());
+        let (start, end) = local_day_range("2026-01-12", "2026-01-18").unwrap();
+        assert_eq!(end - start, Duration::days(7));
+    }
+}
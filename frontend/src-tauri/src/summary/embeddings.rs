@@ -0,0 +1,295 @@
+// summary/embeddings.rs
+//
+// Text embeddings for semantic search and Q&A. Segments are embedded either locally
+// through an Ollama embedding model (nothing leaves the machine) or with an embeddings
+// API: OpenAI, or the OpenAI-compatible custom endpoint. The model is chosen separately
+// from the summary model because chat models can't produce embeddings.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::time::Duration;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::summary::ollama_provider::normalize_host;
+use crate::summary::provider::{cloud_http_client, send_with_retry, SummaryError};
+
+const OPENAI_EMBEDDINGS_URL: &str = "https://api.openai.com/v1/embeddings";
+pub const DEFAULT_OLLAMA_EMBEDDING_MODEL: &str = "nomic-embed-text";
+pub const DEFAULT_OPENAI_EMBEDDING_MODEL: &str = "text-embedding-3-small";
+pub const EMBEDDING_PROVIDERS: &[&str] = &["ollama", "openai", "custom-openai"];
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Embedding model settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingConfig {
+    /// "ollama", "openai" or "custom-openai"
+    pub provider: String,
+    /// Model name; empty uses the provider's default
+    #[serde(default)]
+    pub model: String,
+}
+
+impl EmbeddingConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if !EMBEDDING_PROVIDERS.contains(&self.provider.as_str()) {
+            return Err(format!(
+                "Unknown embedding provider '{}'. Must be one of: {}",
+                self.provider,
+                EMBEDDING_PROVIDERS.join(", ")
+            ));
+        }
+        if self.provider == "custom-openai" && self.model.trim().is_empty() {
+            return Err("An embedding model name is required for a custom endpoint".to_string());
+        }
+        Ok(())
+    }
+
+    /// Model name with the provider default filled in
+    pub fn model_name(&self) -> String {
+        match self.model.trim() {
+            "" if self.provider == "openai" => DEFAULT_OPENAI_EMBEDDING_MODEL.to_string(),
+            "" => DEFAULT_OLLAMA_EMBEDDING_MODEL.to_string(),
+            model => model.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Embed texts, returning one vector per text in the same order
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SummaryError>;
+
+    /// Model identifier; vectors from different models are not comparable
+    fn model_name(&self) -> &str;
+
+    fn provider_name(&self) -> &'static str;
+}
+
+fn check_count(provider: &str, vectors: Vec<Vec<f32>>, expected: usize) -> Result<Vec<Vec<f32>>, SummaryError> {
+    if vectors.len() != expected || vectors.iter().any(Vec::is_empty) {
+        return Err(SummaryError::InvalidResponse(format!(
+            "{} returned {} embedding(s) for {} text(s)",
+            provider,
+            vectors.len(),
+            expected
+        )));
+    }
+    Ok(vectors)
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaEmbedRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaEmbedResponse {
+    #[serde(default)]
+    embeddings: Vec<Vec<f32>>,
+}
+
+/// Local embeddings through Ollama's native /api/embed endpoint
+pub struct OllamaEmbeddingProvider {
+    client: Client,
+    host: String,
+    model_name: String,
+}
+
+impl OllamaEmbeddingProvider {
+    pub fn new(host: Option<String>, model_name: String) -> Self {
+        Self {
+            client: Client::new(),
+            host: normalize_host(host.as_deref()),
+            model_name,
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OllamaEmbeddingProvider {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SummaryError> {
+        let url = format!("{}/api/embed", self.host);
+        let body = OllamaEmbedRequest {
+            model: &self.model_name,
+            input: texts,
+        };
+        let response = send_with_retry(
+            "Ollama",
+            || self.client.post(&url).json(&body).timeout(REQUEST_TIMEOUT),
+            None,
+        )
+        .await?;
+        let parsed: OllamaEmbedResponse = response
+            .json()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Invalid Ollama embeddings response: {}", e)))?;
+        check_count("Ollama", parsed.embeddings, texts.len())
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Ollama"
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OpenAIEmbedRequest<'a> {
+    model: &'a str,
+    input: &'a [String],
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIEmbedResponse {
+    data: Vec<OpenAIEmbedding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIEmbedding {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+/// Embeddings from OpenAI or any OpenAI-compatible `/embeddings` endpoint
+pub struct OpenAIEmbeddingProvider {
+    client: Client,
+    url: String,
+    api_key: Option<String>,
+    model_name: String,
+}
+
+impl OpenAIEmbeddingProvider {
+    /// # Arguments
+    /// * `base_url` - API base such as "http://localhost:8000/v1"; None uses OpenAI
+    /// * `api_key` - Bearer token, if the endpoint needs one
+    /// * `model_name` - Embedding model (e.g., "text-embedding-3-small")
+    pub fn new(base_url: Option<&str>, api_key: Option<String>, model_name: String) -> Self {
+        let url = match base_url {
+            Some(base_url) => format!("{}/embeddings", base_url.trim_end_matches('/')),
+            None => OPENAI_EMBEDDINGS_URL.to_string(),
+        };
+        Self {
+            client: cloud_http_client(),
+            url,
+            api_key: api_key.filter(|key| !key.is_empty()),
+            model_name,
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAIEmbeddingProvider {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SummaryError> {
+        let body = OpenAIEmbedRequest {
+            model: &self.model_name,
+            input: texts,
+        };
+        let response = send_with_retry(
+            "OpenAI embeddings",
+            || {
+                let request = self.client.post(&self.url).json(&body).timeout(REQUEST_TIMEOUT);
+                match &self.api_key {
+                    Some(api_key) => request.bearer_auth(api_key),
+                    None => request,
+                }
+            },
+            None,
+        )
+        .await?;
+        let mut parsed: OpenAIEmbedResponse = response
+            .json()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Invalid embeddings response: {}", e)))?;
+        // The API may return items out of order; `index` is authoritative
+        parsed.data.sort_by_key(|item| item.index);
+        check_count(
+            "OpenAI embeddings",
+            parsed.data.into_iter().map(|item| item.embedding).collect(),
+            texts.len(),
+        )
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "OpenAI"
+    }
+}
+
+/// Build the embedding provider from settings. None when semantic indexing is off.
+pub async fn configured_embedding_provider(pool: &SqlitePool) -> Result<Option<Box<dyn EmbeddingProvider>>, String> {
+    let config = match SettingsRepository::get_embedding_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load embedding settings: {}", e))?
+    {
+        Some(config) => config,
+        None => return Ok(None),
+    };
+    config.validate()?;
+
+    let provider: Box<dyn EmbeddingProvider> = match config.provider.as_str() {
+        "ollama" => {
+            let host = SettingsRepository::get_model_config(pool)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|settings| settings.ollama_endpoint);
+            Box::new(OllamaEmbeddingProvider::new(host, config.model_name()))
+        }
+        "openai" => {
+            let api_key = SettingsRepository::get_api_key(pool, "openai")
+                .await
+                .map_err(|e| format!("Failed to retrieve API key for openai: {}", e))?
+                .filter(|key| !key.is_empty())
+                .ok_or_else(|| "API key not found for openai".to_string())?;
+            Box::new(OpenAIEmbeddingProvider::new(None, Some(api_key), config.model_name()))
+        }
+        _ => {
+            let custom = SettingsRepository::get_custom_openai_config(pool)
+                .await
+                .map_err(|e| format!("Failed to load custom endpoint: {}", e))?
+                .ok_or_else(|| "No custom OpenAI-compatible endpoint configured".to_string())?;
+            Box::new(OpenAIEmbeddingProvider::new(
+                Some(&custom.endpoint),
+                custom.api_key,
+                config.model_name(),
+            ))
+        }
+    };
+    info!(
+        "Embedding provider: {} ({})",
+        provider.provider_name(),
+        provider.model_name()
+    );
+    Ok(Some(provider))
+}
+
+#[command]
+pub async fn get_embedding_config<R: Runtime>(app: AppHandle<R>) -> Result<Option<EmbeddingConfig>, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_embedding_config(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load embedding settings: {}", e))
+}
+
+/// Choose the embedding model, or pass None to turn semantic indexing off
+#[command]
+pub async fn save_embedding_config<R: Runtime>(app: AppHandle<R>, config: Option<EmbeddingConfig>) -> Result<(), String> {
+    if let Some(config) = &config {
+        config.validate()?;
+    }
+    let state = app.state::<AppState>();
+    SettingsRepository::save_embedding_config(state.db_manager.pool(), config.as_ref())
+        .await
+        .map_err(|e| format!("Failed to save embedding settings: {}", e))
+}
@@ -0,0 +1,266 @@
+// summary/feedback.rs
+//
+// Summary feedback loop. Users rate a generated summary, or one of its sections, with a
+// thumbs up/down and an optional comment. Each rating is stored with the template,
+// provider and model of the version it was given on, and the report aggregates ratings
+// per template/provider/model and per section, with the latest comments, so prompt
+// templates can be tuned on what users actually thought of their output.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::models::SummaryFeedback;
+use crate::database::repositories::summary_feedback::{NewSummaryFeedback, SummaryFeedbackRepository};
+use crate::database::repositories::summary_version::SummaryVersionsRepository;
+use crate::state::AppState;
+
+// Comments listed per group in the report
+const MAX_COMMENTS: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionFeedback {
+    /// None for ratings on the summary as a whole
+    pub section: Option<String>,
+    pub up: usize,
+    pub down: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedbackComment {
+    pub meeting_id: String,
+    pub section: Option<String>,
+    pub rating: i64,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Ratings of the summaries one template wrote with one model
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedbackGroup {
+    pub template_id: Option<String>,
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub up: usize,
+    pub down: usize,
+    /// Share of thumbs up, 0.0-1.0
+    pub approval: f64,
+    /// Most disliked sections first
+    pub sections: Vec<SectionFeedback>,
+    /// Newest first
+    pub comments: Vec<FeedbackComment>,
+}
+
+/// Headings of a markdown summary, without the leading #s
+fn section_headings(markdown: &str) -> Vec<String> {
+    markdown
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('#'))
+        .map(|line| line.trim_start_matches('#').trim().to_string())
+        .filter(|heading| !heading.is_empty())
+        .collect()
+}
+
+/// Group feedback (newest first) by template, provider and model; groups with the most
+/// ratings come first
+fn build_report(feedback: &[SummaryFeedback]) -> Vec<FeedbackGroup> {
+    type Key = (Option<String>, Option<String>, Option<String>);
+    let mut groups: BTreeMap<Key, FeedbackGroup> = BTreeMap::new();
+    for item in feedback {
+        let key = (item.template_id.clone(), item.provider.clone(), item.model.clone());
+        let group = groups.entry(key).or_insert_with(|| FeedbackGroup {
+            template_id: item.template_id.clone(),
+            provider: item.provider.clone(),
+            model: item.model.clone(),
+            up: 0,
+            down: 0,
+            approval: 0.0,
+            sections: Vec::new(),
+            comments: Vec::new(),
+        });
+
+        let positive = item.rating > 0;
+        if positive {
+            group.up += 1;
+        } else {
+            group.down += 1;
+        }
+        let position = group.sections.iter().position(|section| section.section == item.section);
+        let section = match position {
+            Some(index) => &mut group.sections[index],
+            None => {
+                group.sections.push(SectionFeedback {
+                    section: item.section.clone(),
+                    up: 0,
+                    down: 0,
+                });
+                group.sections.last_mut().expect("just pushed")
+            }
+        };
+        if positive {
+            section.up += 1;
+        } else {
+            section.down += 1;
+        }
+
+        if let Some(comment) = item.comment.as_deref().filter(|comment| !comment.trim().is_empty()) {
+            if group.comments.len() < MAX_COMMENTS {
+                group.comments.push(FeedbackComment {
+                    meeting_id: item.meeting_id.clone(),
+                    section: item.section.clone(),
+                    rating: item.rating,
+                    comment: comment.trim().to_string(),
+                    created_at: item.created_at,
+                });
+            }
+        }
+    }
+
+    let mut groups: Vec<FeedbackGroup> = groups
+        .into_values()
+        .map(|mut group| {
+            group.approval = group.up as f64 / (group.up + group.down) as f64;
+            group
+                .sections
+                .sort_by(|a, b| b.down.cmp(&a.down).then(a.up.cmp(&b.up)));
+            group
+        })
+        .collect();
+    groups.sort_by_key(|group| std::cmp::Reverse(group.up + group.down));
+    groups
+}
+
+/// Rate the meeting's latest generated summary, or one of its sections
+///
+/// # Arguments
+/// * `section` - Heading of the rated section; None rates the whole summary
+/// * `positive` - Thumbs up (true) or down (false)
+/// * `comment` - Optional free-text feedback
+#[command]
+pub async fn submit_summary_feedback<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    section: Option<String>,
+    positive: bool,
+    comment: Option<String>,
+) -> Result<SummaryFeedback, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let versions = SummaryVersionsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary versions: {}", e))?;
+    let generated = versions.iter().find(|version| version.source == "generated");
+
+    let section = section.map(|section| section.trim().to_string()).filter(|section| !section.is_empty());
+    if let (Some(section), Some(version)) = (&section, generated) {
+        if !section_headings(&version.markdown).contains(section) {
+            return Err(format!("The summary has no section \"{}\"", section));
+        }
+    }
+
+    let feedback = SummaryFeedbackRepository::insert(
+        pool,
+        &NewSummaryFeedback {
+            meeting_id: &meeting_id,
+            version: generated.map(|version| version.version),
+            section: section.as_deref(),
+            rating: if positive { 1 } else { -1 },
+            comment: comment.as_deref().map(str::trim).filter(|comment| !comment.is_empty()),
+            template_id: generated.and_then(|version| version.template_id.as_deref()),
+            provider: generated.and_then(|version| version.provider.as_deref()),
+            model: generated.and_then(|version| version.model.as_deref()),
+        },
+    )
+    .await
+    .map_err(|e| format!("Failed to save summary feedback: {}", e))?;
+    info!(
+        "Saved {} feedback on {} of {}",
+        if positive { "positive" } else { "negative" },
+        feedback.section.as_deref().unwrap_or("the summary"),
+        meeting_id
+    );
+    Ok(feedback)
+}
+
+#[command]
+pub async fn list_summary_feedback<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<SummaryFeedback>, String> {
+    let state = app.state::<AppState>();
+    SummaryFeedbackRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary feedback: {}", e))
+}
+
+/// Feedback aggregated per template, provider and model; `template_id` narrows it to one
+/// template
+#[command]
+pub async fn get_summary_feedback_report<R: Runtime>(
+    app: AppHandle<R>,
+    template_id: Option<String>,
+) -> Result<Vec<FeedbackGroup>, String> {
+    let state = app.state::<AppState>();
+    let feedback = SummaryFeedbackRepository::list(state.db_manager.pool(), template_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to load summary feedback: {}", e))?;
+    Ok(build_report(&feedback))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feedback(template: &str, section: Option<&str>, rating: i64, comment: Option<&str>) -> SummaryFeedback {
+        SummaryFeedback {
+            id: "feedback".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            version: Some(1),
+            section: section.map(str::to_string),
+            rating,
+            comment: comment.map(str::to_string),
+            template_id: Some(template.to_string()),
+            provider: Some("OpenAI".to_string()),
+            model: Some("gpt-4o".to_string()),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn report_groups_by_template_and_ranks_disliked_sections() {
+        let report = build_report(&[
+            feedback("daily_standup", Some("Action Items"), -1, Some("Missed two owners")),
+            feedback("daily_standup", Some("Action Items"), -1, Some("  ")),
+            feedback("daily_standup", Some("Decisions"), 1, None),
+            feedback("daily_standup", None, 1, Some("Good overall")),
+            feedback("standard_meeting", None, 1, None),
+        ]);
+
+        assert_eq!(report.len(), 2);
+        let standup = &report[0];
+        assert_eq!(standup.template_id.as_deref(), Some("daily_standup"));
+        assert_eq!((standup.up, standup.down), (2, 2));
+        assert_eq!(standup.approval, 0.5);
+        assert_eq!(
+            standup.sections[0],
+            SectionFeedback {
+                section: Some("Action Items".to_string()),
+                up: 0,
+                down: 2
+            }
+        );
+        let comments: Vec<&str> = standup.comments.iter().map(|c| c.comment.as_str()).collect();
+        assert_eq!(comments, vec!["Missed two owners", "Good overall"]);
+        assert_eq!(report[1].approval, 1.0);
+    }
+
+    #[test]
+    fn headings_are_read_without_markers() {
+        let markdown = "# Weekly sync\n\nIntro\n\n## Action Items\n- Ana: send notes\n###   Risks \n#";
+        assert_eq!(section_headings(markdown), vec!["Weekly sync", "Action Items", "Risks"]);
+    }
+}
@@ -0,0 +1,241 @@
+// summary/follow_up.rs
+//
+// Follow-up email drafts: turns a meeting's stored minutes, decisions and action items
+// into a ready-to-send recap email with the configured summary model. The email has its
+// own template so it reads like a message to attendees rather than a copy of the minutes.
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::models::{ActionItem, MeetingOutcome};
+use crate::database::repositories::action_item::ActionItemsRepository;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_outcome::MeetingOutcomesRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::state::AppState;
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You write follow-up emails after meetings on behalf of one of the attendees. \
+Write in a friendly, professional tone and stick to what the meeting notes say; do not invent commitments, \
+owners or dates. Follow the requested structure and output only the email.";
+
+/// Email template for the follow-up draft; `{{title}}`, `{{date}}`, `{{minutes}}`,
+/// `{{decisions}}` and `{{action_items}}` are filled in from the stored meeting data
+const FOLLOW_UP_EMAIL_TEMPLATE: &str = "Draft a follow-up email for the meeting \"{{title}}\" held on {{date}}.
+
+Structure:
+Subject: <one line, mentioning the meeting topic>
+
+<short greeting to the attendees>
+
+<recap: two to four sentences on what was discussed>
+
+Decisions:
+<one bullet per decision, or leave the section out when there are none>
+
+Action items:
+<one bullet per item as \"Owner - task (due date)\"; use \"Unassigned\" when nobody took it>
+
+<one closing sentence about next steps, then a sign-off without a name>
+
+Decisions recorded for this meeting:
+{{decisions}}
+
+Action items recorded for this meeting:
+{{action_items}}
+
+<meeting_minutes>
+{{minutes}}
+</meeting_minutes>";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FollowUpEmail {
+    pub subject: String,
+    pub body: String,
+}
+
+fn bullet_list(items: Vec<String>) -> String {
+    if items.is_empty() {
+        return "(none)".to_string();
+    }
+    items
+        .into_iter()
+        .map(|item| format!("- {}", item))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn action_item_line(item: &ActionItem) -> String {
+    let mut line = format!(
+        "{} - {}",
+        item.assignee.as_deref().unwrap_or("Unassigned"),
+        item.description
+    );
+    if let Some(due_date) = &item.due_date {
+        line.push_str(&format!(" (due {})", due_date));
+    }
+    if item.done {
+        line.push_str(" [done]");
+    }
+    line
+}
+
+/// Fill the email template with the meeting's stored data
+fn render_prompt(
+    title: &str,
+    date: &str,
+    minutes: &str,
+    outcomes: &[MeetingOutcome],
+    action_items: &[ActionItem],
+) -> String {
+    let decisions = outcomes
+        .iter()
+        .filter(|outcome| outcome.kind == "decision")
+        .map(|outcome| outcome.text.clone())
+        .collect();
+    FOLLOW_UP_EMAIL_TEMPLATE
+        .replace("{{title}}", title)
+        .replace("{{date}}", date)
+        .replace("{{decisions}}", &bullet_list(decisions))
+        .replace(
+            "{{action_items}}",
+            &bullet_list(action_items.iter().map(action_item_line).collect()),
+        )
+        // Last, so nothing in the minutes is mistaken for a placeholder
+        .replace("{{minutes}}", minutes.trim())
+}
+
+/// Split the model's reply into subject and body. Falls back to a generic subject when
+/// the reply has no "Subject:" line.
+fn parse_email(reply: &str, title: &str) -> FollowUpEmail {
+    let reply = reply.trim();
+    let mut lines = reply.lines();
+    let subject = lines.next().and_then(|line| {
+        let line = line.trim().trim_matches('*').trim();
+        line.strip_prefix("Subject:")
+            .or_else(|| line.strip_prefix("subject:"))
+            .map(|subject| subject.trim_matches('*').trim().to_string())
+    });
+    match subject {
+        Some(subject) if !subject.is_empty() => FollowUpEmail {
+            subject,
+            body: lines.collect::<Vec<_>>().join("\n").trim().to_string(),
+        },
+        _ => FollowUpEmail {
+            subject: format!("Follow-up: {}", title),
+            body: reply.to_string(),
+        },
+    }
+}
+
+/// Markdown of the stored minutes, None when the meeting has no completed summary
+pub(crate) fn stored_minutes(result: Option<&str>) -> Option<String> {
+    let summary: serde_json::Value = serde_json::from_str(result?).ok()?;
+    summary
+        .get("markdown")
+        .and_then(|markdown| markdown.as_str())
+        .filter(|markdown| !markdown.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Draft a follow-up email from a meeting's stored minutes with the configured summary model
+pub(crate) async fn draft_follow_up_email<R: Runtime>(app: &AppHandle<R>, meeting_id: &str) -> Result<FollowUpEmail, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+    let process = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?;
+    let minutes = stored_minutes(process.as_ref().and_then(|p| p.result.as_deref()))
+        .ok_or_else(|| "Generate the meeting minutes before drafting a follow-up email".to_string())?;
+    let outcomes = MeetingOutcomesRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load decisions: {}", e))?;
+    let action_items = ActionItemsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load action items: {}", e))?;
+
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(meeting_id), "follow_up_email");
+    let prompt = render_prompt(
+        &meeting.title,
+        &meeting.created_at.0.format("%A, %Y-%m-%d").to_string(),
+        &minutes,
+        &outcomes,
+        &action_items,
+    );
+    let reply = provider
+        .complete(SYSTEM_PROMPT, &prompt, None)
+        .await
+        .map_err(|e| format!("Failed to generate follow-up email: {}", e))?;
+    info!("Drafted follow-up email for {}", meeting_id);
+
+    Ok(parse_email(&reply, &meeting.title))
+}
+
+/// Draft a follow-up email (recap, decisions, action items with owners) from a meeting's
+/// stored minutes with the configured summary model
+#[command]
+pub async fn generate_follow_up_email<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<FollowUpEmail, String> {
+    draft_follow_up_email(&app, &meeting_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_render_prompt_lists_owners() {
+        let item = |description: &str, assignee: Option<&str>, due_date: Option<&str>| ActionItem {
+            id: "action-1".to_string(),
+            meeting_id: "meeting-1".to_string(),
+            description: description.to_string(),
+            assignee: assignee.map(str::to_string),
+            due_date: due_date.map(str::to_string),
+            segment_id: None,
+            done: false,
+            issue_key: None,
+            issue_url: None,
+            created_at: Utc::now(),
+        };
+        let prompt = render_prompt(
+            "Q3 planning",
+            "Monday, 2026-01-12",
+            "## Summary\nBudget talk about {{title}}",
+            &[],
+            &[
+                item("Send the deck", Some("Ana"), Some("2026-01-16")),
+                item("Book the room", None, None),
+            ],
+        );
+        assert!(prompt.contains("\"Q3 planning\" held on Monday, 2026-01-12"));
+        assert!(prompt.contains("Decisions recorded for this meeting:\n(none)"));
+        assert!(prompt.contains("- Ana - Send the deck (due 2026-01-16)\n- Unassigned - Book the room"));
+        assert!(prompt.contains("Budget talk about {{title}}"));
+    }
+
+    #[test]
+    fn test_parse_email() {
+        let email = parse_email("**Subject:** Q3 planning recap\n\nHi all,\n\nThanks!", "Q3 planning");
+        assert_eq!(email.subject, "Q3 planning recap");
+        assert_eq!(email.body, "Hi all,\n\nThanks!");
+
+        let email = parse_email("Hi all,\nThanks!", "Q3 planning");
+        assert_eq!(email.subject, "Follow-up: Q3 planning");
+        assert_eq!(email.body, "Hi all,\nThanks!");
+
+        assert_eq!(stored_minutes(Some(r##"{"markdown":"# Minutes"}"##)).as_deref(), Some("# Minutes"));
+        assert_eq!(stored_minutes(Some(r#"{"markdown":""}"#)), None);
+        assert_eq!(stored_minutes(None), None);
+    }
+}
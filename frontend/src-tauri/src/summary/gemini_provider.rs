@@ -0,0 +1,285 @@
+// summary/gemini_provider.rs
+//
+// Google Gemini summary provider on the native generateContent API. Gemini 1.5+ models
+// have 1-2M token context windows, enough for a multi-hour transcript in one pass, which
+// keeps cross-references between early and late parts of the meeting intact.
+
+use async_trait::async_trait;
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::summary::provider::{
+    cloud_http_client, read_stream_lines, send_with_retry, SummaryError, SummaryProvider, TokenCallback,
+};
+
+const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+pub const DEFAULT_GEMINI_MODEL: &str = "gemini-1.5-flash";
+const MAX_OUTPUT_TOKENS: u32 = 8192;
+// Room for the system prompt and template on top of the transcript
+const PROMPT_OVERHEAD_TOKENS: usize = 4000;
+// Single-pass requests over a million tokens take a while to process
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(900);
+
+#[derive(Debug, Serialize)]
+struct Part<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct Content<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<&'a str>,
+    parts: Vec<Part<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig {
+    max_output_tokens: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateContentRequest<'a> {
+    system_instruction: Content<'a>,
+    contents: Vec<Content<'a>>,
+    generation_config: GenerationConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateContentResponse {
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+    prompt_feedback: Option<PromptFeedback>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Candidate {
+    content: Option<CandidateContent>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CandidateContent {
+    #[serde(default)]
+    parts: Vec<CandidatePart>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CandidatePart {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptFeedback {
+    block_reason: Option<String>,
+}
+
+/// Context window of a Gemini model, in tokens
+pub fn model_context_tokens(model_name: &str) -> usize {
+    let model = model_name.to_lowercase();
+    if model.contains("1.5-pro") {
+        2_097_152
+    } else if ["1.5", "2.0", "2.5"].iter().any(|version| model.contains(version)) {
+        1_048_576
+    } else {
+        // gemini-1.0 / gemini-pro and unknown models
+        32_768
+    }
+}
+
+/// Generated text of the first candidate, or why there is none
+fn response_text(response: GenerateContentResponse) -> std::result::Result<String, SummaryError> {
+    let candidate = match response.candidates.into_iter().next() {
+        Some(candidate) => candidate,
+        None => {
+            let reason = response
+                .prompt_feedback
+                .and_then(|feedback| feedback.block_reason)
+                .unwrap_or_else(|| "no candidates returned".to_string());
+            return Err(SummaryError::InvalidResponse(format!("Gemini blocked the request: {}", reason)));
+        }
+    };
+    if candidate.finish_reason.as_deref() == Some("MAX_TOKENS") {
+        warn!("Gemini response hit the {}-token output limit and may be truncated", MAX_OUTPUT_TOKENS);
+    }
+    let text: String = candidate
+        .content
+        .map(|content| content.parts.into_iter().map(|part| part.text).collect())
+        .unwrap_or_default();
+    if text.trim().is_empty() {
+        return Err(SummaryError::InvalidResponse(format!(
+            "Gemini returned no text (finish reason: {})",
+            candidate.finish_reason.unwrap_or_else(|| "unknown".to_string())
+        )));
+    }
+    Ok(text.trim().to_string())
+}
+
+pub struct GeminiSummaryProvider {
+    client: Client,
+    api_key: String,
+    model_name: String,
+}
+
+impl GeminiSummaryProvider {
+    /// # Arguments
+    /// * `api_key` - Google AI Studio API key
+    /// * `model_name` - Gemini model (e.g., "gemini-1.5-pro"); empty uses the default
+    pub fn new(api_key: String, model_name: String) -> Self {
+        let model_name = if model_name.trim().is_empty() {
+            DEFAULT_GEMINI_MODEL.to_string()
+        } else {
+            model_name
+        };
+        info!("🌐 Gemini summary provider initialized with model: {}", model_name);
+        Self {
+            client: cloud_http_client(),
+            api_key,
+            model_name,
+        }
+    }
+
+    /// `method` is "generateContent" or "streamGenerateContent?alt=sse"
+    async fn send(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        method: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<Response, SummaryError> {
+        let request = GenerateContentRequest {
+            system_instruction: Content {
+                role: None,
+                parts: vec![Part { text: system_prompt }],
+            },
+            contents: vec![Content {
+                role: Some("user"),
+                parts: vec![Part { text: user_prompt }],
+            }],
+            generation_config: GenerationConfig {
+                max_output_tokens: MAX_OUTPUT_TOKENS,
+            },
+        };
+        let url = format!("{}/{}:{}", GEMINI_API_BASE, self.model_name, method);
+
+        info!("🌐 Gemini request: model={}", self.model_name);
+        send_with_retry(
+            "Gemini",
+            || {
+                self.client
+                    .post(&url)
+                    .header("x-goog-api-key", &self.api_key)
+                    .json(&request)
+                    .timeout(REQUEST_TIMEOUT)
+            },
+            cancellation_token,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for GeminiSummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self
+            .send(system_prompt, user_prompt, "generateContent", cancellation_token)
+            .await?;
+        let generate_response = response
+            .json::<GenerateContentResponse>()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Failed to parse Gemini response: {}", e)))?;
+        let text = response_text(generate_response)?;
+        info!("🌐 Gemini response received ({} chars)", text.len());
+        Ok(text)
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self
+            .send(system_prompt, user_prompt, "streamGenerateContent?alt=sse", cancellation_token)
+            .await?;
+        // Each SSE event carries a partial GenerateContentResponse
+        let mut text = String::new();
+        read_stream_lines(response, "Gemini", cancellation_token, |line| {
+            let chunk = match line.strip_prefix("data:").map(str::trim) {
+                Some(data) => match serde_json::from_str::<GenerateContentResponse>(data) {
+                    Ok(chunk) => chunk,
+                    Err(_) => return false,
+                },
+                None => return false,
+            };
+            let delta: String = chunk
+                .candidates
+                .into_iter()
+                .next()
+                .and_then(|candidate| candidate.content)
+                .map(|content| content.parts.into_iter().map(|part| part.text).collect())
+                .unwrap_or_default();
+            if !delta.is_empty() {
+                on_token(&delta);
+                text.push_str(&delta);
+            }
+            false
+        })
+        .await?;
+        if text.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse("Gemini returned no text".to_string()));
+        }
+        Ok(text.trim().to_string())
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        Some(model_context_tokens(&self.model_name) - MAX_OUTPUT_TOKENS as usize - PROMPT_OVERHEAD_TOKENS)
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Gemini"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_context_tokens() {
+        assert_eq!(model_context_tokens("gemini-1.5-pro-latest"), 2_097_152);
+        assert_eq!(model_context_tokens("gemini-2.0-flash"), 1_048_576);
+        assert_eq!(model_context_tokens("gemini-pro"), 32_768);
+    }
+
+    #[test]
+    fn test_response_text() {
+        let ok: GenerateContentResponse = serde_json::from_str(
+            r#"{"candidates":[{"content":{"parts":[{"text":"Decisions: "},{"text":"ship it"}]},"finishReason":"STOP"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(response_text(ok).unwrap(), "Decisions: ship it");
+
+        let blocked: GenerateContentResponse =
+            serde_json::from_str(r#"{"promptFeedback":{"blockReason":"SAFETY"}}"#).unwrap();
+        assert!(response_text(blocked).unwrap_err().to_string().contains("SAFETY"));
+    }
+}
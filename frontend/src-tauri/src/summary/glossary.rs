@@ -0,0 +1,148 @@
+// summary/glossary.rs
+//
+// Glossary of the user's own context: team members, project names, acronyms and other
+// terms. Speech recognition tends to misspell exactly these, so the glossary is added to
+// the minutes prompt, telling the model the correct spellings and what acronyms stand for.
+// Entries are grouped by kind and capped to a token budget so a large glossary can't
+// crowd out the transcript.
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::warn;
+
+use crate::database::models::GlossaryEntry;
+use crate::database::repositories::glossary::GlossaryRepository;
+use crate::state::AppState;
+use crate::summary::processor::rough_token_count;
+
+/// Most of the prompt the glossary may take
+const MAX_GLOSSARY_TOKENS: usize = 1500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GlossaryKind {
+    Person,
+    Project,
+    Acronym,
+    Term,
+}
+
+impl GlossaryKind {
+    pub const ALL: [GlossaryKind; 4] = [
+        GlossaryKind::Person,
+        GlossaryKind::Project,
+        GlossaryKind::Acronym,
+        GlossaryKind::Term,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GlossaryKind::Person => "person",
+            GlossaryKind::Project => "project",
+            GlossaryKind::Acronym => "acronym",
+            GlossaryKind::Term => "term",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value.trim())
+    }
+
+    fn heading(&self) -> &'static str {
+        match self {
+            GlossaryKind::Person => "People",
+            GlossaryKind::Project => "Projects",
+            GlossaryKind::Acronym => "Acronyms",
+            GlossaryKind::Term => "Terms",
+        }
+    }
+}
+
+/// Glossary block for the minutes prompt; None when the glossary is empty
+pub fn render_glossary(entries: &[GlossaryEntry]) -> Option<String> {
+    let mut body = String::new();
+    let mut tokens = 0;
+    let mut skipped = 0;
+    for kind in GlossaryKind::ALL {
+        let mut section = String::new();
+        for entry in entries.iter().filter(|entry| GlossaryKind::parse(&entry.kind) == Some(kind)) {
+            let line = match entry.definition.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+                Some(definition) => format!("- {}: {}\n", entry.term.trim(), definition),
+                None => format!("- {}\n", entry.term.trim()),
+            };
+            let line_tokens = rough_token_count(&line);
+            if tokens + line_tokens > MAX_GLOSSARY_TOKENS {
+                skipped += 1;
+                continue;
+            }
+            tokens += line_tokens;
+            section.push_str(&line);
+        }
+        if !section.is_empty() {
+            body.push_str(&format!("{}:\n{}", kind.heading(), section));
+        }
+    }
+    if skipped > 0 {
+        warn!("Glossary is over its prompt budget; left out {} entries", skipped);
+    }
+    if body.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "Glossary (people, projects and terms from this team). When the transcript mentions any of \
+         them, possibly misheard or misspelled, use the spelling given here. Expand an acronym the \
+         first time it appears in the minutes, e.g. \"SLO (service level objective)\".\n\n<glossary>\n{}</glossary>",
+        body
+    ))
+}
+
+/// The stored glossary as a prompt block, if there is one
+pub async fn glossary_instruction(pool: &SqlitePool) -> Result<Option<String>, sqlx::Error> {
+    let entries = GlossaryRepository::list(pool).await?;
+    Ok(render_glossary(&entries))
+}
+
+#[command]
+pub async fn list_glossary_entries<R: Runtime>(app: AppHandle<R>) -> Result<Vec<GlossaryEntry>, String> {
+    let state = app.state::<AppState>();
+    GlossaryRepository::list(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load glossary: {}", e))
+}
+
+/// Add a glossary entry, or update the one with `id`
+#[command]
+pub async fn save_glossary_entry<R: Runtime>(
+    app: AppHandle<R>,
+    id: Option<String>,
+    kind: GlossaryKind,
+    term: String,
+    definition: Option<String>,
+) -> Result<GlossaryEntry, String> {
+    let term = term.trim();
+    if term.is_empty() {
+        return Err("Glossary term cannot be empty".to_string());
+    }
+    let definition = definition.as_deref().map(str::trim).filter(|d| !d.is_empty());
+
+    let state = app.state::<AppState>();
+    GlossaryRepository::save(state.db_manager.pool(), id.as_deref(), kind.as_str(), term, definition)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => "Glossary entry not found".to_string(),
+            e => format!("Failed to save glossary entry: {}", e),
+        })
+}
+
+#[command]
+pub async fn delete_glossary_entry<R: Runtime>(app: AppHandle<R>, id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let deleted = GlossaryRepository::delete(state.db_manager.pool(), &id)
+        .await
+        .map_err(|e| format!("Failed to delete glossary entry: {}", e))?;
+    if !deleted {
+        return Err("Glossary entry not found".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// summary/groq_provider.rs
+//
+// Groq chat-completions summary provider (Llama 3.x models). Groq is also a cloud
+// transcription option, so the key saved for transcription is used when no separate
+// summary key is set - one Groq key covers recording through minutes.
+
+use async_trait::async_trait;
+use reqwest::{Client, Response};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::summary::llm_client::{ChatMessage, ChatRequest, ChatResponse};
+use crate::summary::openai_provider::{parse_stream_line, StreamLine};
+use crate::summary::provider::{
+    cloud_http_client, read_stream_lines, send_with_retry, SummaryError, SummaryProvider, TokenCallback,
+};
+
+const GROQ_CHAT_URL: &str = "https://api.groq.com/openai/v1/chat/completions";
+pub const DEFAULT_GROQ_MODEL: &str = "llama-3.3-70b-versatile";
+/// Context window of the Llama 3.1+ models Groq serves
+const GROQ_CONTEXT_TOKENS: usize = 131_072;
+const MAX_OUTPUT_TOKENS: u32 = 8192;
+// Room for the system prompt and template on top of the transcript
+const PROMPT_OVERHEAD_TOKENS: usize = 4000;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Summary key if set, otherwise the transcription key
+pub fn preferred_api_key(summary_key: Option<String>, transcript_key: Option<String>) -> Option<String> {
+    summary_key
+        .filter(|key| !key.trim().is_empty())
+        .or_else(|| transcript_key.filter(|key| !key.trim().is_empty()))
+}
+
+pub struct GroqSummaryProvider {
+    client: Client,
+    api_key: String,
+    model_name: String,
+}
+
+impl GroqSummaryProvider {
+    /// # Arguments
+    /// * `api_key` - Groq API key
+    /// * `model_name` - Groq chat model (e.g., "llama-3.1-8b-instant"); empty uses the default
+    pub fn new(api_key: String, model_name: String) -> Self {
+        let model_name = if model_name.trim().is_empty() {
+            DEFAULT_GROQ_MODEL.to_string()
+        } else {
+            model_name
+        };
+        info!("🌐 Groq summary provider initialized with model: {}", model_name);
+        Self {
+            client: cloud_http_client(),
+            api_key,
+            model_name,
+        }
+    }
+
+    async fn send(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        stream: bool,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<Response, SummaryError> {
+        let request = ChatRequest {
+            model: self.model_name.clone(),
+            messages: vec![
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: system_prompt.to_string(),
+                },
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: user_prompt.to_string(),
+                },
+            ],
+            max_tokens: Some(MAX_OUTPUT_TOKENS),
+            temperature: None,
+            top_p: None,
+            stream: stream.then_some(true),
+        };
+
+        info!("🌐 Groq request: model={}", self.model_name);
+        // Free-tier rate limits surface as 429s, which send_with_retry backs off from
+        send_with_retry(
+            "Groq",
+            || {
+                self.client
+                    .post(GROQ_CHAT_URL)
+                    .bearer_auth(&self.api_key)
+                    .json(&request)
+                    .timeout(REQUEST_TIMEOUT)
+            },
+            cancellation_token,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for GroqSummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, false, cancellation_token).await?;
+        let chat_response = response
+            .json::<ChatResponse>()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Failed to parse Groq response: {}", e)))?;
+        let content = chat_response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .filter(|content| !content.trim().is_empty())
+            .ok_or_else(|| SummaryError::InvalidResponse("Groq returned no content".to_string()))?;
+        info!("🌐 Groq response received ({} chars)", content.len());
+        Ok(content.trim().to_string())
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, true, cancellation_token).await?;
+        let mut content = String::new();
+        read_stream_lines(response, "Groq", cancellation_token, |line| match parse_stream_line(line) {
+            StreamLine::Delta(delta) => {
+                on_token(&delta);
+                content.push_str(&delta);
+                false
+            }
+            StreamLine::Done => true,
+            StreamLine::Ignore => false,
+        })
+        .await?;
+        if content.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse("Groq returned no content".to_string()));
+        }
+        Ok(content.trim().to_string())
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        Some(GROQ_CONTEXT_TOKENS - MAX_OUTPUT_TOKENS as usize - PROMPT_OVERHEAD_TOKENS)
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Groq"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_falls_back_to_transcription_key() {
+        assert_eq!(
+            preferred_api_key(Some("gsk_summary".to_string()), Some("gsk_transcript".to_string())),
+            Some("gsk_summary".to_string())
+        );
+        assert_eq!(
+            preferred_api_key(Some(" ".to_string()), Some("gsk_transcript".to_string())),
+            Some("gsk_transcript".to_string())
+        );
+        assert_eq!(preferred_api_key(None, Some(String::new())), None);
+    }
+}
@@ -0,0 +1,391 @@
+// summary/highlights.rs
+//
+// Highlight reel: the summary model picks the few most important quotes of a meeting.
+// Quotes must appear verbatim in the segment they cite, so every highlight carries the
+// speaker and the recording time of its segment. Highlights are stored per meeting,
+// appended to the minutes (and so to anything exported from them), and can be shared as
+// a formatted snippet or cut from the recording as an audio clip.
+
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::audio::ffmpeg::find_ffmpeg_path;
+use crate::database::models::MeetingHighlight;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_highlight::{MeetingHighlightsRepository, NewMeetingHighlight};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, batch_lines, complete_validated, numbered_lines};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+/// Highlights kept for a meeting
+const MAX_HIGHLIGHTS: usize = 5;
+/// Longer "quotes" are paraphrased passages, not quotes
+const MAX_QUOTE_WORDS: usize = 60;
+/// Clip length when the quoted segment is the last one and has no end time
+const LAST_SEGMENT_CLIP_SECONDS: f64 = 20.0;
+
+const SYSTEM_PROMPT: &str = "You pick the most important quotes from meeting transcripts: statements that \
+capture a decision, a commitment, a key insight or a strong concern, and that make sense on their own. Each \
+transcript line starts with its segment number in brackets, followed by the speaker when known. Copy every \
+quote word for word from a single segment (you may quote part of it) and do not fix grammar. Reply with only \
+a JSON object {\"highlights\": [{\"segment\": <number>, \"quote\": \"<verbatim text>\", \"importance\": <1-5>, \
+\"reason\": \"<why it matters, a few words>\"}]} with at most five highlights, or an empty array when nothing \
+stands out.";
+
+/// A quote that passed validation; `segment` indexes the meeting's segments
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedHighlight {
+    pub segment: usize,
+    pub quote: String,
+    pub importance: u64,
+    pub reason: Option<String>,
+}
+
+/// Lower-case words only, so a quote matches its segment despite punctuation and spacing
+fn normalize_words(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Validate a reply: quotes from segments inside `range` that appear verbatim in them
+pub fn validate_highlights(
+    reply: &str,
+    segments: &[(String, String, Option<f64>, Option<String>)],
+    range: Range<usize>,
+) -> Result<Vec<ExtractedHighlight>, String> {
+    let json = match (reply.find('{'), reply.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON object".to_string()),
+    };
+    let object: Value = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+    let items = match object.get("highlights") {
+        Some(Value::Array(items)) => items,
+        _ => return Err("\"highlights\" must be an array".to_string()),
+    };
+
+    let mut highlights = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let segment = match item.get("segment").and_then(Value::as_u64) {
+            Some(segment) if range.contains(&(segment as usize)) => segment as usize,
+            _ => {
+                return Err(format!(
+                    "highlights[{}]: \"segment\" must be one of the transcript lines {}..{}",
+                    index, range.start, range.end
+                ))
+            }
+        };
+        let quote = match item.get("quote").and_then(Value::as_str).map(|q| q.trim().trim_matches('"').trim()) {
+            Some(quote) if !quote.is_empty() => quote.to_string(),
+            _ => return Err(format!("highlights[{}]: \"quote\" must be a non-empty string", index)),
+        };
+        if quote.split_whitespace().count() > MAX_QUOTE_WORDS {
+            return Err(format!("highlights[{}]: quotes must be at most {} words", index, MAX_QUOTE_WORDS));
+        }
+        if !normalize_words(&segments[segment].1).contains(&normalize_words(&quote)) {
+            return Err(format!(
+                "highlights[{}]: the quote is not word for word in segment {}",
+                index, segment
+            ));
+        }
+        highlights.push(ExtractedHighlight {
+            segment,
+            quote,
+            importance: item.get("importance").and_then(Value::as_u64).unwrap_or(3).clamp(1, 5),
+            reason: item
+                .get("reason")
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|reason| !reason.is_empty())
+                .map(str::to_string),
+        });
+    }
+    Ok(highlights)
+}
+
+/// The most important highlights over all batches, one per segment, in meeting order
+pub fn select_highlights(mut candidates: Vec<ExtractedHighlight>) -> Vec<ExtractedHighlight> {
+    // Stable sort keeps earlier quotes first among equally important ones
+    candidates.sort_by_key(|candidate| Reverse(candidate.importance));
+    let mut selected: Vec<ExtractedHighlight> = Vec::new();
+    for candidate in candidates {
+        if selected.len() == MAX_HIGHLIGHTS {
+            break;
+        }
+        if !selected.iter().any(|highlight| highlight.segment == candidate.segment) {
+            selected.push(candidate);
+        }
+    }
+    selected.sort_by_key(|highlight| highlight.segment);
+    selected
+}
+
+/// "## Highlights" section for the minutes
+pub fn render_highlights_markdown(highlights: &[NewMeetingHighlight]) -> String {
+    let mut markdown = String::from("## Highlights\n");
+    for highlight in highlights {
+        let mut attribution = Vec::new();
+        if let Some(speaker) = &highlight.speaker {
+            attribution.push(format!("**{}**", speaker));
+        }
+        if let Some(start) = highlight.start_time {
+            attribution.push(crate::utils::format_timestamp(start));
+        }
+        markdown.push_str(&format!("\n> \"{}\"", highlight.quote));
+        if !attribution.is_empty() {
+            markdown.push_str(&format!("\n> — {}", attribution.join(", ")));
+        }
+        markdown.push('\n');
+    }
+    markdown.trim_end().to_string()
+}
+
+/// Pick a meeting's highlights, store them and return the minutes section. None when the
+/// meeting has no transcript segments or nothing stood out.
+pub async fn extract_and_store(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &dyn SummaryProvider,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<String>, String> {
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let lines = numbered_lines(&segments);
+    let mut candidates = Vec::new();
+    for (batch_index, range) in batch_lines(&lines, batch_budget(provider)).into_iter().enumerate() {
+        let user_prompt = format!("<transcript>\n{}\n</transcript>", lines[range.clone()].join("\n"));
+        let validate = |reply: &str| validate_highlights(reply, &segments, range.clone());
+        match complete_validated(provider, SYSTEM_PROMPT, &user_prompt, validate, cancellation_token).await {
+            Ok(batch_highlights) => candidates.extend(batch_highlights),
+            Err(SummaryError::InvalidResponse(e)) => {
+                warn!("Skipping highlights of batch {} for {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Highlight selection failed: {}", e)),
+        }
+    }
+
+    let rows: Vec<NewMeetingHighlight> = select_highlights(candidates)
+        .into_iter()
+        .map(|highlight| {
+            let (id, _, start_time, speaker) = &segments[highlight.segment];
+            NewMeetingHighlight {
+                quote: highlight.quote,
+                speaker: speaker.clone(),
+                segment_id: Some(id.clone()),
+                start_time: *start_time,
+                end_time: segments.get(highlight.segment + 1).and_then(|(_, _, start, _)| *start),
+                reason: highlight.reason,
+            }
+        })
+        .collect();
+    MeetingHighlightsRepository::replace_for_meeting(pool, meeting_id, &rows)
+        .await
+        .map_err(|e| format!("Failed to save highlights: {}", e))?;
+    info!("Picked {} highlight(s) for {}", rows.len(), meeting_id);
+
+    if rows.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(render_highlights_markdown(&rows)))
+}
+
+#[command]
+pub async fn get_meeting_highlights<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingHighlight>, String> {
+    let state = app.state::<AppState>();
+    MeetingHighlightsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load highlights: {}", e))
+}
+
+/// Re-pick a meeting's highlights with the configured model
+#[command]
+pub async fn generate_meeting_highlights<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingHighlight>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "highlights");
+    extract_and_store(pool, &meeting_id, provider.as_ref(), None).await?;
+    get_meeting_highlights(app, meeting_id).await
+}
+
+/// A highlight ready to share: the quote as text, plus where to play it from
+#[derive(Debug, Clone, Serialize)]
+pub struct HighlightSnippet {
+    pub text: String,
+    /// The meeting recording, when it is still on disk
+    pub audio_path: Option<String>,
+    pub start_time: Option<f64>,
+    pub end_time: Option<f64>,
+}
+
+async fn load_highlight(pool: &SqlitePool, highlight_id: &str) -> Result<MeetingHighlight, String> {
+    MeetingHighlightsRepository::get(pool, highlight_id)
+        .await
+        .map_err(|e| format!("Failed to load highlight: {}", e))?
+        .ok_or_else(|| "Highlight not found".to_string())
+}
+
+#[command]
+pub async fn get_highlight_snippet<R: Runtime>(
+    app: AppHandle<R>,
+    highlight_id: String,
+) -> Result<HighlightSnippet, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let highlight = load_highlight(pool, &highlight_id).await?;
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &highlight.meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .ok_or_else(|| "Meeting not found".to_string())?;
+
+    let mut source = vec![meeting.title.clone(), meeting.created_at.0.format("%Y-%m-%d").to_string()];
+    if let Some(start) = highlight.start_time {
+        source.push(crate::utils::format_timestamp(start));
+    }
+    let text = match &highlight.speaker {
+        Some(speaker) => format!("\"{}\" — {} ({})", highlight.quote, speaker, source.join(", ")),
+        None => format!("\"{}\" ({})", highlight.quote, source.join(", ")),
+    };
+    let audio_path = meeting
+        .folder_path
+        .map(|folder| Path::new(&folder).join("audio.mp4"))
+        .filter(|path| path.exists())
+        .map(|path| path.to_string_lossy().to_string());
+
+    Ok(HighlightSnippet {
+        text,
+        audio_path,
+        start_time: highlight.start_time,
+        end_time: highlight.end_time,
+    })
+}
+
+/// Cut a highlight out of the meeting recording into an audio file at `destination`
+#[command]
+pub async fn export_highlight_clip<R: Runtime>(
+    app: AppHandle<R>,
+    highlight_id: String,
+    destination: String,
+) -> Result<String, String> {
+    let snippet = get_highlight_snippet(app, highlight_id).await?;
+    let audio_path = snippet
+        .audio_path
+        .ok_or_else(|| "The meeting recording is not available".to_string())?;
+    let start = snippet
+        .start_time
+        .ok_or_else(|| "The highlight has no recording time".to_string())?;
+    let duration = snippet
+        .end_time
+        .map(|end| end - start)
+        .filter(|duration| *duration > 0.0)
+        .unwrap_or(LAST_SEGMENT_CLIP_SECONDS);
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| "FFmpeg not found".to_string())?;
+    let destination = PathBuf::from(destination);
+
+    let output_path = destination.clone();
+    let output = tokio::task::spawn_blocking(move || {
+        let mut command = std::process::Command::new(ffmpeg_path);
+        command.args([
+            "-ss",
+            &format!("{:.2}", start),
+            "-i",
+            &audio_path,
+            "-t",
+            &format!("{:.2}", duration),
+            "-vn",
+            "-c:a",
+            "aac",
+            "-b:a",
+            "128k",
+            "-y",
+        ]);
+        command.arg(&output_path);
+
+        // Hide console window on Windows
+        #[cfg(target_os = "windows")]
+        {
+            use std::os::windows::process::CommandExt;
+            const CREATE_NO_WINDOW: u32 = 0x08000000;
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        command.output()
+    })
+    .await
+    .map_err(|e| format!("Clip export task failed: {}", e))?
+    .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "FFmpeg failed to cut the clip: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    info!("Exported highlight clip to {}", destination.display());
+    Ok(destination.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_and_select_highlights() {
+        let segments: Vec<(String, String, Option<f64>, Option<String>)> = vec![
+            ("t0".into(), "Okay, let's start.".into(), Some(0.0), None),
+            ("t1".into(), "We are shipping on Friday, no matter what.".into(), Some(4.0), Some("Ana".into())),
+            ("t2".into(), "I'm worried   the migration isn't tested.".into(), Some(9.0), Some("Ben".into())),
+        ];
+        let reply = r#"{"highlights":[
+            {"segment":1,"quote":"\"We are shipping on Friday, no matter what\"","importance":5},
+            {"segment":2,"quote":"i'm worried the migration isn't tested","importance":4,"reason":"risk"}]}"#;
+        let highlights = validate_highlights(reply, &segments, 0..3).unwrap();
+        assert_eq!(highlights[0].quote, "We are shipping on Friday, no matter what");
+        assert_eq!(highlights[1].reason.as_deref(), Some("risk"));
+
+        // Paraphrases and segments outside the batch are rejected
+        assert!(validate_highlights(r#"{"highlights":[{"segment":1,"quote":"We ship Friday"}]}"#, &segments, 0..3).is_err());
+        assert!(validate_highlights(r#"{"highlights":[{"segment":2,"quote":"worried"}]}"#, &segments, 0..2).is_err());
+
+        let candidate = |segment, importance| ExtractedHighlight {
+            segment,
+            quote: String::new(),
+            importance,
+            reason: None,
+        };
+        let selected = select_highlights(vec![
+            candidate(9, 2),
+            candidate(3, 5),
+            candidate(3, 4),
+            candidate(7, 4),
+            candidate(1, 3),
+            candidate(5, 3),
+            candidate(8, 1),
+        ]);
+        let segments: Vec<usize> = selected.iter().map(|highlight| highlight.segment).collect();
+        assert_eq!(segments, vec![1, 3, 5, 7, 9]);
+    }
+}
@@ -0,0 +1,261 @@
+// summary/live_summary.rs
+//
+// Rolling summary while a meeting is still being recorded. Every few minutes the segments
+// finished since the previous pass are condensed into notes (map) and folded into the
+// running summary (reduce); the result is emitted as `live-summary-update` and kept for
+// `get_live_summary`, so someone joining late can catch up instantly.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::audio::recording_saver::{speaker_label, TranscriptSegment};
+use crate::state::AppState;
+use crate::summary::processor::{chunk_text, clean_llm_markdown_output, rough_token_count};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+// Cancels the running update loop
+static LIVE_SUMMARY_TOKEN: Mutex<Option<CancellationToken>> = Mutex::new(None);
+// Latest rolling summary of the current (or last) recording
+static LATEST_SUMMARY: Mutex<Option<LiveSummary>> = Mutex::new(None);
+
+// Upper bound for the notes of one map pass, so local models keep up in real time
+const MAP_CHUNK_TOKENS: usize = 6_000;
+
+const MAP_SYSTEM_PROMPT: &str = "You take notes during a live meeting. Condense the following transcript \
+excerpt into terse bullet points: topics discussed, decisions, action items with owners, and open questions. \
+Keep names as written. Output only the bullet points.";
+
+const REDUCE_SYSTEM_PROMPT: &str = "You maintain a running summary of a meeting that is still in progress, \
+for someone who joins late. Merge the new notes into the current summary: keep it short (at most about 200 \
+words), in Markdown with the sections **So far**, **Decisions**, **Action items** and **Open questions** \
+(omit empty ones), and keep the most recent discussion visible. Output only the updated summary.";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LiveSummary {
+    pub summary: String,
+    /// Recording time (seconds) up to which the transcript is covered
+    pub covered_until: f64,
+    pub segment_count: usize,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Segments not yet covered by the summary, in recording order
+fn pending_segments(segments: Vec<TranscriptSegment>, last_sequence_id: Option<u64>) -> Vec<TranscriptSegment> {
+    let mut pending: Vec<TranscriptSegment> = segments
+        .into_iter()
+        .filter(|s| !matches!(last_sequence_id, Some(last) if s.sequence_id <= last) && !s.text.trim().is_empty())
+        .collect();
+    pending.sort_by(|a, b| a.audio_start_time.total_cmp(&b.audio_start_time));
+    pending
+}
+
+/// Transcript lines for the map pass, as "[02:15] Speaker: text"
+fn transcript_lines(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|s| {
+            let total = s.audio_start_time.max(0.0) as u64;
+            let speaker = s
+                .speaker_label
+                .as_deref()
+                .or_else(|| s.speaker.as_deref().map(speaker_label));
+            match speaker {
+                Some(speaker) => format!("[{:02}:{:02}] {}: {}", total / 60, total % 60, speaker, s.text.trim()),
+                None => format!("[{:02}:{:02}] {}", total / 60, total % 60, s.text.trim()),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn reduce_prompt(previous: Option<&str>, notes: &str) -> String {
+    format!(
+        "<current_summary>\n{}\n</current_summary>\n\n<new_notes>\n{}\n</new_notes>",
+        previous.unwrap_or("(nothing yet, the meeting just started)"),
+        notes
+    )
+}
+
+/// Fold newly finished segments into the running summary
+async fn update_summary(
+    provider: &dyn SummaryProvider,
+    previous: Option<&str>,
+    segments: &[TranscriptSegment],
+    cancellation_token: &CancellationToken,
+) -> Result<String, SummaryError> {
+    let lines = transcript_lines(segments);
+    let chunk_tokens = provider
+        .context_tokens()
+        .unwrap_or(MAP_CHUNK_TOKENS)
+        .min(MAP_CHUNK_TOKENS);
+    let chunks = if rough_token_count(&lines) < chunk_tokens {
+        vec![lines]
+    } else {
+        chunk_text(&lines, chunk_tokens, 100)
+    };
+
+    let mut notes = Vec::with_capacity(chunks.len());
+    for chunk in &chunks {
+        notes.push(provider.complete(MAP_SYSTEM_PROMPT, chunk, Some(cancellation_token)).await?);
+    }
+    let summary = provider
+        .complete(
+            REDUCE_SYSTEM_PROMPT,
+            &reduce_prompt(previous, &notes.join("\n")),
+            Some(cancellation_token),
+        )
+        .await?;
+    Ok(clean_llm_markdown_output(&summary))
+}
+
+async fn run<R: Runtime>(app: AppHandle<R>, interval: Duration, cancellation_token: CancellationToken) {
+    let pool = app.state::<AppState>().db_manager.pool().clone();
+    let provider = match SummaryService::configured_summary_provider(&pool, app.path().app_data_dir().ok()).await {
+        // The recording has no meeting yet; usage is recorded without one
+        Ok(provider) => metered(provider, &pool, None, "live_summary"),
+        Err(e) => {
+            warn!("Live summary disabled for this recording: {}", e);
+            let _ = app.emit("live-summary-error", e);
+            return;
+        }
+    };
+    info!(
+        "Live summary running every {}s with {} ({})",
+        interval.as_secs(),
+        provider.provider_name(),
+        provider.model_name()
+    );
+
+    let mut last_sequence_id: Option<u64> = None;
+    let mut summarized_segments = 0usize;
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = cancellation_token.cancelled() => break,
+        }
+
+        let segments = crate::audio::recording_commands::get_transcript_history()
+            .await
+            .unwrap_or_default();
+        let pending = pending_segments(segments, last_sequence_id);
+        if pending.is_empty() {
+            continue;
+        }
+
+        let previous = LATEST_SUMMARY
+            .lock()
+            .ok()
+            .and_then(|latest| latest.as_ref().map(|l| l.summary.clone()));
+        match update_summary(provider.as_ref(), previous.as_deref(), &pending, &cancellation_token).await {
+            Ok(summary) => {
+                last_sequence_id = pending.iter().map(|s| s.sequence_id).max().or(last_sequence_id);
+                summarized_segments += pending.len();
+                let live_summary = LiveSummary {
+                    summary,
+                    covered_until: pending.iter().map(|s| s.audio_end_time).fold(0.0, f64::max),
+                    segment_count: summarized_segments,
+                    updated_at: Utc::now(),
+                };
+                let _ = app.emit("live-summary-update", &live_summary);
+                if let Ok(mut latest) = LATEST_SUMMARY.lock() {
+                    *latest = Some(live_summary);
+                }
+            }
+            Err(SummaryError::Cancelled) => break,
+            // Try again with these segments included on the next tick
+            Err(e) => warn!("Live summary update failed: {}", e),
+        }
+    }
+    info!("Live summary stopped");
+}
+
+/// Start the rolling summary for a new recording if enabled in the recording preferences
+pub async fn start<R: Runtime>(app: &AppHandle<R>) {
+    stop();
+    if let Ok(mut latest) = LATEST_SUMMARY.lock() {
+        *latest = None;
+    }
+
+    let prefs = match crate::audio::recording_preferences::load_recording_preferences(app).await {
+        Ok(prefs) => prefs,
+        Err(e) => {
+            warn!("Failed to load recording preferences for live summary: {}", e);
+            return;
+        }
+    };
+    if !prefs.live_summary {
+        return;
+    }
+
+    let cancellation_token = CancellationToken::new();
+    if let Ok(mut token) = LIVE_SUMMARY_TOKEN.lock() {
+        *token = Some(cancellation_token.clone());
+    }
+    let interval = Duration::from_secs(u64::from(prefs.live_summary_interval_minutes.max(1)) * 60);
+    tauri::async_runtime::spawn(run(app.clone(), interval, cancellation_token));
+}
+
+/// Stop the rolling summary; the last summary stays available until the next recording
+pub fn stop() {
+    if let Some(token) = LIVE_SUMMARY_TOKEN.lock().ok().and_then(|mut token| token.take()) {
+        token.cancel();
+    }
+}
+
+/// Latest rolling summary, for views opened after the meeting started
+#[command]
+pub async fn get_live_summary() -> Result<Option<LiveSummary>, String> {
+    LATEST_SUMMARY
+        .lock()
+        .map(|latest| latest.clone())
+        .map_err(|e| format!("Failed to read live summary: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(sequence_id: u64, start: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id: format!("seg-{}", sequence_id),
+            text: text.to_string(),
+            audio_start_time: start,
+            audio_end_time: start + 4.0,
+            duration: 4.0,
+            display_time: String::new(),
+            confidence: 0.9,
+            sequence_id,
+            hallucination_flag: None,
+            audio_quality: None,
+            speaker: Some("mic".to_string()),
+            speaker_label: None,
+            overlapping_speech: false,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_pending_segments_in_recording_order() {
+        let segments = vec![
+            segment(3, 135.0, "Let's ship on Friday"),
+            segment(1, 10.0, "Morning"),
+            segment(2, 60.0, "  "),
+            segment(4, 120.0, "Agreed"),
+        ];
+        let pending = pending_segments(segments.clone(), None);
+        assert_eq!(pending.iter().map(|s| s.sequence_id).collect::<Vec<_>>(), vec![1, 4, 3]);
+
+        let pending = pending_segments(segments, Some(2));
+        assert_eq!(
+            transcript_lines(&pending),
+            "[02:00] Me: Agreed\n[02:15] Me: Let's ship on Friday"
+        );
+    }
+}
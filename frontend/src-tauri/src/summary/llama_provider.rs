@@ -0,0 +1,125 @@
+// summary/llama_provider.rs
+//
+// Embedded llama.cpp summary provider. Runs a small GGUF instruct model from the managed
+// models directory (<app data>/models/summary) in the llama-helper sidecar, so the whole
+// minutes pipeline works offline for users who can't run Ollama or use a cloud key.
+
+use async_trait::async_trait;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::summary_engine::generate_with_builtin;
+use crate::summary::summary_engine::models::{self, ModelDef};
+
+// Chat template tokens around the system prompt and transcript
+const PROMPT_OVERHEAD_TOKENS: usize = 500;
+
+pub struct LlamaSummaryProvider {
+    app_data_dir: PathBuf,
+    model: ModelDef,
+}
+
+impl LlamaSummaryProvider {
+    /// Fails when the model is unknown or its GGUF file hasn't been downloaded yet, so
+    /// generation stops before the first pass instead of halfway through the minutes.
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - App data directory holding the managed models
+    /// * `model_name` - Built-in model (e.g., "gemma3:1b"); empty uses the default
+    pub fn new(app_data_dir: PathBuf, model_name: &str) -> Result<Self, SummaryError> {
+        let model = if model_name.trim().is_empty() {
+            models::get_default_model()
+        } else {
+            models::get_model_by_name(model_name)
+                .ok_or_else(|| SummaryError::NotConfigured(format!("Unknown built-in model: {}", model_name)))?
+        };
+        let model_path = models::get_models_directory(&app_data_dir).join(&model.gguf_file);
+        if !model_path.exists() {
+            return Err(SummaryError::NotConfigured(format!(
+                "{} is not downloaded yet. Download it in the summary model settings",
+                model.display_name
+            )));
+        }
+
+        info!("🦙 Built-in summary provider initialized with {} ({})", model.name, model_path.display());
+        Ok(Self { app_data_dir, model })
+    }
+}
+
+/// Transcript tokens per request: the model's window minus the reply it may write and the
+/// chat template, so a full chunk never pushes the reply out of the context
+fn input_budget(context_size: u32) -> usize {
+    (context_size as usize).saturating_sub(models::DEFAULT_MAX_TOKENS as usize + PROMPT_OVERHEAD_TOKENS)
+}
+
+#[async_trait]
+impl SummaryProvider for LlamaSummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> Result<String, SummaryError> {
+        let text = generate_with_builtin(
+            &self.app_data_dir,
+            &self.model.name,
+            system_prompt,
+            user_prompt,
+            cancellation_token,
+        )
+        .await
+        .map_err(|e| {
+            if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+                SummaryError::Cancelled
+            } else {
+                SummaryError::RequestFailed(e.to_string())
+            }
+        })?;
+
+        if text.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse(format!(
+                "{} returned an empty reply",
+                self.model.display_name
+            )));
+        }
+        Ok(text)
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        Some(input_budget(self.model.context_size))
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model.name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Built-in AI"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_budget_leaves_room_for_the_reply() {
+        assert_eq!(input_budget(32_768), 32_768 - 4096 - 500);
+        assert_eq!(input_budget(2048), 0);
+    }
+
+    #[test]
+    fn missing_model_file_is_not_configured() {
+        let dir = std::env::temp_dir().join("llama-provider-missing-model");
+        match LlamaSummaryProvider::new(dir, "gemma3:1b") {
+            Err(SummaryError::NotConfigured(message)) => assert!(message.contains("not downloaded")),
+            _ => panic!("expected NotConfigured"),
+        }
+        assert!(matches!(
+            LlamaSummaryProvider::new(std::env::temp_dir(), "no-such-model"),
+            Err(SummaryError::NotConfigured(_))
+        ));
+    }
+}
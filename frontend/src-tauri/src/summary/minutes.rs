@@ -0,0 +1,107 @@
+// summary/minutes.rs
+//
+// Orchestrates turning a finished transcript into structured meeting minutes:
+// runs the (possibly chunked) template summary through a SummaryProvider and splits
+// the generated title from the body, so callers get minutes rather than raw LLM output.
+
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::provider::{SummaryError, SummaryProvider, TokenCallback};
+use crate::summary::style::SummaryStyle;
+use crate::summary::templates::MinutesTemplate;
+
+/// Minutes generated for one meeting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingMinutes {
+    /// Title suggested by the model (first "# " heading), if any
+    pub title: Option<String>,
+    /// Minutes body in markdown, without the title heading
+    pub markdown: String,
+    pub chunks_processed: i64,
+}
+
+/// Generate minutes for a transcript using the given provider and template
+///
+/// # Arguments
+/// * `provider` - Summary provider to run completions with
+/// * `transcript` - Full transcript text
+/// * `custom_prompt` - Optional user-provided context
+/// * `template` - Section template or user prompt template to write the minutes with
+/// * `style` - Output preset (executive brief, detailed minutes, ...)
+/// * `on_token` - Optional receiver for the final minutes text as it is generated
+/// * `cancellation_token` - Optional cancellation token to stop processing
+pub async fn generate_minutes(
+    provider: &dyn SummaryProvider,
+    transcript: &str,
+    custom_prompt: &str,
+    template: &MinutesTemplate,
+    style: SummaryStyle,
+    on_token: Option<&TokenCallback>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<MeetingMinutes, SummaryError> {
+    let (markdown, chunks_processed) = generate_meeting_summary(
+        provider,
+        transcript,
+        custom_prompt,
+        template,
+        style,
+        on_token,
+        cancellation_token,
+    )
+    .await?;
+    if chunks_processed == 0 && markdown.is_empty() {
+        return Err(SummaryError::InvalidResponse(
+            "No content was processed".to_string(),
+        ));
+    }
+
+    let (title, markdown) = split_title(&markdown);
+    info!(
+        "Generated {} minutes with {} ({} chunks, title: {:?})",
+        style.as_str(),
+        provider.provider_name(),
+        chunks_processed,
+        title
+    );
+    Ok(MeetingMinutes {
+        title,
+        markdown,
+        chunks_processed,
+    })
+}
+
+/// Separate the leading "# Title" line from the minutes body
+pub fn split_title(markdown: &str) -> (Option<String>, String) {
+    let title = match extract_meeting_name_from_markdown(markdown) {
+        Some(title) if !title.is_empty() => title,
+        _ => return (None, markdown.to_string()),
+    };
+    // Everything after the first heading line is the body
+    let body = match markdown.find('#') {
+        Some(hash_pos) => match markdown[hash_pos..].find('\n') {
+            Some(line_end) => markdown[hash_pos + line_end..].trim_start().to_string(),
+            None => String::new(),
+        },
+        None => String::new(),
+    };
+    (Some(title), body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_title() {
+        let (title, body) = split_title("# Q3 Budget Review\n\n## Decisions\n- Approved");
+        assert_eq!(title.as_deref(), Some("Q3 Budget Review"));
+        assert_eq!(body, "## Decisions\n- Approved");
+
+        let (title, body) = split_title("## Decisions\n- Approved");
+        assert!(title.is_none());
+        assert_eq!(body, "## Decisions\n- Approved");
+    }
+}
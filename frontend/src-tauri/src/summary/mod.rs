@@ -1,10 +1,32 @@
 /// Summary module - handles all meeting summary generation functionality
 ///
 /// This module contains:
-/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter, CustomOpenAI)
+/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter, Gemini, CustomOpenAI)
+/// - SummaryProvider trait abstracting the LLM backends, with native Ollama, streaming OpenAI, Claude, Groq and Gemini providers
+/// - Embedded llama.cpp provider running a GGUF model from the managed models directory, fully offline
 /// - Processor for chunking transcripts and generating summaries
+/// - Minutes orchestration turning a finished transcript into structured minutes
+/// - Structured action-item extraction, stored apart from the free-text summary
+/// - Decision and open-question extraction, appended to the minutes as their own sections
+/// - Topic segmentation into timestamped chapters that structure the minutes
+/// - Agenda coverage: which agenda items were discussed, for how long, and which were skipped
+/// - Standup reports: per-person yesterday/today updates, blockers and risks instead of minutes
+/// - Highlight reel of verbatim key quotes with speaker and time, shareable as text or audio clips
+/// - Automatic meeting titles and one-line descriptions when a recording is saved
+/// - Follow-up email drafts built from the stored minutes
+/// - Rolling live summary refreshed every few minutes while recording
+/// - Q&A over a single meeting's transcript with timestamped citations
+/// - Segment embeddings (local Ollama or embeddings API) and a per-meeting on-disk vector index
+/// - Semantic search across all meetings on top of the vector indexes
+/// - Digests combining the meetings of a date range or tag, on demand or weekly
 /// - Service layer for orchestrating summary generation
-/// - Templates for structured meeting summary generation
+/// - Templates for structured meeting summary generation, plus user prompt templates
+/// - Output style presets (executive brief, detailed minutes, bullet points, narrative)
+/// - Token usage and estimated cost of every summary-model request, per meeting
+/// - Numbered summary versions kept across regenerations, with line diffs and restore
+/// - Thumbs up/down and comments on summaries and sections, reported per template and model
+/// - A glossary of people, projects and acronyms given to the model for correct spellings
+/// - Redaction of names, contacts and account numbers before prompts reach cloud models
 /// - Tauri commands for frontend integration
 
 use serde::{Deserialize, Serialize};
@@ -28,15 +50,47 @@ pub struct CustomOpenAIConfig {
     /// Top-P sampling parameter (0.0-1.0, optional)
     #[serde(rename = "topP")]
     pub top_p: Option<f32>,
+    /// Context window of the served model in tokens (optional; guessed from the model name)
+    #[serde(rename = "contextTokens", default)]
+    pub context_tokens: Option<u32>,
 }
 
+pub mod action_items;
+pub mod agenda;
+pub mod auto_title;
+pub mod chapters;
+pub mod claude_provider;
 pub mod commands;
+pub mod digest;
+pub mod embeddings;
+pub mod feedback;
+pub mod follow_up;
+pub mod gemini_provider;
+pub mod glossary;
+pub mod groq_provider;
+pub mod highlights;
+pub mod live_summary;
+pub mod llama_provider;
 pub mod llm_client;
+pub mod minutes;
+pub mod ollama_provider;
+pub mod openai_provider;
+pub mod outcomes;
 pub mod processor;
+pub mod provider;
+pub mod qa;
+pub mod redaction;
+pub mod semantic_search;
 pub mod service;
+pub mod standup;
+pub mod style;
 pub mod summary_engine;
 pub mod template_commands;
 pub mod templates;
+pub mod tone;
+pub mod usage;
+pub mod vector_index;
+pub mod versions;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
@@ -47,12 +101,16 @@ pub use commands::{
 
 // Re-export template commands
 pub use template_commands::{
-    __cmd__api_get_template_details, __cmd__api_list_templates, __cmd__api_validate_template,
-    api_get_template_details, api_list_templates, api_validate_template,
+    __cmd__api_delete_prompt_template, __cmd__api_get_template_details,
+    __cmd__api_list_prompt_templates, __cmd__api_list_templates, __cmd__api_save_prompt_template,
+    __cmd__api_validate_template, api_delete_prompt_template, api_get_template_details,
+    api_list_prompt_templates, api_list_templates, api_save_prompt_template, api_validate_template,
 };
 
 // Re-export commonly used items
 pub use llm_client::LLMProvider;
+pub use minutes::{generate_minutes, MeetingMinutes};
+pub use provider::{SummaryError, SummaryProvider};
 pub use processor::{
     chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
     generate_meeting_summary, rough_token_count,
@@ -0,0 +1,257 @@
+// summary/ollama_provider.rs
+//
+// Summary provider backed by a local (or self-hosted) Ollama server, so minutes,
+// action items and titles are generated without the transcript leaving the machine.
+// Uses Ollama's native chat API rather than its OpenAI-compatible endpoint, because
+// only the native API accepts `num_ctx`; without it Ollama silently truncates long
+// prompts to its 2048-token default context.
+
+use async_trait::async_trait;
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::summary::provider::{read_stream_lines, SummaryError, SummaryProvider, TokenCallback};
+
+pub const DEFAULT_OLLAMA_HOST: &str = "http://localhost:11434";
+// Local models on CPU can take several minutes for a long transcript chunk
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
+// Tokens kept free for the system prompt and template when chunking
+const PROMPT_OVERHEAD_TOKENS: usize = 300;
+// Chunk size when the model's context window couldn't be fetched
+const DEFAULT_CONTEXT_TOKENS: usize = 4000;
+
+#[derive(Debug, Serialize)]
+struct OllamaChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaChatOptions {
+    num_ctx: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<OllamaChatMessage<'a>>,
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaChatOptions>,
+}
+
+// A full reply, or one line of a streamed reply
+#[derive(Debug, Deserialize)]
+struct OllamaChatResponse {
+    message: Option<OllamaChatResponseMessage>,
+    #[serde(default)]
+    done: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaChatResponseMessage {
+    content: String,
+}
+
+pub struct OllamaSummaryProvider {
+    client: Client,
+    host: String,
+    model_name: String,
+    // Model context window (from /api/show), passed as num_ctx
+    context_size: Option<usize>,
+}
+
+impl OllamaSummaryProvider {
+    /// # Arguments
+    /// * `host` - Ollama server URL; None or empty uses localhost:11434
+    /// * `model_name` - Pulled model to use (e.g., "llama3.2:latest")
+    /// * `context_size` - Model context window in tokens, if known
+    pub fn new(host: Option<String>, model_name: String, context_size: Option<usize>) -> Self {
+        Self {
+            client: Client::new(),
+            host: normalize_host(host.as_deref()),
+            model_name,
+            context_size,
+        }
+    }
+
+    async fn send(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        stream: bool,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<Response, SummaryError> {
+        if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+            return Err(SummaryError::Cancelled);
+        }
+
+        let request = OllamaChatRequest {
+            model: &self.model_name,
+            messages: vec![
+                OllamaChatMessage {
+                    role: "system",
+                    content: system_prompt,
+                },
+                OllamaChatMessage {
+                    role: "user",
+                    content: user_prompt,
+                },
+            ],
+            stream,
+            options: self.context_size.map(|num_ctx| OllamaChatOptions { num_ctx }),
+        };
+
+        info!("🦙 Ollama request to {}: model={}", self.host, self.model_name);
+        let request_future = self
+            .client
+            .post(format!("{}/api/chat", self.host))
+            .json(&request)
+            .timeout(REQUEST_TIMEOUT)
+            .send();
+
+        let result = match cancellation_token {
+            Some(token) => tokio::select! {
+                result = request_future => result,
+                _ = token.cancelled() => return Err(SummaryError::Cancelled),
+            },
+            None => request_future.await,
+        };
+        let response = result.map_err(|e| {
+            if e.is_connect() {
+                SummaryError::RequestFailed(format!(
+                    "Could not reach Ollama at {}. Is the Ollama server running?",
+                    self.host
+                ))
+            } else if e.is_timeout() {
+                SummaryError::RequestFailed(format!(
+                    "Ollama request timed out after {} seconds",
+                    REQUEST_TIMEOUT.as_secs()
+                ))
+            } else {
+                SummaryError::RequestFailed(format!("Failed to send request to Ollama: {}", e))
+            }
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if status == reqwest::StatusCode::NOT_FOUND {
+                return Err(SummaryError::NotConfigured(format!(
+                    "Model '{}' is not available on {}. Pull it with 'ollama pull {}'",
+                    self.model_name, self.host, self.model_name
+                )));
+            }
+            return Err(SummaryError::RequestFailed(format!("Ollama returned {}: {}", status, body)));
+        }
+        Ok(response)
+    }
+}
+
+/// Host URL without trailing slash, with a scheme, defaulting to the local server
+pub fn normalize_host(host: Option<&str>) -> String {
+    let host = host.map(str::trim).filter(|h| !h.is_empty()).unwrap_or(DEFAULT_OLLAMA_HOST);
+    let host = host.trim_end_matches('/');
+    if host.starts_with("http://") || host.starts_with("https://") {
+        host.to_string()
+    } else {
+        format!("http://{}", host)
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for OllamaSummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, false, cancellation_token).await?;
+        let chat_response = response
+            .json::<OllamaChatResponse>()
+            .await
+            .map_err(|e| SummaryError::InvalidResponse(format!("Failed to parse Ollama response: {}", e)))?;
+        info!("🦙 Ollama response received from {}", self.model_name);
+        match (chat_response.message, chat_response.error) {
+            (_, Some(error)) => Err(SummaryError::RequestFailed(format!("Ollama error: {}", error))),
+            (Some(message), None) => Ok(message.content.trim().to_string()),
+            (None, None) => Err(SummaryError::InvalidResponse("Ollama returned no message".to_string())),
+        }
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let response = self.send(system_prompt, user_prompt, true, cancellation_token).await?;
+        // Streamed replies are newline-delimited JSON objects
+        let mut content = String::new();
+        let mut stream_error = None;
+        read_stream_lines(response, "Ollama", cancellation_token, |line| {
+            let chunk = match serde_json::from_str::<OllamaChatResponse>(line) {
+                Ok(chunk) => chunk,
+                Err(_) => return false,
+            };
+            if let Some(error) = chunk.error {
+                stream_error = Some(SummaryError::RequestFailed(format!("Ollama error: {}", error)));
+                return true;
+            }
+            if let Some(message) = chunk.message.filter(|m| !m.content.is_empty()) {
+                on_token(&message.content);
+                content.push_str(&message.content);
+            }
+            chunk.done
+        })
+        .await?;
+        if let Some(e) = stream_error {
+            return Err(e);
+        }
+        info!("🦙 Ollama response streamed from {}", self.model_name);
+        Ok(content.trim().to_string())
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        Some(
+            self.context_size
+                .map(|size| size.saturating_sub(PROMPT_OVERHEAD_TOKENS))
+                .unwrap_or(DEFAULT_CONTEXT_TOKENS),
+        )
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Ollama"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_host() {
+        assert_eq!(normalize_host(None), DEFAULT_OLLAMA_HOST);
+        assert_eq!(normalize_host(Some("  ")), DEFAULT_OLLAMA_HOST);
+        assert_eq!(normalize_host(Some("http://gpu-box:11434/")), "http://gpu-box:11434");
+        assert_eq!(normalize_host(Some("192.168.1.20:11434")), "http://192.168.1.20:11434");
+    }
+
+    #[test]
+    fn test_chunks_to_model_context() {
+        let provider = OllamaSummaryProvider::new(None, "llama3.2".to_string(), Some(8192));
+        assert_eq!(provider.context_tokens(), Some(8192 - PROMPT_OVERHEAD_TOKENS));
+        let unknown = OllamaSummaryProvider::new(None, "llama3.2".to_string(), None);
+        assert_eq!(unknown.context_tokens(), Some(DEFAULT_CONTEXT_TOKENS));
+    }
+}
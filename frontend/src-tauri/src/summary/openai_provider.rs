@@ -0,0 +1,272 @@
+// summary/openai_provider.rs
+//
+// OpenAI chat-completions summary provider. Responses are streamed so long minutes
+// don't hit the request timeout while the model is still writing, and so the UI can
+// show the minutes as they are generated. The SSE line parser is shared with the other
+// OpenAI-compatible providers.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::summary::llm_client::{ChatMessage, ChatRequest};
+use crate::summary::provider::{
+    cloud_http_client, read_stream_lines, send_with_retry, SummaryError, SummaryProvider, TokenCallback,
+};
+
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+pub const DEFAULT_OPENAI_MODEL: &str = "gpt-4o-mini";
+// Whole-response limit; streaming keeps the connection busy, so this only bounds runaway generations
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(600);
+// Room kept for the reply, which shares the context window with the prompt
+const RESPONSE_RESERVE_TOKENS: usize = 8192;
+// Reserved for system prompt + template formatting
+const PROMPT_OVERHEAD_TOKENS: usize = 4000;
+
+#[derive(Debug, Deserialize)]
+struct StreamChunk {
+    choices: Vec<StreamChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamChoice {
+    delta: StreamDelta,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamDelta {
+    content: Option<String>,
+}
+
+/// One line of a server-sent-events chat stream
+#[derive(Debug, PartialEq)]
+pub enum StreamLine {
+    Delta(String),
+    Done,
+    Ignore,
+}
+
+/// Parse a line of an OpenAI-style SSE stream ("data: {...}" / "data: [DONE]")
+pub fn parse_stream_line(line: &str) -> StreamLine {
+    let data = match line.trim().strip_prefix("data:") {
+        Some(data) => data.trim(),
+        None => return StreamLine::Ignore,
+    };
+    if data == "[DONE]" {
+        return StreamLine::Done;
+    }
+    match serde_json::from_str::<StreamChunk>(data) {
+        Ok(chunk) => chunk
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|choice| choice.delta.content)
+            .filter(|content| !content.is_empty())
+            .map(StreamLine::Delta)
+            .unwrap_or(StreamLine::Ignore),
+        Err(_) => StreamLine::Ignore,
+    }
+}
+
+/// Context window of an OpenAI model, in tokens; None for models not in the table
+pub fn model_context_tokens(model_name: &str) -> Option<usize> {
+    let model = model_name.to_lowercase();
+    let model = model.rsplit('/').next().unwrap_or(&model);
+    if model.starts_with("gpt-4.1") {
+        Some(1_047_576)
+    } else if model.starts_with("gpt-5") {
+        Some(400_000)
+    } else if ["o1", "o3", "o4"].iter().any(|family| model.starts_with(family)) {
+        Some(200_000)
+    } else if ["gpt-4o", "chatgpt-4o", "gpt-4-turbo"].iter().any(|family| model.starts_with(family)) {
+        Some(128_000)
+    } else if model.starts_with("gpt-4-32k") {
+        Some(32_768)
+    } else if model.starts_with("gpt-4") {
+        Some(8_192)
+    } else if model.starts_with("gpt-3.5") {
+        Some(16_385)
+    } else {
+        None
+    }
+}
+
+/// Context window of a model served through an OpenAI-compatible API (OpenRouter, custom
+/// endpoints), guessed from its name; None when the family isn't recognised
+pub fn compatible_model_context_tokens(model_name: &str) -> Option<usize> {
+    let model = model_name.to_lowercase();
+    if let Some(tokens) = model_context_tokens(&model) {
+        return Some(tokens);
+    }
+    if model.contains("claude") {
+        Some(crate::summary::claude_provider::CLAUDE_CONTEXT_TOKENS)
+    } else if model.contains("gemini") {
+        Some(crate::summary::gemini_provider::model_context_tokens(&model))
+    } else if ["llama-3.1", "llama-3.2", "llama-3.3", "llama3.1", "llama3.2", "llama3.3", "llama-4"]
+        .iter()
+        .any(|family| model.contains(family))
+    {
+        Some(131_072)
+    } else if model.contains("deepseek") {
+        Some(65_536)
+    } else if ["qwen", "mistral", "mixtral"].iter().any(|family| model.contains(family)) {
+        Some(32_768)
+    } else {
+        None
+    }
+}
+
+pub struct OpenAISummaryProvider {
+    client: Client,
+    api_key: String,
+    model_name: String,
+}
+
+impl OpenAISummaryProvider {
+    /// # Arguments
+    /// * `api_key` - OpenAI API key
+    /// * `model_name` - Chat model (e.g., "gpt-4o", "gpt-4o-mini"); empty uses the default
+    pub fn new(api_key: String, model_name: String) -> Self {
+        let model_name = if model_name.trim().is_empty() {
+            DEFAULT_OPENAI_MODEL.to_string()
+        } else {
+            model_name
+        };
+        info!("🌐 OpenAI summary provider initialized with model: {}", model_name);
+        Self {
+            client: cloud_http_client(),
+            api_key,
+            model_name,
+        }
+    }
+
+    /// Always streams, so long generations never sit idle against the request timeout
+    async fn stream_chat(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: Option<&TokenCallback>,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let body = ChatRequest {
+            model: self.model_name.clone(),
+            messages: vec![
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: system_prompt.to_string(),
+                },
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: user_prompt.to_string(),
+                },
+            ],
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            stream: Some(true),
+        };
+
+        info!("🌐 OpenAI request: model={}", self.model_name);
+        let response = send_with_retry(
+            "OpenAI",
+            || {
+                self.client
+                    .post(OPENAI_CHAT_URL)
+                    .bearer_auth(&self.api_key)
+                    .json(&body)
+                    .timeout(REQUEST_TIMEOUT)
+            },
+            cancellation_token,
+        )
+        .await?;
+
+        let mut content = String::new();
+        read_stream_lines(response, "OpenAI", cancellation_token, |line| match parse_stream_line(line) {
+            StreamLine::Delta(delta) => {
+                if let Some(on_token) = on_token {
+                    on_token(&delta);
+                }
+                content.push_str(&delta);
+                false
+            }
+            StreamLine::Done => true,
+            StreamLine::Ignore => false,
+        })
+        .await?;
+
+        if content.trim().is_empty() {
+            return Err(SummaryError::InvalidResponse("OpenAI returned no content".to_string()));
+        }
+        info!("🌐 OpenAI response streamed ({} chars)", content.len());
+        Ok(content.trim().to_string())
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for OpenAISummaryProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        self.stream_chat(system_prompt, user_prompt, None, cancellation_token).await
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        self.stream_chat(system_prompt, user_prompt, Some(on_token), cancellation_token)
+            .await
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        // Unknown (newer) models are assumed to have at least gpt-4o's window
+        let context = model_context_tokens(&self.model_name).unwrap_or(128_000);
+        Some(context.saturating_sub(RESPONSE_RESERVE_TOKENS + PROMPT_OVERHEAD_TOKENS))
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "OpenAI"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stream_line() {
+        assert_eq!(
+            parse_stream_line(r#"data: {"choices":[{"delta":{"content":"Decisions"}}]}"#),
+            StreamLine::Delta("Decisions".to_string())
+        );
+        assert_eq!(
+            parse_stream_line(r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#),
+            StreamLine::Ignore
+        );
+        assert_eq!(parse_stream_line("data: [DONE]"), StreamLine::Done);
+        assert_eq!(parse_stream_line(": keep-alive"), StreamLine::Ignore);
+    }
+
+    #[test]
+    fn test_model_context_tokens() {
+        assert_eq!(model_context_tokens("gpt-4o-mini"), Some(128_000));
+        assert_eq!(model_context_tokens("gpt-4.1-nano"), Some(1_047_576));
+        assert_eq!(model_context_tokens("gpt-4"), Some(8_192));
+        assert_eq!(compatible_model_context_tokens("openai/gpt-3.5-turbo"), Some(16_385));
+        assert_eq!(compatible_model_context_tokens("meta-llama/llama-3.1-70b-instruct"), Some(131_072));
+        assert_eq!(compatible_model_context_tokens("my-finetune"), None);
+    }
+}
@@ -0,0 +1,366 @@
+// summary/outcomes.rs
+//
+// Decision and open-question extraction. Runs alongside action items over the numbered
+// transcript: every decision made and every question left unanswered is kept with the
+// segments where it came up, stored per meeting and rendered as dedicated sections at
+// the end of the minutes.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting_outcome::MeetingOutcomesRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, batch_lines, complete_validated, numbered_lines};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+/// JSON schema every reply must satisfy (also shown to the model)
+pub const OUTCOMES_SCHEMA: &str = r##"{
+  "type": "object",
+  "additionalProperties": false,
+  "required": ["decisions", "open_questions"],
+  "properties": {
+    "decisions": { "$ref": "#/$defs/outcome" },
+    "open_questions": { "$ref": "#/$defs/outcome" }
+  },
+  "$defs": {
+    "outcome": {
+      "type": "array",
+      "items": {
+        "type": "object",
+        "additionalProperties": false,
+        "required": ["text", "segments"],
+        "properties": {
+          "text": { "type": "string", "minLength": 1 },
+          "segments": { "type": "array", "minItems": 1, "items": { "type": "integer", "minimum": 0 } }
+        }
+      }
+    }
+  }
+}"##;
+
+const SYSTEM_PROMPT: &str = "You extract outcomes from meeting transcripts. Each transcript line starts \
+with its segment number in brackets. List every decision the participants explicitly made or agreed on, \
+and every question that was raised but not answered by the end of the excerpt. Write each as one short \
+self-contained sentence and give the numbers of the segments where it was discussed. Do not list \
+proposals nobody agreed to, or questions that were answered. Reply with only a JSON object that \
+validates against this JSON schema:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutcomeKind {
+    Decision,
+    OpenQuestion,
+}
+
+impl OutcomeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutcomeKind::Decision => "decision",
+            OutcomeKind::OpenQuestion => "open_question",
+        }
+    }
+
+    fn schema_key(&self) -> &'static str {
+        match self {
+            OutcomeKind::Decision => "decisions",
+            OutcomeKind::OpenQuestion => "open_questions",
+        }
+    }
+}
+
+/// A decision or open question that passed schema validation. `segments` index the
+/// meeting's segments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractedOutcome {
+    pub kind: OutcomeKind,
+    pub text: String,
+    pub segments: Vec<usize>,
+}
+
+/// Stored outcome as returned to the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingOutcomeEntry {
+    pub id: String,
+    pub kind: String,
+    pub text: String,
+    pub segment_ids: Vec<String>,
+}
+
+/// Validate a model reply against OUTCOMES_SCHEMA. `segments` is the range of segment
+/// numbers the model was shown.
+pub fn validate_outcomes(reply: &str, segments: Range<usize>) -> Result<Vec<ExtractedOutcome>, String> {
+    let json = match (reply.find('{'), reply.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON object".to_string()),
+    };
+    let object: serde_json::Map<String, Value> =
+        serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+    if let Some(key) = object
+        .keys()
+        .find(|key| !["decisions", "open_questions"].contains(&key.as_str()))
+    {
+        return Err(format!("unexpected property \"{}\"", key));
+    }
+
+    let mut outcomes = Vec::new();
+    for kind in [OutcomeKind::Decision, OutcomeKind::OpenQuestion] {
+        let key = kind.schema_key();
+        let items = match object.get(key) {
+            Some(Value::Array(items)) => items,
+            Some(_) => return Err(format!("\"{}\" must be an array", key)),
+            None => return Err(format!("missing required property \"{}\"", key)),
+        };
+        for (index, item) in items.iter().enumerate() {
+            let item = item
+                .as_object()
+                .ok_or_else(|| format!("{}[{}]: must be an object", key, index))?;
+            if let Some(extra) = item.keys().find(|k| !["text", "segments"].contains(&k.as_str())) {
+                return Err(format!("{}[{}]: unexpected property \"{}\"", key, index, extra));
+            }
+            let text = match item.get("text") {
+                Some(Value::String(text)) if !text.trim().is_empty() => text.trim().to_string(),
+                _ => return Err(format!("{}[{}]: \"text\" must be a non-empty string", key, index)),
+            };
+            let numbers = match item.get("segments") {
+                Some(Value::Array(numbers)) if !numbers.is_empty() => numbers,
+                _ => return Err(format!("{}[{}]: \"segments\" must be a non-empty array", key, index)),
+            };
+            let mut segment_numbers = Vec::with_capacity(numbers.len());
+            for number in numbers {
+                match number.as_u64() {
+                    Some(n) if segments.contains(&(n as usize)) => segment_numbers.push(n as usize),
+                    _ => {
+                        return Err(format!(
+                            "{}[{}]: segment {} is not one of the transcript lines {}..{}",
+                            key, index, number, segments.start, segments.end
+                        ))
+                    }
+                }
+            }
+            segment_numbers.sort_unstable();
+            segment_numbers.dedup();
+            outcomes.push(ExtractedOutcome {
+                kind,
+                text,
+                segments: segment_numbers,
+            });
+        }
+    }
+    Ok(outcomes)
+}
+
+/// Merge outcomes repeated across batches, keeping every segment they were linked to
+fn merge_outcomes(outcomes: Vec<ExtractedOutcome>) -> Vec<ExtractedOutcome> {
+    let normalize = |text: &str| -> String {
+        text.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect()
+    };
+    let mut merged: Vec<ExtractedOutcome> = Vec::new();
+    for outcome in outcomes {
+        let key = normalize(&outcome.text);
+        match merged
+            .iter_mut()
+            .find(|existing| existing.kind == outcome.kind && normalize(&existing.text) == key)
+        {
+            Some(existing) => {
+                existing.segments.extend(outcome.segments);
+                existing.segments.sort_unstable();
+                existing.segments.dedup();
+            }
+            None => merged.push(outcome),
+        }
+    }
+    merged
+}
+
+/// Timestamp of a segment for the minutes, e.g. "00:12:34"
+fn segment_reference(segments: &[(String, String, Option<f64>, Option<String>)], index: usize) -> Option<String> {
+    segments
+        .get(index)
+        .and_then(|(_, _, start, _)| *start)
+        .map(crate::utils::format_timestamp)
+}
+
+/// "## Decisions" and "## Open Questions" sections for the minutes, each entry followed
+/// by the transcript times it was discussed at
+pub fn render_outcomes_markdown(
+    outcomes: &[ExtractedOutcome],
+    segments: &[(String, String, Option<f64>, Option<String>)],
+) -> String {
+    let mut markdown = String::new();
+    for (kind, heading, empty) in [
+        (OutcomeKind::Decision, "## Decisions", "No decisions recorded."),
+        (OutcomeKind::OpenQuestion, "## Open Questions", "No open questions."),
+    ] {
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        markdown.push_str(heading);
+        markdown.push_str("\n\n");
+        let mut any = false;
+        for outcome in outcomes.iter().filter(|o| o.kind == kind) {
+            any = true;
+            let times: Vec<String> = outcome
+                .segments
+                .iter()
+                .filter_map(|&i| segment_reference(segments, i))
+                .collect();
+            if times.is_empty() {
+                markdown.push_str(&format!("- {}\n", outcome.text));
+            } else {
+                markdown.push_str(&format!("- {} _({})_\n", outcome.text, times.join(", ")));
+            }
+        }
+        if !any {
+            markdown.push_str(empty);
+            markdown.push('\n');
+        }
+    }
+    markdown.trim_end().to_string()
+}
+
+/// Extract a meeting's decisions and open questions, store them and return the minutes
+/// sections. None when the meeting has no transcript segments to link to.
+pub async fn extract_and_store(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &dyn SummaryProvider,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<String>, String> {
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let lines = numbered_lines(&segments);
+    let system_prompt = format!("{}\n{}", SYSTEM_PROMPT, OUTCOMES_SCHEMA);
+    let mut extracted = Vec::new();
+    for (batch_index, range) in batch_lines(&lines, batch_budget(provider)).into_iter().enumerate() {
+        let user_prompt = format!("<transcript>\n{}\n</transcript>", lines[range.clone()].join("\n"));
+        let validate = |reply: &str| validate_outcomes(reply, range.clone());
+        match complete_validated(provider, &system_prompt, &user_prompt, validate, cancellation_token).await {
+            Ok(outcomes) => extracted.extend(outcomes),
+            Err(SummaryError::InvalidResponse(e)) => {
+                warn!("Skipping outcomes of batch {} for {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Outcome extraction failed: {}", e)),
+        }
+    }
+    let outcomes = merge_outcomes(extracted);
+
+    let rows: Vec<(String, String, Vec<String>)> = outcomes
+        .iter()
+        .map(|outcome| {
+            let segment_ids = outcome
+                .segments
+                .iter()
+                .filter_map(|&i| segments.get(i).map(|(id, _, _, _)| id.clone()))
+                .collect();
+            (outcome.kind.as_str().to_string(), outcome.text.clone(), segment_ids)
+        })
+        .collect();
+    MeetingOutcomesRepository::replace_for_meeting(pool, meeting_id, &rows)
+        .await
+        .map_err(|e| format!("Failed to save meeting outcomes: {}", e))?;
+    info!(
+        "Extracted {} decision(s) and {} open question(s) for {}",
+        outcomes.iter().filter(|o| o.kind == OutcomeKind::Decision).count(),
+        outcomes.iter().filter(|o| o.kind == OutcomeKind::OpenQuestion).count(),
+        meeting_id
+    );
+
+    Ok(Some(render_outcomes_markdown(&outcomes, &segments)))
+}
+
+#[command]
+pub async fn get_meeting_outcomes<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingOutcomeEntry>, String> {
+    let state = app.state::<AppState>();
+    let outcomes = MeetingOutcomesRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting outcomes: {}", e))?;
+    Ok(outcomes
+        .into_iter()
+        .map(|outcome| MeetingOutcomeEntry {
+            id: outcome.id,
+            kind: outcome.kind,
+            text: outcome.text,
+            segment_ids: serde_json::from_str(&outcome.segment_ids).unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Re-run decision and open-question extraction for a meeting with the configured model
+#[command]
+pub async fn extract_meeting_outcomes<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<MeetingOutcomeEntry>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "outcomes");
+    extract_and_store(pool, &meeting_id, provider.as_ref(), None).await?;
+    get_meeting_outcomes(app, meeting_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_outcomes() {
+        let reply = r#"Here you go: {"decisions":[{"text":"Ship v2 on Monday","segments":[4,3,4]}],
+            "open_questions":[{"text":"Who owns the migration?","segments":[6]}]}"#;
+        let outcomes = validate_outcomes(reply, 0..10).unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].kind, OutcomeKind::Decision);
+        assert_eq!(outcomes[0].segments, vec![3, 4]);
+        assert_eq!(outcomes[1].kind, OutcomeKind::OpenQuestion);
+
+        for bad in [
+            r#"{"decisions":[]}"#,
+            r#"{"decisions":[{"text":"x","segments":[]}],"open_questions":[]}"#,
+            r#"{"decisions":[{"text":"x","segments":[12]}],"open_questions":[]}"#,
+            r#"{"decisions":[],"open_questions":[],"risks":[]}"#,
+        ] {
+            assert!(validate_outcomes(bad, 0..10).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_merge_and_render() {
+        let outcome = |kind, text: &str, segments: Vec<usize>| ExtractedOutcome {
+            kind,
+            text: text.to_string(),
+            segments,
+        };
+        let merged = merge_outcomes(vec![
+            outcome(OutcomeKind::Decision, "Ship v2 on Monday.", vec![1]),
+            outcome(OutcomeKind::Decision, "ship v2 on monday", vec![0]),
+        ]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].segments, vec![0, 1]);
+
+        let segments = vec![
+            ("t0".to_string(), "a".to_string(), Some(5.0), None),
+            ("t1".to_string(), "b".to_string(), Some(754.0), None),
+        ];
+        let markdown = render_outcomes_markdown(&merged, &segments);
+        assert_eq!(
+            markdown,
+            "## Decisions\n\n- Ship v2 on Monday. _(00:00:05, 00:12:34)_\n\n## Open Questions\n\nNo open questions."
+        );
+    }
+}
@@ -1,11 +1,10 @@
-use crate::summary::llm_client::{generate_summary, LLMProvider};
-use crate::summary::templates;
+use crate::summary::provider::{SummaryError, SummaryProvider, TokenCallback};
+use crate::summary::style::SummaryStyle;
+use crate::summary::templates::{self, MinutesTemplate};
 use once_cell::sync::Lazy;
 use regex::Regex;
-use reqwest::Client;
-use std::path::PathBuf;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 // Compile regex once and reuse (significant performance improvement for repeated calls)
 static THINKING_TAG_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -135,179 +134,20 @@ pub fn extract_meeting_name_from_markdown(markdown: &str) -> Option<String> {
         .map(|line| line.trim_start_matches("# ").trim().to_string())
 }
 
-/// Generates a complete meeting summary with conditional chunking strategy
-///
-/// # Arguments
-/// * `client` - Reqwest HTTP client
-/// * `provider` - LLM provider to use
-/// * `model_name` - Specific model name
-/// * `api_key` - API key for the provider
-/// * `text` - Full transcript text to summarize
-/// * `custom_prompt` - Optional user-provided context
-/// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
-/// * `token_threshold` - Token limit for single-pass processing (default 4000)
-/// * `ollama_endpoint` - Optional custom Ollama endpoint
-/// * `custom_openai_endpoint` - Optional custom OpenAI-compatible endpoint
-/// * `max_tokens` - Optional max tokens for completion (CustomOpenAI provider)
-/// * `temperature` - Optional temperature (CustomOpenAI provider)
-/// * `top_p` - Optional top_p (CustomOpenAI provider)
-/// * `app_data_dir` - Optional app data directory (BuiltInAI provider)
-/// * `cancellation_token` - Optional cancellation token to stop processing
-///
-/// # Returns
-/// Tuple of (final_summary_markdown, number_of_chunks_processed)
-pub async fn generate_meeting_summary(
-    client: &Client,
-    provider: &LLMProvider,
-    model_name: &str,
-    api_key: &str,
-    text: &str,
-    custom_prompt: &str,
-    template_id: &str,
-    token_threshold: usize,
-    ollama_endpoint: Option<&str>,
-    custom_openai_endpoint: Option<&str>,
-    max_tokens: Option<u32>,
-    temperature: Option<f32>,
-    top_p: Option<f32>,
-    app_data_dir: Option<&PathBuf>,
-    cancellation_token: Option<&CancellationToken>,
-) -> Result<(String, i64), String> {
-    // Check cancellation at the start
-    if let Some(token) = cancellation_token {
-        if token.is_cancelled() {
-            return Err("Summary generation was cancelled".to_string());
-        }
-    }
-    info!(
-        "Starting summary generation with provider: {:?}, model: {}",
-        provider, model_name
-    );
-
-    let total_tokens = rough_token_count(text);
-    info!("Transcript length: {} tokens", total_tokens);
-
-    let content_to_summarize: String;
-    let successful_chunk_count: i64;
-
-    // Strategy: Use single-pass for cloud providers or short transcripts
-    // Use multi-level chunking for Ollama/BuiltInAI with long transcripts
-    // Note: CustomOpenAI is treated like cloud providers (unlimited context)
-    if (provider != &LLMProvider::Ollama && provider != &LLMProvider::BuiltInAI) || total_tokens < token_threshold {
-        info!(
-            "Using single-pass summarization (tokens: {}, threshold: {})",
-            total_tokens, token_threshold
-        );
-        content_to_summarize = text.to_string();
-        successful_chunk_count = 1;
-    } else {
-        info!(
-            "Using multi-level summarization (tokens: {} exceeds threshold: {})",
-            total_tokens, token_threshold
-        );
-
-        // Reserve 300 tokens for prompt overhead
-        let chunks = chunk_text(text, token_threshold - 300, 100);
-        let num_chunks = chunks.len();
-        info!("Split transcript into {} chunks", num_chunks);
-
-        let mut chunk_summaries = Vec::new();
-        let system_prompt_chunk = "You are an expert meeting summarizer.";
-        let user_prompt_template_chunk = "Provide a concise but comprehensive summary of the following transcript chunk. Capture all key points, decisions, action items, and mentioned individuals.\n\n<transcript_chunk>\n{}\n</transcript_chunk>";
-
-        for (i, chunk) in chunks.iter().enumerate() {
-            // Check for cancellation before processing each chunk
-            if let Some(token) = cancellation_token {
-                if token.is_cancelled() {
-                    info!("Summary generation cancelled during chunk {}/{}", i + 1, num_chunks);
-                    return Err("Summary generation was cancelled".to_string());
-                }
-            }
-
-            info!("Processing chunk {}/{}", i + 1, num_chunks);
-            let user_prompt_chunk = user_prompt_template_chunk.replace("{}", chunk.as_str());
-
-            match generate_summary(
-                client,
-                provider,
-                model_name,
-                api_key,
-                system_prompt_chunk,
-                &user_prompt_chunk,
-                ollama_endpoint,
-                custom_openai_endpoint,
-                max_tokens,
-                temperature,
-                top_p,
-                app_data_dir,
-                cancellation_token,
-            )
-            .await
-            {
-                Ok(summary) => {
-                    chunk_summaries.push(summary);
-                    info!("✓ Chunk {}/{} processed successfully", i + 1, num_chunks);
-                }
-                Err(e) => {
-                    // Check if error is due to cancellation
-                    if e.contains("cancelled") {
-                        return Err(e);
-                    }
-                    error!("Failed processing chunk {}/{}: {}", i + 1, num_chunks, e);
-                }
-            }
-        }
-
-        if chunk_summaries.is_empty() {
-            return Err(
-                "Multi-level summarization failed: No chunks were processed successfully."
-                    .to_string(),
-            );
-        }
-
-        successful_chunk_count = chunk_summaries.len() as i64;
-        info!(
-            "Successfully processed {} out of {} chunks",
-            successful_chunk_count, num_chunks
-        );
-
-        // Combine chunk summaries if multiple chunks
-        content_to_summarize = if chunk_summaries.len() > 1 {
-            info!(
-                "Combining {} chunk summaries into cohesive summary",
-                chunk_summaries.len()
-            );
-            let combined_text = chunk_summaries.join("\n---\n");
-            let system_prompt_combine = "You are an expert at synthesizing meeting summaries.";
-            let user_prompt_combine_template = "The following are consecutive summaries of a meeting. Combine them into a single, coherent, and detailed narrative summary that retains all important details, organized logically.\n\n<summaries>\n{}\n</summaries>";
-
-            let user_prompt_combine = user_prompt_combine_template.replace("{}", &combined_text);
-            generate_summary(
-                client,
-                provider,
-                model_name,
-                api_key,
-                system_prompt_combine,
-                &user_prompt_combine,
-                ollama_endpoint,
-                custom_openai_endpoint,
-                max_tokens,
-                temperature,
-                top_p,
-                app_data_dir,
-                cancellation_token,
-            )
-            .await?
-        } else {
-            chunk_summaries.remove(0)
-        };
-    }
-
-    info!("Generating final markdown report with template: {}", template_id);
+const PROMPT_TEMPLATE_SYSTEM_PROMPT: &str = "You are an expert meeting summarizer. Write the meeting \
+minutes the user asks for in Markdown, starting with a \"# \" title line. Only use information present in \
+the transcript; do not add or infer anything.";
 
+/// System and user prompts for the final pass with a section template
+fn section_template_prompts(
+    template_id: &str,
+    content_to_summarize: &str,
+) -> Result<(String, String), SummaryError> {
     // Load the template using the provided template_id
     let template = templates::get_template(template_id)
-        .map_err(|e| format!("Failed to load template '{}': {}", template_id, e))?;
+        .map_err(|e| {
+            SummaryError::NotConfigured(format!("Failed to load template '{}': {}", template_id, e))
+        })?;
 
     // Generate markdown structure and section instructions using template methods
     let clean_template_markdown = template.to_markdown_structure();
@@ -334,7 +174,7 @@ pub async fn generate_meeting_summary(
         section_instructions, clean_template_markdown
     );
 
-    let mut final_user_prompt = format!(
+    let final_user_prompt = format!(
         r#"
 <transcript_chunks>
 {}
@@ -343,40 +183,379 @@ pub async fn generate_meeting_summary(
         content_to_summarize
     );
 
+    Ok((final_system_prompt, final_user_prompt))
+}
+
+/// System and user prompts for the final report over `content`
+fn final_prompts(
+    template: &MinutesTemplate,
+    content: &str,
+    custom_prompt: &str,
+    style: SummaryStyle,
+) -> Result<(String, String), SummaryError> {
+    let (system_prompt, mut user_prompt) = match template {
+        MinutesTemplate::Sections(template_id) => section_template_prompts(template_id, content)?,
+        MinutesTemplate::Prompt(prompt_template, variables) => {
+            let (system_prompt, user_prompt) = prompt_template.render(content, variables);
+            (
+                system_prompt.unwrap_or_else(|| PROMPT_TEMPLATE_SYSTEM_PROMPT.to_string()),
+                user_prompt,
+            )
+        }
+    };
+
     if !custom_prompt.is_empty() {
-        final_user_prompt.push_str("\n\nUser Provided Context:\n\n<user_context>\n");
-        final_user_prompt.push_str(custom_prompt);
-        final_user_prompt.push_str("\n</user_context>");
+        user_prompt.push_str("\n\nUser Provided Context:\n\n<user_context>\n");
+        user_prompt.push_str(custom_prompt);
+        user_prompt.push_str("\n</user_context>");
+    }
+    style.apply(&mut user_prompt);
+    Ok((system_prompt, user_prompt))
+}
+
+// Tokens taken by the map and reduce prompts themselves
+const MAP_REDUCE_PROMPT_TOKENS: usize = 300;
+// Smallest budget worth chunking to; below this a model can't summarize anything useful
+const MIN_BUDGET_TOKENS: usize = 1_000;
+// Bounds for the length asked of each chunk summary
+const MIN_SUMMARY_TOKENS: usize = 200;
+const MAX_SUMMARY_TOKENS: usize = 1_500;
+// Reduce rounds before any remaining excess is cut off
+const MAX_REDUCE_ROUNDS: usize = 4;
+// Times a request rejected for its length is retried with half the budget
+const CONTEXT_RETRIES: usize = 2;
+
+const MAP_SYSTEM_PROMPT: &str = "You are an expert meeting summarizer.";
+const MAP_USER_PROMPT: &str = "Provide a concise but comprehensive summary of the following transcript chunk, \
+in at most {words} words. Capture all key points, decisions, action items, and mentioned individuals.\n\n\
+<transcript_chunk>\n{content}\n</transcript_chunk>";
+const REDUCE_SYSTEM_PROMPT: &str = "You are an expert at synthesizing meeting summaries.";
+const REDUCE_USER_PROMPT: &str = "The following are consecutive summaries of parts of one meeting. Combine them \
+into a single, coherent summary of at most {words} words that retains all decisions, action items, owners and \
+important details, organized logically.\n\n<summaries>\n{content}\n</summaries>";
+
+/// Whether a provider rejected a request because the prompt was longer than its context
+fn is_context_length_error(error: &SummaryError) -> bool {
+    const MARKERS: &[&str] = &[
+        "context length",
+        "context_length",
+        "context window",
+        "maximum context",
+        "prompt is too long",
+        "too many tokens",
+        "input is too long",
+        "reduce the length",
+    ];
+    match error {
+        SummaryError::RequestFailed(message) | SummaryError::InvalidResponse(message) => {
+            let message = message.to_lowercase();
+            MARKERS.iter().any(|marker| message.contains(marker))
+        }
+        _ => false,
+    }
+}
+
+/// Consecutive groups of summaries (given their token counts) that each fit `budget`,
+/// so each group can be merged in one request
+fn reduce_groups(token_counts: &[usize], budget: usize) -> Vec<std::ops::Range<usize>> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    let mut tokens = 0;
+    for (i, &count) in token_counts.iter().enumerate() {
+        if i > start && tokens + count > budget {
+            groups.push(start..i);
+            start = i;
+            tokens = 0;
+        }
+        tokens += count;
+    }
+    if start < token_counts.len() {
+        groups.push(start..token_counts.len());
+    }
+    groups
+}
+
+/// Cut text down to roughly `max_tokens`, at a word boundary
+fn truncate_to_tokens(text: &str, max_tokens: usize) -> String {
+    match chunk_text(text, max_tokens, 0).into_iter().next() {
+        Some(first) => first.trim_end().to_string(),
+        None => String::new(),
+    }
+}
+
+fn check_cancelled(cancellation_token: Option<&CancellationToken>) -> Result<(), SummaryError> {
+    match cancellation_token {
+        Some(token) if token.is_cancelled() => Err(SummaryError::Cancelled),
+        _ => Ok(()),
+    }
+}
+
+/// Condense a transcript that doesn't fit the final prompt: summarize chunks that fit the
+/// context (map), then merge the summaries in groups until they fit `content_budget`
+/// (reduce). Returns the condensed text and the number of chunks summarized.
+async fn map_reduce(
+    provider: &dyn SummaryProvider,
+    text: &str,
+    context_tokens: usize,
+    content_budget: usize,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<(String, i64), SummaryError> {
+    let request_budget = context_tokens.saturating_sub(MAP_REDUCE_PROMPT_TOKENS).max(MIN_BUDGET_TOKENS);
+    let chunks = chunk_text(text, request_budget, 100);
+    let num_chunks = chunks.len();
+    // Ask for short enough summaries that they fit the final prompt together when possible
+    let summary_tokens = (content_budget / num_chunks.max(1)).clamp(MIN_SUMMARY_TOKENS, MAX_SUMMARY_TOKENS);
+    info!(
+        "Map-reduce over {} chunks of up to {} tokens ({} tokens per summary, {} for the final prompt)",
+        num_chunks, request_budget, summary_tokens, content_budget
+    );
+
+    let mut summaries = Vec::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        if check_cancelled(cancellation_token).is_err() {
+            info!("Summary generation cancelled during chunk {}/{}", i + 1, num_chunks);
+            return Err(SummaryError::Cancelled);
+        }
+
+        info!("Processing chunk {}/{}", i + 1, num_chunks);
+        let user_prompt = MAP_USER_PROMPT
+            .replace("{words}", &(summary_tokens / 2).to_string())
+            .replace("{content}", chunk);
+        match provider.complete(MAP_SYSTEM_PROMPT, &user_prompt, cancellation_token).await {
+            Ok(summary) => {
+                summaries.push(clean_llm_markdown_output(&summary));
+                info!("✓ Chunk {}/{} processed successfully", i + 1, num_chunks);
+            }
+            Err(SummaryError::Cancelled) => return Err(SummaryError::Cancelled),
+            // Retried by the caller with a smaller budget
+            Err(e) if is_context_length_error(&e) => return Err(e),
+            Err(e) => {
+                error!("Failed processing chunk {}/{}: {}", i + 1, num_chunks, e);
+            }
+        }
+    }
+
+    if summaries.is_empty() {
+        return Err(SummaryError::RequestFailed(
+            "Multi-level summarization failed: No chunks were processed successfully."
+                .to_string(),
+        ));
+    }
+    let successful_chunk_count = summaries.len() as i64;
+    info!(
+        "Successfully processed {} out of {} chunks",
+        successful_chunk_count, num_chunks
+    );
+
+    let mut round = 0;
+    loop {
+        let counts: Vec<usize> = summaries.iter().map(|summary| rough_token_count(summary)).collect();
+        if counts.iter().sum::<usize>() <= content_budget {
+            break;
+        }
+        let groups = reduce_groups(&counts, request_budget);
+        if round == MAX_REDUCE_ROUNDS || groups.len() == summaries.len() {
+            // Merging can't make progress: keep an equal share of each summary
+            warn!(
+                "Summaries still exceed the final prompt budget after {} reduce rounds; truncating",
+                round
+            );
+            let share = content_budget / summaries.len();
+            summaries = summaries
+                .iter()
+                .map(|summary| truncate_to_tokens(summary, share))
+                .collect();
+            break;
+        }
+
+        round += 1;
+        info!(
+            "Reduce round {}: merging {} summaries into {}",
+            round,
+            summaries.len(),
+            groups.len()
+        );
+        let merged_tokens = (content_budget / groups.len()).clamp(MIN_SUMMARY_TOKENS, MAX_SUMMARY_TOKENS);
+        let mut merged = Vec::with_capacity(groups.len());
+        for group in groups {
+            check_cancelled(cancellation_token)?;
+            if group.len() == 1 {
+                merged.push(summaries[group.start].clone());
+                continue;
+            }
+            let user_prompt = REDUCE_USER_PROMPT
+                .replace("{words}", &(merged_tokens / 2).to_string())
+                .replace("{content}", &summaries[group].join("\n---\n"));
+            let summary = provider
+                .complete(REDUCE_SYSTEM_PROMPT, &user_prompt, cancellation_token)
+                .await?;
+            merged.push(clean_llm_markdown_output(&summary));
+        }
+        summaries = merged;
     }
 
+    Ok((summaries.join("\n---\n"), successful_chunk_count))
+}
+
+/// One attempt at the minutes, with `context_tokens` as the model's usable context
+#[allow(clippy::too_many_arguments)]
+async fn summarize_within(
+    provider: &dyn SummaryProvider,
+    text: &str,
+    custom_prompt: &str,
+    template: &MinutesTemplate,
+    style: SummaryStyle,
+    context_tokens: Option<usize>,
+    on_token: Option<&TokenCallback>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<(String, i64), SummaryError> {
+    let total_tokens = rough_token_count(text);
+
+    // The template, instructions and context share the window with the transcript
+    let (system_prompt, user_prompt) = final_prompts(template, "", custom_prompt, style)?;
+    let prompt_tokens = rough_token_count(&system_prompt) + rough_token_count(&user_prompt);
+
+    let (content_to_summarize, successful_chunk_count) = match context_tokens {
+        Some(context_tokens) if total_tokens + prompt_tokens > context_tokens => {
+            let content_budget = context_tokens.saturating_sub(prompt_tokens).max(MIN_BUDGET_TOKENS);
+            info!(
+                "Using map-reduce summarization (tokens: {}, final prompt: {}, context: {})",
+                total_tokens, prompt_tokens, context_tokens
+            );
+            map_reduce(provider, text, context_tokens, content_budget, cancellation_token).await?
+        }
+        _ => {
+            info!(
+                "Using single-pass summarization (tokens: {}, context: {:?})",
+                total_tokens, context_tokens
+            );
+            (text.to_string(), 1)
+        }
+    };
+
+    match template {
+        MinutesTemplate::Sections(template_id) => {
+            info!("Generating final markdown report with template: {}", template_id)
+        }
+        MinutesTemplate::Prompt(prompt_template, _) => {
+            info!("Generating final markdown report with prompt template: {}", prompt_template.id)
+        }
+    }
+    let (final_system_prompt, final_user_prompt) =
+        final_prompts(template, &content_to_summarize, custom_prompt, style)?;
+
     // Check cancellation before final summary generation
-    if let Some(token) = cancellation_token {
-        if token.is_cancelled() {
-            info!("Summary generation cancelled before final summary");
-            return Err("Summary generation was cancelled".to_string());
+    if check_cancelled(cancellation_token).is_err() {
+        info!("Summary generation cancelled before final summary");
+        return Err(SummaryError::Cancelled);
+    }
+
+    let raw_markdown = match on_token {
+        Some(on_token) => {
+            provider
+                .complete_streaming(&final_system_prompt, &final_user_prompt, on_token, cancellation_token)
+                .await?
+        }
+        None => {
+            provider
+                .complete(&final_system_prompt, &final_user_prompt, cancellation_token)
+                .await?
         }
+    };
+
+    Ok((clean_llm_markdown_output(&raw_markdown), successful_chunk_count))
+}
+
+/// Generates a complete meeting summary, condensing transcripts that don't fit the model's
+/// context with a map-reduce pass first
+///
+/// # Arguments
+/// * `provider` - Summary provider to run completions with
+/// * `text` - Full transcript text to summarize
+/// * `custom_prompt` - Optional user-provided context
+/// * `template` - Section template or user prompt template for the final report
+/// * `style` - Length and structure preset for the final report
+/// * `on_token` - Optional receiver for the final report as it streams in
+///   (intermediate chunk summaries are not streamed)
+/// * `cancellation_token` - Optional cancellation token to stop processing
+///
+/// # Returns
+/// Tuple of (final_summary_markdown, number_of_chunks_processed)
+pub async fn generate_meeting_summary(
+    provider: &dyn SummaryProvider,
+    text: &str,
+    custom_prompt: &str,
+    template: &MinutesTemplate,
+    style: SummaryStyle,
+    on_token: Option<&TokenCallback>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<(String, i64), SummaryError> {
+    check_cancelled(cancellation_token)?;
+    info!(
+        "Starting summary generation with provider: {}, model: {}",
+        provider.provider_name(),
+        provider.model_name()
+    );
+    info!("Transcript length: {} tokens", rough_token_count(text));
+
+    // Context sizes are estimates; when a provider still rejects a request as too long,
+    // try again with half the budget
+    let mut context_tokens = provider.context_tokens();
+    let mut retries = 0;
+    loop {
+        let result = summarize_within(
+            provider,
+            text,
+            custom_prompt,
+            template,
+            style,
+            context_tokens,
+            on_token,
+            cancellation_token,
+        )
+        .await;
+        match result {
+            Err(e) if retries < CONTEXT_RETRIES && is_context_length_error(&e) => {
+                let current = context_tokens.unwrap_or_else(|| rough_token_count(text));
+                context_tokens = Some((current / 2).max(MIN_BUDGET_TOKENS));
+                retries += 1;
+                warn!(
+                    "{} rejected the prompt as too long ({}); retrying with a {}-token budget",
+                    provider.provider_name(),
+                    e,
+                    context_tokens.unwrap_or_default()
+                );
+            }
+            Ok(result) => {
+                info!("Summary generation completed successfully");
+                return Ok(result);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reduce_groups() {
+        assert_eq!(reduce_groups(&[400, 300, 500, 200, 900], 1_000), vec![0..2, 2..4, 4..5]);
+        // A summary larger than the budget still gets a group of its own
+        assert_eq!(reduce_groups(&[1_500, 100], 1_000), vec![0..1, 1..2]);
+        assert!(reduce_groups(&[], 1_000).is_empty());
     }
 
-    let raw_markdown = generate_summary(
-        client,
-        provider,
-        model_name,
-        api_key,
-        &final_system_prompt,
-        &final_user_prompt,
-        ollama_endpoint,
-        custom_openai_endpoint,
-        max_tokens,
-        temperature,
-        top_p,
-        app_data_dir,
-        cancellation_token,
-    )
-    .await?;
-
-    // Clean the output
-    let final_markdown = clean_llm_markdown_output(&raw_markdown);
-
-    info!("Summary generation completed successfully");
-    Ok((final_markdown, successful_chunk_count))
+    #[test]
+    fn test_is_context_length_error() {
+        let openai = SummaryError::RequestFailed(
+            "HTTP 400: This model's maximum context length is 128000 tokens".to_string(),
+        );
+        assert!(is_context_length_error(&openai));
+        let claude = SummaryError::RequestFailed("prompt is too long: 210000 tokens > 200000 maximum".to_string());
+        assert!(is_context_length_error(&claude));
+        assert!(!is_context_length_error(&SummaryError::RequestFailed("HTTP 429: rate limited".to_string())));
+        assert!(!is_context_length_error(&SummaryError::Cancelled));
+    }
 }
@@ -0,0 +1,306 @@
+// summary/provider.rs
+//
+// Defines the SummaryProvider trait and error type for all summary backends
+// (cloud chat APIs, Ollama, the built-in sidecar), mirroring the transcription
+// provider abstraction. Orchestration in `summary::minutes` only talks to this trait.
+
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::summary::llm_client::{generate_summary, LLMProvider};
+use crate::summary::service::LlmConnection;
+
+// ============================================================================
+// SUMMARY PROVIDER TRAIT & ERROR TYPES
+// ============================================================================
+
+/// Granular error types for summary generation
+#[derive(Debug, Clone)]
+pub enum SummaryError {
+    /// Provider selected but missing credentials, endpoint or model
+    NotConfigured(String),
+    Cancelled,
+    RequestFailed(String),
+    InvalidResponse(String),
+}
+
+impl std::fmt::Display for SummaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotConfigured(msg) => write!(f, "Summary provider not configured: {}", msg),
+            Self::Cancelled => write!(f, "Summary generation was cancelled"),
+            Self::RequestFailed(msg) => write!(f, "Summary request failed: {}", msg),
+            Self::InvalidResponse(msg) => write!(f, "Invalid summary response: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SummaryError {}
+
+/// Trait for summary providers (OpenAI, Claude, Ollama, built-in AI, future providers)
+#[async_trait]
+pub trait SummaryProvider: Send + Sync {
+    /// Run one completion
+    ///
+    /// # Arguments
+    /// * `system_prompt` - System instructions for the model
+    /// * `user_prompt` - Content to process
+    /// * `cancellation_token` - Optional token to abort the request
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError>;
+
+    /// Run one completion, passing text to `on_token` as the model generates it.
+    /// Providers without streaming support deliver the whole reply in one call.
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let text = self.complete(system_prompt, user_prompt, cancellation_token).await?;
+        on_token(&text);
+        Ok(text)
+    }
+
+    /// Usable context window in tokens; None if transcripts always fit in one request
+    fn context_tokens(&self) -> Option<usize>;
+
+    /// Get the model used for completions
+    fn model_name(&self) -> &str;
+
+    /// Get the provider name (for logging/debugging)
+    fn provider_name(&self) -> &'static str;
+}
+
+/// Receives generated text as it streams in (for live progress in the UI)
+pub type TokenCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+// ============================================================================
+// SHARED HTTP INFRASTRUCTURE FOR CLOUD PROVIDERS
+// ============================================================================
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+const MAX_ATTEMPTS: u32 = 3;
+const INITIAL_RETRY: Duration = Duration::from_secs(2);
+
+/// HTTP client for cloud summary providers. Honors the system proxy settings
+/// (HTTP_PROXY / HTTPS_PROXY / NO_PROXY) and fails fast when the host is unreachable.
+pub fn cloud_http_client() -> Client {
+    Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .build()
+        .unwrap_or_else(|_| Client::new())
+}
+
+/// Rate limits and server-side failures are worth another attempt; client errors aren't
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Send a request, retrying connectivity errors, rate limits and 5xx responses with
+/// exponential backoff. `build` creates a fresh request for every attempt.
+pub async fn send_with_retry(
+    provider_name: &str,
+    build: impl Fn() -> RequestBuilder,
+    cancellation_token: Option<&CancellationToken>,
+) -> std::result::Result<Response, SummaryError> {
+    let mut delay = INITIAL_RETRY;
+    let mut attempt = 1;
+    loop {
+        let request_future = build().send();
+        let result = match cancellation_token {
+            Some(token) => tokio::select! {
+                result = request_future => result,
+                _ = token.cancelled() => return Err(SummaryError::Cancelled),
+            },
+            None => request_future.await,
+        };
+
+        let failure = match result {
+            Ok(response) if response.status().is_success() => return Ok(response),
+            Ok(response) if is_retryable_status(response.status()) && attempt < MAX_ATTEMPTS => {
+                format!("{} returned {}", provider_name, response.status())
+            }
+            Ok(response) => {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(if status == StatusCode::UNAUTHORIZED || status == StatusCode::FORBIDDEN {
+                    SummaryError::NotConfigured(format!("{} rejected the API key: {}", provider_name, body))
+                } else {
+                    SummaryError::RequestFailed(format!("{} API error {}: {}", provider_name, status, body))
+                });
+            }
+            Err(e) if (e.is_connect() || e.is_timeout()) && attempt < MAX_ATTEMPTS => {
+                format!("{} unreachable: {}", provider_name, e)
+            }
+            Err(e) => {
+                return Err(SummaryError::RequestFailed(format!(
+                    "{} API request failed: {}",
+                    provider_name, e
+                )))
+            }
+        };
+
+        warn!(
+            "{} (attempt {}/{}), retrying in {}s",
+            failure,
+            attempt,
+            MAX_ATTEMPTS,
+            delay.as_secs()
+        );
+        match cancellation_token {
+            Some(token) => tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = token.cancelled() => return Err(SummaryError::Cancelled),
+            },
+            None => tokio::time::sleep(delay).await,
+        }
+        delay *= 2;
+        attempt += 1;
+    }
+}
+
+/// Read a streaming response line by line (SSE or NDJSON), calling `on_line` for each
+/// complete line until it returns true or the stream ends
+pub async fn read_stream_lines(
+    response: Response,
+    provider_name: &str,
+    cancellation_token: Option<&CancellationToken>,
+    mut on_line: impl FnMut(&str) -> bool + Send,
+) -> std::result::Result<(), SummaryError> {
+    // Bytes, not text: a multi-byte character can be split across network chunks
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+    loop {
+        let next = match cancellation_token {
+            Some(token) => tokio::select! {
+                next = stream.next() => next,
+                _ = token.cancelled() => return Err(SummaryError::Cancelled),
+            },
+            None => stream.next().await,
+        };
+        let bytes = match next {
+            Some(Ok(bytes)) => bytes,
+            Some(Err(e)) => {
+                return Err(SummaryError::RequestFailed(format!(
+                    "{} stream interrupted: {}",
+                    provider_name, e
+                )))
+            }
+            None => break,
+        };
+        buffer.extend_from_slice(&bytes);
+
+        // Handle every complete line; keep a partial trailing line for the next read
+        while let Some(newline) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=newline).collect();
+            if on_line(String::from_utf8_lossy(&line).trim_end()) {
+                return Ok(());
+            }
+        }
+    }
+    let rest = String::from_utf8_lossy(&buffer);
+    if !rest.trim().is_empty() {
+        on_line(rest.trim_end());
+    }
+    Ok(())
+}
+
+// ============================================================================
+// LLM CLIENT ADAPTER
+// ============================================================================
+
+/// SummaryProvider over the multi-provider `llm_client::generate_summary`
+pub struct LlmClientProvider {
+    client: Client,
+    provider: LLMProvider,
+    model_name: String,
+    connection: LlmConnection,
+    app_data_dir: Option<PathBuf>,
+    context_tokens: Option<usize>,
+}
+
+impl LlmClientProvider {
+    pub fn new(
+        provider: LLMProvider,
+        model_name: String,
+        connection: LlmConnection,
+        app_data_dir: Option<PathBuf>,
+        context_tokens: Option<usize>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            provider,
+            model_name,
+            connection,
+            app_data_dir,
+            context_tokens,
+        }
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for LlmClientProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        generate_summary(
+            &self.client,
+            &self.provider,
+            &self.model_name,
+            &self.connection.api_key,
+            system_prompt,
+            user_prompt,
+            self.connection.ollama_endpoint.as_deref(),
+            self.connection.custom_openai_endpoint.as_deref(),
+            self.connection.max_tokens,
+            self.connection.temperature,
+            self.connection.top_p,
+            self.app_data_dir.as_ref(),
+            cancellation_token,
+        )
+        .await
+        .map_err(|e| {
+            if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+                SummaryError::Cancelled
+            } else {
+                SummaryError::RequestFailed(e)
+            }
+        })
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        self.context_tokens
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn provider_name(&self) -> &'static str {
+        match self.provider {
+            LLMProvider::OpenAI => "OpenAI",
+            LLMProvider::Claude => "Claude",
+            LLMProvider::Groq => "Groq",
+            LLMProvider::Ollama => "Ollama",
+            LLMProvider::BuiltInAI => "Built-in AI",
+            LLMProvider::OpenRouter => "OpenRouter",
+            LLMProvider::CustomOpenAI => "Custom OpenAI",
+            LLMProvider::Gemini => "Gemini",
+        }
+    }
+}
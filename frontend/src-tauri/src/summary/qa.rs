@@ -0,0 +1,298 @@
+// summary/qa.rs
+//
+// Chat with a meeting: answers questions about one meeting with the configured summary
+// model. Short meetings are sent whole; for longer ones the segments most relevant to the
+// question are retrieved (BM25 over segment text, plus neighbouring segments for context).
+// The model cites segment numbers, which are returned as timestamped citations so the
+// chat panel can jump to the recording.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::batch_budget;
+use crate::summary::processor::{clean_llm_markdown_output, rough_token_count};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+const SYSTEM_PROMPT: &str = "You answer questions about a meeting using only the transcript excerpts \
+provided. Each excerpt line starts with its segment number in brackets, e.g. [12]. Cite the segments \
+supporting each statement by putting their numbers in brackets after it, like [12] or [12][15]. If the \
+excerpts do not contain the answer, say so briefly instead of guessing. Answer concisely in Markdown.";
+
+/// Segments retrieved per question before neighbours are added
+const TOP_SEGMENTS: usize = 8;
+/// Previous chat turns included for follow-up questions
+const HISTORY_TURNS: usize = 6;
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+
+static WORD_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[\p{L}\p{N}]+").unwrap());
+static CITATION_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\s?)\[(\d+)\]").unwrap());
+
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "are", "was", "were", "what", "who", "when", "where", "which", "how", "why", "did",
+    "does", "that", "this", "with", "about", "from", "have", "has", "had", "you", "they", "them", "there",
+    "their", "our", "not", "but", "any", "can", "could", "would", "should", "will", "said", "say", "tell",
+];
+
+/// A previous message in the chat panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatTurn {
+    /// "user" or "assistant"
+    pub role: String,
+    pub content: String,
+}
+
+/// A transcript segment an answer refers to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Citation {
+    pub segment_id: String,
+    pub audio_start_time: Option<f64>,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingAnswer {
+    /// Markdown answer; segment references are rewritten to their timestamps
+    pub answer: String,
+    /// Cited segments in recording order
+    pub citations: Vec<Citation>,
+}
+
+fn terms(text: &str) -> Vec<String> {
+    WORD_REGEX
+        .find_iter(&text.to_lowercase())
+        .map(|word| word.as_str().to_string())
+        .filter(|word| word.chars().count() > 2 && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Segment indices relevant to `question`, most relevant first (BM25; segments without any
+/// query term are left out)
+pub(crate) fn rank_segments(question: &str, texts: &[&str]) -> Vec<usize> {
+    let query: BTreeSet<String> = terms(question).into_iter().collect();
+    if query.is_empty() || texts.is_empty() {
+        return Vec::new();
+    }
+
+    let documents: Vec<Vec<String>> = texts.iter().map(|text| terms(text)).collect();
+    let average_length = documents.iter().map(Vec::len).sum::<usize>() as f64 / documents.len() as f64;
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for document in &documents {
+        let unique: BTreeSet<&str> = document.iter().map(String::as_str).collect();
+        for term in unique.into_iter().filter(|term| query.contains(*term)) {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+
+    let count = documents.len() as f64;
+    let mut scored: Vec<(usize, f64)> = documents
+        .iter()
+        .enumerate()
+        .filter_map(|(index, document)| {
+            let length_norm = 1.0 - BM25_B + BM25_B * document.len() as f64 / average_length.max(1.0);
+            let score: f64 = query
+                .iter()
+                .filter_map(|term| {
+                    let frequency = document.iter().filter(|word| *word == term).count() as f64;
+                    let df = *document_frequency.get(term.as_str())? as f64;
+                    let idf = ((count - df + 0.5) / (df + 0.5) + 1.0).ln();
+                    Some(idf * frequency * (BM25_K1 + 1.0) / (frequency + BM25_K1 * length_norm))
+                })
+                .sum();
+            (score > 0.0).then_some((index, score))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    scored.into_iter().map(|(index, _)| index).collect()
+}
+
+/// Segments to show the model: all of them when they fit the budget, otherwise the top
+/// ranked ones with a neighbour on each side, in recording order
+fn context_segments(question: &str, lines: &[String], texts: &[&str], budget: usize) -> Vec<usize> {
+    if lines.iter().map(|line| rough_token_count(line)).sum::<usize>() <= budget {
+        return (0..lines.len()).collect();
+    }
+
+    let mut selected = BTreeSet::new();
+    let mut tokens = 0;
+    for index in rank_segments(question, texts).into_iter().take(TOP_SEGMENTS) {
+        let neighbours = index.saturating_sub(1)..(index + 2).min(lines.len());
+        for (neighbour, line) in lines.iter().enumerate().take(neighbours.end).skip(neighbours.start) {
+            if selected.contains(&neighbour) {
+                continue;
+            }
+            let line_tokens = rough_token_count(line);
+            if tokens + line_tokens > budget {
+                return selected.into_iter().collect();
+            }
+            tokens += line_tokens;
+            selected.insert(neighbour);
+        }
+    }
+    selected.into_iter().collect()
+}
+
+/// Segment numbers cited in an answer that were part of the context
+fn cited_segments(answer: &str, context: &[usize]) -> Vec<usize> {
+    let cited: BTreeSet<usize> = CITATION_REGEX
+        .captures_iter(answer)
+        .filter_map(|captures| captures[2].parse().ok())
+        .filter(|index| context.contains(index))
+        .collect();
+    cited.into_iter().collect()
+}
+
+/// Replace "[12]" references with the segment's timestamp, dropping ones the model made up
+fn timestamp_citations(answer: &str, context: &[usize], start_times: &[Option<f64>]) -> String {
+    CITATION_REGEX
+        .replace_all(answer, |captures: &regex::Captures| {
+            match captures[2].parse::<usize>().ok().filter(|index| context.contains(index)) {
+                Some(index) => match start_times[index] {
+                    Some(start) => format!("{}[{}]", &captures[1], crate::utils::format_timestamp(start)),
+                    None => String::new(),
+                },
+                None => String::new(),
+            }
+        })
+        .into_owned()
+}
+
+fn history_prompt(history: &[ChatTurn]) -> String {
+    let recent = &history[history.len().saturating_sub(HISTORY_TURNS)..];
+    if recent.is_empty() {
+        return String::new();
+    }
+    let mut prompt = String::from("<conversation>\n");
+    for turn in recent {
+        let role = if turn.role == "assistant" { "Assistant" } else { "User" };
+        prompt.push_str(&format!("{}: {}\n", role, turn.content.trim()));
+    }
+    prompt.push_str("</conversation>\n\n");
+    prompt
+}
+
+/// Answer a question about a meeting, citing the transcript segments the answer is based on
+#[command]
+pub async fn ask_meeting_question<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    question: String,
+    history: Option<Vec<ChatTurn>>,
+) -> Result<MeetingAnswer, String> {
+    if question.trim().is_empty() {
+        return Err("Question cannot be empty".to_string());
+    }
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Err("This meeting has no transcript to ask about".to_string());
+    }
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "question");
+
+    let lines: Vec<String> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, (_, text, start, speaker))| {
+            let time = start.map(crate::utils::format_timestamp).unwrap_or_default();
+            match speaker {
+                Some(speaker) => format!("[{}] ({}) {}: {}", i, time, speaker, text.trim()),
+                None => format!("[{}] ({}) {}", i, time, text.trim()),
+            }
+        })
+        .collect();
+    let texts: Vec<&str> = segments.iter().map(|(_, text, _, _)| text.as_str()).collect();
+    let history = history.unwrap_or_default();
+    let budget = batch_budget(provider.as_ref()).saturating_sub(rough_token_count(&history_prompt(&history)));
+    let context = context_segments(&question, &lines, &texts, budget);
+
+    let excerpts = if context.is_empty() {
+        "(no part of the transcript matches the question)".to_string()
+    } else {
+        context.iter().map(|&i| lines[i].as_str()).collect::<Vec<_>>().join("\n")
+    };
+    let user_prompt = format!(
+        "{}<transcript_excerpts>\n{}\n</transcript_excerpts>\n\nQuestion: {}",
+        history_prompt(&history),
+        excerpts,
+        question.trim()
+    );
+    let reply = provider
+        .complete(SYSTEM_PROMPT, &user_prompt, None)
+        .await
+        .map_err(|e| format!("Failed to answer question: {}", e))?;
+    let reply = clean_llm_markdown_output(&reply);
+
+    let start_times: Vec<Option<f64>> = segments.iter().map(|(_, _, start, _)| *start).collect();
+    let citations = cited_segments(&reply, &context)
+        .into_iter()
+        .map(|i| {
+            let (id, text, start, speaker) = &segments[i];
+            Citation {
+                segment_id: id.clone(),
+                audio_start_time: *start,
+                speaker: speaker.clone(),
+                text: text.clone(),
+            }
+        })
+        .collect::<Vec<_>>();
+    info!(
+        "Answered question on {} from {} of {} segment(s), {} citation(s)",
+        meeting_id,
+        context.len(),
+        segments.len(),
+        citations.len()
+    );
+
+    Ok(MeetingAnswer {
+        answer: timestamp_citations(&reply, &context, &start_times),
+        citations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_segments() {
+        let texts = [
+            "Good morning everyone",
+            "The budget for the launch is fifty thousand",
+            "We could move the launch to March",
+            "Budget approval needs the CFO, budget is tight",
+        ];
+        let ranked = rank_segments("What did we say about the budget?", &texts);
+        assert_eq!(ranked, vec![3, 1]);
+        assert!(rank_segments("the and what", &texts).is_empty());
+
+        let lines: Vec<String> = texts.iter().enumerate().map(|(i, t)| format!("[{}] {}", i, t)).collect();
+        // Everything fits: the whole transcript is used
+        assert_eq!(context_segments("launch date", &lines, &texts, 10_000), vec![0, 1, 2, 3]);
+        // Only a few lines fit: the best match and its neighbours
+        assert_eq!(context_segments("march", &lines, &texts, 50), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_citations() {
+        let answer = "The launch moves to March [2][3], pending approval [9].";
+        let context = vec![1, 2, 3];
+        assert_eq!(cited_segments(answer, &context), vec![2, 3]);
+        assert_eq!(
+            timestamp_citations(answer, &context, &[Some(0.0), Some(5.0), Some(65.0), None]),
+            "The launch moves to March [00:01:05], pending approval."
+        );
+    }
+}
@@ -0,0 +1,477 @@
+// summary/redaction.rs
+//
+// Optional PII redaction for cloud summary models. When enabled, every prompt sent to a
+// cloud provider has email addresses, phone numbers, IBANs, card and account numbers and
+// people's names replaced by placeholders such as [EMAIL_1]. Names come from the voice
+// profiles and the people in the glossary, plus names after a title ("Dr. Smith"). The
+// placeholder mapping never leaves the machine: replies are restored before anything else
+// sees them, and the minutes keep the originals unless the user prefers the placeholders,
+// in which case the mapping is stored per meeting so they can be put back later.
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::repositories::glossary::GlossaryRepository;
+use crate::database::repositories::redaction_mapping::RedactionMappingsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::repositories::voice_profile::VoiceProfilesRepository;
+use crate::state::AppState;
+use crate::summary::follow_up::stored_minutes;
+use crate::summary::glossary::GlossaryKind;
+use crate::summary::provider::{SummaryError, SummaryProvider, TokenCallback};
+use crate::summary::versions;
+
+static EMAIL: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)\b[A-Z0-9._%+-]+@[A-Z0-9.-]+\.[A-Z]{2,}\b").unwrap());
+static IBAN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b[A-Z]{2}\d{2}(?:\s?[A-Z0-9]{4}){2,7}(?:\s?[A-Z0-9]{1,3})?\b").unwrap());
+static CARD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\d(?:[\s-]?\d){12,18}\b").unwrap());
+static PHONE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{1,4}\)[\s.-]?)?\d{2,4}(?:[\s.-]\d{2,4}){1,4}\b").unwrap()
+});
+static DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\d{1,4}[-./]\d{1,2}[-./]\d{1,4}$").unwrap());
+static ACCOUNT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\d{8,}\b").unwrap());
+static TITLED_NAME: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:Mr|Mrs|Ms|Dr|Prof)\.?\s+\p{Lu}[\p{L}'-]+(?:\s+\p{Lu}[\p{L}'-]+)?").unwrap()
+});
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[(?:PERSON|EMAIL|PHONE|ACCOUNT)_\d+\]").unwrap());
+
+/// Longest placeholder held back while streaming, e.g. "[ACCOUNT_1234]"
+const MAX_PLACEHOLDER_CHARS: usize = 16;
+
+/// PII redaction settings, stored as JSON in settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RedactionSettings {
+    /// Redact prompts sent to cloud models
+    pub enabled: bool,
+    /// Put the originals back into the minutes (otherwise they keep the placeholders)
+    #[serde(default = "default_restore")]
+    pub restore_originals: bool,
+}
+
+fn default_restore() -> bool {
+    true
+}
+
+/// Local models never send the transcript anywhere
+fn is_local(provider: &dyn SummaryProvider) -> bool {
+    matches!(provider.provider_name(), "Ollama" | "Built-in AI")
+}
+
+/// Luhn checksum of a card number, so random digit runs aren't taken for cards
+fn luhn_valid(number: &str) -> bool {
+    let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| match i % 2 {
+            1 if digit * 2 > 9 => digit * 2 - 9,
+            1 => digit * 2,
+            _ => digit,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[derive(Debug, Default)]
+struct RedactionMap {
+    placeholders: HashMap<String, String>,
+    originals: HashMap<String, String>,
+    counts: HashMap<&'static str, usize>,
+    /// Placeholders handed out by this redactor, in order
+    added: Vec<String>,
+}
+
+impl RedactionMap {
+    fn placeholder(&mut self, kind: &'static str, original: &str) -> String {
+        if let Some(placeholder) = self.placeholders.get(original) {
+            return placeholder.clone();
+        }
+        let count = self.counts.entry(kind).or_insert(0);
+        *count += 1;
+        let placeholder = format!("[{}_{}]", kind, count);
+        self.placeholders.insert(original.to_string(), placeholder.clone());
+        self.originals.insert(placeholder.clone(), original.to_string());
+        self.added.push(placeholder.clone());
+        placeholder
+    }
+}
+
+/// Replaces personal data with placeholders and back, keeping the mapping in memory
+pub struct Redactor {
+    names: Option<Regex>,
+    map: Mutex<RedactionMap>,
+}
+
+impl Redactor {
+    /// `names` are people to redact wherever they appear; `existing` are (placeholder,
+    /// original) pairs from earlier runs, so the same person keeps the same placeholder
+    pub fn new(names: &[String], existing: &[(String, String)]) -> Self {
+        let mut names: Vec<&str> = names.iter().map(|name| name.trim()).filter(|name| name.chars().count() >= 3).collect();
+        // Longest first, so "Ana Ruiz" wins over "Ana"
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        names.dedup();
+        let names = if names.is_empty() {
+            None
+        } else {
+            let alternatives: Vec<String> = names.iter().map(|name| regex::escape(name)).collect();
+            Regex::new(&format!(r"\b(?:{})\b", alternatives.join("|"))).ok()
+        };
+
+        let mut map = RedactionMap::default();
+        for (placeholder, original) in existing {
+            let kind = ["PERSON", "EMAIL", "PHONE", "ACCOUNT"]
+                .into_iter()
+                .find(|kind| placeholder.starts_with(&format!("[{}_", kind)));
+            let number = placeholder
+                .trim_end_matches(']')
+                .rsplit('_')
+                .next()
+                .and_then(|n| n.parse::<usize>().ok());
+            if let (Some(kind), Some(number)) = (kind, number) {
+                let count = map.counts.entry(kind).or_insert(0);
+                *count = (*count).max(number);
+                map.placeholders.insert(original.clone(), placeholder.clone());
+                map.originals.insert(placeholder.clone(), original.clone());
+            }
+        }
+        Redactor {
+            names,
+            map: Mutex::new(map),
+        }
+    }
+
+    pub fn redact(&self, text: &str) -> String {
+        let mut map = self.map.lock().unwrap();
+        let mut replace = |text: &str, pattern: &Regex, kind: &'static str, keep: &dyn Fn(&str) -> bool| -> String {
+            pattern
+                .replace_all(text, |caps: &Captures| {
+                    let found = &caps[0];
+                    if keep(found) {
+                        map.placeholder(kind, found)
+                    } else {
+                        found.to_string()
+                    }
+                })
+                .into_owned()
+        };
+
+        let text = replace(text, &EMAIL, "EMAIL", &|_| true);
+        let text = replace(&text, &IBAN, "ACCOUNT", &|_| true);
+        let text = replace(&text, &CARD, "ACCOUNT", &luhn_valid);
+        let text = replace(&text, &PHONE, "PHONE", &|found| {
+            let digits = found.chars().filter(char::is_ascii_digit).count();
+            (8..=15).contains(&digits) && !DATE.is_match(found)
+        });
+        let text = replace(&text, &ACCOUNT, "ACCOUNT", &|_| true);
+        let text = match &self.names {
+            Some(names) => replace(&text, names, "PERSON", &|_| true),
+            None => text,
+        };
+        replace(&text, &TITLED_NAME, "PERSON", &|_| true)
+    }
+
+    /// Put the originals back for every placeholder this redactor knows
+    pub fn restore(&self, text: &str) -> String {
+        let map = self.map.lock().unwrap();
+        restore_with(text, &map.originals)
+    }
+
+    /// (placeholder, original) pairs handed out by this redactor
+    pub fn new_mappings(&self) -> Vec<(String, String)> {
+        let map = self.map.lock().unwrap();
+        map.added
+            .iter()
+            .filter_map(|placeholder| {
+                map.originals
+                    .get(placeholder)
+                    .map(|original| (placeholder.clone(), original.clone()))
+            })
+            .collect()
+    }
+
+    pub fn redaction_count(&self) -> usize {
+        self.map.lock().unwrap().added.len()
+    }
+}
+
+fn restore_with(text: &str, originals: &HashMap<String, String>) -> String {
+    PLACEHOLDER
+        .replace_all(text, |caps: &Captures| {
+            originals
+                .get(&caps[0])
+                .cloned()
+                .unwrap_or_else(|| caps[0].to_string())
+        })
+        .into_owned()
+}
+
+/// Restores placeholders in streamed text, holding back a placeholder split across deltas
+struct StreamRestorer {
+    redactor: Arc<Redactor>,
+    pending: Mutex<String>,
+}
+
+impl StreamRestorer {
+    /// Text ready to show after receiving `delta`
+    fn push(&self, delta: &str) -> String {
+        let mut pending = self.pending.lock().unwrap();
+        pending.push_str(delta);
+        let split = match pending.rfind('[') {
+            Some(open) if !pending[open..].contains(']') && pending.len() - open <= MAX_PLACEHOLDER_CHARS => open,
+            _ => pending.len(),
+        };
+        let ready = self.redactor.restore(&pending[..split]);
+        pending.replace_range(..split, "");
+        ready
+    }
+
+    fn finish(&self) -> String {
+        let mut pending = self.pending.lock().unwrap();
+        let rest = self.redactor.restore(&pending);
+        pending.clear();
+        rest
+    }
+}
+
+/// SummaryProvider that redacts prompts before they leave the machine and restores replies
+pub struct RedactingProvider {
+    inner: Box<dyn SummaryProvider>,
+    redactor: Arc<Redactor>,
+}
+
+#[async_trait]
+impl SummaryProvider for RedactingProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let reply = self
+            .inner
+            .complete(
+                &self.redactor.redact(system_prompt),
+                &self.redactor.redact(user_prompt),
+                cancellation_token,
+            )
+            .await?;
+        Ok(self.redactor.restore(&reply))
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let restorer = Arc::new(StreamRestorer {
+            redactor: self.redactor.clone(),
+            pending: Mutex::new(String::new()),
+        });
+        let forward = on_token.clone();
+        let stream_restorer = restorer.clone();
+        let restoring: TokenCallback = Arc::new(move |delta: &str| {
+            let ready = stream_restorer.push(delta);
+            if !ready.is_empty() {
+                forward(&ready);
+            }
+        });
+        let reply = self
+            .inner
+            .complete_streaming(
+                &self.redactor.redact(system_prompt),
+                &self.redactor.redact(user_prompt),
+                &restoring,
+                cancellation_token,
+            )
+            .await?;
+        let rest = restorer.finish();
+        if !rest.is_empty() {
+            on_token(&rest);
+        }
+        Ok(self.redactor.restore(&reply))
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        self.inner.context_tokens()
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+
+    fn provider_name(&self) -> &'static str {
+        self.inner.provider_name()
+    }
+}
+
+/// Wrap a cloud provider in redaction when it is enabled in settings. Returns the
+/// redactor too, for storing the mapping and redacting the minutes; `meeting_id` reuses
+/// the placeholders of earlier runs for that meeting.
+pub async fn protect(
+    pool: &SqlitePool,
+    provider: Box<dyn SummaryProvider>,
+    meeting_id: Option<&str>,
+) -> Result<(Box<dyn SummaryProvider>, Option<Arc<Redactor>>), String> {
+    let settings = SettingsRepository::get_redaction_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load redaction settings: {}", e))?
+        .unwrap_or_default();
+    if !settings.enabled || is_local(provider.as_ref()) {
+        return Ok((provider, None));
+    }
+
+    let mut names: Vec<String> = VoiceProfilesRepository::list(pool)
+        .await
+        .map_err(|e| format!("Failed to load voice profiles: {}", e))?
+        .into_iter()
+        .map(|profile| profile.name)
+        .collect();
+    let glossary = GlossaryRepository::list(pool)
+        .await
+        .map_err(|e| format!("Failed to load glossary: {}", e))?;
+    names.extend(
+        glossary
+            .into_iter()
+            .filter(|entry| GlossaryKind::parse(&entry.kind) == Some(GlossaryKind::Person))
+            .map(|entry| entry.term),
+    );
+    let existing = match meeting_id {
+        Some(meeting_id) => RedactionMappingsRepository::list_for_meeting(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load redaction mappings: {}", e))?,
+        None => Vec::new(),
+    };
+
+    info!("Redacting personal data before sending prompts to {}", provider.provider_name());
+    let redactor = Arc::new(Redactor::new(&names, &existing));
+    let provider: Box<dyn SummaryProvider> = Box::new(RedactingProvider {
+        inner: provider,
+        redactor: redactor.clone(),
+    });
+    Ok((provider, Some(redactor)))
+}
+
+/// Store the placeholders used for a meeting and, unless the user wants the originals
+/// back, redact the minutes with them
+pub async fn finish_minutes(pool: &SqlitePool, meeting_id: &str, redactor: &Redactor, markdown: String) -> String {
+    let mappings = redactor.new_mappings();
+    if let Err(e) = RedactionMappingsRepository::save_for_meeting(pool, meeting_id, &mappings).await {
+        warn!("Failed to save redaction mappings for {}: {}", meeting_id, e);
+    }
+    info!("Redacted {} item(s) of personal data for {}", redactor.redaction_count(), meeting_id);
+
+    let restore_originals = match SettingsRepository::get_redaction_settings(pool).await {
+        Ok(settings) => settings.unwrap_or_default().restore_originals,
+        Err(e) => {
+            warn!("Failed to load redaction settings, keeping originals: {}", e);
+            true
+        }
+    };
+    if restore_originals {
+        markdown
+    } else {
+        redactor.redact(&markdown)
+    }
+}
+
+#[command]
+pub async fn get_redaction_settings<R: Runtime>(app: AppHandle<R>) -> Result<RedactionSettings, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_redaction_settings(state.db_manager.pool())
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load redaction settings: {}", e))
+}
+
+#[command]
+pub async fn save_redaction_settings<R: Runtime>(app: AppHandle<R>, settings: RedactionSettings) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::save_redaction_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save redaction settings: {}", e))
+}
+
+/// Put the original names, numbers and addresses back into minutes that were saved with
+/// placeholders
+#[command]
+pub async fn restore_redacted_summary<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let originals: HashMap<String, String> = RedactionMappingsRepository::list_for_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load redaction mappings: {}", e))?
+        .into_iter()
+        .collect();
+    let process = SummaryProcessesRepository::get_summary_data(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?;
+    let markdown = stored_minutes(process.as_ref().and_then(|p| p.result.as_deref()))
+        .ok_or_else(|| "The meeting has no summary".to_string())?;
+
+    let restored = restore_with(&markdown, &originals);
+    if restored == markdown {
+        return Ok(());
+    }
+    versions::snapshot_current(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to save the current summary: {}", e))?;
+    SummaryProcessesRepository::update_meeting_summary(pool, &meeting_id, &serde_json::json!({ "markdown": restored }))
+        .await
+        .map_err(|e| format!("Failed to save summary: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_and_restore() {
+        let redactor = Redactor::new(&["Ana Ruiz".to_string(), "Ana".to_string()], &[]);
+        let text = "Ana Ruiz (ana.ruiz@example.com, +34 612 345 678) said Dr. Smith will pay \
+                    into ES91 2100 0418 4502 0005 1332 with card 4111 1111 1111 1111 by 12-05-2024. \
+                    Ana agreed; ticket 1234 is open.";
+        let redacted = redactor.redact(text);
+        assert_eq!(
+            redacted,
+            "[PERSON_1] ([EMAIL_1], [PHONE_1]) said [PERSON_3] will pay into [ACCOUNT_1] with card \
+             [ACCOUNT_2] by 12-05-2024. [PERSON_2] agreed; ticket 1234 is open."
+        );
+        assert_eq!(redactor.restore(&redacted), text);
+        // The same value keeps its placeholder
+        assert_eq!(redactor.redact("Mail ana.ruiz@example.com"), "Mail [EMAIL_1]");
+
+        // Earlier placeholders carry over and numbering continues after them
+        let next = Redactor::new(&[], &redactor.new_mappings());
+        assert_eq!(next.redact("Dr. Smith and Dr. Jones"), "[PERSON_3] and [PERSON_4]");
+        assert_eq!(next.new_mappings(), vec![("[PERSON_4]".to_string(), "Dr. Jones".to_string())]);
+    }
+
+    #[test]
+    fn test_stream_restorer() {
+        let redactor = Arc::new(Redactor::new(&["Ana Ruiz".to_string()], &[]));
+        redactor.redact("Ana Ruiz");
+        let restorer = StreamRestorer {
+            redactor,
+            pending: Mutex::new(String::new()),
+        };
+        let mut out = String::new();
+        for delta in ["Owner: [PER", "SON_1] and [link", "] [x"] {
+            out.push_str(&restorer.push(delta));
+        }
+        out.push_str(&restorer.finish());
+        assert_eq!(out, "Owner: Ana Ruiz and [link] [x");
+    }
+}
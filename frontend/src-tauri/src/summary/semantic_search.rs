@@ -0,0 +1,159 @@
+// summary/semantic_search.rs
+//
+// Search all meetings by meaning rather than exact words ("when did we discuss the pricing
+// change?"). The query is embedded with the configured embedding model and compared with
+// every meeting's vector index; indexes that are missing or out of date are refreshed
+// first, so meetings recorded before indexing was turned on are searchable too.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::embeddings::configured_embedding_provider;
+use crate::summary::vector_index::{index_dir, update_meeting_index};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SemanticSearchResult {
+    pub meeting_id: String,
+    pub meeting_title: String,
+    /// Meeting creation time (RFC 3339)
+    pub meeting_date: String,
+    pub segment_id: String,
+    pub text: String,
+    pub speaker: Option<String>,
+    pub audio_start_time: Option<f64>,
+    /// Cosine similarity to the query (higher is closer)
+    pub score: f32,
+}
+
+/// Keep the `limit` best (meeting index, segment id, score) hits across meetings, best first
+fn best_hits(mut hits: Vec<(usize, String, f32)>, limit: usize) -> Vec<(usize, String, f32)> {
+    hits.retain(|(_, _, score)| *score > 0.0);
+    hits.sort_by(|a, b| b.2.total_cmp(&a.2));
+    hits.truncate(limit);
+    hits
+}
+
+/// Search every meeting's transcript by meaning, returning the closest segments with
+/// their meeting, speaker and timestamp
+#[command]
+pub async fn semantic_search_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SemanticSearchResult>, String> {
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let provider = configured_embedding_provider(pool)
+        .await?
+        .ok_or_else(|| "Semantic search needs an embedding model; choose one in settings".to_string())?;
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+    let dir = index_dir(&app_data_dir);
+
+    let query_vector = provider
+        .embed(&[query.trim().to_string()])
+        .await
+        .map_err(|e| format!("Failed to embed query: {}", e))?
+        .remove(0);
+    let meetings = MeetingsRepository::get_meetings(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+
+    let mut hits = Vec::new();
+    for (meeting_index, meeting) in meetings.iter().enumerate() {
+        let (index, embedded) = match update_meeting_index(pool, &dir, &meeting.id, provider.as_ref()).await {
+            Ok(updated) => updated,
+            Err(e) => {
+                // One unreachable meeting shouldn't hide results from the others
+                warn!("Skipping meeting {} in semantic search: {}", meeting.id, e);
+                continue;
+            }
+        };
+        if embedded > 0 {
+            let _ = app.emit(
+                "semantic-index-progress",
+                serde_json::json!({
+                    "meeting_id": meeting.id,
+                    "completed_meetings": meeting_index + 1,
+                    "total_meetings": meetings.len(),
+                }),
+            );
+        }
+        hits.extend(
+            index
+                .search(&query_vector, limit)
+                .into_iter()
+                .map(|(entry, score)| (meeting_index, index.entries[entry].segment_id.clone(), score)),
+        );
+    }
+    let hits = best_hits(hits, limit);
+
+    let ids: Vec<String> = hits.iter().map(|(_, id, _)| id.clone()).collect();
+    let segments: HashMap<String, (String, Option<f64>, Option<String>)> =
+        TranscriptsRepository::get_segments_by_ids(pool, &ids)
+            .await
+            .map_err(|e| format!("Failed to load segments: {}", e))?
+            .into_iter()
+            .map(|(id, _, text, start, speaker)| (id, (text, start, speaker)))
+            .collect();
+
+    let results: Vec<SemanticSearchResult> = hits
+        .into_iter()
+        .filter_map(|(meeting_index, segment_id, score)| {
+            let meeting = &meetings[meeting_index];
+            let (text, start, speaker) = segments.get(&segment_id)?.clone();
+            Some(SemanticSearchResult {
+                meeting_id: meeting.id.clone(),
+                meeting_title: meeting.title.clone(),
+                meeting_date: meeting.created_at.0.to_rfc3339(),
+                segment_id,
+                text,
+                speaker,
+                audio_start_time: start,
+                score,
+            })
+        })
+        .collect();
+    info!(
+        "Semantic search over {} meeting(s) returned {} result(s)",
+        meetings.len(),
+        results.len()
+    );
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_hits_across_meetings() {
+        let hits = vec![
+            (0, "a".to_string(), 0.42),
+            (1, "b".to_string(), 0.81),
+            (0, "c".to_string(), -0.1),
+            (2, "d".to_string(), 0.63),
+        ];
+        let best = best_hits(hits, 2);
+        assert_eq!(
+            best.iter().map(|(m, id, _)| (*m, id.as_str())).collect::<Vec<_>>(),
+            vec![(1, "b"), (2, "d")]
+        );
+        assert!(best_hits(vec![(0, "c".to_string(), 0.0)], 5).is_empty());
+    }
+}
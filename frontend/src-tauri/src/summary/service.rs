@@ -1,14 +1,35 @@
 use crate::database::repositories::{
     meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    transcript::TranscriptsRepository,
 };
+use crate::audio::transcription::language_id;
 use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
+use crate::summary::agenda;
+use crate::summary::chapters;
+use crate::summary::claude_provider::ClaudeSummaryProvider;
+use crate::summary::gemini_provider::GeminiSummaryProvider;
+use crate::summary::glossary;
+use crate::summary::groq_provider::{preferred_api_key, GroqSummaryProvider};
+use crate::summary::highlights;
+use crate::summary::llama_provider::LlamaSummaryProvider;
+use crate::summary::minutes::{generate_minutes, MeetingMinutes};
+use crate::summary::ollama_provider::OllamaSummaryProvider;
+use crate::summary::openai_provider::{compatible_model_context_tokens, OpenAISummaryProvider};
+use crate::summary::outcomes;
+use crate::summary::redaction;
+use crate::summary::provider::{LlmClientProvider, SummaryError, SummaryProvider, TokenCallback};
+use crate::summary::standup;
+use crate::summary::style::SummaryStyle;
+use crate::summary::usage::metered;
+use crate::summary::versions;
+use crate::summary::templates::{self, MinutesTemplate, PromptVariables, PROMPT_TEMPLATE_PREFIX};
 use crate::ollama::metadata::ModelMetadataCache;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
@@ -22,6 +43,24 @@ static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
 static CANCELLATION_REGISTRY: Lazy<Arc<Mutex<HashMap<String, CancellationToken>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+// Context windows assumed for OpenAI-compatible models that aren't recognised by name
+const OPENROUTER_FALLBACK_CONTEXT_TOKENS: usize = 32_768;
+const CUSTOM_FALLBACK_CONTEXT_TOKENS: usize = 8_192;
+// Kept free for the reply and the prompt template on OpenAI-compatible providers
+const COMPATIBLE_RESPONSE_RESERVE_TOKENS: usize = 2_048;
+const COMPATIBLE_PROMPT_OVERHEAD_TOKENS: usize = 1_000;
+
+/// Credentials and endpoint settings for one LLM provider, resolved from the settings table
+#[derive(Debug, Clone, Default)]
+pub struct LlmConnection {
+    pub api_key: String,
+    pub ollama_endpoint: Option<String>,
+    pub custom_openai_endpoint: Option<String>,
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
@@ -49,6 +88,11 @@ impl SummaryService {
         false
     }
 
+    /// Whether a summary is being generated for any meeting
+    pub fn is_generating() -> bool {
+        CANCELLATION_REGISTRY.lock().map_or(false, |registry| !registry.is_empty())
+    }
+
     /// Cleans up the cancellation token after processing completes
     fn cleanup_cancellation_token(meeting_id: &str) {
         if let Ok(mut registry) = CANCELLATION_REGISTRY.lock() {
@@ -58,71 +102,52 @@ impl SummaryService {
         }
     }
 
-    /// Processes transcript in the background and generates summary
-    ///
-    /// This function is designed to be spawned as an async task and does not block
-    /// the main thread. It updates the database with progress and results.
+    /// Resolves the API key and endpoint settings needed to call the given LLM provider
     ///
     /// # Arguments
-    /// * `_app` - Tauri app handle (for future use)
     /// * `pool` - SQLx connection pool
-    /// * `meeting_id` - Unique identifier for the meeting
-    /// * `text` - Full transcript text
-    /// * `model_provider` - LLM provider name (e.g., "ollama", "openai")
-    /// * `model_name` - Specific model (e.g., "gpt-4", "llama3.2:latest")
-    /// * `custom_prompt` - Optional user-provided context
-    /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
-    pub async fn process_transcript_background<R: tauri::Runtime>(
-        _app: AppHandle<R>,
-        pool: SqlitePool,
-        meeting_id: String,
-        text: String,
-        model_provider: String,
-        model_name: String,
-        custom_prompt: String,
-        template_id: String,
-    ) {
-        let start_time = Instant::now();
-        info!(
-            "Starting background processing for meeting_id: {}",
-            meeting_id
-        );
-
-        // Register cancellation token for this meeting
-        let cancellation_token = Self::register_cancellation_token(&meeting_id);
-
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
-            Err(e) => {
-                Self::update_process_failed(&pool, &meeting_id, &e).await;
-                return;
-            }
-        };
-
+    /// * `provider` - Parsed LLM provider
+    /// * `model_provider` - Provider name as stored in settings (used for API key lookup)
+    pub async fn resolve_llm_connection(
+        pool: &SqlitePool,
+        provider: &LLMProvider,
+        model_provider: &str,
+    ) -> Result<LlmConnection, String> {
         // Validate and setup api_key, Flexible for Ollama, BuiltInAI, and CustomOpenAI
-        let api_key = if provider == LLMProvider::Ollama || provider == LLMProvider::BuiltInAI || provider == LLMProvider::CustomOpenAI {
+        let api_key = if *provider == LLMProvider::Ollama || *provider == LLMProvider::BuiltInAI || *provider == LLMProvider::CustomOpenAI {
             // These providers don't require API keys from the standard database column
             String::new()
+        } else if *provider == LLMProvider::Groq {
+            // The Groq key saved for cloud transcription works for chat models too
+            let summary_key = SettingsRepository::get_api_key(pool, model_provider)
+                .await
+                .map_err(|e| format!("Failed to retrieve API key for {}: {}", model_provider, e))?;
+            let transcript_key = match SettingsRepository::get_transcript_api_key(pool, "groq").await {
+                Ok(key) => key,
+                Err(e) => {
+                    warn!("Failed to read transcription Groq key: {}", e);
+                    None
+                }
+            };
+            preferred_api_key(summary_key, transcript_key)
+                .ok_or_else(|| format!("API key not found for {}", model_provider))?
         } else {
-            match SettingsRepository::get_api_key(&pool, &model_provider).await {
+            match SettingsRepository::get_api_key(pool, model_provider).await {
                 Ok(Some(key)) if !key.is_empty() => key,
                 Ok(None) | Ok(Some(_)) => {
-                    let err_msg = format!("API key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
+                    let err_msg = format!("API key not found for {}", model_provider);
+                    return Err(err_msg);
                 }
                 Err(e) => {
-                    let err_msg = format!("Failed to retrieve API key for {}: {}", &model_provider, e);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
+                    let err_msg = format!("Failed to retrieve API key for {}: {}", model_provider, e);
+                    return Err(err_msg);
                 }
             }
         };
 
         // Get Ollama endpoint if provider is Ollama
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
+        let ollama_endpoint = if *provider == LLMProvider::Ollama {
+            match SettingsRepository::get_model_config(pool).await {
                 Ok(Some(config)) => config.ollama_endpoint,
                 Ok(None) => None,
                 Err(e) => {
@@ -136,8 +161,8 @@ impl SummaryService {
 
         // Get CustomOpenAI config if provider is CustomOpenAI
         let (custom_openai_endpoint, custom_openai_api_key, custom_openai_max_tokens, custom_openai_temperature, custom_openai_top_p) =
-            if provider == LLMProvider::CustomOpenAI {
-                match SettingsRepository::get_custom_openai_config(&pool).await {
+            if *provider == LLMProvider::CustomOpenAI {
+                match SettingsRepository::get_custom_openai_config(pool).await {
                     Ok(Some(config)) => {
                         info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
                         (
@@ -150,13 +175,11 @@ impl SummaryService {
                     }
                     Ok(None) => {
                         let err_msg = "Custom OpenAI provider selected but no configuration found";
-                        Self::update_process_failed(&pool, &meeting_id, err_msg).await;
-                        return;
+                        return Err(err_msg.to_string());
                     }
                     Err(e) => {
                         let err_msg = format!("Failed to retrieve custom OpenAI config: {}", e);
-                        Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                        return;
+                        return Err(err_msg);
                     }
                 }
             } else {
@@ -164,81 +187,498 @@ impl SummaryService {
             };
 
         // For CustomOpenAI, use its API key (if any) instead of the empty string
-        let final_api_key = if provider == LLMProvider::CustomOpenAI {
+        let api_key = if *provider == LLMProvider::CustomOpenAI {
             custom_openai_api_key.unwrap_or_default()
         } else {
             api_key
         };
 
-        // Dynamically fetch context size based on provider and model
-        let token_threshold = if provider == LLMProvider::Ollama {
-            match METADATA_CACHE.get_or_fetch(&model_name, ollama_endpoint.as_deref()).await {
-                Ok(metadata) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = metadata.context_size.saturating_sub(300);
-                    info!(
-                        "✓ Using dynamic context for {}: {} tokens (chunk size: {})",
-                        model_name, metadata.context_size, optimal
-                    );
-                    optimal
+        Ok(LlmConnection {
+            api_key,
+            ollama_endpoint,
+            custom_openai_endpoint,
+            max_tokens: custom_openai_max_tokens,
+            temperature: custom_openai_temperature,
+            top_p: custom_openai_top_p,
+        })
+    }
+
+    /// Picks how the minutes are written: a prompt template selected directly
+    /// ("prompt:<id>"), the prompt template assigned to the meeting type, or else the
+    /// section template `template_id`
+    async fn resolve_minutes_template(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        template_id: &str,
+        meeting_type: Option<&str>,
+        agenda: Option<String>,
+    ) -> Result<MinutesTemplate, String> {
+        let prompt_templates = SettingsRepository::get_prompt_templates(pool)
+            .await
+            .map_err(|e| format!("Failed to load prompt templates: {}", e))?;
+        let selected = match template_id.strip_prefix(PROMPT_TEMPLATE_PREFIX) {
+            Some(id) => Some(
+                prompt_templates
+                    .iter()
+                    .find(|t| t.id == id)
+                    .ok_or_else(|| format!("Prompt template '{}' not found", id))?,
+            ),
+            None => meeting_type.and_then(|t| templates::template_for_meeting_type(&prompt_templates, t)),
+        };
+        let prompt_template = match selected {
+            Some(prompt_template) => prompt_template.clone(),
+            None => return Ok(MinutesTemplate::Sections(template_id.to_string())),
+        };
+        info!("Using prompt template '{}' for meeting {}", prompt_template.id, meeting_id);
+
+        // Speakers in order of first appearance
+        let mut participants: Vec<String> = Vec::new();
+        match TranscriptsRepository::list_segments_for_analysis(pool, meeting_id).await {
+            Ok(segments) => {
+                for speaker in segments.into_iter().filter_map(|(_, _, _, speaker)| speaker) {
+                    if !participants.contains(&speaker) {
+                        participants.push(speaker);
+                    }
                 }
+            }
+            Err(e) => warn!("Failed to load speakers for {}: {}", meeting_id, e),
+        }
+        let date = match MeetingsRepository::get_meeting_metadata(pool, meeting_id).await {
+            Ok(Some(meeting)) => meeting.created_at.0.format("%Y-%m-%d").to_string(),
+            _ => chrono::Local::now().format("%Y-%m-%d").to_string(),
+        };
+
+        Ok(MinutesTemplate::Prompt(
+            prompt_template,
+            PromptVariables {
+                participants,
+                date,
+                agenda,
+            },
+        ))
+    }
+
+    /// Builds the summary provider for the model selected in settings (used by the
+    /// extraction passes that run outside of minutes generation), redacting personal
+    /// data when that is enabled
+    pub async fn configured_summary_provider(
+        pool: &SqlitePool,
+        app_data_dir: Option<PathBuf>,
+    ) -> Result<Box<dyn SummaryProvider>, String> {
+        let settings = SettingsRepository::get_model_config(pool)
+            .await
+            .map_err(|e| format!("Failed to load model settings: {}", e))?
+            .ok_or_else(|| "No summary model configured".to_string())?;
+        let provider = LLMProvider::from_str(&settings.provider)?;
+        let provider =
+            Self::build_summary_provider(pool, provider, &settings.provider, &settings.model, app_data_dir).await?;
+        Ok(redaction::protect(pool, provider, None).await?.0)
+    }
+
+    /// Builds the summary provider for a configured LLM provider and model
+    ///
+    /// Ollama gets its own provider on the native API and the built-in model runs through
+    /// the embedded llama.cpp provider; other providers go through the shared LLM client.
+    /// Local models (Ollama, built-in AI) get their context window so
+    /// long transcripts are chunked; cloud providers handle whole transcripts in a single pass.
+    ///
+    /// # Arguments
+    /// * `pool` - SQLx connection pool
+    /// * `provider` - Parsed LLM provider
+    /// * `model_provider` - Provider name as stored in settings (used for API key lookup)
+    /// * `model_name` - Specific model (e.g., "gpt-4", "llama3.2:latest")
+    /// * `app_data_dir` - App data directory (BuiltInAI provider)
+    pub async fn build_summary_provider(
+        pool: &SqlitePool,
+        provider: LLMProvider,
+        model_provider: &str,
+        model_name: &str,
+        app_data_dir: Option<PathBuf>,
+    ) -> Result<Box<dyn SummaryProvider>, String> {
+        // The built-in model runs in-process from the models directory: no connection settings
+        if provider == LLMProvider::BuiltInAI {
+            let app_data_dir = app_data_dir
+                .ok_or_else(|| "App data directory is required for the built-in model".to_string())?;
+            return LlamaSummaryProvider::new(app_data_dir, model_name)
+                .map(|provider| Box::new(provider) as Box<dyn SummaryProvider>)
+                .map_err(|e| e.to_string());
+        }
+
+        let connection = Self::resolve_llm_connection(pool, &provider, model_provider).await?;
+
+        if provider == LLMProvider::OpenAI {
+            return Ok(Box::new(OpenAISummaryProvider::new(
+                connection.api_key,
+                model_name.to_string(),
+            )));
+        }
+        if provider == LLMProvider::Groq {
+            return Ok(Box::new(GroqSummaryProvider::new(
+                connection.api_key,
+                model_name.to_string(),
+            )));
+        }
+        if provider == LLMProvider::Gemini {
+            return Ok(Box::new(GeminiSummaryProvider::new(
+                connection.api_key,
+                model_name.to_string(),
+            )));
+        }
+        if provider == LLMProvider::Claude {
+            return Ok(Box::new(ClaudeSummaryProvider::new(
+                connection.api_key,
+                model_name.to_string(),
+            )));
+        }
+
+        if provider == LLMProvider::Ollama {
+            // Dynamically fetch the model's context size
+            let context_size =
+                match METADATA_CACHE.get_or_fetch(model_name, connection.ollama_endpoint.as_deref()).await {
+                    Ok(metadata) => {
+                        info!("✓ Using dynamic context for {}: {} tokens", model_name, metadata.context_size);
+                        Some(metadata.context_size)
+                    }
+                    Err(e) => {
+                        warn!("Failed to fetch context for {}: {}. Using default", model_name, e);
+                        None
+                    }
+                };
+            return Ok(Box::new(OllamaSummaryProvider::new(
+                connection.ollama_endpoint,
+                model_name.to_string(),
+                context_size,
+            )));
+        }
+
+        // OpenRouter and custom endpoints: the configured window, else a guess from the
+        // model name; unknown models get a conservative window so long meetings are split
+        // rather than rejected
+        let configured = if provider == LLMProvider::CustomOpenAI {
+            SettingsRepository::get_custom_openai_config(pool)
+                .await
+                .ok()
+                .flatten()
+                .and_then(|config| config.context_tokens)
+                .map(|tokens| tokens as usize)
+        } else {
+            None
+        };
+        let fallback = if provider == LLMProvider::OpenRouter {
+            OPENROUTER_FALLBACK_CONTEXT_TOKENS
+        } else {
+            CUSTOM_FALLBACK_CONTEXT_TOKENS
+        };
+        let context = configured
+            .or_else(|| compatible_model_context_tokens(model_name))
+            .unwrap_or(fallback);
+        let reserve = connection
+            .max_tokens
+            .map(|tokens| tokens as usize)
+            .unwrap_or(COMPATIBLE_RESPONSE_RESERVE_TOKENS)
+            + COMPATIBLE_PROMPT_OVERHEAD_TOKENS;
+        info!("✓ Using {} context window of {} tokens", model_name, context);
+        let context_tokens = Some(context.saturating_sub(reserve).max(context / 2));
+
+        Ok(Box::new(LlmClientProvider::new(
+            provider,
+            model_name.to_string(),
+            connection,
+            app_data_dir,
+            context_tokens,
+        )))
+    }
+
+    /// Processes transcript in the background and generates summary
+    ///
+    /// This function is designed to be spawned as an async task and does not block
+    /// the main thread. It updates the database with progress and results.
+    ///
+    /// # Arguments
+    /// * `_app` - Tauri app handle (for future use)
+    /// * `pool` - SQLx connection pool
+    /// * `meeting_id` - Unique identifier for the meeting
+    /// * `text` - Full transcript text
+    /// * `model_provider` - LLM provider name (e.g., "ollama", "openai")
+    /// * `model_name` - Specific model (e.g., "gpt-4", "llama3.2:latest")
+    /// * `custom_prompt` - Optional user-provided context
+    /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting"),
+    ///   "prompt:<id>" for a user prompt template, or "standup_report" for a per-person
+    ///   standup report instead of minutes
+    /// * `meeting_type` - Meeting type (e.g., "standup", "client_call"); its assigned prompt
+    ///   template is used when `template_id` is a section template
+    /// * `agenda` - Agenda for the `{{agenda}}` prompt template variable and the agenda
+    ///   coverage section; None uses the agenda attached to the meeting, if any
+    /// * `include_speaker_analytics` - Append a per-speaker talk-time table to the minutes
+    /// * `summary_style` - Output preset for this meeting; None uses the default from settings
+    /// * `summary_language` - Language code to write the minutes in; None uses the one stored
+    ///   for the meeting, if any
+    pub async fn process_transcript_background<R: tauri::Runtime>(
+        _app: AppHandle<R>,
+        pool: SqlitePool,
+        meeting_id: String,
+        text: String,
+        model_provider: String,
+        model_name: String,
+        custom_prompt: String,
+        template_id: String,
+        meeting_type: Option<String>,
+        agenda: Option<String>,
+        include_speaker_analytics: bool,
+        summary_style: Option<SummaryStyle>,
+        summary_language: Option<String>,
+    ) {
+        let start_time = Instant::now();
+        info!(
+            "Starting background processing for meeting_id: {}",
+            meeting_id
+        );
+
+        // Register cancellation token for this meeting
+        let cancellation_token = Self::register_cancellation_token(&meeting_id);
+
+        // Parse provider
+        let provider = match LLMProvider::from_str(&model_provider) {
+            Ok(p) => p,
+            Err(e) => {
+                Self::update_process_failed(&pool, &meeting_id, &e).await;
+                Self::cleanup_cancellation_token(&meeting_id);
+                return;
+            }
+        };
+
+        let app_data_dir = _app.path().app_data_dir().ok();
+        let summary_provider = match Self::build_summary_provider(
+            &pool,
+            provider,
+            &model_provider,
+            &model_name,
+            app_data_dir,
+        )
+        .await
+        {
+            Ok(summary_provider) => summary_provider,
+            Err(e) => {
+                Self::update_process_failed(&pool, &meeting_id, &e).await;
+                Self::cleanup_cancellation_token(&meeting_id);
+                return;
+            }
+        };
+        // Every pass below goes through this provider, so redaction covers all of them
+        let (summary_provider, redactor) =
+            match redaction::protect(&pool, summary_provider, Some(&meeting_id)).await {
+                Ok(protected) => protected,
                 Err(e) => {
-                    warn!(
-                        "Failed to fetch context for {}: {}. Using default 4000",
-                        model_name, e
-                    );
-                    4000  // Fallback to safe default
+                    Self::update_process_failed(&pool, &meeting_id, &e).await;
+                    Self::cleanup_cancellation_token(&meeting_id);
+                    return;
                 }
+            };
+        let summary_provider = metered(summary_provider, &pool, Some(&meeting_id), "minutes");
+
+        // Output language: the one chosen for this meeting, otherwise the meeting's own. For
+        // multilingual meetings, tell the model which languages it will see.
+        let stored_meeting = match MeetingsRepository::get_meeting_metadata(&pool, &meeting_id).await {
+            Ok(meeting) => meeting,
+            Err(e) => {
+                warn!("Failed to load summary language and agenda for {}: {}", meeting_id, e);
+                None
             }
-        } else if provider == LLMProvider::BuiltInAI {
-            // Get model's context size from registry
-            use crate::summary::summary_engine::models;
-            let model = models::get_model_by_name(&model_name)
-                .ok_or_else(|| format!("Unknown model: {}", model_name));
-
-            match model {
-                Ok(model_def) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = model_def.context_size.saturating_sub(300) as usize;
-                    info!(
-                        "✓ Using BuiltInAI context size: {} tokens (chunk size: {})",
-                        model_def.context_size, optimal
-                    );
-                    optimal
+        };
+        let summary_language = summary_language
+            .or_else(|| stored_meeting.as_ref().and_then(|meeting| meeting.summary_language.clone()));
+        let agenda = agenda
+            .filter(|agenda| !agenda.trim().is_empty())
+            .or_else(|| stored_meeting.and_then(|meeting| meeting.agenda));
+        let mut custom_prompt = custom_prompt;
+        let breakdown = match TranscriptsRepository::language_breakdown(&pool, &meeting_id).await {
+            Ok(breakdown) => breakdown,
+            Err(e) => {
+                warn!("Failed to load segment languages for {}: {}", meeting_id, e);
+                Vec::new()
+            }
+        };
+        let language_instruction = match &summary_language {
+            Some(language) => {
+                info!("Writing minutes for {} in '{}'", meeting_id, language);
+                Some(language_id::output_language_instruction(language, &breakdown))
+            }
+            None => {
+                let instruction = language_id::multilingual_instruction(&breakdown);
+                if instruction.is_some() {
+                    info!("Meeting {} is multilingual: {:?}", meeting_id, breakdown);
+                }
+                instruction
+            }
+        };
+        if let Some(instruction) = language_instruction {
+            if !custom_prompt.is_empty() {
+                custom_prompt.push_str("\n\n");
+            }
+            custom_prompt.push_str(&instruction);
+        }
+
+        // Correct spellings of the team's names, projects and acronyms
+        match glossary::glossary_instruction(&pool).await {
+            Ok(Some(instruction)) => {
+                if !custom_prompt.is_empty() {
+                    custom_prompt.push_str("\n\n");
                 }
+                custom_prompt.push_str(&instruction);
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Skipping glossary for {}: {}", meeting_id, e),
+        }
+
+        let minutes_template = match Self::resolve_minutes_template(
+            &pool,
+            &meeting_id,
+            &template_id,
+            meeting_type.as_deref(),
+            agenda.clone(),
+        )
+        .await
+        {
+            Ok(minutes_template) => minutes_template,
+            Err(e) => {
+                Self::update_process_failed(&pool, &meeting_id, &e).await;
+                Self::cleanup_cancellation_token(&meeting_id);
+                return;
+            }
+        };
+
+        let summary_style = match summary_style {
+            Some(summary_style) => summary_style,
+            None => match SettingsRepository::get_summary_style(&pool).await {
+                Ok(summary_style) => summary_style.unwrap_or_default(),
                 Err(e) => {
-                    warn!("{}, using default 2048", e);
-                    1748  // 2048 - 300 for overhead
+                    warn!("Failed to load default summary style, using detailed minutes: {}", e);
+                    SummaryStyle::default()
+                }
+            },
+        };
+
+        // Standups get a structured per-person report instead of generic minutes
+        let standup_report = template_id == standup::STANDUP_TEMPLATE_ID;
+
+        // Split the meeting into topical chapters; their outline structures the minutes
+        let mut chapters_section = None;
+        if !standup_report {
+            match chapters::extract_and_store(
+                &pool,
+                &meeting_id,
+                summary_provider.as_ref(),
+                Some(&cancellation_token),
+            )
+            .await
+            {
+                Ok(Some(meeting_chapters)) if meeting_chapters.len() > 1 => {
+                    if !custom_prompt.is_empty() {
+                        custom_prompt.push_str("\n\n");
+                    }
+                    custom_prompt.push_str(&chapters::chapter_outline(&meeting_chapters));
+                    chapters_section = Some(chapters::render_chapters_markdown(&meeting_chapters));
                 }
+                Ok(_) => {}
+                Err(e) => warn!("Skipping chapters for {}: {}", meeting_id, e),
+            }
+        }
+
+        // Stream the minutes to the UI as `summary-stream` events while they are written
+        let stream_app = _app.clone();
+        let stream_meeting_id = meeting_id.clone();
+        let on_token: TokenCallback = Arc::new(move |delta: &str| {
+            let _ = stream_app.emit(
+                "summary-stream",
+                serde_json::json!({ "meeting_id": stream_meeting_id, "delta": delta }),
+            );
+        });
+
+        // Generate minutes
+        let mut result = if standup_report {
+            match standup::extract_and_store(
+                &pool,
+                &meeting_id,
+                summary_provider.as_ref(),
+                Some(&cancellation_token),
+            )
+            .await
+            {
+                Ok(Some((report, batches))) => Ok(MeetingMinutes {
+                    title: None,
+                    markdown: standup::render_report_markdown(&report),
+                    chunks_processed: batches as i64,
+                }),
+                Ok(None) => Err(SummaryError::InvalidResponse(
+                    "No transcript segments to build a standup report from".to_string(),
+                )),
+                Err(_) if cancellation_token.is_cancelled() => Err(SummaryError::Cancelled),
+                Err(e) => Err(SummaryError::RequestFailed(e)),
             }
         } else {
-            // Cloud providers (OpenAI, Claude, Groq, CustomOpenAI) handle large contexts automatically
-            100000  // Effectively unlimited for single-pass processing
+            generate_minutes(
+                summary_provider.as_ref(),
+                &text,
+                &custom_prompt,
+                &minutes_template,
+                summary_style,
+                Some(&on_token),
+                Some(&cancellation_token),
+            )
+            .await
         };
 
-        // Get app data directory for BuiltInAI provider
-        let app_data_dir = _app.path().app_data_dir().ok();
+        // Decisions and open questions get their own sections, linked back to transcript segments
+        let mut outcomes_section = None;
+        if result.is_ok() {
+            match outcomes::extract_and_store(
+                &pool,
+                &meeting_id,
+                summary_provider.as_ref(),
+                Some(&cancellation_token),
+            )
+            .await
+            {
+                Ok(section) => outcomes_section = section,
+                Err(_) if cancellation_token.is_cancelled() => result = Err(SummaryError::Cancelled),
+                Err(e) => warn!("Skipping decisions and open questions for {}: {}", meeting_id, e),
+            }
+        }
 
-        // Generate summary
-        let client = reqwest::Client::new();
-        let result = generate_meeting_summary(
-            &client,
-            &provider,
-            &model_name,
-            &final_api_key,
-            &text,
-            &custom_prompt,
-            &template_id,
-            token_threshold,
-            ollama_endpoint.as_deref(),
-            custom_openai_endpoint.as_deref(),
-            custom_openai_max_tokens,
-            custom_openai_temperature,
-            custom_openai_top_p,
-            app_data_dir.as_ref(),
-            Some(&cancellation_token),
-        )
-        .await;
+        // A few verbatim quotes with speaker and recording time
+        let mut highlights_section = None;
+        if result.is_ok() {
+            match highlights::extract_and_store(
+                &pool,
+                &meeting_id,
+                summary_provider.as_ref(),
+                Some(&cancellation_token),
+            )
+            .await
+            {
+                Ok(section) => highlights_section = section,
+                Err(_) if cancellation_token.is_cancelled() => result = Err(SummaryError::Cancelled),
+                Err(e) => warn!("Skipping highlights for {}: {}", meeting_id, e),
+            }
+        }
+
+        // Which agenda items were discussed, for how long, and which were skipped
+        let mut agenda_section = None;
+        if let Some(agenda) = agenda.as_ref().filter(|_| result.is_ok()) {
+            match agenda::analyze(
+                &pool,
+                &meeting_id,
+                agenda,
+                summary_provider.as_ref(),
+                Some(&cancellation_token),
+            )
+            .await
+            {
+                Ok(coverage) => agenda_section = coverage.as_ref().map(agenda::render_coverage_markdown),
+                Err(_) if cancellation_token.is_cancelled() => result = Err(SummaryError::Cancelled),
+                Err(e) => warn!("Skipping agenda coverage for {}: {}", meeting_id, e),
+            }
+        }
 
         let duration = start_time.elapsed().as_secs_f64();
 
@@ -246,58 +686,64 @@ impl SummaryService {
         Self::cleanup_cancellation_token(&meeting_id);
 
         match result {
-            Ok((mut final_markdown, num_chunks)) => {
-                if num_chunks == 0 && final_markdown.is_empty() {
-                    Self::update_process_failed(
-                        &pool,
-                        &meeting_id,
-                        "Summary generation failed: No content was processed.",
-                    )
-                    .await;
-                    return;
-                }
-
+            Ok(minutes) => {
+                let num_chunks = minutes.chunks_processed;
+                let mut final_markdown = minutes.markdown;
                 info!(
                     "✓ Successfully processed {} chunks for meeting_id: {}. Duration: {:.2}s",
                     num_chunks, meeting_id, duration
                 );
                 info!("final markdown is {}", &final_markdown);
 
-                // Extract and update meeting name if present
-                if let Some(name) = extract_meeting_name_from_markdown(&final_markdown) {
-                    if !name.is_empty() {
-                        info!(
-                            "Updating meeting name to '{}' for meeting_id: {}",
-                            name, meeting_id
-                        );
-                        if let Err(e) =
-                            MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name).await
-                        {
-                            error!("Failed to update meeting name for {}: {}", meeting_id, e);
-                        }
+                if let Some(name) = minutes.title {
+                    info!(
+                        "Updating meeting name to '{}' for meeting_id: {}",
+                        name, meeting_id
+                    );
+                    if let Err(e) =
+                        MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name).await
+                    {
+                        error!("Failed to update meeting name for {}: {}", meeting_id, e);
+                    }
+                }
+
+                if let Some(section) = chapters_section {
+                    final_markdown = format!("{}\n\n{}", final_markdown.trim_end(), section);
+                }
+
+                if let Some(section) = highlights_section {
+                    final_markdown = format!("{}\n\n{}", final_markdown.trim_end(), section);
+                }
+
+                if let Some(section) = outcomes_section {
+                    final_markdown = format!("{}\n\n{}", final_markdown.trim_end(), section);
+                }
+
+                if let Some(section) = agenda_section {
+                    final_markdown = format!("{}\n\n{}", final_markdown.trim_end(), section);
+                }
 
-                        // Strip the title line from markdown
-                        info!("Stripping title from final_markdown");
-                        if let Some(hash_pos) = final_markdown.find('#') {
-                            // Find end of first line after '#'
-                            let body_start =
-                                if let Some(line_end) = final_markdown[hash_pos..].find('\n') {
-                                    hash_pos + line_end
-                                } else {
-                                    final_markdown.len() // No newline, whole string is title
-                                };
-
-                            final_markdown = final_markdown[body_start..].trim_start().to_string();
-                        } else {
-                            // No '#' found, clear the string
-                            final_markdown.clear();
+                if include_speaker_analytics {
+                    match crate::diarization::talk_time::meeting_talk_time(&pool, &meeting_id).await {
+                        Ok(analytics) => {
+                            if let Some(section) =
+                                crate::diarization::talk_time::render_talk_time_markdown(&analytics)
+                            {
+                                final_markdown = format!("{}\n\n{}", final_markdown.trim_end(), section);
+                            }
                         }
+                        Err(e) => warn!("Skipping speaker analytics for {}: {}", meeting_id, e),
                     }
                 }
 
+                if let Some(redactor) = &redactor {
+                    final_markdown =
+                        redaction::finish_minutes(&pool, &meeting_id, redactor, final_markdown).await;
+                }
+
                 // Create result JSON with markdown only (summary_json will be added on first edit)
                 let result_json = serde_json::json!({
-                    "markdown": final_markdown,
+                    "markdown": &final_markdown,
                 });
 
                 // Update database with completed status
@@ -319,19 +765,34 @@ impl SummaryService {
                         "Summary saved successfully for meeting_id: {}",
                         meeting_id
                     );
+                    if let Err(e) = versions::record_generated(
+                        &pool,
+                        &meeting_id,
+                        &final_markdown,
+                        &template_id,
+                        summary_provider.as_ref(),
+                        summary_style,
+                    )
+                    .await
+                    {
+                        warn!("Failed to save summary version for {}: {}", meeting_id, e);
+                    }
+                    crate::integrations::spawn_meeting_event(
+                        &_app,
+                        crate::integrations::MeetingEvent::SummaryReady,
+                        meeting_id.clone(),
+                    );
                 }
             }
-            Err(e) => {
-                // Check if error is due to cancellation
-                if e.contains("cancelled") {
-                    info!("Summary generation was cancelled for meeting_id: {}", meeting_id);
-                    if let Err(db_err) = SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await {
-                        error!("Failed to update DB status to cancelled for {}: {}", meeting_id, db_err);
-                    }
-                } else {
-                    Self::update_process_failed(&pool, &meeting_id, &e).await;
+            Err(SummaryError::Cancelled) => {
+                info!("Summary generation was cancelled for meeting_id: {}", meeting_id);
+                if let Err(db_err) = SummaryProcessesRepository::update_process_cancelled(&pool, &meeting_id).await {
+                    error!("Failed to update DB status to cancelled for {}: {}", meeting_id, db_err);
                 }
             }
+            Err(e) => {
+                Self::update_process_failed(&pool, &meeting_id, &e.to_string()).await;
+            }
         }
     }
 
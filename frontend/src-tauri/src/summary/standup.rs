@@ -0,0 +1,336 @@
+// summary/standup.rs
+//
+// Standup reports. Selecting the "standup_report" template replaces the generic minutes
+// with a structured report: the summary model pulls each person's yesterday/today
+// updates, blockers and risks out of the numbered transcript, they are stored per
+// meeting, and the minutes list the blockers and risks first, then everyone's updates.
+
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::ops::Range;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::database::models::StandupItem;
+use crate::database::repositories::standup_item::{NewStandupItem, StandupItemsRepository};
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::action_items::{batch_budget, batch_lines, complete_validated, numbered_lines};
+use crate::summary::provider::{SummaryError, SummaryProvider};
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+/// Template id that selects the standup report instead of generic minutes
+pub const STANDUP_TEMPLATE_ID: &str = "standup_report";
+
+const UPDATE_KINDS: [&str; 4] = ["yesterday", "today", "blocker", "risk"];
+
+const SYSTEM_PROMPT: &str = "You turn standup meeting transcripts into structured updates. Each transcript \
+line starts with its segment number in brackets, followed by the speaker when known. For every person who \
+gave an update, list what they did since the last standup (\"yesterday\"), what they plan to do next \
+(\"today\"), anything blocking them (\"blocker\") and any risk to deadlines or quality they or others raised \
+(\"risk\"). Write each as one short sentence and use the person's name as it appears in the transcript. \
+Reply with only a JSON object {\"updates\": [{\"speaker\": \"<name>\", \"kind\": \"yesterday\" | \"today\" | \
+\"blocker\" | \"risk\", \"text\": \"<sentence>\", \"segments\": [<segment numbers>]}]}.";
+
+/// An update that passed validation; `segments` index the meeting's segments
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedUpdate {
+    pub speaker: String,
+    pub kind: &'static str,
+    pub text: String,
+    pub segments: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StandupEntry {
+    pub text: String,
+    pub start_time: Option<f64>,
+}
+
+/// One person's part of the standup
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StandupMember {
+    pub speaker: String,
+    pub yesterday: Vec<StandupEntry>,
+    pub today: Vec<StandupEntry>,
+    pub blockers: Vec<StandupEntry>,
+    pub risks: Vec<StandupEntry>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StandupReport {
+    /// In the order people first gave an update
+    pub members: Vec<StandupMember>,
+}
+
+/// Validate a reply: {"updates": [{speaker, kind, text, segments}]} with segments inside
+/// `segments`. Updates without a speaker are kept under "Unassigned".
+pub fn validate_updates(reply: &str, segments: Range<usize>) -> Result<Vec<ExtractedUpdate>, String> {
+    let json = match (reply.find('{'), reply.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &reply[start..=end],
+        _ => return Err("reply does not contain a JSON object".to_string()),
+    };
+    let object: Value = serde_json::from_str(json).map_err(|e| format!("invalid JSON: {}", e))?;
+    let items = match object.get("updates") {
+        Some(Value::Array(items)) => items,
+        _ => return Err("\"updates\" must be an array".to_string()),
+    };
+
+    let mut updates = Vec::with_capacity(items.len());
+    for (index, item) in items.iter().enumerate() {
+        let kind = match item.get("kind").and_then(Value::as_str) {
+            Some(kind) => UPDATE_KINDS
+                .into_iter()
+                .find(|known| *known == kind.trim())
+                .ok_or_else(|| format!("updates[{}]: unknown kind \"{}\"", index, kind))?,
+            None => return Err(format!("updates[{}]: \"kind\" must be a string", index)),
+        };
+        let text = match item.get("text").and_then(Value::as_str).map(str::trim) {
+            Some(text) if !text.is_empty() => text.to_string(),
+            _ => return Err(format!("updates[{}]: \"text\" must be a non-empty string", index)),
+        };
+        let speaker = item
+            .get("speaker")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|speaker| !speaker.is_empty())
+            .unwrap_or("Unassigned")
+            .to_string();
+        let mut segment_numbers = Vec::new();
+        for number in item.get("segments").and_then(Value::as_array).into_iter().flatten() {
+            match number.as_u64() {
+                Some(n) if segments.contains(&(n as usize)) => segment_numbers.push(n as usize),
+                _ => {
+                    return Err(format!(
+                        "updates[{}]: segment {} is not one of the transcript lines {}..{}",
+                        index, number, segments.start, segments.end
+                    ))
+                }
+            }
+        }
+        segment_numbers.sort_unstable();
+        segment_numbers.dedup();
+        updates.push(ExtractedUpdate {
+            speaker,
+            kind,
+            text,
+            segments: segment_numbers,
+        });
+    }
+    Ok(updates)
+}
+
+/// Group stored updates by person, matching names case-insensitively
+pub fn build_report(items: &[StandupItem]) -> StandupReport {
+    let mut members: Vec<StandupMember> = Vec::new();
+    for item in items {
+        let index = match members
+            .iter()
+            .position(|member| member.speaker.eq_ignore_ascii_case(&item.speaker))
+        {
+            Some(index) => index,
+            None => {
+                members.push(StandupMember {
+                    speaker: item.speaker.clone(),
+                    ..StandupMember::default()
+                });
+                members.len() - 1
+            }
+        };
+        let entries = match item.kind.as_str() {
+            "yesterday" => &mut members[index].yesterday,
+            "today" => &mut members[index].today,
+            "blocker" => &mut members[index].blockers,
+            "risk" => &mut members[index].risks,
+            _ => continue,
+        };
+        let normalize = |text: &str| -> String { text.to_lowercase().chars().filter(|c| c.is_alphanumeric()).collect() };
+        if entries.iter().any(|entry| normalize(&entry.text) == normalize(&item.text)) {
+            continue;
+        }
+        entries.push(StandupEntry {
+            text: item.text.clone(),
+            start_time: item.start_time,
+        });
+    }
+    StandupReport { members }
+}
+
+fn entry_line(entry: &StandupEntry) -> String {
+    match entry.start_time {
+        Some(start) => format!("{} _({})_", entry.text, crate::utils::format_timestamp(start)),
+        None => entry.text.clone(),
+    }
+}
+
+/// The standup report as minutes: blockers and risks up front, then each person's updates
+pub fn render_report_markdown(report: &StandupReport) -> String {
+    let mut markdown = String::from("## Blockers & Risks\n\n");
+    let mut any = false;
+    for member in &report.members {
+        for (label, entries) in [("Blocker", &member.blockers), ("Risk", &member.risks)] {
+            for entry in entries {
+                any = true;
+                markdown.push_str(&format!("- **{}** ({}): {}\n", member.speaker, label, entry_line(entry)));
+            }
+        }
+    }
+    if !any {
+        markdown.push_str("No blockers or risks raised.\n");
+    }
+
+    markdown.push_str("\n## Updates\n");
+    for member in &report.members {
+        markdown.push_str(&format!("\n### {}\n", member.speaker));
+        for (label, entries) in [("Yesterday", &member.yesterday), ("Today", &member.today)] {
+            if entries.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("\n**{}**\n\n", label));
+            for entry in entries {
+                markdown.push_str(&format!("- {}\n", entry_line(entry)));
+            }
+        }
+    }
+    if report.members.is_empty() {
+        markdown.push_str("\nNo updates were given.\n");
+    }
+    markdown.trim_end().to_string()
+}
+
+/// Extract a standup's per-person updates, store them and return the report with the
+/// number of transcript batches read. None when the meeting has no transcript segments.
+pub async fn extract_and_store(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    provider: &dyn SummaryProvider,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Option<(StandupReport, usize)>, String> {
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    let lines = numbered_lines(&segments);
+    let batches = batch_lines(&lines, batch_budget(provider));
+    let mut updates = Vec::new();
+    for (batch_index, range) in batches.iter().enumerate() {
+        let user_prompt = format!("<transcript>\n{}\n</transcript>", lines[range.clone()].join("\n"));
+        let validate = |reply: &str| validate_updates(reply, range.clone());
+        match complete_validated(provider, SYSTEM_PROMPT, &user_prompt, validate, cancellation_token).await {
+            Ok(batch_updates) => updates.extend(batch_updates),
+            Err(SummaryError::InvalidResponse(e)) => {
+                warn!("Skipping standup updates of batch {} for {}: {}", batch_index, meeting_id, e)
+            }
+            Err(e) => return Err(format!("Standup extraction failed: {}", e)),
+        }
+    }
+
+    let rows: Vec<NewStandupItem> = updates
+        .into_iter()
+        .map(|update| NewStandupItem {
+            start_time: update
+                .segments
+                .first()
+                .and_then(|&i| segments.get(i))
+                .and_then(|(_, _, start, _)| *start),
+            segment_ids: update
+                .segments
+                .iter()
+                .filter_map(|&i| segments.get(i).map(|(id, _, _, _)| id.clone()))
+                .collect(),
+            speaker: update.speaker,
+            kind: update.kind.to_string(),
+            text: update.text,
+        })
+        .collect();
+    StandupItemsRepository::replace_for_meeting(pool, meeting_id, &rows)
+        .await
+        .map_err(|e| format!("Failed to save standup updates: {}", e))?;
+
+    let items = StandupItemsRepository::list_for_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load standup updates: {}", e))?;
+    let report = build_report(&items);
+    info!(
+        "Extracted standup updates from {} people for {}",
+        report.members.len(),
+        meeting_id
+    );
+    Ok(Some((report, batches.len())))
+}
+
+/// The stored standup report of a meeting (empty when none was extracted)
+#[command]
+pub async fn get_standup_report<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<StandupReport, String> {
+    let state = app.state::<AppState>();
+    let items = StandupItemsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load standup updates: {}", e))?;
+    Ok(build_report(&items))
+}
+
+/// Re-run standup extraction for a meeting with the configured model
+#[command]
+pub async fn extract_standup_report<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<StandupReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = SummaryService::configured_summary_provider(pool, app.path().app_data_dir().ok()).await?;
+    let provider = metered(provider, pool, Some(&meeting_id), "standup");
+    extract_and_store(pool, &meeting_id, provider.as_ref(), None)
+        .await?
+        .map(|(report, _)| report)
+        .ok_or_else(|| "The meeting has no transcript".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_validate_and_build_report() {
+        let reply = r#"{"updates":[
+            {"speaker":"Ana","kind":"yesterday","text":"Finished the login page","segments":[2]},
+            {"speaker":"Ana","kind":"blocker","text":"Waiting on API keys","segments":[3,3]},
+            {"speaker":"","kind":"today","text":"Review PRs","segments":[]}]}"#;
+        let updates = validate_updates(reply, 0..10).unwrap();
+        assert_eq!(updates.len(), 3);
+        assert_eq!(updates[1].segments, vec![3]);
+        assert_eq!(updates[2].speaker, "Unassigned");
+        assert!(validate_updates(r#"{"updates":[{"kind":"mood","text":"ok"}]}"#, 0..10).is_err());
+        assert!(validate_updates(r#"{"updates":[{"kind":"today","text":"x","segments":[11]}]}"#, 0..10).is_err());
+
+        let item = |speaker: &str, kind: &str, text: &str, start_time| StandupItem {
+            id: String::new(),
+            meeting_id: "meeting-1".to_string(),
+            speaker: speaker.to_string(),
+            kind: kind.to_string(),
+            text: text.to_string(),
+            segment_ids: "[]".to_string(),
+            start_time,
+            created_at: Utc::now(),
+        };
+        let report = build_report(&[
+            item("Ana", "yesterday", "Finished the login page", Some(12.0)),
+            item("Ben", "today", "Fix the build", None),
+            item("ana", "blocker", "Waiting on API keys", Some(65.0)),
+            item("Ana", "yesterday", "finished the login page.", None),
+        ]);
+        assert_eq!(report.members.len(), 2);
+        assert_eq!(report.members[0].yesterday.len(), 1);
+        assert_eq!(
+            render_report_markdown(&report),
+            "## Blockers & Risks\n\n- **Ana** (Blocker): Waiting on API keys _(00:01:05)_\n\n## Updates\n\n### Ana\n\n\
+             **Yesterday**\n\n- Finished the login page _(00:00:12)_\n\n### Ben\n\n**Today**\n\n- Fix the build"
+        );
+    }
+}
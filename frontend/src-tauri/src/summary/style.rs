@@ -0,0 +1,146 @@
+// summary/style.rs
+//
+// Output presets for the minutes: an executive brief, detailed minutes, bullet points
+// only, or a narrative write-up. A preset doesn't replace the template; it adds
+// instructions on length and structure to the final prompt, so any section or prompt
+// template can be written in any style. The user's default is stored in settings and can
+// be overridden per meeting when the summary is generated.
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SummaryStyle {
+    ExecutiveBrief,
+    #[default]
+    DetailedMinutes,
+    BulletPoints,
+    Narrative,
+}
+
+/// A preset as listed in the summary settings
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryStyleInfo {
+    pub id: SummaryStyle,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+impl SummaryStyle {
+    pub const ALL: [SummaryStyle; 4] = [
+        SummaryStyle::ExecutiveBrief,
+        SummaryStyle::DetailedMinutes,
+        SummaryStyle::BulletPoints,
+        SummaryStyle::Narrative,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SummaryStyle::ExecutiveBrief => "executive_brief",
+            SummaryStyle::DetailedMinutes => "detailed_minutes",
+            SummaryStyle::BulletPoints => "bullet_points",
+            SummaryStyle::Narrative => "narrative",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|style| style.as_str() == value.trim())
+    }
+
+    pub fn info(&self) -> SummaryStyleInfo {
+        let (name, description) = match self {
+            SummaryStyle::ExecutiveBrief => ("Executive brief", "The outcome in a few sentences and short lists, under a page"),
+            SummaryStyle::DetailedMinutes => ("Detailed minutes", "Every topic with its discussion, reasoning and owners"),
+            SummaryStyle::BulletPoints => ("Bullet points", "Terse bullets under each heading, no prose"),
+            SummaryStyle::Narrative => ("Narrative", "Readable paragraphs telling how the meeting went"),
+        };
+        SummaryStyleInfo {
+            id: *self,
+            name,
+            description,
+        }
+    }
+
+    /// Length and structure instructions added to the final minutes prompt
+    fn instructions(&self) -> &'static str {
+        match self {
+            SummaryStyle::ExecutiveBrief => {
+                "Write an executive brief for someone with two minutes to read it. Start with a \
+                 \"**Bottom line:**\" paragraph of at most three sentences. Keep every section to at most \
+                 three short bullets, drop sections with nothing relevant instead of marking them empty, \
+                 and leave out discussion details. Stay under 250 words in total."
+            }
+            SummaryStyle::DetailedMinutes => {
+                "Write detailed minutes. Cover every topic that was discussed, including the options \
+                 considered, the reasoning behind decisions, disagreements and who raised what. Prefer \
+                 completeness over brevity."
+            }
+            SummaryStyle::BulletPoints => {
+                "Use bullet points only: under each heading write short, single-line bullets without \
+                 full paragraphs, introductions or closing remarks. Start each bullet with the key word \
+                 or name where possible."
+            }
+            SummaryStyle::Narrative => {
+                "Write each section as flowing prose paragraphs instead of bullet lists, in the past \
+                 tense, so the minutes read as an account of how the meeting went. Keep tables and \
+                 checklists only where the template requires them."
+            }
+        }
+    }
+
+    /// Append this style's instructions to a final minutes prompt
+    pub fn apply(&self, user_prompt: &mut String) {
+        user_prompt.push_str("\n\nOutput Style (takes precedence over the template's length and formatting):\n\n<output_style>\n");
+        user_prompt.push_str(self.instructions());
+        user_prompt.push_str("\n</output_style>");
+    }
+}
+
+#[command]
+pub async fn list_summary_styles() -> Result<Vec<SummaryStyleInfo>, String> {
+    Ok(SummaryStyle::ALL.iter().map(SummaryStyle::info).collect())
+}
+
+/// The style used when a summary is generated without choosing one
+#[command]
+pub async fn get_summary_style<R: Runtime>(app: AppHandle<R>) -> Result<SummaryStyle, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_summary_style(state.db_manager.pool())
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load summary style: {}", e))
+}
+
+#[command]
+pub async fn save_summary_style<R: Runtime>(app: AppHandle<R>, style: SummaryStyle) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::save_summary_style(state.db_manager.pool(), style)
+        .await
+        .map_err(|e| format!("Failed to save summary style: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_round_trip() {
+        for style in SummaryStyle::ALL {
+            assert_eq!(SummaryStyle::parse(style.as_str()), Some(style));
+            assert_eq!(
+                serde_json::to_string(&style).unwrap(),
+                format!("\"{}\"", style.as_str())
+            );
+        }
+        assert_eq!(SummaryStyle::parse("haiku"), None);
+        assert_eq!(SummaryStyle::default(), SummaryStyle::DetailedMinutes);
+
+        let mut prompt = String::from("<transcript_chunks>...</transcript_chunks>");
+        SummaryStyle::BulletPoints.apply(&mut prompt);
+        assert!(prompt.ends_with("or name where possible.\n</output_style>"));
+    }
+}
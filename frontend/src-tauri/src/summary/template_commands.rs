@@ -1,6 +1,8 @@
-use crate::summary::templates;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::summary::templates::{self, PromptTemplate};
 use serde::{Deserialize, Serialize};
-use tauri::Runtime;
+use tauri::{Manager, Runtime};
 use tracing::{info, warn};
 
 /// Template metadata for UI display
@@ -123,6 +125,70 @@ pub async fn api_validate_template<R: Runtime>(
     }
 }
 
+/// Lists the user's prompt templates
+#[tauri::command]
+pub async fn api_list_prompt_templates<R: Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Vec<PromptTemplate>, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_prompt_templates(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load prompt templates: {}", e))
+}
+
+/// Creates or updates a prompt template
+///
+/// Assigning meeting types moves them from any other template, so each meeting type
+/// maps to at most one prompt template.
+///
+/// # Arguments
+/// * `template` - Template to save; replaces the existing one with the same id
+#[tauri::command]
+pub async fn api_save_prompt_template<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    template: PromptTemplate,
+) -> Result<Vec<PromptTemplate>, String> {
+    info!("api_save_prompt_template called for template_id: {}", template.id);
+    template.validate()?;
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut prompt_templates = SettingsRepository::get_prompt_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load prompt templates: {}", e))?;
+    templates::upsert_prompt_template(&mut prompt_templates, template);
+
+    SettingsRepository::save_prompt_templates(pool, &prompt_templates)
+        .await
+        .map_err(|e| format!("Failed to save prompt templates: {}", e))?;
+    Ok(prompt_templates)
+}
+
+/// Deletes a prompt template
+#[tauri::command]
+pub async fn api_delete_prompt_template<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    template_id: String,
+) -> Result<Vec<PromptTemplate>, String> {
+    info!("api_delete_prompt_template called for template_id: {}", template_id);
+
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let mut prompt_templates = SettingsRepository::get_prompt_templates(pool)
+        .await
+        .map_err(|e| format!("Failed to load prompt templates: {}", e))?;
+    let before = prompt_templates.len();
+    prompt_templates.retain(|t| t.id != template_id);
+    if prompt_templates.len() == before {
+        return Err(format!("Prompt template '{}' not found", template_id));
+    }
+
+    SettingsRepository::save_prompt_templates(pool, &prompt_templates)
+        .await
+        .map_err(|e| format!("Failed to save prompt templates: {}", e))?;
+    Ok(prompt_templates)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
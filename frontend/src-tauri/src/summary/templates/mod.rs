@@ -36,9 +36,17 @@
 //! - Linux: `~/.config/Meetily/templates/`
 //!
 //! Custom templates must follow the JSON schema defined in `types::Template`.
+//!
+//! # Prompt Templates
+//!
+//! Users can also write the final minutes prompt themselves (`prompt::PromptTemplate`),
+//! using `{{transcript}}`, `{{participants}}`, `{{date}}` and `{{agenda}}` variables.
+//! Prompt templates are stored in settings and can be assigned to meeting types
+//! (standup, 1:1, client call) so they are picked automatically.
 
 mod defaults;
 mod loader;
+mod prompt;
 mod types;
 
 // Re-export public API
@@ -46,6 +54,10 @@ pub use loader::{
     get_template, list_template_ids, list_templates, set_bundled_templates_dir,
     validate_and_parse_template,
 };
+pub use prompt::{
+    template_for_meeting_type, upsert_prompt_template, MinutesTemplate, PromptTemplate, PromptVariables, MEETING_TYPES,
+    PROMPT_TEMPLATE_PREFIX, PROMPT_VARIABLES,
+};
 pub use types::{Template, TemplateSection};
 
 #[cfg(test)]
@@ -0,0 +1,207 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Variables a prompt template can reference as `{{name}}`
+pub const PROMPT_VARIABLES: &[&str] = &["transcript", "participants", "date", "agenda"];
+
+/// Template ids starting with this select a prompt template instead of a section template
+pub const PROMPT_TEMPLATE_PREFIX: &str = "prompt:";
+
+/// Meeting types a prompt template can be assigned to
+pub const MEETING_TYPES: &[&str] = &["standup", "one_on_one", "client_call", "general"];
+
+static VARIABLE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{\s*([A-Za-z_]+)\s*\}\}").unwrap());
+
+/// A user-defined prompt template for the final minutes pass, stored in settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    /// Template identifier, unique among the user's templates
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// Meeting types this template is used for by default (see `MEETING_TYPES`)
+    #[serde(default)]
+    pub meeting_types: Vec<String>,
+
+    /// Optional system prompt; a generic minutes-writer prompt is used otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+
+    /// Prompt body with `{{transcript}}`, `{{participants}}`, `{{date}}`, `{{agenda}}` placeholders
+    pub prompt: String,
+}
+
+/// Values for a template's variables, except the transcript which is filled in last
+/// (it may be a combination of chunk summaries for long meetings)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptVariables {
+    pub participants: Vec<String>,
+    /// Meeting date, e.g. "2026-01-09"
+    pub date: String,
+    pub agenda: Option<String>,
+}
+
+impl PromptTemplate {
+    /// Validates the template structure and placeholders
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("Template id cannot be empty".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("Template name cannot be empty".to_string());
+        }
+        if let Some(meeting_type) = self
+            .meeting_types
+            .iter()
+            .find(|t| !MEETING_TYPES.contains(&t.as_str()))
+        {
+            return Err(format!(
+                "Unknown meeting type '{}'. Must be one of: {}",
+                meeting_type,
+                MEETING_TYPES.join(", ")
+            ));
+        }
+
+        let mut has_transcript = false;
+        for text in std::iter::once(&self.prompt).chain(self.system_prompt.iter()) {
+            for captures in VARIABLE_REGEX.captures_iter(text) {
+                let name = &captures[1];
+                if !PROMPT_VARIABLES.contains(&name) {
+                    return Err(format!(
+                        "Unknown variable '{{{{{}}}}}'. Available: {}",
+                        name,
+                        PROMPT_VARIABLES.join(", ")
+                    ));
+                }
+                has_transcript |= name == "transcript";
+            }
+            // Anything still looking like a placeholder is malformed, e.g. "{{ date" or "{{agenda-1}}"
+            if VARIABLE_REGEX.replace_all(text, "").contains("{{") {
+                return Err("Template contains a malformed '{{' placeholder".to_string());
+            }
+        }
+        if !has_transcript {
+            return Err("Template must include the {{transcript}} variable".to_string());
+        }
+        Ok(())
+    }
+
+    /// Substitute the variables into `text`
+    fn render_text(text: &str, transcript: &str, variables: &PromptVariables) -> String {
+        VARIABLE_REGEX
+            .replace_all(text, |captures: &regex::Captures| match &captures[1] {
+                "transcript" => transcript.to_string(),
+                "participants" if variables.participants.is_empty() => "Not recorded".to_string(),
+                "participants" => variables.participants.join(", "),
+                "date" => variables.date.clone(),
+                "agenda" => variables
+                    .agenda
+                    .as_deref()
+                    .filter(|agenda| !agenda.trim().is_empty())
+                    .unwrap_or("No agenda provided")
+                    .to_string(),
+                // Unknown names are rejected by validate(); leave them as written
+                _ => captures[0].to_string(),
+            })
+            .into_owned()
+    }
+
+    /// Render the (system prompt, user prompt) pair for the final minutes pass
+    pub fn render(&self, transcript: &str, variables: &PromptVariables) -> (Option<String>, String) {
+        (
+            self.system_prompt
+                .as_deref()
+                .map(|system| Self::render_text(system, transcript, variables)),
+            Self::render_text(&self.prompt, transcript, variables),
+        )
+    }
+}
+
+/// The template the user assigned to a meeting type, if any
+pub fn template_for_meeting_type<'a>(
+    templates: &'a [PromptTemplate],
+    meeting_type: &str,
+) -> Option<&'a PromptTemplate> {
+    templates
+        .iter()
+        .find(|template| template.meeting_types.iter().any(|t| t == meeting_type))
+}
+
+/// Insert or replace a template by id. A meeting type belongs to one template at a time,
+/// so the types it claims are taken off the others.
+pub fn upsert_prompt_template(templates: &mut Vec<PromptTemplate>, template: PromptTemplate) {
+    for other in templates.iter_mut().filter(|t| t.id != template.id) {
+        other.meeting_types.retain(|t| !template.meeting_types.contains(t));
+    }
+    match templates.iter_mut().find(|t| t.id == template.id) {
+        Some(existing) => *existing = template,
+        None => templates.push(template),
+    }
+}
+
+/// How the final minutes pass is prompted
+#[derive(Debug, Clone)]
+pub enum MinutesTemplate {
+    /// Section template by id (built-in, bundled or custom JSON)
+    Sections(String),
+    /// User prompt template from settings, with its variable values
+    Prompt(PromptTemplate, PromptVariables),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(prompt: &str) -> PromptTemplate {
+        PromptTemplate {
+            id: "client".to_string(),
+            name: "Client call".to_string(),
+            meeting_types: vec!["client_call".to_string()],
+            system_prompt: None,
+            prompt: prompt.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_variables() {
+        let variables = PromptVariables {
+            participants: vec!["Ana".to_string(), "Marc".to_string()],
+            date: "2026-01-09".to_string(),
+            agenda: None,
+        };
+        let (system, prompt) = template("On {{ date }} with {{participants}}.\nAgenda: {{agenda}}\n{{transcript}}")
+            .render("[0] Ana: hi", &variables);
+        assert!(system.is_none());
+        assert_eq!(
+            prompt,
+            "On 2026-01-09 with Ana, Marc.\nAgenda: No agenda provided\n[0] Ana: hi"
+        );
+    }
+
+    #[test]
+    fn test_validate_placeholders() {
+        assert!(template("Summarize {{transcript}} for {{participants}}").validate().is_ok());
+        assert!(template("Summarize the meeting").validate().is_err());
+        assert!(template("{{transcript}} {{budget}}").validate().is_err());
+        assert!(template("{{transcript}} {{ date").validate().is_err());
+
+        let mut wrong_type = template("{{transcript}}");
+        wrong_type.meeting_types = vec!["retro".to_string()];
+        assert!(wrong_type.validate().is_err());
+
+        let mut templates = vec![template("{{transcript}}")];
+        assert!(template_for_meeting_type(&templates, "client_call").is_some());
+        assert!(template_for_meeting_type(&templates, "standup").is_none());
+
+        // A new template taking over "client_call" removes it from the old one
+        let mut replacement = template("Client recap: {{transcript}}");
+        replacement.id = "client_v2".to_string();
+        upsert_prompt_template(&mut templates, replacement);
+        assert_eq!(templates.len(), 2);
+        assert!(templates[0].meeting_types.is_empty());
+        assert_eq!(template_for_meeting_type(&templates, "client_call").unwrap().id, "client_v2");
+    }
+}
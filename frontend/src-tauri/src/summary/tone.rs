@@ -0,0 +1,409 @@
+// summary/tone.rs
+//
+// Optional tone analysis pass: tags each transcript segment with a sentiment
+// (positive / neutral / negative) and a tension score, using either a small local
+// lexicon classifier or the configured summary LLM, and groups tense stretches into
+// "heated moments" for the meeting timeline.
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::llm_client::LLMProvider;
+use crate::summary::redaction;
+use crate::summary::service::SummaryService;
+use crate::summary::usage::metered;
+
+/// Segments at or above this tension count towards a heated moment
+pub const HEATED_TENSION: f64 = 0.6;
+/// Tense segments closer than this (seconds) belong to the same heated moment
+const HEATED_MERGE_GAP: f64 = 30.0;
+/// Segments per LLM request
+const LLM_BATCH_SIZE: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Sentiment {
+    Positive,
+    Neutral,
+    Negative,
+}
+
+impl Sentiment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sentiment::Positive => "positive",
+            Sentiment::Neutral => "neutral",
+            Sentiment::Negative => "negative",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "positive" => Some(Sentiment::Positive),
+            "neutral" => Some(Sentiment::Neutral),
+            "negative" => Some(Sentiment::Negative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Tone {
+    pub sentiment: Sentiment,
+    /// 0.0 (calm) to 1.0 (heated)
+    pub tension: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentTone {
+    pub transcript_id: String,
+    pub audio_start_time: Option<f64>,
+    pub speaker: Option<String>,
+    pub sentiment: Sentiment,
+    pub tension: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeatedMoment {
+    pub start: f64,
+    pub end: f64,
+    pub peak_tension: f64,
+    pub segment_count: usize,
+    pub speakers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToneReport {
+    pub method: String,
+    pub segments: Vec<SegmentTone>,
+    pub heated_moments: Vec<HeatedMoment>,
+}
+
+const POSITIVE_WORDS: &[&str] = &[
+    "agree", "agreed", "amazing", "awesome", "appreciate", "excellent", "glad", "good", "great",
+    "happy", "helpful", "love", "nice", "perfect", "progress", "thanks", "thank", "win", "works",
+];
+const NEGATIVE_WORDS: &[&str] = &[
+    "bad", "blocked", "broken", "concern", "concerned", "delay", "delayed", "fail", "failed",
+    "problem", "risk", "sorry", "unfortunately", "worried", "worse", "wrong", "issue", "issues",
+];
+// Words that signal conflict or frustration rather than just bad news
+const TENSION_WORDS: &[&str] = &[
+    "angry", "annoyed", "disagree", "frustrated", "frustrating", "ridiculous", "unacceptable",
+    "nonsense", "seriously", "never", "always", "blame", "fault", "stop", "listen",
+];
+const NEGATIONS: &[&str] = &["not", "no", "never", "don't", "doesn't", "didn't", "isn't", "wasn't", "can't", "won't"];
+
+static WORD_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"[A-Za-z']+").unwrap());
+
+/// Local lexicon classifier: counts positive/negative words (flipped after a negation)
+/// and scores tension from conflict words, exclamations and shouted words
+pub fn classify_lexicon(text: &str) -> Tone {
+    let words: Vec<&str> = WORD_RE.find_iter(text).map(|m| m.as_str()).collect();
+    let mut score = 0i32;
+    let mut tension_hits = 0usize;
+    let mut shouted = 0usize;
+
+    for (i, word) in words.iter().enumerate() {
+        let lower = word.to_lowercase();
+        let negated = words[i.saturating_sub(2)..i]
+            .iter()
+            .any(|w| NEGATIONS.contains(&w.to_lowercase().as_str()));
+        let polarity = if POSITIVE_WORDS.contains(&lower.as_str()) {
+            1
+        } else if NEGATIVE_WORDS.contains(&lower.as_str()) {
+            -1
+        } else {
+            0
+        };
+        score += if negated { -polarity } else { polarity };
+
+        if TENSION_WORDS.contains(&lower.as_str()) {
+            tension_hits += 1;
+        }
+        if word.len() >= 3 && word.chars().all(|c| c.is_ascii_uppercase()) {
+            shouted += 1;
+        }
+    }
+
+    let exclamations = text.matches('!').count();
+    let tension = (tension_hits as f64 * 0.35 + shouted as f64 * 0.2 + exclamations as f64 * 0.15)
+        .min(1.0);
+    let sentiment = if score > 0 && tension < HEATED_TENSION {
+        Sentiment::Positive
+    } else if score < 0 || tension >= HEATED_TENSION {
+        Sentiment::Negative
+    } else {
+        Sentiment::Neutral
+    };
+    Tone { sentiment, tension }
+}
+
+/// Group tense segments into heated moments on the timeline
+pub fn find_heated_moments(segments: &[SegmentTone]) -> Vec<HeatedMoment> {
+    let mut moments: Vec<HeatedMoment> = Vec::new();
+    for segment in segments.iter().filter(|s| s.tension >= HEATED_TENSION) {
+        let start = match segment.audio_start_time {
+            Some(start) => start,
+            None => continue,
+        };
+        match moments.last_mut() {
+            Some(moment) if start - moment.end <= HEATED_MERGE_GAP => {
+                moment.end = moment.end.max(start);
+                moment.peak_tension = moment.peak_tension.max(segment.tension);
+                moment.segment_count += 1;
+                if let Some(speaker) = &segment.speaker {
+                    if !moment.speakers.contains(speaker) {
+                        moment.speakers.push(speaker.clone());
+                    }
+                }
+            }
+            _ => moments.push(HeatedMoment {
+                start,
+                end: start,
+                peak_tension: segment.tension,
+                segment_count: 1,
+                speakers: segment.speaker.iter().cloned().collect(),
+            }),
+        }
+    }
+    moments
+}
+
+#[derive(Debug, Deserialize)]
+struct LlmToneItem {
+    i: usize,
+    sentiment: String,
+    tension: f64,
+}
+
+/// Parse the LLM's JSON array reply, tolerating surrounding prose or code fences
+fn parse_llm_tones(reply: &str, batch_len: usize) -> Option<Vec<Option<Tone>>> {
+    let start = reply.find('[')?;
+    let end = reply.rfind(']')?;
+    let items: Vec<LlmToneItem> = serde_json::from_str(reply.get(start..=end)?).ok()?;
+
+    let mut tones = vec![None; batch_len];
+    for item in items {
+        if let (Some(slot), Some(sentiment)) = (tones.get_mut(item.i), Sentiment::parse(&item.sentiment)) {
+            *slot = Some(Tone {
+                sentiment,
+                tension: item.tension.clamp(0.0, 1.0),
+            });
+        }
+    }
+    Some(tones)
+}
+
+const LLM_SYSTEM_PROMPT: &str = "You rate the tone of meeting transcript lines. For each numbered line, \
+return its sentiment (positive, neutral or negative) and a tension score from 0.0 (calm) to 1.0 \
+(heated argument or open frustration). Reply with only a JSON array like \
+[{\"i\":0,\"sentiment\":\"neutral\",\"tension\":0.1}], one object per line.";
+
+/// Classify segments with the configured summary model, falling back to the lexicon per batch
+async fn classify_with_llm<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    texts: &[(Option<String>, String)],
+) -> Result<Vec<Tone>, String> {
+    let settings = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load model settings: {}", e))?
+        .ok_or_else(|| "No summary model configured".to_string())?;
+    let provider = LLMProvider::from_str(&settings.provider)?;
+    let app_data_dir = app.path().app_data_dir().ok();
+    let summary_provider =
+        SummaryService::build_summary_provider(pool, provider, &settings.provider, &settings.model, app_data_dir)
+            .await?;
+    let (summary_provider, _) = redaction::protect(pool, summary_provider, Some(meeting_id)).await?;
+    let summary_provider = metered(summary_provider, pool, Some(meeting_id), "tone");
+
+    let mut tones = Vec::with_capacity(texts.len());
+    let batches: Vec<_> = texts.chunks(LLM_BATCH_SIZE).collect();
+    for (batch_index, batch) in batches.iter().enumerate() {
+        let lines = batch
+            .iter()
+            .enumerate()
+            .map(|(i, (speaker, text))| match speaker {
+                Some(speaker) => format!("{}. {}: {}", i, speaker, text.trim()),
+                None => format!("{}. {}", i, text.trim()),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let reply = summary_provider.complete(LLM_SYSTEM_PROMPT, &lines, None).await;
+
+        let parsed = match reply {
+            Ok(reply) => parse_llm_tones(&reply, batch.len()),
+            Err(e) => {
+                warn!("Tone analysis request failed for batch {}: {}", batch_index, e);
+                None
+            }
+        };
+        if parsed.is_none() {
+            warn!("Using local tone classifier for batch {} of {}", batch_index, meeting_id);
+        }
+        let parsed = parsed.unwrap_or_else(|| vec![None; batch.len()]);
+        for ((_, text), tone) in batch.iter().zip(parsed) {
+            tones.push(tone.unwrap_or_else(|| classify_lexicon(text)));
+        }
+
+        let _ = app.emit(
+            "meeting-tone-progress",
+            serde_json::json!({
+                "meeting_id": meeting_id,
+                "completed_batches": batch_index + 1,
+                "total_batches": batches.len(),
+            }),
+        );
+    }
+    Ok(tones)
+}
+
+fn build_report(method: &str, segments: Vec<SegmentTone>) -> ToneReport {
+    let heated_moments = find_heated_moments(&segments);
+    ToneReport {
+        method: method.to_string(),
+        segments,
+        heated_moments,
+    }
+}
+
+/// Run the tone pass over a meeting and store the result per segment.
+/// `method` is "lexicon" (local, default) or "llm" (configured summary model).
+#[command]
+pub async fn analyze_meeting_tone<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    method: Option<String>,
+) -> Result<ToneReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let method = method.unwrap_or_else(|| "lexicon".to_string());
+
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    let texts: Vec<(Option<String>, String)> = segments
+        .iter()
+        .map(|(_, text, _, speaker)| (speaker.clone(), text.clone()))
+        .collect();
+
+    let tones = match method.as_str() {
+        "lexicon" => texts.iter().map(|(_, text)| classify_lexicon(text)).collect(),
+        "llm" => classify_with_llm(&app, pool, &meeting_id, &texts).await?,
+        other => return Err(format!("Unknown tone analysis method: {}", other)),
+    };
+
+    let results: Vec<SegmentTone> = segments
+        .into_iter()
+        .zip(tones)
+        .map(|((transcript_id, _, audio_start_time, speaker), tone)| SegmentTone {
+            transcript_id,
+            audio_start_time,
+            speaker,
+            sentiment: tone.sentiment,
+            tension: tone.tension,
+        })
+        .collect();
+
+    let rows: Vec<(String, String, f64)> = results
+        .iter()
+        .map(|s| (s.transcript_id.clone(), s.sentiment.as_str().to_string(), s.tension))
+        .collect();
+    TranscriptsRepository::save_segment_tones(pool, &rows)
+        .await
+        .map_err(|e| format!("Failed to save tone analysis: {}", e))?;
+
+    let report = build_report(&method, results);
+    info!(
+        "Tone analysis ({}) for {}: {} segments, {} heated moment(s)",
+        method,
+        meeting_id,
+        report.segments.len(),
+        report.heated_moments.len()
+    );
+    Ok(report)
+}
+
+/// Previously stored tone analysis of a meeting (segments not yet analyzed are omitted)
+#[command]
+pub async fn get_meeting_tone<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<ToneReport, String> {
+    let state = app.state::<AppState>();
+    let meeting = crate::database::repositories::meeting::MeetingsRepository::get_meeting(
+        state.db_manager.pool(),
+        &meeting_id,
+    )
+    .await
+    .map_err(|e| format!("Failed to load meeting: {}", e))?
+    .ok_or_else(|| format!("Meeting {} not found", meeting_id))?;
+
+    let segments: Vec<SegmentTone> = meeting
+        .transcripts
+        .into_iter()
+        .filter_map(|t| {
+            let sentiment = t.sentiment.as_deref().and_then(Sentiment::parse)?;
+            Some(SegmentTone {
+                transcript_id: t.id,
+                audio_start_time: t.audio_start_time,
+                speaker: t.speaker_label.or(t.speaker),
+                sentiment,
+                tension: t.tension.unwrap_or(0.0),
+            })
+        })
+        .collect();
+    Ok(build_report("stored", segments))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexicon_sentiment_and_negation() {
+        assert_eq!(classify_lexicon("Great progress, thanks everyone").sentiment, Sentiment::Positive);
+        assert_eq!(classify_lexicon("This is not good").sentiment, Sentiment::Negative);
+        assert_eq!(classify_lexicon("Let's look at the next slide").sentiment, Sentiment::Neutral);
+
+        let heated = classify_lexicon("This is RIDICULOUS, I completely disagree!");
+        assert_eq!(heated.sentiment, Sentiment::Negative);
+        assert!(heated.tension >= HEATED_TENSION);
+    }
+
+    #[test]
+    fn test_heated_moments_merge_nearby_segments() {
+        let tone = |start: f64, tension: f64, speaker: &str| SegmentTone {
+            transcript_id: format!("t{}", start),
+            audio_start_time: Some(start),
+            speaker: Some(speaker.to_string()),
+            sentiment: Sentiment::Negative,
+            tension,
+        };
+        let moments = find_heated_moments(&[
+            tone(10.0, 0.8, "Me"),
+            tone(20.0, 0.2, "Remote"),
+            tone(25.0, 0.7, "Remote"),
+            tone(300.0, 0.9, "Me"),
+        ]);
+        assert_eq!(moments.len(), 2);
+        assert_eq!(moments[0].segment_count, 2);
+        assert_eq!(moments[0].speakers, vec!["Me".to_string(), "Remote".to_string()]);
+        assert_eq!(moments[1].peak_tension, 0.9);
+    }
+
+    #[test]
+    fn test_parse_llm_reply_with_code_fence() {
+        let reply = "```json\n[{\"i\":1,\"sentiment\":\"Negative\",\"tension\":1.4}]\n```";
+        let tones = parse_llm_tones(reply, 2).unwrap();
+        assert!(tones[0].is_none());
+        assert_eq!(tones[1], Some(Tone { sentiment: Sentiment::Negative, tension: 1.0 }));
+    }
+}
@@ -0,0 +1,242 @@
+// summary/usage.rs
+//
+// LLM usage and cost tracking. Providers are wrapped in `MeteredProvider`, which records
+// every completion (provider, model, operation, meeting) with token counts estimated from
+// the prompt and reply, priced from a table of published per-token prices. Local models
+// cost nothing; models missing from the table are counted but left unpriced.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::database::models::LlmUsageTotals;
+use crate::database::repositories::llm_usage::{LlmUsageRepository, NewLlmUsage};
+use crate::state::AppState;
+use crate::summary::processor::rough_token_count;
+use crate::summary::provider::{SummaryError, SummaryProvider, TokenCallback};
+
+/// USD per million (input, output) tokens, matched against the model name in order, so
+/// smaller variants come before their base model
+const PRICES: &[(&str, f64, f64)] = &[
+    // OpenAI
+    ("gpt-4.1-nano", 0.10, 0.40),
+    ("gpt-4.1-mini", 0.40, 1.60),
+    ("gpt-4.1", 2.00, 8.00),
+    ("gpt-4o-mini", 0.15, 0.60),
+    ("gpt-4o", 2.50, 10.00),
+    ("gpt-4-turbo", 10.00, 30.00),
+    ("gpt-5-nano", 0.05, 0.40),
+    ("gpt-5-mini", 0.25, 2.00),
+    ("gpt-5", 1.25, 10.00),
+    ("gpt-3.5-turbo", 0.50, 1.50),
+    ("o4-mini", 1.10, 4.40),
+    ("o3-mini", 1.10, 4.40),
+    ("o3", 2.00, 8.00),
+    // Anthropic
+    ("claude-3-haiku", 0.25, 1.25),
+    ("haiku", 0.80, 4.00),
+    ("sonnet", 3.00, 15.00),
+    ("opus", 15.00, 75.00),
+    // Google
+    ("gemini-2.5-flash-lite", 0.10, 0.40),
+    ("gemini-2.5-flash", 0.30, 2.50),
+    ("gemini-2.5-pro", 1.25, 10.00),
+    ("gemini-2.0-flash", 0.10, 0.40),
+    ("gemini-1.5-flash", 0.075, 0.30),
+    ("gemini-1.5-pro", 1.25, 5.00),
+    // Groq
+    ("llama-3.3-70b", 0.59, 0.79),
+    ("llama-3.1-8b", 0.05, 0.08),
+];
+
+/// Estimated cost in USD of one request; None when the model's price isn't known
+pub fn estimate_cost(provider: &str, model: &str, prompt_tokens: i64, completion_tokens: i64) -> Option<f64> {
+    if matches!(provider, "Ollama" | "Built-in AI") {
+        return Some(0.0);
+    }
+    // Self-hosted endpoints have no list price
+    if provider == "Custom OpenAI" {
+        return None;
+    }
+    let model = model.to_lowercase();
+    let (_, input, output) = PRICES.iter().find(|(name, _, _)| model.contains(name))?;
+    Some((prompt_tokens as f64 * input + completion_tokens as f64 * output) / 1_000_000.0)
+}
+
+/// SummaryProvider that records the usage of every completion it runs
+pub struct MeteredProvider {
+    inner: Box<dyn SummaryProvider>,
+    pool: SqlitePool,
+    meeting_id: Option<String>,
+    operation: &'static str,
+}
+
+/// Wrap a provider so its requests are recorded against `meeting_id` as `operation`
+/// ("minutes", "action_items", "question", ...)
+pub fn metered(
+    provider: Box<dyn SummaryProvider>,
+    pool: &SqlitePool,
+    meeting_id: Option<&str>,
+    operation: &'static str,
+) -> Box<dyn SummaryProvider> {
+    Box::new(MeteredProvider {
+        inner: provider,
+        pool: pool.clone(),
+        meeting_id: meeting_id.map(str::to_string),
+        operation,
+    })
+}
+
+impl MeteredProvider {
+    async fn record(&self, system_prompt: &str, user_prompt: &str, reply: &str) {
+        let prompt_tokens = (rough_token_count(system_prompt) + rough_token_count(user_prompt)) as i64;
+        let completion_tokens = rough_token_count(reply) as i64;
+        let provider = self.inner.provider_name();
+        let model = self.inner.model_name();
+        let usage = NewLlmUsage {
+            meeting_id: self.meeting_id.as_deref(),
+            provider,
+            model,
+            operation: self.operation,
+            prompt_tokens,
+            completion_tokens,
+            cost_usd: estimate_cost(provider, model, prompt_tokens, completion_tokens),
+        };
+        // Usage is informational; a failed insert shouldn't fail the request
+        if let Err(e) = LlmUsageRepository::insert(&self.pool, &usage).await {
+            warn!("Failed to record LLM usage for {}: {}", self.operation, e);
+        }
+    }
+}
+
+#[async_trait]
+impl SummaryProvider for MeteredProvider {
+    async fn complete(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let reply = self.inner.complete(system_prompt, user_prompt, cancellation_token).await?;
+        self.record(system_prompt, user_prompt, &reply).await;
+        Ok(reply)
+    }
+
+    async fn complete_streaming(
+        &self,
+        system_prompt: &str,
+        user_prompt: &str,
+        on_token: &TokenCallback,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> std::result::Result<String, SummaryError> {
+        let reply = self
+            .inner
+            .complete_streaming(system_prompt, user_prompt, on_token, cancellation_token)
+            .await?;
+        self.record(system_prompt, user_prompt, &reply).await;
+        Ok(reply)
+    }
+
+    fn context_tokens(&self) -> Option<usize> {
+        self.inner.context_tokens()
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+
+    fn provider_name(&self) -> &'static str {
+        self.inner.provider_name()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageReport {
+    /// The meeting reported on; None for all usage
+    pub meeting_id: Option<String>,
+    pub calls: i64,
+    pub prompt_tokens: i64,
+    pub completion_tokens: i64,
+    /// Estimated USD over the priced requests
+    pub cost_usd: f64,
+    /// Requests to models without a known price
+    pub unpriced_calls: i64,
+    pub by_model: Vec<LlmUsageTotals>,
+    pub by_operation: Vec<LlmUsageTotals>,
+    /// Per-meeting totals (only in the report over all usage)
+    pub by_meeting: Vec<LlmUsageTotals>,
+}
+
+fn sum_totals(groups: &[LlmUsageTotals]) -> (i64, i64, i64, f64, i64) {
+    groups.iter().fold((0, 0, 0, 0.0, 0), |acc, group| {
+        (
+            acc.0 + group.calls,
+            acc.1 + group.prompt_tokens,
+            acc.2 + group.completion_tokens,
+            acc.3 + group.cost_usd,
+            acc.4 + group.unpriced_calls,
+        )
+    })
+}
+
+/// Summary-model usage and estimated cost for one meeting, or for everything when
+/// `meeting_id` is None
+#[command]
+pub async fn get_llm_usage_report<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: Option<String>,
+) -> Result<UsageReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let meeting_id = meeting_id.filter(|id| !id.trim().is_empty());
+
+    let by_model = LlmUsageRepository::totals_by_model(pool, meeting_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to load LLM usage: {}", e))?;
+    let by_operation = LlmUsageRepository::totals_by_operation(pool, meeting_id.as_deref())
+        .await
+        .map_err(|e| format!("Failed to load LLM usage: {}", e))?;
+    let by_meeting = match meeting_id {
+        Some(_) => Vec::new(),
+        None => LlmUsageRepository::totals_by_meeting(pool)
+            .await
+            .map_err(|e| format!("Failed to load LLM usage: {}", e))?,
+    };
+
+    let (calls, prompt_tokens, completion_tokens, cost_usd, unpriced_calls) = sum_totals(&by_model);
+    Ok(UsageReport {
+        meeting_id,
+        calls,
+        prompt_tokens,
+        completion_tokens,
+        cost_usd,
+        unpriced_calls,
+        by_model,
+        by_operation,
+        by_meeting,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_cost() {
+        // 10k prompt + 1k completion tokens on gpt-4o-mini: 0.0015 + 0.0006
+        let cost = estimate_cost("OpenAI", "gpt-4o-mini", 10_000, 1_000).unwrap();
+        assert!((cost - 0.0021).abs() < 1e-9);
+        // Smaller variants aren't priced as their base model
+        let cost = estimate_cost("Claude", "claude-3-5-haiku-latest", 1_000_000, 0).unwrap();
+        assert!((cost - 0.80).abs() < 1e-9);
+        let cost = estimate_cost("OpenRouter", "openai/gpt-4o", 1_000_000, 0).unwrap();
+        assert!((cost - 2.50).abs() < 1e-9);
+
+        assert_eq!(estimate_cost("Ollama", "llama3.2:latest", 50_000, 5_000), Some(0.0));
+        assert_eq!(estimate_cost("Custom OpenAI", "gpt-4o", 1_000, 100), None);
+        assert_eq!(estimate_cost("OpenRouter", "some/new-model", 1_000, 100), None);
+    }
+}
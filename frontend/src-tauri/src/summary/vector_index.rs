@@ -0,0 +1,337 @@
+// summary/vector_index.rs
+//
+// Per-meeting vector index of transcript segment embeddings, kept on disk under
+// `<app data>/vector_index/`: `<meeting id>.json` holds the model and one entry per
+// segment, `<meeting id>.vec` the unit-length vectors as little-endian f32. Updates are
+// incremental: only segments whose text changed (or that are new) are embedded again,
+// and switching embedding models rebuilds the index.
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+use crate::summary::embeddings::{configured_embedding_provider, EmbeddingProvider};
+
+/// Segments embedded per request
+const EMBED_BATCH_SIZE: usize = 32;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub segment_id: String,
+    pub audio_start_time: Option<f64>,
+    /// Hash of the embedded text, to spot edited segments
+    pub text_hash: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexMetadata {
+    model: String,
+    dimensions: usize,
+    entries: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorIndex {
+    pub model: String,
+    pub dimensions: usize,
+    pub entries: Vec<IndexEntry>,
+    vectors: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingIndexStatus {
+    pub model: String,
+    pub dimensions: usize,
+    pub segment_count: usize,
+    /// Segments embedded by this update (the rest were reused)
+    pub embedded: usize,
+}
+
+/// Stable 64-bit FNV-1a hash (std's hasher may change between Rust releases)
+fn text_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+    vector
+}
+
+impl VectorIndex {
+    pub fn new(model: &str) -> Self {
+        Self {
+            model: model.to_string(),
+            dimensions: 0,
+            entries: Vec::new(),
+            vectors: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn vector(&self, index: usize) -> &[f32] {
+        &self.vectors[index * self.dimensions..(index + 1) * self.dimensions]
+    }
+
+    /// Add a segment; the first vector fixes the index's dimensions
+    pub fn push(&mut self, entry: IndexEntry, vector: Vec<f32>) -> Result<(), String> {
+        if self.entries.is_empty() {
+            self.dimensions = vector.len();
+        }
+        if vector.len() != self.dimensions || vector.is_empty() {
+            return Err(format!(
+                "Embedding has {} dimensions, the index uses {}",
+                vector.len(),
+                self.dimensions
+            ));
+        }
+        self.entries.push(entry);
+        self.vectors.extend(normalize(vector));
+        Ok(())
+    }
+
+    /// The `limit` segments most similar to `query` as (entry index, cosine similarity),
+    /// best first
+    pub fn search(&self, query: &[f32], limit: usize) -> Vec<(usize, f32)> {
+        if query.len() != self.dimensions {
+            return Vec::new();
+        }
+        let query = normalize(query.to_vec());
+        let mut scored: Vec<(usize, f32)> = (0..self.len())
+            .map(|i| (i, self.vector(i).iter().zip(&query).map(|(a, b)| a * b).sum()))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scored.truncate(limit);
+        scored
+    }
+
+    fn paths(dir: &Path, meeting_id: &str) -> (PathBuf, PathBuf) {
+        (dir.join(format!("{}.json", meeting_id)), dir.join(format!("{}.vec", meeting_id)))
+    }
+
+    /// Load a meeting's index; None when it doesn't exist or is incomplete
+    pub fn load(dir: &Path, meeting_id: &str) -> std::io::Result<Option<Self>> {
+        let (metadata_path, vectors_path) = Self::paths(dir, meeting_id);
+        if !metadata_path.exists() || !vectors_path.exists() {
+            return Ok(None);
+        }
+        let metadata: IndexMetadata = match serde_json::from_slice(&std::fs::read(&metadata_path)?) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                warn!("Ignoring unreadable vector index for {}: {}", meeting_id, e);
+                return Ok(None);
+            }
+        };
+        let vectors: Vec<f32> = std::fs::read(&vectors_path)?
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        if vectors.len() != metadata.entries.len() * metadata.dimensions {
+            warn!("Vector index for {} is incomplete, rebuilding", meeting_id);
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            model: metadata.model,
+            dimensions: metadata.dimensions,
+            entries: metadata.entries,
+            vectors,
+        }))
+    }
+
+    pub fn save(&self, dir: &Path, meeting_id: &str) -> std::io::Result<()> {
+        std::fs::create_dir_all(dir)?;
+        let (metadata_path, vectors_path) = Self::paths(dir, meeting_id);
+        let bytes: Vec<u8> = self.vectors.iter().flat_map(|x| x.to_le_bytes()).collect();
+        std::fs::write(vectors_path, bytes)?;
+        let metadata = IndexMetadata {
+            model: self.model.clone(),
+            dimensions: self.dimensions,
+            entries: self.entries.clone(),
+        };
+        std::fs::write(metadata_path, serde_json::to_vec(&metadata)?)
+    }
+
+    pub fn delete(dir: &Path, meeting_id: &str) -> std::io::Result<()> {
+        let (metadata_path, vectors_path) = Self::paths(dir, meeting_id);
+        for path in [metadata_path, vectors_path] {
+            if path.exists() {
+                std::fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Directory holding all meeting indexes
+pub fn index_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("vector_index")
+}
+
+/// Bring a meeting's index up to date with its transcript, embedding only new or edited
+/// segments. Returns the index and how many segments were embedded.
+pub async fn update_meeting_index(
+    pool: &SqlitePool,
+    dir: &Path,
+    meeting_id: &str,
+    provider: &dyn EmbeddingProvider,
+) -> Result<(VectorIndex, usize), String> {
+    let segments = TranscriptsRepository::list_segments_for_analysis(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load transcripts: {}", e))?;
+    let existing = VectorIndex::load(dir, meeting_id)
+        .map_err(|e| format!("Failed to read vector index: {}", e))?
+        .filter(|index| index.model == provider.model_name());
+
+    // Speaker names are part of the text so "what did Ana say" finds Ana's segments
+    let texts: Vec<String> = segments
+        .iter()
+        .map(|(_, text, _, speaker)| match speaker {
+            Some(speaker) => format!("{}: {}", speaker, text.trim()),
+            None => text.trim().to_string(),
+        })
+        .collect();
+
+    let reusable = |segment_id: &str, hash: u64| {
+        existing.as_ref().and_then(|index| {
+            index
+                .entries
+                .iter()
+                .position(|entry| entry.segment_id == segment_id && entry.text_hash == hash)
+                .map(|i| index.vector(i).to_vec())
+        })
+    };
+    let mut vectors: Vec<Option<Vec<f32>>> = segments
+        .iter()
+        .zip(&texts)
+        .map(|((id, _, _, _), text)| reusable(id, text_hash(text)))
+        .collect();
+
+    let missing: Vec<usize> = (0..segments.len()).filter(|&i| vectors[i].is_none()).collect();
+    for batch in missing.chunks(EMBED_BATCH_SIZE) {
+        let batch_texts: Vec<String> = batch.iter().map(|&i| texts[i].clone()).collect();
+        let embedded = provider
+            .embed(&batch_texts)
+            .await
+            .map_err(|e| format!("Embedding failed: {}", e))?;
+        for (&i, vector) in batch.iter().zip(embedded) {
+            vectors[i] = Some(vector);
+        }
+    }
+
+    let mut index = VectorIndex::new(provider.model_name());
+    for (((id, _, start, _), text), vector) in segments.iter().zip(&texts).zip(vectors) {
+        let entry = IndexEntry {
+            segment_id: id.clone(),
+            audio_start_time: *start,
+            text_hash: text_hash(text),
+        };
+        index.push(entry, vector.unwrap_or_default())?;
+    }
+    if !missing.is_empty() || existing.as_ref().map(VectorIndex::len) != Some(index.len()) {
+        index
+            .save(dir, meeting_id)
+            .map_err(|e| format!("Failed to save vector index: {}", e))?;
+    }
+    Ok((index, missing.len()))
+}
+
+/// Index a just-saved meeting in the background when an embedding model is configured
+pub fn spawn_index_update<R: Runtime>(app: &AppHandle<R>, meeting_id: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let pool = app.state::<AppState>().db_manager.pool().clone();
+        let provider = match configured_embedding_provider(&pool).await {
+            Ok(Some(provider)) => provider,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Skipping vector index for {}: {}", meeting_id, e);
+                return;
+            }
+        };
+        let app_data_dir = match app.path().app_data_dir() {
+            Ok(app_data_dir) => app_data_dir,
+            Err(_) => return,
+        };
+        match update_meeting_index(&pool, &index_dir(&app_data_dir), &meeting_id, provider.as_ref()).await {
+            Ok((index, embedded)) => info!("Indexed {} ({} segments, {} embedded)", meeting_id, index.len(), embedded),
+            Err(e) => warn!("Vector index for {} failed: {}", meeting_id, e),
+        }
+    });
+}
+
+/// Build or refresh a meeting's vector index with the configured embedding model
+#[command]
+pub async fn index_meeting_embeddings<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<MeetingIndexStatus, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let provider = configured_embedding_provider(pool)
+        .await?
+        .ok_or_else(|| "No embedding model configured".to_string())?;
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))?;
+
+    let (index, embedded) = update_meeting_index(pool, &index_dir(&app_data_dir), &meeting_id, provider.as_ref()).await?;
+    Ok(MeetingIndexStatus {
+        model: index.model,
+        dimensions: index.dimensions,
+        segment_count: index.entries.len(),
+        embedded,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str) -> IndexEntry {
+        IndexEntry {
+            segment_id: id.to_string(),
+            audio_start_time: Some(1.5),
+            text_hash: text_hash(id),
+        }
+    }
+
+    #[test]
+    fn test_search_and_round_trip() {
+        let mut index = VectorIndex::new("nomic-embed-text");
+        index.push(entry("budget"), vec![3.0, 0.0, 0.0]).unwrap();
+        index.push(entry("hiring"), vec![0.0, 2.0, 0.0]).unwrap();
+        index.push(entry("launch"), vec![1.0, 1.0, 0.0]).unwrap();
+        assert!(index.push(entry("bad"), vec![1.0, 0.0]).is_err());
+
+        let results = index.search(&[0.0, 5.0, 0.1], 2);
+        assert_eq!(results.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![1, 2]);
+        assert!((results[0].1 - 0.9998).abs() < 0.001);
+
+        let dir = std::env::temp_dir().join(format!("vector-index-test-{}", std::process::id()));
+        index.save(&dir, "meeting-1").unwrap();
+        let loaded = VectorIndex::load(&dir, "meeting-1").unwrap().unwrap();
+        assert_eq!(loaded.model, "nomic-embed-text");
+        assert_eq!(loaded.dimensions, 3);
+        assert_eq!(loaded.entries[2].segment_id, "launch");
+        assert_eq!(loaded.vector(0), &[1.0, 0.0, 0.0]);
+        VectorIndex::delete(&dir, "meeting-1").unwrap();
+        assert!(VectorIndex::load(&dir, "meeting-1").unwrap().is_none());
+        let _ = std::fs::remove_dir(&dir);
+    }
+}
@@ -0,0 +1,271 @@
+// summary/versions.rs
+//
+// Summary versioning. Each generated summary is saved as a numbered version with the
+// template, model and style that wrote it, so a meeting can be regenerated (another
+// template or model, or after the transcript was corrected) without losing earlier
+// minutes. Before the stored summary is replaced, it is saved too unless it already
+// matches the latest version, which keeps manual edits. Any two versions, or a version and
+// the current summary, can be compared as a line diff, and an old version restored.
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tracing::info;
+
+use crate::database::models::SummaryVersion;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::repositories::summary_version::{NewSummaryVersion, SummaryVersionsRepository};
+use crate::state::AppState;
+use crate::summary::follow_up::stored_minutes;
+use crate::summary::provider::SummaryProvider;
+use crate::summary::style::SummaryStyle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffKind {
+    Equal,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SummaryDiff {
+    pub from_version: i64,
+    /// None when compared against the current summary
+    pub to_version: Option<i64>,
+    pub added: usize,
+    pub removed: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Line diff of two texts from their longest common subsequence. Removed lines come before
+/// the added lines that replace them.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j]: common lines of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |kind, text: &str| DiffLine {
+        kind,
+        text: text.to_string(),
+    };
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(line(DiffKind::Equal, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(line(DiffKind::Removed, old[i]));
+            i += 1;
+        } else {
+            lines.push(line(DiffKind::Added, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|text| line(DiffKind::Removed, text)));
+    lines.extend(new[j..].iter().map(|text| line(DiffKind::Added, text)));
+    lines
+}
+
+async fn current_markdown(pool: &SqlitePool, meeting_id: &str) -> Result<Option<String>, sqlx::Error> {
+    let process = SummaryProcessesRepository::get_summary_data(pool, meeting_id).await?;
+    Ok(stored_minutes(process.as_ref().and_then(|p| p.result.as_deref())))
+}
+
+/// Save the meeting's current summary as a version unless it already is the latest one:
+/// "edited" when it changed since, "earlier" when it predates versioning
+pub async fn snapshot_current(pool: &SqlitePool, meeting_id: &str) -> Result<Option<i64>, sqlx::Error> {
+    let markdown = match current_markdown(pool, meeting_id).await? {
+        Some(markdown) => markdown,
+        None => return Ok(None),
+    };
+    let source = match SummaryVersionsRepository::latest_markdown(pool, meeting_id).await? {
+        Some(latest) if latest.trim() == markdown.trim() => return Ok(None),
+        Some(_) => "edited",
+        None => "earlier",
+    };
+    let version = SummaryVersionsRepository::insert(
+        pool,
+        &NewSummaryVersion {
+            meeting_id,
+            source,
+            markdown: &markdown,
+            template_id: None,
+            provider: None,
+            model: None,
+            style: None,
+        },
+    )
+    .await?;
+    info!("Saved current summary of {} as version {} ({})", meeting_id, version, source);
+    Ok(Some(version))
+}
+
+/// Save freshly generated minutes as the meeting's next version
+pub async fn record_generated(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    markdown: &str,
+    template_id: &str,
+    provider: &dyn SummaryProvider,
+    style: SummaryStyle,
+) -> Result<i64, sqlx::Error> {
+    SummaryVersionsRepository::insert(
+        pool,
+        &NewSummaryVersion {
+            meeting_id,
+            source: "generated",
+            markdown,
+            template_id: Some(template_id),
+            provider: Some(provider.provider_name()),
+            model: Some(provider.model_name()),
+            style: Some(style.as_str()),
+        },
+    )
+    .await
+}
+
+async fn load_version(pool: &SqlitePool, meeting_id: &str, version: i64) -> Result<SummaryVersion, String> {
+    SummaryVersionsRepository::get(pool, meeting_id, version)
+        .await
+        .map_err(|e| format!("Failed to load summary version: {}", e))?
+        .ok_or_else(|| format!("Summary version {} not found", version))
+}
+
+/// A meeting's summary versions, newest first
+#[command]
+pub async fn list_summary_versions<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+) -> Result<Vec<SummaryVersion>, String> {
+    let state = app.state::<AppState>();
+    SummaryVersionsRepository::list_for_meeting(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary versions: {}", e))
+}
+
+#[command]
+pub async fn get_summary_version<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    version: i64,
+) -> Result<SummaryVersion, String> {
+    let state = app.state::<AppState>();
+    load_version(state.db_manager.pool(), &meeting_id, version).await
+}
+
+/// What changed from `from_version` to `to_version`, or to the current summary when
+/// `to_version` is None
+#[command]
+pub async fn diff_summary_versions<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    from_version: i64,
+    to_version: Option<i64>,
+) -> Result<SummaryDiff, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let from = load_version(pool, &meeting_id, from_version).await?;
+    let to = match to_version {
+        Some(version) => load_version(pool, &meeting_id, version).await?.markdown,
+        None => current_markdown(pool, &meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load summary: {}", e))?
+            .unwrap_or_default(),
+    };
+
+    let lines = diff_lines(&from.markdown, &to);
+    let count = |kind| lines.iter().filter(|line| line.kind == kind).count();
+    Ok(SummaryDiff {
+        from_version,
+        to_version,
+        added: count(DiffKind::Added),
+        removed: count(DiffKind::Removed),
+        lines,
+    })
+}
+
+/// Make an earlier version the meeting's summary again; the summary it replaces is saved
+/// as a version first
+#[command]
+pub async fn restore_summary_version<R: Runtime>(
+    app: AppHandle<R>,
+    meeting_id: String,
+    version: i64,
+) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+
+    let restored = load_version(pool, &meeting_id, version).await?;
+    snapshot_current(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to save the current summary: {}", e))?;
+
+    // Markdown only, like freshly generated minutes; the editor rebuilds its blocks from it
+    let summary = serde_json::json!({ "markdown": restored.markdown });
+    let saved = SummaryProcessesRepository::update_meeting_summary(pool, &meeting_id, &summary)
+        .await
+        .map_err(|e| format!("Failed to restore summary version: {}", e))?;
+    if !saved {
+        return Err("Meeting not found".to_string());
+    }
+    info!("Restored summary version {} for {}", version, meeting_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let old = "# Minutes\n- Ship v2\n- Hire designer\nNotes";
+        let new = "# Minutes\n- Ship v2 on Friday\n- Hire designer\nNotes\n- Budget approved";
+        let diff = diff_lines(old, new);
+        let rendered: Vec<String> = diff
+            .iter()
+            .map(|line| {
+                let mark = match line.kind {
+                    DiffKind::Equal => ' ',
+                    DiffKind::Added => '+',
+                    DiffKind::Removed => '-',
+                };
+                format!("{}{}", mark, line.text)
+            })
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                " # Minutes",
+                "-- Ship v2",
+                "+- Ship v2 on Friday",
+                " - Hire designer",
+                " Notes",
+                "+- Budget approved",
+            ]
+        );
+
+        assert!(diff_lines("same\ntext", "same\ntext").iter().all(|line| line.kind == DiffKind::Equal));
+        assert_eq!(diff_lines("", "new").len(), 1);
+    }
+}
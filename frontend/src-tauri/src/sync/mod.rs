@@ -0,0 +1,539 @@
+// Sync to user-provided storage
+// Optional two-way sync of meetings through an S3 bucket or WebDAV share the user controls.
+// Everything is encrypted with a passphrase before it leaves the machine, so the storage
+// only ever holds opaque objects. Recordings stay local; with exports on, each meeting's
+// Markdown minutes are uploaded next to its record.
+//
+// Layout under the configured folder: index.mmsync (every record's hash and last write),
+// meetings/<id>.mmsync and exports/<id>.md.mmsync.
+
+pub mod record;
+pub mod remote;
+
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::meeting_sync::MeetingSyncRepository;
+use crate::database::repositories::protected_meeting::ProtectedMeetingsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::export::document::MeetingDocument;
+use crate::export::load_export_settings;
+use crate::export::markdown::{render_markdown, DEFAULT_MARKDOWN_TEMPLATE};
+use crate::state::{self, AppState};
+use record::{
+    decide, open, seal, LocalState, RemoteEntry, RemoteIndex, SyncAction, SyncedHashes, SyncedMeeting, RECORD_FORMAT,
+};
+use remote::SyncRemote;
+
+const MIN_PASSPHRASE_CHARS: usize = 8;
+const DEFAULT_FOLDER: &str = "meeting-minutes";
+const DEFAULT_INTERVAL_MINUTES: u32 = 30;
+/// How often the background task checks whether a sync is due
+const TICK: Duration = Duration::from_secs(60);
+
+// One sync at a time, whether started by hand or by the background task
+static SYNC_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// Where synced data is stored
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SyncTarget {
+    S3 {
+        /// e.g. "https://s3.eu-west-1.amazonaws.com" or "http://nas.local:9000"
+        endpoint: String,
+        region: String,
+        bucket: String,
+        access_key_id: String,
+        secret_access_key: String,
+        /// Address the bucket in the URL path instead of the host name
+        #[serde(default)]
+        path_style: bool,
+    },
+    Webdav {
+        /// Share URL, e.g. "https://cloud.example.com/remote.php/dav/files/me"
+        url: String,
+        username: String,
+        password: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncSettings {
+    pub enabled: bool,
+    pub target: SyncTarget,
+    /// Folder in the bucket or share; machines using the same folder sync with each other
+    #[serde(default = "default_folder")]
+    pub folder: String,
+    /// Encrypts everything uploaded; every machine needs the same one
+    pub passphrase: String,
+    #[serde(default = "default_interval")]
+    pub interval_minutes: u32,
+    /// Also upload each meeting's Markdown export
+    #[serde(default)]
+    pub include_exports: bool,
+}
+
+fn default_folder() -> String {
+    DEFAULT_FOLDER.to_string()
+}
+
+fn default_interval() -> u32 {
+    DEFAULT_INTERVAL_MINUTES
+}
+
+impl SyncSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+            return Err(format!("Use a sync passphrase of at least {} characters", MIN_PASSPHRASE_CHARS));
+        }
+        if !(5..=24 * 60).contains(&self.interval_minutes) {
+            return Err("Sync every 5 minutes to once a day".to_string());
+        }
+        folder_path(&self.folder)?;
+        let required = match &self.target {
+            SyncTarget::S3 {
+                endpoint,
+                region,
+                bucket,
+                access_key_id,
+                secret_access_key,
+                ..
+            } => vec![
+                ("endpoint", endpoint),
+                ("region", region),
+                ("bucket", bucket),
+                ("access key", access_key_id),
+                ("secret key", secret_access_key),
+            ],
+            SyncTarget::Webdav { url, username, .. } => vec![("URL", url), ("username", username)],
+        };
+        match required.iter().find(|(_, value)| value.trim().is_empty()) {
+            Some((name, _)) => Err(format!("The storage {} is required", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The folder as a "/"-separated path without empty, "." or ".." parts
+fn folder_path(folder: &str) -> Result<String, String> {
+    let parts: Vec<&str> = folder.split(['/', '\\']).map(str::trim).filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() || parts.iter().any(|part| *part == "." || *part == "..") {
+        return Err(format!("Invalid sync folder: {}", folder));
+    }
+    Ok(parts.join("/"))
+}
+
+/// The folder and each of its parents, outermost first, then its subfolders
+fn folders_to_create(folder: &str) -> Vec<String> {
+    let parts: Vec<&str> = folder.split('/').collect();
+    let mut folders: Vec<String> = (1..=parts.len()).map(|count| parts[..count].join("/")).collect();
+    folders.push(format!("{}/meetings", folder));
+    folders.push(format!("{}/exports", folder));
+    folders
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub meeting_id: String,
+    /// "local" or "remote": the side whose later write was kept
+    pub kept: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncReport {
+    pub pushed: usize,
+    pub pulled: usize,
+    pub deleted_local: usize,
+    pub deleted_remote: usize,
+    /// Meetings changed on both sides since the last sync
+    pub conflicts: Vec<SyncConflict>,
+    /// Meetings that failed to sync; the rest still did
+    pub errors: Vec<String>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+struct SyncContext<'a> {
+    pool: &'a SqlitePool,
+    remote: &'a dyn SyncRemote,
+    settings: &'a SyncSettings,
+    folder: String,
+}
+
+impl SyncContext<'_> {
+    fn index_key(&self) -> String {
+        format!("{}/index.mmsync", self.folder)
+    }
+
+    fn meeting_key(&self, meeting_id: &str) -> String {
+        format!("{}/meetings/{}.mmsync", self.folder, meeting_id)
+    }
+
+    fn export_key(&self, meeting_id: &str) -> String {
+        format!("{}/exports/{}.md.mmsync", self.folder, meeting_id)
+    }
+
+    async fn fetch_index(&self) -> Result<Option<RemoteIndex>, String> {
+        self.remote
+            .get(&self.index_key())
+            .await?
+            .map(|data| open(&data, &self.settings.passphrase))
+            .transpose()
+    }
+
+    async fn push(&self, record: &SyncedMeeting, hash: &str) -> Result<(), String> {
+        let meeting_id = &record.meeting.id;
+        self.remote
+            .put(&self.meeting_key(meeting_id), seal(record, &self.settings.passphrase)?)
+            .await?;
+        if self.settings.include_exports {
+            let template = load_export_settings(self.pool)
+                .await?
+                .markdown_template
+                .unwrap_or_else(|| DEFAULT_MARKDOWN_TEMPLATE.to_string());
+            let markdown = render_markdown(&MeetingDocument::load(self.pool, meeting_id).await?, &template);
+            self.remote
+                .put(&self.export_key(meeting_id), seal(&markdown, &self.settings.passphrase)?)
+                .await?;
+        }
+        MeetingSyncRepository::save_hashes(
+            self.pool,
+            meeting_id,
+            &SyncedHashes {
+                local_hash: hash.to_string(),
+                remote_hash: hash.to_string(),
+            },
+        )
+        .await
+        .map_err(|e| format!("Failed to save sync state: {}", e))
+    }
+
+    /// Delete the meeting's record and export from the storage, mark it deleted in the
+    /// index and forget its sync state
+    async fn push_deletion(&self, index: &mut RemoteIndex, meeting_id: &str, content_hash: &str) -> Result<(), String> {
+        self.remote.delete(&self.meeting_key(meeting_id)).await?;
+        self.remote.delete(&self.export_key(meeting_id)).await?;
+        index.meetings.insert(
+            meeting_id.to_string(),
+            RemoteEntry {
+                content_hash: content_hash.to_string(),
+                modified_at: Utc::now(),
+                deleted: true,
+            },
+        );
+        MeetingSyncRepository::forget(self.pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to save sync state: {}", e))
+    }
+
+    async fn pull(&self, meeting_id: &str, entry: &RemoteEntry) -> Result<(), String> {
+        let data = self
+            .remote
+            .get(&self.meeting_key(meeting_id))
+            .await?
+            .ok_or_else(|| "the synced record is missing".to_string())?;
+        let record: SyncedMeeting = open(&data, &self.settings.passphrase)?;
+        if record.format > RECORD_FORMAT {
+            return Err("synced by a newer version of the app; update to sync it".to_string());
+        }
+        if record.meeting.id != meeting_id {
+            return Err("the synced record belongs to another meeting".to_string());
+        }
+        MeetingSyncRepository::apply_record(self.pool, &record)
+            .await
+            .map_err(|e| format!("Failed to save synced meeting: {}", e))?;
+
+        // Hash what was actually stored, so the next sync doesn't see a local change
+        let stored = SyncedMeeting::load(self.pool, meeting_id)
+            .await?
+            .ok_or_else(|| "the synced meeting was not saved".to_string())?;
+        MeetingSyncRepository::save_hashes(
+            self.pool,
+            meeting_id,
+            &SyncedHashes {
+                local_hash: stored.content_hash()?,
+                remote_hash: entry.content_hash.clone(),
+            },
+        )
+        .await
+        .map_err(|e| format!("Failed to save sync state: {}", e))
+    }
+}
+
+/// Delete a meeting removed on another machine, with its index and attachments; it was
+/// already in the trash there
+async fn delete_local_meeting<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, meeting_id: &str) -> Result<(), String> {
+    crate::trash::purge_meeting(app, pool, meeting_id).await?;
+    Ok(())
+}
+
+/// Push local changes, pull remote ones and update the remote index
+async fn run_sync<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, settings: &SyncSettings) -> Result<SyncReport, String> {
+    let _running = SYNC_LOCK
+        .try_lock()
+        .map_err(|_| "A sync is already running".to_string())?;
+    let remote = remote::connect(&settings.target)?;
+    let context = SyncContext {
+        pool,
+        remote: remote.as_ref(),
+        settings,
+        folder: folder_path(&settings.folder)?,
+    };
+    remote.prepare(&folders_to_create(&context.folder)).await?;
+
+    let mut index = context.fetch_index().await?.unwrap_or_default();
+    let synced = MeetingSyncRepository::list_hashes(pool)
+        .await
+        .map_err(|e| format!("Failed to load sync state: {}", e))?;
+    // Meetings in the trash are still synced, so they can be restored on any machine
+    let local_ids: Vec<String> = MeetingsRepository::all_meeting_ids(pool)
+        .await
+        .map_err(|e| format!("Failed to load meetings: {}", e))?;
+    let meeting_ids: BTreeSet<&String> = local_ids.iter().chain(index.meetings.keys()).chain(synced.keys()).collect();
+    let meeting_ids: Vec<String> = meeting_ids.into_iter().cloned().collect();
+
+    let mut report = SyncReport::default();
+    for meeting_id in meeting_ids {
+        let result: Result<(), String> = async {
+            // Protected meetings stay on this machine, unlocked or not; a copy synced before
+            // the meeting was protected is deleted from the storage
+            let protected = ProtectedMeetingsRepository::get(pool, &meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load meeting protection: {}", e))?;
+            if protected.is_some() {
+                match index.meetings.get(&meeting_id).filter(|entry| !entry.deleted).cloned() {
+                    Some(entry) => {
+                        context.push_deletion(&mut index, &meeting_id, &entry.content_hash).await?;
+                        report.deleted_remote += 1;
+                    }
+                    None if synced.contains_key(&meeting_id) => {
+                        MeetingSyncRepository::forget(pool, &meeting_id)
+                            .await
+                            .map_err(|e| format!("Failed to save sync state: {}", e))?;
+                    }
+                    None => {}
+                }
+                return Ok(());
+            }
+            let record = match local_ids.contains(&meeting_id) {
+                true => SyncedMeeting::load(pool, &meeting_id).await?,
+                false => None,
+            };
+            let local = match &record {
+                Some(record) => Some(LocalState {
+                    content_hash: record.content_hash()?,
+                    modified_at: record.meeting.updated_at.0,
+                }),
+                None => None,
+            };
+            let hashes = synced.get(&meeting_id);
+            let entry = index.meetings.get(&meeting_id).cloned();
+            let (action, conflict) = decide(local.as_ref(), hashes, entry.as_ref());
+
+            match (action, &record, &local, &entry) {
+                (SyncAction::Push, Some(record), Some(local), _) => {
+                    context.push(record, &local.content_hash).await?;
+                    index.meetings.insert(
+                        meeting_id.clone(),
+                        RemoteEntry {
+                            content_hash: local.content_hash.clone(),
+                            modified_at: local.modified_at,
+                            deleted: false,
+                        },
+                    );
+                    report.pushed += 1;
+                }
+                (SyncAction::Pull, _, _, Some(entry)) => {
+                    context.pull(&meeting_id, entry).await?;
+                    crate::summary::vector_index::spawn_index_update(app, meeting_id.clone());
+                    report.pulled += 1;
+                }
+                (SyncAction::PushDeletion, _, _, Some(entry)) => {
+                    context.push_deletion(&mut index, &meeting_id, &entry.content_hash).await?;
+                    report.deleted_remote += 1;
+                }
+                (SyncAction::DeleteLocal, _, _, _) => {
+                    delete_local_meeting(app, pool, &meeting_id).await?;
+                    MeetingSyncRepository::forget(pool, &meeting_id)
+                        .await
+                        .map_err(|e| format!("Failed to save sync state: {}", e))?;
+                    report.deleted_local += 1;
+                }
+                (SyncAction::Record, _, Some(local), Some(entry)) => {
+                    let hashes = SyncedHashes {
+                        local_hash: local.content_hash.clone(),
+                        remote_hash: entry.content_hash.clone(),
+                    };
+                    MeetingSyncRepository::save_hashes(pool, &meeting_id, &hashes)
+                        .await
+                        .map_err(|e| format!("Failed to save sync state: {}", e))?;
+                }
+                // Gone on both sides: nothing left to remember
+                (SyncAction::Nothing, None, _, _) if hashes.is_some() => {
+                    MeetingSyncRepository::forget(pool, &meeting_id)
+                        .await
+                        .map_err(|e| format!("Failed to save sync state: {}", e))?;
+                }
+                _ => {}
+            }
+            if conflict {
+                let kept = if action == SyncAction::Pull { "remote" } else { "local" };
+                report.conflicts.push(SyncConflict {
+                    meeting_id: meeting_id.clone(),
+                    kept: kept.to_string(),
+                });
+            }
+            Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            warn!("Failed to sync meeting {}: {}", meeting_id, e);
+            report.errors.push(format!("{}: {}", meeting_id, e));
+        }
+    }
+
+    // Another machine may have synced meanwhile; keep its newer entries
+    if let Some(latest) = context.fetch_index().await? {
+        index.merge(latest);
+    }
+    remote
+        .put(&context.index_key(), seal(&index, &settings.passphrase)?)
+        .await?;
+
+    report.finished_at = Some(Utc::now());
+    info!(
+        "Sync finished: {} pushed, {} pulled, {} deleted here, {} deleted remotely, {} conflict(s), {} error(s)",
+        report.pushed,
+        report.pulled,
+        report.deleted_local,
+        report.deleted_remote,
+        report.conflicts.len(),
+        report.errors.len()
+    );
+    let _ = app.emit("sync-completed", &report);
+    Ok(report)
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<Option<SyncSettings>, String> {
+    SettingsRepository::get_sync_settings(pool)
+        .await
+        .map_err(|e| format!("Failed to load sync settings: {}", e))
+}
+
+/// Sync in the background every `interval_minutes` while sync is enabled
+pub fn start_sync_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let mut last_run: Option<Instant> = None;
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                match load_settings(&pool).await {
+                    Ok(Some(settings)) if settings.enabled => {
+                        let interval = Duration::from_secs(settings.interval_minutes as u64 * 60);
+                        if !last_run.is_some_and(|last_run| last_run.elapsed() < interval) {
+                            last_run = Some(Instant::now());
+                            if let Err(e) = run_sync(&app, &pool, &settings).await {
+                                warn!("Sync failed: {}", e);
+                                let _ = app.emit("sync-failed", e);
+                            }
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => warn!("{}", e),
+                }
+            }
+            tokio::time::sleep(TICK).await;
+        }
+    });
+}
+
+#[command]
+pub async fn get_sync_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<SyncSettings>, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+/// Save the sync configuration; pointing it at other storage or another folder starts
+/// over there, so every meeting is compared afresh at the next sync
+#[command]
+pub async fn save_sync_settings<R: Runtime>(app: AppHandle<R>, settings: SyncSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let previous = load_settings(pool).await?;
+    SettingsRepository::save_sync_settings(pool, &settings)
+        .await
+        .map_err(|e| format!("Failed to save sync settings: {}", e))?;
+    let moved = previous.is_some_and(|previous| {
+        previous.target != settings.target || folder_path(&previous.folder) != folder_path(&settings.folder)
+    });
+    if moved {
+        MeetingSyncRepository::forget_all(pool)
+            .await
+            .map_err(|e| format!("Failed to reset sync state: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Check the storage can be reached and the passphrase opens what is already there
+///
+/// # Returns
+/// How many meetings are stored remotely
+#[command]
+pub async fn test_sync_connection(settings: SyncSettings) -> Result<usize, String> {
+    settings.validate()?;
+    let remote = remote::connect(&settings.target)?;
+    let folder = folder_path(&settings.folder)?;
+    remote.prepare(&folders_to_create(&folder)).await?;
+    let index: RemoteIndex = match remote.get(&format!("{}/index.mmsync", folder)).await? {
+        Some(data) => open(&data, &settings.passphrase)?,
+        None => RemoteIndex::default(),
+    };
+    Ok(index.meetings.values().filter(|entry| !entry.deleted).count())
+}
+
+/// Sync now with the saved settings, even when background sync is off
+#[command]
+pub async fn sync_now<R: Runtime>(app: AppHandle<R>) -> Result<SyncReport, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool().clone();
+    let settings = load_settings(&pool)
+        .await?
+        .ok_or_else(|| "Set up sync first".to_string())?;
+    settings.validate()?;
+    run_sync(&app, &pool, &settings).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_sync_folder() {
+        assert_eq!(folder_path(" /team/ minutes /").unwrap(), "team/minutes");
+        assert_eq!(folder_path("a\\b").unwrap(), "a/b");
+        assert!(folder_path("/").is_err());
+        assert!(folder_path("team/../other").is_err());
+        assert_eq!(
+            folders_to_create("team/minutes"),
+            vec!["team", "team/minutes", "team/minutes/meetings", "team/minutes/exports"]
+        );
+
+        let settings: SyncSettings = serde_json::from_str(
+            r#"{"enabled": true, "passphrase": "correct horse",
+                "target": {"kind": "webdav", "url": "https://dav.example.com", "username": "me", "password": "pw"}}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.folder, DEFAULT_FOLDER);
+        assert_eq!(settings.interval_minutes, DEFAULT_INTERVAL_MINUTES);
+        assert!(settings.validate().is_ok());
+        assert!(SyncSettings { passphrase: "short".to_string(), ..settings.clone() }.validate().is_err());
+        assert!(SyncSettings { interval_minutes: 1, ..settings }.validate().is_err());
+    }
+}
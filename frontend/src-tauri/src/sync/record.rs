@@ -0,0 +1,268 @@
+// Synced meeting records
+// A meeting travels as one JSON record (meeting row, transcript, minutes, action items,
+// tags, participants) encrypted with the sync passphrase. The remote index lists every
+// record with its content hash and last-write time; comparing hashes with the ones saved at
+// the last sync tells which side changed, and when both did, the later write wins.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+
+use crate::backup::archive::{DecryptingReader, EncryptingWriter};
+use crate::database::models::{ActionItem, MeetingModel, MeetingParticipant, Transcript};
+use crate::database::repositories::{
+    action_item::ActionItemsRepository, meeting::MeetingsRepository, meeting_participant::MeetingParticipantsRepository,
+    meeting_tag::MeetingTagsRepository, summary::SummaryProcessesRepository, transcript::TranscriptsRepository,
+};
+
+/// Bumped when the record layout changes; newer records are left for a newer app
+pub const RECORD_FORMAT: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncedMeeting {
+    pub format: u32,
+    /// folder_path and library_folder_id are cleared: they only mean something on the
+    /// machine that set them
+    pub meeting: MeetingModel,
+    pub transcripts: Vec<Transcript>,
+    /// Stored minutes (summary_processes.result), when a summary was generated
+    pub summary: Option<String>,
+    pub action_items: Vec<ActionItem>,
+    pub tags: Vec<String>,
+    pub participants: Vec<MeetingParticipant>,
+}
+
+impl SyncedMeeting {
+    pub async fn load(pool: &SqlitePool, meeting_id: &str) -> Result<Option<Self>, String> {
+        let Some(mut meeting) = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load meeting: {}", e))?
+        else {
+            return Ok(None);
+        };
+        meeting.folder_path = None;
+        meeting.library_folder_id = None;
+
+        let summary = SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+            .await
+            .map_err(|e| format!("Failed to load summary: {}", e))?
+            .filter(|process| process.status == "completed")
+            .and_then(|process| process.result);
+        Ok(Some(Self {
+            format: RECORD_FORMAT,
+            meeting,
+            transcripts: TranscriptsRepository::list_for_meeting(pool, meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load transcript: {}", e))?,
+            summary,
+            action_items: ActionItemsRepository::list_for_meeting(pool, meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load action items: {}", e))?,
+            tags: MeetingTagsRepository::list_for_meeting(pool, meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load tags: {}", e))?,
+            participants: MeetingParticipantsRepository::list_for_meeting(pool, meeting_id)
+                .await
+                .map_err(|e| format!("Failed to load participants: {}", e))?,
+        }))
+    }
+
+    /// Hash of the content, blind to when the meeting row was last touched
+    pub fn content_hash(&self) -> Result<String, String> {
+        let mut content = self.clone();
+        content.meeting.updated_at = content.meeting.created_at.clone();
+        let json = serde_json::to_vec(&content).map_err(|e| format!("Failed to serialize meeting: {}", e))?;
+        Ok(Sha256::digest(&json).iter().map(|byte| format!("{:02x}", byte)).collect())
+    }
+}
+
+/// One record in the remote index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteEntry {
+    pub content_hash: String,
+    /// When the content was last changed, on whichever machine changed it
+    pub modified_at: DateTime<Utc>,
+    /// The meeting was deleted; the entry stays so other machines delete it too
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RemoteIndex {
+    pub meetings: BTreeMap<String, RemoteEntry>,
+}
+
+impl RemoteIndex {
+    /// Fold in an index written by another machine meanwhile, keeping the later write of
+    /// each record
+    pub fn merge(&mut self, other: RemoteIndex) {
+        for (meeting_id, theirs) in other.meetings {
+            match self.meetings.get(&meeting_id) {
+                Some(ours) if ours.modified_at >= theirs.modified_at => {}
+                _ => {
+                    self.meetings.insert(meeting_id, theirs);
+                }
+            }
+        }
+    }
+}
+
+/// Hashes saved at the last sync of a meeting
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncedHashes {
+    pub local_hash: String,
+    pub remote_hash: String,
+}
+
+/// The meeting as it is on this machine now
+#[derive(Debug, Clone)]
+pub struct LocalState {
+    pub content_hash: String,
+    pub modified_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncAction {
+    Nothing,
+    /// Upload the local record
+    Push,
+    /// Replace the local meeting with the remote record
+    Pull,
+    /// Mark the remote record deleted
+    PushDeletion,
+    /// Delete the local meeting
+    DeleteLocal,
+    /// Both sides already agree; only remember that
+    Record,
+}
+
+/// What to do with one meeting, and whether both sides had changed since the last sync
+pub fn decide(
+    local: Option<&LocalState>,
+    synced: Option<&SyncedHashes>,
+    remote: Option<&RemoteEntry>,
+) -> (SyncAction, bool) {
+    let local_changed = |local: &LocalState| match synced {
+        Some(hashes) => hashes.local_hash != local.content_hash,
+        None => true,
+    };
+    let remote_changed = |remote: &RemoteEntry| match synced {
+        Some(hashes) => remote.deleted || hashes.remote_hash != remote.content_hash,
+        None => true,
+    };
+
+    match (local, remote) {
+        (None, None) => (SyncAction::Nothing, false),
+        (None, Some(remote)) if remote.deleted => (SyncAction::Nothing, false),
+        (Some(_), None) => (SyncAction::Push, false),
+        // Deleted here since the last sync
+        (None, Some(remote)) => match synced {
+            None => (SyncAction::Pull, false),
+            Some(_) if remote_changed(remote) => (SyncAction::Pull, true),
+            Some(_) => (SyncAction::PushDeletion, false),
+        },
+        (Some(local), Some(remote)) if remote.deleted => {
+            if synced.is_some() && !local_changed(local) {
+                (SyncAction::DeleteLocal, false)
+            } else {
+                // An edit outlives a deletion made elsewhere
+                (SyncAction::Push, synced.is_some())
+            }
+        }
+        (Some(local), Some(remote)) => {
+            if local.content_hash == remote.content_hash {
+                let action = if synced.is_some() && !local_changed(local) {
+                    SyncAction::Nothing
+                } else {
+                    SyncAction::Record
+                };
+                return (action, false);
+            }
+            match (local_changed(local), remote_changed(remote)) {
+                (false, false) => (SyncAction::Nothing, false),
+                (true, false) => (SyncAction::Push, false),
+                (false, true) => (SyncAction::Pull, false),
+                (true, true) if local.modified_at > remote.modified_at => (SyncAction::Push, true),
+                (true, true) => (SyncAction::Pull, true),
+            }
+        }
+    }
+}
+
+/// Encrypt a serializable value with the sync passphrase
+pub fn seal<T: Serialize>(value: &T, passphrase: &str) -> Result<Vec<u8>, String> {
+    let json = serde_json::to_vec(value).map_err(|e| format!("Failed to serialize sync record: {}", e))?;
+    let mut writer = EncryptingWriter::new(Vec::new(), passphrase).map_err(|e| e.to_string())?;
+    writer.write_all(&json).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())
+}
+
+/// Decrypt and parse an object written by `seal`
+pub fn open<T: for<'de> Deserialize<'de>>(data: &[u8], passphrase: &str) -> Result<T, String> {
+    let mut json = Vec::new();
+    DecryptingReader::new(data, passphrase)
+        .and_then(|mut reader| reader.read_to_end(&mut json))
+        .map_err(|_| "Wrong sync passphrase, or the synced data is damaged".to_string())?;
+    serde_json::from_slice(&json).map_err(|e| format!("Invalid sync record: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 1, hour, 0, 0).unwrap()
+    }
+
+    fn entry(hash: &str, hour: u32, deleted: bool) -> RemoteEntry {
+        RemoteEntry {
+            content_hash: hash.to_string(),
+            modified_at: at(hour),
+            deleted,
+        }
+    }
+
+    #[test]
+    fn decides_by_what_changed_since_the_last_sync() {
+        let synced = SyncedHashes {
+            local_hash: "a".to_string(),
+            remote_hash: "a".to_string(),
+        };
+        let local = |hash: &str, hour| LocalState {
+            content_hash: hash.to_string(),
+            modified_at: at(hour),
+        };
+
+        assert_eq!(decide(Some(&local("a", 9)), None, None), (SyncAction::Push, false));
+        assert_eq!(decide(None, None, Some(&entry("a", 9, false))), (SyncAction::Pull, false));
+        assert_eq!(decide(Some(&local("a", 9)), None, Some(&entry("a", 9, false))), (SyncAction::Record, false));
+        assert_eq!(decide(Some(&local("a", 9)), Some(&synced), Some(&entry("a", 9, false))), (SyncAction::Nothing, false));
+        assert_eq!(decide(Some(&local("b", 10)), Some(&synced), Some(&entry("a", 9, false))), (SyncAction::Push, false));
+        assert_eq!(decide(Some(&local("a", 9)), Some(&synced), Some(&entry("c", 10, false))), (SyncAction::Pull, false));
+        // Both edited: the later write wins
+        assert_eq!(decide(Some(&local("b", 11)), Some(&synced), Some(&entry("c", 10, false))), (SyncAction::Push, true));
+        assert_eq!(decide(Some(&local("b", 10)), Some(&synced), Some(&entry("c", 11, false))), (SyncAction::Pull, true));
+        // Deletions
+        assert_eq!(decide(None, Some(&synced), Some(&entry("a", 9, false))), (SyncAction::PushDeletion, false));
+        assert_eq!(decide(None, Some(&synced), Some(&entry("c", 10, false))), (SyncAction::Pull, true));
+        assert_eq!(decide(Some(&local("a", 9)), Some(&synced), Some(&entry("a", 10, true))), (SyncAction::DeleteLocal, false));
+        assert_eq!(decide(Some(&local("b", 9)), Some(&synced), Some(&entry("a", 10, true))), (SyncAction::Push, true));
+        assert_eq!(decide(None, Some(&synced), Some(&entry("a", 10, true))), (SyncAction::Nothing, false));
+
+        let mut index = RemoteIndex::default();
+        index.meetings.insert("m1".to_string(), entry("a", 9, false));
+        index.meetings.insert("m2".to_string(), entry("b", 12, false));
+        let mut theirs = RemoteIndex::default();
+        theirs.meetings.insert("m1".to_string(), entry("c", 10, false));
+        theirs.meetings.insert("m2".to_string(), entry("d", 11, true));
+        theirs.meetings.insert("m3".to_string(), entry("e", 8, false));
+        index.merge(theirs);
+        assert_eq!(index.meetings["m1"], entry("c", 10, false));
+        assert_eq!(index.meetings["m2"], entry("b", 12, false));
+        assert_eq!(index.meetings.len(), 3);
+    }
+}
@@ -0,0 +1,268 @@
+// Trash
+// Deleting a meeting moves it to the trash, where it is hidden from the library, search and
+// exports but keeps all of its data, so an accidental delete can be undone. Meetings are
+// removed for good once they have been in the trash for the purge window (30 days unless
+// configured), by a background task, or right away when the trash is emptied. Purging
+// also deletes a recording shared through deduplicated audio storage, so its space is freed.
+//
+// Sync keeps trashed meetings until they are purged; only the purge deletes them remotely.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::path::Path;
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::{self, AppState};
+
+/// How often the background task purges expired meetings
+const PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+fn default_purge_after_days() -> u32 {
+    30
+}
+
+/// Trash settings, stored as JSON in settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashSettings {
+    /// Days a meeting stays in the trash before it is deleted for good
+    #[serde(default = "default_purge_after_days")]
+    pub purge_after_days: u32,
+}
+
+impl Default for TrashSettings {
+    fn default() -> Self {
+        Self {
+            purge_after_days: default_purge_after_days(),
+        }
+    }
+}
+
+impl TrashSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=365).contains(&self.purge_after_days) {
+            return Err("Meetings stay in the trash between 1 and 365 days".to_string());
+        }
+        Ok(())
+    }
+
+    /// When a meeting deleted at `deleted_at` is purged
+    fn purge_at(&self, deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+        deleted_at + Duration::days(self.purge_after_days as i64)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrashedMeeting {
+    pub id: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: DateTime<Utc>,
+    pub purge_at: DateTime<Utc>,
+}
+
+async fn load_settings(pool: &SqlitePool) -> Result<TrashSettings, String> {
+    SettingsRepository::get_trash_settings(pool)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(|e| format!("Failed to load trash settings: {}", e))
+}
+
+/// Delete a meeting for good, with its search index and attachments
+pub(crate) async fn purge_meeting<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, meeting_id: &str) -> Result<bool, String> {
+    let folder = MeetingsRepository::get_meeting_metadata(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?
+        .and_then(|meeting| meeting.folder_path)
+        .filter(|folder| !folder.is_empty());
+    let deleted = MeetingsRepository::delete_meeting(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to delete meeting: {}", e))?;
+    if let Ok(app_data_dir) = app.path().app_data_dir() {
+        let index_dir = crate::summary::vector_index::index_dir(&app_data_dir);
+        if let Err(e) = crate::summary::vector_index::VectorIndex::delete(&index_dir, meeting_id) {
+            warn!("Failed to delete vector index for {}: {}", meeting_id, e);
+        }
+        if let Err(e) = crate::attachments::remove_meeting_attachments(&app_data_dir, meeting_id) {
+            warn!("Failed to delete attachments of {}: {}", meeting_id, e);
+        }
+    }
+    // A deduplicated recording is the meeting's link to the shared copy; once removed,
+    // `free_recordings` can delete the copy
+    if let Some(folder) = folder.filter(|_| deleted) {
+        if let Err(e) = crate::audio::dedup::remove_linked_recording(pool, &Path::new(&folder).join("audio.mp4")).await {
+            warn!("Failed to delete the recording of {}: {}", meeting_id, e);
+        }
+    }
+    Ok(deleted)
+}
+
+/// Remove stored recordings only purged meetings linked to; a purge only deletes the
+/// meeting folder's link to a deduplicated recording
+async fn free_recordings(pool: &SqlitePool) {
+    match crate::audio::dedup::collect_garbage(pool).await {
+        Ok(report) if report.removed_blobs > 0 => {
+            info!("Freed {} bytes of recordings no meeting uses any more", report.freed_bytes);
+        }
+        Ok(_) => {}
+        Err(e) => warn!("Failed to free unused recordings: {}", e),
+    }
+}
+
+/// Purge the given meetings; returns how many were deleted
+async fn purge_all<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, meeting_ids: &[String]) -> usize {
+    let mut purged = 0;
+    for meeting_id in meeting_ids {
+        match purge_meeting(app, pool, meeting_id).await {
+            Ok(true) => purged += 1,
+            Ok(false) => {}
+            Err(e) => warn!("Failed to purge meeting {} from the trash: {}", meeting_id, e),
+        }
+    }
+    if purged > 0 {
+        free_recordings(pool).await;
+    }
+    purged
+}
+
+/// Purge meetings that have been in the trash longer than the purge window
+async fn purge_expired<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool) -> Result<usize, String> {
+    let settings = load_settings(pool).await?;
+    let cutoff = Utc::now() - Duration::days(settings.purge_after_days as i64);
+    let expired = MeetingsRepository::trashed_before(pool, cutoff)
+        .await
+        .map_err(|e| format!("Failed to load the trash: {}", e))?;
+    Ok(purge_all(app, pool, &expired).await)
+}
+
+/// Start the background task that empties expired meetings from the trash
+pub fn start_purge_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let pool = state::try_pool(&app);
+            if let Some(pool) = pool {
+                match purge_expired(&app, &pool).await {
+                    Ok(0) => {}
+                    Ok(purged) => {
+                        info!("Purged {} meeting(s) from the trash", purged);
+                        let _ = app.emit("trash-purged", serde_json::json!({ "purged": purged }));
+                    }
+                    Err(e) => warn!("Trash purge failed: {}", e),
+                }
+            }
+            tokio::time::sleep(PURGE_INTERVAL).await;
+        }
+    });
+}
+
+/// Meetings in the trash, most recently deleted first, with when each is purged
+#[command]
+pub async fn list_trash<R: Runtime>(app: AppHandle<R>) -> Result<Vec<TrashedMeeting>, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let settings = load_settings(pool).await?;
+    let trashed = MeetingsRepository::list_trashed(pool)
+        .await
+        .map_err(|e| format!("Failed to load the trash: {}", e))?;
+    Ok(trashed
+        .into_iter()
+        .map(|(id, title, created_at, deleted_at)| TrashedMeeting {
+            id,
+            title,
+            created_at: created_at.0,
+            deleted_at,
+            purge_at: settings.purge_at(deleted_at),
+        })
+        .collect())
+}
+
+/// Take a meeting out of the trash
+#[command]
+pub async fn restore_meeting<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let restored = MeetingsRepository::restore_from_trash(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to restore meeting: {}", e))?;
+    if !restored {
+        return Err(format!("Meeting {} is not in the trash", meeting_id));
+    }
+    info!("Restored meeting {} from the trash", meeting_id);
+    Ok(())
+}
+
+/// Delete one meeting in the trash for good, without waiting for the purge window
+#[command]
+pub async fn delete_meeting_permanently<R: Runtime>(app: AppHandle<R>, meeting_id: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let in_trash = MeetingsRepository::list_trashed(pool)
+        .await
+        .map_err(|e| format!("Failed to load the trash: {}", e))?
+        .iter()
+        .any(|(id, ..)| *id == meeting_id);
+    if !in_trash {
+        return Err(format!("Meeting {} is not in the trash", meeting_id));
+    }
+    if purge_meeting(&app, pool, &meeting_id).await? {
+        free_recordings(pool).await;
+    }
+    Ok(())
+}
+
+/// Delete every meeting in the trash for good; returns how many were deleted
+#[command]
+pub async fn empty_trash<R: Runtime>(app: AppHandle<R>) -> Result<usize, String> {
+    let state = app.state::<AppState>();
+    let pool = state.db_manager.pool();
+    let trashed: Vec<String> = MeetingsRepository::list_trashed(pool)
+        .await
+        .map_err(|e| format!("Failed to load the trash: {}", e))?
+        .into_iter()
+        .map(|(id, ..)| id)
+        .collect();
+    let purged = purge_all(&app, pool, &trashed).await;
+    info!("Emptied the trash: {} of {} meeting(s) deleted", purged, trashed.len());
+    if purged < trashed.len() {
+        return Err(format!("{} meeting(s) could not be deleted", trashed.len() - purged));
+    }
+    Ok(purged)
+}
+
+#[command]
+pub async fn get_trash_settings<R: Runtime>(app: AppHandle<R>) -> Result<TrashSettings, String> {
+    let state = app.state::<AppState>();
+    load_settings(state.db_manager.pool()).await
+}
+
+#[command]
+pub async fn save_trash_settings<R: Runtime>(app: AppHandle<R>, settings: TrashSettings) -> Result<(), String> {
+    settings.validate()?;
+    let state = app.state::<AppState>();
+    SettingsRepository::save_trash_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save trash settings: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn validates_purge_window() {
+        let settings: TrashSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, TrashSettings::default());
+        assert!(settings.validate().is_ok());
+        assert!(TrashSettings { purge_after_days: 0 }.validate().is_err());
+        assert!(TrashSettings { purge_after_days: 366 }.validate().is_err());
+
+        let deleted_at = Utc.with_ymd_and_hms(2026, 2, 1, 9, 0, 0).unwrap();
+        assert_eq!(
+            TrashSettings { purge_after_days: 7 }.purge_at(deleted_at),
+            Utc.with_ymd_and_hms(2026, 2, 8, 9, 0, 0).unwrap()
+        );
+    }
+}
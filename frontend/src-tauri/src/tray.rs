@@ -40,6 +40,11 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         "pause_recording" => pause_recording_handler(app),
         "resume_recording" => resume_recording_handler(app),
         "stop_recording" => stop_recording_handler(app),
+        "bookmark_moment" => {
+            if let Err(e) = crate::bookmarks::bookmark_moment(app, None) {
+                log::warn!("Tray: Failed to bookmark the current moment: {}", e);
+            }
+        }
         "open_window" => focus_main_window(app),
         "settings" => {
             focus_main_window(app);
@@ -342,6 +347,7 @@ fn build_menu<R: Runtime>(
             }
             RecordingState::Recording => {
                 builder = builder
+                    .item(&MenuItemBuilder::with_id("bookmark_moment", "★ Bookmark This Moment").build(app)?)
                     .item(&MenuItemBuilder::with_id("pause_recording", "⏸ Pause Recording").build(app)?)
                     .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
             }
@@ -0,0 +1,646 @@
+// Watch folder
+// Audio files dropped into a configured directory (e.g. a recorder's sync folder) are filed
+// as meetings without opening the app's recording flow: the file is imported, transcribed
+// with the default transcription engine, saved, and summarized with the configured model.
+// The folder is polled, and a file is only picked up once its size and modification time
+// stop changing, so files still being copied are left alone. Each file is processed once;
+// progress is emitted as "watch-folder-progress" events.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, SystemTime};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::api::TranscriptSegment;
+use crate::audio::audio_processing::create_meeting_folder;
+use crate::audio::ffmpeg::find_ffmpeg_path;
+use crate::audio::recording_preferences::load_recording_preferences;
+use crate::audio::transcription::engine::{get_or_init_transcription_engine, validate_transcription_model_ready};
+use crate::audio::transcription::{apply_replacements, HallucinationFilter, HallucinationVerdict, TranscriptionError};
+use crate::audio::vad::ContinuousVadProcessor;
+use crate::database::models::WatchFolderFile;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::database::repositories::transcript_chunk::TranscriptChunksRepository;
+use crate::database::repositories::watch_folder_file::WatchFolderFilesRepository;
+use crate::integrations::MeetingEvent;
+use crate::state::{self, AppState};
+use crate::summary::service::SummaryService;
+use crate::utils::format_timestamp;
+
+const SCAN_INTERVAL: Duration = Duration::from_secs(15);
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "wav", "m4a", "mp4", "aac", "ogg", "opus", "flac", "webm", "wma", "aiff"];
+const DEFAULT_TEMPLATE: &str = "standard_meeting";
+/// Longest stretch of speech sent to the engine at once
+const MAX_SEGMENT_MS: u32 = 25_000;
+const VAD_REDEMPTION_MS: u32 = 400;
+/// Samples decoded per read (10 s at 16 kHz)
+const DECODE_BLOCK_SAMPLES: usize = 16_000 * 10;
+const HISTORY_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchFolderSettings {
+    pub enabled: bool,
+    pub folder: String,
+    /// Generate minutes once a file is transcribed
+    #[serde(default = "default_summarize")]
+    pub summarize: bool,
+    /// Summary template; None uses the standard meeting template
+    #[serde(default)]
+    pub template_id: Option<String>,
+}
+
+fn default_summarize() -> bool {
+    true
+}
+
+impl WatchFolderSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.folder.trim().is_empty() {
+            return Err("Choose a folder to watch".to_string());
+        }
+        if self.enabled && !Path::new(self.folder.trim()).is_dir() {
+            return Err(format!("{} is not a folder", self.folder.trim()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchStage {
+    Importing,
+    Transcribing,
+    Summarizing,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchFolderProgress {
+    pub path: String,
+    pub file_name: String,
+    pub stage: WatchStage,
+    /// Share of the recording transcribed (0.0-1.0), while transcribing
+    pub progress: Option<f64>,
+    pub meeting_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// A file found in the watched folder
+#[derive(Debug, Clone, PartialEq)]
+struct FoundFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    let hidden = path
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+    !hidden
+        && path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| AUDIO_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+}
+
+/// Audio files directly in `folder`
+fn scan(folder: &Path) -> std::io::Result<Vec<FoundFile>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+        if !is_audio_file(&path) {
+            continue;
+        }
+        let Ok(metadata) = std::fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_file() {
+            files.push(FoundFile {
+                size: metadata.len(),
+                modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                path,
+            });
+        }
+    }
+    files.sort_by_key(|file| file.modified);
+    Ok(files)
+}
+
+/// Files unchanged since the previous scan; the rest may still be being written
+fn settled(previous: &HashMap<PathBuf, FoundFile>, current: &[FoundFile]) -> Vec<FoundFile> {
+    current
+        .iter()
+        .filter(|file| previous.get(&file.path) == Some(*file))
+        .cloned()
+        .collect()
+}
+
+/// Whether a recorded file is the same version as the one found
+fn already_processed(record: &WatchFolderFile, file: &FoundFile) -> bool {
+    let modified: DateTime<Utc> = file.modified.into();
+    record.size_bytes == file.size as i64 && record.modified_at.timestamp() == modified.timestamp()
+}
+
+/// Length in seconds from FFmpeg's "Duration: 00:12:34.56" banner line
+fn parse_ffmpeg_duration(stderr: &str) -> Option<f64> {
+    let value = stderr.split("Duration: ").nth(1)?.split(',').next()?.trim();
+    let mut parts = value.split(':');
+    let hours: f64 = parts.next()?.parse().ok()?;
+    let minutes: f64 = parts.next()?.parse().ok()?;
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    Some(hours * 3600.0 + minutes * 60.0 + seconds)
+}
+
+fn samples_from_le_bytes(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|sample| sample * sample).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Transcript as "[MM:SS] text" lines, for the summary
+fn transcript_text(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| {
+            let total = segment.audio_start_time.unwrap_or(0.0).max(0.0) as u64;
+            format!("[{:02}:{:02}] {}", total / 60, total % 60, segment.text.trim())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn ffmpeg_command(ffmpeg_path: &Path) -> std::process::Command {
+    #[allow(unused_mut)]
+    let mut command = std::process::Command::new(ffmpeg_path);
+
+    // Hide console window on Windows
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    command
+}
+
+/// Fill `buffer` from `reader`, short only at the end of the stream
+fn read_block(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..])? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
+/// Decode `audio` to 16 kHz mono and send its speech segments as they are found
+fn decode_speech(
+    ffmpeg_path: &Path,
+    audio: &Path,
+    segments: tokio::sync::mpsc::Sender<Result<crate::audio::vad::SpeechSegment, String>>,
+) -> Result<(), String> {
+    let mut vad = ContinuousVadProcessor::new(16_000, VAD_REDEMPTION_MS).map_err(|e| e.to_string())?;
+    vad.set_max_segment_duration(Some(MAX_SEGMENT_MS));
+
+    let mut child = ffmpeg_command(ffmpeg_path)
+        .arg("-i")
+        .arg(audio)
+        .args(["-vn", "-ac", "1", "-ar", "16000", "-f", "f32le", "pipe:1"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to run FFmpeg: {}", e))?;
+    let mut stdout = child.stdout.take().ok_or_else(|| "FFmpeg produced no output".to_string())?;
+
+    let mut buffer = vec![0u8; DECODE_BLOCK_SAMPLES * 4];
+    loop {
+        let filled = read_block(&mut stdout, &mut buffer).map_err(|e| format!("Failed to decode audio: {}", e))?;
+        if filled == 0 {
+            break;
+        }
+        for segment in vad.process_audio(&samples_from_le_bytes(&buffer[..filled])).map_err(|e| e.to_string())? {
+            // The receiver is gone when transcription failed
+            if segments.blocking_send(Ok(segment)).is_err() {
+                let _ = child.kill();
+                return Ok(());
+            }
+        }
+        if filled < buffer.len() {
+            break;
+        }
+    }
+    for segment in vad.flush().map_err(|e| e.to_string())? {
+        let _ = segments.blocking_send(Ok(segment));
+    }
+
+    let status = child.wait().map_err(|e| format!("FFmpeg failed: {}", e))?;
+    if !status.success() {
+        return Err(format!("FFmpeg failed to decode {}", audio.display()));
+    }
+    Ok(())
+}
+
+fn emit_progress<R: Runtime>(app: &AppHandle<R>, file: &Path, stage: WatchStage, progress: Option<f64>) {
+    let _ = app.emit(
+        "watch-folder-progress",
+        WatchFolderProgress {
+            path: file.to_string_lossy().to_string(),
+            file_name: file
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            stage,
+            progress,
+            meeting_id: None,
+            error: None,
+        },
+    );
+}
+
+/// Transcribe a recording with the default engine
+async fn transcribe_file<R: Runtime>(app: &AppHandle<R>, source: &Path, audio: &Path) -> Result<Vec<TranscriptSegment>, String> {
+    validate_transcription_model_ready(app).await?;
+    let engine = get_or_init_transcription_engine(app).await?;
+    let ffmpeg_path = find_ffmpeg_path().ok_or_else(|| "FFmpeg not found".to_string())?;
+
+    // FFmpeg prints the input's length even when given no output
+    let (probe_path, probe_audio) = (ffmpeg_path.clone(), audio.to_path_buf());
+    let duration = tokio::task::spawn_blocking(move || ffmpeg_command(&probe_path).arg("-i").arg(&probe_audio).output())
+        .await
+        .map_err(|e| format!("Audio probing task failed: {}", e))?
+        .ok()
+        .and_then(|probe| parse_ffmpeg_duration(&String::from_utf8_lossy(&probe.stderr)))
+        .filter(|duration| *duration > 0.0);
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(4);
+    let audio_path = audio.to_path_buf();
+    let decoder = tokio::task::spawn_blocking(move || {
+        if let Err(e) = decode_speech(&ffmpeg_path, &audio_path, sender.clone()) {
+            let _ = sender.blocking_send(Err(e));
+        }
+    });
+
+    let language = crate::get_language_preference_internal();
+    let filter = HallucinationFilter::default();
+    let mut transcript = Vec::new();
+    while let Some(segment) = receiver.recv().await {
+        let segment = segment?;
+        let (start, end) = (segment.start_timestamp_ms / 1000.0, segment.end_timestamp_ms / 1000.0);
+        let chunk_rms = rms(&segment.samples);
+        let result = match engine.transcribe(segment.samples, language.clone()).await {
+            Ok(result) => result,
+            Err(e @ (TranscriptionError::NetworkUnavailable(_) | TranscriptionError::ModelNotLoaded)) => {
+                return Err(e.to_string());
+            }
+            Err(e) => {
+                warn!("Skipped {:.1}s-{:.1}s of {}: {}", start, end, source.display(), e);
+                continue;
+            }
+        };
+
+        let text = apply_replacements(&result.text);
+        let suppressed = matches!(
+            filter.check(&text, chunk_rms, result.no_speech_prob),
+            HallucinationVerdict::Suppressed(_)
+        );
+        if !text.trim().is_empty() && !suppressed {
+            transcript.push(TranscriptSegment {
+                id: format!("watch-{}", Uuid::new_v4()),
+                text: text.trim().to_string(),
+                timestamp: format_timestamp(start),
+                audio_start_time: Some(start),
+                audio_end_time: Some(end),
+                duration: Some(end - start),
+                quality_score: None,
+                speaker: None,
+                speaker_label: None,
+                language: result.language,
+                confidence: result.confidence.map(f64::from),
+            });
+        }
+        let progress = duration.map(|duration| (end / duration).clamp(0.0, 1.0));
+        emit_progress(app, source, WatchStage::Transcribing, progress);
+    }
+    decoder.await.map_err(|e| format!("Audio decoding task failed: {}", e))?;
+    Ok(transcript)
+}
+
+/// Generate minutes with the configured model, waiting for them
+async fn summarize<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    meeting_id: &str,
+    text: String,
+    template_id: &str,
+) -> Result<(), String> {
+    let config = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load model settings: {}", e))?
+        .ok_or_else(|| "No summary model configured".to_string())?;
+    let meeting_template = crate::meeting_templates::template_for_meeting(pool, meeting_id).await?;
+    let (provider, model) = meeting_template
+        .as_ref()
+        .and_then(|meeting_template| meeting_template.summary_model())
+        .unwrap_or((config.provider, config.model));
+    let template_id = meeting_template
+        .and_then(|meeting_template| meeting_template.summary_template_id)
+        .unwrap_or_else(|| template_id.to_string());
+    SummaryProcessesRepository::create_or_reset_process(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to initialize summary: {}", e))?;
+    TranscriptChunksRepository::save_transcript_data(pool, meeting_id, &text, &provider, &model, 40000, 1000)
+        .await
+        .map_err(|e| format!("Failed to save transcript data: {}", e))?;
+
+    SummaryService::process_transcript_background(
+        app.clone(),
+        pool.clone(),
+        meeting_id.to_string(),
+        text,
+        provider,
+        model,
+        String::new(),
+        template_id,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )
+    .await;
+
+    match SummaryProcessesRepository::get_summary_data(pool, meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load summary: {}", e))?
+    {
+        Some(process) if process.status == "completed" => Ok(()),
+        Some(process) => Err(process.error.unwrap_or_else(|| format!("Summary {}", process.status))),
+        None => Err("Summary was not saved".to_string()),
+    }
+}
+
+/// Import, transcribe and file one recording; returns the meeting id and any summary error
+async fn process_file<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    settings: &WatchFolderSettings,
+    file: &Path,
+) -> Result<(String, Option<String>), String> {
+    emit_progress(app, file, WatchStage::Importing, None);
+    let title = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Imported recording".to_string());
+    let preferences = load_recording_preferences(app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+    let folder = create_meeting_folder(&preferences.save_folder, &title, false)
+        .map_err(|e| format!("Failed to create recording folder: {}", e))?;
+
+    let transcribed = async {
+        crate::import::import_audio(file, &folder).await?;
+        emit_progress(app, file, WatchStage::Transcribing, Some(0.0));
+        let segments = transcribe_file(app, file, &folder.join("audio.mp4")).await?;
+        if segments.is_empty() {
+            return Err("No speech was found in the recording".to_string());
+        }
+        Ok(segments)
+    }
+    .await;
+    let segments = match transcribed {
+        Ok(segments) => segments,
+        Err(e) => {
+            if let Err(remove_error) = std::fs::remove_dir_all(&folder) {
+                warn!("Failed to remove {}: {}", folder.display(), remove_error);
+            }
+            return Err(e);
+        }
+    };
+
+    let meeting_id = TranscriptsRepository::save_transcript(
+        pool,
+        &title,
+        &segments,
+        Some(folder.to_string_lossy().to_string()),
+    )
+    .await
+    .map_err(|e| format!("Failed to save transcript: {}", e))?;
+    info!(
+        "Transcribed {} from the watch folder as meeting {} ({} segment(s))",
+        file.display(),
+        meeting_id,
+        segments.len()
+    );
+
+    if let Err(e) = crate::audio::dedup::deduplicate_file(pool, &folder.join("audio.mp4")).await {
+        warn!("Failed to deduplicate the recording of {}: {}", meeting_id, e);
+    }
+    if let Err(e) = crate::meeting_templates::apply_matching(pool, &meeting_id).await {
+        warn!("{}", e);
+    }
+    crate::meeting_info::spawn_autofill(app, meeting_id.clone(), false);
+    crate::integrations::spawn_meeting_event(app, MeetingEvent::TranscriptReady, meeting_id.clone());
+    crate::summary::auto_title::spawn_for_meeting(app, meeting_id.clone());
+    crate::summary::vector_index::spawn_index_update(app, meeting_id.clone());
+
+    let mut summary_error = None;
+    if settings.summarize {
+        emit_progress(app, file, WatchStage::Summarizing, None);
+        let template_id = settings.template_id.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+        if let Err(e) = summarize(app, pool, &meeting_id, transcript_text(&segments), template_id).await {
+            warn!("Failed to summarize meeting {}: {}", meeting_id, e);
+            summary_error = Some(format!("Transcribed, but the summary failed: {}", e));
+        }
+    }
+    Ok((meeting_id, summary_error))
+}
+
+/// Process a settled file unless this version of it was processed before
+async fn handle_file<R: Runtime>(app: &AppHandle<R>, pool: &SqlitePool, settings: &WatchFolderSettings, file: &FoundFile) {
+    let key = file.path.to_string_lossy().to_string();
+    match WatchFolderFilesRepository::get(pool, &key).await {
+        Ok(Some(record)) if already_processed(&record, file) => return,
+        Ok(_) => {}
+        Err(e) => {
+            warn!("Failed to load watch folder history: {}", e);
+            return;
+        }
+    }
+    if let Err(e) = WatchFolderFilesRepository::start(pool, &key, file.size as i64, file.modified.into()).await {
+        warn!("Failed to save watch folder history: {}", e);
+        return;
+    }
+
+    info!("Processing {} from the watch folder", file.path.display());
+    let (meeting_id, error, stage) = match process_file(app, pool, settings, &file.path).await {
+        Ok((meeting_id, summary_error)) => (Some(meeting_id), summary_error, WatchStage::Done),
+        Err(e) => {
+            warn!("Failed to process {}: {}", file.path.display(), e);
+            (None, Some(e), WatchStage::Failed)
+        }
+    };
+    // A summary failure still leaves a filed meeting
+    let failed = (stage == WatchStage::Failed).then_some(error.as_deref()).flatten();
+    if let Err(e) = WatchFolderFilesRepository::finish(pool, &key, meeting_id.as_deref(), failed).await {
+        warn!("Failed to save watch folder history: {}", e);
+    }
+    let _ = app.emit(
+        "watch-folder-progress",
+        WatchFolderProgress {
+            path: key,
+            file_name: file
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            stage,
+            progress: None,
+            meeting_id,
+            error,
+        },
+    );
+}
+
+/// Poll the watched folder and process new recordings one at a time
+pub fn start_watch_task<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let mut seen: HashMap<PathBuf, FoundFile> = HashMap::new();
+        let mut recovered = false;
+        loop {
+            tokio::time::sleep(SCAN_INTERVAL).await;
+            let Some(pool) = state::try_pool(&app) else {
+                continue;
+            };
+            if !recovered {
+                recovered = true;
+                if let Err(e) = WatchFolderFilesRepository::fail_interrupted(&pool).await {
+                    warn!("Failed to update watch folder history: {}", e);
+                }
+            }
+            let settings = match SettingsRepository::get_watch_folder_settings(&pool).await {
+                Ok(Some(settings)) if settings.enabled => settings,
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!("Failed to load watch folder settings: {}", e);
+                    continue;
+                }
+            };
+            // The transcription engine is busy with the live meeting
+            if crate::audio::recording_commands::is_recording().await {
+                continue;
+            }
+
+            let folder = PathBuf::from(settings.folder.trim());
+            let found = match scan(&folder) {
+                Ok(found) => found,
+                Err(e) => {
+                    warn!("Failed to read watch folder {}: {}", folder.display(), e);
+                    continue;
+                }
+            };
+            let ready = settled(&seen, &found);
+            seen = found.into_iter().map(|file| (file.path.clone(), file)).collect();
+            for file in ready {
+                handle_file(&app, &pool, &settings, &file).await;
+            }
+        }
+    });
+}
+
+#[command]
+pub async fn get_watch_folder_settings<R: Runtime>(app: AppHandle<R>) -> Result<Option<WatchFolderSettings>, String> {
+    let state = app.state::<AppState>();
+    SettingsRepository::get_watch_folder_settings(state.db_manager.pool())
+        .await
+        .map_err(|e| format!("Failed to load watch folder settings: {}", e))
+}
+
+#[command]
+pub async fn save_watch_folder_settings<R: Runtime>(app: AppHandle<R>, settings: WatchFolderSettings) -> Result<(), String> {
+    settings.validate()?;
+    let settings = WatchFolderSettings {
+        folder: settings.folder.trim().to_string(),
+        template_id: settings.template_id.filter(|template_id| !template_id.trim().is_empty()),
+        ..settings
+    };
+    let state = app.state::<AppState>();
+    SettingsRepository::save_watch_folder_settings(state.db_manager.pool(), &settings)
+        .await
+        .map_err(|e| format!("Failed to save watch folder settings: {}", e))
+}
+
+/// Files picked up from the watch folder, most recent first
+#[command]
+pub async fn list_watch_folder_files<R: Runtime>(app: AppHandle<R>) -> Result<Vec<WatchFolderFile>, String> {
+    let state = app.state::<AppState>();
+    WatchFolderFilesRepository::list_recent(state.db_manager.pool(), HISTORY_LIMIT)
+        .await
+        .map_err(|e| format!("Failed to load watch folder history: {}", e))
+}
+
+/// Process a file again at the next scan, e.g. after it failed
+#[command]
+pub async fn retry_watch_folder_file<R: Runtime>(app: AppHandle<R>, path: String) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let forgotten = WatchFolderFilesRepository::delete(state.db_manager.pool(), &path)
+        .await
+        .map_err(|e| format!("Failed to update watch folder history: {}", e))?;
+    if !forgotten {
+        return Err(format!("{} was not picked up from the watch folder", path));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_folder_helpers() {
+        assert!(is_audio_file(Path::new("/inbox/Standup.M4A")));
+        assert!(!is_audio_file(Path::new("/inbox/notes.txt")));
+        assert!(!is_audio_file(Path::new("/inbox/.Standup.m4a")));
+
+        assert_eq!(
+            parse_ffmpeg_duration("Input #0, mp3\n  Duration: 01:02:03.50, start: 0.000000, bitrate: 128 kb/s"),
+            Some(3723.5)
+        );
+        assert_eq!(parse_ffmpeg_duration("Duration: N/A, bitrate: N/A"), None);
+        assert_eq!(samples_from_le_bytes(&[0, 0, 128, 63, 0, 0, 0, 191, 1]), vec![1.0, -0.5]);
+
+        let file = |name: &str, size| FoundFile {
+            path: PathBuf::from(name),
+            size,
+            modified: SystemTime::UNIX_EPOCH + Duration::from_secs(60),
+        };
+        let previous: HashMap<PathBuf, FoundFile> =
+            [file("a.mp3", 10), file("b.mp3", 10)].into_iter().map(|f| (f.path.clone(), f)).collect();
+        // b.mp3 is still growing and c.mp3 is new
+        assert_eq!(
+            settled(&previous, &[file("a.mp3", 10), file("b.mp3", 20), file("c.mp3", 5)]),
+            vec![file("a.mp3", 10)]
+        );
+    }
+}
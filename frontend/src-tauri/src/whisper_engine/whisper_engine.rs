@@ -32,6 +32,18 @@ pub struct ModelInfo {
     pub description: String,
 }
 
+/// Detailed result of a single Whisper transcription call
+#[derive(Debug, Clone)]
+pub struct WhisperTranscription {
+    pub text: String,
+    pub confidence: f32,
+    pub is_partial: bool,
+    /// Estimated probability that the audio contained no speech (0.0 - 1.0)
+    pub no_speech_prob: f32,
+    /// Language whisper detected (ISO 639-1), only set when running with automatic detection
+    pub language: Option<String>,
+}
+
 pub struct WhisperEngine {
     models_dir: PathBuf,
     current_context: Arc<RwLock<Option<WhisperContext>>>,
@@ -388,6 +400,11 @@ impl WhisperEngine {
             return String::new();
         }
 
+        // Keep laughter as an annotation instead of discarding it as meaningless
+        if crate::audio::acoustic_events::is_laughter_text(text) {
+            return crate::audio::acoustic_events::LAUGHTER_ANNOTATION.to_string();
+        }
+
         // Check for obviously meaningless patterns first
         if Self::is_meaningless_output(text) {
             // Performance optimization: reduce meaningless output logging to debug level
@@ -533,6 +550,12 @@ impl WhisperEngine {
     
     /// Transcribe audio with streaming support for partial results and adaptive quality
     pub async fn transcribe_audio_with_confidence(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<(String, f32, bool)> {
+        let result = self.transcribe_audio_detailed(audio_data, language).await?;
+        Ok((result.text, result.confidence, result.is_partial))
+    }
+
+    /// Transcribe audio and return text along with confidence and a no-speech estimate
+    pub async fn transcribe_audio_detailed(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<WhisperTranscription> {
         let ctx_lock = self.current_context.read().await;
         let ctx = ctx_lock.as_ref()
             .ok_or_else(|| anyhow!("No model loaded. Please load a model first."))?;
@@ -610,14 +633,43 @@ impl WhisperEngine {
         let mut result = String::new();
         let mut total_confidence = 0.0;
         let mut segment_count = 0;
+        let mut token_prob_sum = 0.0f32;
+        let mut token_count = 0usize;
+        let eot_token = ctx.token_eot();
 
         let num_segments = num_segments?;
+
+        // Per-chunk language for multilingual meetings (a forced language tells us nothing)
+        let detected_language = if language_code.is_none() {
+            state
+                .full_lang_id_from_state()
+                .ok()
+                .and_then(whisper_rs::get_lang_str)
+                .map(|lang| lang.to_string())
+        } else {
+            None
+        };
+
         for i in 0..num_segments {
             let segment_text = match state.full_get_segment_text_lossy(i) {
                 Ok(text) => text,
                 Err(_) => continue,
             };
 
+            // Accumulate text token probabilities (special tokens are >= EOT) for the no-speech estimate
+            if let Ok(n_tokens) = state.full_n_tokens(i) {
+                for t in 0..n_tokens {
+                    let is_special = state.full_get_token_id(i, t).map_or(true, |id| id >= eot_token);
+                    if is_special {
+                        continue;
+                    }
+                    if let Ok(prob) = state.full_get_token_prob(i, t) {
+                        token_prob_sum += prob;
+                        token_count += 1;
+                    }
+                }
+            }
+
             // Calculate confidence based on segment length and duration (simplified approach)
             let segment_length = segment_text.len() as f32;
             let segment_confidence = if segment_length > 0.0 {
@@ -646,7 +698,21 @@ impl WhisperEngine {
             0.0
         };
 
-        Ok((cleaned_result, avg_confidence, is_partial))
+        // whisper-rs doesn't expose whisper.cpp's no_speech_prob, so approximate it from
+        // how unsure the decoder was about the tokens it emitted
+        let no_speech_prob = if token_count > 0 {
+            (1.0 - token_prob_sum / token_count as f32).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        Ok(WhisperTranscription {
+            text: cleaned_result,
+            confidence: avg_confidence,
+            is_partial,
+            no_speech_prob,
+            language: detected_language,
+        })
     }
 
     pub async fn transcribe_audio(&self, audio_data: Vec<f32>, language: Option<String>) -> Result<String> {